@@ -1,6 +1,7 @@
 // Copyright 2023 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+use iota_sdk::client::ErrorContext;
 use packable::error::UnexpectedEOF;
 use serde::{ser::SerializeMap, Serialize, Serializer};
 
@@ -30,6 +31,9 @@ pub enum Error {
     /// Unpack errors.
     #[error("{0}")]
     Unpack(#[from] packable::error::UnpackError<iota_sdk::types::block::Error, UnexpectedEOF>),
+    /// A batch was called inside of a batch.
+    #[error("batches can't be nested")]
+    NestedBatch,
 }
 
 #[cfg(feature = "stronghold")]
@@ -46,23 +50,55 @@ impl From<iota_sdk::client::node_api::mqtt::Error> for Error {
     }
 }
 
-// Serialize type with Display error.
+impl Error {
+    /// Returns the kind of the error, the variant name in camelCase.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Self::Block(_) => "block",
+            Self::Client(_) => "client",
+            Self::Wallet(_) => "wallet",
+            Self::PrefixHex(_) => "prefixHex",
+            Self::SerdeJson(_) => "serdeJson",
+            Self::Unpack(_) => "unpack",
+            Self::NestedBatch => "nestedBatch",
+        }
+    }
+
+    /// Returns the machine-readable code of the error, the kinds of the error and the SDK errors it wraps separated by
+    /// a dot, e.g. `wallet.client.responseError`.
+    pub fn code(&self) -> String {
+        match self {
+            Self::Client(error) => format!("{}.{}", self.kind(), error.kind()),
+            Self::Wallet(error) => format!("{}.{}", self.kind(), error.code()),
+            _ => self.kind().to_string(),
+        }
+    }
+
+    /// Returns the contextual data of the error, like the offending output id or the URL and status code of a failed
+    /// node request.
+    pub fn context(&self) -> ErrorContext {
+        match self {
+            Self::Client(error) => error.context(),
+            Self::Wallet(error) => error.context(),
+            _ => ErrorContext::default(),
+        }
+    }
+}
+
+// Serialize type with kind, code of wrapped errors, Display error and context.
 impl Serialize for Error {
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
         S: Serializer,
     {
-        let mut seq = serializer.serialize_map(Some(2))?;
-        let mut kind_dbg = format!("{self:?}");
-        // Convert first char to lowercase
-        if let Some(r) = kind_dbg.get_mut(0..1) {
-            r.make_ascii_lowercase();
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("type", self.kind())?;
+        // The code only differs from the kind if the error wraps another one
+        if let Self::Client(_) | Self::Wallet(_) = self {
+            map.serialize_entry("code", &self.code())?;
         }
-        // Split by whitespace for struct variants and split by `(` for tuple variants
-        // Safe to unwrap because kind_dbg is never an empty string
-        let kind = kind_dbg.split([' ', '(']).next().unwrap();
-        seq.serialize_entry("type", &kind)?;
-        seq.serialize_entry("error", &self.to_string())?;
-        seq.end()
+        map.serialize_entry("error", &self.to_string())?;
+        self.context().serialize_entries(&mut map)?;
+        map.end()
     }
 }
@@ -14,6 +14,8 @@ use std::fmt::{Formatter, Result as FmtResult};
 use derivative::Derivative;
 use fern_logger::{logger_init, LoggerConfig, LoggerOutputConfigBuilder};
 pub use iota_sdk;
+#[cfg(feature = "storage")]
+use iota_sdk::wallet::storage::encryption::StorageEncryption;
 use iota_sdk::{
     client::secret::{SecretManager, SecretManagerDto},
     wallet::{wallet::Wallet, ClientOptions},
@@ -46,6 +48,10 @@ pub struct WalletOptions {
     pub coin_type: Option<u32>,
     #[derivative(Debug(format_with = "OmittedDebug::omitted_fmt"))]
     pub secret_manager: Option<SecretManagerDto>,
+    /// Password to encrypt the storage with.
+    #[derivative(Debug(format_with = "OmittedDebug::omitted_fmt"))]
+    #[serde(default)]
+    pub storage_password: Option<String>,
 }
 
 impl WalletOptions {
@@ -58,6 +64,11 @@ impl WalletOptions {
             builder = builder.with_storage_path(storage_path);
         }
 
+        #[cfg(feature = "storage")]
+        if let Some(storage_password) = &self.storage_password {
+            builder = builder.with_storage_encryption(StorageEncryption::Password(storage_password.clone()));
+        }
+
         if let Some(secret_manager) = &self.secret_manager {
             builder = builder.with_secret_manager(SecretManager::try_from(secret_manager)?);
         }
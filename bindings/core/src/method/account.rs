@@ -7,12 +7,12 @@ use iota_sdk::{
     types::api::plugins::participation::types::{ParticipationEventId, ParticipationEventType},
     wallet::account::types::participation::ParticipationEventRegistrationOptions,
 };
+#[cfg(feature = "events")]
+use iota_sdk::wallet::events::types::NotificationPreferences;
 use iota_sdk::{
-    client::{
-        api::{PreparedTransactionDataDto, SignedTransactionDataDto},
-        secret::GenerateAddressOptions,
-    },
+    client::api::{PreparedTransactionDataDto, SignedTransactionDataDto},
     types::block::{
+        address::Bech32Address,
         dto::U256Dto,
         output::{
             dto::{AliasIdDto, NftIdDto, OutputDto, TokenIdDto},
@@ -22,11 +22,12 @@ use iota_sdk::{
     },
     wallet::{
         account::{
-            AliasOutputOptionsDto, FilterOptions, IncreaseNativeTokenSupplyOptionsDto, NativeTokenOptionsDto,
-            NftOptionsDto, OutputOptionsDto, OutputsToClaim, SyncOptions, TransactionOptionsDto,
+            types::AssetId, AddressGenerationOptions, AliasOutputOptionsDto, FilterOptions,
+            IncreaseNativeTokenSupplyOptionsDto, NativeTokenOptionsDto, NftOptionsDto, OutputOptionsDto, OutputsToClaim,
+            SendAllOptions, SpendingPolicy, SyncOptions, TransactionFilterOptions, TransactionOptionsDto, UtxoSnapshot,
         },
         message_interface::dtos::AddressWithAmountDto,
-        AddressAndNftId, AddressNativeTokens,
+        SendNativeTokensParams, SendNftParams,
     },
 };
 use serde::{Deserialize, Serialize};
@@ -91,7 +92,7 @@ pub enum AccountMethod {
     /// Expected response: [`GeneratedAddress`](crate::Response::GeneratedAddress)
     GenerateAddresses {
         amount: u32,
-        options: Option<GenerateAddressOptions>,
+        options: Option<AddressGenerationOptions>,
     },
     /// Get the [`OutputData`](iota_sdk::wallet::account::types::OutputData) of an output stored in the account
     /// Expected response: [`OutputData`](crate::Response::OutputData)
@@ -101,6 +102,14 @@ pub enum AccountMethod {
     /// Expected response: [`Output`](crate::Response::Output)
     #[serde(rename_all = "camelCase")]
     GetFoundryOutput { token_id: TokenIdDto },
+    /// Get the IRC30 metadata of a native token, cached in the account
+    /// Expected response: [`Irc30Metadata`](crate::Response::Irc30Metadata)
+    #[serde(rename_all = "camelCase")]
+    GetNativeTokenMetadata { token_id: TokenIdDto },
+    /// Get the IRC27 metadata of an NFT
+    /// Expected response: [`Irc27Metadata`](crate::Response::Irc27Metadata)
+    #[serde(rename_all = "camelCase")]
+    GetNftMetadata { nft_id: NftIdDto },
     /// Get outputs with additional unlock conditions
     /// Expected response: [`OutputIds`](crate::Response::OutputIds)
     #[serde(rename_all = "camelCase")]
@@ -114,9 +123,17 @@ pub enum AccountMethod {
     /// Expected response: [`IncomingTransactionData`](crate::Response::IncomingTransactionData)
     #[serde(rename_all = "camelCase")]
     GetIncomingTransactionData { transaction_id: TransactionId },
+    /// Get the number of milestones that confirmed a transaction stored in the account, counting the one that included
+    /// it.
+    /// Expected response: [`TransactionConfirmations`](crate::Response::TransactionConfirmations)
+    #[serde(rename_all = "camelCase")]
+    GetTransactionConfirmations { transaction_id: TransactionId },
     /// Expected response: [`Addresses`](crate::Response::Addresses)
     /// List addresses.
     Addresses,
+    /// List the addresses of the alias and NFT outputs owned by the account.
+    /// Expected response: [`ChainAddresses`](crate::Response::ChainAddresses)
+    ChainAddresses,
     /// Returns only addresses of the account with unspent outputs
     /// Expected response:
     /// [`AddressesWithUnspentOutputs`](crate::Response::AddressesWithUnspentOutputs)
@@ -125,6 +142,14 @@ pub enum AccountMethod {
     /// Expected response: [`OutputsData`](crate::Response::OutputsData)
     #[serde(rename_all = "camelCase")]
     Outputs { filter_options: Option<FilterOptions> },
+    /// Returns a page of the outputs of the account, ordered from the newest to the oldest
+    /// Expected response: [`OutputsData`](crate::Response::OutputsData)
+    #[serde(rename_all = "camelCase")]
+    OutputsPage {
+        filter_options: Option<FilterOptions>,
+        offset: usize,
+        limit: usize,
+    },
     /// Returns all unspent outputs of the account
     /// Expected response: [`OutputsData`](crate::Response::OutputsData)
     #[serde(rename_all = "camelCase")]
@@ -136,6 +161,14 @@ pub enum AccountMethod {
     /// Returns all transaction of the account
     /// Expected response: [`Transactions`](crate::Response::Transactions)
     Transactions,
+    /// Returns a page of the transactions of the account, ordered from the newest to the oldest
+    /// Expected response: [`Transactions`](crate::Response::Transactions)
+    #[serde(rename_all = "camelCase")]
+    TransactionsPage {
+        filter_options: Option<TransactionFilterOptions>,
+        offset: usize,
+        limit: usize,
+    },
     /// Returns all pending transactions of the account
     /// Expected response: [`Transactions`](crate::Response::Transactions)
     PendingTransactions,
@@ -182,6 +215,12 @@ pub enum AccountMethod {
     /// Get account balance information.
     /// Expected response: [`Balance`](crate::Response::Balance)
     GetBalance,
+    /// Get the health of the account, updated by every sync.
+    /// Expected response: [`AccountHealth`](crate::Response::AccountHealth)
+    GetHealth,
+    /// Get the inclusion latency statistics of the transactions of the account.
+    /// Expected response: [`InclusionStats`](crate::Response::InclusionStats)
+    GetInclusionStats,
     /// Prepare an output.
     /// Expected response: [`Output`](crate::Response::Output)
     #[serde(rename_all = "camelCase")]
@@ -195,6 +234,12 @@ pub enum AccountMethod {
         outputs: Vec<OutputDto>,
         options: Option<TransactionOptionsDto>,
     },
+    /// Prepare a transaction that pays a payment URI, e.g. scanned from a QR code.
+    /// Expected response: [`PreparedTransaction`](crate::Response::PreparedTransaction)
+    PreparePaymentUri {
+        uri: String,
+        options: Option<TransactionOptionsDto>,
+    },
     /// Prepare send amount.
     /// Expected response: [`PreparedTransaction`](crate::Response::PreparedTransaction)
     #[serde(rename_all = "camelCase")]
@@ -202,6 +247,19 @@ pub enum AccountMethod {
         addresses_with_amount: Vec<AddressWithAmountDto>,
         options: Option<TransactionOptionsDto>,
     },
+    /// Prepare send amount without locking the inputs, for a review before sending.
+    /// Expected response: [`TransactionDryRun`](crate::Response::TransactionDryRun)
+    #[serde(rename_all = "camelCase")]
+    DryRunSendAmount {
+        addresses_with_amount: Vec<AddressWithAmountDto>,
+        options: Option<TransactionOptionsDto>,
+    },
+    /// Summarize a prepared transaction.
+    /// Expected response: [`TransactionSummary`](crate::Response::TransactionSummary)
+    #[serde(rename_all = "camelCase")]
+    SummarizeTransaction {
+        prepared_transaction_data: PreparedTransactionDataDto,
+    },
     /// Retries (promotes or reattaches) a transaction sent from the account for a provided transaction id until it's
     /// included (referenced by a milestone). Returns the included block id.
     /// Expected response: [`BlockId`](crate::Response::BlockId)
@@ -221,6 +279,25 @@ pub enum AccountMethod {
         /// Sync options
         options: Option<SyncOptions>,
     },
+    /// Cross-check the stored outputs and transactions with the node and repair the local state.
+    /// Expected response: [`IntegrityReport`](crate::Response::IntegrityReport)
+    VerifyIntegrity,
+    /// Remove spent outputs and transactions that are older than the provided number of seconds.
+    /// Expected response: [`PrunedRecords`](crate::Response::PrunedRecords)
+    #[serde(rename_all = "camelCase")]
+    Prune { keep_spent_outputs_for_secs: u64 },
+    /// Export the unspent outputs of the account as snapshot for another wallet instance.
+    /// Expected response: [`UtxoSnapshot`](crate::Response::UtxoSnapshot)
+    UtxoSnapshot,
+    /// Import the unspent outputs of a trusted snapshot, so the next sync doesn't need to request them.
+    /// Expected response: [`ImportedOutputs`](crate::Response::ImportedOutputs)
+    ImportUtxoSnapshot { snapshot: UtxoSnapshot },
+    /// Get the age of the unspent outputs per address, based on the timestamps of the milestones that booked them.
+    /// Expected response: [`OutputAges`](crate::Response::OutputAges)
+    GetOutputAges,
+    /// Create an attestation of the age of the unspent outputs, signed over a hex encoded challenge of the verifier.
+    /// Expected response: [`AgeAttestation`](crate::Response::AgeAttestation)
+    CreateAgeAttestation { challenge: String },
     /// Send amount.
     /// Expected response: [`SentTransaction`](crate::Response::SentTransaction)
     #[serde(rename_all = "camelCase")]
@@ -228,20 +305,85 @@ pub enum AccountMethod {
         addresses_with_amount: Vec<AddressWithAmountDto>,
         options: Option<TransactionOptionsDto>,
     },
+    /// Send amount in as many transactions as needed.
+    /// Expected response: [`Transactions`](crate::Response::Transactions)
+    #[serde(rename_all = "camelCase")]
+    SendAmountBatched {
+        addresses_with_amount: Vec<AddressWithAmountDto>,
+        options: Option<TransactionOptionsDto>,
+    },
     /// Send native tokens.
     /// Expected response: [`SentTransaction`](crate::Response::SentTransaction)
     #[serde(rename_all = "camelCase")]
     SendNativeTokens {
-        addresses_and_native_tokens: Vec<AddressNativeTokens>,
+        addresses_and_native_tokens: Vec<SendNativeTokensParams>,
         options: Option<TransactionOptionsDto>,
     },
     /// Send nft.
     /// Expected response: [`SentTransaction`](crate::Response::SentTransaction)
     #[serde(rename_all = "camelCase")]
     SendNft {
-        addresses_and_nft_ids: Vec<AddressAndNftId>,
+        addresses_and_nft_ids: Vec<SendNftParams>,
+        options: Option<TransactionOptionsDto>,
+    },
+    /// Append an update with the state to the state log in the mutable metadata of an NFT.
+    /// Expected response: [`SentTransaction`](crate::Response::SentTransaction)
+    #[serde(rename_all = "camelCase")]
+    AppendNftState {
+        nft_id: NftIdDto,
+        state: serde_json::Value,
         options: Option<TransactionOptionsDto>,
     },
+    /// Get the state updates of the state log in the mutable metadata of an NFT.
+    /// Expected response: [`StateUpdates`](crate::Response::StateUpdates)
+    #[serde(rename_all = "camelCase")]
+    GetNftStateHistory { nft_id: NftIdDto },
+    /// Get the largest amount that can be sent to an address in a single transaction.
+    /// Expected response: [`MaxSendableAmount`](crate::Response::MaxSendableAmount)
+    MaxSendableAmount { address: String },
+    /// Send all basic outputs (optionally NFTs) to an address.
+    /// Expected response: [`Transactions`](crate::Response::Transactions)
+    #[serde(rename_all = "camelCase")]
+    SendAll {
+        address: String,
+        options: Option<SendAllOptions>,
+    },
+    /// Freeze an address, its outputs will be excluded from input selection until it gets unfrozen.
+    /// Expected response: [`Ok`](crate::Response::Ok)
+    FreezeAddress { address: Bech32Address },
+    /// Unfreeze an address, so its outputs can be used as inputs again.
+    /// Expected response: [`Ok`](crate::Response::Ok)
+    UnfreezeAddress { address: Bech32Address },
+    /// Enable or disable the quarantine of received NFTs and native tokens that weren't accepted before.
+    /// Expected response: [`Ok`](crate::Response::Ok)
+    SetAssetQuarantine { enabled: bool },
+    /// Get the quarantined assets.
+    /// Expected response: [`QuarantinedAssets`](crate::Response::QuarantinedAssets)
+    QuarantinedAssets,
+    /// Accept an asset, so it's released from the quarantine.
+    /// Expected response: [`Ok`](crate::Response::Ok)
+    AcceptAsset { asset: AssetId },
+    /// Set the limits for the transactions of the account, checked before a transaction is signed.
+    /// The policy isn't stored and needs to be set again after a restart.
+    /// Expected response: [`Ok`](crate::Response::Ok)
+    SetSpendingPolicy { policy: SpendingPolicy },
+    /// Get the limits for the transactions of the account.
+    /// Expected response: [`SpendingPolicy`](crate::Response::SpendingPolicy)
+    GetSpendingPolicy,
+    /// Reserve unspent outputs, so they aren't selected as inputs until they are released or the timeout elapsed.
+    /// Expected response: [`Ok`](crate::Response::Ok)
+    #[serde(rename_all = "camelCase")]
+    ReserveOutputs {
+        output_ids: Vec<OutputId>,
+        timeout_secs: u64,
+    },
+    /// Release reserved outputs, so they can be selected as inputs again.
+    /// Expected response: [`Ok`](crate::Response::Ok)
+    #[serde(rename_all = "camelCase")]
+    ReleaseOutputs { output_ids: Vec<OutputId> },
+    /// Get the ids of the reserved outputs.
+    /// Expected response: [`OutputIds`](crate::Response::OutputIds)
+    ReservedOutputs,
     /// Set the alias of the account.
     /// Expected response: [`Ok`](crate::Response::Ok)
     SetAlias { alias: String },
@@ -249,6 +391,17 @@ pub enum AccountMethod {
     /// If storage is enabled, will persist during restarts.
     /// Expected response: [`Ok`](crate::Response::Ok)
     SetDefaultSyncOptions { options: SyncOptions },
+    /// Set which wallet events are emitted for the account.
+    /// If storage is enabled, will persist during restarts.
+    /// Expected response: [`Ok`](crate::Response::Ok)
+    #[cfg(feature = "events")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "events")))]
+    SetNotificationPreferences { preferences: NotificationPreferences },
+    /// Get which wallet events are emitted for the account.
+    /// Expected response: [`NotificationPreferences`](crate::Response::NotificationPreferences)
+    #[cfg(feature = "events")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "events")))]
+    GetNotificationPreferences,
     /// Send outputs in a transaction.
     /// Expected response: [`SentTransaction`](crate::Response::SentTransaction)
     SendOutputs {
@@ -271,6 +424,10 @@ pub enum AccountMethod {
     /// Expected response: [`SentTransaction`](crate::Response::SentTransaction)
     #[serde(rename_all = "camelCase")]
     ClaimOutputs { output_ids_to_claim: Vec<OutputId> },
+    /// Accept an nft offer.
+    /// Expected response: [`SentTransaction`](crate::Response::SentTransaction)
+    #[serde(rename_all = "camelCase")]
+    AcceptNftOffer { output_id: OutputId },
     /// Vote for a participation event.
     /// Expected response: [`SentTransaction`](crate::Response::SentTransaction)
     #[cfg(feature = "participation")]
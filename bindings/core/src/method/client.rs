@@ -7,8 +7,8 @@ use iota_sdk::client::mqtt::Topic;
 use iota_sdk::{
     client::{
         api::{
-            ClientBlockBuilderOptions as BuildBlockOptions, GetAddressesBuilderOptions as GenerateAddressesOptions,
-            PreparedTransactionDataDto,
+            ClientBlockBuilderOptions as BuildBlockOptions, ConeWalkOptions,
+            GetAddressesBuilderOptions as GenerateAddressesOptions, PreparedTransactionDataDto,
         },
         node_api::indexer::query_parameters::QueryParameter,
         node_manager::node::NodeAuth,
@@ -16,7 +16,7 @@ use iota_sdk::{
     },
     types::block::{
         output::{
-            dto::{AliasIdDto, NativeTokenDto, NftIdDto, TokenSchemeDto},
+            dto::{AliasIdDto, NativeTokenDto, NftIdDto, OutputDto, TokenSchemeDto},
             feature::dto::FeatureDto,
             unlock_condition::dto::UnlockConditionDto,
             AliasId, FoundryId, NftId, OutputId,
@@ -132,7 +132,26 @@ pub enum ClientMethod {
     GetLocalPow,
     /// Get fallback to local proof of work timeout
     GetFallbackToLocalPow,
-    /// Returns the unhealthy nodes.
+    /// Calculates the minimum required storage deposit for an output.
+    /// Expected response:
+    /// [`MinimumRequiredStorageDeposit`](crate::Response::MinimumRequiredStorageDeposit)
+    MinimumRequiredStorageDeposit { output: OutputDto },
+    /// Estimates if the local PoW can sustain the requested block rate.
+    /// Expected response: [`PowCapacityEstimate`](crate::Response::PowCapacityEstimate)
+    #[serde(rename_all = "camelCase")]
+    EstimatePowCapacity {
+        /// The requested block rate in blocks per second
+        blocks_per_second: f64,
+        /// The expected length of the blocks in bytes
+        block_length: Option<usize>,
+    },
+    /// Returns the recorded requests to the nodes and their responses, if the request journal is enabled.
+    /// Expected response: [`RequestJournal`](crate::Response::RequestJournal)
+    GetRequestJournal,
+    /// Removes all entries from the request journal.
+    /// Expected response: [`Ok`](crate::Response::Ok)
+    ClearRequestJournal,
+    /// Returns the nodes that are left out of the healthy node pool, with the error of the last node sync.
     #[cfg(not(target_family = "wasm"))]
     UnhealthyNodes,
     /// Prepare a transaction for signing
@@ -177,6 +196,37 @@ pub enum ClientMethod {
     GetInfo,
     /// Get peers
     GetPeers,
+    /// Get a peer of the node
+    #[cfg(feature = "operator")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "operator")))]
+    #[serde(rename_all = "camelCase")]
+    GetPeer {
+        /// Peer ID
+        peer_id: String,
+    },
+    /// Add a peer to the node
+    #[cfg(feature = "operator")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "operator")))]
+    #[serde(rename_all = "camelCase")]
+    AddPeer {
+        /// Multiaddress of the peer
+        multi_address: String,
+        /// Alias of the peer
+        alias: Option<String>,
+    },
+    /// Remove a peer from the node
+    /// Expected response: [`Ok`](crate::Response::Ok)
+    #[cfg(feature = "operator")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "operator")))]
+    #[serde(rename_all = "camelCase")]
+    RemovePeer {
+        /// Peer ID
+        peer_id: String,
+    },
+    /// Get the features and the API route groups of the node
+    #[cfg(feature = "operator")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "operator")))]
+    GetNodeCapabilities,
     /// Get tips
     GetTips,
     /// Post block (JSON)
@@ -208,6 +258,26 @@ pub enum ClientMethod {
         /// Block ID
         block_id: BlockId,
     },
+    /// Get the ids of the blocks that reference the block
+    #[serde(rename_all = "camelCase")]
+    GetBlockChildren {
+        /// Block ID
+        block_id: BlockId,
+    },
+    /// Get the ids of the blocks that are referenced by the block
+    #[serde(rename_all = "camelCase")]
+    GetBlockParents {
+        /// Block ID
+        block_id: BlockId,
+    },
+    /// Get the blocks in the past or future cone of a block, breadth first
+    #[serde(rename_all = "camelCase")]
+    WalkCone {
+        /// Block ID of the start block
+        block_id: BlockId,
+        /// Direction and limits of the walk
+        options: Option<ConeWalkOptions>,
+    },
     /// Get output
     #[serde(rename_all = "camelCase")]
     GetOutput {
@@ -389,6 +459,20 @@ pub enum ClientMethod {
         /// Addresses
         addresses: Vec<String>,
     },
+    /// Get the IRC30 metadata of the native token of a foundry.
+    /// Expected response: [`Irc30Metadata`](crate::Response::Irc30Metadata)
+    #[serde(rename_all = "camelCase")]
+    FoundryMetadata {
+        /// Foundry ID
+        foundry_id: FoundryId,
+    },
+    /// Get the IRC27 metadata of an NFT.
+    /// Expected response: [`Irc27Metadata`](crate::Response::Irc27Metadata)
+    #[serde(rename_all = "camelCase")]
+    NftMetadata {
+        /// NFT ID
+        nft_id: NftId,
+    },
     /// Reattaches blocks for provided block id. Blocks can be reattached only if they are valid and haven't been
     /// confirmed for a while.
     #[serde(rename_all = "camelCase")]
@@ -458,4 +542,8 @@ pub enum ClientMethod {
         /// The address for request funds
         address: String,
     },
+    /// Call multiple client methods in one round trip. The methods are called in order and a failing method doesn't
+    /// stop the batch. Batches can't be nested.
+    /// Expected response: [`Batch`](crate::Response::Batch) with the response of every method at its position
+    CallBatch { methods: Vec<ClientMethod> },
 }
@@ -30,6 +30,7 @@ pub enum SecretManagerMethod {
         /// Transaction Essence Hash
         transaction_essence_hash: Vec<u8>,
         /// Chain to sign the essence hash with
+        #[serde(with = "iota_sdk::client::secret::types::chain_serde")]
         chain: Chain,
     },
     /// Signs a message with an Ed25519 private key.
@@ -37,6 +38,7 @@ pub enum SecretManagerMethod {
         /// The message to sign, hex encoded String
         message: String,
         /// Chain to sign the essence hash with
+        #[serde(with = "iota_sdk::client::secret::types::chain_serde")]
         chain: Chain,
     },
     /// Sign a transaction
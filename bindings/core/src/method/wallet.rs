@@ -9,16 +9,17 @@ use derivative::Derivative;
 use iota_sdk::wallet::events::types::{WalletEvent, WalletEventType};
 use iota_sdk::{
     client::{node_manager::node::NodeAuth, secret::GenerateAddressOptions},
+    types::block::address::Bech32Address,
     wallet::{
-        account::{types::AccountIdentifier, SyncOptions},
-        ClientOptions,
+        account::{types::AccountIdentifier, SyncOptions, TransactionOptionsDto},
+        ClientOptions, FireflyImportOptions, FireflyProfile,
     },
     Url,
 };
 use serde::{Deserialize, Serialize};
 
 use crate::method::account::AccountMethod;
-#[cfg(feature = "stronghold")]
+#[cfg(any(feature = "storage", feature = "stronghold"))]
 use crate::OmittedDebug;
 
 /// The methods that can be sent to the actor.
@@ -34,11 +35,20 @@ pub enum WalletMethod {
         alias: Option<String>,
         /// The bech32 HRP.
         bech32_hrp: Option<String>,
+        /// The identifier of the account in an external system, the existing account is returned if one was already
+        /// created for it.
+        external_id: Option<String>,
+        /// The addresses of a watch-only account, which can't sign transactions or generate addresses.
+        watch_only_addresses: Option<Vec<Bech32Address>>,
     },
     /// Read account.
     /// Expected response: [`Account`](crate::Response::Account)
     #[serde(rename_all = "camelCase")]
     GetAccount { account_id: AccountIdentifier },
+    /// Read the account that was created for an identifier of an external system.
+    /// Expected response: [`Account`](crate::Response::Account)
+    #[serde(rename_all = "camelCase")]
+    GetAccountFor { external_id: String },
     /// Return the account indexes.
     /// Expected response: [`AccountIndexes`](crate::Response::AccountIndexes)
     GetAccountIndexes,
@@ -54,6 +64,10 @@ pub enum WalletMethod {
         /// The account method to call.
         method: AccountMethod,
     },
+    /// Call multiple wallet methods in one round trip. The methods are called in order and a failing method doesn't
+    /// stop the batch. Batches can't be nested.
+    /// Expected response: [`Batch`](crate::Response::Batch) with the response of every method at its position
+    CallBatch { methods: Vec<WalletMethod> },
     /// Backup storage. Password must be the current one, when Stronghold is used as SecretManager.
     /// Expected response: [`Ok`](crate::Response::Ok)
     #[cfg(feature = "stronghold")]
@@ -87,6 +101,34 @@ pub enum WalletMethod {
     #[cfg(feature = "stronghold")]
     #[cfg_attr(docsrs, doc(cfg(feature = "stronghold")))]
     IsStrongholdPasswordAvailable,
+    /// Exports an account encrypted with a password, so it can be imported into another wallet.
+    /// Expected response: [`ExportedAccount`](crate::Response::ExportedAccount)
+    #[cfg(feature = "storage")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "storage")))]
+    #[serde(rename_all = "camelCase")]
+    ExportAccount {
+        account_id: AccountIdentifier,
+        #[derivative(Debug(format_with = "OmittedDebug::omitted_fmt"))]
+        password: String,
+    },
+    /// Imports an account that was exported with [`ExportAccount`](Self::ExportAccount).
+    /// Expected response: [`Account`](crate::Response::Account)
+    #[cfg(feature = "storage")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "storage")))]
+    #[serde(rename_all = "camelCase")]
+    ImportAccount {
+        exported_account: String,
+        #[derivative(Debug(format_with = "OmittedDebug::omitted_fmt"))]
+        password: String,
+    },
+    /// Recreates the accounts of a profile exported by Firefly.
+    /// Expected response: [`Accounts`](crate::Response::Accounts)
+    ImportFireflyProfile {
+        /// The exported profile.
+        profile: FireflyProfile,
+        /// Options for the import.
+        options: Option<FireflyImportOptions>,
+    },
     /// Find accounts with unspent outputs
     /// Expected response: [`Accounts`](crate::Response::Accounts)
     #[serde(rename_all = "camelCase")]
@@ -129,9 +171,28 @@ pub enum WalletMethod {
         /// accounts will be restored.
         ignore_if_bech32_mismatch: Option<String>,
     },
+    /// Removes an account, if it has no unspent outputs or `force` is true.
+    /// Expected response: [`Ok`](crate::Response::Ok)
+    #[serde(rename_all = "camelCase")]
+    RemoveAccount {
+        account_id: AccountIdentifier,
+        #[serde(default)]
+        force: bool,
+    },
     /// Removes the latest account (account with the largest account index).
     /// Expected response: [`Ok`](crate::Response::Ok)
     RemoveLatestAccount,
+    /// Search the addresses, transactions, outputs, NFTs, native tokens and tags of all accounts for a partial value.
+    /// Expected response: [`SearchResults`](crate::Response::SearchResults)
+    Search { query: String },
+    /// Encrypts all records of the storage with a new password, or decrypts them if no password is provided.
+    /// Expected response: [`Ok`](crate::Response::Ok)
+    #[cfg(feature = "storage")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "storage")))]
+    SetStoragePassword {
+        #[derivative(Debug(format_with = "OmittedDebug::omitted_fmt"))]
+        password: Option<String>,
+    },
     /// Updates the client options for all accounts.
     /// Expected response: [`Ok`](crate::Response::Ok)
     #[serde(rename_all = "camelCase")]
@@ -154,6 +215,9 @@ pub enum WalletMethod {
     #[cfg(feature = "ledger_nano")]
     #[cfg_attr(docsrs, doc(cfg(feature = "ledger_nano")))]
     GetLedgerNanoStatus,
+    /// Returns the status of the storage, the stronghold, the nodes and the background syncing for health checks.
+    /// Expected response: [`WalletHealth`](crate::Response::WalletHealth)
+    GetHealth,
     /// Set the stronghold password.
     /// Expected response: [`Ok`](crate::Response::Ok)
     #[cfg(feature = "stronghold")]
@@ -188,6 +252,18 @@ pub enum WalletMethod {
     /// Stop background syncing.
     /// Expected response: [`Ok`](crate::Response::Ok)
     StopBackgroundSync,
+    /// Send an amount from one account to the first public address of another account.
+    /// Expected response: [`SentTransaction`](crate::Response::SentTransaction)
+    TransferBetweenAccounts {
+        /// The sending account.
+        from: AccountIdentifier,
+        /// The receiving account.
+        to: AccountIdentifier,
+        /// The amount to transfer.
+        amount: String,
+        /// Options for the transaction.
+        options: Option<TransactionOptionsDto>,
+    },
     /// Emits an event for testing if the event system is working
     /// Expected response: [`Ok`](crate::Response::Ok)
     #[cfg(feature = "events")]
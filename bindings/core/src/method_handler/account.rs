@@ -3,19 +3,21 @@
 
 #[cfg(feature = "participation")]
 use std::str::FromStr;
+use std::time::Duration;
 
 use iota_sdk::{
     client::api::{
         PreparedTransactionData, PreparedTransactionDataDto, SignedTransactionData, SignedTransactionDataDto,
     },
     types::block::{
-        output::{dto::OutputDto, AliasId, NftId, Output, Rent, TokenId},
+        output::{dto::OutputDto, AliasId, NftId, Output, TokenId},
         Error,
     },
     wallet::{
         account::{
             types::{AccountBalanceDto, TransactionDto},
-            Account, AliasOutputOptions, MintTokenTransactionDto, OutputDataDto, OutputOptions, TransactionOptions,
+            Account, AliasOutputOptions, MintTokenTransactionDto, OutputDataDto, OutputOptions, PrunedRecordsDto,
+            TransactionDryRunDto, TransactionOptions,
         },
         message_interface::AddressWithUnspentOutputsDto,
         AddressWithAmount, IncreaseNativeTokenSupplyOptions, NativeTokenOptions, NftOptions,
@@ -112,10 +114,21 @@ pub(crate) async fn call_account_method_internal(account: &Account, method: Acco
             let output = account.get_foundry_output(token_id).await?;
             Response::Output(OutputDto::from(&output))
         }
+        AccountMethod::GetNativeTokenMetadata { token_id } => {
+            let token_id = TokenId::try_from(&token_id)?;
+            Response::Irc30Metadata(account.get_native_token_metadata(token_id).await?)
+        }
+        AccountMethod::GetNftMetadata { nft_id } => {
+            let nft_id = NftId::try_from(&nft_id)?;
+            Response::Irc27Metadata(account.get_nft_metadata(nft_id).await?)
+        }
         AccountMethod::GetTransaction { transaction_id } => {
             let transaction = account.get_transaction(&transaction_id).await;
             Response::Transaction(transaction.as_ref().map(TransactionDto::from).map(Box::new))
         }
+        AccountMethod::GetTransactionConfirmations { transaction_id } => {
+            Response::TransactionConfirmations(account.transaction_confirmations(&transaction_id).await?)
+        }
         AccountMethod::GetIncomingTransactionData { transaction_id } => {
             let transaction = account.get_incoming_transaction_data(&transaction_id).await;
 
@@ -133,6 +146,7 @@ pub(crate) async fn call_account_method_internal(account: &Account, method: Acco
             let addresses = account.addresses().await?;
             Response::Addresses(addresses)
         }
+        AccountMethod::ChainAddresses => Response::ChainAddresses(account.chain_addresses().await?),
         AccountMethod::AddressesWithUnspentOutputs => {
             let addresses = account.addresses_with_unspent_outputs().await?;
             Response::AddressesWithUnspentOutputs(addresses.iter().map(AddressWithUnspentOutputsDto::from).collect())
@@ -141,6 +155,14 @@ pub(crate) async fn call_account_method_internal(account: &Account, method: Acco
             let outputs = account.outputs(filter_options).await?;
             Response::OutputsData(outputs.iter().map(OutputDataDto::from).collect())
         }
+        AccountMethod::OutputsPage {
+            filter_options,
+            offset,
+            limit,
+        } => {
+            let outputs = account.outputs_page(filter_options, offset, limit).await?;
+            Response::OutputsData(outputs.iter().map(OutputDataDto::from).collect())
+        }
         AccountMethod::UnspentOutputs { filter_options } => {
             let outputs = account.unspent_outputs(filter_options).await?;
             Response::OutputsData(outputs.iter().map(OutputDataDto::from).collect())
@@ -158,6 +180,14 @@ pub(crate) async fn call_account_method_internal(account: &Account, method: Acco
             let transactions = account.transactions().await?;
             Response::Transactions(transactions.iter().map(TransactionDto::from).collect())
         }
+        AccountMethod::TransactionsPage {
+            filter_options,
+            offset,
+            limit,
+        } => {
+            let transactions = account.transactions_page(filter_options, offset, limit).await?;
+            Response::Transactions(transactions.iter().map(TransactionDto::from).collect())
+        }
         AccountMethod::PendingTransactions => {
             let transactions = account.pending_transactions().await?;
             Response::Transactions(transactions.iter().map(TransactionDto::from).collect())
@@ -210,9 +240,7 @@ pub(crate) async fn call_account_method_internal(account: &Account, method: Acco
         }
         AccountMethod::MinimumRequiredStorageDeposit { output } => {
             let output = Output::try_from_dto(&output, account.client().get_token_supply().await?)?;
-            let rent_structure = account.client().get_rent_structure().await?;
-
-            let minimum_storage_deposit = output.rent_cost(&rent_structure);
+            let minimum_storage_deposit = account.client().minimum_storage_deposit(&output).await?;
 
             Response::MinimumRequiredStorageDeposit(minimum_storage_deposit.to_string())
         }
@@ -229,6 +257,8 @@ pub(crate) async fn call_account_method_internal(account: &Account, method: Acco
             Response::SentTransaction(TransactionDto::from(&transaction))
         }
         AccountMethod::GetBalance => Response::Balance(AccountBalanceDto::from(&account.balance().await?)),
+        AccountMethod::GetHealth => Response::AccountHealth(account.health().await),
+        AccountMethod::GetInclusionStats => Response::InclusionStats(account.inclusion_stats().await),
         AccountMethod::PrepareOutput {
             options,
             transaction_options,
@@ -244,6 +274,15 @@ pub(crate) async fn call_account_method_internal(account: &Account, method: Acco
                 .await?;
             Response::Output(OutputDto::from(&output))
         }
+        AccountMethod::PreparePaymentUri { uri, options } => {
+            let data = account
+                .prepare_payment_uri(
+                    &uri,
+                    options.as_ref().map(TransactionOptions::try_from_dto).transpose()?,
+                )
+                .await?;
+            Response::PreparedTransaction(PreparedTransactionDataDto::from(&data))
+        }
         AccountMethod::PrepareSendAmount {
             addresses_with_amount,
             options,
@@ -259,6 +298,32 @@ pub(crate) async fn call_account_method_internal(account: &Account, method: Acco
                 .await?;
             Response::PreparedTransaction(PreparedTransactionDataDto::from(&data))
         }
+        AccountMethod::DryRunSendAmount {
+            addresses_with_amount,
+            options,
+        } => {
+            let dry_run = account
+                .dry_run_send_amount(
+                    addresses_with_amount
+                        .iter()
+                        .map(AddressWithAmount::try_from)
+                        .collect::<iota_sdk::wallet::Result<Vec<AddressWithAmount>>>()?,
+                    options.as_ref().map(TransactionOptions::try_from_dto).transpose()?,
+                )
+                .await?;
+            Response::TransactionDryRun(TransactionDryRunDto::from(&dry_run))
+        }
+        AccountMethod::SummarizeTransaction {
+            prepared_transaction_data,
+        } => {
+            let summary = account
+                .summarize_transaction(&PreparedTransactionData::try_from_dto(
+                    &prepared_transaction_data,
+                    &account.client().get_protocol_parameters().await?,
+                )?)
+                .await?;
+            Response::TransactionSummary(summary)
+        }
         AccountMethod::PrepareTransaction { outputs, options } => {
             let token_supply = account.client().get_token_supply().await?;
             let data = account
@@ -283,6 +348,22 @@ pub(crate) async fn call_account_method_internal(account: &Account, method: Acco
             Response::BlockId(block_id)
         }
         AccountMethod::Sync { options } => Response::Balance(AccountBalanceDto::from(&account.sync(options).await?)),
+        AccountMethod::VerifyIntegrity => Response::IntegrityReport(account.verify_integrity().await?),
+        AccountMethod::Prune {
+            keep_spent_outputs_for_secs,
+        } => {
+            let pruned_records = account.prune(Duration::from_secs(keep_spent_outputs_for_secs)).await?;
+            Response::PrunedRecords(PrunedRecordsDto::from(&pruned_records))
+        }
+        AccountMethod::UtxoSnapshot => Response::UtxoSnapshot(account.utxo_snapshot().await?),
+        AccountMethod::ImportUtxoSnapshot { snapshot } => {
+            Response::ImportedOutputs(account.import_utxo_snapshot(&snapshot).await?)
+        }
+        AccountMethod::GetOutputAges => Response::OutputAges(account.output_ages().await?),
+        AccountMethod::CreateAgeAttestation { challenge } => {
+            let challenge = prefix_hex::decode::<Vec<u8>>(&challenge)?;
+            Response::AgeAttestation(account.age_attestation(&challenge).await?)
+        }
         AccountMethod::SendAmount {
             addresses_with_amount,
             options,
@@ -298,6 +379,21 @@ pub(crate) async fn call_account_method_internal(account: &Account, method: Acco
                 .await?;
             Response::SentTransaction(TransactionDto::from(&transaction))
         }
+        AccountMethod::SendAmountBatched {
+            addresses_with_amount,
+            options,
+        } => {
+            let transactions = account
+                .send_amount_batched(
+                    addresses_with_amount
+                        .iter()
+                        .map(AddressWithAmount::try_from)
+                        .collect::<iota_sdk::wallet::Result<Vec<AddressWithAmount>>>()?,
+                    options.as_ref().map(TransactionOptions::try_from_dto).transpose()?,
+                )
+                .await?;
+            Response::Transactions(transactions.iter().map(TransactionDto::from).collect())
+        }
         AccountMethod::SendNativeTokens {
             addresses_and_native_tokens,
             options,
@@ -322,6 +418,64 @@ pub(crate) async fn call_account_method_internal(account: &Account, method: Acco
                 .await?;
             Response::SentTransaction(TransactionDto::from(&transaction))
         }
+        AccountMethod::AppendNftState { nft_id, state, options } => {
+            let transaction = account
+                .append_nft_state(
+                    NftId::try_from(&nft_id)?,
+                    state,
+                    options.as_ref().map(TransactionOptions::try_from_dto).transpose()?,
+                )
+                .await?;
+            Response::SentTransaction(TransactionDto::from(&transaction))
+        }
+        AccountMethod::GetNftStateHistory { nft_id } => {
+            let nft_id = NftId::try_from(&nft_id)?;
+            Response::StateUpdates(account.nft_state_history(nft_id).await?)
+        }
+        AccountMethod::MaxSendableAmount { address } => {
+            let amount = account.max_sendable_amount(&address).await?;
+            Response::MaxSendableAmount(amount.to_string())
+        }
+        AccountMethod::SendAll { address, options } => {
+            let transactions = account.send_all(&address, options).await?;
+            Response::Transactions(transactions.iter().map(TransactionDto::from).collect())
+        }
+        AccountMethod::FreezeAddress { address } => {
+            account.freeze_address(&address).await?;
+            Response::Ok
+        }
+        AccountMethod::UnfreezeAddress { address } => {
+            account.unfreeze_address(&address).await?;
+            Response::Ok
+        }
+        AccountMethod::SetAssetQuarantine { enabled } => {
+            account.set_asset_quarantine(enabled).await?;
+            Response::Ok
+        }
+        AccountMethod::QuarantinedAssets => Response::QuarantinedAssets(account.quarantined_assets().await),
+        AccountMethod::AcceptAsset { asset } => {
+            account.accept_asset(asset).await?;
+            Response::Ok
+        }
+        AccountMethod::SetSpendingPolicy { policy } => {
+            account.set_spending_policy(policy).await;
+            Response::Ok
+        }
+        AccountMethod::GetSpendingPolicy => Response::SpendingPolicy(account.spending_policy().await),
+        AccountMethod::ReserveOutputs {
+            output_ids,
+            timeout_secs,
+        } => {
+            account
+                .reserve_outputs(&output_ids, Duration::from_secs(timeout_secs))
+                .await?;
+            Response::Ok
+        }
+        AccountMethod::ReleaseOutputs { output_ids } => {
+            account.release_outputs(&output_ids).await;
+            Response::Ok
+        }
+        AccountMethod::ReservedOutputs => Response::OutputIds(account.reserved_outputs().await),
         AccountMethod::SetAlias { alias } => {
             account.set_alias(&alias).await?;
             Response::Ok
@@ -330,6 +484,15 @@ pub(crate) async fn call_account_method_internal(account: &Account, method: Acco
             account.set_default_sync_options(options).await?;
             Response::Ok
         }
+        #[cfg(feature = "events")]
+        AccountMethod::SetNotificationPreferences { preferences } => {
+            account.set_notification_preferences(preferences).await?;
+            Response::Ok
+        }
+        #[cfg(feature = "events")]
+        AccountMethod::GetNotificationPreferences => {
+            Response::NotificationPreferences(account.notification_preferences().await)
+        }
         AccountMethod::SendOutputs { outputs, options } => {
             let token_supply = account.client().get_token_supply().await?;
             let transaction = account
@@ -368,6 +531,10 @@ pub(crate) async fn call_account_method_internal(account: &Account, method: Acco
             let transaction = account.claim_outputs(output_ids_to_claim.to_vec()).await?;
             Response::SentTransaction(TransactionDto::from(&transaction))
         }
+        AccountMethod::AcceptNftOffer { output_id } => {
+            let transaction = account.accept_nft_offer(output_id).await?;
+            Response::SentTransaction(TransactionDto::from(&transaction))
+        }
         #[cfg(feature = "participation")]
         AccountMethod::Vote { event_id, answers } => {
             let transaction = account.vote(event_id, answers).await?;
@@ -53,6 +53,21 @@ impl CallMethod for SecretManager {
 
 /// Call a client method.
 pub async fn call_client_method(client: &Client, method: ClientMethod) -> Response {
+    match method {
+        ClientMethod::CallBatch { methods } => {
+            log::debug!("Client batch of {} methods", methods.len());
+            let mut responses = Vec::with_capacity(methods.len());
+            for method in methods {
+                responses.push(call_single_client_method(client, method).await);
+            }
+            Response::Batch(responses)
+        }
+        method => call_single_client_method(client, method).await,
+    }
+}
+
+// Calls a client method that isn't a batch
+async fn call_single_client_method(client: &Client, method: ClientMethod) -> Response {
     log::debug!("Client method: {method:?}");
     let result = convert_async_panics(|| async { call_client_method_internal(client, method).await }).await;
 
@@ -64,6 +79,21 @@ pub async fn call_client_method(client: &Client, method: ClientMethod) -> Respon
 
 /// Call a wallet method.
 pub async fn call_wallet_method(wallet: &Wallet, method: WalletMethod) -> Response {
+    match method {
+        WalletMethod::CallBatch { methods } => {
+            log::debug!("Wallet batch of {} methods", methods.len());
+            let mut responses = Vec::with_capacity(methods.len());
+            for method in methods {
+                responses.push(call_single_wallet_method(wallet, method).await);
+            }
+            Response::Batch(responses)
+        }
+        method => call_single_wallet_method(wallet, method).await,
+    }
+}
+
+// Calls a wallet method that isn't a batch
+async fn call_single_wallet_method(wallet: &Wallet, method: WalletMethod) -> Response {
     log::debug!("Wallet method: {method:?}");
     let result = convert_async_panics(|| async { call_wallet_method_internal(wallet, method).await }).await;
 
@@ -1,13 +1,14 @@
 // Copyright 2023 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+use futures::TryStreamExt;
 use iota_sdk::{
     client::{
         api::{PreparedTransactionData, PreparedTransactionDataDto},
         request_funds_from_faucet, Client,
     },
     types::{
-        api::core::response::OutputWithMetadataResponse,
+        api::core::{dto::ReceiptDto, response::OutputWithMetadataResponse},
         block::{
             input::dto::UtxoInputDto,
             output::{
@@ -29,7 +30,7 @@ use {
     iota_sdk::types::block::payload::milestone::option::dto::ReceiptMilestoneOptionDto,
 };
 
-use crate::{method::ClientMethod, response::Response, Result};
+use crate::{method::ClientMethod, response::Response, Error, Result};
 
 /// Listen to MQTT events
 #[cfg(feature = "mqtt")]
@@ -217,6 +218,15 @@ pub(crate) async fn call_client_method_internal(client: &Client, method: ClientM
         ClientMethod::GetNetworkId => Response::NetworkId(client.get_network_id().await?),
         ClientMethod::GetBech32Hrp => Response::Bech32Hrp(client.get_bech32_hrp().await?),
         ClientMethod::GetMinPowScore => Response::MinPowScore(client.get_min_pow_score().await?),
+        ClientMethod::EstimatePowCapacity {
+            blocks_per_second,
+            block_length,
+        } => Response::PowCapacityEstimate(client.estimate_pow_capacity(blocks_per_second, block_length).await?),
+        ClientMethod::GetRequestJournal => Response::RequestJournal(client.request_journal()),
+        ClientMethod::ClearRequestJournal => {
+            client.clear_request_journal();
+            Response::Ok
+        }
         ClientMethod::GetTipsInterval => Response::TipsInterval(client.get_tips_interval()),
         ClientMethod::GetProtocolParameters => {
             let params = client.get_protocol_parameters().await?;
@@ -237,6 +247,11 @@ pub(crate) async fn call_client_method_internal(client: &Client, method: ClientM
         }
         ClientMethod::GetLocalPow => Response::Bool(client.get_local_pow()),
         ClientMethod::GetFallbackToLocalPow => Response::Bool(client.get_fallback_to_local_pow()),
+        ClientMethod::MinimumRequiredStorageDeposit { output } => {
+            let output = Output::try_from_dto(&output, client.get_token_supply().await?)?;
+
+            Response::MinimumRequiredStorageDeposit(client.minimum_storage_deposit(&output).await?.to_string())
+        }
         ClientMethod::PrepareTransaction {
             secret_manager,
             options,
@@ -293,13 +308,24 @@ pub(crate) async fn call_client_method_internal(client: &Client, method: ClientM
             Response::BlockIdWithBlock(block_id, BlockDto::from(&block))
         }
         #[cfg(not(target_family = "wasm"))]
-        ClientMethod::UnhealthyNodes => {
-            Response::UnhealthyNodes(client.unhealthy_nodes().into_iter().cloned().collect())
-        }
+        ClientMethod::UnhealthyNodes => Response::UnhealthyNodes(client.unhealthy_nodes()),
         ClientMethod::GetHealth { url } => Response::Bool(client.get_health(&url).await?),
         ClientMethod::GetNodeInfo { url, auth } => Response::NodeInfo(Client::get_node_info(&url, auth).await?),
         ClientMethod::GetInfo => Response::Info(client.get_info().await?),
         ClientMethod::GetPeers => Response::Peers(client.get_peers().await?),
+        #[cfg(feature = "operator")]
+        ClientMethod::GetPeer { peer_id } => Response::Peer(client.get_peer(&peer_id).await?),
+        #[cfg(feature = "operator")]
+        ClientMethod::AddPeer { multi_address, alias } => {
+            Response::Peer(client.add_peer(&multi_address, alias.as_deref()).await?)
+        }
+        #[cfg(feature = "operator")]
+        ClientMethod::RemovePeer { peer_id } => {
+            client.remove_peer(&peer_id).await?;
+            Response::Ok
+        }
+        #[cfg(feature = "operator")]
+        ClientMethod::GetNodeCapabilities => Response::NodeCapabilities(client.get_node_capabilities().await?),
         ClientMethod::GetTips => Response::Tips(client.get_tips().await?),
         ClientMethod::PostBlockRaw { block_bytes } => Response::BlockId(
             client
@@ -319,6 +345,14 @@ pub(crate) async fn call_client_method_internal(client: &Client, method: ClientM
             Response::BlockMetadata(client.get_block_metadata(&block_id).await?)
         }
         ClientMethod::GetBlockRaw { block_id } => Response::BlockRaw(client.get_block_raw(&block_id).await?),
+        ClientMethod::GetBlockChildren { block_id } => Response::BlockIds(client.get_block_children(&block_id).await?),
+        ClientMethod::GetBlockParents { block_id } => Response::BlockIds(client.get_block_parents(&block_id).await?),
+        ClientMethod::WalkCone { block_id, options } => Response::VisitedBlocks(
+            client
+                .walk_cone(block_id, options.unwrap_or_default())
+                .try_collect()
+                .await?,
+        ),
         ClientMethod::GetOutput { output_id } => Response::OutputWithMetadataResponse(
             client
                 .get_output(&output_id)
@@ -346,11 +380,18 @@ pub(crate) async fn call_client_method_internal(client: &Client, method: ClientM
         ClientMethod::GetUtxoChangesByIndex { index } => {
             Response::MilestoneUtxoChanges(client.get_utxo_changes_by_index(index).await?)
         }
-        ClientMethod::GetReceipts => Response::Receipts(client.get_receipts().await?),
-        ClientMethod::GetReceiptsMigratedAt { milestone_index } => {
-            Response::Receipts(client.get_receipts_migrated_at(milestone_index).await?)
+        ClientMethod::GetReceipts => {
+            Response::Receipts(client.get_receipts().await?.iter().map(ReceiptDto::from).collect())
         }
-        ClientMethod::GetTreasury => Response::Treasury(client.get_treasury().await?),
+        ClientMethod::GetReceiptsMigratedAt { milestone_index } => Response::Receipts(
+            client
+                .get_receipts_migrated_at(milestone_index)
+                .await?
+                .iter()
+                .map(ReceiptDto::from)
+                .collect(),
+        ),
+        ClientMethod::GetTreasury => Response::Treasury((&client.get_treasury().await?).into()),
         ClientMethod::GetIncludedBlock { transaction_id } => {
             Response::Block(BlockDto::from(&client.get_included_block(&transaction_id).await?))
         }
@@ -442,6 +483,10 @@ pub(crate) async fn call_client_method_internal(client: &Client, method: ClientM
                 .collect();
             Response::Outputs(outputs_response)
         }
+        ClientMethod::FoundryMetadata { foundry_id } => {
+            Response::Irc30Metadata(client.foundry_metadata(foundry_id).await?)
+        }
+        ClientMethod::NftMetadata { nft_id } => Response::Irc27Metadata(client.nft_metadata(nft_id).await?),
         ClientMethod::Reattach { block_id } => {
             let (block_id, block) = client.reattach(&block_id).await?;
             Response::Reattached((block_id, BlockDto::from(&block)))
@@ -475,6 +520,7 @@ pub(crate) async fn call_client_method_internal(client: &Client, method: ClientM
         ClientMethod::RequestFundsFromFaucet { url, address } => {
             Response::Faucet(request_funds_from_faucet(&url, &address).await?)
         }
+        ClientMethod::CallBatch { .. } => return Err(Error::NestedBatch),
     };
     Ok(response)
 }
@@ -1,20 +1,35 @@
 // Copyright 2023 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use std::time::Duration;
+use std::{str::FromStr, time::Duration};
 
-use iota_sdk::wallet::{message_interface::dtos::AccountDetailsDto, wallet::Wallet};
-#[cfg(feature = "stronghold")]
+#[cfg(feature = "storage")]
+use iota_sdk::wallet::storage::encryption::StorageEncryption;
+use iota_sdk::wallet::{
+    account::{types::TransactionDto, TransactionOptions},
+    message_interface::dtos::AccountDetailsDto,
+    wallet::Wallet,
+};
+#[cfg(any(feature = "storage", feature = "stronghold"))]
 use zeroize::Zeroize;
 
 use super::account::call_account_method_internal;
-use crate::{method::WalletMethod, response::Response, Result};
+use crate::{method::WalletMethod, response::Response, Error, Result};
 
 /// Call a wallet method.
 pub(crate) async fn call_wallet_method_internal(wallet: &Wallet, method: WalletMethod) -> Result<Response> {
     let response = match method {
-        WalletMethod::CreateAccount { alias, bech32_hrp } => {
-            let mut builder = wallet.create_account();
+        WalletMethod::CreateAccount {
+            alias,
+            bech32_hrp,
+            external_id,
+            watch_only_addresses,
+        } => {
+            let mut builder = wallet.create_account().with_external_id(external_id);
+
+            if let Some(watch_only_addresses) = watch_only_addresses {
+                builder = builder.with_watch_only_addresses(watch_only_addresses);
+            }
 
             if let Some(alias) = alias {
                 builder = builder.with_alias(alias);
@@ -27,7 +42,7 @@ pub(crate) async fn call_wallet_method_internal(wallet: &Wallet, method: WalletM
             match builder.finish().await {
                 Ok(account) => {
                     let account = account.read().await;
-                    Response::Account(AccountDetailsDto::from(&*account))
+                    Response::Account(Box::new(AccountDetailsDto::from(&*account)))
                 }
                 Err(e) => return Err(e.into()),
             }
@@ -35,7 +50,12 @@ pub(crate) async fn call_wallet_method_internal(wallet: &Wallet, method: WalletM
         WalletMethod::GetAccount { account_id } => {
             let account = wallet.get_account(account_id.clone()).await?;
             let account = account.read().await;
-            Response::Account(AccountDetailsDto::from(&*account))
+            Response::Account(Box::new(AccountDetailsDto::from(&*account)))
+        }
+        WalletMethod::GetAccountFor { external_id } => {
+            let account = wallet.get_account_for(&external_id).await?;
+            let account = account.read().await;
+            Response::Account(Box::new(AccountDetailsDto::from(&*account)))
         }
         WalletMethod::GetAccountIndexes => {
             let accounts = wallet.get_accounts().await?;
@@ -58,6 +78,7 @@ pub(crate) async fn call_wallet_method_internal(wallet: &Wallet, method: WalletM
             let account = wallet.get_account(account_id).await?;
             call_account_method_internal(&account, method).await?
         }
+        WalletMethod::CallBatch { .. } => return Err(Error::NestedBatch),
         #[cfg(feature = "stronghold")]
         WalletMethod::Backup { destination, password } => {
             wallet.backup(destination, password).await?;
@@ -85,6 +106,35 @@ pub(crate) async fn call_wallet_method_internal(wallet: &Wallet, method: WalletM
             let is_available = wallet.is_stronghold_password_available().await?;
             Response::Bool(is_available)
         }
+        #[cfg(feature = "storage")]
+        WalletMethod::ExportAccount {
+            account_id,
+            mut password,
+        } => {
+            let exported_account = wallet.export_account(account_id, &password).await;
+            password.zeroize();
+            Response::ExportedAccount(exported_account?)
+        }
+        #[cfg(feature = "storage")]
+        WalletMethod::ImportAccount {
+            exported_account,
+            mut password,
+        } => {
+            let account = wallet.import_account(&exported_account, &password).await;
+            password.zeroize();
+            let account = account?;
+            let account = account.read().await;
+            Response::Account(Box::new(AccountDetailsDto::from(&*account)))
+        }
+        WalletMethod::ImportFireflyProfile { profile, options } => {
+            let accounts = wallet.import_firefly_profile(profile, options).await?;
+            let mut account_dtos = Vec::new();
+            for account in accounts {
+                let account = account.read().await;
+                account_dtos.push(AccountDetailsDto::from(&*account));
+            }
+            Response::Accounts(account_dtos)
+        }
         WalletMethod::RecoverAccounts {
             account_start_index,
             account_gap_limit,
@@ -101,10 +151,15 @@ pub(crate) async fn call_wallet_method_internal(wallet: &Wallet, method: WalletM
             }
             Response::Accounts(account_dtos)
         }
+        WalletMethod::RemoveAccount { account_id, force } => {
+            wallet.remove_account(account_id, force).await?;
+            Response::Ok
+        }
         WalletMethod::RemoveLatestAccount => {
             wallet.remove_latest_account().await?;
             Response::Ok
         }
+        WalletMethod::Search { query } => Response::SearchResults(wallet.search(&query).await?),
         #[cfg(feature = "stronghold")]
         WalletMethod::RestoreBackup {
             source,
@@ -122,6 +177,13 @@ pub(crate) async fn call_wallet_method_internal(wallet: &Wallet, method: WalletM
                 .await?;
             Response::Ok
         }
+        #[cfg(feature = "storage")]
+        WalletMethod::SetStoragePassword { mut password } => {
+            let encryption = password.clone().map(StorageEncryption::Password);
+            password.zeroize();
+            wallet.set_storage_encryption(encryption).await?;
+            Response::Ok
+        }
         WalletMethod::SetClientOptions { client_options } => {
             wallet.set_client_options(*client_options).await?;
             Response::Ok
@@ -131,6 +193,7 @@ pub(crate) async fn call_wallet_method_internal(wallet: &Wallet, method: WalletM
             let ledger_nano_status = wallet.get_ledger_nano_status().await?;
             Response::LedgerNanoStatus(ledger_nano_status)
         }
+        WalletMethod::GetHealth => Response::WalletHealth(wallet.health().await),
         WalletMethod::GenerateAddress {
             account_index,
             address_index,
@@ -177,6 +240,23 @@ pub(crate) async fn call_wallet_method_internal(wallet: &Wallet, method: WalletM
             wallet.stop_background_syncing().await?;
             Response::Ok
         }
+        WalletMethod::TransferBetweenAccounts {
+            from,
+            to,
+            amount,
+            options,
+        } => {
+            let amount = u64::from_str(&amount).map_err(|_| iota_sdk::client::Error::InvalidAmount(amount.clone()))?;
+            let transaction = wallet
+                .transfer_between_accounts(
+                    from,
+                    to,
+                    amount,
+                    options.as_ref().map(TransactionOptions::try_from_dto).transpose()?,
+                )
+                .await?;
+            Response::SentTransaction(TransactionDto::from(&transaction))
+        }
         #[cfg(feature = "events")]
         WalletMethod::EmitTestEvent { event } => {
             wallet.emit_test_event(event.clone()).await?;
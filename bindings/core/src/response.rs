@@ -7,11 +7,15 @@ use std::collections::HashSet;
 use derivative::Derivative;
 #[cfg(feature = "ledger_nano")]
 use iota_sdk::client::secret::LedgerNanoStatus;
+#[cfg(feature = "operator")]
+use iota_sdk::client::NodeCapabilities;
+#[cfg(feature = "events")]
+use iota_sdk::wallet::events::types::NotificationPreferences;
 use iota_sdk::{
     client::{
-        api::{PreparedTransactionDataDto, SignedTransactionDataDto},
-        node_manager::node::Node,
-        NetworkInfoDto, NodeInfoWrapper,
+        api::{PreparedTransactionDataDto, SignedTransactionDataDto, VisitedBlock},
+        node_manager::node::{Node, UnhealthyNode},
+        NetworkInfoDto, NodeInfoWrapper, PowCapacityEstimate, RequestJournalEntry,
     },
     types::{
         api::{
@@ -29,6 +33,7 @@ use iota_sdk::{
             input::dto::UtxoInputDto,
             output::{
                 dto::{OutputDto, OutputMetadataDto},
+                feature::{Irc27Metadata, Irc30Metadata, StateUpdate},
                 AliasId, FoundryId, NftId, OutputId,
             },
             payload::{
@@ -43,10 +48,12 @@ use iota_sdk::{
     },
     wallet::{
         account::{
-            types::{AccountAddress, AccountBalanceDto, TransactionDto},
-            MintTokenTransactionDto, OutputDataDto,
+            types::{AccountAddress, AccountBalanceDto, AssetId, ChainAddress, TransactionDto},
+            AccountHealth, AddressAge, AgeAttestation, InclusionStats, IntegrityReport, MintTokenTransactionDto,
+            OutputDataDto, PrunedRecordsDto, SpendingPolicy, TransactionDryRunDto, TransactionSummary, UtxoSnapshot,
         },
         message_interface::dtos::{AccountDetailsDto, AddressWithUnspentOutputsDto},
+        SearchResult, WalletHealth,
     },
 };
 use serde::Serialize;
@@ -84,6 +91,12 @@ pub enum Response {
     /// - [`GetMinPowScore`](crate::method::ClientMethod::GetMinPowScore)
     MinPowScore(u32),
     /// Response for:
+    /// - [`EstimatePowCapacity`](crate::method::ClientMethod::EstimatePowCapacity)
+    PowCapacityEstimate(PowCapacityEstimate),
+    /// Response for:
+    /// - [`GetRequestJournal`](crate::method::ClientMethod::GetRequestJournal)
+    RequestJournal(Option<Vec<RequestJournalEntry>>),
+    /// Response for:
     /// - [`GetTipsInterval`](crate::method::ClientMethod::GetTipsInterval)
     TipsInterval(u64),
     /// Response for:
@@ -104,7 +117,7 @@ pub enum Response {
     /// Response for:
     /// - [`UnhealthyNodes`](crate::method::ClientMethod::UnhealthyNodes)
     #[cfg(not(target_family = "wasm"))]
-    UnhealthyNodes(HashSet<Node>),
+    UnhealthyNodes(Vec<UnhealthyNode>),
     /// Response for:
     /// - [`GetNodeInfo`](crate::method::ClientMethod::GetNodeInfo)
     NodeInfo(NodeInfo),
@@ -115,6 +128,17 @@ pub enum Response {
     /// - [`GetPeers`](crate::method::ClientMethod::GetPeers)
     Peers(Vec<PeerDto>),
     /// Response for:
+    /// - [`GetPeer`](crate::method::ClientMethod::GetPeer)
+    /// - [`AddPeer`](crate::method::ClientMethod::AddPeer)
+    #[cfg(feature = "operator")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "operator")))]
+    Peer(PeerDto),
+    /// Response for:
+    /// - [`GetNodeCapabilities`](crate::method::ClientMethod::GetNodeCapabilities)
+    #[cfg(feature = "operator")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "operator")))]
+    NodeCapabilities(NodeCapabilities),
+    /// Response for:
     /// - [`GetTips`](crate::method::ClientMethod::GetTips)
     Tips(Vec<BlockId>),
     /// Response for:
@@ -130,6 +154,13 @@ pub enum Response {
     /// - [`GetBlockMetadata`](crate::method::ClientMethod::GetBlockMetadata)
     BlockMetadata(BlockMetadataResponse),
     /// Response for:
+    /// - [`GetBlockChildren`](crate::method::ClientMethod::GetBlockChildren)
+    /// - [`GetBlockParents`](crate::method::ClientMethod::GetBlockParents)
+    BlockIds(Vec<BlockId>),
+    /// Response for:
+    /// - [`WalkCone`](crate::method::ClientMethod::WalkCone)
+    VisitedBlocks(Vec<VisitedBlock>),
+    /// Response for:
     /// - [`GetBlockRaw`](crate::method::ClientMethod::GetBlockRaw)
     BlockRaw(Vec<u8>),
     /// Response for:
@@ -174,6 +205,17 @@ pub enum Response {
     /// - [`FoundryOutputIds`](crate::method::ClientMethod::FoundryOutputIds)
     OutputIdsResponse(OutputIdsResponse),
     /// Response for:
+    /// - [`FoundryMetadata`](crate::method::ClientMethod::FoundryMetadata)
+    /// - [`GetNativeTokenMetadata`](crate::method::AccountMethod::GetNativeTokenMetadata)
+    Irc30Metadata(Option<Irc30Metadata>),
+    /// Response for:
+    /// - [`NftMetadata`](crate::method::ClientMethod::NftMetadata)
+    /// - [`GetNftMetadata`](crate::method::AccountMethod::GetNftMetadata)
+    Irc27Metadata(Option<Irc27Metadata>),
+    /// Response for:
+    /// - [`GetNftStateHistory`](crate::method::AccountMethod::GetNftStateHistory)
+    StateUpdates(Vec<StateUpdate>),
+    /// Response for:
     /// - [`FindBlocks`](crate::method::ClientMethod::FindBlocks)
     Blocks(Vec<BlockDto>),
     /// Response for:
@@ -276,50 +318,97 @@ pub enum Response {
     /// - [`EmitTestEvent`](crate::method::WalletMethod::EmitTestEvent),
     /// - [`ClearListeners`](crate::method::WalletMethod::ClearListeners)
     /// - [`StoreMnemonic`](crate::method::WalletMethod::StoreMnemonic)
+    /// - [`ClearRequestJournal`](crate::method::ClientMethod::ClearRequestJournal)
+    /// - [`RemovePeer`](crate::method::ClientMethod::RemovePeer)
     Ok,
     /// Response for any method that returns an error.
     Error(Error),
     /// Response for any method that panics.
     Panic(String),
+    /// Response for
+    /// - [`CallBatch`](crate::method::ClientMethod::CallBatch),
+    /// - [`CallBatch`](crate::method::WalletMethod::CallBatch)
+    Batch(Vec<Response>),
 
     // wallet responses
     /// Response for
     /// - [`CreateAccount`](crate::method::WalletMethod::CreateAccount),
-    /// - [`GetAccount`](crate::method::WalletMethod::GetAccount)
-    Account(AccountDetailsDto),
+    /// - [`GetAccount`](crate::method::WalletMethod::GetAccount),
+    /// - [`GetAccountFor`](crate::method::WalletMethod::GetAccountFor),
+    /// - [`ImportAccount`](crate::method::WalletMethod::ImportAccount)
+    Account(Box<AccountDetailsDto>),
     /// Response for [`GetAccountIndexes`](crate::method::WalletMethod::GetAccountIndexes)
     AccountIndexes(Vec<u32>),
-    /// Response for [`GetAccounts`](crate::method::WalletMethod::GetAccounts)
+    /// Response for
+    /// - [`GetAccounts`](crate::method::WalletMethod::GetAccounts),
+    /// - [`ImportFireflyProfile`](crate::method::WalletMethod::ImportFireflyProfile)
     Accounts(Vec<AccountDetailsDto>),
+    /// Response for [`Search`](crate::method::WalletMethod::Search)
+    SearchResults(Vec<SearchResult>),
     /// Response for [`Addresses`](crate::method::AccountMethod::Addresses)
     Addresses(Vec<AccountAddress>),
+    /// Response for [`ChainAddresses`](crate::method::AccountMethod::ChainAddresses)
+    ChainAddresses(Vec<ChainAddress>),
     /// Response for
     /// - [`AddressesWithUnspentOutputs`](crate::method::AccountMethod::AddressesWithUnspentOutputs)
     AddressesWithUnspentOutputs(Vec<AddressWithUnspentOutputsDto>),
     /// Response for
     /// - [`MinimumRequiredStorageDeposit`](crate::method::AccountMethod::MinimumRequiredStorageDeposit)
+    /// - [`MinimumRequiredStorageDeposit`](crate::method::ClientMethod::MinimumRequiredStorageDeposit)
     MinimumRequiredStorageDeposit(String),
+    /// Response for [`MaxSendableAmount`](crate::method::AccountMethod::MaxSendableAmount)
+    MaxSendableAmount(String),
     /// Response for
     /// - [`GetOutputsWithAdditionalUnlockConditions`](crate::method::AccountMethod::GetOutputsWithAdditionalUnlockConditions)
+    /// - [`ReservedOutputs`](crate::method::AccountMethod::ReservedOutputs)
     OutputIds(Vec<OutputId>),
     /// Response for [`GetOutput`](crate::method::AccountMethod::GetOutput)
     OutputData(Option<Box<OutputDataDto>>),
     /// Response for
     /// - [`Outputs`](crate::method::AccountMethod::Outputs),
+    /// - [`OutputsPage`](crate::method::AccountMethod::OutputsPage),
     /// - [`UnspentOutputs`](crate::method::AccountMethod::UnspentOutputs)
     OutputsData(Vec<OutputDataDto>),
     /// Response for
+    /// - [`PreparePaymentUri`](crate::method::AccountMethod::PreparePaymentUri),
     /// - [`PrepareSendAmount`](crate::method::AccountMethod::PrepareSendAmount),
     /// - [`PrepareTransaction`](crate::method::AccountMethod::PrepareTransaction)
     PreparedTransaction(PreparedTransactionDataDto),
     /// Response for
+    /// - [`DryRunSendAmount`](crate::method::AccountMethod::DryRunSendAmount)
+    TransactionDryRun(TransactionDryRunDto),
+    /// Response for
+    /// - [`SummarizeTransaction`](crate::method::AccountMethod::SummarizeTransaction)
+    TransactionSummary(TransactionSummary),
+    /// Response for
     /// - [`GetTransaction`](crate::method::AccountMethod::GetTransaction),
     Transaction(Option<Box<TransactionDto>>),
     /// Response for
+    /// - [`GetTransactionConfirmations`](crate::method::AccountMethod::GetTransactionConfirmations)
+    TransactionConfirmations(Option<u32>),
+    /// Response for
     /// - [`Transactions`](crate::method::AccountMethod::Transactions),
-    /// - [`PendingTransactions`](crate::method::AccountMethod::PendingTransactions)
+    /// - [`TransactionsPage`](crate::method::AccountMethod::TransactionsPage),
+    /// - [`PendingTransactions`](crate::method::AccountMethod::PendingTransactions),
+    /// - [`SendAll`](crate::method::AccountMethod::SendAll),
+    /// - [`SendAmountBatched`](crate::method::AccountMethod::SendAmountBatched)
     Transactions(Vec<TransactionDto>),
     /// Response for
+    /// - [`Prune`](crate::method::AccountMethod::Prune)
+    PrunedRecords(PrunedRecordsDto),
+    /// Response for
+    /// - [`UtxoSnapshot`](crate::method::AccountMethod::UtxoSnapshot)
+    UtxoSnapshot(UtxoSnapshot),
+    /// Response for
+    /// - [`ImportUtxoSnapshot`](crate::method::AccountMethod::ImportUtxoSnapshot)
+    ImportedOutputs(usize),
+    /// Response for
+    /// - [`GetOutputAges`](crate::method::AccountMethod::GetOutputAges)
+    OutputAges(Vec<AddressAge>),
+    /// Response for
+    /// - [`CreateAgeAttestation`](crate::method::AccountMethod::CreateAgeAttestation)
+    AgeAttestation(AgeAttestation),
+    /// Response for
     /// - [`SignTransactionEssence`](crate::method::AccountMethod::SignTransactionEssence)
     /// - [`SubmitAndStoreTransaction`](crate::method::AccountMethod::SubmitAndStoreTransaction)
     SignedTransactionData(SignedTransactionDataDto),
@@ -331,6 +420,29 @@ pub enum Response {
     /// - [`Sync`](crate::method::AccountMethod::Sync)
     Balance(AccountBalanceDto),
     /// Response for
+    /// - [`GetHealth`](crate::method::AccountMethod::GetHealth)
+    AccountHealth(AccountHealth),
+    /// Response for
+    /// - [`QuarantinedAssets`](crate::method::AccountMethod::QuarantinedAssets)
+    QuarantinedAssets(HashSet<AssetId>),
+    /// Response for
+    /// - [`GetSpendingPolicy`](crate::method::AccountMethod::GetSpendingPolicy)
+    SpendingPolicy(SpendingPolicy),
+    /// Response for
+    /// - [`GetHealth`](crate::method::WalletMethod::GetHealth)
+    WalletHealth(WalletHealth),
+    /// Response for
+    /// - [`ExportAccount`](crate::method::WalletMethod::ExportAccount)
+    #[cfg(feature = "storage")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "storage")))]
+    ExportedAccount(String),
+    /// Response for
+    /// - [`GetInclusionStats`](crate::method::AccountMethod::GetInclusionStats)
+    InclusionStats(InclusionStats),
+    /// Response for
+    /// - [`VerifyIntegrity`](crate::method::AccountMethod::VerifyIntegrity)
+    IntegrityReport(IntegrityReport),
+    /// Response for
     /// - [`GetIncomingTransactionData`](crate::method::AccountMethod::GetIncomingTransactionData),
     IncomingTransactionData(Option<Box<(TransactionId, TransactionDto)>>),
     /// Response for
@@ -339,23 +451,31 @@ pub enum Response {
     /// Response for
     /// - [`ConsolidateOutputs`](crate::method::AccountMethod::ConsolidateOutputs)
     /// - [`ClaimOutputs`](crate::method::AccountMethod::ClaimOutputs)
+    /// - [`AcceptNftOffer`](crate::method::AccountMethod::AcceptNftOffer)
     /// - [`CreateAliasOutput`](crate::method::AccountMethod::CreateAliasOutput)
     /// - [`SendAmount`](crate::method::AccountMethod::SendAmount),
     /// - [`MintNfts`](crate::method::AccountMethod::MintNfts),
     /// - [`SendAmount`](crate::method::AccountMethod::SendAmount),
     /// - [`SendNativeTokens`](crate::method::AccountMethod::SendNativeTokens),
     /// - [`SendNft`](crate::method::AccountMethod::SendNft),
+    /// - [`AppendNftState`](crate::method::AccountMethod::AppendNftState),
     /// - [`SendOutputs`](crate::method::AccountMethod::SendOutputs)
     /// - [`SubmitAndStoreTransaction`](crate::method::AccountMethod::SubmitAndStoreTransaction)
     /// - [`Vote`](crate::method::AccountMethod::Vote)
     /// - [`StopParticipating`](crate::method::AccountMethod::StopParticipating)
     /// - [`IncreaseVotingPower`](crate::method::AccountMethod::IncreaseVotingPower)
     /// - [`DecreaseVotingPower`](crate::method::AccountMethod::DecreaseVotingPower)
+    /// - [`TransferBetweenAccounts`](crate::method::WalletMethod::TransferBetweenAccounts)
     SentTransaction(TransactionDto),
     /// Response for
     /// - [`MintNativeToken`](crate::method::AccountMethod::MintNativeToken),
     MintTokenTransaction(MintTokenTransactionDto),
     /// Response for
+    /// - [`GetNotificationPreferences`](crate::method::AccountMethod::GetNotificationPreferences)
+    #[cfg(feature = "events")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "events")))]
+    NotificationPreferences(NotificationPreferences),
+    /// Response for
     /// - [`GetParticipationEvent`](crate::method::AccountMethod::GetParticipationEvent)
     /// - [`RegisterParticipationEvents`](crate::method::AccountMethod::RegisterParticipationEvents)
     #[cfg(feature = "participation")]
@@ -69,6 +69,7 @@ async fn create_account() -> Result<()> {
         client_options: Some(ClientBuilder::new().from_json(client_options).unwrap()),
         coin_type: Some(SHIMMER_COIN_TYPE),
         secret_manager: Some(serde_json::from_str(secret_manager).unwrap()),
+        storage_password: None,
     };
 
     let wallet = options.build_manager().await?;
@@ -78,6 +79,8 @@ async fn create_account() -> Result<()> {
         .call_method(WalletMethod::CreateAccount {
             alias: None,
             bech32_hrp: None,
+            external_id: None,
+            watch_only_addresses: None,
         })
         .await;
 
@@ -106,6 +109,58 @@ async fn create_account() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn call_batch() -> Result<()> {
+    let storage_path = "test-storage/call_batch";
+    std::fs::remove_dir_all(storage_path).ok();
+
+    let secret_manager = r#"{"Mnemonic":"about solution utility exist rail budget vacuum major survey clerk pave ankle wealth gym gossip still medal expect strong rely amazing inspire lazy lunar"}"#;
+    let client_options = r#"{
+            "nodes":[
+               {
+                  "url":"http://localhost:14265",
+                  "auth":null,
+                  "disabled":false
+               }
+            ]
+         }"#;
+
+    let options = WalletOptions {
+        storage_path: Some(storage_path.to_string()),
+        client_options: Some(ClientBuilder::new().from_json(client_options).unwrap()),
+        coin_type: Some(SHIMMER_COIN_TYPE),
+        secret_manager: Some(serde_json::from_str(secret_manager).unwrap()),
+        storage_password: None,
+    };
+
+    let wallet = options.build_manager().await?;
+
+    let methods = serde_json::from_str(
+        r#"[
+            { "name": "createAccount", "data": { "alias": "Alice" } },
+            { "name": "getAccountIndexes" },
+            { "name": "callAccountMethod", "data": { "accountId": 1, "method": { "name": "getBalance" } } },
+            { "name": "callBatch", "data": { "methods": [] } }
+        ]"#,
+    )?;
+    let response = wallet.call_method(WalletMethod::CallBatch { methods }).await;
+
+    match response {
+        Response::Batch(responses) => {
+            assert_eq!(responses.len(), 4);
+            assert!(matches!(&responses[0], Response::Account(account) if account.alias == "Alice"));
+            assert!(matches!(&responses[1], Response::AccountIndexes(indexes) if indexes == &[0]));
+            // Failing methods don't stop the batch
+            assert!(matches!(&responses[2], Response::Error(_)));
+            assert!(matches!(&responses[3], Response::Error(error) if error.kind() == "nestedBatch"));
+        }
+        _ => panic!("unexpected response {response:?}"),
+    }
+
+    std::fs::remove_dir_all(storage_path).ok();
+    Ok(())
+}
+
 #[tokio::test]
 async fn verify_accounts() -> Result<()> {
     let storage_path = "test-storage/verify_accounts";
@@ -127,6 +182,7 @@ async fn verify_accounts() -> Result<()> {
         client_options: Some(ClientBuilder::new().from_json(client_options).unwrap()),
         coin_type: Some(SHIMMER_COIN_TYPE),
         secret_manager: Some(serde_json::from_str(secret_manager).unwrap()),
+        storage_password: None,
     };
 
     let wallet = options.build_manager().await?;
@@ -146,6 +202,8 @@ async fn verify_accounts() -> Result<()> {
                 .call_method(WalletMethod::CreateAccount {
                     alias: Some(alias.to_owned()),
                     bech32_hrp: None,
+                    external_id: None,
+                    watch_only_addresses: None,
                 })
                 .await,
         );
@@ -193,7 +251,7 @@ async fn verify_accounts() -> Result<()> {
         Response::Accounts(details) => {
             assert_eq!(account_details.len(), details.len());
             for detail in details {
-                assert_eq!(&account_details[&detail.index], &detail);
+                assert_eq!(*account_details[&detail.index], detail);
             }
         }
         response => panic!("unexpected response {response:?}"),
@@ -235,6 +293,7 @@ async fn client_from_wallet() -> Result<()> {
         client_options: Some(ClientBuilder::new().from_json(client_options).unwrap()),
         coin_type: Some(SHIMMER_COIN_TYPE),
         secret_manager: Some(serde_json::from_str(secret_manager).unwrap()),
+        storage_password: None,
     };
 
     let wallet = options.build_manager().await?;
@@ -244,6 +303,8 @@ async fn client_from_wallet() -> Result<()> {
         .call_method(WalletMethod::CreateAccount {
             alias: None,
             bech32_hrp: None,
+            external_id: None,
+            watch_only_addresses: None,
         })
         .await;
 
@@ -48,9 +48,10 @@ fn method_interface_secrets_debug() {
         client_options: None,
         coin_type: None,
         secret_manager: Some(SecretManagerDto::Placeholder),
+        storage_password: Some("password".to_string()),
     };
     assert_eq!(
         format!("{:?}", wallet_options),
-        "WalletOptions { storage_path: None, client_options: None, coin_type: None, secret_manager: Some(<omitted>) }"
+        "WalletOptions { storage_path: None, client_options: None, coin_type: None, secret_manager: Some(<omitted>), storage_password: Some(<omitted>) }"
     );
 }
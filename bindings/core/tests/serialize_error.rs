@@ -1,7 +1,9 @@
 // Copyright 2023 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use iota_sdk::{client::Error as ClientError, wallet::Error as WalletError};
+use iota_sdk::{
+    client::Error as ClientError, types::block::payload::transaction::TransactionId, wallet::Error as WalletError,
+};
 use iota_sdk_bindings_core::Error;
 
 #[test]
@@ -9,11 +11,21 @@ fn custom_error_serialization() {
     let error = Error::Client(ClientError::HealthyNodePoolEmpty);
     assert_eq!(
         serde_json::to_string(&error).unwrap(),
-        "{\"type\":\"client\",\"error\":\"no healthy node available\"}"
+        "{\"type\":\"client\",\"code\":\"client.healthyNodePoolEmpty\",\"error\":\"no healthy node available\"}"
     );
     let error = Error::Wallet(WalletError::AccountNotFound("Alice".to_string()));
     assert_eq!(
         serde_json::to_string(&error).unwrap(),
-        "{\"type\":\"wallet\",\"error\":\"account Alice not found\"}"
+        "{\"type\":\"wallet\",\"code\":\"wallet.accountNotFound\",\"error\":\"account Alice not found\"}"
+    );
+    let error = Error::Wallet(WalletError::TransactionNotFound(TransactionId::null()));
+    assert_eq!(
+        serde_json::to_string(&error).unwrap(),
+        "{\"type\":\"wallet\",\"code\":\"wallet.transactionNotFound\",\"error\":\"transaction 0x0000000000000000000000000000000000000000000000000000000000000000 not found\",\"transactionId\":\"0x0000000000000000000000000000000000000000000000000000000000000000\"}"
+    );
+    let error = Error::Wallet(WalletError::from(ClientError::NotFound("http://localhost:14265".to_string())));
+    assert_eq!(
+        serde_json::to_string(&error).unwrap(),
+        "{\"type\":\"wallet\",\"code\":\"wallet.client.notFound\",\"error\":\"`the requested data http://localhost:14265 was not found.`\",\"status\":404,\"url\":\"http://localhost:14265\"}"
     );
 }
@@ -10,6 +10,8 @@ import type {
     BlockId,
     INetworkInfo,
     INode,
+    IUnhealthyNode,
+    INodeCapabilities,
     IAuth,
     BasicOutputBuilderOptions,
     AliasOutputBuilderOptions,
@@ -20,6 +22,8 @@ import type {
     AliasQueryParameter,
     OutputIdsResponse,
     IBip32Chain,
+    IConeWalkOptions,
+    IVisitedBlock,
 } from '../../types/client';
 import type {
     IUTXOInput,
@@ -195,6 +199,52 @@ export class Client {
         return JSON.parse(response).payload;
     }
 
+    /**
+     * Get the ids of the blocks that reference the block.
+     */
+    async getBlockChildren(blockId: BlockId): Promise<BlockId[]> {
+        const response = await this.methodHandler.callMethod({
+            name: 'getBlockChildren',
+            data: {
+                blockId,
+            },
+        });
+
+        return JSON.parse(response).payload;
+    }
+
+    /**
+     * Get the ids of the blocks that are referenced by the block.
+     */
+    async getBlockParents(blockId: BlockId): Promise<BlockId[]> {
+        const response = await this.methodHandler.callMethod({
+            name: 'getBlockParents',
+            data: {
+                blockId,
+            },
+        });
+
+        return JSON.parse(response).payload;
+    }
+
+    /**
+     * Get the blocks in the past or future cone of a block, closer blocks first.
+     */
+    async walkCone(
+        blockId: BlockId,
+        options?: IConeWalkOptions,
+    ): Promise<IVisitedBlock[]> {
+        const response = await this.methodHandler.callMethod({
+            name: 'walkCone',
+            data: {
+                blockId,
+                options,
+            },
+        });
+
+        return JSON.parse(response).payload;
+    }
+
     /**
      * Find inputs from addresses for a provided amount (useful for offline signing)
      */
@@ -439,6 +489,58 @@ export class Client {
         return JSON.parse(response).payload;
     }
 
+    /**
+     * Get a peer of the node, the node usually requires a JWT for the peer routes.
+     */
+    async getPeer(peerId: string): Promise<IPeer> {
+        const response = await this.methodHandler.callMethod({
+            name: 'getPeer',
+            data: {
+                peerId,
+            },
+        });
+
+        return JSON.parse(response).payload;
+    }
+
+    /**
+     * Add a peer to the node by its multiaddress, returns the added peer.
+     */
+    async addPeer(multiAddress: string, alias?: string): Promise<IPeer> {
+        const response = await this.methodHandler.callMethod({
+            name: 'addPeer',
+            data: {
+                multiAddress,
+                alias,
+            },
+        });
+
+        return JSON.parse(response).payload;
+    }
+
+    /**
+     * Remove a peer from the node.
+     */
+    async removePeer(peerId: string): Promise<void> {
+        await this.methodHandler.callMethod({
+            name: 'removePeer',
+            data: {
+                peerId,
+            },
+        });
+    }
+
+    /**
+     * Get the features and the API route groups of the node.
+     */
+    async getNodeCapabilities(): Promise<INodeCapabilities> {
+        const response = await this.methodHandler.callMethod({
+            name: 'getNodeCapabilities',
+        });
+
+        return JSON.parse(response).payload;
+    }
+
     /**
      * Post block as raw bytes, returns the block ID.
      */
@@ -893,9 +995,9 @@ export class Client {
     }
 
     /**
-     * Returns the unhealthy nodes.
+     * Returns the nodes that are left out of the healthy node pool, with the error of the last node sync.
      */
-    async unhealthyNodes(): Promise<Set<INode>> {
+    async unhealthyNodes(): Promise<IUnhealthyNode[]> {
         const response = await this.methodHandler.callMethod({
             name: 'unhealthyNodes',
         });
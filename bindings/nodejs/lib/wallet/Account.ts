@@ -12,9 +12,9 @@ import type {
     AddressNativeTokens,
     AddressNftId,
     AddressWithUnspentOutputs,
+    AddressGenerationOptions,
     AliasOutputOptions,
     FilterOptions,
-    GenerateAddressOptions,
     IncreaseNativeTokenSupplyOptions,
     MintTokenTransaction,
     NativeTokenOptions,
@@ -350,7 +350,7 @@ export class Account {
      * @returns The address.
      */
     async generateAddress(
-        options?: GenerateAddressOptions,
+        options?: AddressGenerationOptions,
     ): Promise<AccountAddress> {
         const addresses = await this.generateAddresses(1, options);
         return addresses[0];
@@ -364,7 +364,7 @@ export class Account {
      */
     async generateAddresses(
         amount: number,
-        options?: GenerateAddressOptions,
+        options?: AddressGenerationOptions,
     ): Promise<AccountAddress[]> {
         const response = await this.methodHandler.callAccountMethod(
             this.meta.index,
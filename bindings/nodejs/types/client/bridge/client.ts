@@ -14,6 +14,7 @@ import type {
     QueryParameter,
 } from '../queryParameters';
 import type { IAuth } from '../network';
+import type { IConeWalkOptions } from '../coneWalk';
 import type { BasicOutputBuilderOptions } from '../outputBuilderOptions/basicOutputOptions';
 import type { AliasOutputBuilderOptions } from '../outputBuilderOptions/aliasOutputOptions';
 import type { FoundryOutputBuilderOptions } from '../outputBuilderOptions/foundryOutputOptions';
@@ -89,6 +90,28 @@ export interface __GetBlockMetadataMethod__ {
     };
 }
 
+export interface __GetBlockChildrenMethod__ {
+    name: 'getBlockChildren';
+    data: {
+        blockId: BlockId;
+    };
+}
+
+export interface __GetBlockParentsMethod__ {
+    name: 'getBlockParents';
+    data: {
+        blockId: BlockId;
+    };
+}
+
+export interface __WalkConeMethod__ {
+    name: 'walkCone';
+    data: {
+        blockId: BlockId;
+        options?: IConeWalkOptions;
+    };
+}
+
 export interface __FindInputsMethod__ {
     name: 'findInputs';
     data: {
@@ -188,6 +211,32 @@ export interface __GetPeersMethod__ {
     name: 'getPeers';
 }
 
+export interface __GetPeerMethod__ {
+    name: 'getPeer';
+    data: {
+        peerId: string;
+    };
+}
+
+export interface __AddPeerMethod__ {
+    name: 'addPeer';
+    data: {
+        multiAddress: string;
+        alias?: string;
+    };
+}
+
+export interface __RemovePeerMethod__ {
+    name: 'removePeer';
+    data: {
+        peerId: string;
+    };
+}
+
+export interface __GetNodeCapabilitiesMethod__ {
+    name: 'getNodeCapabilities';
+}
+
 export interface __PostBlockRawMethod__ {
     name: 'postBlockRaw';
     data: {
@@ -10,6 +10,9 @@ import type {
     __GetNetworkInfoMethod__,
     __GetBlockMethod__,
     __GetBlockMetadataMethod__,
+    __GetBlockChildrenMethod__,
+    __GetBlockParentsMethod__,
+    __WalkConeMethod__,
     __FindInputsMethod__,
     __FindOutputsMethod__,
     __PrepareTransactionMethod__,
@@ -26,6 +29,10 @@ import type {
     __GetHealthMethod__,
     __GetNodeInfoMethod__,
     __GetPeersMethod__,
+    __GetPeerMethod__,
+    __AddPeerMethod__,
+    __RemovePeerMethod__,
+    __GetNodeCapabilitiesMethod__,
     __PostBlockRawMethod__,
     __GetBlockRawMethod__,
     __GetReceiptsMethod__,
@@ -78,6 +85,9 @@ export type __ClientMethods__ =
     | __GetNetworkInfoMethod__
     | __GetBlockMethod__
     | __GetBlockMetadataMethod__
+    | __GetBlockChildrenMethod__
+    | __GetBlockParentsMethod__
+    | __WalkConeMethod__
     | __FindInputsMethod__
     | __FindOutputsMethod__
     | __PrepareTransactionMethod__
@@ -95,6 +105,10 @@ export type __ClientMethods__ =
     | __GetHealthMethod__
     | __GetNodeInfoMethod__
     | __GetPeersMethod__
+    | __GetPeerMethod__
+    | __AddPeerMethod__
+    | __RemovePeerMethod__
+    | __GetNodeCapabilitiesMethod__
     | __PostBlockRawMethod__
     | __GetBlockRawMethod__
     | __GetMilestoneByIdMethod__
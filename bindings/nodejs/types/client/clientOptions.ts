@@ -12,6 +12,8 @@ export interface IClientOptions {
     permanodes?: Array<string | INode>;
     /** If the node health status should be ignored */
     ignoreNodeHealth?: boolean;
+    /** If no nodes are used, e.g. for offline signing, so requests fail instead of being sent */
+    offline?: boolean;
     /** Interval in which nodes will be checked for their sync status and the NetworkInfo gets updated */
     nodeSyncInterval?: IDuration;
     /** If node quorum is enabled. Will compare the responses from multiple nodes and only returns the
@@ -0,0 +1,28 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+import type { IBlockMetadata } from '@iota/types';
+import type { BlockId } from '../core/blockId';
+
+/** The direction in which the tangle is traversed during a cone walk. */
+export type ConeDirection = 'past' | 'future';
+
+/** Options for the cone walk. */
+export interface IConeWalkOptions {
+    /** The direction in which the tangle is traversed, `past` by default. */
+    direction?: ConeDirection;
+    /** The max distance of the visited blocks from the start block, 10 by default. */
+    maxDepth?: number;
+    /** The max amount of visited blocks, including the start block, 1000 by default. */
+    maxBlocks?: number;
+}
+
+/** A block that was visited during a cone walk. */
+export interface IVisitedBlock {
+    /** The id of the block. */
+    blockId: BlockId;
+    /** The distance of the block from the start block. */
+    depth: number;
+    /** The metadata of the block. */
+    metadata: IBlockMetadata;
+}
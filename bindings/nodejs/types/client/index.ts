@@ -3,6 +3,7 @@ export * from './bridge';
 export * from './buildBlockOptions';
 export * from './burn';
 export * from './clientOptions';
+export * from './coneWalk';
 export * from './generateAddressesOptions';
 export * from './ledgerNanoStatus';
 export * from './network';
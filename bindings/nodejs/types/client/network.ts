@@ -40,6 +40,15 @@ export interface INode {
     disabled?: boolean;
 }
 
+/**
+ * A node that is left out of the healthy node pool.
+ */
+export interface IUnhealthyNode {
+    node: INode;
+    /** Why the node is unhealthy, from the last node sync */
+    error?: string;
+}
+
 /**
  * Struct containing network and PoW related information
  */
@@ -55,3 +64,13 @@ export interface INetworkInfo {
     /** Tips request interval during PoW in seconds */
     tipsInterval: number;
 }
+
+/**
+ * The features and the API route groups of a node.
+ */
+export interface INodeCapabilities {
+    /** The features of the node from the node info. */
+    features: string[];
+    /** The available API route groups, e.g. `indexer/v1`. */
+    routes: string[];
+}
@@ -41,6 +41,10 @@ export interface BaseCoinBalance {
     total: string;
     /** The amount of the outputs that aren't used in a transaction */
     available: string;
+    /** The amount of the outputs with a timelock that didn't elapse yet */
+    locked: string;
+    /** The amount of the outputs with an expiration that didn't pass yet */
+    expiringSoon: string;
     /** Voting power */
     votingPower: string;
 }
@@ -99,6 +103,8 @@ export interface SyncOptions {
     syncOnlyMostBasicOutputs?: boolean;
     /** Sync native token foundries, so their metadata can be returned in the balance. Default: false. */
     syncNativeTokenFoundries?: boolean;
+    /** Max amount of addresses that are synced in parallel. Default: 500. */
+    maxParallelApiRequests?: number;
 }
 
 /** Specifies what outputs should be synced for the ed25519 addresses from the account. */
@@ -125,9 +131,12 @@ export interface NftSyncOptions {
 
 /** The account object */
 export interface AccountMeta {
+    /** The version of the serialization schema */
+    dtoVersion: number;
     index: number;
     coinType: CoinType;
     alias: string;
+    externalId?: string;
     publicAddresses: AccountAddress[];
     internalAddresses: AccountAddress[];
     addressesWithUnspentOutputs: AddressWithUnspentOutputs[];
@@ -158,6 +167,8 @@ export interface AccountMetadata {
 export interface CreateAccountPayload {
     alias?: string;
     bech32Hrp?: string;
+    /** An identifier of the account in an external system, the existing account is returned if one was already created for it */
+    externalId?: string;
 }
 
 /** Options to filter outputs */
@@ -50,3 +50,15 @@ export interface GenerateAddressOptions {
     internal: boolean;
     ledgerNanoPrompt: boolean;
 }
+
+/** Options for the address generation of an account */
+export interface AddressGenerationOptions {
+    /** Generate internal (change) addresses instead of public ones */
+    internal?: boolean;
+    /** The key index of the first address, by default the index after the highest one of the account */
+    startIndex?: number;
+    /** The bech32 HRP of the returned addresses, by default the one of the network */
+    bech32Hrp?: string;
+    /** Display the addresses on ledger devices */
+    ledgerNanoPrompt?: boolean;
+}
@@ -28,6 +28,8 @@ export interface Features {
 export interface Unlocks {
     expirationUnixTime?: number;
     timelockUnixTime?: number;
+    /** Expiration relative to the current time, can't be set together with `expirationUnixTime` */
+    expirationInSeconds?: number;
 }
 
 /** Storage deposit strategy to be used for the output */
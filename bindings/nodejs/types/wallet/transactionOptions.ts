@@ -11,7 +11,12 @@ export interface TransactionOptions {
     burn?: Burn;
     /** Optional note, that is only stored locally */
     note?: string;
+    /** Allows sending amounts below the minimum storage deposit, the storage deposit gets returned to the sender */
     allowMicroAmount: boolean;
+    /** Expiration in seconds for outputs with amounts below the minimum storage deposit, if not set per address */
+    microAmountExpiration?: number;
+    /** Allows using outputs of pending transactions as inputs, so transactions can be chained without waiting for confirmations */
+    allowUnconfirmedInputs?: boolean;
 }
 
 /** The RemainderValueStrategy */
@@ -28,6 +28,33 @@ class NodeCoreAPI():
         """
         return self._call_method('getPeers')
 
+    def get_peer(self, peer_id):
+        """Get a peer of the node.
+        """
+        return self._call_method('getPeer', {
+            'peerId': peer_id
+        })
+
+    def add_peer(self, multi_address, alias=None):
+        """Add a peer to the node.
+        """
+        return self._call_method('addPeer', {
+            'multiAddress': multi_address,
+            'alias': alias
+        })
+
+    def remove_peer(self, peer_id):
+        """Remove a peer from the node.
+        """
+        return self._call_method('removePeer', {
+            'peerId': peer_id
+        })
+
+    def get_node_capabilities(self):
+        """Get the features and the API route groups of the node.
+        """
+        return self._call_method('getNodeCapabilities')
+
     def get_tips(self):
         """Get tips.
         """
@@ -54,6 +81,28 @@ class NodeCoreAPI():
             'blockId': block_id
         })
 
+    def get_block_children(self, block_id):
+        """Get the ids of the blocks that reference the block.
+        """
+        return self._call_method('getBlockChildren', {
+            'blockId': block_id
+        })
+
+    def get_block_parents(self, block_id):
+        """Get the ids of the blocks that are referenced by the block.
+        """
+        return self._call_method('getBlockParents', {
+            'blockId': block_id
+        })
+
+    def walk_cone(self, block_id, options=None):
+        """Get the blocks in the past or future cone of a block, closer blocks first.
+        """
+        return self._call_method('walkCone', {
+            'blockId': block_id,
+            'options': options
+        })
+
     def get_block_raw(self, block_id):
         """Get block raw.
         """
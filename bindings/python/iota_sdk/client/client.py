@@ -527,7 +527,7 @@ class Client(NodeCoreAPI, NodeIndexerAPI, HighLevelAPI, ClientUtils):
         return self._call_method('getFallbackToLocalPow')
 
     def unhealthy_nodes(self):
-        """Returns the unhealthy nodes.
+        """Returns the nodes that are left out of the healthy node pool, with the error of the last node sync.
         """
         return self._call_method('unhealthyNodes')
 
@@ -14,10 +14,10 @@ use crate::{
         claimable_outputs_command, consolidate_command, create_alias_outputs_command, decrease_native_token_command,
         decrease_voting_power_command, destroy_alias_command, destroy_foundry_command, faucet_command,
         increase_native_token_command, increase_voting_power_command, mint_native_token_command, mint_nft_command,
-        new_address_command, output_command, outputs_command, participation_overview_command, send_command,
-        send_native_token_command, send_nft_command, stop_participating_command, sync_command, transaction_command,
-        transactions_command, unspent_outputs_command, vote_command, voting_output_command, voting_power_command,
-        AccountCli, AccountCommand,
+        new_address_command, output_command, outputs_command, participation_overview_command, payment_uri_command,
+        send_command, send_native_token_command, send_nft_command, stop_participating_command, sync_command,
+        transaction_command, transactions_command, unspent_outputs_command, vote_command, voting_output_command,
+        voting_power_command, AccountCli, AccountCommand,
     },
     error::Error,
     helper::{bytes_from_hex_or_file, print_account_help},
@@ -51,7 +51,13 @@ pub async fn account_prompt_internal(account: Account, history: &mut AccountHist
         .history_with(history)
         .completion_with(&ACCOUNT_COMPLETION)
         .interact_text()?;
-    match command.as_str() {
+
+    run_account_command(account, &command).await
+}
+
+// run a single account command, returns true if the account prompt should be exited
+pub async fn run_account_command(account: Account, command: &str) -> Result<bool, Error> {
+    match command {
         "h" => print_account_help(),
         "clear" => {
             // Clear console
@@ -128,6 +134,15 @@ pub async fn account_prompt_internal(account: Account, history: &mut AccountHist
                 AccountCommand::NewAddress => new_address_command(&account).await,
                 AccountCommand::Output { output_id } => output_command(&account, output_id).await,
                 AccountCommand::Outputs => outputs_command(&account).await,
+                AccountCommand::PaymentUri {
+                    amount,
+                    tag,
+                    note,
+                    expiration,
+                } => {
+                    let expiration = expiration.map(|e| e.as_secs() as u32);
+                    payment_uri_command(&account, amount, tag, note, expiration).await
+                }
                 AccountCommand::Send {
                     address,
                     amount,
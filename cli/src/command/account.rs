@@ -17,14 +17,19 @@ use iota_sdk::{
             payload::transaction::TransactionId,
         },
     },
+    utils::payment_uri::PaymentUri,
     wallet::{
         account::{types::AccountAddress, Account, OutputsToClaim, TransactionOptions},
-        AddressAndNftId, AddressNativeTokens, AddressWithAmount, NativeTokenOptions, NftOptions,
+        AddressWithAmount, NativeTokenOptions, NftOptions, SendNativeTokensParams, SendNftParams,
     },
     U256,
 };
 
-use crate::{error::Error, helper::to_utc_date_time, println_log_info};
+use crate::{
+    error::Error,
+    helper::{parse_amount, to_utc_date_time},
+    println_log_info,
+};
 
 #[derive(Debug, Parser)]
 #[command(author, version, about, long_about = None, propagate_version = true)]
@@ -145,11 +150,27 @@ pub enum AccountCommand {
     },
     /// List all outputs.
     Outputs,
+    /// Create a payment URI to request funds to the latest address, e.g. to share it as QR code.
+    PaymentUri {
+        /// Amount to request in base units or with a unit, e.g. 1000000 or "1 SMR".
+        #[arg(long, value_parser = parse_amount)]
+        amount: Option<u64>,
+        /// Tag to identify the payment, e.g. an invoice number.
+        #[arg(long)]
+        tag: Option<String>,
+        /// Note to the payer.
+        #[arg(long)]
+        note: Option<String>,
+        /// Time after which the request expires, e.g. "1h".
+        #[arg(long)]
+        expiration: Option<humantime::Duration>,
+    },
     /// Send an amount.
     Send {
         /// Address to send funds to, e.g. rms1qztwng6cty8cfm42nzvq099ev7udhrnk0rw8jt8vttf9kpqnxhpsx869vr3.
         address: String,
-        /// Amount to send, e.g. 1000000.
+        /// Amount to send in base units or with a unit, e.g. 1000000 or "1 SMR".
+        #[arg(value_parser = parse_amount)]
         amount: u64,
         /// Bech32 encoded return address, to which the storage deposit will be returned if one is necessary
         /// given the provided amount. If a storage deposit is needed and a return address is not provided, it will
@@ -226,12 +247,14 @@ pub enum AccountCommand {
     VotingPower,
     /// Increase the voting power of the account.
     IncreaseVotingPower {
-        /// Amount to increase the voting power by, e.g. 100.
+        /// Amount to increase the voting power by, in base units or with a unit, e.g. 100 or "1.5 SMR".
+        #[arg(value_parser = parse_amount)]
         amount: u64,
     },
     /// Decrease the voting power of the account.
     DecreaseVotingPower {
-        /// Amount to decrease the voting power by, e.g. 100.
+        /// Amount to decrease the voting power by, in base units or with a unit, e.g. 100 or "1.5 SMR".
+        #[arg(value_parser = parse_amount)]
         amount: u64,
     },
     /// Get the voting output of the account.
@@ -608,6 +631,30 @@ pub async fn outputs_command(account: &Account) -> Result<(), Error> {
     Ok(())
 }
 
+// `payment-uri` command
+pub async fn payment_uri_command(
+    account: &Account,
+    amount: Option<u64>,
+    tag: Option<String>,
+    note: Option<String>,
+    expiration: Option<u32>,
+) -> Result<(), Error> {
+    let address = match account.addresses().await?.last() {
+        Some(address) => address.address().clone(),
+        None => return Err(Error::Miscellaneous("generate an address first".to_string())),
+    };
+    let now = iota_sdk::utils::unix_timestamp_now().as_secs() as u32;
+    let payment_uri = PaymentUri::new(address)
+        .with_amount(amount)
+        .with_tag(tag)
+        .with_note(note)
+        .with_expiration(expiration.map(|expiration| now + expiration));
+
+    println_log_info!("{payment_uri}");
+
+    Ok(())
+}
+
 // `send` command
 pub async fn send_command(
     account: &Account,
@@ -670,7 +717,7 @@ pub async fn send_native_token_command(
         account.send(outputs, None).await?
     } else {
         // Send native tokens with storage deposit return and expiration
-        let outputs = vec![AddressNativeTokens {
+        let outputs = vec![SendNativeTokensParams {
             address,
             native_tokens: vec![(
                 TokenId::from_str(&token_id)?,
@@ -692,10 +739,7 @@ pub async fn send_native_token_command(
 
 // `send-nft` command
 pub async fn send_nft_command(account: &Account, address: String, nft_id: String) -> Result<(), Error> {
-    let outputs = vec![AddressAndNftId {
-        address,
-        nft_id: NftId::from_str(&nft_id)?,
-    }];
+    let outputs = vec![SendNftParams::new(address, NftId::from_str(&nft_id)?)];
     let transaction = account.send_nft(outputs, None).await?;
 
     println_log_info!(
@@ -35,6 +35,9 @@ pub struct WalletCli {
     pub stronghold_snapshot_path: String,
     /// Set the account to enter.
     pub account: Option<String>,
+    /// Run an account command without the prompt, e.g. "balance", the account has to be set.
+    #[arg(long, value_name = "COMMAND")]
+    pub exec: Option<String>,
     /// Set the log level.
     #[arg(short, long, default_value = DEFAULT_LOG_LEVEL)]
     pub log_level: LevelFilter,
@@ -6,6 +6,7 @@ use clap::Parser;
 use dialoguer::{console::Term, theme::ColorfulTheme, Input, Password, Select};
 use iota_sdk::{
     client::verify_mnemonic,
+    utils::unit::{self, Unit},
     wallet::{Account, Wallet},
 };
 use tokio::{
@@ -20,8 +21,15 @@ use crate::{
 };
 
 const DEFAULT_MNEMONIC_FILE_PATH: &str = "./mnemonic.txt";
+// Amounts without a unit are in base units
+const BASE_UNIT: Unit = Unit::new("", 0);
 
 pub fn get_password(prompt: &str, confirmation: bool) -> Result<String, Error> {
+    // Allows using the CLI non-interactively, e.g. in scripts or integration tests
+    if let Ok(password) = std::env::var("STRONGHOLD_PASSWORD") {
+        return Ok(password);
+    }
+
     let mut password = Password::new();
 
     password.with_prompt(prompt);
@@ -222,6 +230,11 @@ async fn read_mnemonics_from_file(path: &str) -> Result<Vec<String>, Error> {
     Ok(mnemonics)
 }
 
+/// Parses an amount argument like `1000000` or `1.5 SMR` into base units.
+pub fn parse_amount(amount: &str) -> Result<u64, String> {
+    unit::parse_amount(amount, BASE_UNIT).map_err(|e| e.to_string())
+}
+
 /// Converts a unix timestamp in milliseconds to a DateTime<Utc>
 pub fn to_utc_date_time(ts_millis: u128) -> Result<DateTime<Utc>, Error> {
     let millis = ts_millis % 1000;
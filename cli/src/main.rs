@@ -52,7 +52,15 @@ async fn run(cli: WalletCli) -> Result<(), Error> {
 
     if let Some(wallet) = wallet {
         match cli.account.or(account) {
-            Some(account) => account::account_prompt(wallet.get_account(account).await?).await?,
+            Some(account) => {
+                let account = wallet.get_account(account).await?;
+                match cli.exec {
+                    Some(command) => {
+                        account::run_account_command(account, &command).await?;
+                    }
+                    None => account::account_prompt(account).await?,
+                }
+            }
             None => {
                 if let Some(account) = pick_account(&wallet).await? {
                     account::account_prompt(account).await?;
@@ -6,10 +6,7 @@
 //!
 //! `cargo run --example get_funds --release`
 
-use iota_sdk::{
-    client::request_funds_from_faucet,
-    wallet::{Result, Wallet},
-};
+use iota_sdk::wallet::{Result, Wallet};
 
 #[tokio::main]
 async fn main() -> Result<()> {
@@ -23,31 +20,17 @@ async fn main() -> Result<()> {
     let account = wallet.get_account("Alice").await?;
     let balance = account.sync(None).await?;
 
-    let address = account.addresses().await?;
-
     let funds_before = balance.base_coin().available();
 
     println!("Starting available funds: {funds_before}");
 
-    let faucet_response =
-        request_funds_from_faucet(&std::env::var("FAUCET_URL").unwrap(), &address[0].address().to_string()).await?;
-
-    println!("Response from faucet: {faucet_response}");
-
-    println!("Waiting for funds...");
-    // Check for changes to the balance
-    let start = std::time::Instant::now();
-    let balance = loop {
-        if start.elapsed().as_secs() > 60 {
-            panic!("took too long waiting for funds")
-        };
-        let balance = account.sync(None).await?;
-        if balance.base_coin().available() > funds_before {
-            break balance;
-        } else {
-            tokio::time::sleep(instant::Duration::from_secs(2)).await;
-        }
-    };
+    println!("Requesting funds and waiting until they arrived...");
+    let balance = account
+        .request_funds_from_faucet(
+            &std::env::var("FAUCET_URL").unwrap(),
+            std::time::Duration::from_secs(60),
+        )
+        .await?;
 
     println!("New available funds: {}", balance.base_coin().available());
 
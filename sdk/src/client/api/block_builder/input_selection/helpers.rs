@@ -7,9 +7,7 @@ use crate::{
     client::Result,
     types::block::{
         address::{Address, Ed25519Address},
-        output::{
-            unlock_condition::AddressUnlockCondition, BasicOutputBuilder, NativeTokens, Output, Rent, RentStructure,
-        },
+        output::{unlock_condition::AddressUnlockCondition, BasicOutputBuilder, NativeTokens, Output, RentStructure},
     },
 };
 
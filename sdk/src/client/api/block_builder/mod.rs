@@ -10,7 +10,9 @@ use std::ops::Range;
 use packable::bounded::TryIntoBoundedU16Error;
 use serde::{Deserialize, Serialize};
 
-pub use self::transaction::verify_semantic;
+pub use self::transaction::{
+    rebind_network, validate_block_length, validate_payload_length, verify_network_id, verify_semantic,
+};
 use crate::{
     client::{
         api::block_builder::input_selection::Burn, constants::SHIMMER_COIN_TYPE, secret::SecretManager, Client, Error,
@@ -222,6 +224,13 @@ impl<'a> ClientBlockBuilder<'a> {
         self
     }
 
+    /// Set the tag and data from a tagged data payload, which will be attached to the block
+    pub fn with_tagged_data_payload(mut self, payload: TaggedDataPayload) -> Self {
+        self.tag.replace(payload.tag().to_vec());
+        self.data.replace(payload.data().to_vec());
+        self
+    }
+
     /// Set 1-8 custom parent block ids
     pub fn with_parents(mut self, parent_ids: impl Into<Option<Vec<BlockId>>>) -> Result<Self> {
         self.parents = parent_ids.into().map(Parents::from_vec).transpose()?;
@@ -312,6 +321,19 @@ impl<'a> ClientBlockBuilder<'a> {
 
     /// Consume the builder and get the API result
     pub async fn finish(self) -> Result<Block> {
+        let payload = self.build_payload().await?;
+        self.finish_block(payload).await
+    }
+
+    /// Consume the builder and get the block with PoW if local PoW is enabled, without posting it. Useful to post it
+    /// later or via another channel.
+    pub async fn build(self) -> Result<Block> {
+        let payload = self.build_payload().await?;
+        self.client.finish_block_builder(self.parents, payload).await
+    }
+
+    // Builds the payload for the block, a transaction if there are outputs, tagged data if there is a tag or none
+    async fn build_payload(&self) -> Result<Option<Payload>> {
         // tagged_data payload requires an tagged_data tag
         if self.data.is_some() && self.tag.is_none() {
             return Err(Error::MissingParameter("tag"));
@@ -323,32 +345,30 @@ impl<'a> ClientBlockBuilder<'a> {
             if self.secret_manager.is_none() && self.inputs.is_none() {
                 return Err(Error::MissingParameter("seed"));
             }
-            // Send block with transaction
+            // Block with transaction
             let prepared_transaction_data = self.prepare_transaction().await?;
-            let tx_payload = self.sign_transaction(prepared_transaction_data).await?;
-            self.finish_block(Some(tx_payload)).await
+            Ok(Some(self.sign_transaction(prepared_transaction_data).await?))
         } else if self.tag.is_some() {
-            // Send block with tagged_data payload
-            self.finish_tagged_data().await
+            // Block with tagged_data payload
+            Ok(Some(self.tagged_data_payload()?))
         } else {
-            // Send block without payload
-            self.finish_block(None).await
+            // Block without payload
+            Ok(None)
         }
     }
 
+    fn tagged_data_payload(&self) -> Result<Payload> {
+        let tag = self.tag.clone().ok_or(Error::MissingParameter("tag"))?;
+        let data = self.data.clone().unwrap_or_default();
+
+        Ok(Payload::from(
+            TaggedDataPayload::new(tag, data).map_err(|e| Error::TaggedData(e.to_string()))?,
+        ))
+    }
+
     /// Consume the builder and get the API result
     pub async fn finish_tagged_data(self) -> Result<Block> {
-        let payload: Payload;
-        {
-            let index = &self.tag.as_ref();
-            let empty_slice = &vec![];
-            let data = &self.data.as_ref().unwrap_or(empty_slice);
-
-            // build tagged_data
-            let index = TaggedDataPayload::new(index.expect("no tagged_data tag").to_vec(), (*data).clone())
-                .map_err(|e| Error::TaggedData(e.to_string()))?;
-            payload = Payload::from(index);
-        }
+        let payload = self.tagged_data_payload()?;
 
         // building block
         self.finish_block(Some(payload)).await
@@ -4,18 +4,29 @@
 //! PoW functions.
 
 #[cfg(not(target_family = "wasm"))]
-use crate::pow::miner::{Miner, MinerBuilder, MinerCancel};
+use crate::{
+    client::CancellationToken,
+    pow::miner::{Miner, MinerBuilder, MinerCancel},
+};
 #[cfg(target_family = "wasm")]
 use crate::pow::wasm_miner::{SingleThreadedMiner, SingleThreadedMinerBuilder};
 use crate::{
-    client::{Client, Error, Result},
+    client::{api::validate_payload_length, Client, Error, Result},
     types::block::{parent::Parents, payload::Payload, Block, BlockBuilder, Error as BlockError},
 };
 
 impl Client {
     /// Finishes the block with local PoW if needed.
     /// Without local PoW, it will finish the block with a 0 nonce.
+    /// The block is built once its submission slot is reached, see
+    /// [`ClientBuilder::with_submission_options()`](crate::client::ClientBuilder::with_submission_options()).
     pub async fn finish_block_builder(&self, parents: Option<Parents>, payload: Option<Payload>) -> Result<Block> {
+        // Fail before waiting and doing PoW for a block that can't be built
+        if let Some(payload) = &payload {
+            validate_payload_length(payload)?;
+        }
+        self.wait_for_submission_slot().await?;
+
         if self.get_local_pow() {
             self.finish_pow(parents, payload).await
         } else {
@@ -25,11 +36,17 @@ impl Client {
                 None => Parents::from_vec(self.get_tips().await?)?,
             };
 
-            Ok(BlockBuilder::new(parents).with_payload(payload).finish()?)
+            let protocol_parameters = self.get_protocol_parameters().await?;
+
+            Ok(BlockBuilder::new(parents)
+                .with_protocol_parameters(&protocol_parameters)
+                .with_payload(payload)
+                .finish()?)
         }
     }
 
     /// Calls the appropriate PoW function depending whether the compilation is for wasm or not.
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip_all))]
     pub async fn finish_pow(&self, parents: Option<Parents>, payload: Option<Payload>) -> Result<Block> {
         #[cfg(not(target_family = "wasm"))]
         let block = self.finish_multi_threaded_pow(parents, payload).await?;
@@ -45,10 +62,12 @@ impl Client {
     #[cfg(not(target_family = "wasm"))]
     async fn finish_multi_threaded_pow(&self, parents: Option<Parents>, payload: Option<Payload>) -> Result<Block> {
         let pow_worker_count = self.pow_worker_count;
-        let min_pow_score = self.get_min_pow_score().await?;
+        let protocol_version = self.get_protocol_version().await?;
+        let pow_score = self.get_target_pow_score().await?;
         let tips_interval = self.get_tips_interval();
 
         loop {
+            self.cancellation_token.check()?;
             let cancel = MinerCancel::new();
             let cancel_2 = cancel.clone();
             let payload_ = payload.clone();
@@ -56,13 +75,15 @@ impl Client {
                 Some(parents) => parents.clone(),
                 None => Parents::from_vec(self.get_tips().await?)?,
             };
-            let time_thread = std::thread::spawn(move || Ok(pow_timeout(tips_interval, cancel)));
+            let pow_start_time = instant::Instant::now();
+            let cancellation_token = self.cancellation_token.clone();
+            let time_thread = std::thread::spawn(move || Ok(pow_timeout(tips_interval, cancel, cancellation_token)));
             let pow_thread = std::thread::spawn(move || {
                 let mut client_miner = MinerBuilder::new().with_cancel(cancel_2);
                 if let Some(worker_count) = pow_worker_count {
                     client_miner = client_miner.with_num_workers(worker_count);
                 }
-                do_pow(client_miner.finish(), min_pow_score, payload_, parents).map(Some)
+                do_pow(client_miner.finish(), protocol_version, pow_score, payload_, parents).map(Some)
             });
 
             let threads = vec![pow_thread, time_thread];
@@ -71,6 +92,10 @@ impl Client {
                 match t.join().expect("failed to join threads.") {
                     Ok(block) => {
                         if let Some(block) = block {
+                            let pow_duration = pow_start_time.elapsed();
+                            self.pow_timings.record(pow_score, block.packed_len(), pow_duration);
+                            #[cfg(feature = "metrics")]
+                            crate::metrics::record_pow(pow_duration);
                             return Ok(block);
                         }
                     }
@@ -89,21 +114,34 @@ impl Client {
     /// Fetches new tips after each tips interval elapses if no parents are provided.
     #[cfg(target_family = "wasm")]
     async fn finish_single_threaded_pow(&self, parents: Option<Parents>, payload: Option<Payload>) -> Result<Block> {
-        let min_pow_score: u32 = self.get_min_pow_score().await?;
+        let protocol_version = self.get_protocol_version().await?;
+        let pow_score: u32 = self.get_target_pow_score().await?;
         let tips_interval: u64 = self.get_tips_interval();
 
         loop {
+            self.cancellation_token.check()?;
             let parents = match &parents {
                 Some(parents) => parents.clone(),
                 None => Parents::from_vec(self.get_tips().await?)?,
             };
 
+            let pow_start_time = instant::Instant::now();
             let single_threaded_miner = SingleThreadedMinerBuilder::new()
                 .with_timeout_in_seconds(tips_interval)
                 .finish();
 
-            match do_pow(single_threaded_miner, min_pow_score, payload.clone(), parents) {
+            match do_pow(
+                single_threaded_miner,
+                protocol_version,
+                pow_score,
+                payload.clone(),
+                parents,
+            ) {
                 Ok(block) => {
+                    let pow_duration = pow_start_time.elapsed();
+                    self.pow_timings.record(pow_score, block.packed_len(), pow_duration);
+                    #[cfg(feature = "metrics")]
+                    crate::metrics::record_pow(pow_duration);
                     return Ok(block);
                 }
                 Err(Error::Block(BlockError::NonceNotFound)) => {}
@@ -119,19 +157,25 @@ impl Client {
 fn do_pow(
     #[cfg(not(target_family = "wasm"))] miner: Miner,
     #[cfg(target_family = "wasm")] miner: SingleThreadedMiner,
+    protocol_version: u8,
     min_pow_score: u32,
     payload: Option<Payload>,
     parents: Parents,
 ) -> Result<Block> {
     Ok(BlockBuilder::new(parents)
+        .with_protocol_version(protocol_version)
         .with_payload(payload)
         .finish_nonce(|bytes| miner.nonce(bytes, min_pow_score))?)
 }
 
-// PoW timeout, if we reach this we will restart the PoW with new tips, so the final block will never be lazy.
+// PoW timeout, if we reach this we will restart the PoW with new tips, so the final block will never be lazy. The PoW
+// is also stopped once the client is cancelled.
 #[cfg(not(target_family = "wasm"))]
-fn pow_timeout(after_seconds: u64, cancel: MinerCancel) -> Option<Block> {
-    std::thread::sleep(std::time::Duration::from_secs(after_seconds));
+fn pow_timeout(after_seconds: u64, cancel: MinerCancel, cancellation_token: CancellationToken) -> Option<Block> {
+    let timeout = instant::Instant::now() + std::time::Duration::from_secs(after_seconds);
+    while instant::Instant::now() < timeout && !cancellation_token.is_cancelled() && !cancel.is_cancelled() {
+        std::thread::sleep(std::time::Duration::from_millis(100));
+    }
 
     cancel.trigger();
 
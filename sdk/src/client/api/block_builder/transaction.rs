@@ -18,7 +18,8 @@ use crate::{
             transaction::{RegularTransactionEssence, TransactionEssence, TransactionPayload},
             Payload, TaggedDataPayload,
         },
-        semantic::{semantic_validation, ConflictReason, ValidationContext},
+        protocol::ProtocolParameters,
+        semantic::{validate_transaction_payload, ConflictReason},
         signature::Ed25519Signature,
         Block, BlockId,
     },
@@ -33,10 +34,11 @@ const REFERENCE_ALIAS_NFT_UNLOCK_LENGTH: usize = 1 + 2;
 
 impl<'a> ClientBlockBuilder<'a> {
     /// Prepare a transaction
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip_all))]
     pub async fn prepare_transaction(&self) -> Result<PreparedTransactionData> {
         log::debug!("[prepare_transaction]");
-        let protocol_parameters = self.client.get_protocol_parameters().await?;
-        let token_supply = self.client.get_token_supply().await?;
+        let protocol_parameters = self.client.get_supported_protocol_parameters().await?;
+        let token_supply = protocol_parameters.token_supply();
 
         for output in &self.outputs {
             // Check if the outputs have enough amount to cover the storage deposit
@@ -53,7 +55,7 @@ impl<'a> ClientBlockBuilder<'a> {
         // Build transaction payload
         let inputs_commitment = InputsCommitment::new(selected_transaction_data.inputs.iter().map(|i| &i.output));
 
-        let mut essence = RegularTransactionEssence::builder(self.client.get_network_id().await?, inputs_commitment);
+        let mut essence = RegularTransactionEssence::builder(protocol_parameters.network_id(), inputs_commitment);
         let inputs = selected_transaction_data
             .inputs
             .iter()
@@ -74,23 +76,32 @@ impl<'a> ClientBlockBuilder<'a> {
             essence = essence.with_payload(tagged_data_payload);
         }
 
-        let regular_essence = essence.finish(&self.client.get_protocol_parameters().await?)?;
+        let regular_essence = essence.finish(&protocol_parameters)?;
 
         validate_regular_transaction_essence_length(&regular_essence)?;
 
         let essence = TransactionEssence::Regular(regular_essence);
 
-        Ok(PreparedTransactionData {
+        let prepared_transaction_data = PreparedTransactionData {
             essence,
             inputs_data: selected_transaction_data.inputs,
             remainder: selected_transaction_data.remainder,
-        })
+        };
+
+        prepared_transaction_data.verify_inputs_commitment()?;
+
+        Ok(prepared_transaction_data)
     }
 
     /// Sign the transaction
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip_all))]
     pub async fn sign_transaction(&self, prepared_transaction_data: PreparedTransactionData) -> Result<Payload> {
         log::debug!("[sign_transaction] {:?}", prepared_transaction_data);
         let secret_manager = self.secret_manager.ok_or(Error::MissingParameter("secret manager"))?;
+        verify_network_id(
+            &prepared_transaction_data.essence,
+            &self.client.get_protocol_parameters().await?,
+        )?;
         let current_time = self.client.get_time_checked().await?;
 
         let unlocks = secret_manager
@@ -111,34 +122,75 @@ impl<'a> ClientBlockBuilder<'a> {
     }
 }
 
-// TODO @thibault-martinez: this is very cumbersome with the current state, will refactor.
 /// Verifies the semantic of a prepared transaction.
 pub fn verify_semantic(
     input_signing_data: &[InputSigningData],
     transaction: &TransactionPayload,
     current_time: u32,
 ) -> crate::client::Result<ConflictReason> {
-    let transaction_id = transaction.id();
-    let TransactionEssence::Regular(essence) = transaction.essence();
-    let output_ids = input_signing_data.iter().map(|input| *input.output_id());
-    let outputs = input_signing_data
+    let inputs = input_signing_data
         .iter()
-        .map(|i| i.output.clone())
-        .collect::<Vec<Output>>();
-    let inputs = output_ids
-        .into_iter()
-        .zip(outputs.iter())
+        .map(|input| (*input.output_id(), &input.output))
         .collect::<Vec<(OutputId, &Output)>>();
 
-    let context = ValidationContext::new(
-        &transaction_id,
-        essence,
-        inputs.iter().map(|(id, input)| (id, *input)),
-        transaction.unlocks(),
-        current_time,
-    );
+    Ok(validate_transaction_payload(transaction, &inputs, current_time)?)
+}
+
+/// Verifies that a transaction essence is for the network of the protocol parameters, so it's not signed for another
+/// network, e.g. if it was prepared or imported while connected to another node.
+pub fn verify_network_id(essence: &TransactionEssence, protocol_parameters: &ProtocolParameters) -> Result<()> {
+    let TransactionEssence::Regular(essence) = essence;
+    if essence.network_id() != protocol_parameters.network_id() {
+        return Err(Error::TransactionNetworkIdMismatch {
+            expected: protocol_parameters.network_id(),
+            actual: essence.network_id(),
+        });
+    }
+    Ok(())
+}
+
+/// Rebuilds a transaction essence with the network id of the protocol parameters, keeping its inputs, outputs and
+/// payload. The transaction can only be accepted by the network if the inputs are still unspent in it, e.g. after the
+/// network id changed with a protocol upgrade.
+pub fn rebind_network(
+    essence: &TransactionEssence,
+    protocol_parameters: &ProtocolParameters,
+) -> Result<TransactionEssence> {
+    let TransactionEssence::Regular(essence) = essence;
+
+    Ok(TransactionEssence::Regular(
+        RegularTransactionEssence::builder(protocol_parameters.network_id(), *essence.inputs_commitment())
+            .with_inputs(essence.inputs().to_vec())
+            .with_outputs(essence.outputs().to_vec())
+            .with_payload(essence.payload().cloned())
+            .finish(protocol_parameters)?,
+    ))
+}
+
+/// Verifies that a payload doesn't exceed the block size limit with 8 parents, so the block can be built with any
+/// tips.
+pub fn validate_payload_length(payload: &Payload) -> Result<()> {
+    let length = payload.packed_len();
+    if length > MAX_TX_LENGTH_FOR_BLOCK_WITH_8_PARENTS {
+        return Err(Error::InvalidPayloadLength {
+            length,
+            max_length: MAX_TX_LENGTH_FOR_BLOCK_WITH_8_PARENTS,
+        });
+    }
+    Ok(())
+}
 
-    Ok(semantic_validation(context, inputs.as_slice(), transaction.unlocks())?)
+/// Verifies that a block doesn't exceed the block size limit, before it's submitted to a node.
+pub fn validate_block_length(block: &Block) -> Result<()> {
+    let length = block.packed_len();
+    if length > Block::LENGTH_MAX {
+        return Err(Error::InvalidBlockLength {
+            length,
+            max_length: Block::LENGTH_MAX,
+            payload_length: block.payload().map_or(0, Payload::packed_len),
+        });
+    }
+    Ok(())
 }
 
 /// Verifies that the transaction payload doesn't exceed the block size limit with 8 parents.
@@ -0,0 +1,102 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! High level APIs to anchor data in the Tangle and retrieve it again
+
+use std::collections::HashSet;
+
+use crypto::ciphers::chacha;
+
+use crate::{
+    client::{node_api::indexer::query_parameters::QueryParameter, Client, Error, Result},
+    types::block::{
+        payload::{transaction::TransactionEssence, Payload, TaggedDataPayload},
+        Block, BlockId,
+    },
+};
+
+impl Client {
+    /// Sends a block with a tagged data payload and returns it, without transferring any value.
+    pub async fn send_data(&self, tag: impl Into<Vec<u8>> + Send, data: impl Into<Vec<u8>> + Send) -> Result<Block> {
+        self.build_block()
+            .with_tag(tag.into())
+            .with_data(data.into())
+            .finish()
+            .await
+    }
+
+    /// Sends a block with a tagged data payload, the data gets encrypted with XChaCha20-Poly1305 before, so only
+    /// the holders of the key can read it. The tag stays unencrypted, so the block can still be found.
+    pub async fn send_encrypted_data(
+        &self,
+        tag: impl Into<Vec<u8>> + Send,
+        data: impl AsRef<[u8]> + Send,
+        key: &[u8; 32],
+    ) -> Result<Block> {
+        let encrypted_data = chacha::aead_encrypt(key, data.as_ref())?;
+
+        self.send_data(tag, encrypted_data).await
+    }
+
+    /// Returns the tagged data of a block, either the payload of the block or the one of its transaction.
+    pub async fn retrieve_data(&self, block_id: &BlockId) -> Result<TaggedDataPayload> {
+        let block = self.get_block(block_id).await?;
+
+        match block.payload() {
+            Some(Payload::TaggedData(tagged_data)) => Ok(*tagged_data.clone()),
+            Some(Payload::Transaction(transaction)) => {
+                let TransactionEssence::Regular(essence) = transaction.essence();
+                match essence.payload() {
+                    Some(Payload::TaggedData(tagged_data)) => Ok(*tagged_data.clone()),
+                    _ => Err(Error::TaggedData(format!("block {block_id} has no tagged data"))),
+                }
+            }
+            _ => Err(Error::TaggedData(format!("block {block_id} has no tagged data"))),
+        }
+    }
+
+    /// Returns the decrypted data of a block sent with [`Client::send_encrypted_data()`].
+    pub async fn retrieve_encrypted_data(&self, block_id: &BlockId, key: &[u8; 32]) -> Result<Vec<u8>> {
+        let tagged_data = self.retrieve_data(block_id).await?;
+
+        Ok(chacha::aead_decrypt(key, tagged_data.data())?)
+    }
+
+    /// Finds the blocks with transactions that created basic outputs with a tag feature matching the tag.
+    /// The node doesn't index blocks that only have a tagged data payload, these can only be retrieved by their id.
+    pub async fn find_blocks_by_tag(&self, tag: impl AsRef<[u8]> + Send) -> Result<Vec<BlockId>> {
+        let tag = prefix_hex::encode(tag.as_ref());
+        let output_ids = self.basic_output_ids(vec![QueryParameter::Tag(tag)]).await?;
+
+        let transaction_ids = output_ids
+            .items
+            .iter()
+            .map(|output_id| *output_id.transaction_id())
+            .collect::<HashSet<_>>();
+
+        let mut block_ids = Vec::with_capacity(transaction_ids.len());
+        for transaction_id in transaction_ids {
+            block_ids.push(self.get_included_block(&transaction_id).await?.id());
+        }
+
+        Ok(block_ids)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn data_futures_are_send() {
+        fn assert_send<T: Send>(_: &T) {}
+        // Only type checked, the helpers must be usable from the tasks of a multi-threaded runtime
+        let _ = |client: &Client, block_id: &BlockId| {
+            assert_send(&client.send_data("tag", "data"));
+            assert_send(&client.send_encrypted_data("tag", "data", &[0; 32]));
+            assert_send(&client.retrieve_data(block_id));
+            assert_send(&client.retrieve_encrypted_data(block_id, &[0; 32]));
+            assert_send(&client.find_blocks_by_tag("tag"));
+        };
+    }
+}
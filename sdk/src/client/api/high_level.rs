@@ -1,7 +1,9 @@
 // Copyright 2022 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use std::collections::HashSet;
+use std::{collections::HashSet, time::Duration};
+
+use futures::Stream;
 
 use crate::{
     client::{
@@ -15,10 +17,16 @@ use crate::{
         Client,
     },
     types::{
-        api::core::dto::LedgerInclusionStateDto,
+        api::core::{
+            dto::LedgerInclusionStateDto,
+            response::{ConfirmedMilestoneResponse, UtxoChanges},
+        },
         block::{
             input::{Input, UtxoInput, INPUT_COUNT_MAX},
-            output::{OutputId, OutputWithMetadata},
+            output::{
+                feature::{Irc27Metadata, Irc30Metadata},
+                FoundryId, NftId, Output, OutputId, OutputWithMetadata,
+            },
             parent::Parents,
             payload::{
                 transaction::{TransactionEssence, TransactionId},
@@ -62,6 +70,13 @@ impl Client {
         ClientBlockBuilder::new(self)
     }
 
+    /// A block builder to attach tagged data or a transaction to a block, with optional custom parents. Use
+    /// [`ClientBlockBuilder::build()`] to get the block without posting it, or [`ClientBlockBuilder::finish()`] to
+    /// post it.
+    pub fn build_block(&self) -> ClientBlockBuilder<'_> {
+        ClientBlockBuilder::new(self)
+    }
+
     /// Return a list of addresses from a secret manager regardless of their validity.
     pub fn get_addresses<'a>(&'a self, secret_manager: &'a SecretManager) -> GetAddressesBuilder<'a> {
         GetAddressesBuilder::new(secret_manager).with_client(self)
@@ -116,19 +131,9 @@ impl Client {
         // Reattached Blocks that get returned
         let mut blocks_with_id = Vec::new();
         for _ in 0..max_attempts.unwrap_or(DEFAULT_RETRY_UNTIL_INCLUDED_MAX_AMOUNT) {
-            #[cfg(target_family = "wasm")]
-            gloo_timers::future::TimeoutFuture::new(
-                (interval.unwrap_or(DEFAULT_RETRY_UNTIL_INCLUDED_INTERVAL) * 1000)
-                    .try_into()
-                    .unwrap(),
-            )
-            .await;
-
-            #[cfg(not(target_family = "wasm"))]
-            tokio::time::sleep(std::time::Duration::from_secs(
-                interval.unwrap_or(DEFAULT_RETRY_UNTIL_INCLUDED_INTERVAL),
-            ))
-            .await;
+            self.cancellation_token
+                .sleep(Duration::from_secs(interval.unwrap_or(DEFAULT_RETRY_UNTIL_INCLUDED_INTERVAL)))
+                .await?;
 
             // Check inclusion state for each attachment
             let block_ids_len = block_ids.len();
@@ -265,6 +270,28 @@ impl Client {
         Ok(output_responses.clone())
     }
 
+    /// Get the IRC30 metadata of the native token of a foundry, `None` if the foundry has no metadata. Fails if the
+    /// metadata doesn't follow the standard.
+    pub async fn foundry_metadata(&self, foundry_id: FoundryId) -> Result<Option<Irc30Metadata>> {
+        let output_id = self.foundry_output_id(foundry_id).await?;
+
+        match self.get_output(&output_id).await?.output() {
+            Output::Foundry(foundry) => Ok(foundry.irc_30_metadata()?),
+            _ => Err(Error::NotFound(format!("foundry {foundry_id}"))),
+        }
+    }
+
+    /// Get the IRC27 metadata of an NFT, `None` if the NFT has no metadata. Fails if the metadata doesn't follow the
+    /// standard.
+    pub async fn nft_metadata(&self, nft_id: NftId) -> Result<Option<Irc27Metadata>> {
+        let output_id = self.nft_output_id(nft_id).await?;
+
+        match self.get_output(&output_id).await?.output() {
+            Output::Nft(nft) => Ok(nft.irc_27_metadata()?),
+            _ => Err(Error::NotFound(format!("nft {nft_id}"))),
+        }
+    }
+
     /// Reattaches blocks for provided block id. Blocks can be reattached only if they are valid and haven't been
     /// confirmed for a while.
     pub async fn reattach(&self, block_id: &BlockId) -> Result<(BlockId, Block)> {
@@ -345,4 +372,75 @@ impl Client {
 
         Ok(current_time)
     }
+
+    /// Returns a stream of the confirmed milestones, polled from the node info every `interval`. A milestone is only
+    /// yielded if it's newer than the previous one, so milestones confirmed between two polls are skipped. Failed
+    /// requests are yielded as errors and polling continues. To get every milestone, subscribe to the
+    /// `milestone-info/confirmed` MQTT topic instead.
+    pub fn milestone_stream(&self, interval: Duration) -> impl Stream<Item = Result<ConfirmedMilestoneResponse>> + '_ {
+        futures::stream::unfold((None, true), move |(last_index, first_poll)| async move {
+            let mut first_poll = first_poll;
+            loop {
+                if !first_poll {
+                    #[cfg(target_family = "wasm")]
+                    gloo_timers::future::TimeoutFuture::new(interval.as_millis() as u32).await;
+                    #[cfg(not(target_family = "wasm"))]
+                    tokio::time::sleep(interval).await;
+                }
+                first_poll = false;
+
+                match self.get_info().await {
+                    Ok(info) => {
+                        let milestone = info.node_info.status.confirmed_milestone;
+                        if last_index < Some(milestone.index) {
+                            let index = milestone.index;
+                            return Some((Ok(milestone), (Some(index), false)));
+                        }
+                    }
+                    Err(e) => return Some((Err(e), (last_index, false))),
+                }
+            }
+        })
+    }
+
+    /// Returns a stream of the outputs that were created and consumed by every confirmed milestone, starting with the
+    /// milestone with `start_index` or, if it's `None`, the next one that is confirmed. Unlike
+    /// [`Client::milestone_stream()`], no milestone is skipped: the node info is polled every `interval` and the UTXO
+    /// changes of all milestones up to the confirmed one are requested in order. A failed request is yielded as an
+    /// error and repeated after the interval.
+    pub fn utxo_changes_stream(
+        &self,
+        start_index: Option<u32>,
+        interval: Duration,
+    ) -> impl Stream<Item = Result<UtxoChanges>> + '_ {
+        futures::stream::unfold(
+            (start_index, None, false),
+            move |(mut next_index, mut confirmed_index, mut wait)| async move {
+                loop {
+                    if wait {
+                        #[cfg(target_family = "wasm")]
+                        gloo_timers::future::TimeoutFuture::new(interval.as_millis() as u32).await;
+                        #[cfg(not(target_family = "wasm"))]
+                        tokio::time::sleep(interval).await;
+                    }
+
+                    if let Some(index) = next_index.filter(|index| confirmed_index >= Some(*index)) {
+                        return Some(match self.get_utxo_changes(index).await {
+                            Ok(utxo_changes) => (Ok(utxo_changes), (Some(index + 1), confirmed_index, false)),
+                            Err(e) => (Err(e), (next_index, confirmed_index, true)),
+                        });
+                    }
+
+                    match self.get_info().await {
+                        Ok(info) => {
+                            let index = info.node_info.status.confirmed_milestone.index;
+                            confirmed_index = Some(index);
+                            wait = *next_index.get_or_insert(index + 1) > index;
+                        }
+                        Err(e) => return Some((Err(e), (next_index, confirmed_index, true))),
+                    }
+                }
+            },
+        )
+    }
 }
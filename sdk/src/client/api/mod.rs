@@ -6,9 +6,11 @@
 mod address;
 mod block_builder;
 mod consolidation;
+mod data;
 mod high_level;
+mod tangle;
 mod types;
 
-pub use self::{address::*, block_builder::*, types::*};
+pub use self::{address::*, block_builder::*, tangle::*, types::*};
 
-const ADDRESS_GAP_RANGE: u32 = 20;
+pub(crate) const ADDRESS_GAP_RANGE: u32 = 20;
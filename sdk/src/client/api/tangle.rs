@@ -0,0 +1,147 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::{
+    collections::{HashSet, VecDeque},
+    str::FromStr,
+};
+
+use futures::Stream;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    client::{
+        constants::{DEFAULT_CONE_WALK_MAX_BLOCKS, DEFAULT_CONE_WALK_MAX_DEPTH},
+        Client, Error, Result,
+    },
+    types::{api::core::response::BlockMetadataResponse, block::BlockId},
+};
+
+/// The direction in which the tangle is traversed during a cone walk.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ConeDirection {
+    /// Follow the parents, to the blocks that are referenced by the start block.
+    #[default]
+    Past,
+    /// Follow the children, to the blocks that reference the start block.
+    Future,
+}
+
+/// Options for [`Client::walk_cone()`].
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConeWalkOptions {
+    /// The direction in which the tangle is traversed.
+    #[serde(default)]
+    pub direction: ConeDirection,
+    /// The max distance of the visited blocks from the start block, which has a depth of 0.
+    #[serde(default = "default_max_depth")]
+    pub max_depth: u32,
+    /// The max amount of visited blocks, including the start block.
+    #[serde(default = "default_max_blocks")]
+    pub max_blocks: usize,
+}
+
+fn default_max_depth() -> u32 {
+    DEFAULT_CONE_WALK_MAX_DEPTH
+}
+
+fn default_max_blocks() -> usize {
+    DEFAULT_CONE_WALK_MAX_BLOCKS
+}
+
+impl Default for ConeWalkOptions {
+    fn default() -> Self {
+        Self {
+            direction: ConeDirection::default(),
+            max_depth: DEFAULT_CONE_WALK_MAX_DEPTH,
+            max_blocks: DEFAULT_CONE_WALK_MAX_BLOCKS,
+        }
+    }
+}
+
+/// A block that was visited during a cone walk.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VisitedBlock {
+    /// The id of the block.
+    pub block_id: BlockId,
+    /// The distance of the block from the start block.
+    pub depth: u32,
+    /// The metadata of the block, with the index of the milestone that referenced it and, if the block contains a
+    /// milestone, the index of that milestone.
+    pub metadata: BlockMetadataResponse,
+}
+
+// The state of a cone walk between two visited blocks
+struct ConeWalk {
+    queue: VecDeque<(BlockId, u32)>,
+    seen: HashSet<BlockId>,
+    visited: usize,
+}
+
+impl Client {
+    /// Returns a stream of the blocks in the past or future cone of a block, visited breadth first, so blocks closer to
+    /// the start block come first. The walk stops when `max_blocks` blocks were visited, blocks further away than
+    /// `max_depth` are not visited. A block that fails to be requested is yielded as an error and the walk continues
+    /// without the blocks behind it.
+    pub fn walk_cone(
+        &self,
+        block_id: BlockId,
+        options: ConeWalkOptions,
+    ) -> impl Stream<Item = Result<VisitedBlock>> + '_ {
+        let walk = ConeWalk {
+            queue: VecDeque::from([(block_id, 0)]),
+            seen: HashSet::from([block_id]),
+            visited: 0,
+        };
+
+        futures::stream::unfold(walk, move |mut walk| {
+            let options = options.clone();
+            async move {
+                if walk.visited >= options.max_blocks {
+                    return None;
+                }
+                let (block_id, depth) = walk.queue.pop_front()?;
+                walk.visited += 1;
+
+                let visited_block = self.visit_block(block_id, depth, &options, &mut walk).await;
+                Some((visited_block, walk))
+            }
+        })
+    }
+
+    // Requests the metadata of a block and queues its parents or children
+    async fn visit_block(
+        &self,
+        block_id: BlockId,
+        depth: u32,
+        options: &ConeWalkOptions,
+        walk: &mut ConeWalk,
+    ) -> Result<VisitedBlock> {
+        let metadata = self.get_block_metadata(&block_id).await?;
+
+        if depth < options.max_depth {
+            let next_block_ids = match options.direction {
+                ConeDirection::Past => metadata
+                    .parents
+                    .iter()
+                    .map(|parent| BlockId::from_str(parent).map_err(Error::Block))
+                    .collect::<Result<Vec<_>>>()?,
+                ConeDirection::Future => self.get_block_children(&block_id).await?,
+            };
+            for next_block_id in next_block_ids {
+                if walk.seen.insert(next_block_id) {
+                    walk.queue.push_back((next_block_id, depth + 1));
+                }
+            }
+        }
+
+        Ok(VisitedBlock {
+            block_id,
+            depth,
+            metadata,
+        })
+    }
+}
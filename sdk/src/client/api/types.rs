@@ -80,6 +80,36 @@ impl PreparedTransactionData {
         })
     }
 
+    /// Verifies that the inputs data are the outputs of the inputs of the essence, in the same order, and that the
+    /// inputs commitment of the essence commits to them.
+    pub fn verify_inputs_commitment(&self) -> Result<(), Error> {
+        let TransactionEssence::Regular(essence) = &self.essence;
+
+        if essence.inputs().len() != self.inputs_data.len() {
+            return Err(Error::InputCountMismatch {
+                expected: essence.inputs().len(),
+                actual: self.inputs_data.len(),
+            });
+        }
+
+        for (index, (input, input_data)) in essence.inputs().iter().zip(&self.inputs_data).enumerate() {
+            // PANIC: the inputs of a regular essence are UTXO inputs.
+            let expected = input.as_utxo().output_id();
+
+            if expected != input_data.output_id() {
+                return Err(Error::InputOutputIdMismatch {
+                    index,
+                    expected: *expected,
+                    actual: *input_data.output_id(),
+                });
+            }
+        }
+
+        essence
+            .inputs_commitment()
+            .verify(self.inputs_data.iter().map(|input_data| &input_data.output))
+    }
+
     /// Unverified conversion from [`PreparedTransactionDataDto`] to [`PreparedTransactionData`].
     pub fn try_from_dto_unverified(value: &PreparedTransactionDataDto) -> Result<Self, Error> {
         Ok(Self {
@@ -169,6 +199,7 @@ pub struct RemainderData {
     /// The remainder output
     pub output: Output,
     /// The chain derived from seed, for the remainder addresses
+    #[serde(default, with = "crate::client::secret::types::chain_serde::option")]
     pub chain: Option<Chain>,
     /// The remainder address
     pub address: Address,
@@ -180,6 +211,7 @@ pub struct RemainderDataDto {
     /// The remainder output
     pub output: OutputDto,
     /// The chain derived from seed, for the remainder addresses
+    #[serde(default, with = "crate::client::secret::types::chain_serde::option")]
     pub chain: Option<Chain>,
     /// The remainder address
     pub address: AddressDto,
@@ -14,14 +14,17 @@ use tokio::runtime::Runtime;
 
 #[cfg(feature = "mqtt")]
 use crate::client::node_api::mqtt::{BrokerOptions, MqttEvent};
+#[cfg(feature = "test_utils")]
+use crate::client::test_utils::MockNode;
 use crate::{
     client::{
+        cache::{CacheOptions, ClientCache},
+        cancellation::CancellationToken,
         constants::{DEFAULT_API_TIMEOUT, DEFAULT_REMOTE_POW_API_TIMEOUT, DEFAULT_TIPS_INTERVAL},
         error::Result,
-        node_manager::{
-            builder::validate_url,
-            node::{Node, NodeAuth},
-        },
+        node_manager::node::NodeAuth,
+        request_journal::{RequestJournalEntry, RequestJournalOptions},
+        submission::{SubmissionOptions, SubmissionQueue, SubmissionWindow},
         Client,
     },
     types::block::{
@@ -49,6 +52,10 @@ pub struct NetworkInfo {
     pub tips_interval: u64,
     /// The latest cached milestone timestamp.
     pub latest_milestone_timestamp: Option<u32>,
+    /// Whether the protocol parameters were set with [`ClientBuilder::with_protocol_parameters()`], so they aren't
+    /// replaced by the ones of the nodes.
+    #[serde(default)]
+    pub protocol_parameters_overridden: bool,
 }
 
 /// Dto for the NetworkInfo
@@ -132,6 +139,12 @@ pub struct ClientBuilder {
     /// The amount of threads to be used for proof of work
     #[serde(default)]
     pub pow_worker_count: Option<usize>,
+    /// Options for the cache of immutable data like blocks, milestones and spent outputs, disabled if not set
+    #[serde(default)]
+    pub cache: Option<CacheOptions>,
+    /// Options for the submission of blocks, like a target PoW score and a max block rate
+    #[serde(default)]
+    pub submission_options: SubmissionOptions,
 }
 
 fn default_api_timeout() -> Duration {
@@ -151,6 +164,7 @@ impl Default for NetworkInfo {
             fallback_to_local_pow: true,
             tips_interval: DEFAULT_TIPS_INTERVAL,
             latest_milestone_timestamp: None,
+            protocol_parameters_overridden: false,
         }
     }
 }
@@ -165,6 +179,8 @@ impl Default for ClientBuilder {
             api_timeout: DEFAULT_API_TIMEOUT,
             remote_pow_timeout: DEFAULT_REMOTE_POW_API_TIMEOUT,
             pow_worker_count: None,
+            cache: None,
+            submission_options: SubmissionOptions::default(),
         }
     }
 }
@@ -179,26 +195,42 @@ impl ClientBuilder {
     #[allow(unused_assignments)]
     pub fn from_json(mut self, client_config: &str) -> Result<Self> {
         self = serde_json::from_str(client_config)?;
-        // validate URLs
-        if let Some(node_dto) = &self.node_manager_builder.primary_node {
-            let node: Node = node_dto.into();
-            validate_url(node.url)?;
+        self.node_manager_builder.validate_urls()?;
+        Ok(self)
+    }
+
+    /// Validates the options, so invalid ones are rejected with an error that names the option instead of failing
+    /// once the client is built or sends requests. Quorum options are only validated if quorum is enabled.
+    pub fn validate(&self) -> Result<()> {
+        self.node_manager_builder.validate()?;
+
+        let invalid = |option, reason: &str| crate::client::Error::InvalidClientOptions {
+            option,
+            reason: reason.to_string(),
+        };
+        if self.api_timeout.is_zero() {
+            return Err(invalid("apiTimeout", "must be greater than zero"));
         }
-        if let Some(node_dto) = &self.node_manager_builder.primary_pow_node {
-            let node: Node = node_dto.into();
-            validate_url(node.url)?;
+        if self.remote_pow_timeout.is_zero() {
+            return Err(invalid("remotePowTimeout", "must be greater than zero"));
         }
-        for node_dto in &self.node_manager_builder.nodes {
-            let node: Node = node_dto.into();
-            validate_url(node.url)?;
+        if self.network_info.tips_interval == 0 {
+            return Err(invalid("tipsInterval", "must be greater than zero"));
         }
-        if let Some(permanodes) = &self.node_manager_builder.permanodes {
-            for node_dto in permanodes {
-                let node: Node = node_dto.into();
-                validate_url(node.url)?;
+        if self.pow_worker_count == Some(0) {
+            return Err(invalid("powWorkerCount", "must be greater than zero"));
+        }
+        // Set protocol parameters aren't checked against a node, e.g. on private tangles with a custom HRP
+        if self.network_info.protocol_parameters_overridden {
+            let bech32_hrp = self.network_info.protocol_parameters.bech32_hrp();
+            if bech32_hrp.to_lowercase() != bech32_hrp
+                || ::bech32::encode(bech32_hrp, Vec::<::bech32::u5>::new(), ::bech32::Variant::Bech32).is_err()
+            {
+                return Err(invalid("protocolParameters", &format!("malformed bech32 hrp `{bech32_hrp}`")));
             }
         }
-        Ok(self)
+
+        Ok(())
     }
 
     /// Export the client builder as JSON string
@@ -256,6 +288,15 @@ impl ClientBuilder {
         self
     }
 
+    /// Builds a client that doesn't use any node, e.g. for offline signing. Local functionality like address
+    /// derivation, building outputs, storage deposit calculations, signing and packing works with the protocol
+    /// parameters of the builder, which can be set with [`ClientBuilder::with_protocol_parameters()`], and requests
+    /// fail with [`Error::OfflineMode`](crate::client::Error::OfflineMode).
+    pub fn offline(mut self) -> Self {
+        self.node_manager_builder = self.node_manager_builder.with_offline();
+        self
+    }
+
     /// Set if quorum should be used or not
     pub fn with_quorum(mut self, quorum: bool) -> Self {
         self.node_manager_builder = self.node_manager_builder.with_quorum(quorum);
@@ -326,13 +367,143 @@ impl ClientBuilder {
         self
     }
 
+    /// Adds a custom header which will be sent with every request, e.g. an API key for load-balanced nodes.
+    pub fn with_header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.node_manager_builder = self.node_manager_builder.with_header(name.into(), value.into());
+        self
+    }
+
+    /// Adds custom headers which will be sent with every request.
+    pub fn with_headers(mut self, headers: HashMap<String, String>) -> Self {
+        self.node_manager_builder = self.node_manager_builder.with_headers(headers);
+        self
+    }
+
+    /// Sets an HTTP(S) proxy which will be used for all requests, e.g. "http://proxy.example.com:8080". SOCKS proxies
+    /// aren't supported. Not available for wasm, where the browser settings apply.
+    pub fn with_proxy(mut self, url: &str) -> Result<Self> {
+        self.node_manager_builder = self.node_manager_builder.with_proxy(url)?;
+        Ok(self)
+    }
+
+    /// Sets a JWT which is sent as bearer token to all nodes, except the ones with their own JWT in their
+    /// authentication.
+    pub fn with_jwt(mut self, jwt: impl Into<String>) -> Self {
+        self.node_manager_builder = self.node_manager_builder.with_jwt(jwt.into());
+        self
+    }
+
+    /// Sets how often idempotent requests are retried on the same node with exponential backoff, or after the delay of
+    /// the Retry-After header, if the node responds with 429 (too many requests). Set to 0 to disable retries. After
+    /// server errors the request is sent to the next node instead.
+    pub fn with_max_retries(mut self, max_retries: u32) -> Self {
+        self.node_manager_builder = self.node_manager_builder.with_max_retries(max_retries);
+        self
+    }
+
+    /// Limits the requests per second that are sent to a single node.
+    pub fn with_max_requests_per_second(mut self, max_requests_per_second: u32) -> Self {
+        self.node_manager_builder = self
+            .node_manager_builder
+            .with_max_requests_per_second(max_requests_per_second);
+        self
+    }
+
+    /// Limits the requests that are sent to a single node at the same time, further requests wait until one finished.
+    pub fn with_max_parallel_requests_per_node(mut self, max_parallel_requests: usize) -> Self {
+        self.node_manager_builder = self
+            .node_manager_builder
+            .with_max_parallel_requests_per_node(max_parallel_requests);
+        self
+    }
+
+    /// Enables the journal of all requests to the nodes and their responses, to debug the interaction with the nodes,
+    /// see [`Client::request_journal()`].
+    pub fn with_request_journal(mut self, options: impl Into<Option<RequestJournalOptions>>) -> Self {
+        self.node_manager_builder = self.node_manager_builder.with_request_journal(options.into());
+        self
+    }
+
+    /// Replays a recorded request journal, the recorded responses are returned instead of sending requests to the
+    /// nodes. Requests are matched by method, path and query, so a bug report can be reproduced without the original
+    /// node, see [`request_journal`](crate::client::request_journal).
+    pub fn with_request_replay(mut self, entries: Vec<RequestJournalEntry>) -> Self {
+        self.node_manager_builder = self.node_manager_builder.with_request_replay(entries);
+        self
+    }
+
+    /// Sends all requests to an in-memory mock node instead of the network, so tests run hermetically, see
+    /// [`test_utils`](crate::client::test_utils). The mock node is added as node with the URL [`MockNode::URL`].
+    #[cfg(feature = "test_utils")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "test_utils")))]
+    pub fn with_mock_node(mut self, mock_node: MockNode) -> Result<Self> {
+        self.node_manager_builder = self
+            .node_manager_builder
+            .with_node(MockNode::URL)?
+            .with_mock_node(mock_node);
+        Ok(self)
+    }
+
+    /// Enables the cache for immutable data like blocks, milestones and spent outputs, so repeated lookups don't
+    /// require requests to the nodes.
+    pub fn with_cache(mut self, options: impl Into<Option<CacheOptions>>) -> Self {
+        self.cache = options.into();
+        self
+    }
+
+    /// Sets the protocol parameters, which are then used instead of the ones of the nodes, so transactions can be built
+    /// and signed without a node.
+    pub fn with_protocol_parameters(mut self, protocol_parameters: ProtocolParameters) -> Self {
+        self.network_info.protocol_parameters = protocol_parameters;
+        self.network_info.protocol_parameters_overridden = true;
+        self
+    }
+
+    /// Sets the options for the submission of blocks, to shape the throughput of services that send many blocks, e.g.
+    /// bulk payouts during congestion.
+    pub fn with_submission_options(mut self, options: SubmissionOptions) -> Self {
+        self.submission_options = options;
+        self
+    }
+
+    /// Sets a PoW score for the local PoW above the min PoW score of the network, so blocks are more likely to be
+    /// processed during congestion.
+    pub fn with_target_pow_score(mut self, target_pow_score: u32) -> Self {
+        self.submission_options.target_pow_score.replace(target_pow_score);
+        self
+    }
+
+    /// Limits the blocks that are built and submitted per second, further blocks wait for their slot.
+    pub fn with_max_blocks_per_second(mut self, max_blocks_per_second: u32) -> Self {
+        self.submission_options
+            .max_blocks_per_second
+            .replace(max_blocks_per_second);
+        self
+    }
+
+    /// Defers the submission of blocks to a time window, blocks wait until it starts and fail with
+    /// [`Error::SubmissionWindowClosed`](crate::client::Error::SubmissionWindowClosed) after it ended.
+    pub fn with_submission_window(mut self, window: SubmissionWindow) -> Self {
+        self.submission_options.window.replace(window);
+        self
+    }
+
     /// Build the Client instance.
     pub fn finish(self) -> Result<Client> {
         let network_info = Arc::new(RwLock::new(self.network_info));
         let healthy_nodes = Arc::new(RwLock::new(HashMap::new()));
-
+        let node_errors = Arc::new(RwLock::new(HashMap::new()));
+        let cancellation_token = CancellationToken::new();
+        let http_client = self
+            .node_manager_builder
+            .build_http_client()?
+            .with_cancellation_token(cancellation_token.clone());
+
+        // Without nodes there is nothing to sync
         #[cfg(not(target_family = "wasm"))]
-        let (runtime, sync_handle) = {
+        let (runtime, sync_handle) = if self.node_manager_builder.offline {
+            (None, None)
+        } else {
             let nodes = self
                 .node_manager_builder
                 .primary_node
@@ -342,25 +513,35 @@ impl ClientBuilder {
                 .collect();
 
             let healthy_nodes_ = healthy_nodes.clone();
+            let node_errors_ = node_errors.clone();
             let network_info_ = network_info.clone();
+            let http_client_ = http_client.clone();
+            let ignore_node_health = self.node_manager_builder.ignore_node_health;
+            let node_sync_interval = self.node_manager_builder.node_sync_interval;
 
             let (runtime, sync_handle) = std::thread::spawn(move || {
                 let runtime = Runtime::new().expect("failed to create Tokio runtime");
+                // Unreachable nodes are only left out of the healthy node pool, so a failing initial sync must not
+                // prevent the client from being created, the background sync process will pick the nodes up again.
                 if let Err(e) = runtime.block_on(Client::sync_nodes(
                     &healthy_nodes_,
+                    &node_errors_,
                     &nodes,
                     &network_info_,
-                    self.node_manager_builder.ignore_node_health,
+                    ignore_node_health,
+                    &http_client_,
                 )) {
-                    panic!("failed to sync nodes: {e:?}");
+                    log::warn!("Initial node syncing failed: {e}");
                 }
                 let sync_handle = Client::start_sync_process(
                     &runtime,
                     healthy_nodes_,
+                    node_errors_,
                     nodes,
-                    self.node_manager_builder.node_sync_interval,
+                    node_sync_interval,
                     network_info_,
-                    self.node_manager_builder.ignore_node_health,
+                    ignore_node_health,
+                    http_client_,
                 );
                 (runtime, sync_handle)
             })
@@ -372,7 +553,7 @@ impl ClientBuilder {
         #[cfg(feature = "mqtt")]
         let (mqtt_event_tx, mqtt_event_rx) = tokio::sync::watch::channel(MqttEvent::Connected);
         let client = Client {
-            node_manager: self.node_manager_builder.build(healthy_nodes),
+            node_manager: self.node_manager_builder.build(healthy_nodes, node_errors, http_client),
             #[cfg(not(target_family = "wasm"))]
             runtime,
             #[cfg(not(target_family = "wasm"))]
@@ -389,6 +570,10 @@ impl ClientBuilder {
             api_timeout: self.api_timeout,
             remote_pow_timeout: self.remote_pow_timeout,
             pow_worker_count: self.pow_worker_count,
+            cache: self.cache.map(|options| Arc::new(ClientCache::new(options))),
+            pow_timings: Default::default(),
+            submission_queue: Arc::new(SubmissionQueue::new(self.submission_options)),
+            cancellation_token,
         };
         Ok(client)
     }
@@ -0,0 +1,312 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! In-memory cache for immutable data requested from the nodes, like blocks, milestones and spent outputs.
+
+use std::{
+    collections::{BTreeMap, HashMap},
+    hash::Hash,
+    sync::Mutex,
+    time::Duration,
+};
+
+use instant::Instant;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    client::constants::DEFAULT_CACHE_CAPACITY,
+    types::block::{
+        output::{OutputId, OutputWithMetadata},
+        payload::{
+            milestone::{MilestoneId, MilestonePayload},
+            transaction::TransactionId,
+        },
+        Block, BlockId,
+    },
+};
+
+/// Options for the client cache.
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct CacheOptions {
+    /// Max amount of entries per cached type, the least recently used entries are evicted first.
+    #[serde(default = "default_capacity")]
+    pub capacity: usize,
+    /// How long entries stay valid, they don't expire if not set.
+    #[serde(default)]
+    pub ttl: Option<Duration>,
+}
+
+fn default_capacity() -> usize {
+    DEFAULT_CACHE_CAPACITY
+}
+
+impl Default for CacheOptions {
+    fn default() -> Self {
+        Self {
+            capacity: DEFAULT_CACHE_CAPACITY,
+            ttl: None,
+        }
+    }
+}
+
+/// Statistics of a single cache.
+#[derive(Clone, Copy, Debug, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct CacheStats {
+    /// Amount of lookups that were answered from the cache.
+    pub hits: u64,
+    /// Amount of lookups that required a request to a node.
+    pub misses: u64,
+    /// Amount of entries that were evicted because the capacity was reached.
+    pub evictions: u64,
+    /// Current amount of entries.
+    pub entries: usize,
+}
+
+impl CacheStats {
+    /// Returns the share of lookups that were answered from the cache, between 0 and 1.
+    pub fn hit_rate(&self) -> f64 {
+        let lookups = self.hits + self.misses;
+        if lookups == 0 {
+            0.0
+        } else {
+            self.hits as f64 / lookups as f64
+        }
+    }
+}
+
+/// Statistics of all client caches.
+#[derive(Clone, Copy, Debug, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct ClientCacheStats {
+    /// Blocks by their id.
+    pub blocks: CacheStats,
+    /// Ids of the blocks that included a transaction.
+    pub included_blocks: CacheStats,
+    /// Milestones by their id or index.
+    pub milestones: CacheStats,
+    /// Spent outputs, unspent outputs are never cached because they can still change.
+    pub spent_outputs: CacheStats,
+}
+
+/// The caches used by the client, all cached data is immutable once it's known to the node.
+#[derive(Debug)]
+pub(crate) struct ClientCache {
+    pub(crate) blocks: LruCache<BlockId, Block>,
+    pub(crate) included_blocks: LruCache<TransactionId, BlockId>,
+    pub(crate) milestones: LruCache<MilestoneId, MilestonePayload>,
+    pub(crate) milestone_ids: LruCache<u32, MilestoneId>,
+    pub(crate) spent_outputs: LruCache<OutputId, OutputWithMetadata>,
+}
+
+impl ClientCache {
+    pub(crate) fn new(options: CacheOptions) -> Self {
+        Self {
+            blocks: LruCache::new(options),
+            included_blocks: LruCache::new(options),
+            milestones: LruCache::new(options),
+            milestone_ids: LruCache::new(options),
+            spent_outputs: LruCache::new(options),
+        }
+    }
+
+    pub(crate) fn stats(&self) -> ClientCacheStats {
+        ClientCacheStats {
+            blocks: self.blocks.stats(),
+            included_blocks: self.included_blocks.stats(),
+            milestones: self.milestones.stats(),
+            spent_outputs: self.spent_outputs.stats(),
+        }
+    }
+
+    pub(crate) fn clear(&self) {
+        self.blocks.clear();
+        self.included_blocks.clear();
+        self.milestones.clear();
+        self.milestone_ids.clear();
+        self.spent_outputs.clear();
+    }
+}
+
+/// A size bound least recently used cache with an optional time to live for the entries.
+/// A poisoned lock is handled like an empty cache, so the data is requested from the node again.
+#[derive(Debug)]
+pub(crate) struct LruCache<K, V> {
+    inner: Mutex<LruCacheInner<K, V>>,
+}
+
+#[derive(Debug)]
+struct LruCacheInner<K, V> {
+    options: CacheOptions,
+    // Incremented on every access, to order the entries by their last access
+    tick: u64,
+    entries: HashMap<K, CacheEntry<V>>,
+    // Maps the last access to the key, the first entry is the least recently used one
+    recency: BTreeMap<u64, K>,
+    stats: CacheStats,
+}
+
+#[derive(Debug)]
+struct CacheEntry<V> {
+    value: V,
+    inserted_at: Instant,
+    last_access: u64,
+}
+
+impl<K: Clone + Eq + Hash, V: Clone> LruCache<K, V> {
+    pub(crate) fn new(options: CacheOptions) -> Self {
+        Self {
+            inner: Mutex::new(LruCacheInner {
+                options,
+                tick: 0,
+                entries: HashMap::new(),
+                recency: BTreeMap::new(),
+                stats: CacheStats::default(),
+            }),
+        }
+    }
+
+    /// Returns a clone of the cached value and marks it as recently used.
+    pub(crate) fn get(&self, key: &K) -> Option<V> {
+        let mut inner = self.inner.lock().ok()?;
+        let inner = &mut *inner;
+
+        let expired = match inner.entries.get(key) {
+            Some(entry) => inner.options.ttl.map_or(false, |ttl| entry.inserted_at.elapsed() > ttl),
+            None => {
+                inner.stats.misses += 1;
+                #[cfg(feature = "metrics")]
+                crate::metrics::record_cache_lookup(false);
+                return None;
+            }
+        };
+        if expired {
+            if let Some(entry) = inner.entries.remove(key) {
+                inner.recency.remove(&entry.last_access);
+            }
+            inner.stats.misses += 1;
+            #[cfg(feature = "metrics")]
+            crate::metrics::record_cache_lookup(false);
+            return None;
+        }
+
+        inner.tick += 1;
+        let entry = inner.entries.get_mut(key)?;
+        inner.recency.remove(&entry.last_access);
+        entry.last_access = inner.tick;
+        inner.recency.insert(inner.tick, key.clone());
+        inner.stats.hits += 1;
+        #[cfg(feature = "metrics")]
+        crate::metrics::record_cache_lookup(true);
+
+        Some(entry.value.clone())
+    }
+
+    /// Inserts a value, evicts the least recently used entry if the capacity is reached.
+    pub(crate) fn insert(&self, key: K, value: V) {
+        let Ok(mut inner) = self.inner.lock() else {
+            return;
+        };
+        let inner = &mut *inner;
+
+        if inner.options.capacity == 0 {
+            return;
+        }
+
+        if let Some(entry) = inner.entries.remove(&key) {
+            inner.recency.remove(&entry.last_access);
+        } else if inner.entries.len() >= inner.options.capacity {
+            if let Some(least_recently_used) = inner.recency.keys().next().copied() {
+                if let Some(evicted_key) = inner.recency.remove(&least_recently_used) {
+                    inner.entries.remove(&evicted_key);
+                    inner.stats.evictions += 1;
+                }
+            }
+        }
+
+        inner.tick += 1;
+        inner.recency.insert(inner.tick, key.clone());
+        inner.entries.insert(
+            key,
+            CacheEntry {
+                value,
+                inserted_at: Instant::now(),
+                last_access: inner.tick,
+            },
+        );
+    }
+
+    pub(crate) fn stats(&self) -> CacheStats {
+        self.inner.lock().map_or_else(
+            |_| CacheStats::default(),
+            |inner| CacheStats {
+                entries: inner.entries.len(),
+                ..inner.stats
+            },
+        )
+    }
+
+    pub(crate) fn clear(&self) {
+        if let Ok(mut inner) = self.inner.lock() {
+            inner.entries.clear();
+            inner.recency.clear();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lru_eviction() {
+        let cache = LruCache::new(CacheOptions {
+            capacity: 2,
+            ttl: None,
+        });
+
+        cache.insert(1, "a");
+        cache.insert(2, "b");
+        // Access 1, so 2 is the least recently used entry
+        assert_eq!(cache.get(&1), Some("a"));
+        cache.insert(3, "c");
+
+        assert_eq!(cache.get(&2), None);
+        assert_eq!(cache.get(&1), Some("a"));
+        assert_eq!(cache.get(&3), Some("c"));
+        assert_eq!(
+            cache.stats(),
+            CacheStats {
+                hits: 3,
+                misses: 1,
+                evictions: 1,
+                entries: 2,
+            }
+        );
+    }
+
+    #[test]
+    fn ttl_expiry() {
+        let cache = LruCache::new(CacheOptions {
+            capacity: 10,
+            ttl: Some(Duration::ZERO),
+        });
+
+        cache.insert(1, "a");
+        std::thread::sleep(Duration::from_millis(1));
+
+        assert_eq!(cache.get(&1), None);
+        assert_eq!(cache.stats().entries, 0);
+    }
+
+    #[test]
+    fn zero_capacity() {
+        let cache = LruCache::new(CacheOptions { capacity: 0, ttl: None });
+
+        cache.insert(1, "a");
+
+        assert_eq!(cache.get(&1), None);
+        assert_eq!(cache.stats().entries, 0);
+    }
+}
@@ -0,0 +1,127 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Cancellation of the long-running operations of a client, so applications can shut down within a deadline.
+//!
+//! Once the [`CancellationToken`] of a client is cancelled, node requests in flight are aborted and node requests,
+//! local PoW, retries until a block is included and the MQTT event loop fail or stop with [`Error::Cancelled`]. The
+//! cancellation can't be undone, a new client needs to be created to continue.
+
+use std::{
+    future::Future,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
+
+use tokio::sync::Notify;
+
+use crate::client::{Error, Result};
+
+/// A handle to cancel the long-running operations of a client, clones cancel the same operations.
+#[derive(Clone, Debug, Default)]
+pub struct CancellationToken(Arc<CancellationTokenInner>);
+
+#[derive(Debug, Default)]
+struct CancellationTokenInner {
+    cancelled: AtomicBool,
+    notify: Notify,
+}
+
+impl CancellationToken {
+    /// Creates a token that isn't cancelled.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Cancels the operations, operations that are waiting are woken up.
+    pub fn cancel(&self) {
+        self.0.cancelled.store(true, Ordering::SeqCst);
+        self.0.notify.notify_waiters();
+    }
+
+    /// Returns true if the token was cancelled.
+    pub fn is_cancelled(&self) -> bool {
+        self.0.cancelled.load(Ordering::SeqCst)
+    }
+
+    /// Waits until the token is cancelled.
+    pub async fn cancelled(&self) {
+        // Created before checking the flag, so a cancellation in between isn't missed
+        let notified = self.0.notify.notified();
+        if self.is_cancelled() {
+            return;
+        }
+        notified.await;
+    }
+
+    /// Fails with [`Error::Cancelled`] if the token was cancelled.
+    pub(crate) fn check(&self) -> Result<()> {
+        if self.is_cancelled() {
+            return Err(Error::Cancelled);
+        }
+        Ok(())
+    }
+
+    /// Runs the future until it completes or the token is cancelled, then the future is dropped.
+    pub(crate) async fn run_until_cancelled<F: Future + Send>(&self, future: F) -> Result<F::Output> {
+        self.check()?;
+        tokio::select! {
+            biased;
+            _ = self.cancelled() => Err(Error::Cancelled),
+            output = future => Ok(output),
+        }
+    }
+
+    /// Sleeps for the duration, fails early with [`Error::Cancelled`] if the token is cancelled.
+    pub(crate) async fn sleep(&self, duration: Duration) -> Result<()> {
+        #[cfg(target_family = "wasm")]
+        let sleep = gloo_timers::future::TimeoutFuture::new(duration.as_millis() as u32);
+        #[cfg(not(target_family = "wasm"))]
+        let sleep = tokio::time::sleep(duration);
+
+        self.run_until_cancelled(sleep).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn cancel() {
+        let token = CancellationToken::new();
+        let clone = token.clone();
+        assert!(!token.is_cancelled());
+        assert!(token.check().is_ok());
+
+        let waiting = tokio::spawn(async move { clone.sleep(Duration::from_secs(60)).await });
+        token.cancel();
+
+        assert!(matches!(waiting.await.unwrap(), Err(Error::Cancelled)));
+        assert!(token.is_cancelled());
+        assert!(matches!(token.check(), Err(Error::Cancelled)));
+        assert!(matches!(
+            token.run_until_cancelled(async { 42 }).await,
+            Err(Error::Cancelled)
+        ));
+        // Doesn't wait once cancelled
+        token.cancelled().await;
+    }
+
+    #[tokio::test]
+    async fn run_until_cancelled() {
+        let token = CancellationToken::new();
+        assert_eq!(token.run_until_cancelled(async { 42 }).await.unwrap(), 42);
+        assert!(token.sleep(Duration::from_millis(1)).await.is_ok());
+    }
+
+    #[test]
+    fn run_until_cancelled_is_send() {
+        fn assert_send<T: Send>(_: &T) {}
+        // Not polled, the cancellable requests must be sendable from the tasks of a multi-threaded runtime
+        assert_send(&CancellationToken::new().run_until_cancelled(async {}));
+    }
+}
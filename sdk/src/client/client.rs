@@ -22,11 +22,18 @@ use crate::client::constants::CACHE_NETWORK_INFO_TIMEOUT_IN_SECONDS;
 use crate::{
     client::{
         builder::{ClientBuilder, NetworkInfo},
+        cache::{ClientCache, ClientCacheStats},
+        cancellation::CancellationToken,
         constants::DEFAULT_TIPS_INTERVAL,
         error::Result,
+        pow_advisory::PowTimings,
+        submission::SubmissionQueue,
         Error,
     },
-    types::block::{output::RentStructure, protocol::ProtocolParameters},
+    types::block::{
+        output::{Output, RentStructure},
+        protocol::{verify_protocol_version, ProtocolParameters},
+    },
 };
 
 /// An instance of the client using HORNET or Bee URI
@@ -57,6 +64,14 @@ pub struct Client {
     #[allow(dead_code)] // not used for wasm
     /// pow_worker_count for local PoW.
     pub(crate) pow_worker_count: Option<usize>,
+    /// Cache for immutable data, if enabled.
+    pub(crate) cache: Option<Arc<ClientCache>>,
+    /// Timings of the most recent local PoW.
+    pub(crate) pow_timings: Arc<PowTimings>,
+    /// Submission slots of the blocks.
+    pub(crate) submission_queue: Arc<SubmissionQueue>,
+    /// Cancels the long-running operations of the client.
+    pub(crate) cancellation_token: CancellationToken,
 }
 
 impl std::fmt::Debug for Client {
@@ -121,13 +136,18 @@ impl Client {
                 static ref LAST_SYNC: std::sync::Mutex<Option<u32>> = std::sync::Mutex::new(None);
             };
             let current_time = crate::utils::unix_timestamp_now().as_secs() as u32;
+            let network_info = self
+                .network_info
+                .read()
+                .map_err(|_| crate::client::Error::PoisonError)?
+                .clone();
+            // Protocol parameters that were set don't need to be requested, so no node is needed
+            if network_info.protocol_parameters_overridden || self.node_manager.offline {
+                return Ok(network_info);
+            }
             if let Some(last_sync) = *LAST_SYNC.lock().unwrap() {
                 if current_time < last_sync {
-                    return Ok(self
-                        .network_info
-                        .read()
-                        .map_err(|_| crate::client::Error::PoisonError)?
-                        .clone());
+                    return Ok(network_info);
                 }
             }
             let info = self.get_info().await?.node_info;
@@ -135,7 +155,9 @@ impl Client {
                 .network_info
                 .write()
                 .map_err(|_| crate::client::Error::PoisonError)?;
-            client_network_info.protocol_parameters = info.protocol.try_into()?;
+            let protocol_parameters: ProtocolParameters = info.protocol.try_into()?;
+            verify_protocol_version(protocol_parameters.protocol_version())?;
+            client_network_info.protocol_parameters = protocol_parameters;
 
             *LAST_SYNC.lock().unwrap() = Some(current_time + CACHE_NETWORK_INFO_TIMEOUT_IN_SECONDS);
         }
@@ -147,11 +169,21 @@ impl Client {
             .clone())
     }
 
-    /// Gets the protocol parameters of the node we're connecting to.
+    /// Gets the protocol parameters of the node we're connecting to, cached until the next node sync, or the ones set
+    /// with [`ClientBuilder::with_protocol_parameters()`].
     pub async fn get_protocol_parameters(&self) -> Result<ProtocolParameters> {
         Ok(self.get_network_info().await?.protocol_parameters)
     }
 
+    /// Gets the protocol parameters to build and sign transactions with. Fails with
+    /// [`UnsupportedProtocolVersion`](crate::types::block::Error::UnsupportedProtocolVersion) if their protocol
+    /// version isn't supported, as the blocks would be invalid.
+    pub async fn get_supported_protocol_parameters(&self) -> Result<ProtocolParameters> {
+        let protocol_parameters = self.get_protocol_parameters().await?;
+        verify_protocol_version(protocol_parameters.protocol_version())?;
+        Ok(protocol_parameters)
+    }
+
     /// Gets the protocol version of the node we're connecting to.
     pub async fn get_protocol_version(&self) -> Result<u8> {
         Ok(self.get_network_info().await?.protocol_parameters.protocol_version())
@@ -192,6 +224,24 @@ impl Client {
         Ok(self.get_network_info().await?.protocol_parameters.token_supply())
     }
 
+    /// Returns the minimum storage deposit for an output, according to the rent structure of the node we're connecting
+    /// to.
+    pub async fn minimum_storage_deposit(&self, output: &Output) -> Result<u64> {
+        Ok(self.get_rent_structure().await?.minimum_storage_deposit(output))
+    }
+
+    /// Returns the statistics of the client cache, if it's enabled.
+    pub fn cache_stats(&self) -> Option<ClientCacheStats> {
+        self.cache.as_ref().map(|cache| cache.stats())
+    }
+
+    /// Removes all entries from the client cache.
+    pub fn clear_cache(&self) {
+        if let Some(cache) = &self.cache {
+            cache.clear();
+        }
+    }
+
     /// returns the tips interval
     pub fn get_tips_interval(&self) -> u64 {
         self.network_info
@@ -199,6 +249,17 @@ impl Client {
             .map_or(DEFAULT_TIPS_INTERVAL, |info| info.tips_interval)
     }
 
+    /// Returns if the client was built with [`ClientBuilder::offline()`], so it doesn't use any node.
+    pub fn is_offline(&self) -> bool {
+        self.node_manager.offline
+    }
+
+    /// Returns the token to cancel the long-running operations of the client and its clones when shutting down, see
+    /// [`cancellation`](crate::client::cancellation). The client can't be used anymore once it's cancelled.
+    pub fn cancellation_token(&self) -> &CancellationToken {
+        &self.cancellation_token
+    }
+
     /// returns if local pow should be used or not
     pub fn get_local_pow(&self) -> bool {
         self.network_info
@@ -206,6 +267,14 @@ impl Client {
             .map_or(NetworkInfo::default().local_pow, |info| info.local_pow)
     }
 
+    /// Returns a clone of the client which uses the timeout for its node API requests instead of the one set with
+    /// [`ClientBuilder::with_api_timeout()`], e.g. for single requests that are known to take longer.
+    pub fn with_timeout(&self, timeout: Duration) -> Self {
+        let mut client = self.clone();
+        client.api_timeout = timeout;
+        client
+    }
+
     pub(crate) fn get_timeout(&self) -> Duration {
         self.api_timeout
     }
@@ -0,0 +1,284 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Compatibility with the address derivation of other wallets.
+//!
+//! Wallets don't all derive their addresses the same way from a mnemonic: profiles that were created for IOTA use the
+//! IOTA coin type also on Shimmer, e.g. to claim the Shimmer airdrop, and single address wallets derive a new account
+//! for every address instead of a new address index. A user migrating such a mnemonic only sees the addresses of the
+//! standard derivation and seems to have lost funds. The functions of this module derive the addresses of the known
+//! [`DerivationScheme`]s, find the scheme of an address and scan the addresses of all schemes for funds.
+//! ```ignore
+//! let schemes = DerivationScheme::known(SHIMMER_COIN_TYPE);
+//! for funded in client.scan_derivation_schemes(&secret_manager, &schemes, &CompatScanOptions::default()).await? {
+//!     println!("{} holds {} on {}", funded.bech32_address, funded.amount, funded.derived.path);
+//! }
+//! ```
+
+use std::{collections::HashSet, fmt, ops::Range};
+
+use crypto::keys::slip10::Chain;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    client::{
+        constants::{HD_WALLET_TYPE, IOTA_COIN_TYPE, SHIMMER_COIN_TYPE},
+        node_api::indexer::query_parameters::QueryParameter,
+        secret::{GenerateAddressOptions, SecretManage, SecretManager},
+        Client, Result,
+    },
+    types::block::{address::Address, output::OutputId},
+};
+
+/// A way to derive addresses from a seed.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum DerivationScheme {
+    /// `m/44'/coin_type'/account_index'/change'/address_index'`, used by this SDK and Firefly. Both the public and the
+    /// internal (change) addresses are derived.
+    #[serde(rename_all = "camelCase")]
+    Bip44 { coin_type: u32 },
+    /// Every address is the first address of its own account: `m/44'/coin_type'/address_index'/0'/0'`, used by single
+    /// address wallets.
+    #[serde(rename_all = "camelCase")]
+    SingleAddressAccounts { coin_type: u32 },
+}
+
+impl DerivationScheme {
+    /// Returns the known schemes of wallets for a network with `coin_type`, starting with the standard one. IOTA and
+    /// Shimmer also include the schemes with the coin type of the other network.
+    pub fn known(coin_type: u32) -> Vec<Self> {
+        let mut coin_types = vec![coin_type];
+        match coin_type {
+            IOTA_COIN_TYPE => coin_types.push(SHIMMER_COIN_TYPE),
+            SHIMMER_COIN_TYPE => coin_types.push(IOTA_COIN_TYPE),
+            _ => {}
+        }
+
+        coin_types
+            .iter()
+            .map(|&coin_type| Self::Bip44 { coin_type })
+            .chain(
+                coin_types
+                    .iter()
+                    .map(|&coin_type| Self::SingleAddressAccounts { coin_type }),
+            )
+            .collect()
+    }
+
+    /// Returns the coin type of the scheme.
+    pub fn coin_type(&self) -> u32 {
+        match self {
+            Self::Bip44 { coin_type } | Self::SingleAddressAccounts { coin_type } => *coin_type,
+        }
+    }
+}
+
+/// The BIP-44 path of a derived address, all levels are hardened.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DerivationPath {
+    pub coin_type: u32,
+    pub account_index: u32,
+    pub internal: bool,
+    pub address_index: u32,
+}
+
+impl DerivationPath {
+    /// Returns the chain of the path, to sign with the address.
+    pub fn chain(&self) -> Chain {
+        Chain::from_u32_hardened(vec![
+            HD_WALLET_TYPE,
+            self.coin_type,
+            self.account_index,
+            self.internal as u32,
+            self.address_index,
+        ])
+    }
+}
+
+impl fmt::Display for DerivationPath {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "m/{HD_WALLET_TYPE}'/{}'/{}'/{}'/{}'",
+            self.coin_type, self.account_index, self.internal as u32, self.address_index
+        )
+    }
+}
+
+/// The indexes that are derived for every scheme.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CompatScanOptions {
+    /// The account indexes of the [`DerivationScheme::Bip44`] addresses.
+    pub account_indexes: Range<u32>,
+    /// The address indexes of the [`DerivationScheme::Bip44`] addresses, the account indexes of the
+    /// [`DerivationScheme::SingleAddressAccounts`] addresses.
+    pub address_indexes: Range<u32>,
+}
+
+impl Default for CompatScanOptions {
+    fn default() -> Self {
+        Self {
+            account_indexes: 0..5,
+            address_indexes: 0..super::api::ADDRESS_GAP_RANGE,
+        }
+    }
+}
+
+/// An address with the scheme and path it was derived with.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DerivedAddress {
+    pub scheme: DerivationScheme,
+    pub path: DerivationPath,
+    pub address: Address,
+}
+
+/// A derived address that holds funds.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FundedAddress {
+    pub derived: DerivedAddress,
+    pub bech32_address: String,
+    /// The basic outputs of the address.
+    pub output_ids: Vec<OutputId>,
+    /// The summed amount of the outputs.
+    pub amount: u64,
+}
+
+/// Derives the addresses of the schemes in their order. An address that two schemes have in common, like the first
+/// address of an account, is only returned for the first scheme.
+pub async fn derive_addresses(
+    secret_manager: &SecretManager,
+    schemes: &[DerivationScheme],
+    options: &CompatScanOptions,
+) -> Result<Vec<DerivedAddress>> {
+    let mut derived = Vec::new();
+    let mut seen = HashSet::new();
+
+    for &scheme in schemes {
+        let mut paths = Vec::new();
+
+        match scheme {
+            DerivationScheme::Bip44 { coin_type } => {
+                for account_index in options.account_indexes.clone() {
+                    for internal in [false, true] {
+                        let addresses = secret_manager
+                            .generate_addresses(
+                                coin_type,
+                                account_index,
+                                options.address_indexes.clone(),
+                                Some(GenerateAddressOptions {
+                                    internal,
+                                    ..Default::default()
+                                }),
+                            )
+                            .await?;
+                        for (address_index, address) in options.address_indexes.clone().zip(addresses) {
+                            let path = DerivationPath {
+                                coin_type,
+                                account_index,
+                                internal,
+                                address_index,
+                            };
+                            paths.push((path, address));
+                        }
+                    }
+                }
+            }
+            DerivationScheme::SingleAddressAccounts { coin_type } => {
+                for account_index in options.address_indexes.clone() {
+                    let address = secret_manager
+                        .generate_addresses(coin_type, account_index, 0..1, None)
+                        .await?
+                        .remove(0);
+                    paths.push((
+                        DerivationPath {
+                            coin_type,
+                            account_index,
+                            internal: false,
+                            address_index: 0,
+                        },
+                        address,
+                    ));
+                }
+            }
+        }
+
+        derived.extend(
+            paths
+                .into_iter()
+                .filter(|(_, address)| seen.insert(*address))
+                .map(|(path, address)| DerivedAddress { scheme, path, address }),
+        );
+    }
+
+    Ok(derived)
+}
+
+/// Returns how `address` was derived from the secret manager, `None` if none of the schemes derives it within the
+/// indexes of the options.
+pub async fn find_derivation(
+    secret_manager: &SecretManager,
+    address: &Address,
+    schemes: &[DerivationScheme],
+    options: &CompatScanOptions,
+) -> Result<Option<DerivedAddress>> {
+    // Scheme by scheme, so the more common ones don't wait for the derivation of the others
+    for scheme in schemes {
+        let found = derive_addresses(secret_manager, std::slice::from_ref(scheme), options)
+            .await?
+            .into_iter()
+            .find(|derived| &derived.address == address);
+
+        if found.is_some() {
+            return Ok(found);
+        }
+    }
+
+    Ok(None)
+}
+
+impl Client {
+    /// Derives the addresses of the schemes and returns the ones that hold basic outputs, e.g. to find the funds of a
+    /// mnemonic that was used in another wallet.
+    pub async fn scan_derivation_schemes(
+        &self,
+        secret_manager: &SecretManager,
+        schemes: &[DerivationScheme],
+        options: &CompatScanOptions,
+    ) -> Result<Vec<FundedAddress>> {
+        let bech32_hrp = self.get_bech32_hrp().await?;
+        let mut funded = Vec::new();
+
+        for derived in derive_addresses(secret_manager, schemes, options).await? {
+            let bech32_address = derived.address.to_bech32(&bech32_hrp);
+            let output_ids = self
+                .basic_output_ids(vec![QueryParameter::Address(bech32_address.clone())])
+                .await?
+                .items;
+
+            if output_ids.is_empty() {
+                continue;
+            }
+
+            let amount = self
+                .get_outputs(output_ids.clone())
+                .await?
+                .iter()
+                .map(|output| output.output().amount())
+                .sum();
+
+            funded.push(FundedAddress {
+                derived,
+                bech32_address,
+                output_ids,
+                amount,
+            });
+        }
+
+        Ok(funded)
+    }
+}
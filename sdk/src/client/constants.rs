@@ -15,10 +15,32 @@ pub(crate) const DEFAULT_RETRY_UNTIL_INCLUDED_MAX_AMOUNT: u64 = 40;
 pub(crate) const DEFAULT_TIPS_INTERVAL: u64 = 5;
 /// Interval in which the node info will be requested and healthy nodes will be added to the healthy node pool
 pub(crate) const NODE_SYNC_INTERVAL: Duration = Duration::from_secs(60);
+/// Interval in which the outputs of an address are requested while waiting for funds from a faucet
+pub(crate) const FAUCET_POLL_INTERVAL: Duration = Duration::from_secs(2);
 pub(crate) const DEFAULT_MIN_QUORUM_SIZE: usize = 3;
 pub(crate) const DEFAULT_QUORUM_THRESHOLD: usize = 66;
+/// Default amount of retries for idempotent requests, if a node responds with 429
+pub(crate) const DEFAULT_MAX_RETRIES: u32 = 3;
+/// Base delay for the exponential backoff between retries
+pub(crate) const DEFAULT_RETRY_BASE_DELAY: Duration = Duration::from_millis(250);
+/// Max delay between two retries, also used to limit the delay requested by a node with a Retry-After header
+pub(crate) const MAX_RETRY_DELAY: Duration = Duration::from_secs(10);
+/// Default amount of entries per cache, if the client cache is enabled
+pub(crate) const DEFAULT_CACHE_CAPACITY: usize = 1000;
+/// Amount of recent local PoW timings that are kept to estimate the PoW capacity
+pub(crate) const POW_TIMING_SAMPLES: usize = 20;
+/// Default amount of entries the request journal keeps in memory, if it's enabled
+pub(crate) const DEFAULT_REQUEST_JOURNAL_ENTRIES: usize = 1000;
+/// Default max length of the request and response bodies that are recorded in the request journal
+pub(crate) const DEFAULT_REQUEST_JOURNAL_BODY_LENGTH: usize = 10_000;
+/// Default max size of the request journal file before it's rotated
+pub(crate) const DEFAULT_REQUEST_JOURNAL_FILE_SIZE: u64 = 10 * 1024 * 1024;
+/// Default max distance of the blocks that are visited during a cone walk from the start block
+pub(crate) const DEFAULT_CONE_WALK_MAX_DEPTH: u32 = 10;
+/// Default max amount of blocks that are visited during a cone walk
+pub(crate) const DEFAULT_CONE_WALK_MAX_BLOCKS: usize = 1000;
 pub(crate) const DEFAULT_USER_AGENT: &str = concat!(env!("CARGO_PKG_NAME"), "/", env!("CARGO_PKG_VERSION"));
-#[cfg(not(target_family = "wasm"))]
+/// Max amount of requests that are sent at the same time when multiple outputs are requested
 pub(crate) const MAX_PARALLEL_API_REQUESTS: usize = 100;
 /// Max allowed difference between the local time and latest milestone time, 5 minutes in seconds
 pub(crate) const FIVE_MINUTES_IN_SECONDS: u32 = 300;
@@ -41,3 +63,5 @@ pub const HD_WALLET_TYPE: u32 = 44;
 pub const IOTA_COIN_TYPE: u32 = 4218;
 /// Shimmer coin type <https://github.com/satoshilabs/slips/blob/master/slip-0044.md>
 pub const SHIMMER_COIN_TYPE: u32 = 4219;
+/// Ether coin type <https://github.com/satoshilabs/slips/blob/master/slip-0044.md>, used by EVM wallets
+pub const ETHER_COIN_TYPE: u32 = 60;
@@ -13,7 +13,7 @@ use serde::{
 
 use crate::{
     client::{api::input_selection::Error as InputSelectionError, node_api::indexer::QueryParameter},
-    types::block::semantic::ConflictReason,
+    types::block::{output::OutputId, payload::transaction::TransactionId, semantic::ConflictReason, BlockId},
 };
 
 /// Type alias of `Result` in iota-client
@@ -31,12 +31,18 @@ pub enum Error {
     /// Block types error
     #[error("{0}")]
     Block(#[from] crate::types::block::Error),
+    /// The operation was cancelled with the cancellation token of the client
+    #[error("the operation was cancelled")]
+    Cancelled,
     /// The wallet account has enough funds, but split on too many outputs
     #[error("the wallet account has enough funds, but split on too many outputs: {0}, max. is 128, consolidate them")]
     ConsolidationRequired(usize),
     /// Crypto.rs error
     #[error("{0}")]
     Crypto(#[from] crypto::Error),
+    /// The funds requested from a faucet didn't arrive in time
+    #[error("funds requested from the faucet for {0} didn't arrive in time")]
+    FaucetTimeout(String),
     /// Address not found
     #[error("address: {address} not found in range: {range}")]
     InputAddressNotFound {
@@ -59,9 +65,35 @@ pub enum Error {
         /// The expected bech32 human readable part.
         expected: String,
     },
+    /// The block is too large
+    #[error("the block is too large: {length}/{max_length} bytes, of which the payload has {payload_length}")]
+    InvalidBlockLength {
+        /// The found length.
+        length: usize,
+        /// The max length.
+        max_length: usize,
+        /// The length of the payload of the block, 0 without payload.
+        payload_length: usize,
+    },
+    /// An option of the client builder is invalid
+    #[error("invalid client option `{option}`: {reason}")]
+    InvalidClientOptions {
+        /// The name of the invalid option.
+        option: &'static str,
+        /// Why the option is invalid.
+        reason: String,
+    },
     /// Invalid mnemonic error
     #[error("invalid mnemonic {0}")]
     InvalidMnemonic(String),
+    /// The payload is too large for a block
+    #[error("the payload is too large for a block. Its length is {length}, max length is {max_length}")]
+    InvalidPayloadLength {
+        /// The found length.
+        length: usize,
+        /// The max length.
+        max_length: usize,
+    },
     /// The transaction essence is too large
     #[error("the transaction essence is too large. Its length is {length}, max length is {max_length}")]
     InvalidRegularTransactionEssenceLength {
@@ -90,9 +122,15 @@ pub enum Error {
     /// The block doesn't need to be promoted or reattached
     #[error("block ID `{0}` doesn't need to be promoted or reattached")]
     NoNeedPromoteOrReattach(String),
+    /// No recorded response for a request when replaying a request journal
+    #[error("no recorded response for request: {0}")]
+    NoRecordedResponse(String),
     /// The requested data was not found.
     #[error("the requested data {0} was not found.")]
     NotFound(String),
+    /// The client is in offline mode, but the request needs a node
+    #[error("the client is in offline mode, `{0}` requires a node")]
+    OfflineMode(String),
     /// Output Error
     #[error("output error: {0}")]
     Output(&'static str),
@@ -140,6 +178,12 @@ pub enum Error {
     /// Specifically used for `TryInfo` implementations for `SecretManager`.
     #[error("cannot unwrap a SecretManager: type mismatch!")]
     SecretManagerMismatch,
+    /// A block can't be submitted, because the submission window ended
+    #[error("the submission window ended at {end}")]
+    SubmissionWindowClosed {
+        /// The unix time the submission window ended.
+        end: u32,
+    },
     /// No node available in the healthy node pool
     #[error("no healthy node available")]
     HealthyNodePoolEmpty,
@@ -163,6 +207,17 @@ pub enum Error {
         /// The timestamp of the latest milestone.
         milestone_timestamp: u32,
     },
+    /// The network id of a transaction essence doesn't match the one of the client.
+    #[error(
+        "the network id of the transaction essence {actual} doesn't match the network id of the client {expected}, if \
+         the inputs are still unspent the essence can be rebuilt for the current network with `rebind_network()`"
+    )]
+    TransactionNetworkIdMismatch {
+        /// The network id of the client.
+        expected: u64,
+        /// The network id of the transaction essence.
+        actual: u64,
+    },
     /// The semantic validation of a transaction failed.
     #[error("the semantic validation of a transaction failed with conflict reason: {} - {0:?}", *.0 as u8)]
     TransactionSemantic(ConflictReason),
@@ -203,6 +258,12 @@ pub enum Error {
     #[error("{0}")]
     Ledger(#[from] crate::client::secret::ledger_nano::Error),
 
+    /// PKCS#11 error
+    #[cfg(feature = "pkcs11")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "pkcs11")))]
+    #[error("{0}")]
+    Pkcs11(#[from] crate::client::secret::pkcs11::Error),
+
     /// MQTT error
     #[cfg(feature = "mqtt")]
     #[cfg_attr(docsrs, doc(cfg(feature = "mqtt")))]
@@ -216,23 +277,148 @@ pub enum Error {
     Stronghold(#[from] crate::client::stronghold::Error),
 }
 
-// Serialize type with Display error
+impl Error {
+    /// Returns the kind of the error, the variant name in camelCase, that is stable and can be matched by bindings.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Self::ApiTypes(_) => "apiTypes",
+            Self::Blake2b256(_) => "blake2b256",
+            Self::Block(_) => "block",
+            Self::Cancelled => "cancelled",
+            Self::ConsolidationRequired(_) => "consolidationRequired",
+            Self::Crypto(_) => "crypto",
+            Self::FaucetTimeout(_) => "faucetTimeout",
+            Self::InputAddressNotFound { .. } => "inputAddressNotFound",
+            Self::InvalidAmount(_) => "invalidAmount",
+            Self::InvalidBIP32ChainData => "invalidBIP32ChainData",
+            Self::InvalidBech32Hrp { .. } => "invalidBech32Hrp",
+            Self::InvalidBlockLength { .. } => "invalidBlockLength",
+            Self::InvalidClientOptions { .. } => "invalidClientOptions",
+            Self::InvalidMnemonic(_) => "invalidMnemonic",
+            Self::InvalidPayloadLength { .. } => "invalidPayloadLength",
+            Self::InvalidRegularTransactionEssenceLength { .. } => "invalidRegularTransactionEssenceLength",
+            Self::InvalidTransactionPayloadLength { .. } => "invalidTransactionPayloadLength",
+            Self::Json(_) => "json",
+            Self::MissingParameter(_) => "missingParameter",
+            Self::Node(_) => "node",
+            Self::NoNeedPromoteOrReattach(_) => "noNeedPromoteOrReattach",
+            Self::NoRecordedResponse(_) => "noRecordedResponse",
+            Self::NotFound(_) => "notFound",
+            Self::OfflineMode(_) => "offlineMode",
+            Self::Output(_) => "output",
+            Self::PlaceholderSecretManager => "placeholderSecretManager",
+            Self::PoisonError => "poisonError",
+            Self::Pow(_) => "pow",
+            Self::PrefixHex(_) => "prefixHex",
+            Self::QuorumPoolSizeError { .. } => "quorumPoolSizeError",
+            Self::QuorumThresholdError { .. } => "quorumThresholdError",
+            Self::ResponseError { .. } => "responseError",
+            Self::Reqwest(_) => "reqwest",
+            Self::SecretManagerMismatch => "secretManagerMismatch",
+            Self::SubmissionWindowClosed { .. } => "submissionWindowClosed",
+            Self::HealthyNodePoolEmpty => "healthyNodePoolEmpty",
+            Self::TaggedData(_) => "taggedData",
+            Self::TangleInclusion(_) => "tangleInclusion",
+            #[cfg(not(target_family = "wasm"))]
+            Self::TaskJoin(_) => "taskJoin",
+            Self::TimeNotSynced { .. } => "timeNotSynced",
+            Self::TransactionNetworkIdMismatch { .. } => "transactionNetworkIdMismatch",
+            Self::TransactionSemantic(_) => "transactionSemantic",
+            Self::UnexpectedApiResponse => "unexpectedApiResponse",
+            Self::UnsupportedQueryParameter(_) => "unsupportedQueryParameter",
+            Self::Unpack(_) => "unpack",
+            Self::UrlAuth(_) => "urlAuth",
+            Self::Url(_) => "url",
+            Self::UrlValidation(_) => "urlValidation",
+            Self::InputSelection(_) => "inputSelection",
+            Self::MissingBip32Chain => "missingBip32Chain",
+            #[cfg(feature = "participation")]
+            Self::Participation(_) => "participation",
+            #[cfg(feature = "ledger_nano")]
+            Self::Ledger(_) => "ledger",
+            #[cfg(feature = "pkcs11")]
+            Self::Pkcs11(_) => "pkcs11",
+            #[cfg(feature = "mqtt")]
+            Self::Mqtt(_) => "mqtt",
+            #[cfg(feature = "stronghold")]
+            Self::Stronghold(_) => "stronghold",
+        }
+    }
+
+    /// Returns the contextual data of the error, like the URL and status code of a failed node request.
+    pub fn context(&self) -> ErrorContext {
+        match self {
+            Self::ResponseError { code, url, .. } => ErrorContext {
+                status: Some(*code),
+                url: Some(url.clone()),
+                ..Default::default()
+            },
+            Self::Reqwest(error) => ErrorContext {
+                status: error.status().map(|status| status.as_u16()),
+                url: error.url().map(|url| url.to_string()),
+                ..Default::default()
+            },
+            Self::NotFound(url) => ErrorContext {
+                status: Some(404),
+                url: Some(url.clone()),
+                ..Default::default()
+            },
+            Self::NoNeedPromoteOrReattach(block_id) | Self::TangleInclusion(block_id) => ErrorContext {
+                block_id: block_id.parse().ok(),
+                ..Default::default()
+            },
+            _ => ErrorContext::default(),
+        }
+    }
+}
+
+/// Contextual data of an error, so bindings don't need to parse it from the error message.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct ErrorContext {
+    /// The HTTP status code of the node response.
+    pub status: Option<u16>,
+    /// The URL of the node request.
+    pub url: Option<String>,
+    /// The offending block id.
+    pub block_id: Option<BlockId>,
+    /// The offending output id.
+    pub output_id: Option<OutputId>,
+    /// The offending transaction id.
+    pub transaction_id: Option<TransactionId>,
+}
+
+impl ErrorContext {
+    /// Serializes the set fields of the context as entries of an error map.
+    pub fn serialize_entries<M: SerializeMap>(&self, map: &mut M) -> std::result::Result<(), M::Error> {
+        if let Some(status) = &self.status {
+            map.serialize_entry("status", status)?;
+        }
+        if let Some(url) = &self.url {
+            map.serialize_entry("url", url)?;
+        }
+        if let Some(block_id) = &self.block_id {
+            map.serialize_entry("blockId", block_id)?;
+        }
+        if let Some(output_id) = &self.output_id {
+            map.serialize_entry("outputId", output_id)?;
+        }
+        if let Some(transaction_id) = &self.transaction_id {
+            map.serialize_entry("transactionId", transaction_id)?;
+        }
+        Ok(())
+    }
+}
+
+// Serialize type with kind, Display error and context
 impl Serialize for Error {
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
         S: Serializer,
     {
-        let mut seq = serializer.serialize_map(Some(2))?;
-        let mut kind_dbg = format!("{self:?}");
-        // Convert first char to lowercase
-        if let Some(r) = kind_dbg.get_mut(0..1) {
-            r.make_ascii_lowercase();
-        }
-        // Split by whitespace for struct variants and split by `(` for tuple variants
-        // Safe to unwrap because kind_dbg is never an empty string
-        let kind = kind_dbg.split([' ', '(']).next().unwrap();
-        seq.serialize_entry("type", &kind)?;
-        seq.serialize_entry("error", &self.to_string())?;
-        seq.end()
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("type", self.kind())?;
+        map.serialize_entry("error", &self.to_string())?;
+        self.context().serialize_entries(&mut map)?;
+        map.end()
     }
 }
@@ -1,6 +1,8 @@
 // Copyright 2022 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+use std::ops::Range;
+
 use crypto::keys::slip10::Chain;
 use serde::{Deserialize, Serialize};
 
@@ -9,12 +11,12 @@ use crate::client::mqtt::Topic;
 use crate::{
     client::{
         api::{
-            ClientBlockBuilderOptions as BuildBlockOptions, GetAddressesBuilderOptions as GenerateAddressesOptions,
-            PreparedTransactionDataDto,
+            ClientBlockBuilderOptions as BuildBlockOptions, ConeWalkOptions,
+            GetAddressesBuilderOptions as GenerateAddressesOptions, PreparedTransactionDataDto,
         },
         node_api::indexer::query_parameters::QueryParameter,
         node_manager::node::NodeAuth,
-        secret::SecretManagerDto,
+        secret::{GenerateAddressOptions, SecretManagerDto},
     },
     types::block::{
         address::{dto::Ed25519AddressDto, AliasAddress},
@@ -111,6 +113,21 @@ pub enum Message {
         /// Addresses generation options
         options: GenerateAddressesOptions,
     },
+    /// Generate EVM addresses.
+    #[serde(rename_all = "camelCase")]
+    GenerateEvmAddresses {
+        /// Create secret manager from json
+        secret_manager: SecretManagerDto,
+        /// Coin type, e.g. [`ETHER_COIN_TYPE`](crate::client::constants::ETHER_COIN_TYPE)
+        coin_type: u32,
+        /// Account index
+        account_index: u32,
+        /// Range of the address indexes
+        range: Range<u32>,
+        /// Options
+        #[serde(default)]
+        options: Option<GenerateAddressOptions>,
+    },
     /// Build and post a block
     #[serde(rename_all = "camelCase")]
     BuildAndPostBlock {
@@ -173,6 +190,7 @@ pub enum Message {
         /// Transaction Essence Hash
         transaction_essence_hash: Vec<u8>,
         /// Chain to sign the essence hash with
+        #[serde(with = "crate::client::secret::types::chain_serde")]
         chain: Chain,
     },
     /// Signs a message with an Ed25519 private key.
@@ -183,6 +201,18 @@ pub enum Message {
         /// The message to sign, hex encoded String
         message: String,
         /// Chain to sign the essence hash with
+        #[serde(with = "crate::client::secret::types::chain_serde")]
+        chain: Chain,
+    },
+    /// Signs a 32 byte hash with a secp256k1 private key.
+    #[serde(rename_all = "camelCase")]
+    SignSecp256k1Ecdsa {
+        /// Secret manager
+        secret_manager: SecretManagerDto,
+        /// The hash to sign, hex encoded String
+        hash: String,
+        /// Chain of the private key, e.g. from [`evm_chain()`](crate::client::secret::evm_chain)
+        #[serde(with = "crate::client::secret::types::chain_serde")]
         chain: Chain,
     },
     /// Verifies the Ed25519Signature for a message against an Ed25519Address.
@@ -259,6 +289,26 @@ pub enum Message {
         /// Block ID
         block_id: BlockId,
     },
+    /// Get the ids of the blocks that reference the block
+    #[serde(rename_all = "camelCase")]
+    GetBlockChildren {
+        /// Block ID
+        block_id: BlockId,
+    },
+    /// Get the ids of the blocks that are referenced by the block
+    #[serde(rename_all = "camelCase")]
+    GetBlockParents {
+        /// Block ID
+        block_id: BlockId,
+    },
+    /// Get the blocks in the past or future cone of a block, breadth first
+    #[serde(rename_all = "camelCase")]
+    WalkCone {
+        /// Block ID of the start block
+        block_id: BlockId,
+        /// Direction and limits of the walk
+        options: Option<ConeWalkOptions>,
+    },
     /// Get output
     #[serde(rename_all = "camelCase")]
     GetOutput {
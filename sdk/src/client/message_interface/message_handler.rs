@@ -4,7 +4,7 @@
 use std::{any::Any, panic::AssertUnwindSafe};
 
 use backtrace::Backtrace;
-use futures::{Future, FutureExt};
+use futures::{Future, FutureExt, TryStreamExt};
 use zeroize::Zeroize;
 #[cfg(feature = "mqtt")]
 use {
@@ -19,11 +19,11 @@ use crate::{
         api::{PreparedTransactionData, PreparedTransactionDataDto},
         message_interface::{message::Message, response::Response},
         request_funds_from_faucet,
-        secret::{SecretManage, SecretManager},
+        secret::{types::Secp256k1EcdsaSignatureDto, SecretManage, SecretManager},
         Client, Result,
     },
     types::{
-        api::core::response::OutputWithMetadataResponse,
+        api::core::{dto::ReceiptDto, response::OutputWithMetadataResponse},
         block::{
             address::{dto::AddressDto, Address, Ed25519Address},
             input::dto::UtxoInputDto,
@@ -149,6 +149,9 @@ impl ClientMessageHandler {
             } => {
                 log::debug!("Response: GenerateAddresses{{ secret_manager: <omitted>, options: {options:?} }}")
             }
+            Message::GenerateEvmAddresses { options, .. } => {
+                log::debug!("Response: GenerateEvmAddresses{{ secret_manager: <omitted>, options: {options:?} }}")
+            }
             Message::BuildAndPostBlock {
                 secret_manager: _,
                 options,
@@ -210,7 +213,7 @@ impl ClientMessageHandler {
 
     // If cfg(not(feature = "stronghold")) then secret_manager doesn't necessarily to be mutable, but otherwise it has
     // to be. Instead of rendering the code messy just because of this, we just allow unused mutable variables.
-    #[allow(unused_mut)]
+    #[allow(unused_mut, clippy::large_stack_frames)]
     async fn handle_message(&self, message: Message) -> Result<Response> {
         match message {
             Message::BuildAliasOutput {
@@ -326,6 +329,21 @@ impl ClientMessageHandler {
                     .await?;
                 Ok(Response::GeneratedAddresses(addresses))
             }
+            Message::GenerateEvmAddresses {
+                secret_manager,
+                coin_type,
+                account_index,
+                range,
+                options,
+            } => {
+                let secret_manager: SecretManager = (&secret_manager).try_into()?;
+                let addresses = secret_manager
+                    .generate_evm_addresses(coin_type, account_index, range, options)
+                    .await?;
+                Ok(Response::GeneratedAddresses(
+                    addresses.iter().map(ToString::to_string).collect(),
+                ))
+            }
             Message::BuildAndPostBlock {
                 secret_manager,
                 options,
@@ -454,6 +472,18 @@ impl ClientMessageHandler {
                 let signature = secret_manager.sign_ed25519(&msg, &chain).await?;
                 Ok(Response::Ed25519Signature(Ed25519SignatureDto::from(&signature)))
             }
+            Message::SignSecp256k1Ecdsa {
+                secret_manager,
+                hash,
+                chain,
+            } => {
+                let secret_manager: SecretManager = (&secret_manager).try_into()?;
+                let hash: [u8; 32] = prefix_hex::decode(hash)?;
+                let signature = secret_manager.sign_secp256k1_ecdsa(&hash, &chain).await?;
+                Ok(Response::Secp256k1EcdsaSignature(Secp256k1EcdsaSignatureDto::from(
+                    &signature,
+                )))
+            }
             Message::VerifyEd25519Signature {
                 signature,
                 message,
@@ -493,9 +523,7 @@ impl ClientMessageHandler {
                 Ok(Response::BlockIdWithBlock(block_id, BlockDto::from(&block)))
             }
             #[cfg(not(target_family = "wasm"))]
-            Message::UnhealthyNodes => Ok(Response::UnhealthyNodes(
-                self.client.unhealthy_nodes().into_iter().cloned().collect(),
-            )),
+            Message::UnhealthyNodes => Ok(Response::UnhealthyNodes(self.client.unhealthy_nodes())),
             Message::GetHealth { url } => Ok(Response::Bool(self.client.get_health(&url).await?)),
             Message::GetNodeInfo { url, auth } => Ok(Response::NodeInfo(Client::get_node_info(&url, auth).await?)),
             Message::GetInfo => Ok(Response::Info(self.client.get_info().await?)),
@@ -524,6 +552,18 @@ impl ClientMessageHandler {
                 self.client.get_block_metadata(&block_id).await?,
             )),
             Message::GetBlockRaw { block_id } => Ok(Response::BlockRaw(self.client.get_block_raw(&block_id).await?)),
+            Message::GetBlockChildren { block_id } => {
+                Ok(Response::BlockIds(self.client.get_block_children(&block_id).await?))
+            }
+            Message::GetBlockParents { block_id } => {
+                Ok(Response::BlockIds(self.client.get_block_parents(&block_id).await?))
+            }
+            Message::WalkCone { block_id, options } => Ok(Response::VisitedBlocks(
+                self.client
+                    .walk_cone(block_id, options.unwrap_or_default())
+                    .try_collect()
+                    .await?,
+            )),
             Message::GetOutput { output_id } => {
                 let output_with_meta = self.client.get_output(&output_id).await?;
                 Ok(Response::Output(OutputWithMetadataResponse::from(&output_with_meta)))
@@ -549,11 +589,18 @@ impl ClientMessageHandler {
             Message::GetUtxoChangesByIndex { index } => Ok(Response::MilestoneUtxoChanges(
                 self.client.get_utxo_changes_by_index(index).await?,
             )),
-            Message::GetReceipts => Ok(Response::Receipts(self.client.get_receipts().await?)),
+            Message::GetReceipts => Ok(Response::Receipts(
+                self.client.get_receipts().await?.iter().map(ReceiptDto::from).collect(),
+            )),
             Message::GetReceiptsMigratedAt { milestone_index } => Ok(Response::Receipts(
-                self.client.get_receipts_migrated_at(milestone_index).await?,
+                self.client
+                    .get_receipts_migrated_at(milestone_index)
+                    .await?
+                    .iter()
+                    .map(ReceiptDto::from)
+                    .collect(),
             )),
-            Message::GetTreasury => Ok(Response::Treasury(self.client.get_treasury().await?)),
+            Message::GetTreasury => Ok(Response::Treasury((&self.client.get_treasury().await?).into())),
             Message::GetIncludedBlock { transaction_id } => Ok(Response::Block(BlockDto::from(
                 &self.client.get_included_block(&transaction_id).await?,
             ))),
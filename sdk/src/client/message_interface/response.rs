@@ -2,14 +2,17 @@
 // SPDX-License-Identifier: Apache-2.0
 
 #[cfg(not(target_family = "wasm"))]
-use std::collections::HashSet;
-
 use serde::Serialize;
 
 #[cfg(feature = "ledger_nano")]
 use crate::client::secret::LedgerNanoStatus;
 use crate::{
-    client::{api::PreparedTransactionDataDto, node_manager::node::Node, Error, NetworkInfoDto, NodeInfoWrapper},
+    client::{
+        api::{PreparedTransactionDataDto, VisitedBlock},
+        node_manager::node::{Node, UnhealthyNode},
+        secret::types::Secp256k1EcdsaSignatureDto,
+        Error, NetworkInfoDto, NodeInfoWrapper,
+    },
     types::{
         api::{
             core::{
@@ -59,6 +62,7 @@ pub enum Response {
     Bool(bool),
     /// Response for:
     /// - [`GenerateAddresses`](crate::client::message_interface::Message::GenerateAddresses)
+    /// - [`GenerateEvmAddresses`](crate::client::message_interface::Message::GenerateEvmAddresses)
     GeneratedAddresses(Vec<String>),
     /// Response for:
     /// - [`GetNode`](crate::client::message_interface::Message::GetNode)
@@ -99,9 +103,12 @@ pub enum Response {
     /// - [`SignEd25519`](crate::client::message_interface::Message::SignEd25519)
     Ed25519Signature(Ed25519SignatureDto),
     /// Response for:
+    /// - [`SignSecp256k1Ecdsa`](crate::client::message_interface::Message::SignSecp256k1Ecdsa)
+    Secp256k1EcdsaSignature(Secp256k1EcdsaSignatureDto),
+    /// Response for:
     /// - [`UnhealthyNodes`](crate::client::message_interface::Message::UnhealthyNodes)
     #[cfg(not(target_family = "wasm"))]
-    UnhealthyNodes(HashSet<Node>),
+    UnhealthyNodes(Vec<UnhealthyNode>),
     /// Response for:
     /// - [`GetNodeInfo`](crate::client::message_interface::Message::GetNodeInfo)
     NodeInfo(NodeInfo),
@@ -127,6 +134,13 @@ pub enum Response {
     /// - [`GetBlockMetadata`](crate::client::message_interface::Message::GetBlockMetadata)
     BlockMetadata(BlockMetadataResponse),
     /// Response for:
+    /// - [`GetBlockChildren`](crate::client::message_interface::Message::GetBlockChildren)
+    /// - [`GetBlockParents`](crate::client::message_interface::Message::GetBlockParents)
+    BlockIds(Vec<BlockId>),
+    /// Response for:
+    /// - [`WalkCone`](crate::client::message_interface::Message::WalkCone)
+    VisitedBlocks(Vec<VisitedBlock>),
+    /// Response for:
     /// - [`GetBlockRaw`](crate::client::message_interface::Message::GetBlockRaw)
     BlockRaw(Vec<u8>),
     /// Response for:
@@ -36,8 +36,11 @@ macro_rules! lazy_static {
 
 pub mod api;
 pub mod builder;
+pub mod cache;
+pub mod cancellation;
 #[allow(clippy::module_inception)]
 pub mod client;
+pub mod compat;
 pub mod constants;
 pub mod error;
 #[cfg(feature = "message_interface")]
@@ -45,20 +48,33 @@ pub mod error;
 pub mod message_interface;
 pub mod node_api;
 pub mod node_manager;
+pub mod pow_advisory;
+pub mod request_journal;
 pub mod secret;
 pub mod storage;
+pub mod submission;
 #[cfg(feature = "stronghold")]
 #[cfg_attr(docsrs, doc(cfg(feature = "stronghold")))]
 pub mod stronghold;
+#[cfg(feature = "test_utils")]
+#[cfg_attr(docsrs, doc(cfg(feature = "test_utils")))]
+pub mod test_utils;
 pub mod utils;
 
 #[cfg(feature = "mqtt")]
 pub use self::node_api::mqtt;
+#[cfg(feature = "operator")]
+pub use self::node_api::core::routes::NodeCapabilities;
 pub use self::{
     builder::{ClientBuilder, NetworkInfo, NetworkInfoDto},
+    cache::{CacheOptions, CacheStats, ClientCacheStats},
+    cancellation::CancellationToken,
     client::*,
     error::*,
-    node_api::core::routes::NodeInfoWrapper,
+    node_api::core::{routes::NodeInfoWrapper, OutputsWithErrors},
+    pow_advisory::PowCapacityEstimate,
+    request_journal::{parse_request_journal, RequestJournalEntry, RequestJournalOptions},
+    submission::{SubmissionOptions, SubmissionWindow},
     utils::*,
 };
 
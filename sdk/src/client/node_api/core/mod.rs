@@ -5,70 +5,62 @@
 
 pub mod routes;
 
-#[cfg(not(target_family = "wasm"))]
-use crate::client::constants::MAX_PARALLEL_API_REQUESTS;
+use std::{collections::HashSet, future::Future};
+
+use futures::StreamExt;
+
 use crate::{
-    client::{Client, Result},
+    client::{constants::MAX_PARALLEL_API_REQUESTS, Client, Error, Result},
     types::block::output::{dto::OutputMetadataDto, OutputId, OutputWithMetadata},
 };
 
+/// The outputs returned by [`Client::get_outputs_with_errors()`] and the errors of the output IDs that couldn't be
+/// requested.
+#[derive(Debug, Default)]
+pub struct OutputsWithErrors {
+    /// The requested outputs, in the order of their output IDs.
+    pub outputs: Vec<OutputWithMetadata>,
+    /// The output IDs that couldn't be requested, e.g. because the outputs were pruned, with their errors.
+    pub errors: Vec<(OutputId, Error)>,
+}
+
 impl Client {
-    /// Request outputs by their output ID in parallel
+    /// Request outputs by their output ID in parallel, duplicated output IDs are only requested once. Fails with the
+    /// first error, use [`Client::get_outputs_with_errors()`] to keep the outputs that could be requested.
     pub async fn get_outputs(&self, output_ids: Vec<OutputId>) -> Result<Vec<OutputWithMetadata>> {
-        let mut outputs = Vec::new();
+        let OutputsWithErrors { outputs, mut errors } = self.get_outputs_with_errors(output_ids).await;
 
-        #[cfg(target_family = "wasm")]
-        for output_id in output_ids {
-            outputs.push(self.get_output(&output_id).await?);
+        if errors.is_empty() {
+            Ok(outputs)
+        } else {
+            Err(errors.swap_remove(0).1)
         }
+    }
 
-        #[cfg(not(target_family = "wasm"))]
-        for output_ids_chunk in output_ids.chunks(MAX_PARALLEL_API_REQUESTS).map(<[OutputId]>::to_vec) {
-            let mut tasks = Vec::new();
-            for output_id in output_ids_chunk {
-                let client_ = self.clone();
-
-                tasks.push(async move {
-                    tokio::spawn(async move {
-                        let output_response = client_.get_output(&output_id).await?;
-                        crate::client::Result::Ok(output_response)
-                    })
-                    .await
-                });
-            }
-            for res in futures::future::try_join_all(tasks).await? {
-                let output_response = res?;
-                outputs.push(output_response);
+    /// Request outputs by their output ID in parallel, duplicated output IDs are only requested once. A failed request
+    /// doesn't fail the others, its error is returned with the output ID instead.
+    pub async fn get_outputs_with_errors(&self, output_ids: Vec<OutputId>) -> OutputsWithErrors {
+        let mut outputs_with_errors = OutputsWithErrors::default();
+
+        for (output_id, res) in self
+            .request_outputs(output_ids, |client, output_id| async move {
+                client.get_output(&output_id).await
+            })
+            .await
+        {
+            match res {
+                Ok(output) => outputs_with_errors.outputs.push(output),
+                Err(err) => outputs_with_errors.errors.push((output_id, err)),
             }
         }
-        Ok(outputs)
+
+        outputs_with_errors
     }
 
     /// Request outputs by their output ID in parallel, ignoring failed requests
     /// Useful to get data about spent outputs, that might not be pruned yet
     pub async fn get_outputs_ignore_errors(&self, output_ids: Vec<OutputId>) -> Result<Vec<OutputWithMetadata>> {
-        let mut outputs = Vec::new();
-
-        #[cfg(target_family = "wasm")]
-        for output_id in output_ids {
-            if let Ok(output_response) = self.get_output(&output_id).await {
-                outputs.push(output_response);
-            }
-        }
-
-        #[cfg(not(target_family = "wasm"))]
-        for output_ids_chunk in output_ids.chunks(MAX_PARALLEL_API_REQUESTS).map(<[OutputId]>::to_vec) {
-            let mut tasks = Vec::new();
-            for output_id in output_ids_chunk {
-                let client_ = self.clone();
-
-                tasks.push(async move { tokio::spawn(async move { client_.get_output(&output_id).await.ok() }).await });
-            }
-            for output_response in (futures::future::try_join_all(tasks).await?).into_iter().flatten() {
-                outputs.push(output_response);
-            }
-        }
-        Ok(outputs)
+        Ok(self.get_outputs_with_errors(output_ids).await.outputs)
     }
 
     /// Requests metadata for outputs by their output ID in parallel, ignoring failed requests
@@ -76,30 +68,39 @@ impl Client {
         &self,
         output_ids: Vec<OutputId>,
     ) -> Result<Vec<OutputMetadataDto>> {
-        let mut output_metadata_responses = Vec::new();
-
-        #[cfg(target_family = "wasm")]
-        for output_id in output_ids {
-            if let Ok(output_metadata_response) = self.get_output_metadata(&output_id).await {
-                output_metadata_responses.push(output_metadata_response);
-            }
-        }
-
-        #[cfg(not(target_family = "wasm"))]
-        for output_ids_chunk in output_ids.chunks(MAX_PARALLEL_API_REQUESTS).map(<[OutputId]>::to_vec) {
-            let mut tasks = Vec::new();
-            for output_id in output_ids_chunk {
-                let client_ = self.clone();
-
-                tasks.push(async move {
-                    tokio::spawn(async move { client_.get_output_metadata(&output_id).await.ok() }).await
-                });
-            }
-            for output_metadata_response in (futures::future::try_join_all(tasks).await?).into_iter().flatten() {
-                output_metadata_responses.push(output_metadata_response);
-            }
-        }
+        Ok(self
+            .request_outputs(output_ids, |client, output_id| async move {
+                client.get_output_metadata(&output_id).await
+            })
+            .await
+            .into_iter()
+            .filter_map(|(_, res)| res.ok())
+            .collect())
+    }
 
-        Ok(output_metadata_responses)
+    // Requests the deduplicated output IDs with at most MAX_PARALLEL_API_REQUESTS requests at the same time, a new
+    // request starts as soon as a previous one finished. The results are in the order of the output IDs.
+    async fn request_outputs<'a, T, F, Fut>(
+        &'a self,
+        output_ids: Vec<OutputId>,
+        request: F,
+    ) -> Vec<(OutputId, Result<T>)>
+    where
+        T: Send,
+        F: Fn(&'a Self, OutputId) -> Fut + Send + Sync,
+        Fut: Future<Output = Result<T>> + Send + 'a,
+    {
+        let mut requested_output_ids = HashSet::new();
+        let request = &request;
+
+        futures::stream::iter(
+            output_ids
+                .into_iter()
+                .filter(|output_id| requested_output_ids.insert(*output_id)),
+        )
+        .map(|output_id| async move { (output_id, request(self, output_id).await) })
+        .buffered(MAX_PARALLEL_API_REQUESTS)
+        .collect()
+        .await
     }
 }
@@ -3,25 +3,31 @@
 
 //! Node core API routes.
 
-use std::str::FromStr;
+use std::{str::FromStr, time::Duration};
 
 use packable::PackableExt;
 use serde::{Deserialize, Serialize};
 use url::Url;
 
+#[cfg(feature = "operator")]
+use crate::types::api::core::response::{AddPeerResponse, PeerResponse};
 use crate::{
     client::{
+        api::validate_block_length,
         constants::{DEFAULT_API_TIMEOUT, DEFAULT_USER_AGENT},
-        node_manager::node::{Node, NodeAuth},
+        node_manager::{
+            http_client::HttpClient,
+            node::{Node, NodeAuth},
+        },
         Client, Error, Result,
     },
     types::{
         api::core::{
-            dto::{PeerDto, ReceiptDto},
+            dto::PeerDto,
             response::{
-                BlockMetadataResponse, BlockResponse, InfoResponse, MilestoneResponse, OutputWithMetadataResponse,
-                PeersResponse, ReceiptsResponse, RoutesResponse, SubmitBlockResponse, TipsResponse, TreasuryResponse,
-                UtxoChangesResponse,
+                BlockChildrenResponse, BlockMetadataResponse, BlockResponse, InfoResponse, MilestoneResponse,
+                OutputWithMetadataResponse, PeersResponse, Receipt, ReceiptsResponse, RoutesResponse,
+                SubmitBlockResponse, TipsResponse, Treasury, TreasuryResponse, UtxoChanges, UtxoChangesResponse,
             },
         },
         block::{
@@ -45,31 +51,85 @@ pub struct NodeInfoWrapper {
     pub url: String,
 }
 
+/// The features and the API route groups of a node, e.g. to check if a plugin is enabled before its routes are used.
+#[cfg(feature = "operator")]
+#[cfg_attr(docsrs, doc(cfg(feature = "operator")))]
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NodeCapabilities {
+    /// The features of the node from the node info
+    pub features: Vec<String>,
+    /// The available API route groups, e.g. `indexer/v1`
+    pub routes: Vec<String>,
+}
+
+#[cfg(feature = "operator")]
+impl NodeCapabilities {
+    /// Returns true if the node has the feature.
+    pub fn has_feature(&self, feature: &str) -> bool {
+        self.features.iter().any(|f| f == feature)
+    }
+
+    /// Returns true if the API route group is available, e.g. `participation/v1`.
+    pub fn has_route(&self, route: &str) -> bool {
+        self.routes.iter().any(|r| r == route)
+    }
+}
+
 impl Client {
     // Node routes.
 
     /// Returns the health of the node.
     /// GET /health
     pub async fn get_health(&self, url: &str) -> Result<bool> {
-        let path = "health";
+        Self::get_node_health_with_http_client(&self.node_manager.http_client, url, None, self.get_timeout()).await
+    }
+
+    /// GET /health endpoint
+    pub async fn get_node_health(url: &str, auth: Option<NodeAuth>) -> Result<bool> {
+        Self::get_node_health_with_http_client(
+            &HttpClient::new(DEFAULT_USER_AGENT.to_string()),
+            url,
+            auth,
+            DEFAULT_API_TIMEOUT,
+        )
+        .await
+    }
 
-        let mut url = Url::parse(url)?;
+    pub(crate) async fn get_node_health_with_http_client(
+        http_client: &HttpClient,
+        url: &str,
+        auth: Option<NodeAuth>,
+        timeout: Duration,
+    ) -> Result<bool> {
+        let mut url = crate::client::node_manager::builder::validate_url(Url::parse(url)?)?;
+        if let Some(auth) = &auth {
+            if let Some((name, password)) = &auth.basic_auth_name_pwd {
+                url.set_username(name)
+                    .map_err(|_| crate::client::Error::UrlAuth("username"))?;
+                url.set_password(Some(password))
+                    .map_err(|_| crate::client::Error::UrlAuth("password"))?;
+            }
+        }
+        let path = "health";
         url.set_path(path);
-        let status = crate::client::node_manager::http_client::HttpClient::new(DEFAULT_USER_AGENT.to_string())
-            .get(
+
+        let response = http_client
+            .get_once(
                 Node {
                     url,
-                    auth: None,
+                    auth,
                     disabled: false,
                 },
-                DEFAULT_API_TIMEOUT,
+                timeout,
             )
-            .await?
-            .status();
+            .await;
 
-        match status {
-            200 => Ok(true),
-            _ => Ok(false),
+        match response {
+            Ok(response) => Ok(response.status() == 200),
+            // An unhealthy node answers with 503
+            Err(Error::ResponseError { .. }) => Ok(false),
+            Err(e) => Err(e),
         }
     }
 
@@ -83,6 +143,17 @@ impl Client {
             .await
     }
 
+    /// Returns the features from the node info and the available API route groups of the node.
+    /// GET /api/core/v2/info and /api/routes
+    #[cfg(feature = "operator")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "operator")))]
+    pub async fn get_node_capabilities(&self) -> Result<NodeCapabilities> {
+        let features = self.get_info().await?.node_info.features;
+        let routes = self.get_routes().await?.routes;
+
+        Ok(NodeCapabilities { features, routes })
+    }
+
     /// Returns general information about the node.
     /// GET /api/core/v2/info
     pub async fn get_info(&self) -> Result<NodeInfoWrapper> {
@@ -95,6 +166,21 @@ impl Client {
 
     /// GET /api/core/v2/info endpoint
     pub async fn get_node_info(url: &str, auth: Option<NodeAuth>) -> Result<InfoResponse> {
+        Self::get_node_info_with_http_client(
+            &HttpClient::new(DEFAULT_USER_AGENT.to_string()),
+            url,
+            auth,
+            DEFAULT_API_TIMEOUT,
+        )
+        .await
+    }
+
+    pub(crate) async fn get_node_info_with_http_client(
+        http_client: &HttpClient,
+        url: &str,
+        auth: Option<NodeAuth>,
+        timeout: Duration,
+    ) -> Result<InfoResponse> {
         let mut url = crate::client::node_manager::builder::validate_url(Url::parse(url)?)?;
         if let Some(auth) = &auth {
             if let Some((name, password)) = &auth.basic_auth_name_pwd {
@@ -107,19 +193,18 @@ impl Client {
         let path = "api/core/v2/info";
         url.set_path(path);
 
-        let resp: InfoResponse =
-            crate::client::node_manager::http_client::HttpClient::new(DEFAULT_USER_AGENT.to_string())
-                .get(
-                    Node {
-                        url,
-                        auth,
-                        disabled: false,
-                    },
-                    DEFAULT_API_TIMEOUT,
-                )
-                .await?
-                .into_json()
-                .await?;
+        let resp: InfoResponse = http_client
+            .get(
+                Node {
+                    url,
+                    auth,
+                    disabled: false,
+                },
+                timeout,
+            )
+            .await?
+            .into_json()
+            .await?;
 
         Ok(resp)
     }
@@ -144,9 +229,11 @@ impl Client {
 
     // Blocks routes.
 
-    /// Returns the BlockId of the submitted block.
+    /// Returns the BlockId of the submitted block, fails without submitting it if the block is too large.
     /// POST JSON to /api/core/v2/blocks
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip_all))]
     pub async fn post_block(&self, block: &Block) -> Result<BlockId> {
+        validate_block_length(block)?;
         let path = "api/core/v2/blocks";
         let local_pow = self.get_local_pow();
         let timeout = if local_pow {
@@ -164,52 +251,42 @@ impl Client {
         {
             Ok(res) => res,
             Err(e) => {
-                if let Error::Node(e) = e {
-                    let fallback_to_local_pow = self.get_fallback_to_local_pow();
-                    // hornet and bee return different error blocks
-                    if (e == *"no available nodes with remote Pow"
-                        || e.contains("proof of work is not enabled")
-                        || e.contains("`Pow` not enabled"))
-                        && fallback_to_local_pow
+                if remote_pow_unavailable(&e) && self.get_fallback_to_local_pow() {
+                    // Without this we get:within `impl Future<Output = [async output]>`, the trait `Send` is not
+                    // implemented for `std::sync::RwLockWriteGuard<'_, NetworkInfo>`
                     {
-                        // Without this we get:within `impl Future<Output = [async output]>`, the trait `Send` is not
-                        // implemented for `std::sync::RwLockWriteGuard<'_, NetworkInfo>`
-                        {
+                        let mut client_network_info = self
+                            .network_info
+                            .write()
+                            .map_err(|_| crate::client::Error::PoisonError)?;
+                        // switch to local PoW
+                        client_network_info.local_pow = true;
+                    }
+                    let block_res = self.finish_block_builder(None, block.payload().cloned()).await;
+                    let block_with_local_pow = match block_res {
+                        Ok(block) => {
+                            // reset local PoW state
                             let mut client_network_info = self
                                 .network_info
                                 .write()
                                 .map_err(|_| crate::client::Error::PoisonError)?;
-                            // switch to local PoW
-                            client_network_info.local_pow = true;
+                            client_network_info.local_pow = false;
+                            block
                         }
-                        let block_res = self.finish_block_builder(None, block.payload().cloned()).await;
-                        let block_with_local_pow = match block_res {
-                            Ok(block) => {
-                                // reset local PoW state
-                                let mut client_network_info = self
-                                    .network_info
-                                    .write()
-                                    .map_err(|_| crate::client::Error::PoisonError)?;
-                                client_network_info.local_pow = false;
-                                block
-                            }
-                            Err(e) => {
-                                // reset local PoW state
-                                self.network_info
-                                    .write()
-                                    .map_err(|_| crate::client::Error::PoisonError)?
-                                    .local_pow = false;
-                                return Err(e);
-                            }
-                        };
-                        let block_dto = BlockDto::from(&block_with_local_pow);
-
-                        self.node_manager
-                            .post_request_json(path, timeout, serde_json::to_value(block_dto)?, true)
-                            .await?
-                    } else {
-                        return Err(Error::Node(e));
-                    }
+                        Err(e) => {
+                            // reset local PoW state
+                            self.network_info
+                                .write()
+                                .map_err(|_| crate::client::Error::PoisonError)?
+                                .local_pow = false;
+                            return Err(e);
+                        }
+                    };
+                    let block_dto = BlockDto::from(&block_with_local_pow);
+
+                    self.node_manager
+                        .post_request_json(path, timeout, serde_json::to_value(block_dto)?, true)
+                        .await?
                 } else {
                     return Err(e);
                 }
@@ -219,9 +296,10 @@ impl Client {
         Ok(BlockId::from_str(&resp.block_id)?)
     }
 
-    /// Returns the BlockId of the submitted block.
+    /// Returns the BlockId of the submitted block, fails without submitting it if the block is too large.
     /// POST /api/core/v2/blocks
     pub async fn post_block_raw(&self, block: &Block) -> Result<BlockId> {
+        validate_block_length(block)?;
         let path = "api/core/v2/blocks";
         let local_pow = self.get_local_pow();
         let timeout = if local_pow {
@@ -238,50 +316,40 @@ impl Client {
         {
             Ok(res) => res,
             Err(e) => {
-                if let Error::Node(e) = e {
-                    let fallback_to_local_pow = self.get_fallback_to_local_pow();
-                    // hornet and bee return different error blocks
-                    if (e == *"no available nodes with remote Pow"
-                        || e.contains("proof of work is not enabled")
-                        || e.contains("`Pow` not enabled"))
-                        && fallback_to_local_pow
+                if remote_pow_unavailable(&e) && self.get_fallback_to_local_pow() {
+                    // Without this we get:within `impl Future<Output = [async output]>`, the trait `Send` is not
+                    // implemented for `std::sync::RwLockWriteGuard<'_, NetworkInfo>`
                     {
-                        // Without this we get:within `impl Future<Output = [async output]>`, the trait `Send` is not
-                        // implemented for `std::sync::RwLockWriteGuard<'_, NetworkInfo>`
-                        {
+                        let mut client_network_info = self
+                            .network_info
+                            .write()
+                            .map_err(|_| crate::client::Error::PoisonError)?;
+                        // switch to local PoW
+                        client_network_info.local_pow = true;
+                    }
+                    let block_res = self.finish_block_builder(None, block.payload().cloned()).await;
+                    let block_with_local_pow = match block_res {
+                        Ok(block) => {
+                            // reset local PoW state
                             let mut client_network_info = self
                                 .network_info
                                 .write()
                                 .map_err(|_| crate::client::Error::PoisonError)?;
-                            // switch to local PoW
-                            client_network_info.local_pow = true;
+                            client_network_info.local_pow = false;
+                            block
                         }
-                        let block_res = self.finish_block_builder(None, block.payload().cloned()).await;
-                        let block_with_local_pow = match block_res {
-                            Ok(block) => {
-                                // reset local PoW state
-                                let mut client_network_info = self
-                                    .network_info
-                                    .write()
-                                    .map_err(|_| crate::client::Error::PoisonError)?;
-                                client_network_info.local_pow = false;
-                                block
-                            }
-                            Err(e) => {
-                                // reset local PoW state
-                                self.network_info
-                                    .write()
-                                    .map_err(|_| crate::client::Error::PoisonError)?
-                                    .local_pow = false;
-                                return Err(e);
-                            }
-                        };
-                        self.node_manager
-                            .post_request_bytes(path, timeout, &block_with_local_pow.pack_to_vec(), true)
-                            .await?
-                    } else {
-                        return Err(Error::Node(e));
-                    }
+                        Err(e) => {
+                            // reset local PoW state
+                            self.network_info
+                                .write()
+                                .map_err(|_| crate::client::Error::PoisonError)?
+                                .local_pow = false;
+                            return Err(e);
+                        }
+                    };
+                    self.node_manager
+                        .post_request_bytes(path, timeout, &block_with_local_pow.pack_to_vec(), true)
+                        .await?
                 } else {
                     return Err(e);
                 }
@@ -294,6 +362,10 @@ impl Client {
     /// Finds a block by its BlockId. This method returns the given block object.
     /// GET /api/core/v2/blocks/{BlockId}
     pub async fn get_block(&self, block_id: &BlockId) -> Result<Block> {
+        if let Some(block) = self.cache.as_ref().and_then(|cache| cache.blocks.get(block_id)) {
+            return Ok(block);
+        }
+
         let path = &format!("api/core/v2/blocks/{block_id}");
 
         let resp = self
@@ -301,10 +373,16 @@ impl Client {
             .get_request::<BlockResponse>(path, None, self.get_timeout(), false, true)
             .await?;
 
-        match resp {
-            BlockResponse::Json(dto) => Ok(Block::try_from_dto(&dto, &self.get_protocol_parameters().await?)?),
-            BlockResponse::Raw(_) => Err(crate::client::Error::UnexpectedApiResponse),
+        let block = match resp {
+            BlockResponse::Json(dto) => Block::try_from_dto(&dto, &self.get_protocol_parameters().await?)?,
+            BlockResponse::Raw(_) => return Err(crate::client::Error::UnexpectedApiResponse),
+        };
+
+        if let Some(cache) = &self.cache {
+            cache.blocks.insert(*block_id, block.clone());
         }
+
+        Ok(block)
     }
 
     /// Finds a block by its BlockId. This method returns the given block raw data.
@@ -327,23 +405,56 @@ impl Client {
             .await
     }
 
+    /// Returns the ids of the blocks that directly reference the block, the node limits the number of returned ids.
+    /// GET /api/core/v2/blocks/{BlockId}/children
+    pub async fn get_block_children(&self, block_id: &BlockId) -> Result<Vec<BlockId>> {
+        let path = &format!("api/core/v2/blocks/{block_id}/children");
+
+        let resp = self
+            .node_manager
+            .get_request::<BlockChildrenResponse>(path, None, self.get_timeout(), false, false)
+            .await?;
+
+        resp.children
+            .iter()
+            .map(|child| BlockId::from_str(child).map_err(Error::Block))
+            .collect::<Result<Vec<_>>>()
+    }
+
+    /// Returns the ids of the blocks that are directly referenced by the block.
+    pub async fn get_block_parents(&self, block_id: &BlockId) -> Result<Vec<BlockId>> {
+        Ok(self.get_block(block_id).await?.parents().to_vec())
+    }
+
     // UTXO routes.
 
     /// Finds an output, as JSON, by its OutputId (TransactionId + output_index).
     /// GET /api/core/v2/outputs/{outputId}
     pub async fn get_output(&self, output_id: &OutputId) -> Result<OutputWithMetadata> {
+        if let Some(output) = self.cache.as_ref().and_then(|cache| cache.spent_outputs.get(output_id)) {
+            return Ok(output);
+        }
+
         let path = &format!("api/core/v2/outputs/{output_id}");
 
         let response: OutputWithMetadataResponse = self
             .node_manager
-            .get_request(path, None, self.get_timeout(), false, true)
+            .get_request(path, None, self.get_timeout(), true, true)
             .await?;
 
         let token_supply = self.get_token_supply().await?;
         let output = Output::try_from_dto(&response.output, token_supply)?;
         let metadata = OutputMetadata::try_from(&response.metadata)?;
+        let output = OutputWithMetadata::new(output, metadata);
+
+        // Only spent outputs can be cached, unspent ones can still get spent
+        if let Some(cache) = &self.cache {
+            if output.metadata().is_spent() {
+                cache.spent_outputs.insert(*output_id, output.clone());
+            }
+        }
 
-        Ok(OutputWithMetadata::new(output, metadata))
+        Ok(output)
     }
 
     /// Finds an output, as raw bytes, by its OutputId (TransactionId + output_index).
@@ -359,53 +470,77 @@ impl Client {
     /// Get the metadata for a given `OutputId` (TransactionId + output_index).
     /// GET /api/core/v2/outputs/{outputId}/metadata
     pub async fn get_output_metadata(&self, output_id: &OutputId) -> Result<OutputMetadataDto> {
+        if let Some(output) = self.cache.as_ref().and_then(|cache| cache.spent_outputs.get(output_id)) {
+            return Ok(OutputMetadataDto::from(output.metadata()));
+        }
+
         let path = &format!("api/core/v2/outputs/{output_id}/metadata");
 
         self.node_manager
-            .get_request::<OutputMetadataDto>(path, None, self.get_timeout(), false, true)
+            .get_request::<OutputMetadataDto>(path, None, self.get_timeout(), true, true)
             .await
     }
 
     /// Gets all stored receipts.
     /// GET /api/core/v2/receipts
-    pub async fn get_receipts(&self) -> Result<Vec<ReceiptDto>> {
+    pub async fn get_receipts(&self) -> Result<Vec<Receipt>> {
         let path = &"api/core/v2/receipts";
 
         let resp = self
             .node_manager
-            .get_request::<ReceiptsResponse>(path, None, DEFAULT_API_TIMEOUT, false, false)
+            .get_request::<ReceiptsResponse>(path, None, self.get_timeout(), false, false)
             .await?;
+        let token_supply = self.get_token_supply().await?;
 
-        Ok(resp.receipts)
+        resp.receipts
+            .iter()
+            .map(|receipt| Ok(Receipt::try_from_dto(receipt, token_supply)?))
+            .collect()
     }
 
     /// Gets the receipts by the given milestone index.
     /// GET /api/core/v2/receipts/{migratedAt}
-    pub async fn get_receipts_migrated_at(&self, milestone_index: u32) -> Result<Vec<ReceiptDto>> {
+    pub async fn get_receipts_migrated_at(&self, milestone_index: u32) -> Result<Vec<Receipt>> {
         let path = &format!("api/core/v2/receipts/{milestone_index}");
 
         let resp = self
             .node_manager
-            .get_request::<ReceiptsResponse>(path, None, DEFAULT_API_TIMEOUT, false, false)
+            .get_request::<ReceiptsResponse>(path, None, self.get_timeout(), false, false)
             .await?;
+        let token_supply = self.get_token_supply().await?;
 
-        Ok(resp.receipts)
+        resp.receipts
+            .iter()
+            .map(|receipt| Ok(Receipt::try_from_dto(receipt, token_supply)?))
+            .collect()
     }
 
     /// Gets the current treasury output.
     /// The treasury output contains all tokens from the legacy network that have not yet been migrated.
     /// GET /api/core/v2/treasury
-    pub async fn get_treasury(&self) -> Result<TreasuryResponse> {
+    pub async fn get_treasury(&self) -> Result<Treasury> {
         let path = "api/core/v2/treasury";
 
-        self.node_manager
-            .get_request(path, None, DEFAULT_API_TIMEOUT, false, false)
-            .await
+        let resp = self
+            .node_manager
+            .get_request::<TreasuryResponse>(path, None, self.get_timeout(), false, false)
+            .await?;
+
+        Ok(Treasury::try_from_dto(&resp, self.get_token_supply().await?)?)
     }
 
     /// Returns the block, as object, that was included in the ledger for a given TransactionId.
     /// GET /api/core/v2/transactions/{transactionId}/included-block
     pub async fn get_included_block(&self, transaction_id: &TransactionId) -> Result<Block> {
+        if let Some(block) = self.cache.as_ref().and_then(|cache| {
+            cache
+                .included_blocks
+                .get(transaction_id)
+                .and_then(|block_id| cache.blocks.get(&block_id))
+        }) {
+            return Ok(block);
+        }
+
         let path = &format!("api/core/v2/transactions/{transaction_id}/included-block");
 
         let resp = self
@@ -413,10 +548,18 @@ impl Client {
             .get_request::<BlockResponse>(path, None, self.get_timeout(), true, true)
             .await?;
 
-        match resp {
-            BlockResponse::Json(dto) => Ok(Block::try_from_dto(&dto, &self.get_protocol_parameters().await?)?),
-            BlockResponse::Raw(_) => Err(crate::client::Error::UnexpectedApiResponse),
+        let block = match resp {
+            BlockResponse::Json(dto) => Block::try_from_dto(&dto, &self.get_protocol_parameters().await?)?,
+            BlockResponse::Raw(_) => return Err(crate::client::Error::UnexpectedApiResponse),
+        };
+
+        if let Some(cache) = &self.cache {
+            let block_id = block.id();
+            cache.included_blocks.insert(*transaction_id, block_id);
+            cache.blocks.insert(block_id, block.clone());
         }
+
+        Ok(block)
     }
 
     /// Returns the block, as raw bytes, that was included in the ledger for a given TransactionId.
@@ -444,6 +587,10 @@ impl Client {
     /// Gets the milestone by the given milestone id.
     /// GET /api/core/v2/milestones/{milestoneId}
     pub async fn get_milestone_by_id(&self, milestone_id: &MilestoneId) -> Result<MilestonePayload> {
+        if let Some(milestone) = self.cache.as_ref().and_then(|cache| cache.milestones.get(milestone_id)) {
+            return Ok(milestone);
+        }
+
         let path = &format!("api/core/v2/milestones/{milestone_id}");
 
         let resp = self
@@ -451,13 +598,16 @@ impl Client {
             .get_request::<MilestoneResponse>(path, None, self.get_timeout(), false, true)
             .await?;
 
-        match resp {
-            MilestoneResponse::Json(dto) => Ok(MilestonePayload::try_from_dto(
-                &dto,
-                &self.get_protocol_parameters().await?,
-            )?),
-            MilestoneResponse::Raw(_) => Err(crate::client::Error::UnexpectedApiResponse),
-        }
+        let milestone = match resp {
+            MilestoneResponse::Json(dto) => {
+                MilestonePayload::try_from_dto(&dto, &self.get_protocol_parameters().await?)?
+            }
+            MilestoneResponse::Raw(_) => return Err(crate::client::Error::UnexpectedApiResponse),
+        };
+
+        self.cache_milestone(&milestone);
+
+        Ok(milestone)
     }
 
     /// Gets the milestone by the given milestone id.
@@ -483,6 +633,15 @@ impl Client {
     /// Gets the milestone by the given milestone index.
     /// GET /api/core/v2/milestones/{index}
     pub async fn get_milestone_by_index(&self, index: u32) -> Result<MilestonePayload> {
+        if let Some(milestone) = self.cache.as_ref().and_then(|cache| {
+            cache
+                .milestone_ids
+                .get(&index)
+                .and_then(|milestone_id| cache.milestones.get(&milestone_id))
+        }) {
+            return Ok(milestone);
+        }
+
         let path = &format!("api/core/v2/milestones/by-index/{index}");
 
         let resp = self
@@ -490,12 +649,24 @@ impl Client {
             .get_request::<MilestoneResponse>(path, None, self.get_timeout(), false, true)
             .await?;
 
-        match resp {
-            MilestoneResponse::Json(dto) => Ok(MilestonePayload::try_from_dto(
-                &dto,
-                &self.get_protocol_parameters().await?,
-            )?),
-            MilestoneResponse::Raw(_) => Err(crate::client::Error::UnexpectedApiResponse),
+        let milestone = match resp {
+            MilestoneResponse::Json(dto) => {
+                MilestonePayload::try_from_dto(&dto, &self.get_protocol_parameters().await?)?
+            }
+            MilestoneResponse::Raw(_) => return Err(crate::client::Error::UnexpectedApiResponse),
+        };
+
+        self.cache_milestone(&milestone);
+
+        Ok(milestone)
+    }
+
+    // Caches a milestone by its id and index, if the cache is enabled
+    fn cache_milestone(&self, milestone: &MilestonePayload) {
+        if let Some(cache) = &self.cache {
+            let milestone_id = milestone.id();
+            cache.milestone_ids.insert(*milestone.essence().index(), milestone_id);
+            cache.milestones.insert(milestone_id, milestone.clone());
         }
     }
 
@@ -519,6 +690,14 @@ impl Client {
             .await
     }
 
+    /// Gets the ids of the outputs that were created and consumed by the milestone with the index.
+    /// GET /api/core/v2/milestones/by-index/{index}/utxo-changes
+    pub async fn get_utxo_changes(&self, milestone_index: u32) -> Result<UtxoChanges> {
+        let utxo_changes = self.get_utxo_changes_by_index(milestone_index).await?;
+
+        Ok(UtxoChanges::try_from(&utxo_changes)?)
+    }
+
     // Peers routes.
 
     /// GET /api/core/v2/peers
@@ -533,15 +712,50 @@ impl Client {
         Ok(resp.0)
     }
 
-    // // RoutePeer is the route for getting peers by their peerID.
-    // // GET returns the peer
-    // // DELETE deletes the peer.
-    // RoutePeer = "/peers/:" + restapipkg.ParameterPeerID
+    /// Returns information about a peer of the node, the node usually requires a JWT for the peer routes.
+    /// GET /api/core/v2/peers/{peerId}
+    #[cfg(feature = "operator")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "operator")))]
+    pub async fn get_peer(&self, peer_id: &str) -> Result<PeerDto> {
+        let path = &format!("api/core/v2/peers/{peer_id}");
+
+        let resp = self
+            .node_manager
+            .get_request::<PeerResponse>(path, None, self.get_timeout(), false, false)
+            .await?;
+
+        Ok(resp.0)
+    }
+
+    /// Adds a peer to the node by its multiaddress, with an optional alias, and returns the added peer.
+    /// POST /api/core/v2/peers
+    #[cfg(feature = "operator")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "operator")))]
+    pub async fn add_peer(&self, multi_address: &str, alias: Option<&str>) -> Result<PeerDto> {
+        let path = "api/core/v2/peers";
+
+        let resp = self
+            .node_manager
+            .post_request_json::<AddPeerResponse>(
+                path,
+                self.get_timeout(),
+                serde_json::json!({ "multiAddress": multi_address, "alias": alias }),
+                true,
+            )
+            .await?;
+
+        Ok(resp.0)
+    }
+
+    /// Removes a peer from the node.
+    /// DELETE /api/core/v2/peers/{peerId}
+    #[cfg(feature = "operator")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "operator")))]
+    pub async fn remove_peer(&self, peer_id: &str) -> Result<()> {
+        let path = &format!("api/core/v2/peers/{peer_id}");
 
-    // // RoutePeers is the route for getting all peers of the node.
-    // // GET returns a list of all peers.
-    // // POST adds a new peer.
-    // RoutePeers = "/peers"
+        self.node_manager.delete_request(path, self.get_timeout()).await
+    }
 
     // Control routes.
 
@@ -553,3 +767,15 @@ impl Client {
     // // POST creates a snapshot (full, delta or both).
     // RouteControlSnapshotsCreate = "/control/snapshots/create"
 }
+
+// Returns true if a block got rejected because the node doesn't do remote PoW, hornet and bee return different errors
+fn remote_pow_unavailable(error: &Error) -> bool {
+    match error {
+        Error::Node(text) | Error::ResponseError { text, .. } => {
+            text == "no available nodes with remote Pow"
+                || text.contains("proof of work is not enabled")
+                || text.contains("`Pow` not enabled")
+        }
+        _ => false,
+    }
+}
@@ -6,10 +6,12 @@
 pub mod query_parameters;
 pub mod routes;
 
+use futures::{Stream, TryStreamExt};
+
 pub(crate) use self::query_parameters::{QueryParameter, QueryParameters};
 use crate::{
     client::{Client, Result},
-    types::api::plugins::indexer::OutputIdsResponse,
+    types::{api::plugins::indexer::OutputIdsResponse, block::output::OutputId},
 };
 
 impl Client {
@@ -58,4 +60,59 @@ impl Client {
 
         Ok(merged_output_ids_response)
     }
+
+    /// Get a stream of output ids for a provided URL route and query parameters, the pages are requested lazily by
+    /// following the cursors, so large result sets don't need to be loaded into memory at once.
+    /// If a `QueryParameter::Cursor(_)` is provided, the stream starts at this page.
+    pub(crate) fn output_ids_stream<'a>(
+        &'a self,
+        route: &'a str,
+        query_parameters: QueryParameters,
+        need_quorum: bool,
+        prefer_permanode: bool,
+    ) -> impl Stream<Item = Result<OutputId>> + 'a {
+        futures::stream::try_unfold(Some(query_parameters), move |query_parameters| async move {
+            // No query parameters means the last page had no cursor
+            match query_parameters {
+                Some(query_parameters) => self
+                    .output_ids_page(route, query_parameters, need_quorum, prefer_permanode)
+                    .await
+                    .map(|(output_ids, next_query_parameters)| {
+                        Some((
+                            futures::stream::iter(output_ids.into_iter().map(Result::Ok)),
+                            next_query_parameters,
+                        ))
+                    }),
+                None => Ok(None),
+            }
+        })
+        .try_flatten()
+    }
+
+    // Requests a single page, returns the output ids and the query parameters for the next page, if there is one.
+    async fn output_ids_page(
+        &self,
+        route: &str,
+        mut query_parameters: QueryParameters,
+        need_quorum: bool,
+        prefer_permanode: bool,
+    ) -> Result<(Vec<OutputId>, Option<QueryParameters>)> {
+        let output_ids_response = self
+            .node_manager
+            .get_request::<OutputIdsResponse>(
+                route,
+                query_parameters.to_query_string().as_deref(),
+                self.get_timeout(),
+                need_quorum,
+                prefer_permanode,
+            )
+            .await?;
+
+        let next_query_parameters = output_ids_response.cursor.map(|cursor| {
+            query_parameters.replace(QueryParameter::Cursor(cursor));
+            query_parameters
+        });
+
+        Ok((output_ids_response.items, next_query_parameters))
+    }
 }
@@ -7,7 +7,10 @@ use std::fmt;
 
 use serde::{Deserialize, Serialize};
 
-use crate::client::{Error, Result};
+use crate::{
+    client::{Error, Result},
+    types::block::address::Bech32Address,
+};
 
 // https://github.com/gohornet/hornet/blob/bb1271be9f3a638f6acdeb6de74eab64515f27f1/plugins/indexer/v1/routes.go#L54
 
@@ -41,6 +44,167 @@ impl QueryParameters {
         }
     }
 
+    /// Bech32-encoded address that should be searched for.
+    #[must_use]
+    pub fn address(mut self, address: &Bech32Address) -> Self {
+        self.replace(QueryParameter::Address(address.to_string()));
+        self
+    }
+
+    /// Filter foundry outputs based on the address of the controlling alias.
+    #[must_use]
+    pub fn alias_address(mut self, address: &Bech32Address) -> Self {
+        self.replace(QueryParameter::AliasAddress(address.to_string()));
+        self
+    }
+
+    /// Returns outputs that were created after a certain Unix timestamp.
+    #[must_use]
+    pub fn created_after(mut self, timestamp: u32) -> Self {
+        self.replace(QueryParameter::CreatedAfter(timestamp));
+        self
+    }
+
+    /// Returns outputs that were created before a certain Unix timestamp.
+    #[must_use]
+    pub fn created_before(mut self, timestamp: u32) -> Self {
+        self.replace(QueryParameter::CreatedBefore(timestamp));
+        self
+    }
+
+    /// Starts the search from the cursor (confirmationMS+outputId.pageSize).
+    #[must_use]
+    pub fn cursor(mut self, cursor: impl Into<String>) -> Self {
+        self.replace(QueryParameter::Cursor(cursor.into()));
+        self
+    }
+
+    /// Filters outputs based on the return address in the expiration unlock condition.
+    #[must_use]
+    pub fn expiration_return_address(mut self, address: &Bech32Address) -> Self {
+        self.replace(QueryParameter::ExpirationReturnAddress(address.to_string()));
+        self
+    }
+
+    /// Returns outputs that expire after a certain Unix timestamp.
+    #[must_use]
+    pub fn expires_after(mut self, timestamp: u32) -> Self {
+        self.replace(QueryParameter::ExpiresAfter(timestamp));
+        self
+    }
+
+    /// Returns outputs that expire before a certain Unix timestamp.
+    #[must_use]
+    pub fn expires_before(mut self, timestamp: u32) -> Self {
+        self.replace(QueryParameter::ExpiresBefore(timestamp));
+        self
+    }
+
+    /// Filters outputs based on the governor (governance controller) address.
+    #[must_use]
+    pub fn governor(mut self, address: &Bech32Address) -> Self {
+        self.replace(QueryParameter::Governor(address.to_string()));
+        self
+    }
+
+    /// Filters outputs based on the presence of expiration unlock condition.
+    #[must_use]
+    pub fn has_expiration(mut self, value: bool) -> Self {
+        self.replace(QueryParameter::HasExpiration(value));
+        self
+    }
+
+    /// Filters outputs based on the presence of native tokens.
+    #[must_use]
+    pub fn has_native_tokens(mut self, value: bool) -> Self {
+        self.replace(QueryParameter::HasNativeTokens(value));
+        self
+    }
+
+    /// Filters outputs based on the presence of storage deposit return unlock condition.
+    #[must_use]
+    pub fn has_storage_deposit_return(mut self, value: bool) -> Self {
+        self.replace(QueryParameter::HasStorageDepositReturn(value));
+        self
+    }
+
+    /// Filters outputs based on the presence of timelock unlock condition.
+    #[must_use]
+    pub fn has_timelock(mut self, value: bool) -> Self {
+        self.replace(QueryParameter::HasTimelock(value));
+        self
+    }
+
+    /// Filters outputs based on the issuer address.
+    #[must_use]
+    pub fn issuer(mut self, address: &Bech32Address) -> Self {
+        self.replace(QueryParameter::Issuer(address.to_string()));
+        self
+    }
+
+    /// Filters outputs that have at most a certain number of distinct native tokens.
+    #[must_use]
+    pub fn max_native_token_count(mut self, count: u32) -> Self {
+        self.replace(QueryParameter::MaxNativeTokenCount(count));
+        self
+    }
+
+    /// Filters outputs that have at least a certain number of distinct native tokens.
+    #[must_use]
+    pub fn min_native_token_count(mut self, count: u32) -> Self {
+        self.replace(QueryParameter::MinNativeTokenCount(count));
+        self
+    }
+
+    /// The maximum amount of items returned in one call.
+    #[must_use]
+    pub fn page_size(mut self, page_size: usize) -> Self {
+        self.replace(QueryParameter::PageSize(page_size));
+        self
+    }
+
+    /// Filters outputs based on the presence of validated sender.
+    #[must_use]
+    pub fn sender(mut self, address: &Bech32Address) -> Self {
+        self.replace(QueryParameter::Sender(address.to_string()));
+        self
+    }
+
+    /// Filters outputs based on the state controller address.
+    #[must_use]
+    pub fn state_controller(mut self, address: &Bech32Address) -> Self {
+        self.replace(QueryParameter::StateController(address.to_string()));
+        self
+    }
+
+    /// Filters outputs based on the return address in the storage deposit return unlock condition.
+    #[must_use]
+    pub fn storage_deposit_return_address(mut self, address: &Bech32Address) -> Self {
+        self.replace(QueryParameter::StorageDepositReturnAddress(address.to_string()));
+        self
+    }
+
+    /// Filters outputs based on matching tag feature, the tag gets hex encoded.
+    #[must_use]
+    pub fn tag(mut self, tag: impl AsRef<[u8]>) -> Self {
+        self.replace(QueryParameter::Tag(prefix_hex::encode(tag.as_ref())));
+        self
+    }
+
+    /// Returns outputs that are timelocked after a certain Unix timestamp.
+    #[must_use]
+    pub fn timelocked_after(mut self, timestamp: u32) -> Self {
+        self.replace(QueryParameter::TimelockedAfter(timestamp));
+        self
+    }
+
+    /// Returns outputs that are timelocked before a certain Unix timestamp.
+    #[must_use]
+    pub fn timelocked_before(mut self, timestamp: u32) -> Self {
+        self.replace(QueryParameter::TimelockedBefore(timestamp));
+        self
+    }
+
     /// Returns true if the slice contains an element with the given kind.
     pub(crate) fn contains(&self, kind: u8) -> bool {
         self.0.iter().any(|q| q.kind() == kind)
@@ -62,6 +226,12 @@ impl QueryParameters {
     }
 }
 
+impl From<QueryParameters> for Vec<QueryParameter> {
+    fn from(value: QueryParameters) -> Self {
+        value.0
+    }
+}
+
 /// Query parameter for output requests.
 #[derive(Debug, Clone, Serialize, Deserialize, Eq, PartialEq)]
 #[serde(rename_all = "camelCase")]
@@ -310,4 +480,25 @@ mod tests {
         // Contains no cursor query parameter
         assert!(!query_parameters.contains(QueryParameter::Cursor(String::new()).kind()));
     }
+
+    #[test]
+    fn query_parameters_builder() {
+        let address = Bech32Address::try_from_str("rms1qpllaj0pyveqfkwxmnngz2c488hfdtmfrj3wfkgxtk4gtyrax0jaxzt70zy")
+            .unwrap();
+
+        let query_parameters = QueryParameters::empty()
+            .tag(b"tag")
+            .has_expiration(false)
+            .address(&address)
+            .page_size(50)
+            .cursor("1680100000.0x1ab2.50");
+        assert_eq!(
+            query_parameters.to_query_string().unwrap(),
+            "address=rms1qpllaj0pyveqfkwxmnngz2c488hfdtmfrj3wfkgxtk4gtyrax0jaxzt70zy&cursor=1680100000.0x1ab2.50&hasExpiration=false&pageSize=50&tag=0x746167"
+        );
+
+        // Setting a parameter again replaces it
+        let query_parameters = query_parameters.page_size(100);
+        assert!(Vec::<QueryParameter>::from(query_parameters).contains(&QueryParameter::PageSize(100)));
+    }
 }
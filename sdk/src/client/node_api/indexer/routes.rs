@@ -3,6 +3,8 @@
 
 //! IOTA node indexer routes
 
+use futures::Stream;
+
 use crate::{
     client::{
         node_api::indexer::{
@@ -37,6 +39,18 @@ impl Client {
         self.get_output_ids(route, query_parameters, true, false).await
     }
 
+    /// Get a stream of basic output ids filtered by the given parameters, transparently following the cursors.
+    /// Supports the same query parameters as [`Client::basic_output_ids()`].
+    /// api/indexer/v1/outputs/basic
+    pub fn basic_output_ids_stream(
+        &self,
+        query_parameters: Vec<QueryParameter>,
+    ) -> Result<impl Stream<Item = Result<OutputId>> + '_> {
+        let query_parameters = verify_query_parameters_basic_outputs(query_parameters)?;
+
+        Ok(self.output_ids_stream("api/indexer/v1/outputs/basic", query_parameters, true, false))
+    }
+
     /// Get alias outputs filtered by the given parameters.
     /// GET with query parameter returns all outputIDs that fit these filter criteria.
     /// Query parameters: "stateController", "governor", "issuer", "sender", "createdBefore", "createdAfter"
@@ -50,6 +64,18 @@ impl Client {
         self.get_output_ids(route, query_parameters, true, false).await
     }
 
+    /// Get a stream of alias output ids filtered by the given parameters, transparently following the cursors.
+    /// Supports the same query parameters as [`Client::alias_output_ids()`].
+    /// api/indexer/v1/outputs/alias
+    pub fn alias_output_ids_stream(
+        &self,
+        query_parameters: Vec<QueryParameter>,
+    ) -> Result<impl Stream<Item = Result<OutputId>> + '_> {
+        let query_parameters = verify_query_parameters_alias_outputs(query_parameters)?;
+
+        Ok(self.output_ids_stream("api/indexer/v1/outputs/alias", query_parameters, true, false))
+    }
+
     /// Get alias output by its aliasID.
     /// api/indexer/v1/outputs/alias/:{AliasId}
     pub async fn alias_output_id(&self, alias_id: AliasId) -> Result<OutputId> {
@@ -75,6 +101,18 @@ impl Client {
         self.get_output_ids(route, query_parameters, true, false).await
     }
 
+    /// Get a stream of foundry output ids filtered by the given parameters, transparently following the cursors.
+    /// Supports the same query parameters as [`Client::foundry_output_ids()`].
+    /// api/indexer/v1/outputs/foundry
+    pub fn foundry_output_ids_stream(
+        &self,
+        query_parameters: Vec<QueryParameter>,
+    ) -> Result<impl Stream<Item = Result<OutputId>> + '_> {
+        let query_parameters = verify_query_parameters_foundry_outputs(query_parameters)?;
+
+        Ok(self.output_ids_stream("api/indexer/v1/outputs/foundry", query_parameters, true, false))
+    }
+
     /// Get foundry output by its foundryID.
     /// api/indexer/v1/outputs/foundry/:{FoundryID}
     pub async fn foundry_output_id(&self, foundry_id: FoundryId) -> Result<OutputId> {
@@ -101,6 +139,18 @@ impl Client {
         self.get_output_ids(route, query_parameters, true, false).await
     }
 
+    /// Get a stream of NFT output ids filtered by the given parameters, transparently following the cursors.
+    /// Supports the same query parameters as [`Client::nft_output_ids()`].
+    /// api/indexer/v1/outputs/nft
+    pub fn nft_output_ids_stream(
+        &self,
+        query_parameters: Vec<QueryParameter>,
+    ) -> Result<impl Stream<Item = Result<OutputId>> + '_> {
+        let query_parameters = verify_query_parameters_nft_outputs(query_parameters)?;
+
+        Ok(self.output_ids_stream("api/indexer/v1/outputs/nft", query_parameters, true, false))
+    }
+
     /// Get NFT output by its nftID.
     /// api/indexer/v1/outputs/nft/:{NftId}
     pub async fn nft_output_id(&self, nft_id: NftId) -> Result<OutputId> {
@@ -4,6 +4,9 @@
 /// MQTT related errors.
 #[derive(Debug, thiserror::Error)]
 pub enum Error {
+    /// The client was cancelled.
+    #[error("the client was cancelled")]
+    Cancelled,
     /// Client error.
     #[error("client error {0}")]
     Client(#[from] rumqttc::ClientError),
@@ -22,7 +22,7 @@ use tokio::sync::{
 
 pub use self::{error::Error, types::*};
 use crate::{
-    client::{Client, NetworkInfo},
+    client::{CancellationToken, Client, NetworkInfo},
     types::block::{
         payload::{milestone::ReceiptMilestoneOption, MilestonePayload},
         Block,
@@ -56,6 +56,9 @@ impl Client {
 }
 
 async fn set_mqtt_client(client: &Client) -> Result<(), Error> {
+    if client.cancellation_token.is_cancelled() {
+        return Err(Error::Cancelled);
+    }
     // if the client was disconnected, we clear it so we can start over
     if *client.mqtt_event_receiver().borrow() == MqttEvent::Disconnected {
         *client.mqtt_client.write().await = None;
@@ -135,6 +138,7 @@ async fn set_mqtt_client(client: &Client) -> Result<(), Error> {
                     client.mqtt_event_channel.0.clone(),
                     connection,
                     client.network_info.clone(),
+                    client.cancellation_token.clone(),
                 );
             }
         }
@@ -149,6 +153,7 @@ fn poll_mqtt(
     event_sender: Arc<Sender<MqttEvent>>,
     mut event_loop: EventLoop,
     network_info: Arc<StdRwLock<NetworkInfo>>,
+    cancellation_token: CancellationToken,
 ) {
     std::thread::spawn(move || {
         let runtime = tokio::runtime::Builder::new_current_thread()
@@ -166,7 +171,14 @@ fn poll_mqtt(
             let mut connection_failure_count = 0;
 
             loop {
-                let event = event_loop.poll().await;
+                let event = tokio::select! {
+                    biased;
+                    _ = cancellation_token.cancelled() => {
+                        let _ = event_sender.send(MqttEvent::Disconnected);
+                        break;
+                    }
+                    event = event_loop.poll() => event,
+                };
                 let mqtt_topic_handlers_guard = mqtt_topic_handlers_guard.clone();
 
                 match event {
@@ -341,7 +353,7 @@ impl<'a> MqttTopicManager<'a> {
 
     /// Add a collection of topics to the list.
     pub fn with_topics(mut self, topics: Vec<Topic>) -> Self {
-        self.topics.extend(topics.into_iter());
+        self.topics.extend(topics);
         self
     }
 
@@ -365,8 +377,7 @@ impl<'a> MqttTopicManager<'a> {
             .subscribe_many(
                 self.topics
                     .iter()
-                    .map(|t| SubscribeFilter::new(t.topic().to_string(), QoS::AtLeastOnce))
-                    .collect::<Vec<SubscribeFilter>>(),
+                    .map(|t| SubscribeFilter::new(t.topic().to_string(), QoS::AtLeastOnce)),
             )
             .await?;
         {
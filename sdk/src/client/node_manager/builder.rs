@@ -4,23 +4,29 @@
 //! The node manager that takes care of sending requests with healthy nodes and quorum if enabled
 
 use std::{
-    collections::{HashMap, HashSet},
+    collections::{BTreeMap, HashMap, HashSet},
     sync::{Arc, RwLock},
     time::Duration,
 };
 
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Serialize, Serializer};
 use url::Url;
 
+#[cfg(feature = "test_utils")]
+use crate::client::test_utils::MockNode;
 use crate::{
     client::{
-        constants::{DEFAULT_MIN_QUORUM_SIZE, DEFAULT_QUORUM_THRESHOLD, DEFAULT_USER_AGENT, NODE_SYNC_INTERVAL},
+        constants::{
+            DEFAULT_MAX_RETRIES, DEFAULT_MIN_QUORUM_SIZE, DEFAULT_QUORUM_THRESHOLD, DEFAULT_USER_AGENT,
+            NODE_SYNC_INTERVAL,
+        },
         error::{Error, Result},
         node_manager::{
             http_client::HttpClient,
             node::{Node, NodeAuth, NodeDto},
             NodeManager,
         },
+        request_journal::{RequestJournalEntry, RequestJournalOptions},
     },
     types::api::core::response::InfoResponse,
 };
@@ -34,13 +40,17 @@ pub struct NodeManagerBuilder {
     /// Node which will be tried first when using remote PoW, even before the primary_node
     pub primary_pow_node: Option<NodeDto>,
     /// Nodes
-    #[serde(default)]
+    #[serde(default, serialize_with = "serialize_nodes")]
     pub nodes: HashSet<NodeDto>,
     /// Permanodes
+    #[serde(serialize_with = "serialize_permanodes")]
     pub permanodes: Option<HashSet<NodeDto>>,
     /// If the node health should be ignored
     #[serde(default)]
     pub ignore_node_health: bool,
+    /// If no nodes are used, so requests fail with [`Error::OfflineMode`] instead of being sent
+    #[serde(default)]
+    pub offline: bool,
     /// Interval in which nodes will be checked for their sync status and the [NetworkInfo](crate::client::NetworkInfo)
     /// gets updated
     #[serde(default = "default_node_sync_interval")]
@@ -58,6 +68,64 @@ pub struct NodeManagerBuilder {
     /// The User-Agent header for requests
     #[serde(default = "default_user_agent")]
     pub user_agent: String,
+    /// Custom headers which will be sent with every request, e.g. API keys for load-balanced nodes
+    #[serde(default, serialize_with = "serialize_headers")]
+    pub headers: HashMap<String, String>,
+    /// HTTP(S) proxy used for all requests, not available for wasm
+    #[serde(default)]
+    pub proxy: Option<Url>,
+    /// JWT sent as bearer token to all nodes without their own JWT
+    #[serde(default)]
+    pub jwt: Option<String>,
+    /// How often idempotent requests are retried if a node responds with 429
+    #[serde(default = "default_max_retries")]
+    pub max_retries: u32,
+    /// Max requests per second that are sent to a single node, no limit if `None`
+    #[serde(default)]
+    pub max_requests_per_second: Option<u32>,
+    /// Max requests that are sent to a single node at the same time, no limit if `None`
+    #[serde(default)]
+    pub max_parallel_requests_per_node: Option<usize>,
+    /// Options for the journal of all requests and responses, disabled if not set
+    #[serde(default)]
+    pub request_journal: Option<RequestJournalOptions>,
+    /// Recorded request journal entries whose responses are returned instead of sending requests
+    #[serde(skip)]
+    pub(crate) request_replay: Option<Vec<RequestJournalEntry>>,
+    /// Mock node that answers all requests instead of the nodes
+    #[cfg(feature = "test_utils")]
+    #[serde(skip)]
+    pub(crate) mock_node: Option<MockNode>,
+}
+
+// Sets and maps are serialized in a fixed order, so the same options are always stored as the same JSON, e.g. in
+// backups
+fn sorted_nodes(nodes: &HashSet<NodeDto>) -> Vec<&NodeDto> {
+    let mut nodes = nodes.iter().collect::<Vec<_>>();
+    nodes.sort_by_cached_key(|node| serde_json::to_string(node).unwrap_or_default());
+    nodes
+}
+
+fn serialize_nodes<S: Serializer>(nodes: &HashSet<NodeDto>, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+    sorted_nodes(nodes).serialize(serializer)
+}
+
+fn serialize_permanodes<S: Serializer>(
+    permanodes: &Option<HashSet<NodeDto>>,
+    serializer: S,
+) -> std::result::Result<S::Ok, S::Error> {
+    permanodes.as_ref().map(sorted_nodes).serialize(serializer)
+}
+
+fn serialize_headers<S: Serializer>(
+    headers: &HashMap<String, String>,
+    serializer: S,
+) -> std::result::Result<S::Ok, S::Error> {
+    headers.iter().collect::<BTreeMap<_, _>>().serialize(serializer)
+}
+
+fn default_max_retries() -> u32 {
+    DEFAULT_MAX_RETRIES
 }
 
 fn default_user_agent() -> String {
@@ -163,6 +231,11 @@ impl NodeManagerBuilder {
         self
     }
 
+    pub(crate) fn with_offline(mut self) -> Self {
+        self.offline = true;
+        self
+    }
+
     pub(crate) fn with_node_auth(mut self, url: &str, auth: Option<NodeAuth>) -> Result<Self> {
         let mut url = validate_url(Url::parse(url)?)?;
         if let Some(auth) = &auth {
@@ -218,7 +291,160 @@ impl NodeManagerBuilder {
         self
     }
 
-    pub(crate) fn build(self, healthy_nodes: Arc<RwLock<HashMap<Node, InfoResponse>>>) -> NodeManager {
+    pub(crate) fn with_header(mut self, name: String, value: String) -> Self {
+        self.headers.insert(name, value);
+        self
+    }
+
+    pub(crate) fn with_headers(mut self, headers: HashMap<String, String>) -> Self {
+        self.headers.extend(headers);
+        self
+    }
+
+    pub(crate) fn with_proxy(mut self, url: &str) -> Result<Self> {
+        self.proxy.replace(Url::parse(url)?);
+        Ok(self)
+    }
+
+    pub(crate) fn with_jwt(mut self, jwt: String) -> Self {
+        self.jwt.replace(jwt);
+        self
+    }
+
+    pub(crate) fn with_max_retries(mut self, max_retries: u32) -> Self {
+        self.max_retries = max_retries;
+        self
+    }
+
+    pub(crate) fn with_max_requests_per_second(mut self, max_requests_per_second: u32) -> Self {
+        self.max_requests_per_second.replace(max_requests_per_second);
+        self
+    }
+
+    pub(crate) fn with_max_parallel_requests_per_node(mut self, max_parallel_requests: usize) -> Self {
+        self.max_parallel_requests_per_node.replace(max_parallel_requests);
+        self
+    }
+
+    pub(crate) fn with_request_journal(mut self, options: Option<RequestJournalOptions>) -> Self {
+        self.request_journal = options;
+        self
+    }
+
+    pub(crate) fn with_request_replay(mut self, entries: Vec<RequestJournalEntry>) -> Self {
+        self.request_replay.replace(entries);
+        self
+    }
+
+    #[cfg(feature = "test_utils")]
+    pub(crate) fn with_mock_node(mut self, mock_node: MockNode) -> Self {
+        self.mock_node.replace(mock_node);
+        self
+    }
+
+    // Returns the nodes with the name of the option they're set in
+    fn nodes_by_option(&self) -> impl Iterator<Item = (&'static str, Node)> + '_ {
+        self.primary_node
+            .iter()
+            .map(|node| ("primaryNode", node.into()))
+            .chain(self.primary_pow_node.iter().map(|node| ("primaryPowNode", node.into())))
+            .chain(self.nodes.iter().map(|node| ("nodes", node.into())))
+            .chain(self.permanodes.iter().flatten().map(|node| ("permanodes", node.into())))
+    }
+
+    /// Validates the URLs of the nodes.
+    pub(crate) fn validate_urls(&self) -> Result<()> {
+        for (_, node) in self.nodes_by_option() {
+            validate_url(node.url)?;
+        }
+        Ok(())
+    }
+
+    /// Validates the options, the errors name the invalid option.
+    pub(crate) fn validate(&self) -> Result<()> {
+        let invalid = |option, reason| Error::InvalidClientOptions { option, reason };
+
+        let mut auths = HashMap::new();
+        for (option, node) in self.nodes_by_option() {
+            validate_url(node.url.clone()).map_err(|err| invalid(option, format!("{}: {err}", node.url)))?;
+            // The same node can't be used with different credentials
+            if option == "nodes" {
+                if let Some(auth) = auths.insert(node.url.clone(), node.auth.clone()) {
+                    if auth != node.auth {
+                        return Err(invalid(
+                            option,
+                            format!("{} is set with different authentications", node.url),
+                        ));
+                    }
+                }
+            }
+        }
+
+        if self.node_sync_interval.is_zero() {
+            return Err(invalid("nodeSyncInterval", "must be greater than zero".to_string()));
+        }
+        if self.quorum {
+            if self.quorum_threshold == 0 || self.quorum_threshold > 100 {
+                return Err(invalid(
+                    "quorumThreshold",
+                    format!("{} isn't a percentage between 1 and 100", self.quorum_threshold),
+                ));
+            }
+            let mut quorum_nodes = self
+                .nodes
+                .iter()
+                .map(|node| Node::from(node).url)
+                .collect::<HashSet<_>>();
+            quorum_nodes.extend(self.primary_node.iter().map(|node| Node::from(node).url));
+            if quorum_nodes.len() < self.min_quorum_size {
+                return Err(Error::QuorumPoolSizeError {
+                    available_nodes: quorum_nodes.len(),
+                    minimum_threshold: self.min_quorum_size,
+                });
+            }
+        }
+
+        reqwest::header::HeaderValue::from_str(&self.user_agent)
+            .map_err(|err| invalid("userAgent", err.to_string()))?;
+        for (name, value) in &self.headers {
+            reqwest::header::HeaderName::from_bytes(name.as_bytes())
+                .map_err(|err| invalid("headers", format!("{name}: {err}")))?;
+            reqwest::header::HeaderValue::from_str(value)
+                .map_err(|err| invalid("headers", format!("{name}: {err}")))?;
+        }
+        if let Some(jwt) = &self.jwt {
+            reqwest::header::HeaderValue::from_str(&format!("Bearer {jwt}"))
+                .map_err(|err| invalid("jwt", err.to_string()))?;
+        }
+        if let Some(proxy) = &self.proxy {
+            if !["http", "https"].contains(&proxy.scheme()) {
+                return Err(invalid("proxy", format!("invalid scheme: {}", proxy.scheme())));
+            }
+        }
+
+        Ok(())
+    }
+
+    pub(crate) fn build_http_client(&self) -> Result<HttpClient> {
+        let http_client = HttpClient::with_options(self.user_agent.clone(), self.headers.clone(), self.proxy.as_ref())?
+            .with_jwt(self.jwt.clone())
+            .with_max_retries(self.max_retries)
+            .with_max_requests_per_second(self.max_requests_per_second)
+            .with_max_parallel_requests_per_node(self.max_parallel_requests_per_node)
+            .with_request_journal(self.request_journal.clone())
+            .with_request_replay(self.request_replay.clone());
+        #[cfg(feature = "test_utils")]
+        let http_client = http_client.with_mock_node(self.mock_node.clone());
+
+        Ok(http_client)
+    }
+
+    pub(crate) fn build(
+        self,
+        healthy_nodes: Arc<RwLock<HashMap<Node, InfoResponse>>>,
+        node_errors: Arc<RwLock<HashMap<Node, String>>>,
+        http_client: HttpClient,
+    ) -> NodeManager {
         NodeManager {
             primary_node: self.primary_node.map(|node| node.into()),
             primary_pow_node: self.primary_pow_node.map(|node| node.into()),
@@ -227,12 +453,14 @@ impl NodeManagerBuilder {
                 .permanodes
                 .map(|nodes| nodes.into_iter().map(|node| node.into()).collect()),
             ignore_node_health: self.ignore_node_health,
+            offline: self.offline,
             node_sync_interval: self.node_sync_interval,
             healthy_nodes,
+            node_errors,
             quorum: self.quorum,
             min_quorum_size: self.min_quorum_size,
             quorum_threshold: self.quorum_threshold,
-            http_client: HttpClient::new(self.user_agent),
+            http_client,
         }
     }
 }
@@ -245,11 +473,22 @@ impl Default for NodeManagerBuilder {
             nodes: HashSet::new(),
             permanodes: None,
             ignore_node_health: false,
+            offline: false,
             node_sync_interval: NODE_SYNC_INTERVAL,
             quorum: false,
             min_quorum_size: DEFAULT_MIN_QUORUM_SIZE,
             quorum_threshold: DEFAULT_QUORUM_THRESHOLD,
             user_agent: DEFAULT_USER_AGENT.to_string(),
+            headers: HashMap::new(),
+            proxy: None,
+            jwt: None,
+            max_retries: DEFAULT_MAX_RETRIES,
+            max_requests_per_second: None,
+            max_parallel_requests_per_node: None,
+            request_journal: None,
+            request_replay: None,
+            #[cfg(feature = "test_utils")]
+            mock_node: None,
         }
     }
 }
@@ -3,40 +3,215 @@
 
 //! The node manager that takes care of sending requests with healthy nodes and quorum if enabled
 
-use std::time::Duration;
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
 
 use reqwest::RequestBuilder;
 use serde::de::DeserializeOwned;
 use serde_json::Value;
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
+use url::Url;
 
+#[cfg(feature = "test_utils")]
+use crate::client::test_utils::MockNode;
 use crate::client::{
+    cancellation::CancellationToken,
+    constants::{DEFAULT_MAX_RETRIES, DEFAULT_RETRY_BASE_DELAY, MAX_RETRY_DELAY},
     error::{Error, Result},
     node_manager::node::Node,
+    request_journal::{RequestJournal, RequestJournalEntry, RequestJournalOptions, RequestReplay},
 };
-pub(crate) struct Response(reqwest::Response);
+pub(crate) struct Response(ResponseInner);
+
+enum ResponseInner {
+    Stream(reqwest::Response),
+    // The body was already read to record it in the request journal
+    Buffered {
+        status: u16,
+        retry_after: Option<Duration>,
+        body: Vec<u8>,
+    },
+}
 
 impl Response {
     pub(crate) fn status(&self) -> u16 {
-        self.0.status().as_u16()
+        match &self.0 {
+            ResponseInner::Stream(response) => response.status().as_u16(),
+            ResponseInner::Buffered { status, .. } => *status,
+        }
+    }
+
+    fn retry_after(&self) -> Option<Duration> {
+        match &self.0 {
+            ResponseInner::Stream(response) => retry_after(response),
+            ResponseInner::Buffered { retry_after, .. } => *retry_after,
+        }
     }
 
     pub(crate) async fn into_json<T: DeserializeOwned>(self) -> Result<T> {
-        self.0.json().await.map_err(Into::into)
+        match self.0 {
+            ResponseInner::Stream(response) => response.json().await.map_err(Into::into),
+            ResponseInner::Buffered { body, .. } => serde_json::from_slice(&body).map_err(Into::into),
+        }
     }
 
     pub(crate) async fn into_text(self) -> Result<String> {
-        self.0.text().await.map_err(Into::into)
+        match self.0 {
+            ResponseInner::Stream(response) => response.text().await.map_err(Into::into),
+            ResponseInner::Buffered { body, .. } => Ok(String::from_utf8_lossy(&body).into_owned()),
+        }
     }
 
     pub(crate) async fn into_bytes(self) -> Result<Vec<u8>> {
-        self.0.bytes().await.map(|b| b.to_vec()).map_err(Into::into)
+        match self.0 {
+            ResponseInner::Stream(response) => response.bytes().await.map(|b| b.to_vec()).map_err(Into::into),
+            ResponseInner::Buffered { body, .. } => Ok(body),
+        }
     }
 }
 
+// The delay requested by the node via the Retry-After header
+pub(crate) fn retry_after(response: &reqwest::Response) -> Option<Duration> {
+    response
+        .headers()
+        .get(reqwest::header::RETRY_AFTER)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.parse::<u64>().ok())
+        .map(Duration::from_secs)
+}
+
+// Limits the requests per second for every node, so public nodes don't reject our requests because of too many
+// requests.
+#[derive(Clone, Default)]
+struct RateLimiter {
+    max_requests_per_second: Option<u32>,
+    // Next time a request can be sent, per node origin
+    next_request_slots: Arc<Mutex<HashMap<String, instant::Instant>>>,
+}
+
+impl RateLimiter {
+    fn new(max_requests_per_second: Option<u32>) -> Self {
+        Self {
+            max_requests_per_second: max_requests_per_second.filter(|max| *max > 0),
+            next_request_slots: Default::default(),
+        }
+    }
+
+    // Reserves the next free slot for the node and waits until it's reached.
+    async fn wait(&self, url: &Url) {
+        let Some(max_requests_per_second) = self.max_requests_per_second else {
+            return;
+        };
+        let interval = Duration::from_secs(1) / max_requests_per_second;
+
+        let delay = {
+            let mut next_request_slots = match self.next_request_slots.lock() {
+                Ok(slots) => slots,
+                // A poisoned lock only means another request panicked, the slots are still usable
+                Err(poisoned) => poisoned.into_inner(),
+            };
+            let now = instant::Instant::now();
+            let slot = next_request_slots
+                .entry(url.origin().ascii_serialization())
+                .or_insert(now);
+            if *slot < now {
+                *slot = now;
+            }
+            let delay = *slot - now;
+            *slot += interval;
+            delay
+        };
+
+        if !delay.is_zero() {
+            sleep(delay).await;
+        }
+    }
+}
+
+// Limits the requests that are in flight at the same time for every node, so syncing many addresses in parallel doesn't
+// overload a single node.
+#[derive(Clone, Default)]
+struct ConcurrencyLimiter {
+    max_parallel_requests: Option<usize>,
+    // Semaphores per node origin
+    semaphores: Arc<Mutex<HashMap<String, Arc<Semaphore>>>>,
+}
+
+impl ConcurrencyLimiter {
+    fn new(max_parallel_requests: Option<usize>) -> Self {
+        Self {
+            max_parallel_requests: max_parallel_requests.filter(|max| *max > 0),
+            semaphores: Default::default(),
+        }
+    }
+
+    // Waits until a request to the node is allowed, the returned permit needs to be held until the request finished.
+    async fn acquire(&self, url: &Url) -> Option<OwnedSemaphorePermit> {
+        let max_parallel_requests = self.max_parallel_requests?;
+
+        let semaphore = {
+            let mut semaphores = match self.semaphores.lock() {
+                Ok(semaphores) => semaphores,
+                // A poisoned lock only means another request panicked, the semaphores are still usable
+                Err(poisoned) => poisoned.into_inner(),
+            };
+            semaphores
+                .entry(url.origin().ascii_serialization())
+                .or_insert_with(|| Arc::new(Semaphore::new(max_parallel_requests)))
+                .clone()
+        };
+
+        // The semaphore is never closed
+        semaphore.acquire_owned().await.ok()
+    }
+}
+
+async fn sleep(duration: Duration) {
+    #[cfg(target_family = "wasm")]
+    gloo_timers::future::TimeoutFuture::new(duration.as_millis() as u32).await;
+    #[cfg(not(target_family = "wasm"))]
+    tokio::time::sleep(duration).await;
+}
+
+// Only requests rejected because of too many requests are retried on the same node, after server errors the node
+// manager fails over to the next node instead.
+fn is_retryable_status(status: u16) -> bool {
+    status == 429
+}
+
+// Exponential backoff with jitter, so clients don't retry in lockstep. A delay requested by the node via the
+// Retry-After header takes precedence.
+fn retry_delay(attempt: u32, retry_after: Option<Duration>) -> Duration {
+    if let Some(retry_after) = retry_after {
+        return retry_after.min(MAX_RETRY_DELAY);
+    }
+    let backoff = DEFAULT_RETRY_BASE_DELAY
+        .saturating_mul(2u32.saturating_pow(attempt))
+        .min(MAX_RETRY_DELAY);
+    // Jitter between 50% and 100% of the backoff, the sub second nanos are random enough for this purpose
+    let jitter_permille = 500 + crate::utils::unix_timestamp_now().subsec_nanos() % 501;
+    backoff * jitter_permille / 1000
+}
+
 #[derive(Clone)]
 pub(crate) struct HttpClient {
     client: reqwest::Client,
     user_agent: String,
+    headers: HashMap<String, String>,
+    // Sent to nodes without their own JWT
+    jwt: Option<String>,
+    max_retries: u32,
+    rate_limiter: RateLimiter,
+    concurrency_limiter: ConcurrencyLimiter,
+    pub(crate) request_journal: Option<Arc<RequestJournal>>,
+    request_replay: Option<Arc<RequestReplay>>,
+    // Aborts the requests in flight and fails new ones once cancelled
+    pub(crate) cancellation_token: CancellationToken,
+    #[cfg(feature = "test_utils")]
+    mock_node: Option<MockNode>,
 }
 
 impl HttpClient {
@@ -44,17 +219,237 @@ impl HttpClient {
         Self {
             client: reqwest::Client::new(),
             user_agent,
+            headers: HashMap::new(),
+            jwt: None,
+            max_retries: DEFAULT_MAX_RETRIES,
+            rate_limiter: RateLimiter::default(),
+            concurrency_limiter: ConcurrencyLimiter::default(),
+            request_journal: None,
+            request_replay: None,
+            cancellation_token: CancellationToken::default(),
+            #[cfg(feature = "test_utils")]
+            mock_node: None,
+        }
+    }
+
+    /// Sends the JWT as bearer token to all nodes without their own JWT, if provided.
+    pub(crate) fn with_jwt(mut self, jwt: Option<String>) -> Self {
+        self.jwt = jwt;
+        self
+    }
+
+    /// Sets how often idempotent requests are retried if a node responds with 429.
+    pub(crate) fn with_max_retries(mut self, max_retries: u32) -> Self {
+        self.max_retries = max_retries;
+        self
+    }
+
+    /// Limits the requests per second that are sent to a single node.
+    pub(crate) fn with_max_requests_per_second(mut self, max_requests_per_second: Option<u32>) -> Self {
+        self.rate_limiter = RateLimiter::new(max_requests_per_second);
+        self
+    }
+
+    /// Limits the requests that are sent to a single node at the same time.
+    pub(crate) fn with_max_parallel_requests_per_node(mut self, max_parallel_requests: Option<usize>) -> Self {
+        self.concurrency_limiter = ConcurrencyLimiter::new(max_parallel_requests);
+        self
+    }
+
+    /// Records all requests and their responses in a journal, if options are provided.
+    pub(crate) fn with_request_journal(mut self, options: Option<RequestJournalOptions>) -> Self {
+        self.request_journal = options.map(|options| Arc::new(RequestJournal::new(options)));
+        self
+    }
+
+    /// Returns the recorded responses instead of sending requests, if entries are provided.
+    pub(crate) fn with_request_replay(mut self, entries: Option<Vec<RequestJournalEntry>>) -> Self {
+        self.request_replay = entries.map(|entries| Arc::new(RequestReplay::new(entries)));
+        self
+    }
+
+    /// Aborts the requests in flight with [`Error::Cancelled`] once the token is cancelled.
+    pub(crate) fn with_cancellation_token(mut self, cancellation_token: CancellationToken) -> Self {
+        self.cancellation_token = cancellation_token;
+        self
+    }
+
+    /// Answers all requests with the mock node instead of sending them, if a mock node is provided.
+    #[cfg(feature = "test_utils")]
+    pub(crate) fn with_mock_node(mut self, mock_node: Option<MockNode>) -> Self {
+        self.mock_node = mock_node;
+        self
+    }
+
+    /// Creates an http client which sends the custom headers with every request and uses the proxy, if provided.
+    /// Proxies are ignored for wasm, because the browser handles them.
+    pub(crate) fn with_options(
+        user_agent: String,
+        headers: HashMap<String, String>,
+        _proxy: Option<&Url>,
+    ) -> Result<Self> {
+        #[allow(unused_mut)]
+        let mut client_builder = reqwest::Client::builder();
+        #[cfg(not(target_family = "wasm"))]
+        if let Some(proxy) = _proxy {
+            client_builder = client_builder.proxy(reqwest::Proxy::all(proxy.as_str())?);
+        }
+
+        Ok(Self {
+            client: client_builder.build()?,
+            user_agent,
+            headers,
+            jwt: None,
+            max_retries: DEFAULT_MAX_RETRIES,
+            rate_limiter: RateLimiter::default(),
+            concurrency_limiter: ConcurrencyLimiter::default(),
+            request_journal: None,
+            request_replay: None,
+            cancellation_token: CancellationToken::default(),
+            #[cfg(feature = "test_utils")]
+            mock_node: None,
+        })
+    }
+
+    // Sends an idempotent request, retries it with backoff up to `max_retries` times if the node rate limits it.
+    async fn send_idempotent(
+        &self,
+        node: &Node,
+        max_retries: u32,
+        build_request: impl Fn() -> RequestBuilder + Send + Sync,
+    ) -> Result<Response> {
+        let mut attempt = 0;
+        loop {
+            let permit = self.concurrency_limiter.acquire(&node.url).await;
+            self.rate_limiter.wait(&node.url).await;
+            let start_time = instant::Instant::now();
+            let resp = self.send("GET", node, build_request(), None).await?;
+            let status = resp.status();
+            log::debug!(
+                "GET: {:?} ms for {} {}",
+                start_time.elapsed().as_millis(),
+                status,
+                node.url
+            );
+
+            if attempt < max_retries && is_retryable_status(status) {
+                let delay = retry_delay(attempt, resp.retry_after());
+                // Other requests to the node can be sent while waiting
+                drop(permit);
+                log::debug!("retrying request to {} in {:?} after status {status}", node.url, delay);
+                self.cancellation_token.sleep(delay).await?;
+                attempt += 1;
+                continue;
+            }
+
+            let response = Self::parse_response(resp, &node.url).await;
+            drop(permit);
+            return response;
         }
     }
 
-    async fn parse_response(response: reqwest::Response, url: &url::Url) -> Result<Response> {
+    // Sends the request and records its duration in the metrics, if they're enabled.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(level = "debug", skip_all, fields(method = method, endpoint = node.url.path()))
+    )]
+    async fn send(
+        &self,
+        method: &str,
+        node: &Node,
+        request_builder: RequestBuilder,
+        request_body: Option<&[u8]>,
+    ) -> Result<Response> {
+        #[cfg(feature = "metrics")]
+        let start_time = instant::Instant::now();
+        #[cfg_attr(not(feature = "metrics"), allow(clippy::let_and_return))]
+        let response = self
+            .cancellation_token
+            .run_until_cancelled(self.send_with_journal(method, node, request_builder, request_body))
+            .await
+            .unwrap_or_else(Err);
+        #[cfg(feature = "metrics")]
+        crate::metrics::record_request(
+            method,
+            node.url.path(),
+            start_time.elapsed(),
+            matches!(&response, Ok(response) if response.status() < 400),
+        );
+
+        response
+    }
+
+    // Sends the request and records it in the request journal, if it's enabled. When a journal is replayed or a mock
+    // node is set, the recorded or mocked response is returned instead.
+    async fn send_with_journal(
+        &self,
+        method: &str,
+        node: &Node,
+        request_builder: RequestBuilder,
+        request_body: Option<&[u8]>,
+    ) -> Result<Response> {
+        #[cfg(feature = "test_utils")]
+        if let Some(mock_node) = &self.mock_node {
+            let response_delay = mock_node.response_delay();
+            if !response_delay.is_zero() {
+                tokio::time::sleep(response_delay).await;
+            }
+            let (status, body) = mock_node.handle(method, &node.url, request_body);
+            return Ok(Response(ResponseInner::Buffered {
+                status,
+                retry_after: None,
+                body,
+            }));
+        }
+        if let Some(request_replay) = &self.request_replay {
+            let (status, body) = request_replay.next(method, &node.url)?;
+            return Ok(Response(ResponseInner::Buffered {
+                status,
+                retry_after: None,
+                body,
+            }));
+        }
+        let Some(request_journal) = &self.request_journal else {
+            return Ok(Response(ResponseInner::Stream(request_builder.send().await?)));
+        };
+
+        let start_time = instant::Instant::now();
+        let response = match request_builder.send().await {
+            Ok(response) => response,
+            Err(e) => {
+                let duration = start_time.elapsed();
+                request_journal.record(method, &node.url, request_body, Err(e.to_string()), duration);
+                return Err(e.into());
+            }
+        };
+        let status = response.status().as_u16();
+        let retry_after = retry_after(&response);
+        let body = match response.bytes().await {
+            Ok(body) => body.to_vec(),
+            Err(e) => {
+                let duration = start_time.elapsed();
+                request_journal.record(method, &node.url, request_body, Err(e.to_string()), duration);
+                return Err(e.into());
+            }
+        };
+        let duration = start_time.elapsed();
+        request_journal.record(method, &node.url, request_body, Ok((status, &body)), duration);
+
+        Ok(Response(ResponseInner::Buffered {
+            status,
+            retry_after,
+            body,
+        }))
+    }
+
+    async fn parse_response(response: Response, url: &url::Url) -> Result<Response> {
         let status = response.status();
-        if status.is_success() {
-            Ok(Response(response))
+        if (200..300).contains(&status) {
+            Ok(response)
         } else {
             Err(Error::ResponseError {
-                code: status.as_u16(),
-                text: response.text().await?,
+                code: status,
+                text: response.into_text().await?,
                 url: url.to_string(),
             })
         }
@@ -63,10 +458,13 @@ impl HttpClient {
     fn build_request(&self, request_builder: RequestBuilder, node: &Node, _timeout: Duration) -> RequestBuilder {
         let mut request_builder = request_builder.header(reqwest::header::USER_AGENT, &self.user_agent);
 
-        if let Some(node_auth) = &node.auth {
-            if let Some(jwt) = &node_auth.jwt {
-                request_builder = request_builder.bearer_auth(jwt);
-            }
+        for (name, value) in &self.headers {
+            request_builder = request_builder.header(name, value);
+        }
+
+        let node_jwt = node.auth.as_ref().and_then(|node_auth| node_auth.jwt.as_ref());
+        if let Some(jwt) = node_jwt.or(self.jwt.as_ref()) {
+            request_builder = request_builder.bearer_auth(jwt);
         }
         #[cfg(not(target_family = "wasm"))]
         {
@@ -76,38 +474,96 @@ impl HttpClient {
     }
 
     pub(crate) async fn get(&self, node: Node, timeout: Duration) -> Result<Response> {
-        let mut request_builder = self.client.get(node.url.clone());
-        request_builder = self.build_request(request_builder, &node, timeout);
-        let start_time = instant::Instant::now();
-        let resp = request_builder.send().await?;
-        log::debug!(
-            "GET: {:?} ms for {} {}",
-            start_time.elapsed().as_millis(),
-            resp.status(),
-            node.url
-        );
-        Self::parse_response(resp, &node.url).await
+        self.send_idempotent(&node, self.max_retries, || {
+            self.build_request(self.client.get(node.url.clone()), &node, timeout)
+        })
+        .await
+    }
+
+    // Get without retries, for health probes that should report an overloaded node right away
+    pub(crate) async fn get_once(&self, node: Node, timeout: Duration) -> Result<Response> {
+        self.send_idempotent(&node, 0, || {
+            self.build_request(self.client.get(node.url.clone()), &node, timeout)
+        })
+        .await
     }
 
     // Get with header: "accept", "application/vnd.iota.serializer-v1"
     pub(crate) async fn get_bytes(&self, node: Node, timeout: Duration) -> Result<Response> {
-        let mut request_builder = self.client.get(node.url.clone());
-        request_builder = self.build_request(request_builder, &node, timeout);
-        request_builder = request_builder.header("accept", "application/vnd.iota.serializer-v1");
-        let resp = request_builder.send().await?;
-        Self::parse_response(resp, &node.url).await
+        self.send_idempotent(&node, self.max_retries, || {
+            self.build_request(self.client.get(node.url.clone()), &node, timeout)
+                .header("accept", "application/vnd.iota.serializer-v1")
+        })
+        .await
     }
 
     pub(crate) async fn post_json(&self, node: Node, timeout: Duration, json: Value) -> Result<Response> {
+        let _permit = self.concurrency_limiter.acquire(&node.url).await;
+        self.rate_limiter.wait(&node.url).await;
         let mut request_builder = self.client.post(node.url.clone());
         request_builder = self.build_request(request_builder, &node, timeout);
-        Self::parse_response(request_builder.json(&json).send().await?, &node.url).await
+        // Only serialized separately if it's recorded or mocked
+        #[cfg(not(feature = "test_utils"))]
+        let serialize_body = self.request_journal.is_some();
+        #[cfg(feature = "test_utils")]
+        let serialize_body = self.request_journal.is_some() || self.mock_node.is_some();
+        let request_body = serialize_body.then(|| serde_json::to_vec(&json)).transpose()?;
+        let response = self
+            .send("POST", &node, request_builder.json(&json), request_body.as_deref())
+            .await?;
+        Self::parse_response(response, &node.url).await
     }
 
     pub(crate) async fn post_bytes(&self, node: Node, timeout: Duration, body: &[u8]) -> Result<Response> {
+        let _permit = self.concurrency_limiter.acquire(&node.url).await;
+        self.rate_limiter.wait(&node.url).await;
         let mut request_builder = self.client.post(node.url.clone());
         request_builder = self.build_request(request_builder, &node, timeout);
         request_builder = request_builder.header("Content-Type", "application/vnd.iota.serializer-v1");
-        Self::parse_response(request_builder.body(body.to_vec()).send().await?, &node.url).await
+        let response = self
+            .send("POST", &node, request_builder.body(body.to_vec()), Some(body))
+            .await?;
+        Self::parse_response(response, &node.url).await
+    }
+
+    #[cfg(feature = "operator")]
+    pub(crate) async fn delete(&self, node: Node, timeout: Duration) -> Result<Response> {
+        let _permit = self.concurrency_limiter.acquire(&node.url).await;
+        self.rate_limiter.wait(&node.url).await;
+        let request_builder = self.build_request(self.client.delete(node.url.clone()), &node, timeout);
+        let response = self.send("DELETE", &node, request_builder, None).await?;
+        Self::parse_response(response, &node.url).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn retryable_status() {
+        assert!(is_retryable_status(429));
+        // Server errors fail over to the next node instead
+        assert!(!is_retryable_status(500));
+        assert!(!is_retryable_status(503));
+        assert!(!is_retryable_status(404));
+    }
+
+    #[test]
+    fn retry_delay_honors_retry_after() {
+        assert_eq!(retry_delay(0, Some(Duration::from_secs(2))), Duration::from_secs(2));
+        assert_eq!(retry_delay(5, Some(Duration::from_secs(3600))), MAX_RETRY_DELAY);
+
+        let delay = retry_delay(1, None);
+        assert!(delay >= DEFAULT_RETRY_BASE_DELAY && delay <= DEFAULT_RETRY_BASE_DELAY * 2);
+    }
+
+    #[test]
+    fn send_idempotent_is_send() {
+        fn assert_send<T: Send>(_: &T) {}
+        // Only type checked, the requests must be sendable from the tasks of a multi-threaded runtime
+        let _ = |http_client: &HttpClient, node: &Node| {
+            assert_send(&http_client.send_idempotent(node, 0, || http_client.client.get(node.url.clone())));
+        };
     }
 }
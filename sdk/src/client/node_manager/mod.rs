@@ -35,8 +35,11 @@ pub(crate) struct NodeManager {
     pub(crate) nodes: HashSet<Node>,
     permanodes: Option<HashSet<Node>>,
     pub(crate) ignore_node_health: bool,
+    pub(crate) offline: bool,
     node_sync_interval: Duration,
     pub(crate) healthy_nodes: Arc<RwLock<HashMap<Node, InfoResponse>>>,
+    // the reasons why the other nodes aren't healthy, from the last node sync
+    pub(crate) node_errors: Arc<RwLock<HashMap<Node, String>>>,
     quorum: bool,
     min_quorum_size: usize,
     quorum_threshold: usize,
@@ -51,8 +54,10 @@ impl std::fmt::Debug for NodeManager {
         d.field("nodes", &self.nodes);
         d.field("permanodes", &self.permanodes);
         d.field("ignore_node_health", &self.ignore_node_health);
+        d.field("offline", &self.offline);
         d.field("node_sync_interval", &self.node_sync_interval);
         d.field("healthy_nodes", &self.healthy_nodes);
+        d.field("node_errors", &self.node_errors);
         d.field("quorum", &self.quorum);
         d.field("min_quorum_size", &self.min_quorum_size);
         d.field("quorum_threshold", &self.quorum_threshold).finish()
@@ -64,6 +69,22 @@ impl NodeManager {
         NodeManagerBuilder::new()
     }
 
+    // Returns if the node is in the healthy node pool, always true if the node health is ignored.
+    fn is_healthy(&self, node: &Node) -> Result<bool> {
+        #[cfg(not(target_family = "wasm"))]
+        if !self.ignore_node_health {
+            return Ok(self
+                .healthy_nodes
+                .read()
+                .map_err(|_| crate::client::Error::PoisonError)?
+                .contains_key(node));
+        }
+        #[cfg(target_family = "wasm")]
+        let _ = node;
+
+        Ok(true)
+    }
+
     fn get_nodes(
         &self,
         path: &str,
@@ -71,6 +92,11 @@ impl NodeManager {
         use_pow_nodes: bool,
         prefer_permanode: bool,
     ) -> Result<Vec<Node>> {
+        // All requests select their nodes here, so this is the only place that needs to check for the offline mode
+        if self.offline {
+            return Err(Error::OfflineMode(path.to_string()));
+        }
+
         let mut nodes_with_modified_url: Vec<Node> = Vec::new();
 
         if prefer_permanode || (path == "api/core/v2/blocks" && query.is_some()) {
@@ -91,9 +117,16 @@ impl NodeManager {
             }
         }
 
+        // An unhealthy primary node is only used as last resort, so a dead primary node doesn't slow down every
+        // request with a timeout before failing over to the other nodes
+        let mut unhealthy_primary_node = None;
         if let Some(primary_node) = self.primary_node.clone() {
             if !nodes_with_modified_url.iter().any(|n| n.url == primary_node.url) {
-                nodes_with_modified_url.push(primary_node);
+                if self.is_healthy(&primary_node)? {
+                    nodes_with_modified_url.push(primary_node);
+                } else {
+                    unhealthy_primary_node.replace(primary_node);
+                }
             }
         }
 
@@ -136,6 +169,12 @@ impl NodeManager {
             }
         }
 
+        if let Some(primary_node) = unhealthy_primary_node {
+            if !nodes_with_modified_url.iter().any(|n| n.url == primary_node.url) {
+                nodes_with_modified_url.push(primary_node);
+            }
+        }
+
         // remove disabled nodes
         nodes_with_modified_url.retain(|n| !n.disabled);
 
@@ -148,23 +187,31 @@ impl NodeManager {
 
         // Set path and query parameters
         for node in &mut nodes_with_modified_url {
-            node.url.set_path(path);
-            node.url.set_query(query);
-            if let Some(auth) = &node.auth {
-                if let Some((name, password)) = &auth.basic_auth_name_pwd {
-                    node.url
-                        .set_username(name)
-                        .map_err(|_| crate::client::Error::UrlAuth("username"))?;
-                    node.url
-                        .set_password(Some(password))
-                        .map_err(|_| crate::client::Error::UrlAuth("password"))?;
-                }
-            }
+            set_request_url(node, path, query)?;
         }
 
         Ok(nodes_with_modified_url)
     }
 
+    // Returns the permanodes for a request of data that the other nodes didn't find, because they already pruned it.
+    fn get_permanodes_for_pruned(&self, error: &Error, path: &str, query: Option<&str>) -> Result<Vec<Node>> {
+        if !is_pruned(error) {
+            return Ok(Vec::new());
+        }
+        let mut permanodes = self
+            .permanodes
+            .iter()
+            .flatten()
+            .filter(|permanode| !permanode.disabled)
+            .cloned()
+            .collect::<Vec<_>>();
+        for permanode in &mut permanodes {
+            set_request_url(permanode, path, query)?;
+        }
+
+        Ok(permanodes)
+    }
+
     pub(crate) async fn get_request<T: serde::de::DeserializeOwned + std::fmt::Debug + serde::Serialize>(
         &self,
         path: &str,
@@ -173,10 +220,39 @@ impl NodeManager {
         need_quorum: bool,
         prefer_permanode: bool,
     ) -> Result<T> {
-        let mut result: HashMap<String, usize> = HashMap::new();
         // primary_pow_node should only be used for post request with remote PoW
         // Get node urls and set path
         let nodes = self.get_nodes(path, query, false, prefer_permanode)?;
+        let error = match self
+            .get_request_with_nodes(nodes, path, query, timeout, need_quorum)
+            .await
+        {
+            // The permanodes were already requested if they're preferred
+            Err(error) if !prefer_permanode => error,
+            result => return result,
+        };
+
+        // Pruned data can still be requested from the permanodes, quorum isn't possible with them only
+        let permanodes = self.get_permanodes_for_pruned(&error, path, query)?;
+        if permanodes.is_empty() {
+            return Err(error);
+        }
+        log::debug!("[NODE MANAGER] requesting pruned data {path} from permanodes");
+        self.get_request_with_nodes(permanodes, path, query, timeout, false)
+            .await
+            .map_err(|_| error)
+    }
+
+    async fn get_request_with_nodes<T: serde::de::DeserializeOwned + std::fmt::Debug + serde::Serialize>(
+        &self,
+        nodes: Vec<Node>,
+        path: &str,
+        query: Option<&str>,
+        timeout: Duration,
+        need_quorum: bool,
+    ) -> Result<T> {
+        // The responses by their quorum key, with the number of nodes that returned them
+        let mut result: HashMap<String, (usize, String)> = HashMap::new();
         if self.quorum && need_quorum && nodes.len() < self.min_quorum_size {
             return Err(Error::QuorumPoolSizeError {
                 available_nodes: nodes.len(),
@@ -209,9 +285,13 @@ impl NodeManager {
                                 log::warn!("couldn't convert node response to text");
                             },
                             |res_text| {
-                                let counters = result.entry(res_text).or_insert(0);
-                                *counters += 1;
-                                result_counter += 1;
+                                quorum_key(&res_text).map_or_else(
+                                    |_| log::warn!("couldn't parse node response"),
+                                    |key| {
+                                        result.entry(key).or_insert((0, res_text)).0 += 1;
+                                        result_counter += 1;
+                                    },
+                                )
                             },
                         ),
                         Err(Error::ResponseError { code: 404, url, .. }) => {
@@ -243,8 +323,8 @@ impl NodeManager {
 
                                 match res.into_json::<T>().await {
                                     Ok(result_data) => {
-                                        let counters = result.entry(serde_json::to_string(&result_data)?).or_insert(0);
-                                        *counters += 1;
+                                        let res_text = serde_json::to_string(&result_data)?;
+                                        result.entry(quorum_key(&res_text)?).or_insert((0, res_text)).0 += 1;
                                         result_counter += 1;
                                         // Without quorum it's enough if we got one response
                                         if !self.quorum
@@ -281,22 +361,22 @@ impl NodeManager {
             }
         }
 
-        let res = result
-            .into_iter()
-            .max_by_key(|v| v.1)
+        let (count, res_text) = result
+            .into_values()
+            .max_by_key(|(count, _)| *count)
             .ok_or_else(|| error.unwrap_or_else(|| Error::Node("couldn't get a result from any node".into())))?;
 
         // Return if quorum is false or check if quorum was reached
         if !self.quorum
-            || res.1 as f64 >= self.min_quorum_size as f64 * (self.quorum_threshold as f64 / 100.0)
+            || count as f64 >= self.min_quorum_size as f64 * (self.quorum_threshold as f64 / 100.0)
             || !need_quorum
             // with query we ignore quorum because the nodes can store a different amount of history
             || query.is_some()
         {
-            Ok(serde_json::from_str(&res.0)?)
+            Ok(serde_json::from_str(&res_text)?)
         } else {
             Err(Error::QuorumThresholdError {
-                quorum_size: res.1,
+                quorum_size: count,
                 minimum_threshold: self.min_quorum_size,
             })
         }
@@ -312,6 +392,23 @@ impl NodeManager {
         // primary_pow_node should only be used for post request with remote Pow
         // Get node urls and set path
         let nodes = self.get_nodes(path, query, false, false)?;
+        let error = match self.get_request_bytes_with_nodes(nodes, timeout).await {
+            Ok(bytes) => return Ok(bytes),
+            Err(error) => error,
+        };
+
+        // Pruned blocks can still be requested from the permanodes
+        let permanodes = self.get_permanodes_for_pruned(&error, path, query)?;
+        if permanodes.is_empty() {
+            return Err(error);
+        }
+        log::debug!("[NODE MANAGER] requesting pruned data {path} from permanodes");
+        self.get_request_bytes_with_nodes(permanodes, timeout)
+            .await
+            .map_err(|_| error)
+    }
+
+    async fn get_request_bytes_with_nodes(&self, nodes: Vec<Node>, timeout: Duration) -> Result<Vec<u8>> {
         let mut error = None;
         // Send requests
         for node in nodes {
@@ -367,7 +464,7 @@ impl NodeManager {
                     };
                 }
                 Err(e) => {
-                    error.replace(crate::client::Error::Node(e.to_string()));
+                    error.replace(e);
                 }
             }
         }
@@ -401,10 +498,110 @@ impl NodeManager {
                     };
                 }
                 Err(e) => {
-                    error.replace(crate::client::Error::Node(e.to_string()));
+                    error.replace(e);
                 }
             }
         }
         Err(error.unwrap_or_else(|| Error::Node("couldn't get a result from any node".into())))
     }
+
+    #[cfg(feature = "operator")]
+    pub(crate) async fn delete_request(&self, path: &str, timeout: Duration) -> Result<()> {
+        let nodes = self.get_nodes(path, None, false, false)?;
+        let mut error = None;
+        // Send requests
+        for node in nodes {
+            match self.http_client.delete(node, timeout).await {
+                Ok(_) => return Ok(()),
+                Err(Error::ResponseError { code: 404, url, .. }) => {
+                    error.replace(crate::client::Error::NotFound(url));
+                }
+                Err(e) => {
+                    error.replace(e);
+                }
+            }
+        }
+        Err(error.unwrap_or_else(|| Error::Node("couldn't get a result from any node".into())))
+    }
+}
+
+// Sets the path, query and basic auth of a request to the node.
+fn set_request_url(node: &mut Node, path: &str, query: Option<&str>) -> Result<()> {
+    node.url.set_path(path);
+    node.url.set_query(query);
+    if let Some(auth) = &node.auth {
+        if let Some((name, password)) = &auth.basic_auth_name_pwd {
+            node.url
+                .set_username(name)
+                .map_err(|_| crate::client::Error::UrlAuth("username"))?;
+            node.url
+                .set_password(Some(password))
+                .map_err(|_| crate::client::Error::UrlAuth("password"))?;
+        }
+    }
+
+    Ok(())
+}
+
+// Nodes answer requests for pruned data with not found, or an error mentioning it.
+fn is_pruned(error: &Error) -> bool {
+    match error {
+        Error::NotFound(_) => true,
+        Error::ResponseError { text, .. } => text.contains("pruned"),
+        _ => false,
+    }
+}
+
+// Fields of responses that depend on the state of the node that answered instead of the requested data, e.g. the ledger
+// index of output responses, synced nodes can still differ in them.
+const NODE_DEPENDENT_FIELDS: [&str; 3] = ["ledgerIndex", "milestoneIndexBooked", "milestoneTimestampBooked"];
+
+// Returns the key to compare the response of a node with the ones of other nodes for the quorum, without the node
+// dependent fields.
+fn quorum_key(response: &str) -> Result<String> {
+    let mut response = serde_json::from_str::<Value>(response)?;
+    remove_node_dependent_fields(&mut response);
+    Ok(response.to_string())
+}
+
+fn remove_node_dependent_fields(value: &mut Value) {
+    if let Value::Object(map) = value {
+        for field in NODE_DEPENDENT_FIELDS {
+            map.remove(field);
+        }
+        map.values_mut().for_each(remove_node_dependent_fields);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn quorum_key_ignores_node_dependent_fields() {
+        let response = |ledger_index: u32, milestone_index_booked: u32, is_spent: bool| {
+            json!({
+                "metadata": {
+                    "blockId": "0x0000000000000000000000000000000000000000000000000000000000000000",
+                    "isSpent": is_spent,
+                    "milestoneIndexBooked": milestone_index_booked,
+                    "milestoneTimestampBooked": milestone_index_booked * 10,
+                    "ledgerIndex": ledger_index,
+                },
+                "output": { "type": 3, "amount": "1000000" },
+            })
+            .to_string()
+        };
+
+        assert_eq!(
+            quorum_key(&response(10, 5, false)).unwrap(),
+            quorum_key(&response(11, 6, false)).unwrap()
+        );
+        assert_ne!(
+            quorum_key(&response(10, 5, false)).unwrap(),
+            quorum_key(&response(10, 5, true)).unwrap()
+        );
+    }
 }
@@ -29,6 +29,17 @@ pub struct Node {
     pub disabled: bool,
 }
 
+/// A node that is left out of the healthy node pool, returned by
+/// [`Client::unhealthy_nodes()`](crate::client::Client::unhealthy_nodes).
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct UnhealthyNode {
+    /// The node.
+    pub node: Node,
+    /// Why the node is unhealthy, from the last node sync, `None` if the node wasn't synced yet.
+    pub error: Option<String>,
+}
+
 impl From<Url> for Node {
     fn from(url: Url) -> Self {
         Self {
@@ -3,15 +3,19 @@
 
 #[cfg(not(target_family = "wasm"))]
 use {
-    crate::client::NetworkInfo,
-    crate::types::{api::core::response::InfoResponse, block::protocol::ProtocolParameters},
+    super::{http_client::HttpClient, node::UnhealthyNode},
+    crate::client::{constants::DEFAULT_API_TIMEOUT, NetworkInfo},
+    crate::types::{
+        api::core::response::InfoResponse,
+        block::protocol::{verify_protocol_version, ProtocolParameters},
+    },
     std::collections::HashMap,
     std::{
         collections::HashSet,
         sync::{Arc, RwLock},
         time::Duration,
     },
-    tokio::{runtime::Runtime, time::sleep},
+    tokio::runtime::Runtime,
 };
 
 use super::Node;
@@ -19,7 +23,29 @@ use crate::client::{Client, Error, Result};
 
 impl Client {
     /// Get a node candidate from the healthy node pool.
+    /// The primary node is preferred as long as it's healthy, otherwise another healthy node is returned.
     pub fn get_node(&self) -> Result<Node> {
+        #[cfg(not(target_family = "wasm"))]
+        if !self.node_manager.ignore_node_health {
+            let healthy_nodes = self
+                .node_manager
+                .healthy_nodes
+                .read()
+                .map_err(|_| crate::client::Error::PoisonError)?;
+
+            if let Some(primary_node) = &self.node_manager.primary_node {
+                if healthy_nodes.contains_key(primary_node) {
+                    return Ok(primary_node.clone());
+                }
+            }
+
+            return healthy_nodes
+                .keys()
+                .find(|node| !node.disabled)
+                .cloned()
+                .ok_or(Error::HealthyNodePoolEmpty);
+        }
+
         if let Some(primary_node) = &self.node_manager.primary_node {
             return Ok(primary_node.clone());
         }
@@ -29,37 +55,95 @@ impl Client {
         pool.into_iter().next().ok_or(Error::HealthyNodePoolEmpty)
     }
 
-    /// returns the unhealthy nodes.
+    /// returns the healthy nodes.
+    #[cfg(not(target_family = "wasm"))]
+    pub fn healthy_nodes(&self) -> HashSet<Node> {
+        self.node_manager.healthy_nodes.read().map_or_else(
+            |_| HashSet::new(),
+            |healthy_nodes| healthy_nodes.keys().cloned().collect(),
+        )
+    }
+
+    /// Returns the nodes that are left out of the healthy node pool, with the error of the last node sync, e.g. to
+    /// show the connection status.
     #[cfg(not(target_family = "wasm"))]
-    pub fn unhealthy_nodes(&self) -> HashSet<&Node> {
+    pub fn unhealthy_nodes(&self) -> Vec<UnhealthyNode> {
+        let (Ok(healthy_nodes), Ok(node_errors)) = (
+            self.node_manager.healthy_nodes.read(),
+            self.node_manager.node_errors.read(),
+        ) else {
+            return Vec::new();
+        };
+
         self.node_manager
-            .healthy_nodes
-            .read()
-            .map_or(HashSet::new(), |healthy_nodes| {
-                self.node_manager
-                    .nodes
-                    .iter()
-                    .filter(|node| !healthy_nodes.contains_key(node))
-                    .collect()
+            .nodes
+            .iter()
+            .filter(|node| !healthy_nodes.contains_key(node))
+            .map(|node| UnhealthyNode {
+                node: node.clone(),
+                error: node_errors.get(node).cloned(),
             })
+            .collect()
+    }
+
+    /// Returns an error if the protocol parameters were set with
+    /// [`ClientBuilder::with_protocol_parameters()`](crate::client::ClientBuilder::with_protocol_parameters) for
+    /// another network than the one of the healthy nodes, as they aren't replaced by the ones of the nodes.
+    #[cfg(not(target_family = "wasm"))]
+    pub(crate) fn verify_network_of_nodes(&self) -> Result<()> {
+        let network_info = self.network_info.read().map_err(|_| Error::PoisonError)?;
+        if !network_info.protocol_parameters_overridden {
+            return Ok(());
+        }
+        let network_name = network_info.protocol_parameters.network_name();
+        let healthy_nodes = self.node_manager.healthy_nodes.read().map_err(|_| Error::PoisonError)?;
+
+        match healthy_nodes
+            .iter()
+            .find(|(_, info)| info.protocol.network_name != network_name)
+        {
+            Some((node, info)) => Err(Error::InvalidClientOptions {
+                option: "protocolParameters",
+                reason: format!(
+                    "network {network_name} doesn't match network {} of {}",
+                    info.protocol.network_name, node.url
+                ),
+            }),
+            None => Ok(()),
+        }
     }
 
     /// Sync the node lists per node_sync_interval milliseconds
     #[cfg(not(target_family = "wasm"))]
+    #[allow(clippy::too_many_arguments)]
     pub(crate) fn start_sync_process(
         runtime: &Runtime,
         sync: Arc<RwLock<HashMap<Node, InfoResponse>>>,
+        node_errors: Arc<RwLock<HashMap<Node, String>>>,
         nodes: HashSet<Node>,
         node_sync_interval: Duration,
         network_info: Arc<RwLock<NetworkInfo>>,
         ignore_node_health: bool,
+        http_client: HttpClient,
     ) -> tokio::task::JoinHandle<()> {
         runtime.spawn(async move {
             loop {
                 // Delay first since the first `sync_nodes` call is made by the builder to ensure the node list is
-                // filled before the client is used.
-                sleep(node_sync_interval).await;
-                if let Err(e) = Self::sync_nodes(&sync, &nodes, &network_info, ignore_node_health).await {
+                // filled before the client is used. Stops once the client is cancelled.
+                if http_client.cancellation_token.sleep(node_sync_interval).await.is_err() {
+                    log::debug!("Node syncing stopped");
+                    break;
+                }
+                if let Err(e) = Self::sync_nodes(
+                    &sync,
+                    &node_errors,
+                    &nodes,
+                    &network_info,
+                    ignore_node_health,
+                    &http_client,
+                )
+                .await
+                {
                     log::warn!("Syncing nodes failed: {e}");
                 }
             }
@@ -69,19 +153,54 @@ impl Client {
     #[cfg(not(target_family = "wasm"))]
     pub(crate) async fn sync_nodes(
         sync: &Arc<RwLock<HashMap<Node, InfoResponse>>>,
+        node_errors: &Arc<RwLock<HashMap<Node, String>>>,
         nodes: &HashSet<Node>,
         network_info: &Arc<RwLock<NetworkInfo>>,
         ignore_node_health: bool,
+        http_client: &HttpClient,
     ) -> Result<()> {
         log::debug!("sync_nodes");
         let mut healthy_nodes = HashMap::new();
+        let mut errors = HashMap::new();
         let mut network_nodes: HashMap<String, Vec<(InfoResponse, Node)>> = HashMap::new();
+        let mut unsupported_protocol_parameters = None;
 
         for node in nodes {
             // Put the healthy node url into the network_nodes
-            match Self::get_node_info(node.url.as_ref(), node.auth.clone()).await {
+            match Self::get_node_info_with_http_client(
+                http_client,
+                node.url.as_ref(),
+                node.auth.clone(),
+                DEFAULT_API_TIMEOUT,
+            )
+            .await
+            {
                 Ok(info) => {
-                    if info.status.is_healthy || ignore_node_health {
+                    // The info response can be cached by proxies, so we also ask the /health endpoint directly, unless
+                    // the health is ignored anyway
+                    let is_healthy = ignore_node_health
+                        || (info.status.is_healthy
+                            && Self::get_node_health_with_http_client(
+                                http_client,
+                                node.url.as_ref(),
+                                node.auth.clone(),
+                                DEFAULT_API_TIMEOUT,
+                            )
+                            .await
+                            .unwrap_or(false));
+                    // Nodes of protocol versions we can't pack blocks for are not usable
+                    if verify_protocol_version(info.protocol.protocol_version).is_err() {
+                        log::warn!(
+                            "{} runs unsupported protocol version {}",
+                            node.url,
+                            info.protocol.protocol_version
+                        );
+                        errors.insert(
+                            node.clone(),
+                            format!("unsupported protocol version {}", info.protocol.protocol_version),
+                        );
+                        unsupported_protocol_parameters.get_or_insert(info.protocol);
+                    } else if is_healthy {
                         match network_nodes.get_mut(&info.protocol.network_name) {
                             Some(network_node_entry) => {
                                 network_node_entry.push((info, node.clone()));
@@ -92,10 +211,12 @@ impl Client {
                         }
                     } else {
                         log::debug!("{} is not healthy: {:?}", node.url, info);
+                        errors.insert(node.clone(), "node is not healthy".to_string());
                     }
                 }
                 Err(err) => {
                     log::error!("Couldn't get node info: {err}");
+                    errors.insert(node.clone(), err.to_string());
                 }
             }
         }
@@ -114,16 +235,38 @@ impl Client {
                 let mut network_info = network_info.write().map_err(|_| crate::client::Error::PoisonError)?;
 
                 network_info.latest_milestone_timestamp = info.status.latest_milestone.timestamp;
-                network_info.protocol_parameters = ProtocolParameters::try_from(info.protocol.clone())?;
+                if !network_info.protocol_parameters_overridden {
+                    network_info.protocol_parameters = ProtocolParameters::try_from(info.protocol.clone())?;
+                }
             }
 
             for (info, node_url) in nodes {
                 healthy_nodes.insert(node_url.clone(), info.clone());
             }
+            // Only the nodes of the network with the most nodes are used
+            for (network_name, nodes) in &network_nodes {
+                if network_name != most_nodes.0 {
+                    for (_, node) in nodes {
+                        errors.insert(
+                            node.clone(),
+                            format!("node is on network {network_name}, most nodes are on {}", most_nodes.0),
+                        );
+                    }
+                }
+            }
+        } else if let Some(protocol_parameters) = unsupported_protocol_parameters {
+            // Without usable nodes the protocol parameters of the unsupported ones are taken over, so building
+            // transactions fails with their protocol version instead of using outdated parameters
+            let mut network_info = network_info.write().map_err(|_| crate::client::Error::PoisonError)?;
+
+            if !network_info.protocol_parameters_overridden {
+                network_info.protocol_parameters = ProtocolParameters::try_from(protocol_parameters)?;
+            }
         }
 
         // Update the sync list.
         *sync.write().map_err(|_| crate::client::Error::PoisonError)? = healthy_nodes;
+        *node_errors.write().map_err(|_| crate::client::Error::PoisonError)? = errors;
 
         Ok(())
     }
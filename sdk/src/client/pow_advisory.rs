@@ -0,0 +1,208 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Advisory estimates of the block rate that the local PoW of this machine can sustain, based on the timings of the
+//! most recent local PoW.
+
+use std::{collections::VecDeque, sync::Mutex, time::Duration};
+
+use serde::{Deserialize, Serialize};
+
+use crate::client::{constants::POW_TIMING_SAMPLES, Client, Result};
+
+// The work of a PoW is proportional to the min PoW score times the length of the block, because the score is the
+// number of expected hashes divided by the length
+#[derive(Clone, Copy, Debug)]
+struct PowTiming {
+    work: f64,
+    block_length: usize,
+    duration: Duration,
+}
+
+/// Timings of the most recent local PoW of a client.
+#[derive(Debug, Default)]
+pub(crate) struct PowTimings {
+    timings: Mutex<VecDeque<PowTiming>>,
+}
+
+impl PowTimings {
+    /// Records the duration of a finished local PoW.
+    pub(crate) fn record(&self, min_pow_score: u32, block_length: usize, duration: Duration) {
+        let mut timings = self.timings.lock().unwrap();
+        if timings.len() == POW_TIMING_SAMPLES {
+            timings.pop_front();
+        }
+        timings.push_back(PowTiming {
+            work: f64::from(min_pow_score) * block_length as f64,
+            block_length,
+            duration,
+        });
+    }
+
+    // Returns the work per second, the average block length and the number of timings
+    fn throughput(&self) -> Option<(f64, usize, usize)> {
+        let timings = self.timings.lock().unwrap();
+        let duration = timings.iter().map(|timing| timing.duration).sum::<Duration>();
+        if timings.is_empty() || duration.is_zero() {
+            return None;
+        }
+        let work = timings.iter().map(|timing| timing.work).sum::<f64>();
+        let block_length = timings.iter().map(|timing| timing.block_length).sum::<usize>() / timings.len();
+
+        Some((work / duration.as_secs_f64(), block_length, timings.len()))
+    }
+}
+
+/// Estimate if the local PoW can sustain a requested block rate, see [`Client::estimate_pow_capacity()`].
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PowCapacityEstimate {
+    /// If local PoW is enabled, otherwise the PoW is done by the nodes and this machine doesn't limit the block rate.
+    pub local_pow: bool,
+    /// The PoW score of the local PoW, the min PoW score of the network or a higher target PoW score.
+    pub min_pow_score: u32,
+    /// The requested block rate in blocks per second.
+    pub requested_blocks_per_second: f64,
+    /// The number of recent local PoW timings the estimate is based on.
+    pub sample_count: usize,
+    /// The expected duration of the PoW of a single block.
+    pub expected_pow_duration: Option<Duration>,
+    /// The block rate the local PoW can sustain in blocks per second, not set if it's unknown because there are no
+    /// timings yet, or unlimited because no PoW is needed.
+    pub max_blocks_per_second: Option<f64>,
+    /// If the requested block rate can be sustained, not set if it's unknown.
+    pub sustainable: Option<bool>,
+}
+
+impl PowCapacityEstimate {
+    // Estimates the capacity from the throughput of the recent local PoW, see `PowTimings::throughput()`
+    fn new(
+        local_pow: bool,
+        min_pow_score: u32,
+        requested_blocks_per_second: f64,
+        block_length: Option<usize>,
+        throughput: Option<(f64, usize, usize)>,
+    ) -> Self {
+        let sample_count = throughput.map_or(0, |(_, _, sample_count)| sample_count);
+
+        let max_blocks_per_second = match throughput {
+            _ if !local_pow || min_pow_score == 0 => None,
+            Some((work_per_second, average_block_length, _)) => {
+                let work = f64::from(min_pow_score) * block_length.unwrap_or(average_block_length) as f64;
+                Some(work_per_second / work)
+            }
+            None => None,
+        };
+        let sustainable = if !local_pow || min_pow_score == 0 {
+            Some(true)
+        } else {
+            max_blocks_per_second.map(|max_blocks_per_second| requested_blocks_per_second <= max_blocks_per_second)
+        };
+
+        Self {
+            local_pow,
+            min_pow_score,
+            requested_blocks_per_second,
+            sample_count,
+            expected_pow_duration: max_blocks_per_second
+                .filter(|max_blocks_per_second| *max_blocks_per_second > 0.0)
+                .map(|max_blocks_per_second| Duration::from_secs_f64(1.0 / max_blocks_per_second)),
+            max_blocks_per_second,
+            sustainable,
+        }
+    }
+
+    /// Returns how many blocks can be sent in the given interval, to plan the size of batches.
+    pub fn blocks_per_interval(&self, interval: Duration) -> Option<u64> {
+        self.max_blocks_per_second
+            .map(|max_blocks_per_second| (max_blocks_per_second * interval.as_secs_f64()) as u64)
+    }
+}
+
+impl Client {
+    /// Estimates if the local PoW can sustain the requested block rate, based on the timings of the most recent local
+    /// PoW of this client and the current PoW score of the local PoW. It's only advisory, since the duration of a PoW
+    /// varies a lot and the machine may be busy with other work. `block_length` is the expected length of the blocks
+    /// in bytes, by default the average length of the recent blocks is used.
+    /// ```ignore
+    /// let estimate = client.estimate_pow_capacity(2.0, None).await?;
+    /// if estimate.sustainable == Some(false) {
+    ///     let batch_size = estimate.blocks_per_interval(Duration::from_secs(60));
+    /// }
+    /// ```
+    pub async fn estimate_pow_capacity(
+        &self,
+        requested_blocks_per_second: f64,
+        block_length: Option<usize>,
+    ) -> Result<PowCapacityEstimate> {
+        Ok(PowCapacityEstimate::new(
+            self.get_local_pow(),
+            self.get_target_pow_score().await?,
+            requested_blocks_per_second,
+            block_length,
+            self.pow_timings.throughput(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pow_timings_throughput() {
+        let timings = PowTimings::default();
+        assert_eq!(timings.throughput(), None);
+
+        // Work of 1000 * 100 in 1s and 1000 * 300 in 3s
+        timings.record(1000, 100, Duration::from_secs(1));
+        timings.record(1000, 300, Duration::from_secs(3));
+        assert_eq!(timings.throughput(), Some((100_000.0, 200, 2)));
+
+        // Only the most recent timings are kept
+        for _ in 0..POW_TIMING_SAMPLES {
+            timings.record(2000, 100, Duration::from_secs(1));
+        }
+        assert_eq!(timings.throughput(), Some((200_000.0, 100, POW_TIMING_SAMPLES)));
+    }
+
+    #[test]
+    fn pow_timings_without_duration() {
+        let timings = PowTimings::default();
+        timings.record(1000, 100, Duration::ZERO);
+
+        assert_eq!(timings.throughput(), None);
+    }
+
+    #[test]
+    fn estimate_pow_capacity() {
+        // 200_000 work per second, a block of 100 bytes with a score of 1000 takes 0.5s
+        let throughput = Some((200_000.0, 100, 4));
+
+        let estimate = PowCapacityEstimate::new(true, 1000, 1.0, None, throughput);
+        assert_eq!(estimate.sample_count, 4);
+        assert_eq!(estimate.max_blocks_per_second, Some(2.0));
+        assert_eq!(estimate.expected_pow_duration, Some(Duration::from_millis(500)));
+        assert_eq!(estimate.sustainable, Some(true));
+        assert_eq!(estimate.blocks_per_interval(Duration::from_secs(60)), Some(120));
+
+        // Blocks twice as long take twice as much work
+        let estimate = PowCapacityEstimate::new(true, 1000, 1.5, Some(200), throughput);
+        assert_eq!(estimate.max_blocks_per_second, Some(1.0));
+        assert_eq!(estimate.sustainable, Some(false));
+
+        // Without timings the capacity is unknown
+        let estimate = PowCapacityEstimate::new(true, 1000, 1.0, None, None);
+        assert_eq!(estimate.sample_count, 0);
+        assert_eq!(estimate.max_blocks_per_second, None);
+        assert_eq!(estimate.expected_pow_duration, None);
+        assert_eq!(estimate.sustainable, None);
+
+        // Without local PoW or without a min PoW score, there's no limit
+        for (local_pow, min_pow_score) in [(false, 1000), (true, 0)] {
+            let estimate = PowCapacityEstimate::new(local_pow, min_pow_score, 100.0, None, throughput);
+            assert_eq!(estimate.max_blocks_per_second, None);
+            assert_eq!(estimate.sustainable, Some(true));
+        }
+    }
+}
@@ -14,12 +14,12 @@ use iota_ledger_nano::{
     TransportTypes,
 };
 use packable::{error::UnexpectedEOF, unpacker::SliceUnpacker, Packable, PackableExt};
-use tokio::sync::Mutex;
 
-use super::{GenerateAddressOptions, SecretManage, SecretManageExt};
+use super::{EvmAddress, GenerateAddressOptions, Secp256k1EcdsaSignature, SecretManage, SecretManageExt};
 use crate::{
     client::secret::{
         is_alias_transition,
+        signing_queue::{QueuedRequest, SigningQueue, SigningQueueOptions},
         types::{LedgerApp, LedgerDeviceType},
         LedgerNanoStatus, PreparedTransactionData,
     },
@@ -77,6 +77,9 @@ pub enum Error {
     /// No available inputs provided
     #[error("No available inputs provided")]
     NoAvailableInputsProvided,
+    /// The request waited longer than the timeout of the signing queue
+    #[error("timed out waiting for the ledger to handle previous requests")]
+    SigningQueueTimeout,
 }
 
 impl From<crate::types::block::Error> for Error {
@@ -111,8 +114,8 @@ impl From<iota_ledger_nano::api::errors::APIError> for Error {
 pub struct LedgerSecretManager {
     /// Specifies if a real Ledger hardware is used or only a simulator is used.
     pub is_simulator: bool,
-    /// Mutex to prevent multiple simultaneous requests to a ledger.
-    pub mutex: Mutex<()>,
+    /// Queue to prevent more simultaneous requests to a ledger than it can handle.
+    pub signing_queue: SigningQueue,
 }
 
 impl TryFrom<u8> for LedgerDeviceType {
@@ -149,8 +152,12 @@ impl SecretManage for LedgerSecretManager {
             bip32_change: u32::from(options.internal) | HARDENED,
         };
 
-        // lock the mutex to prevent multiple simultaneous requests to a ledger
-        let lock = self.mutex.lock().await;
+        // wait in the queue to prevent multiple simultaneous requests to a ledger
+        let permit = self
+            .signing_queue
+            .acquire(QueuedRequest::GenerateAddresses, Some(account_index))
+            .await
+            .ok_or(Error::SigningQueueTimeout)?;
 
         // get ledger
         let ledger = get_ledger(coin_type, bip32_account, self.is_simulator)?;
@@ -162,7 +169,7 @@ impl SecretManage for LedgerSecretManager {
             ledger.get_addresses(false, bip32, address_indexes.len())?
         };
 
-        drop(lock);
+        drop(permit);
 
         let mut ed25519_addresses = Vec::new();
         for address in addresses {
@@ -174,6 +181,25 @@ impl SecretManage for LedgerSecretManager {
     async fn sign_ed25519(&self, _msg: &[u8], _chain: &Chain) -> Result<Ed25519Signature, Self::Error> {
         Err(Error::UnsupportedOperation)
     }
+
+    async fn generate_evm_addresses(
+        &self,
+        _coin_type: u32,
+        _account_index: u32,
+        _address_indexes: Range<u32>,
+        _options: Option<GenerateAddressOptions>,
+    ) -> Result<Vec<EvmAddress>, Self::Error> {
+        // The IOTA and Shimmer apps only support Ed25519 keys
+        Err(Error::UnsupportedOperation)
+    }
+
+    async fn sign_secp256k1_ecdsa(
+        &self,
+        _hash: &[u8; 32],
+        _chain: &Chain,
+    ) -> Result<Secp256k1EcdsaSignature, Self::Error> {
+        Err(Error::UnsupportedOperation)
+    }
 }
 
 /// needs_blind_signing
@@ -257,8 +283,12 @@ impl SecretManageExt for LedgerSecretManager {
         let essence_bytes = prepared_transaction.essence.pack_to_vec();
         let essence_hash = prepared_transaction.essence.hash().to_vec();
 
-        // lock the mutex to prevent multiple simultaneous requests to a ledger
-        let lock = self.mutex.lock().await;
+        // wait in the queue to prevent multiple simultaneous requests to a ledger
+        let permit = self
+            .signing_queue
+            .acquire(QueuedRequest::SignTransaction, Some(bip32_account & !HARDENED))
+            .await
+            .ok_or(Error::SigningQueueTimeout)?;
 
         let ledger = get_ledger(coin_type, bip32_account, self.is_simulator)?;
         let blind_signing = needs_blind_signing(prepared_transaction, ledger.get_buffer_size());
@@ -359,7 +389,7 @@ impl SecretManageExt for LedgerSecretManager {
         // sign
         let signature_bytes = ledger.sign(input_len as u16)?;
         drop(ledger);
-        drop(lock);
+        drop(permit);
         let mut unpacker = SliceUnpacker::new(&signature_bytes);
 
         // unpack signature to unlocks
@@ -395,15 +425,21 @@ impl LedgerSecretManager {
     pub fn new(is_simulator: bool) -> Self {
         Self {
             is_simulator,
-            mutex: Mutex::new(()),
+            signing_queue: SigningQueue::default(),
         }
     }
 
+    /// Sets the options of the queue for the requests to the ledger, e.g. to fail requests that wait too long.
+    pub fn with_signing_queue_options(mut self, options: SigningQueueOptions) -> Self {
+        self.signing_queue = SigningQueue::new(options);
+        self
+    }
+
     /// Get Ledger hardware status.
     pub async fn get_ledger_nano_status(&self) -> LedgerNanoStatus {
         log::debug!("get_ledger_nano_status");
-        // lock the mutex
-        let _lock = self.mutex.lock().await;
+        // wait in the queue, the status is still requested if it times out, because getting it can't fail
+        let _permit = self.signing_queue.acquire(QueuedRequest::Status, None).await;
         let transport_type = if self.is_simulator {
             TransportTypes::TCP
         } else {
@@ -9,9 +9,10 @@ use async_trait::async_trait;
 use crypto::{
     hashes::{blake2b::Blake2b256, Digest},
     keys::slip10::{Chain, Curve, Seed},
+    signatures::{ed25519, secp256k1_ecdsa},
 };
 
-use super::{GenerateAddressOptions, SecretManage};
+use super::{evm_chain, EvmAddress, GenerateAddressOptions, Secp256k1EcdsaSignature, SecretManage};
 use crate::{
     client::{constants::HD_WALLET_TYPE, Client, Error},
     types::block::{
@@ -48,12 +49,7 @@ impl SecretManage for MnemonicSecretManager {
                 address_index,
             ]);
 
-            let public_key = self
-                .0
-                .derive(Curve::Ed25519, &chain)?
-                .secret_key()
-                .public_key()
-                .to_bytes();
+            let public_key = self.ed25519_secret_key(&chain)?.public_key().to_bytes();
 
             // Hash the public key to get the address
             let result = Blake2b256::digest(public_key).try_into().map_err(|_e| {
@@ -68,12 +64,53 @@ impl SecretManage for MnemonicSecretManager {
 
     async fn sign_ed25519(&self, msg: &[u8], chain: &Chain) -> Result<Ed25519Signature, Self::Error> {
         // Get the private and public key for this Ed25519 address
-        let private_key = self.0.derive(Curve::Ed25519, chain)?.secret_key();
+        let private_key = self.ed25519_secret_key(chain)?;
         let public_key = private_key.public_key().to_bytes();
         let signature = private_key.sign(msg).to_bytes();
 
         Ok(Ed25519Signature::new(public_key, signature))
     }
+
+    async fn generate_evm_addresses(
+        &self,
+        coin_type: u32,
+        account_index: u32,
+        address_indexes: Range<u32>,
+        options: Option<GenerateAddressOptions>,
+    ) -> Result<Vec<EvmAddress>, Self::Error> {
+        let internal = options.map(|o| o.internal).unwrap_or_default();
+        let mut addresses = Vec::new();
+
+        for address_index in address_indexes {
+            let chain = evm_chain(coin_type, account_index, internal, address_index);
+            let public_key = self.secp256k1_secret_key(&chain)?.public_key();
+
+            addresses.push(EvmAddress::from_public_key(&public_key));
+        }
+
+        Ok(addresses)
+    }
+
+    async fn sign_secp256k1_ecdsa(
+        &self,
+        hash: &[u8; 32],
+        chain: &Chain,
+    ) -> Result<Secp256k1EcdsaSignature, Self::Error> {
+        let private_key = self.secp256k1_secret_key(chain)?;
+        let public_key = private_key.public_key().to_bytes();
+
+        // The hash is signed as is, crypto.rs would hash the message with SHA-256 first
+        let signing_key = k256::ecdsa::SigningKey::from_bytes(private_key.to_bytes().as_ref().into())
+            .map_err(|_| crypto::Error::PrivateKeyError)?;
+        let (signature, recovery_id) = signing_key
+            .sign_prehash_recoverable(hash)
+            .map_err(|_| crypto::Error::SignatureError { alg: "secp256k1 ecdsa" })?;
+        let mut signature_bytes = [0; secp256k1_ecdsa::Signature::LENGTH];
+        signature_bytes[..64].copy_from_slice(&signature.to_bytes());
+        signature_bytes[64] = recovery_id.to_byte();
+
+        Ok(Secp256k1EcdsaSignature::new(public_key, signature_bytes))
+    }
 }
 
 impl MnemonicSecretManager {
@@ -84,11 +121,31 @@ impl MnemonicSecretManager {
         Ok(Self(Client::mnemonic_to_seed(mnemonic)?))
     }
 
+    /// Create a new [`MnemonicSecretManager`] from a BIP-39 mnemonic in English and a passphrase, also known as 25th
+    /// word, as used by some other wallets.
+    pub fn try_from_mnemonic_with_passphrase(mnemonic: &str, passphrase: &str) -> Result<Self, Error> {
+        Ok(Self(Client::mnemonic_to_seed_with_passphrase(mnemonic, passphrase)?))
+    }
+
     /// Create a new [`MnemonicSecretManager`] from a hex-encoded raw seed string.
     pub fn try_from_hex_seed(hex: &str) -> Result<Self, Error> {
         let bytes: Vec<u8> = prefix_hex::decode(hex)?;
         Ok(Self(Seed::from_bytes(&bytes)))
     }
+
+    // Derives the Ed25519 private key of the chain
+    fn ed25519_secret_key(&self, chain: &Chain) -> Result<ed25519::SecretKey, Error> {
+        Ok(ed25519::SecretKey::from_bytes(
+            self.0.derive(Curve::Ed25519, chain)?.secret_bytes(),
+        ))
+    }
+
+    // Derives the secp256k1 private key of the chain
+    fn secp256k1_secret_key(&self, chain: &Chain) -> Result<secp256k1_ecdsa::SecretKey, Error> {
+        Ok(secp256k1_ecdsa::SecretKey::try_from_bytes(
+            self.0.derive(Curve::Secp256k1, chain)?.secret_bytes(),
+        )?)
+    }
 }
 
 #[cfg(test)]
@@ -130,4 +187,41 @@ mod tests {
             "atoi1qzt0nhsf38nh6rs4p6zs5knqp6psgha9wsv74uajqgjmwc75ugupx3y7x0r".to_string()
         );
     }
+    #[tokio::test]
+    async fn evm_address() {
+        use crate::client::constants::ETHER_COIN_TYPE;
+
+        // The first account of development nodes like Hardhat
+        let mnemonic = "test test test test test test test test test test test junk";
+        let secret_manager = MnemonicSecretManager::try_from_mnemonic(mnemonic).unwrap();
+
+        let addresses = secret_manager
+            .generate_evm_addresses(ETHER_COIN_TYPE, 0, 0..1, None)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            addresses[0].to_string(),
+            "0xf39Fd6e51aad88F6F4ce6aB8827279cffFb92266".to_string()
+        );
+        assert_eq!(addresses[0].to_string().parse::<EvmAddress>().unwrap(), addresses[0]);
+
+        let signature = secret_manager
+            .sign_secp256k1_ecdsa(&[1; 32], &evm_chain(ETHER_COIN_TYPE, 0, false, 0))
+            .await
+            .unwrap();
+        assert_eq!(signature.evm_address().unwrap(), addresses[0]);
+
+        // The hash is signed without hashing it again
+        let recovered_key = k256::ecdsa::VerifyingKey::recover_from_prehash(
+            &[1; 32],
+            &k256::ecdsa::Signature::from_slice(&signature.signature()[..64]).unwrap(),
+            k256::ecdsa::RecoveryId::from_byte(signature.signature()[64]).unwrap(),
+        )
+        .unwrap();
+        assert_eq!(
+            recovered_key.to_encoded_point(true).as_bytes(),
+            signature.public_key().as_slice()
+        );
+    }
 }
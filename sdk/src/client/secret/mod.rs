@@ -8,8 +8,16 @@
 pub mod ledger_nano;
 /// Module for signing with a mnemonic or seed
 pub mod mnemonic;
+/// Module for signing with keys on a PKCS#11 token
+#[cfg(feature = "pkcs11")]
+#[cfg_attr(docsrs, doc(cfg(feature = "pkcs11")))]
+pub mod pkcs11;
 /// Module for the PlaceholderSecretManager
 pub mod placeholder;
+/// Module for the queue of requests to a ledger nano
+#[cfg(feature = "ledger_nano")]
+#[cfg_attr(docsrs, doc(cfg(feature = "ledger_nano")))]
+pub mod signing_queue;
 /// Module for signing with a Stronghold vault
 #[cfg(feature = "stronghold")]
 #[cfg_attr(docsrs, doc(cfg(feature = "stronghold")))]
@@ -28,9 +36,11 @@ use zeroize::ZeroizeOnDrop;
 
 #[cfg(feature = "ledger_nano")]
 use self::ledger_nano::LedgerSecretManager;
+#[cfg(feature = "pkcs11")]
+use self::pkcs11::{Pkcs11Dto, Pkcs11SecretManager};
 #[cfg(feature = "stronghold")]
 use self::stronghold::StrongholdSecretManager;
-pub use self::types::{GenerateAddressOptions, LedgerNanoStatus};
+pub use self::types::{evm_chain, EvmAddress, GenerateAddressOptions, LedgerNanoStatus, Secp256k1EcdsaSignature};
 use self::{mnemonic::MnemonicSecretManager, placeholder::PlaceholderSecretManager};
 #[cfg(feature = "stronghold")]
 use crate::client::secret::types::StrongholdDto;
@@ -73,6 +83,24 @@ pub trait SecretManage: Send + Sync {
     /// Signs `msg` using the given [`Chain`].
     async fn sign_ed25519(&self, msg: &[u8], chain: &Chain) -> Result<Ed25519Signature, Self::Error>;
 
+    /// Generates EVM addresses from secp256k1 keys of the chains returned by [`evm_chain()`], e.g. for IOTA EVM and
+    /// ShimmerEVM chains. With [`ETHER_COIN_TYPE`](crate::client::constants::ETHER_COIN_TYPE) the addresses are the
+    /// same as the ones that other EVM wallets derive from the mnemonic.
+    async fn generate_evm_addresses(
+        &self,
+        coin_type: u32,
+        account_index: u32,
+        address_indexes: Range<u32>,
+        options: Option<GenerateAddressOptions>,
+    ) -> Result<Vec<EvmAddress>, Self::Error>;
+
+    /// Signs the 32 byte `hash`, e.g. of an EVM transaction, with the secp256k1 key of the given [`Chain`].
+    async fn sign_secp256k1_ecdsa(
+        &self,
+        hash: &[u8; 32],
+        chain: &Chain,
+    ) -> Result<Secp256k1EcdsaSignature, Self::Error>;
+
     /// Signs `essence_hash` using the given `chain`, returning an [`Unlock`].
     async fn signature_unlock(&self, essence_hash: &[u8; 32], chain: &Chain) -> Result<Unlock, Self::Error> {
         Ok(Unlock::Signature(SignatureUnlock::new(Signature::Ed25519(
@@ -114,6 +142,11 @@ pub enum SecretManager {
     #[cfg_attr(docsrs, doc(cfg(feature = "ledger_nano")))]
     LedgerNano(LedgerSecretManager),
 
+    /// Secret manager that uses Ed25519 keys on a PKCS#11 token, like a hardware security module.
+    #[cfg(feature = "pkcs11")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "pkcs11")))]
+    Pkcs11(Pkcs11SecretManager),
+
     /// Secret manager that uses a mnemonic in plain memory. It's not recommended for production use. Use
     /// LedgerNano or Stronghold instead.
     Mnemonic(MnemonicSecretManager),
@@ -130,6 +163,8 @@ impl std::fmt::Debug for SecretManager {
             Self::Stronghold(_) => f.debug_tuple("Stronghold").field(&"...").finish(),
             #[cfg(feature = "ledger_nano")]
             Self::LedgerNano(_) => f.debug_tuple("LedgerNano").field(&"...").finish(),
+            #[cfg(feature = "pkcs11")]
+            Self::Pkcs11(secret_manager) => f.debug_tuple("Pkcs11").field(secret_manager).finish(),
             Self::Mnemonic(_) => f.debug_tuple("Mnemonic").field(&"...").finish(),
             Self::Placeholder(_) => f.debug_struct("Placeholder").finish(),
         }
@@ -157,6 +192,11 @@ pub enum SecretManagerDto {
     #[cfg_attr(docsrs, doc(cfg(feature = "ledger_nano")))]
     #[serde(alias = "ledgerNano")]
     LedgerNano(bool),
+    /// PKCS#11 token
+    #[cfg(feature = "pkcs11")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "pkcs11")))]
+    #[serde(alias = "pkcs11")]
+    Pkcs11(Pkcs11Dto),
     /// Mnemonic
     #[serde(alias = "mnemonic")]
     Mnemonic(String),
@@ -191,6 +231,9 @@ impl TryFrom<&SecretManagerDto> for SecretManager {
             #[cfg(feature = "ledger_nano")]
             SecretManagerDto::LedgerNano(is_simulator) => Self::LedgerNano(LedgerSecretManager::new(*is_simulator)),
 
+            #[cfg(feature = "pkcs11")]
+            SecretManagerDto::Pkcs11(pkcs11_dto) => Self::Pkcs11(Pkcs11SecretManager::try_from(pkcs11_dto)?),
+
             SecretManagerDto::Mnemonic(mnemonic) => Self::Mnemonic(MnemonicSecretManager::try_from_mnemonic(mnemonic)?),
 
             SecretManagerDto::HexSeed(hex_seed) => Self::Mnemonic(MnemonicSecretManager::try_from_hex_seed(hex_seed)?),
@@ -218,6 +261,9 @@ impl From<&SecretManager> for SecretManagerDto {
             #[cfg(feature = "ledger_nano")]
             SecretManager::LedgerNano(ledger_nano) => Self::LedgerNano(ledger_nano.is_simulator),
 
+            #[cfg(feature = "pkcs11")]
+            SecretManager::Pkcs11(pkcs11) => Self::Pkcs11(Pkcs11Dto::from(pkcs11)),
+
             // `MnemonicSecretManager(Seed)` doesn't have Debug or Display implemented and in the current use cases of
             // the client/wallet we also don't need to convert it in this direction with the mnemonic/seed, we only need
             // to know the type
@@ -247,6 +293,10 @@ impl SecretManage for SecretManager {
             Self::LedgerNano(secret_manager) => Ok(secret_manager
                 .generate_addresses(coin_type, account_index, address_indexes, options)
                 .await?),
+            #[cfg(feature = "pkcs11")]
+            Self::Pkcs11(secret_manager) => Ok(secret_manager
+                .generate_addresses(coin_type, account_index, address_indexes, options)
+                .await?),
             Self::Mnemonic(secret_manager) => {
                 secret_manager
                     .generate_addresses(coin_type, account_index, address_indexes, options)
@@ -266,10 +316,62 @@ impl SecretManage for SecretManager {
             Self::Stronghold(secret_manager) => Ok(secret_manager.sign_ed25519(msg, chain).await?),
             #[cfg(feature = "ledger_nano")]
             Self::LedgerNano(secret_manager) => Ok(secret_manager.sign_ed25519(msg, chain).await?),
+            #[cfg(feature = "pkcs11")]
+            Self::Pkcs11(secret_manager) => Ok(secret_manager.sign_ed25519(msg, chain).await?),
             Self::Mnemonic(secret_manager) => secret_manager.sign_ed25519(msg, chain).await,
             Self::Placeholder(secret_manager) => secret_manager.sign_ed25519(msg, chain).await,
         }
     }
+
+    async fn generate_evm_addresses(
+        &self,
+        coin_type: u32,
+        account_index: u32,
+        address_indexes: Range<u32>,
+        options: Option<GenerateAddressOptions>,
+    ) -> crate::client::Result<Vec<EvmAddress>> {
+        match self {
+            #[cfg(feature = "stronghold")]
+            Self::Stronghold(secret_manager) => Ok(secret_manager
+                .generate_evm_addresses(coin_type, account_index, address_indexes, options)
+                .await?),
+            #[cfg(feature = "ledger_nano")]
+            Self::LedgerNano(secret_manager) => Ok(secret_manager
+                .generate_evm_addresses(coin_type, account_index, address_indexes, options)
+                .await?),
+            #[cfg(feature = "pkcs11")]
+            Self::Pkcs11(secret_manager) => Ok(secret_manager
+                .generate_evm_addresses(coin_type, account_index, address_indexes, options)
+                .await?),
+            Self::Mnemonic(secret_manager) => {
+                secret_manager
+                    .generate_evm_addresses(coin_type, account_index, address_indexes, options)
+                    .await
+            }
+            Self::Placeholder(secret_manager) => {
+                secret_manager
+                    .generate_evm_addresses(coin_type, account_index, address_indexes, options)
+                    .await
+            }
+        }
+    }
+
+    async fn sign_secp256k1_ecdsa(
+        &self,
+        hash: &[u8; 32],
+        chain: &Chain,
+    ) -> crate::client::Result<Secp256k1EcdsaSignature> {
+        match self {
+            #[cfg(feature = "stronghold")]
+            Self::Stronghold(secret_manager) => Ok(secret_manager.sign_secp256k1_ecdsa(hash, chain).await?),
+            #[cfg(feature = "ledger_nano")]
+            Self::LedgerNano(secret_manager) => Ok(secret_manager.sign_secp256k1_ecdsa(hash, chain).await?),
+            #[cfg(feature = "pkcs11")]
+            Self::Pkcs11(secret_manager) => Ok(secret_manager.sign_secp256k1_ecdsa(hash, chain).await?),
+            Self::Mnemonic(secret_manager) => secret_manager.sign_secp256k1_ecdsa(hash, chain).await,
+            Self::Placeholder(secret_manager) => secret_manager.sign_secp256k1_ecdsa(hash, chain).await,
+        }
+    }
 }
 
 #[async_trait]
@@ -279,6 +381,8 @@ impl SecretManageExt for SecretManager {
         prepared_transaction_data: &PreparedTransactionData,
         time: Option<u32>,
     ) -> crate::client::Result<Unlocks> {
+        prepared_transaction_data.verify_inputs_commitment()?;
+
         match self {
             #[cfg(feature = "stronghold")]
             Self::Stronghold(_) => {
@@ -289,6 +393,11 @@ impl SecretManageExt for SecretManager {
             Self::LedgerNano(secret_manager) => Ok(secret_manager
                 .sign_transaction_essence(prepared_transaction_data, time)
                 .await?),
+            #[cfg(feature = "pkcs11")]
+            Self::Pkcs11(_) => {
+                self.default_sign_transaction_essence(prepared_transaction_data, time)
+                    .await
+            }
             Self::Mnemonic(_) => {
                 self.default_sign_transaction_essence(prepared_transaction_data, time)
                     .await
@@ -309,7 +418,7 @@ impl SecretManager {
         Ok(Self::Mnemonic(MnemonicSecretManager::try_from_hex_seed(seed)?))
     }
 
-    // Shared implementation for MnemonicSecretManager and StrongholdSecretManager
+    // Shared implementation for MnemonicSecretManager, Pkcs11SecretManager and StrongholdSecretManager
     async fn default_sign_transaction_essence<'a>(
         &self,
         prepared_transaction_data: &PreparedTransactionData,
@@ -0,0 +1,265 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Implementation of [`Pkcs11SecretManager`].
+//!
+//! Hardware security modules don't derive keys from a seed, so every key has to be created on the token beforehand.
+//! The Ed25519 key pair of a BIP32 chain is found by its label, the configured key label followed by the indexes of
+//! the chain after the purpose, e.g. `iota/4218/0/0/1` for the chain `m/44'/4218'/0'/0'/1'` and the key label `iota`.
+
+use std::{ops::Range, path::PathBuf, sync::Mutex};
+
+use async_trait::async_trait;
+use cryptoki::{
+    context::{CInitializeArgs, Pkcs11},
+    mechanism::Mechanism,
+    object::{Attribute, AttributeType, KeyType, ObjectClass, ObjectHandle},
+    session::{Session, UserType},
+    types::AuthPin,
+};
+use crypto::{
+    hashes::{blake2b::Blake2b256, Digest},
+    keys::slip10::Chain,
+};
+use serde::{Deserialize, Serialize};
+use zeroize::ZeroizeOnDrop;
+
+use super::{EvmAddress, GenerateAddressOptions, Secp256k1EcdsaSignature, SecretManage};
+use crate::{
+    client::constants::HD_WALLET_TYPE,
+    types::block::{
+        address::{Address, Ed25519Address},
+        signature::Ed25519Signature,
+    },
+};
+
+// Mask to remove the hardened flag from a chain index
+const HARDENED_MASK: u32 = 0x7FFF_FFFF;
+
+/// PKCS#11 errors.
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    /// Error of the PKCS#11 module.
+    #[error("{0}")]
+    Cryptoki(#[from] cryptoki::error::Error),
+    /// No token in the configured slot.
+    #[error("no token found in slot {0}")]
+    SlotNotFound(u64),
+    /// No key with the label on the token.
+    #[error("no {key_class} key found with label {label}")]
+    KeyNotFound {
+        /// The class of the missing key.
+        key_class: &'static str,
+        /// The label of the missing key.
+        label: String,
+    },
+    /// The token returned an invalid public key or signature.
+    #[error("invalid {0} returned by the token")]
+    InvalidResponse(&'static str),
+    /// The session was poisoned by a panic.
+    #[error("PKCS#11 session lock failed")]
+    PoisonError,
+    /// The operation isn't supported by the secret manager.
+    #[error("unsupported operation: {0}")]
+    UnsupportedOperation(&'static str),
+}
+
+/// Configuration of a [`Pkcs11SecretManager`], also used to create it from bindings.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize, ZeroizeOnDrop)]
+#[serde(rename_all = "camelCase")]
+pub struct Pkcs11Dto {
+    /// The path of the PKCS#11 module of the token, e.g. `/usr/lib/softhsm/libsofthsm2.so`.
+    pub module_path: String,
+    /// The id of the slot with the token.
+    pub slot_id: u64,
+    /// The user PIN of the token.
+    pub pin: Option<String>,
+    /// The prefix of the labels of the keys.
+    pub key_label: String,
+}
+
+/// Secret manager that signs with Ed25519 keys stored on a PKCS#11 token, like a hardware security module.
+pub struct Pkcs11SecretManager {
+    session: Mutex<Session>,
+    module_path: PathBuf,
+    slot_id: u64,
+    key_label: String,
+}
+
+impl std::fmt::Debug for Pkcs11SecretManager {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Pkcs11SecretManager")
+            .field("module_path", &self.module_path)
+            .field("slot_id", &self.slot_id)
+            .field("key_label", &self.key_label)
+            .finish()
+    }
+}
+
+#[async_trait]
+impl SecretManage for Pkcs11SecretManager {
+    type Error = Error;
+
+    async fn generate_addresses(
+        &self,
+        coin_type: u32,
+        account_index: u32,
+        address_indexes: Range<u32>,
+        options: Option<GenerateAddressOptions>,
+    ) -> Result<Vec<Address>, Self::Error> {
+        let internal = options.map(|o| o.internal).unwrap_or_default();
+        let session = self.session.lock().map_err(|_| Error::PoisonError)?;
+        let mut addresses = Vec::new();
+
+        for address_index in address_indexes {
+            let chain = Chain::from_u32_hardened(vec![
+                HD_WALLET_TYPE,
+                coin_type,
+                account_index,
+                internal as u32,
+                address_index,
+            ]);
+            let public_key = self.public_key(&session, &self.label(&chain))?;
+
+            // Hash the public key to get the address
+            addresses.push(Address::Ed25519(Ed25519Address::new(
+                Blake2b256::digest(public_key).into(),
+            )));
+        }
+
+        Ok(addresses)
+    }
+
+    async fn sign_ed25519(&self, msg: &[u8], chain: &Chain) -> Result<Ed25519Signature, Self::Error> {
+        let label = self.label(chain);
+        let session = self.session.lock().map_err(|_| Error::PoisonError)?;
+
+        let public_key = self.public_key(&session, &label)?;
+        let private_key = find_key(&session, ObjectClass::PRIVATE_KEY, &label)?;
+        let signature = session
+            .sign(&Mechanism::Eddsa, private_key, msg)?
+            .try_into()
+            .map_err(|_| Error::InvalidResponse("signature"))?;
+
+        Ok(Ed25519Signature::new(public_key, signature))
+    }
+
+    async fn generate_evm_addresses(
+        &self,
+        _coin_type: u32,
+        _account_index: u32,
+        _address_indexes: Range<u32>,
+        _options: Option<GenerateAddressOptions>,
+    ) -> Result<Vec<EvmAddress>, Self::Error> {
+        Err(Error::UnsupportedOperation("secp256k1 keys"))
+    }
+
+    async fn sign_secp256k1_ecdsa(
+        &self,
+        _hash: &[u8; 32],
+        _chain: &Chain,
+    ) -> Result<Secp256k1EcdsaSignature, Self::Error> {
+        Err(Error::UnsupportedOperation("secp256k1 keys"))
+    }
+}
+
+impl Pkcs11SecretManager {
+    /// Loads the PKCS#11 module, opens a session with the token in the slot and logs in with the PIN if provided.
+    pub fn new(
+        module_path: impl Into<PathBuf>,
+        slot_id: u64,
+        pin: Option<&str>,
+        key_label: impl Into<String>,
+    ) -> Result<Self, Error> {
+        let module_path = module_path.into();
+        let context = Pkcs11::new(&module_path)?;
+        context.initialize(CInitializeArgs::OsThreads)?;
+
+        let slot = context
+            .get_slots_with_token()?
+            .into_iter()
+            .find(|slot| slot.id() == slot_id)
+            .ok_or(Error::SlotNotFound(slot_id))?;
+        let session = context.open_ro_session(slot)?;
+        if let Some(pin) = pin {
+            session.login(UserType::User, Some(&AuthPin::new(pin.to_string())))?;
+        }
+
+        Ok(Self {
+            session: Mutex::new(session),
+            module_path,
+            slot_id,
+            key_label: key_label.into(),
+        })
+    }
+
+    // Returns the label of the key pair of a chain
+    fn label(&self, chain: &Chain) -> String {
+        chain
+            .segments()
+            .iter()
+            .skip(1)
+            .fold(self.key_label.clone(), |label, segment| {
+                format!("{label}/{}", u32::from_be_bytes(segment.bs()) & HARDENED_MASK)
+            })
+    }
+
+    // Returns the public key with the label
+    fn public_key(&self, session: &Session, label: &str) -> Result<[u8; 32], Error> {
+        let public_key = find_key(session, ObjectClass::PUBLIC_KEY, label)?;
+        let ec_point = session
+            .get_attributes(public_key, &[AttributeType::EcPoint])?
+            .into_iter()
+            .find_map(|attribute| match attribute {
+                Attribute::EcPoint(ec_point) => Some(ec_point),
+                _ => None,
+            })
+            .ok_or(Error::InvalidResponse("public key"))?;
+
+        // The point is a DER encoded octet string, but some tokens return the raw key
+        let key_bytes = match ec_point.as_slice() {
+            [0x04, 0x20, key_bytes @ ..] if key_bytes.len() == 32 => key_bytes,
+            key_bytes => key_bytes,
+        };
+        key_bytes.try_into().map_err(|_| Error::InvalidResponse("public key"))
+    }
+}
+
+impl TryFrom<&Pkcs11Dto> for Pkcs11SecretManager {
+    type Error = Error;
+
+    fn try_from(value: &Pkcs11Dto) -> Result<Self, Self::Error> {
+        Self::new(&value.module_path, value.slot_id, value.pin.as_deref(), &value.key_label)
+    }
+}
+
+impl From<&Pkcs11SecretManager> for Pkcs11Dto {
+    fn from(value: &Pkcs11SecretManager) -> Self {
+        Self {
+            module_path: value.module_path.to_string_lossy().into(),
+            slot_id: value.slot_id,
+            pin: None,
+            key_label: value.key_label.clone(),
+        }
+    }
+}
+
+// Finds the Ed25519 key of a class with the label
+fn find_key(session: &Session, key_class: ObjectClass, label: &str) -> Result<ObjectHandle, Error> {
+    session
+        .find_objects(&[
+            Attribute::Class(key_class),
+            Attribute::KeyType(KeyType::EC_EDWARDS),
+            Attribute::Label(label.as_bytes().to_vec()),
+        ])?
+        .into_iter()
+        .next()
+        .ok_or_else(|| Error::KeyNotFound {
+            key_class: if key_class == ObjectClass::PRIVATE_KEY {
+                "private"
+            } else {
+                "public"
+            },
+            label: label.to_string(),
+        })
+}
@@ -8,7 +8,7 @@ use std::ops::Range;
 use async_trait::async_trait;
 use crypto::keys::slip10::Chain;
 
-use super::{GenerateAddressOptions, SecretManage, SecretManageExt};
+use super::{EvmAddress, GenerateAddressOptions, Secp256k1EcdsaSignature, SecretManage, SecretManageExt};
 use crate::{
     client::{secret::PreparedTransactionData, Error},
     types::block::{address::Address, signature::Ed25519Signature, unlock::Unlocks},
@@ -35,6 +35,24 @@ impl SecretManage for PlaceholderSecretManager {
     async fn sign_ed25519(&self, _msg: &[u8], _chain: &Chain) -> Result<Ed25519Signature, Self::Error> {
         Err(Error::PlaceholderSecretManager)
     }
+
+    async fn generate_evm_addresses(
+        &self,
+        _coin_type: u32,
+        _account_index: u32,
+        _address_indexes: Range<u32>,
+        _options: Option<GenerateAddressOptions>,
+    ) -> Result<Vec<EvmAddress>, Self::Error> {
+        Err(Error::PlaceholderSecretManager)
+    }
+
+    async fn sign_secp256k1_ecdsa(
+        &self,
+        _hash: &[u8; 32],
+        _chain: &Chain,
+    ) -> Result<Secp256k1EcdsaSignature, Self::Error> {
+        Err(Error::PlaceholderSecretManager)
+    }
 }
 
 #[async_trait]
@@ -0,0 +1,267 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! A queue for the requests to secret managers that can only handle a limited number of requests at a time, like
+//! hardware wallets.
+
+use std::{
+    collections::VecDeque,
+    sync::{Mutex, PoisonError},
+    time::Duration,
+};
+
+use serde::{Deserialize, Serialize};
+use tokio::sync::{broadcast, Semaphore, SemaphorePermit};
+
+// The number of positions that are buffered for subscribers that don't receive them fast enough
+const QUEUE_POSITION_CAPACITY: usize = 64;
+
+/// Options for a [`SigningQueue`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SigningQueueOptions {
+    /// The number of requests that are handled at the same time, at least 1.
+    pub concurrency: usize,
+    /// The duration a request waits in the queue before it fails, not limited if `None`.
+    pub timeout: Option<Duration>,
+}
+
+impl Default for SigningQueueOptions {
+    fn default() -> Self {
+        Self {
+            concurrency: 1,
+            timeout: None,
+        }
+    }
+}
+
+/// The kind of a request in a [`SigningQueue`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum QueuedRequest {
+    /// Generating addresses.
+    GenerateAddresses,
+    /// Signing a transaction essence.
+    SignTransaction,
+    /// Getting the status of the device.
+    Status,
+}
+
+/// The position of a request in a [`SigningQueue`], published every time it changes.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct QueuePosition {
+    /// The id of the request, unique within the queue.
+    pub request_id: u64,
+    /// The kind of the request.
+    pub request: QueuedRequest,
+    /// The index of the account the request is for, if it's for one.
+    pub account_index: Option<u32>,
+    /// The number of requests that are handled or waiting before this one, 0 once it's handled.
+    pub position: usize,
+}
+
+#[derive(Debug, Default)]
+struct QueueState {
+    next_request_id: u64,
+    waiting: VecDeque<QueuePosition>,
+}
+
+/// A queue that handles requests in the order they were made, with a limited number of requests at the same time.
+#[derive(Debug)]
+pub struct SigningQueue {
+    options: SigningQueueOptions,
+    semaphore: Semaphore,
+    state: Mutex<QueueState>,
+    positions: broadcast::Sender<QueuePosition>,
+}
+
+impl Default for SigningQueue {
+    fn default() -> Self {
+        Self::new(SigningQueueOptions::default())
+    }
+}
+
+impl SigningQueue {
+    /// Creates a [`SigningQueue`].
+    pub fn new(options: SigningQueueOptions) -> Self {
+        let (positions, _) = broadcast::channel(QUEUE_POSITION_CAPACITY);
+
+        Self {
+            options,
+            semaphore: Semaphore::new(options.concurrency.max(1)),
+            state: Default::default(),
+            positions,
+        }
+    }
+
+    /// Returns the options of the queue.
+    pub fn options(&self) -> SigningQueueOptions {
+        self.options
+    }
+
+    /// Returns a receiver of the positions of all requests in the queue.
+    pub fn subscribe(&self) -> broadcast::Receiver<QueuePosition> {
+        self.positions.subscribe()
+    }
+
+    /// Returns the number of requests that are waiting to be handled.
+    pub fn waiting_requests(&self) -> usize {
+        self.state.lock().unwrap_or_else(PoisonError::into_inner).waiting.len()
+    }
+
+    /// Waits until all requests made before are handled and one of the concurrent requests is free. The request is
+    /// handled until the returned permit is dropped. Returns `None` if the timeout elapsed before.
+    pub async fn acquire(&self, request: QueuedRequest, account_index: Option<u32>) -> Option<SigningQueuePermit<'_>> {
+        let waiting_request = self.enqueue(request, account_index);
+
+        // The semaphore hands out permits in the order they were requested and is never closed
+        let permit = match self.options.timeout {
+            Some(timeout) => tokio::time::timeout(timeout, self.semaphore.acquire())
+                .await
+                .ok()
+                .and_then(Result::ok),
+            None => self.semaphore.acquire().await.ok(),
+        };
+        let Some(permit) = permit else {
+            log::debug!(
+                "[SigningQueue] request {} timed out",
+                waiting_request.position.request_id
+            );
+            return None;
+        };
+
+        let position = waiting_request.position;
+        drop(waiting_request);
+        self.publish(QueuePosition {
+            position: 0,
+            ..position
+        });
+
+        Some(SigningQueuePermit { _permit: permit })
+    }
+
+    fn enqueue(&self, request: QueuedRequest, account_index: Option<u32>) -> WaitingRequest<'_> {
+        let mut state = self.state.lock().unwrap_or_else(PoisonError::into_inner);
+        let position = QueuePosition {
+            request_id: state.next_request_id,
+            request,
+            account_index,
+            position: self.handled_requests() + state.waiting.len(),
+        };
+        state.next_request_id += 1;
+        state.waiting.push_back(position);
+        drop(state);
+
+        self.publish(position);
+
+        WaitingRequest { queue: self, position }
+    }
+
+    // Removes a request that is handled, timed out or cancelled and publishes the new positions of the requests after it
+    fn dequeue(&self, request_id: u64) {
+        let mut state = self.state.lock().unwrap_or_else(PoisonError::into_inner);
+        let Some(index) = state
+            .waiting
+            .iter()
+            .position(|waiting| waiting.request_id == request_id)
+        else {
+            return;
+        };
+        state.waiting.remove(index);
+
+        let handled_requests = self.handled_requests();
+        let positions = state
+            .waiting
+            .iter()
+            .enumerate()
+            .skip(index)
+            .map(|(index, waiting)| QueuePosition {
+                position: handled_requests + index,
+                ..*waiting
+            })
+            .collect::<Vec<_>>();
+        drop(state);
+
+        for position in positions {
+            self.publish(position);
+        }
+    }
+
+    fn handled_requests(&self) -> usize {
+        self.options.concurrency.max(1) - self.semaphore.available_permits()
+    }
+
+    fn publish(&self, position: QueuePosition) {
+        // Fails only if there are no subscribers
+        self.positions.send(position).ok();
+    }
+}
+
+// A request in the queue, removed from it when dropped
+struct WaitingRequest<'a> {
+    queue: &'a SigningQueue,
+    position: QueuePosition,
+}
+
+impl Drop for WaitingRequest<'_> {
+    fn drop(&mut self) {
+        self.queue.dequeue(self.position.request_id);
+    }
+}
+
+/// A request of a [`SigningQueue`] that is being handled, the next request is handled once it's dropped.
+#[derive(Debug)]
+pub struct SigningQueuePermit<'a> {
+    _permit: SemaphorePermit<'a>,
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn requests_are_handled_in_order() {
+        let queue = Arc::new(SigningQueue::default());
+        let mut positions = queue.subscribe();
+
+        let permit = queue.acquire(QueuedRequest::SignTransaction, Some(0)).await.unwrap();
+        let waiting_queue = queue.clone();
+        let waiting = tokio::spawn(async move {
+            waiting_queue
+                .acquire(QueuedRequest::GenerateAddresses, Some(1))
+                .await
+                .is_some()
+        });
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        assert_eq!(queue.waiting_requests(), 1);
+
+        drop(permit);
+        assert!(waiting.await.unwrap());
+        assert_eq!(queue.waiting_requests(), 0);
+
+        let mut received = Vec::new();
+        while let Ok(position) = positions.try_recv() {
+            received.push((position.request_id, position.position));
+        }
+        // Both requests are published when they're made and when they're handled
+        assert_eq!(received, vec![(0, 0), (0, 0), (1, 1), (1, 0)]);
+    }
+
+    #[tokio::test]
+    async fn waiting_requests_time_out() {
+        let queue = SigningQueue::new(SigningQueueOptions {
+            concurrency: 1,
+            timeout: Some(Duration::from_millis(50)),
+        });
+
+        let permit = queue.acquire(QueuedRequest::SignTransaction, None).await.unwrap();
+        assert!(queue.acquire(QueuedRequest::Status, None).await.is_none());
+        assert_eq!(queue.waiting_requests(), 0);
+
+        drop(permit);
+        assert!(queue.acquire(QueuedRequest::Status, None).await.is_some());
+    }
+}
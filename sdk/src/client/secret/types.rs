@@ -3,13 +3,16 @@
 
 //! Miscellaneous types for secret managers.
 
-use crypto::keys::slip10::Chain;
+use std::{fmt, str::FromStr};
+
+use crypto::{keys::slip10::Chain, signatures::secp256k1_ecdsa};
 use serde::{Deserialize, Serialize};
+use sha3::{Digest, Keccak256};
 #[cfg(feature = "stronghold")]
 use zeroize::ZeroizeOnDrop;
 
 use crate::{
-    client::Result,
+    client::{constants::HD_WALLET_TYPE, Error, Result},
     types::block::{
         address::Address,
         output::{
@@ -62,6 +65,144 @@ impl GenerateAddressOptions {
     }
 }
 
+/// Returns the BIP-44 chain of an EVM address. Like in other EVM wallets, only the purpose, coin type and account index
+/// are hardened.
+pub fn evm_chain(coin_type: u32, account_index: u32, internal: bool, address_index: u32) -> Chain {
+    const HARDENED: u32 = 1 << 31;
+
+    Chain::from_u32(vec![
+        HD_WALLET_TYPE | HARDENED,
+        coin_type | HARDENED,
+        account_index | HARDENED,
+        internal as u32,
+        address_index,
+    ])
+}
+
+/// An EVM address, the last 20 bytes of the Keccak-256 hash of an uncompressed secp256k1 public key. It's displayed
+/// as hex string with the EIP-55 checksum.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct EvmAddress([u8; Self::LENGTH]);
+
+impl EvmAddress {
+    /// The length of an [`EvmAddress`].
+    pub const LENGTH: usize = 20;
+
+    /// Creates an [`EvmAddress`] from its bytes.
+    pub fn new(bytes: [u8; Self::LENGTH]) -> Self {
+        Self(bytes)
+    }
+
+    /// Creates the [`EvmAddress`] of a secp256k1 public key.
+    pub fn from_public_key(public_key: &secp256k1_ecdsa::PublicKey) -> Self {
+        Self(public_key.to_evm_address().into())
+    }
+
+    /// Returns the bytes of the [`EvmAddress`].
+    pub fn as_bytes(&self) -> &[u8; Self::LENGTH] {
+        &self.0
+    }
+}
+
+impl fmt::Display for EvmAddress {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // EIP-55: a letter is uppercase if the nibble at its position in the hash of the lowercase hex is at least 8
+        let hex = hex::encode(self.0);
+        let hash = Keccak256::digest(hex.as_bytes());
+
+        write!(f, "0x")?;
+        for (i, c) in hex.chars().enumerate() {
+            let nibble = if i % 2 == 0 {
+                hash[i / 2] >> 4
+            } else {
+                hash[i / 2] & 0x0f
+            };
+            if nibble >= 8 {
+                write!(f, "{}", c.to_ascii_uppercase())?;
+            } else {
+                write!(f, "{c}")?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl FromStr for EvmAddress {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        // The checksum isn't verified, so lowercase addresses can be parsed
+        Ok(Self(prefix_hex::decode(s.to_ascii_lowercase())?))
+    }
+}
+
+impl Serialize for EvmAddress {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for EvmAddress {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        s.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+/// A recoverable secp256k1 ECDSA signature with the compressed public key of the signer.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Secp256k1EcdsaSignature {
+    public_key: [u8; secp256k1_ecdsa::PublicKey::LENGTH],
+    signature: [u8; secp256k1_ecdsa::Signature::LENGTH],
+}
+
+impl Secp256k1EcdsaSignature {
+    /// Creates a new [`Secp256k1EcdsaSignature`].
+    pub fn new(
+        public_key: [u8; secp256k1_ecdsa::PublicKey::LENGTH],
+        signature: [u8; secp256k1_ecdsa::Signature::LENGTH],
+    ) -> Self {
+        Self { public_key, signature }
+    }
+
+    /// Returns the compressed public key of the signer.
+    pub fn public_key(&self) -> &[u8; secp256k1_ecdsa::PublicKey::LENGTH] {
+        &self.public_key
+    }
+
+    /// Returns the signature, `r` and `s` followed by the recovery id.
+    pub fn signature(&self) -> &[u8; secp256k1_ecdsa::Signature::LENGTH] {
+        &self.signature
+    }
+
+    /// Returns the [`EvmAddress`] of the signer.
+    pub fn evm_address(&self) -> Result<EvmAddress> {
+        Ok(EvmAddress::from_public_key(
+            &secp256k1_ecdsa::PublicKey::try_from_bytes(&self.public_key)?,
+        ))
+    }
+}
+
+/// Dto for a [`Secp256k1EcdsaSignature`].
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Secp256k1EcdsaSignatureDto {
+    /// The hex encoded compressed public key.
+    pub public_key: String,
+    /// The hex encoded recoverable signature.
+    pub signature: String,
+}
+
+impl From<&Secp256k1EcdsaSignature> for Secp256k1EcdsaSignatureDto {
+    fn from(value: &Secp256k1EcdsaSignature) -> Self {
+        Self {
+            public_key: prefix_hex::encode(value.public_key),
+            signature: prefix_hex::encode(value.signature),
+        }
+    }
+}
+
 /// The Ledger device status.
 #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
 pub struct LedgerApp {
@@ -150,6 +291,7 @@ pub struct InputSigningData {
     /// The output metadata
     pub output_metadata: OutputMetadata,
     /// The chain derived from seed, only for ed25519 addresses
+    #[serde(default, with = "chain_serde::option")]
     pub chain: Option<Chain>,
 }
 
@@ -169,6 +311,7 @@ pub struct InputSigningDataDto {
     /// The output metadata
     pub output_metadata: OutputMetadataDto,
     /// The chain derived from seed, only for ed25519 addresses
+    #[serde(default, with = "chain_serde::option")]
     pub chain: Option<Chain>,
 }
 
@@ -199,3 +342,80 @@ impl From<&InputSigningData> for InputSigningDataDto {
         }
     }
 }
+
+/// (De)serializes a [`Chain`] in the format of previous versions of `iota-crypto`, with segments like
+/// `{"hardened":true,"bs":[128,0,0,44]}`, which is used by stored wallets and the bindings. To be used with
+/// `#[serde(with = "chain_serde")]`, or `#[serde(default, with = "chain_serde::option")]` for an `Option<Chain>`.
+pub mod chain_serde {
+    use crypto::keys::slip10::Chain;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    #[derive(Serialize, Deserialize)]
+    struct Segment {
+        hardened: bool,
+        bs: [u8; 4],
+    }
+
+    struct LegacyChain(Chain);
+
+    impl Serialize for LegacyChain {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            serialize(&self.0, serializer)
+        }
+    }
+
+    impl<'de> Deserialize<'de> for LegacyChain {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            deserialize(deserializer).map(Self)
+        }
+    }
+
+    pub fn serialize<S: Serializer>(chain: &Chain, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(chain.segments().iter().map(|segment| Segment {
+            hardened: segment.is_hardened(),
+            bs: segment.bs(),
+        }))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Chain, D::Error> {
+        let segments = Vec::<Segment>::deserialize(deserializer)?;
+        // The hardened bit is part of the bytes
+        Ok(Chain::from_u32(
+            segments.into_iter().map(|segment| u32::from_be_bytes(segment.bs)),
+        ))
+    }
+
+    pub mod option {
+        use super::*;
+
+        pub fn serialize<S: Serializer>(chain: &Option<Chain>, serializer: S) -> Result<S::Ok, S::Error> {
+            chain.clone().map(LegacyChain).serialize(serializer)
+        }
+
+        pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<Chain>, D::Error> {
+            Ok(Option::<LegacyChain>::deserialize(deserializer)?.map(|chain| chain.0))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn legacy_chain_json() {
+        let json = r#"{"output":{"type":3,"amount":"1000000","unlockConditions":[{"type":0,"address":{"type":0,"pubKeyHash":"0x23508e749227bc8fccd3320b78891c0f793e9dd70be20c76acb64c36dcc46600"}}]},"outputMetadata":{"blockId":"0x488a085dd194ce3c2958a2013036f46811c6b7a9895719ecd5ba1d793f14c442","transactionId":"0x131fc4cb8f315ae36ae3bf6a4e4b3486d5f17581288f1217410da3e0700d195a","outputIndex":0,"isSpent":false,"milestoneIndexBooked":3060013,"milestoneTimestampBooked":1673436369,"ledgerIndex":3060016},"chain":[{"hardened":true,"bs":[128,0,0,44]},{"hardened":true,"bs":[128,0,16,123]},{"hardened":true,"bs":[128,0,0,0]},{"hardened":false,"bs":[0,0,0,1]}]}"#;
+
+        let input = serde_json::from_str::<InputSigningDataDto>(json).unwrap();
+        let chain = input.chain.as_ref().unwrap();
+        assert_eq!(
+            chain.segments()[..3],
+            Chain::from_u32_hardened(vec![44, 4219, 0]).segments()[..]
+        );
+        assert!(!chain.segments()[3].is_hardened());
+        assert_eq!(serde_json::to_string(&input).unwrap(), json);
+
+        let input = serde_json::from_str::<InputSigningDataDto>(&json.replace(r#","chain":[{"hardened":true,"bs":[128,0,0,44]},{"hardened":true,"bs":[128,0,16,123]},{"hardened":true,"bs":[128,0,0,0]},{"hardened":false,"bs":[0,0,0,1]}]"#, "")).unwrap();
+        assert!(input.chain.is_none());
+    }
+}
@@ -21,6 +21,9 @@ pub(super) const SEED_RECORD_PATH: &[u8] = b"iota-wallet-seed";
 /// The value has been hard-coded historically.
 pub(super) const DERIVE_OUTPUT_RECORD_PATH: &[u8] = b"iota-wallet-derived";
 
+/// Stronghold store key of the encrypted mnemonic, only stored if it was stored as exportable.
+pub(super) const MNEMONIC_STORE_KEY: &[u8] = b"iota-wallet-mnemonic";
+
 /// The client path for the seed.
 ///
 /// The value has been hard-coded historically.
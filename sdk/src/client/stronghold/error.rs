@@ -1,6 +1,8 @@
 // Copyright 2023 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+use crate::types::block::address::Address;
+
 /// Stronghold errors.
 #[derive(Debug, thiserror::Error)]
 pub enum Error {
@@ -19,15 +21,32 @@ pub enum Error {
     /// Stronghold memory error
     #[error("stronghold memory error: {0}")]
     Memory(#[from] iota_stronghold::MemoryError),
+    /// The mnemonic doesn't derive the expected address, e.g. because of a wrong passphrase
+    #[error("the mnemonic derives the address {derived:?} instead of the expected {expected:?}")]
+    MnemonicAddressMismatch {
+        /// The expected address.
+        expected: Box<Address>,
+        /// The address derived from the mnemonic.
+        derived: Box<Address>,
+    },
     /// A mnemonic has been already stored into a Stronghold vault
     #[error("a mnemonic has already been stored in the Stronghold vault")]
     MnemonicAlreadyStored,
     /// No mnemonic has been stored into the Stronghold vault
     #[error("no mnemonic has been stored into the Stronghold vault")]
     MnemonicMissing,
+    /// The mnemonic wasn't stored as exportable
+    #[error("the mnemonic can't be exported, it wasn't stored as exportable")]
+    MnemonicNotExportable,
     /// Procedure execution error from Stronghold
     #[error("Stronghold reported a procedure error: {0}")]
     Procedure(#[from] iota_stronghold::procedures::ProcedureError),
+    /// A blocking Stronghold operation panicked or was cancelled
+    #[error("{0}")]
+    TaskJoin(#[from] tokio::task::JoinError),
+    /// The operation isn't supported by Stronghold
+    #[error("unsupported operation: {0}")]
+    UnsupportedOperation(&'static str),
     // TODO remove later
     /// Invalid mnemonic error
     #[error("invalid mnemonic {0}")]
@@ -37,6 +37,10 @@
 //! [`read_stronghold_snapshot()`] or [`write_stronghold_snapshot()`]. The latter can be used to create a snapshot file
 //! after creating a [`StrongholdAdapter`] with a non-existent snapshot path.
 //!
+//! Reading and writing snapshots, deriving keys and signing run on the blocking thread pool of Tokio, so they don't
+//! block the async runtime, e.g. the UI thread of an application. The progress of address generation, snapshot writes
+//! and password changes is published to the receivers of [`subscribe_progress()`].
+//!
 //! [Stronghold]: iota_stronghold
 //! [`StorageProvider`]: crate::client::storage::StorageProvider
 //! [`SecretManage`]: crate::client::secret::SecretManage
@@ -45,6 +49,7 @@
 //! [`set_timeout()`]: self::StrongholdAdapter::set_timeout()
 //! [`read_stronghold_snapshot()`]: self::StrongholdAdapter::read_stronghold_snapshot()
 //! [`write_stronghold_snapshot()`]: self::StrongholdAdapter::write_stronghold_snapshot()
+//! [`subscribe_progress()`]: self::StrongholdAdapter::subscribe_progress()
 
 mod common;
 mod error;
@@ -60,16 +65,47 @@ use std::{
 use derive_builder::Builder;
 use iota_stronghold::{KeyProvider, SnapshotPath, Stronghold};
 use log::{debug, error, warn};
+use serde::{Deserialize, Serialize};
 use tokio::{
-    sync::{Mutex, MutexGuard},
+    sync::{broadcast, Mutex, MutexGuard},
     task::JoinHandle,
 };
 use zeroize::Zeroizing;
 
 use self::common::PRIVATE_DATA_CLIENT_PATH;
-pub use self::error::Error;
+pub use self::{
+    error::Error,
+    secret::{ExpectedAddress, StoreMnemonicOptions},
+};
 use crate::client::storage::StorageProvider;
 
+// The number of progress updates that are buffered for subscribers that don't receive them fast enough
+const PROGRESS_CAPACITY: usize = 64;
+
+/// A long running operation of a [`StrongholdAdapter`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum StrongholdOperation {
+    /// Generating addresses, one step per address.
+    GenerateAddresses,
+    /// Writing the snapshot to the disk, e.g. for a backup.
+    WriteSnapshot,
+    /// Re-encrypting the stored data with a new password, one step per record.
+    ChangePassword,
+}
+
+/// The progress of a [`StrongholdOperation`], published every time a step of it is done.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StrongholdProgress {
+    /// The operation.
+    pub operation: StrongholdOperation,
+    /// The number of steps that are done.
+    pub done: usize,
+    /// The number of steps of the operation.
+    pub total: usize,
+}
+
 /// A wrapper on [Stronghold].
 ///
 /// See the [module-level documentation](self) for more details.
@@ -110,6 +146,10 @@ pub struct StrongholdAdapter {
     /// The path to a Stronghold snapshot file.
     #[builder(setter(skip))]
     pub snapshot_path: PathBuf,
+
+    /// The sender of the progress of long running operations.
+    #[builder(setter(skip))]
+    progress: broadcast::Sender<StrongholdProgress>,
 }
 
 fn check_or_create_snapshot(
@@ -208,6 +248,7 @@ impl StrongholdAdapterBuilder {
             timeout: self.timeout.unwrap_or(None),
             timeout_task: self.timeout_task.unwrap_or_else(|| Arc::new(Mutex::new(None))),
             snapshot_path: snapshot_path.as_ref().to_path_buf(),
+            progress: broadcast::channel(PROGRESS_CAPACITY).0,
         })
     }
 }
@@ -218,6 +259,52 @@ impl StrongholdAdapter {
         StrongholdAdapterBuilder::default()
     }
 
+    /// Returns a receiver of the progress of long running operations, like generating many addresses or writing the
+    /// snapshot.
+    pub fn subscribe_progress(&self) -> broadcast::Receiver<StrongholdProgress> {
+        self.progress.subscribe()
+    }
+
+    fn publish_progress(&self, operation: StrongholdOperation, done: usize, total: usize) {
+        // Fails only if there are no subscribers
+        self.progress
+            .send(StrongholdProgress { operation, done, total })
+            .ok();
+    }
+
+    // Runs an operation on Stronghold on the blocking thread pool, the lock is released when it's done
+    async fn run_blocking<T, F>(&self, f: F) -> Result<T, Error>
+    where
+        T: Send + 'static,
+        F: FnOnce(&Stronghold) -> Result<T, Error> + Send + 'static,
+    {
+        let stronghold = self.stronghold.clone();
+
+        tokio::task::spawn_blocking(move || f(&stronghold.blocking_lock())).await?
+    }
+
+    // Like `run_blocking()`, but with the key, which is locked before Stronghold like everywhere else
+    #[allow(clippy::significant_drop_tightening)]
+    async fn run_blocking_with_key<T, F>(&self, f: F) -> Result<T, Error>
+    where
+        T: Send + 'static,
+        F: FnOnce(&Stronghold, &KeyProvider) -> Result<T, Error> + Send + 'static,
+    {
+        let stronghold = self.stronghold.clone();
+        let key_provider = self.key_provider.clone();
+
+        tokio::task::spawn_blocking(move || {
+            // The key needs to be supplied first.
+            let locked_key_provider = key_provider.blocking_lock();
+            let Some(key_provider) = &*locked_key_provider else {
+                return Err(Error::KeyCleared);
+            };
+
+            f(&stronghold.blocking_lock(), key_provider)
+        })
+        .await?
+    }
+
     /// Test if the key hasn't been cleared.
     pub async fn is_key_available(&self) -> bool {
         self.key_provider.lock().await.is_some()
@@ -241,9 +328,12 @@ impl StrongholdAdapter {
         }
 
         let snapshot_path = SnapshotPath::from_path(&self.snapshot_path);
-        let stronghold = self.stronghold.lock().await;
-
-        check_or_create_snapshot(&stronghold, &key_provider, &snapshot_path)?;
+        let key_provider = self
+            .run_blocking(move |stronghold| {
+                check_or_create_snapshot(stronghold, &key_provider, &snapshot_path)?;
+                Ok(key_provider)
+            })
+            .await?;
 
         *key_provider_guard = Some(key_provider);
         drop(key_provider_guard);
@@ -341,7 +431,8 @@ impl StrongholdAdapter {
             old_key_provider
         };
 
-        for (key, value) in values {
+        let total = values.len();
+        for (done, (key, value)) in values.into_iter().enumerate() {
             if let Err(err) = self.insert(&key, &value).await {
                 error!("an error occurred during the re-encryption of Stronghold store: {err}");
 
@@ -367,6 +458,7 @@ impl StrongholdAdapter {
 
                 return Err(err);
             }
+            self.publish_progress(StrongholdOperation::ChangePassword, done + 1, total);
         }
 
         // Rewrite the snapshot to finish the password changing process.
@@ -455,23 +547,14 @@ impl StrongholdAdapter {
     }
 
     /// Load Stronghold from a snapshot at `snapshot_path`, if it hasn't been loaded yet.
-    #[allow(clippy::significant_drop_tightening)]
     pub async fn read_stronghold_snapshot(&mut self) -> Result<(), Error> {
-        // The key needs to be supplied first.
-        let locked_key_provider = self.key_provider.lock().await;
-        let key_provider = if let Some(key_provider) = &*locked_key_provider {
-            key_provider
-        } else {
-            return Err(Error::KeyCleared);
-        };
-
-        self.stronghold.lock().await.load_client_from_snapshot(
-            PRIVATE_DATA_CLIENT_PATH,
-            key_provider,
-            &SnapshotPath::from_path(&self.snapshot_path),
-        )?;
+        let snapshot_path = SnapshotPath::from_path(&self.snapshot_path);
 
-        Ok(())
+        self.run_blocking_with_key(move |stronghold, key_provider| {
+            stronghold.load_client_from_snapshot(PRIVATE_DATA_CLIENT_PATH, key_provider, &snapshot_path)?;
+            Ok(())
+        })
+        .await
     }
 
     /// Persist Stronghold to a snapshot at a provided `snapshot_path` or at the Stronghold's own `snapshot_path` if
@@ -480,20 +563,16 @@ impl StrongholdAdapter {
     /// It doesn't unload the snapshot; see also [`unload_stronghold_snapshot()`].
     ///
     /// [`unload_stronghold_snapshot()`]: Self::unload_stronghold_snapshot()
-    #[allow(clippy::significant_drop_tightening)]
     pub async fn write_stronghold_snapshot(&self, snapshot_path: Option<&Path>) -> Result<(), Error> {
-        // The key needs to be supplied first.
-        let locked_key_provider = self.key_provider.lock().await;
-        let key_provider = if let Some(key_provider) = &*locked_key_provider {
-            key_provider
-        } else {
-            return Err(Error::KeyCleared);
-        };
+        let snapshot_path = SnapshotPath::from_path(snapshot_path.unwrap_or(&self.snapshot_path));
 
-        self.stronghold.lock().await.commit_with_keyprovider(
-            &SnapshotPath::from_path(snapshot_path.unwrap_or(&self.snapshot_path)),
-            key_provider,
-        )?;
+        self.publish_progress(StrongholdOperation::WriteSnapshot, 0, 1);
+        self.run_blocking_with_key(move |stronghold, key_provider| {
+            stronghold.commit_with_keyprovider(&snapshot_path, key_provider)?;
+            Ok(())
+        })
+        .await?;
+        self.publish_progress(StrongholdOperation::WriteSnapshot, 1, 1);
 
         Ok(())
     }
@@ -563,15 +642,15 @@ mod tests {
         tokio::time::sleep(Duration::from_millis(10)).await;
 
         // Setting a password would spawn a task to automatically clear the key.
-        assert!(matches!(*adapter.key_provider.lock().await, Some(_)));
+        assert!(adapter.key_provider.lock().await.is_some());
         assert_eq!(adapter.get_timeout(), Some(timeout));
-        assert!(matches!(*adapter.timeout_task.lock().await, Some(_)));
+        assert!(adapter.timeout_task.lock().await.is_some());
 
         // After the timeout, the key should be purged.
         tokio::time::sleep(Duration::from_millis(200)).await;
-        assert!(matches!(*adapter.key_provider.lock().await, None));
+        assert!(adapter.key_provider.lock().await.is_none());
         assert_eq!(adapter.get_timeout(), Some(timeout));
-        assert!(matches!(*adapter.timeout_task.lock().await, None));
+        assert!(adapter.timeout_task.lock().await.is_none());
 
         // Set the key again, but this time we manually purge the key.
         let timeout = None;
@@ -580,15 +659,15 @@ mod tests {
         assert!(adapter.set_password("password").await.is_err());
 
         adapter.clear_key().await;
-        assert!(matches!(*adapter.key_provider.lock().await, None));
+        assert!(adapter.key_provider.lock().await.is_none());
         assert_eq!(adapter.get_timeout(), timeout);
-        assert!(matches!(*adapter.timeout_task.lock().await, None));
+        assert!(adapter.timeout_task.lock().await.is_none());
 
         // Even if we attempt to restart the task, it won't.
         adapter.restart_key_clearing_task().await;
-        assert!(matches!(*adapter.key_provider.lock().await, None));
+        assert!(adapter.key_provider.lock().await.is_none());
         assert_eq!(adapter.get_timeout(), timeout);
-        assert!(matches!(*adapter.timeout_task.lock().await, None));
+        assert!(adapter.timeout_task.lock().await.is_none());
 
         fs::remove_file(stronghold_path).unwrap();
     }
@@ -6,21 +6,27 @@
 use std::ops::Range;
 
 use async_trait::async_trait;
-use crypto::hashes::{blake2b::Blake2b256, Digest};
+use crypto::{
+    hashes::{blake2b::Blake2b256, Digest},
+    keys::slip10::Chain,
+};
 use iota_stronghold::{
-    procedures::{self, Chain, KeyType, Slip10DeriveInput},
-    Location,
+    procedures::{self, KeyType, Slip10DeriveInput},
+    Location, Stronghold,
 };
-use zeroize::Zeroize;
+use zeroize::{Zeroize, ZeroizeOnDrop, Zeroizing};
 
 use super::{
-    common::{DERIVE_OUTPUT_RECORD_PATH, PRIVATE_DATA_CLIENT_PATH, SECRET_VAULT_PATH, SEED_RECORD_PATH},
-    StrongholdAdapter,
+    common::{
+        key_provider_from_password, DERIVE_OUTPUT_RECORD_PATH, MNEMONIC_STORE_KEY, PRIVATE_DATA_CLIENT_PATH,
+        SECRET_VAULT_PATH, SEED_RECORD_PATH,
+    },
+    StrongholdAdapter, StrongholdOperation,
 };
 use crate::{
     client::{
         constants::HD_WALLET_TYPE,
-        secret::{GenerateAddressOptions, SecretManage},
+        secret::{EvmAddress, GenerateAddressOptions, Secp256k1EcdsaSignature, SecretManage},
         stronghold::Error,
     },
     types::block::{
@@ -29,6 +35,28 @@ use crate::{
     },
 };
 
+/// Options to store a mnemonic with [`StrongholdAdapter::store_mnemonic_with_options()`].
+#[derive(Clone, Debug, Default, ZeroizeOnDrop)]
+pub struct StoreMnemonicOptions {
+    /// The BIP-39 passphrase, also known as 25th word, the seed is derived with, as used by some other wallets.
+    pub passphrase: Option<String>,
+    /// The address the mnemonic and passphrase need to derive, to detect typos before anything is stored.
+    #[zeroize(skip)]
+    pub expected_address: Option<ExpectedAddress>,
+    /// Whether the mnemonic is also stored encrypted, so it can be exported again with
+    /// [`StrongholdAdapter::export_mnemonic()`]. Otherwise only the seed derived from it is stored.
+    pub exportable: bool,
+}
+
+/// The first public address of the first account of a coin type, expected to be derived from a mnemonic.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct ExpectedAddress {
+    /// The coin type.
+    pub coin_type: u32,
+    /// The address.
+    pub address: Address,
+}
+
 #[async_trait]
 impl SecretManage for StrongholdAdapter {
     type Error = Error;
@@ -56,33 +84,28 @@ impl SecretManage for StrongholdAdapter {
         let mut addresses = Vec::new();
         let internal = options.map(|o| o.internal).unwrap_or_default();
 
-        for address_index in address_indexes {
+        let total = address_indexes.len();
+        for (done, address_index) in address_indexes.enumerate() {
             let bip_path = vec![HD_WALLET_TYPE, coin_type, account_index, internal as u32, address_index];
-            let chain = Chain::from_u32_hardened(bip_path);
+            let chain = procedures::Chain::from_u32_hardened(bip_path);
+            let derive_location = private_key_location(&chain);
+            let seed_location = seed_location.clone();
 
-            let derive_location = Location::generic(
-                SECRET_VAULT_PATH,
-                [
-                    DERIVE_OUTPUT_RECORD_PATH,
-                    &chain.segments().iter().flat_map(|seg| seg.bs()).collect::<Vec<u8>>(),
-                ]
-                .concat(),
-            );
+            // Each address is derived in its own blocking task, so other tasks can run in between.
+            let public_key = self
+                .run_blocking(move |stronghold| {
+                    // Derive a SLIP-10 private key in the vault.
+                    slip10_derive(stronghold, chain, seed_location, derive_location.clone())?;
 
-            // Derive a SLIP-10 private key in the vault.
-            self.slip10_derive(chain, seed_location.clone(), derive_location.clone())
-                .await?;
+                    // Get the Ed25519 public key from the derived SLIP-10 private key in the vault.
+                    let public_key = ed25519_public_key(stronghold, derive_location.clone())?;
 
-            // Get the Ed25519 public key from the derived SLIP-10 private key in the vault.
-            let public_key = self.ed25519_public_key(derive_location.clone()).await?;
+                    // Cleanup location afterwards
+                    delete_secret(stronghold, &derive_location)?;
 
-            // Cleanup location afterwards
-            self.stronghold
-                .lock()
-                .await
-                .get_client(PRIVATE_DATA_CLIENT_PATH)?
-                .vault(SECRET_VAULT_PATH)
-                .delete_secret(derive_location.record_path())?;
+                    Ok(public_key)
+                })
+                .await?;
 
             // Hash the public key to get the address.
             let hash = Blake2b256::digest(public_key);
@@ -92,6 +115,7 @@ impl SecretManage for StrongholdAdapter {
 
             // Collect it.
             addresses.push(address);
+            self.publish_progress(StrongholdOperation::GenerateAddresses, done + 1, total);
         }
 
         Ok(addresses)
@@ -110,32 +134,43 @@ impl SecretManage for StrongholdAdapter {
         // Stronghold arguments.
         let seed_location = Slip10DeriveInput::Seed(Location::generic(SECRET_VAULT_PATH, SEED_RECORD_PATH));
 
-        let derive_location = Location::generic(
-            SECRET_VAULT_PATH,
-            [
-                DERIVE_OUTPUT_RECORD_PATH,
-                &chain.segments().iter().flat_map(|seg| seg.bs()).collect::<Vec<u8>>(),
-            ]
-            .concat(),
-        );
+        let chain = stronghold_chain(chain);
+        let derive_location = private_key_location(&chain);
+        let msg = msg.to_vec();
 
-        // Derive a SLIP-10 private key in the vault.
-        self.slip10_derive(chain.clone(), seed_location, derive_location.clone())
-            .await?;
+        self.run_blocking(move |stronghold| {
+            // Derive a SLIP-10 private key in the vault.
+            slip10_derive(stronghold, chain, seed_location, derive_location.clone())?;
 
-        // Get the Ed25519 public key from the derived SLIP-10 private key in the vault.
-        let public_key = self.ed25519_public_key(derive_location.clone()).await?;
-        let signature = self.ed25519_sign(derive_location.clone(), msg).await?;
+            // Get the Ed25519 public key from the derived SLIP-10 private key in the vault.
+            let public_key = ed25519_public_key(stronghold, derive_location.clone())?;
+            let signature = ed25519_sign(stronghold, derive_location.clone(), msg)?;
 
-        // Cleanup location afterwards
-        self.stronghold
-            .lock()
-            .await
-            .get_client(PRIVATE_DATA_CLIENT_PATH)?
-            .vault(SECRET_VAULT_PATH)
-            .delete_secret(derive_location.record_path())?;
+            // Cleanup location afterwards
+            delete_secret(stronghold, &derive_location)?;
 
-        Ok(Ed25519Signature::new(public_key, signature))
+            Ok(Ed25519Signature::new(public_key, signature))
+        })
+        .await
+    }
+
+    async fn generate_evm_addresses(
+        &self,
+        _coin_type: u32,
+        _account_index: u32,
+        _address_indexes: Range<u32>,
+        _options: Option<GenerateAddressOptions>,
+    ) -> Result<Vec<EvmAddress>, Self::Error> {
+        // The vault only derives Ed25519 keys from the seed
+        Err(Error::UnsupportedOperation("secp256k1 keys"))
+    }
+
+    async fn sign_secp256k1_ecdsa(
+        &self,
+        _hash: &[u8; 32],
+        _chain: &Chain,
+    ) -> Result<Secp256k1EcdsaSignature, Self::Error> {
+        Err(Error::UnsupportedOperation("secp256k1 keys"))
     }
 }
 
@@ -143,76 +178,33 @@ impl SecretManage for StrongholdAdapter {
 impl StrongholdAdapter {
     /// Execute [Procedure::BIP39Recover] in Stronghold to put a mnemonic into the Stronghold vault.
     async fn bip39_recover(&self, mnemonic: String, passphrase: Option<String>, output: Location) -> Result<(), Error> {
-        self.stronghold
-            .lock()
-            .await
-            .get_client(PRIVATE_DATA_CLIENT_PATH)?
-            .execute_procedure(procedures::BIP39Recover {
-                mnemonic,
-                passphrase,
-                output,
-            })?;
-
-        Ok(())
-    }
-
-    /// Execute [Procedure::SLIP10Derive] in Stronghold to derive a SLIP-10 private key in the Stronghold vault.
-    async fn slip10_derive(&self, chain: Chain, input: Slip10DeriveInput, output: Location) -> Result<(), Error> {
-        if let Err(err) = self
-            .stronghold
-            .lock()
-            .await
-            .get_client(PRIVATE_DATA_CLIENT_PATH)?
-            .execute_procedure(procedures::Slip10Derive { chain, input, output })
-        {
-            match err {
-                iota_stronghold::procedures::ProcedureError::Engine(ref e) => {
-                    // Custom error for missing vault error: https://github.com/iotaledger/stronghold.rs/blob/7f0a2e0637394595e953f9071fa74b1d160f51ec/client/src/types/error.rs#L170
-                    if e.to_string().contains("does not exist") {
-                        // Actually the seed, derived from the mnemonic, is not stored.
-                        return Err(Error::MnemonicMissing);
-                    } else {
-                        return Err(err.into());
-                    }
-                }
-                _ => {
-                    return Err(err.into());
-                }
-            }
-        };
-
-        Ok(())
-    }
-
-    /// Execute [Procedure::Ed25519PublicKey] in Stronghold to get an Ed25519 public key from the SLIP-10 private key
-    /// located in `private_key`.
-    async fn ed25519_public_key(&self, private_key: Location) -> Result<[u8; 32], Error> {
-        Ok(self
-            .stronghold
-            .lock()
-            .await
-            .get_client(PRIVATE_DATA_CLIENT_PATH)?
-            .execute_procedure(procedures::PublicKey {
-                ty: KeyType::Ed25519,
-                private_key,
-            })?)
+        self.run_blocking(move |stronghold| {
+            stronghold
+                .get_client(PRIVATE_DATA_CLIENT_PATH)?
+                .execute_procedure(procedures::BIP39Recover {
+                    mnemonic,
+                    passphrase,
+                    output,
+                })?;
+
+            Ok(())
+        })
+        .await
     }
 
-    /// Execute [Procedure::Ed25519Sign] in Stronghold to sign `msg` with `private_key` stored in the Stronghold vault.
-    async fn ed25519_sign(&self, private_key: Location, msg: &[u8]) -> Result<[u8; 64], Error> {
-        Ok(self
-            .stronghold
-            .lock()
+    /// Store a mnemonic into the Stronghold vault.
+    pub async fn store_mnemonic(&self, mnemonic: String) -> Result<(), Error> {
+        self.store_mnemonic_with_options(mnemonic, StoreMnemonicOptions::default())
             .await
-            .get_client(PRIVATE_DATA_CLIENT_PATH)?
-            .execute_procedure(procedures::Ed25519Sign {
-                private_key,
-                msg: msg.to_vec(),
-            })?)
     }
 
-    /// Store a mnemonic into the Stronghold vault.
-    pub async fn store_mnemonic(&self, mut mnemonic: String) -> Result<(), Error> {
+    /// Store a mnemonic into the Stronghold vault, with a BIP-39 passphrase for the seed derivation and/or by
+    /// checking that it derives the expected address first. Nothing is stored if the check fails.
+    pub async fn store_mnemonic_with_options(
+        &self,
+        mut mnemonic: String,
+        options: StoreMnemonicOptions,
+    ) -> Result<(), Error> {
         // The key needs to be supplied first.
         if self.key_provider.lock().await.is_none() {
             return Err(Error::KeyCleared);
@@ -222,7 +214,7 @@ impl StrongholdAdapter {
         let output = Location::generic(SECRET_VAULT_PATH, SEED_RECORD_PATH);
 
         // Trim the mnemonic, in case it hasn't been, as otherwise the restored seed would be wrong.
-        let trimmed_mnemonic = mnemonic.trim().to_string();
+        let trimmed_mnemonic = Zeroizing::new(mnemonic.trim().to_string());
         mnemonic.zeroize();
 
         // Check if the mnemonic is valid.
@@ -241,13 +233,131 @@ impl StrongholdAdapter {
         }
 
         // Execute the BIP-39 recovery procedure to put it into the vault (in memory).
-        self.bip39_recover(trimmed_mnemonic, None, output).await?;
+        self.bip39_recover(trimmed_mnemonic.to_string(), options.passphrase.clone(), output.clone())
+            .await?;
+
+        if let Some(expected_address) = &options.expected_address {
+            let derived = self
+                .generate_addresses(expected_address.coin_type, 0, 0..1, None)
+                .await?[0];
+            if derived != expected_address.address {
+                // Remove the seed again, so another mnemonic or passphrase can be stored
+                self.run_blocking(move |stronghold| delete_secret(stronghold, &output))
+                    .await?;
+                return Err(Error::MnemonicAddressMismatch {
+                    expected: Box::new(expected_address.address),
+                    derived: Box::new(derived),
+                });
+            }
+        }
+
+        if options.exportable {
+            self.insert_encrypted(MNEMONIC_STORE_KEY, trimmed_mnemonic.as_bytes())
+                .await?;
+        }
 
         // Persist Stronghold to the disk
         self.write_stronghold_snapshot(None).await?;
 
         Ok(())
     }
+
+    /// Export the mnemonic that was stored as exportable. The password needs to be provided again, even if the key is
+    /// still cached, so an unattended unlocked Stronghold doesn't reveal it.
+    pub async fn export_mnemonic(&self, password: &str) -> Result<Zeroizing<String>, Error> {
+        {
+            let key_provider_guard = self.key_provider.lock().await;
+            let key_provider = key_provider_guard.as_ref().ok_or(Error::KeyCleared)?;
+            if key_provider.try_unlock()? != key_provider_from_password(password).try_unlock()? {
+                return Err(Error::InvalidPassword);
+            }
+        }
+
+        let mnemonic = self
+            .get_decrypted(MNEMONIC_STORE_KEY)
+            .await?
+            .ok_or(Error::MnemonicNotExportable)?;
+
+        // The mnemonic was stored from a string, so it's valid UTF-8
+        Ok(Zeroizing::new(
+            String::from_utf8(mnemonic).map_err(|_| Error::MnemonicNotExportable)?,
+        ))
+    }
+}
+
+/// Converts a [`Chain`] to the one of the crypto.rs version Stronghold depends on.
+fn stronghold_chain(chain: &Chain) -> procedures::Chain {
+    procedures::Chain::from_u32(chain.segments().iter().map(|segment| u32::from_be_bytes(segment.bs())))
+}
+
+/// The location in the vault for the private key of a chain.
+fn private_key_location(chain: &procedures::Chain) -> Location {
+    Location::generic(
+        SECRET_VAULT_PATH,
+        [
+            DERIVE_OUTPUT_RECORD_PATH,
+            &chain.segments().iter().flat_map(|seg| seg.bs()).collect::<Vec<u8>>(),
+        ]
+        .concat(),
+    )
+}
+
+/// Execute [Procedure::SLIP10Derive] in Stronghold to derive a SLIP-10 private key in the Stronghold vault.
+fn slip10_derive(
+    stronghold: &Stronghold,
+    chain: procedures::Chain,
+    input: Slip10DeriveInput,
+    output: Location,
+) -> Result<(), Error> {
+    if let Err(err) = stronghold
+        .get_client(PRIVATE_DATA_CLIENT_PATH)?
+        .execute_procedure(procedures::Slip10Derive { chain, input, output })
+    {
+        match err {
+            iota_stronghold::procedures::ProcedureError::Engine(ref e) => {
+                // Custom error for missing vault error: https://github.com/iotaledger/stronghold.rs/blob/7f0a2e0637394595e953f9071fa74b1d160f51ec/client/src/types/error.rs#L170
+                if e.to_string().contains("does not exist") {
+                    // Actually the seed, derived from the mnemonic, is not stored.
+                    return Err(Error::MnemonicMissing);
+                } else {
+                    return Err(err.into());
+                }
+            }
+            _ => {
+                return Err(err.into());
+            }
+        }
+    };
+
+    Ok(())
+}
+
+/// Execute [Procedure::Ed25519PublicKey] in Stronghold to get an Ed25519 public key from the SLIP-10 private key
+/// located in `private_key`.
+fn ed25519_public_key(stronghold: &Stronghold, private_key: Location) -> Result<[u8; 32], Error> {
+    Ok(stronghold
+        .get_client(PRIVATE_DATA_CLIENT_PATH)?
+        .execute_procedure(procedures::PublicKey {
+            ty: KeyType::Ed25519,
+            private_key,
+        })?)
+}
+
+/// Execute [Procedure::Ed25519Sign] in Stronghold to sign `msg` with `private_key` stored in the Stronghold vault.
+fn ed25519_sign(stronghold: &Stronghold, private_key: Location, msg: Vec<u8>) -> Result<[u8; 64], Error> {
+    Ok(stronghold
+        .get_client(PRIVATE_DATA_CLIENT_PATH)?
+        .execute_procedure(procedures::Ed25519Sign { private_key, msg })?)
+}
+
+/// Delete the derived private key at `location` from the Stronghold vault.
+fn delete_secret(stronghold: &Stronghold, location: &Location) -> Result<(), Error> {
+    stronghold
+        .get_client(PRIVATE_DATA_CLIENT_PATH)?
+        .vault(SECRET_VAULT_PATH)
+        .delete_secret(location.record_path())?;
+
+    Ok(())
 }
 
 #[cfg(test)]
@@ -289,6 +399,47 @@ mod tests {
         std::fs::remove_file(stronghold_path).ok();
     }
 
+    #[tokio::test]
+    async fn address_generation_progress() {
+        let stronghold_path = "address_generation_progress.stronghold";
+        // Remove potential old stronghold file
+        std::fs::remove_file(stronghold_path).ok();
+        let mnemonic = String::from(
+            "giant dynamic museum toddler six deny defense ostrich bomb access mercy blood explain muscle shoot shallow glad autumn author calm heavy hawk abuse rally",
+        );
+        let stronghold_adapter = StrongholdAdapter::builder()
+            .password("drowssap")
+            .build(stronghold_path)
+            .unwrap();
+        let mut progress = stronghold_adapter.subscribe_progress();
+
+        stronghold_adapter.store_mnemonic(mnemonic).await.unwrap();
+        let addresses = stronghold_adapter
+            .generate_addresses(IOTA_COIN_TYPE, 0, 0..3, None)
+            .await
+            .unwrap();
+        assert_eq!(addresses.len(), 3);
+
+        let mut received = Vec::new();
+        while let Ok(update) = progress.try_recv() {
+            received.push((update.operation, update.done, update.total));
+        }
+        // Storing the mnemonic writes the snapshot
+        assert_eq!(
+            received,
+            vec![
+                (StrongholdOperation::WriteSnapshot, 0, 1),
+                (StrongholdOperation::WriteSnapshot, 1, 1),
+                (StrongholdOperation::GenerateAddresses, 1, 3),
+                (StrongholdOperation::GenerateAddresses, 2, 3),
+                (StrongholdOperation::GenerateAddresses, 3, 3),
+            ]
+        );
+
+        // Remove garbage after test, but don't care about the result
+        std::fs::remove_file(stronghold_path).ok();
+    }
+
     #[tokio::test]
     async fn test_key_cleared() {
         let stronghold_path = "test_key_cleared.stronghold";
@@ -333,4 +484,65 @@ mod tests {
         // Remove garbage after test, but don't care about the result
         std::fs::remove_file(stronghold_path).ok();
     }
+
+    #[tokio::test]
+    async fn store_mnemonic_with_options() {
+        let stronghold_path = "store_mnemonic_with_options.stronghold";
+        // Remove potential old stronghold file
+        std::fs::remove_file(stronghold_path).ok();
+        let mnemonic = "giant dynamic museum toddler six deny defense ostrich bomb access mercy blood explain muscle shoot shallow glad autumn author calm heavy hawk abuse rally";
+        let expected_address = ExpectedAddress {
+            coin_type: IOTA_COIN_TYPE,
+            address: Address::try_from_bech32("atoi1qpszqzadsym6wpppd6z037dvlejmjuke7s24hm95s9fg9vpua7vluehe53e")
+                .unwrap(),
+        };
+        let stronghold_adapter = StrongholdAdapter::builder()
+            .password("drowssap")
+            .build(stronghold_path)
+            .unwrap();
+
+        // The passphrase derives another seed
+        assert!(matches!(
+            stronghold_adapter
+                .store_mnemonic_with_options(
+                    mnemonic.to_string(),
+                    StoreMnemonicOptions {
+                        passphrase: Some("passphrase".to_string()),
+                        expected_address: Some(expected_address),
+                        exportable: true,
+                    },
+                )
+                .await,
+            Err(Error::MnemonicAddressMismatch { .. })
+        ));
+        assert!(matches!(
+            stronghold_adapter.export_mnemonic("drowssap").await,
+            Err(Error::MnemonicNotExportable)
+        ));
+
+        // Nothing was stored, so it can be stored again without the passphrase
+        stronghold_adapter
+            .store_mnemonic_with_options(
+                mnemonic.to_string(),
+                StoreMnemonicOptions {
+                    passphrase: None,
+                    expected_address: Some(expected_address),
+                    exportable: true,
+                },
+            )
+            .await
+            .unwrap();
+
+        assert!(matches!(
+            stronghold_adapter.export_mnemonic("password").await,
+            Err(Error::InvalidPassword)
+        ));
+        assert_eq!(
+            stronghold_adapter.export_mnemonic("drowssap").await.unwrap().as_str(),
+            mnemonic
+        );
+
+        // Remove garbage after test, but don't care about the result
+        std::fs::remove_file(stronghold_path).ok();
+    }
 }
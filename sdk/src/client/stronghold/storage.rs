@@ -15,8 +15,29 @@ use crate::client::{storage::StorageProvider, stronghold::Error};
 impl StorageProvider for StrongholdAdapter {
     type Error = Error;
 
-    #[allow(clippy::significant_drop_tightening)]
     async fn get(&mut self, k: &[u8]) -> Result<Option<Vec<u8>>, Self::Error> {
+        self.get_decrypted(k).await
+    }
+
+    async fn insert(&mut self, k: &[u8], v: &[u8]) -> Result<Option<Vec<u8>>, Self::Error> {
+        self.insert_encrypted(k, v).await
+    }
+
+    async fn delete(&mut self, k: &[u8]) -> Result<Option<Vec<u8>>, Self::Error> {
+        Ok(self
+            .stronghold
+            .lock()
+            .await
+            .get_client(PRIVATE_DATA_CLIENT_PATH)?
+            .store()
+            .delete(k)?)
+    }
+}
+
+/// Encryption of the values of the store with the cached key, shared with the secret manager implementation.
+impl StrongholdAdapter {
+    #[allow(clippy::significant_drop_tightening)]
+    pub(super) async fn get_decrypted(&self, k: &[u8]) -> Result<Option<Vec<u8>>, Error> {
         let data = match self
             .stronghold
             .lock()
@@ -41,7 +62,7 @@ impl StorageProvider for StrongholdAdapter {
         Ok(Some(chacha::aead_decrypt(buffer_ref.deref(), &data)?))
     }
 
-    async fn insert(&mut self, k: &[u8], v: &[u8]) -> Result<Option<Vec<u8>>, Self::Error> {
+    pub(super) async fn insert_encrypted(&self, k: &[u8], v: &[u8]) -> Result<Option<Vec<u8>>, Error> {
         let encrypted_value = {
             let locked_key_provider = self.key_provider.lock().await;
             let key_provider = if let Some(key_provider) = &*locked_key_provider {
@@ -63,16 +84,6 @@ impl StorageProvider for StrongholdAdapter {
             .store()
             .insert(k.to_vec(), encrypted_value, None)?)
     }
-
-    async fn delete(&mut self, k: &[u8]) -> Result<Option<Vec<u8>>, Self::Error> {
-        Ok(self
-            .stronghold
-            .lock()
-            .await
-            .get_client(PRIVATE_DATA_CLIENT_PATH)?
-            .store()
-            .delete(k)?)
-    }
 }
 
 mod tests {
@@ -0,0 +1,162 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Shaping of the block submission, so services that send many blocks during congestion don't have to throttle
+//! themselves.
+//!
+//! Blocks are built when their submission slot comes up, so their parents are recent when they're submitted. Slots are
+//! spread evenly according to the max blocks per second and can be deferred to a future time window.
+
+use std::{sync::Mutex, time::Duration};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    client::{Client, Error, Result},
+    utils::unix_timestamp_now,
+};
+
+/// Options for the submission of blocks, see
+/// [`ClientBuilder::with_submission_options()`](crate::client::ClientBuilder::with_submission_options()).
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct SubmissionOptions {
+    /// The PoW score the local PoW targets, if it's above the min PoW score of the network. A higher score makes the
+    /// blocks more likely to be processed by the nodes during congestion. Remote PoW is done by the nodes with their
+    /// own score.
+    pub target_pow_score: Option<u32>,
+    /// Max amount of blocks that are built and submitted per second.
+    pub max_blocks_per_second: Option<u32>,
+    /// The time window in which blocks are submitted, blocks are deferred until the window starts.
+    pub window: Option<SubmissionWindow>,
+}
+
+/// A time window for the submission of blocks.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SubmissionWindow {
+    /// Unix time in seconds from which blocks are submitted.
+    pub start: u32,
+    /// Unix time in seconds from which no blocks are submitted anymore, blocks fail with
+    /// [`Error::SubmissionWindowClosed`] then.
+    pub end: Option<u32>,
+}
+
+// Reserves submission slots for the blocks of a client
+#[derive(Debug, Default)]
+pub(crate) struct SubmissionQueue {
+    options: SubmissionOptions,
+    // Unix time of the next free slot
+    next_slot: Mutex<Option<Duration>>,
+}
+
+impl SubmissionQueue {
+    pub(crate) fn new(options: SubmissionOptions) -> Self {
+        Self {
+            options,
+            next_slot: Default::default(),
+        }
+    }
+
+    pub(crate) fn target_pow_score(&self) -> Option<u32> {
+        self.options.target_pow_score
+    }
+
+    // Reserves the next free slot, returns the delay until it's reached
+    fn reserve_slot(&self, now: Duration) -> Result<Duration> {
+        let mut slot = self
+            .options
+            .window
+            .map_or(now, |window| now.max(Duration::from_secs(window.start.into())));
+
+        let mut next_slot = match self.next_slot.lock() {
+            Ok(next_slot) => next_slot,
+            // A poisoned lock only means another submission panicked, the slot is still usable
+            Err(poisoned) => poisoned.into_inner(),
+        };
+        if let Some(next_slot) = *next_slot {
+            slot = slot.max(next_slot);
+        }
+
+        if let Some(end) = self.options.window.and_then(|window| window.end) {
+            if slot >= Duration::from_secs(end.into()) {
+                return Err(Error::SubmissionWindowClosed { end });
+            }
+        }
+
+        if let Some(max_blocks_per_second) = self.options.max_blocks_per_second.filter(|max| *max > 0) {
+            *next_slot = Some(slot + Duration::from_secs(1) / max_blocks_per_second);
+        }
+
+        Ok(slot - now)
+    }
+}
+
+impl Client {
+    /// Returns the PoW score of the local PoW, the min PoW score of the network or the higher target PoW score of the
+    /// submission options.
+    pub async fn get_target_pow_score(&self) -> Result<u32> {
+        let min_pow_score = self.get_min_pow_score().await?;
+        Ok(self
+            .submission_queue
+            .target_pow_score()
+            .map_or(min_pow_score, |target_pow_score| target_pow_score.max(min_pow_score)))
+    }
+
+    // Waits until the next submission slot of the block is reached
+    pub(crate) async fn wait_for_submission_slot(&self) -> Result<()> {
+        let delay = self.submission_queue.reserve_slot(unix_timestamp_now())?;
+
+        if !delay.is_zero() {
+            log::debug!("[wait_for_submission_slot] deferring block for {delay:.2?}");
+            #[cfg(target_family = "wasm")]
+            gloo_timers::future::TimeoutFuture::new(delay.as_millis() as u32).await;
+            #[cfg(not(target_family = "wasm"))]
+            tokio::time::sleep(delay).await;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn submission_slots() {
+        let now = Duration::from_secs(1_000);
+
+        let queue = SubmissionQueue::default();
+        assert_eq!(queue.reserve_slot(now).unwrap(), Duration::ZERO);
+        assert_eq!(queue.reserve_slot(now).unwrap(), Duration::ZERO);
+
+        let queue = SubmissionQueue::new(SubmissionOptions {
+            max_blocks_per_second: Some(4),
+            ..Default::default()
+        });
+        assert_eq!(queue.reserve_slot(now).unwrap(), Duration::ZERO);
+        assert_eq!(queue.reserve_slot(now).unwrap(), Duration::from_millis(250));
+        assert_eq!(queue.reserve_slot(now).unwrap(), Duration::from_millis(500));
+        // Slots that passed aren't reserved anymore
+        assert_eq!(
+            queue.reserve_slot(now + Duration::from_secs(5)).unwrap(),
+            Duration::ZERO
+        );
+
+        let queue = SubmissionQueue::new(SubmissionOptions {
+            max_blocks_per_second: Some(1),
+            window: Some(SubmissionWindow {
+                start: 1_010,
+                end: Some(1_012),
+            }),
+            ..Default::default()
+        });
+        assert_eq!(queue.reserve_slot(now).unwrap(), Duration::from_secs(10));
+        assert_eq!(queue.reserve_slot(now).unwrap(), Duration::from_secs(11));
+        assert!(matches!(
+            queue.reserve_slot(now),
+            Err(Error::SubmissionWindowClosed { end: 1_012 })
+        ));
+    }
+}
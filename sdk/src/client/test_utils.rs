@@ -0,0 +1,750 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Utilities to test applications built on the SDK without a network.
+//!
+//! [`MockNode`] is an in-memory node that answers the core and indexer API requests the SDK sends, with outputs,
+//! milestones and confirmations that are fully controlled by the test. A client uses it with
+//! [`ClientBuilder::with_mock_node()`](crate::client::ClientBuilder::with_mock_node), then no requests leave the
+//! process, so tests run hermetically and deterministically in CI.
+//! ```ignore
+//! let mock_node = MockNode::default();
+//! let output_id = mock_node.add_output(output);
+//! let client = Client::builder().with_mock_node(mock_node.clone())?.finish()?;
+//! ```
+
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex, MutexGuard},
+    time::Duration,
+};
+
+use serde::Serialize;
+use url::Url;
+
+use crate::{
+    types::{
+        api::{
+            core::{
+                dto::LedgerInclusionStateDto,
+                response::{
+                    BaseTokenResponse, BlockMetadataResponse, ConfirmedMilestoneResponse, InfoResponse,
+                    LatestMilestoneResponse, MetricsResponse, OutputWithMetadataResponse, RoutesResponse,
+                    StatusResponse, SubmitBlockResponse, TipsResponse, UtxoChangesResponse,
+                },
+            },
+            plugins::indexer::OutputIdsResponse,
+        },
+        block::{
+            address::{Address, Bech32Address},
+            input::Input,
+            output::{
+                dto::{OutputDto, OutputMetadataDto, RentStructureDto},
+                Output, OutputId, OutputMetadata, RentStructure,
+            },
+            payload::{
+                transaction::{TransactionEssence, TransactionId},
+                Payload,
+            },
+            protocol::{dto::ProtocolParametersDto, ProtocolParameters},
+            semantic::ConflictReason,
+            Block, BlockDto, BlockId,
+        },
+    },
+    utils::unix_timestamp_now,
+};
+
+/// How the blocks submitted to a [`MockNode`] are confirmed.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Confirmation {
+    /// Blocks are confirmed by a new milestone as soon as they are submitted.
+    Immediate,
+    /// Blocks are confirmed by the n-th milestone issued with [`MockNode::issue_milestone()`] after their submission.
+    AfterMilestones(u32),
+    /// Blocks are never confirmed.
+    Never,
+    /// Transactions are rejected with the conflict reason by the next milestone issued with
+    /// [`MockNode::issue_milestone()`].
+    Conflicting(ConflictReason),
+}
+
+/// An in-memory node for tests, see the [module documentation](self).
+///
+/// Clones of a mock node share its state, so a test can keep a clone to add outputs, issue milestones and inspect the
+/// requests while a client uses the node. Submitted transactions are applied to the ledger when their block is
+/// confirmed: the inputs are marked as spent and the outputs are added, so a wallet can sync them like on a real
+/// network. Requests of raw bytes are answered with JSON, except for the submission of blocks, and milestone payloads
+/// aren't available.
+#[derive(Clone, Debug)]
+pub struct MockNode {
+    state: Arc<Mutex<MockNodeState>>,
+}
+
+#[derive(Debug)]
+struct MockNodeState {
+    protocol_parameters: ProtocolParameters,
+    confirmation: Confirmation,
+    milestone_index: u32,
+    milestone_timestamp: u32,
+    outputs: HashMap<OutputId, MockOutput>,
+    blocks: HashMap<BlockId, MockBlock>,
+    // The submitted blocks in order, the latest one is returned as tip
+    block_ids: Vec<BlockId>,
+    included_blocks: HashMap<TransactionId, BlockId>,
+    injected_errors: Vec<InjectedError>,
+    requests: Vec<String>,
+    response_delay: Duration,
+    // Used to generate the IDs of added outputs
+    added_outputs: u32,
+}
+
+#[derive(Debug)]
+struct MockOutput {
+    output: Output,
+    block_id: BlockId,
+    milestone_index_booked: u32,
+    milestone_timestamp_booked: u32,
+    // The milestone index and timestamp and the transaction that spent the output
+    spent: Option<(u32, u32, TransactionId)>,
+}
+
+#[derive(Debug)]
+struct MockBlock {
+    block: Block,
+    state: BlockState,
+}
+
+#[derive(Clone, Copy, Debug)]
+enum BlockState {
+    // Confirmed by the milestone with the index, if any
+    Pending(Option<u32>),
+    Included(u32),
+    NoTransaction(u32),
+    Conflicting(u32, ConflictReason),
+}
+
+#[derive(Debug)]
+struct InjectedError {
+    path: String,
+    status: u16,
+    remaining: usize,
+}
+
+impl MockNode {
+    /// The URL of the node, the client sends all requests to it.
+    pub const URL: &'static str = "http://mock-node";
+
+    /// Creates a node of a network with the protocol parameters and a first milestone.
+    pub fn new(protocol_parameters: ProtocolParameters) -> Self {
+        Self {
+            state: Arc::new(Mutex::new(MockNodeState {
+                protocol_parameters,
+                confirmation: Confirmation::Immediate,
+                milestone_index: 1,
+                milestone_timestamp: unix_timestamp_now().as_secs() as u32,
+                outputs: HashMap::new(),
+                blocks: HashMap::new(),
+                block_ids: Vec::new(),
+                included_blocks: HashMap::new(),
+                injected_errors: Vec::new(),
+                requests: Vec::new(),
+                response_delay: Duration::ZERO,
+                added_outputs: 0,
+            })),
+        }
+    }
+
+    /// Returns the protocol parameters of the network.
+    pub fn protocol_parameters(&self) -> ProtocolParameters {
+        self.state().protocol_parameters.clone()
+    }
+
+    /// Sets how submitted blocks are confirmed, [`Confirmation::Immediate`] by default.
+    pub fn set_confirmation(&self, confirmation: Confirmation) {
+        self.state().confirmation = confirmation;
+    }
+
+    /// Adds an unspent output to the ledger, as if it was created by a confirmed transaction, and returns its ID.
+    pub fn add_output(&self, output: Output) -> OutputId {
+        self.state().add_output(output)
+    }
+
+    /// Returns an output of the ledger with its metadata.
+    pub fn output(&self, output_id: &OutputId) -> Option<(Output, OutputMetadata)> {
+        let state = self.state();
+        state
+            .outputs
+            .get(output_id)
+            .map(|output| (output.output.clone(), state.output_metadata(output_id, output)))
+    }
+
+    /// Returns the submitted blocks, oldest first.
+    pub fn blocks(&self) -> Vec<Block> {
+        let state = self.state();
+        state
+            .block_ids
+            .iter()
+            .map(|block_id| state.blocks[block_id].block.clone())
+            .collect()
+    }
+
+    /// Returns the index of the latest milestone.
+    pub fn milestone_index(&self) -> u32 {
+        self.state().milestone_index
+    }
+
+    /// Issues a new milestone, that confirms the pending blocks according to the [`Confirmation`], and returns its
+    /// index.
+    pub fn issue_milestone(&self) -> u32 {
+        self.state().issue_milestone()
+    }
+
+    /// Answers the next `count` requests, whose path starts with `path` like `api/core/v2/outputs`, with the status
+    /// code, e.g. 503 to simulate an unavailable node. Requests are retried by the client on 429 and 5xx, every retry
+    /// counts as request.
+    pub fn inject_error(&self, path: &str, status: u16, count: usize) {
+        self.state().injected_errors.push(InjectedError {
+            path: path.trim_start_matches('/').to_string(),
+            status,
+            remaining: count,
+        });
+    }
+
+    /// Delays all responses, e.g. to simulate a slow or unresponsive node.
+    pub fn set_response_delay(&self, delay: Duration) {
+        self.state().response_delay = delay;
+    }
+
+    pub(crate) fn response_delay(&self) -> Duration {
+        self.state().response_delay
+    }
+
+    /// Returns the received requests as method and path with query, like `GET /api/core/v2/info`, oldest first.
+    pub fn requests(&self) -> Vec<String> {
+        self.state().requests.clone()
+    }
+
+    fn state(&self) -> MutexGuard<'_, MockNodeState> {
+        self.state.lock().unwrap()
+    }
+
+    /// Returns the status code and the body of the response to a request.
+    pub(crate) fn handle(&self, method: &str, url: &Url, body: Option<&[u8]>) -> (u16, Vec<u8>) {
+        let mut state = self.state();
+        let path = url.path().trim_start_matches('/');
+        let query = url.query().map(|query| format!("?{query}")).unwrap_or_default();
+        state.requests.push(format!("{method} /{path}{query}"));
+
+        if let Some(status) = state.injected_error(path) {
+            return error_response(status, "injected error");
+        }
+        let query = url.query_pairs().into_owned().collect::<Vec<_>>();
+        let segments = path.split('/').collect::<Vec<_>>();
+
+        match (method, segments.as_slice()) {
+            ("GET", ["health"]) => (200, Vec::new()),
+            ("GET", ["api", "routes"]) => json_response(&RoutesResponse {
+                routes: vec!["core/v2".to_string(), "indexer/v1".to_string()],
+            }),
+            (_, ["api", "core", "v2", route @ ..]) => state.core_response(method, route, body.unwrap_or_default()),
+            ("GET", ["api", "indexer", "v1", "outputs", route @ ..]) => state.indexer_response(route, &query),
+            _ => not_found("route"),
+        }
+    }
+}
+
+impl Default for MockNode {
+    /// Creates a node of a test network without proof of work.
+    fn default() -> Self {
+        Self::new(
+            ProtocolParameters::new(
+                2,
+                String::from("mock"),
+                String::from("rms"),
+                0,
+                15,
+                RentStructure::new(500, 10, 1),
+                1_813_620_509_061_365,
+            )
+            .unwrap(),
+        )
+    }
+}
+
+// Clones share the state, so they're equal
+impl PartialEq for MockNode {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.state, &other.state)
+    }
+}
+
+impl Eq for MockNode {}
+
+impl MockNodeState {
+    fn injected_error(&mut self, path: &str) -> Option<u16> {
+        let injected_error = self
+            .injected_errors
+            .iter_mut()
+            .find(|e| e.remaining > 0 && path.starts_with(&e.path))?;
+        injected_error.remaining -= 1;
+
+        Some(injected_error.status)
+    }
+
+    fn add_output(&mut self, output: Output) -> OutputId {
+        self.added_outputs += 1;
+
+        let mut id = [0u8; 32];
+        id[..4].copy_from_slice(&self.added_outputs.to_le_bytes());
+        // Output index 0 is always valid
+        let output_id = OutputId::new(TransactionId::new(id), 0).unwrap();
+        let mock_output = MockOutput {
+            output,
+            block_id: BlockId::new(id),
+            milestone_index_booked: self.milestone_index,
+            milestone_timestamp_booked: self.milestone_timestamp,
+            spent: None,
+        };
+        self.outputs.insert(output_id, mock_output);
+
+        output_id
+    }
+
+    fn core_response(&mut self, method: &str, route: &[&str], body: &[u8]) -> (u16, Vec<u8>) {
+        match (method, route) {
+            ("GET", ["info"]) => json_response(&self.info()),
+            ("GET", ["tips"]) => json_response(&TipsResponse {
+                tips: vec![self.block_ids.last().copied().unwrap_or_else(BlockId::null).to_string()],
+            }),
+            ("POST", ["blocks"]) => self.submit_block(body),
+            ("GET", ["blocks", block_id]) => {
+                found_response(self.block(block_id).map(|block| BlockDto::from(&block.block)), "block")
+            }
+            ("GET", ["blocks", block_id, "metadata"]) => {
+                found_response(self.block(block_id).map(block_metadata), "block")
+            }
+            ("GET", ["milestones", "by-index", milestone_index, "utxo-changes"]) => {
+                found_response(self.utxo_changes(milestone_index), "milestone")
+            }
+            ("GET", ["outputs", output_id]) => found_response(self.output_response(output_id), "output"),
+            ("GET", ["outputs", output_id, "metadata"]) => {
+                found_response(self.output_response(output_id).map(|output| output.metadata), "output")
+            }
+            ("GET", ["transactions", transaction_id, "included-block"]) => found_response(
+                self.included_block(transaction_id)
+                    .map(|block| BlockDto::from(&block.block)),
+                "included block",
+            ),
+            ("GET", ["transactions", transaction_id, "included-block", "metadata"]) => found_response(
+                self.included_block(transaction_id).map(block_metadata),
+                "included block",
+            ),
+            _ => not_found("route"),
+        }
+    }
+
+    fn indexer_response(&self, route: &[&str], query: &[(String, String)]) -> (u16, Vec<u8>) {
+        match route {
+            [kind] => json_response(&self.output_ids(kind, query)),
+            [kind, id] => {
+                let output_ids = self.output_ids_by_chain_id(kind, id);
+                found_response((!output_ids.items.is_empty()).then_some(output_ids), "output")
+            }
+            _ => not_found("route"),
+        }
+    }
+
+    fn info(&self) -> InfoResponse {
+        let protocol_parameters = &self.protocol_parameters;
+        let rent_structure = protocol_parameters.rent_structure();
+        let milestone_id = Some(milestone_id(self.milestone_index));
+
+        InfoResponse {
+            name: "MockNode".to_string(),
+            version: env!("CARGO_PKG_VERSION").to_string(),
+            status: StatusResponse {
+                is_healthy: true,
+                latest_milestone: LatestMilestoneResponse {
+                    index: self.milestone_index,
+                    timestamp: Some(self.milestone_timestamp),
+                    milestone_id: milestone_id.clone(),
+                },
+                confirmed_milestone: ConfirmedMilestoneResponse {
+                    index: self.milestone_index,
+                    timestamp: Some(self.milestone_timestamp),
+                    milestone_id,
+                },
+                pruning_index: 0,
+            },
+            supported_protocol_versions: vec![protocol_parameters.protocol_version()],
+            protocol: ProtocolParametersDto {
+                protocol_version: protocol_parameters.protocol_version(),
+                network_name: protocol_parameters.network_name().to_string(),
+                bech32_hrp: protocol_parameters.bech32_hrp().to_string(),
+                min_pow_score: protocol_parameters.min_pow_score(),
+                below_max_depth: protocol_parameters.below_max_depth(),
+                rent_structure: RentStructureDto {
+                    v_byte_cost: rent_structure.byte_cost(),
+                    v_byte_factor_key: rent_structure.byte_factor_key(),
+                    v_byte_factor_data: rent_structure.byte_factor_data(),
+                },
+                token_supply: protocol_parameters.token_supply().to_string(),
+            },
+            pending_protocol_parameters: Vec::new(),
+            base_token: BaseTokenResponse {
+                name: "Shimmer".to_string(),
+                ticker_symbol: "SMR".to_string(),
+                unit: "SMR".to_string(),
+                subunit: Some("glow".to_string()),
+                decimals: 6,
+                use_metric_prefix: false,
+            },
+            metrics: MetricsResponse {
+                blocks_per_second: 0.0,
+                referenced_blocks_per_second: 0.0,
+                referenced_rate: 0.0,
+            },
+            features: Vec::new(),
+        }
+    }
+
+    fn submit_block(&mut self, body: &[u8]) -> (u16, Vec<u8>) {
+        // Blocks are submitted as JSON or as bytes
+        let block = match serde_json::from_slice::<BlockDto>(body) {
+            Ok(block_dto) => Block::try_from_dto(&block_dto, &self.protocol_parameters).map_err(|e| e.to_string()),
+            Err(_) => Block::unpack_strict(body, &self.protocol_parameters).map_err(|e| e.to_string()),
+        };
+        let block = match block {
+            Ok(block) => block,
+            Err(e) => return error_response(400, &format!("invalid block: {e}")),
+        };
+
+        let block_id = block.id();
+        let state = match self.confirmation {
+            Confirmation::Immediate | Confirmation::Conflicting(_) => {
+                BlockState::Pending(Some(self.milestone_index + 1))
+            }
+            Confirmation::AfterMilestones(milestones) => BlockState::Pending(Some(self.milestone_index + milestones)),
+            Confirmation::Never => BlockState::Pending(None),
+        };
+        self.blocks.insert(block_id, MockBlock { block, state });
+        self.block_ids.push(block_id);
+        if self.confirmation == Confirmation::Immediate {
+            self.issue_milestone();
+        }
+
+        let response = SubmitBlockResponse {
+            block_id: block_id.to_string(),
+        };
+        (201, serde_json::to_vec(&response).unwrap_or_default())
+    }
+
+    fn issue_milestone(&mut self) -> u32 {
+        self.milestone_index += 1;
+        self.milestone_timestamp = unix_timestamp_now().as_secs() as u32;
+        let milestone_index = self.milestone_index;
+
+        // Confirmed in the order of submission, so a later double spend conflicts
+        for block_id in self.block_ids.clone() {
+            if let BlockState::Pending(Some(confirmation_index)) = self.blocks[&block_id].state {
+                if confirmation_index <= milestone_index {
+                    let state = self.confirm_block(&block_id);
+                    if let Some(block) = self.blocks.get_mut(&block_id) {
+                        block.state = state;
+                    }
+                }
+            }
+        }
+
+        milestone_index
+    }
+
+    // Applies the transaction of a block to the ledger and returns the new state of the block
+    fn confirm_block(&mut self, block_id: &BlockId) -> BlockState {
+        let milestone_index = self.milestone_index;
+        let Some(Payload::Transaction(transaction)) = self.blocks[block_id].block.payload().cloned() else {
+            return BlockState::NoTransaction(milestone_index);
+        };
+        if let Confirmation::Conflicting(conflict_reason) = self.confirmation {
+            return BlockState::Conflicting(milestone_index, conflict_reason);
+        }
+
+        let TransactionEssence::Regular(essence) = transaction.essence();
+        let transaction_id = transaction.id();
+        let input_ids = essence
+            .inputs()
+            .iter()
+            .filter_map(|input| match input {
+                Input::Utxo(input) => Some(*input.output_id()),
+                Input::Treasury(_) => None,
+            })
+            .collect::<Vec<_>>();
+        for input_id in &input_ids {
+            match self.outputs.get(input_id) {
+                None => return BlockState::Conflicting(milestone_index, ConflictReason::InputUtxoNotFound),
+                Some(output) if output.spent.is_some() => {
+                    return BlockState::Conflicting(milestone_index, ConflictReason::InputUtxoAlreadySpent);
+                }
+                Some(_) => {}
+            }
+        }
+
+        for input_id in &input_ids {
+            if let Some(output) = self.outputs.get_mut(input_id) {
+                output.spent = Some((milestone_index, self.milestone_timestamp, transaction_id));
+            }
+        }
+        for (index, output) in essence.outputs().iter().enumerate() {
+            // The number of outputs is limited by the transaction essence
+            if let Ok(output_id) = OutputId::new(transaction_id, index as u16) {
+                let mock_output = MockOutput {
+                    output: output.clone(),
+                    block_id: *block_id,
+                    milestone_index_booked: milestone_index,
+                    milestone_timestamp_booked: self.milestone_timestamp,
+                    spent: None,
+                };
+                self.outputs.insert(output_id, mock_output);
+            }
+        }
+        self.included_blocks.insert(transaction_id, *block_id);
+
+        BlockState::Included(milestone_index)
+    }
+
+    fn block(&self, block_id: &str) -> Option<&MockBlock> {
+        self.blocks.get(&block_id.parse::<BlockId>().ok()?)
+    }
+
+    fn included_block(&self, transaction_id: &str) -> Option<&MockBlock> {
+        let block_id = self
+            .included_blocks
+            .get(&transaction_id.parse::<TransactionId>().ok()?)?;
+        self.blocks.get(block_id)
+    }
+
+    fn output_metadata(&self, output_id: &OutputId, output: &MockOutput) -> OutputMetadata {
+        OutputMetadata::new(
+            output.block_id,
+            *output_id,
+            output.spent.is_some(),
+            output.spent.map(|(index, _, _)| index),
+            output.spent.map(|(_, timestamp, _)| timestamp),
+            output.spent.map(|(_, _, transaction_id)| transaction_id),
+            output.milestone_index_booked,
+            output.milestone_timestamp_booked,
+            self.milestone_index,
+        )
+    }
+
+    fn output_response(&self, output_id: &str) -> Option<OutputWithMetadataResponse> {
+        let output_id = output_id.parse::<OutputId>().ok()?;
+        let output = self.outputs.get(&output_id)?;
+
+        Some(OutputWithMetadataResponse {
+            metadata: OutputMetadataDto::from(&self.output_metadata(&output_id, output)),
+            output: OutputDto::from(&output.output),
+        })
+    }
+
+    fn utxo_changes(&self, milestone_index: &str) -> Option<UtxoChangesResponse> {
+        let milestone_index = milestone_index.parse::<u32>().ok()?;
+        if milestone_index == 0 || milestone_index > self.milestone_index {
+            return None;
+        }
+
+        let mut created_outputs = Vec::new();
+        let mut consumed_outputs = Vec::new();
+        for (output_id, output) in &self.outputs {
+            if output.milestone_index_booked == milestone_index {
+                created_outputs.push(*output_id);
+            }
+            if matches!(output.spent, Some((spent_index, ..)) if spent_index == milestone_index) {
+                consumed_outputs.push(*output_id);
+            }
+        }
+        // Sorted, so the response doesn't depend on the order of the map
+        created_outputs.sort();
+        consumed_outputs.sort();
+
+        Some(UtxoChangesResponse {
+            index: milestone_index,
+            created_outputs: created_outputs.iter().map(ToString::to_string).collect(),
+            consumed_outputs: consumed_outputs.iter().map(ToString::to_string).collect(),
+        })
+    }
+
+    // Returns the unspent outputs of a kind of the indexer API, like `basic`
+    fn unspent_outputs<'a>(&'a self, kind: &'a str) -> impl Iterator<Item = (&'a OutputId, &'a MockOutput)> + 'a {
+        self.outputs.iter().filter(move |(_, output)| {
+            output.spent.is_none()
+                && matches!(
+                    (kind, &output.output),
+                    ("basic", Output::Basic(_))
+                        | ("alias", Output::Alias(_))
+                        | ("foundry", Output::Foundry(_))
+                        | ("nft", Output::Nft(_))
+                )
+        })
+    }
+
+    fn output_ids(&self, kind: &str, query: &[(String, String)]) -> OutputIdsResponse {
+        let mut output_ids = self
+            .unspent_outputs(kind)
+            .filter(|(_, output)| {
+                query
+                    .iter()
+                    .all(|(name, value)| matches_query(&output.output, name, value))
+            })
+            .map(|(output_id, output)| (output.milestone_index_booked, *output_id))
+            .collect::<Vec<_>>();
+        output_ids.sort();
+
+        OutputIdsResponse {
+            ledger_index: self.milestone_index,
+            cursor: None,
+            items: output_ids.into_iter().map(|(_, output_id)| output_id).collect(),
+        }
+    }
+
+    // Returns the output of an alias, foundry or NFT ID
+    fn output_ids_by_chain_id(&self, kind: &str, id: &str) -> OutputIdsResponse {
+        let id = id.to_lowercase();
+        let items = self
+            .unspent_outputs(kind)
+            .filter(|(output_id, output)| {
+                let chain_id = match &output.output {
+                    Output::Alias(alias) => alias.alias_id_non_null(output_id).to_string(),
+                    Output::Foundry(foundry) => foundry.id().to_string(),
+                    Output::Nft(nft) => nft.nft_id_non_null(output_id).to_string(),
+                    _ => return false,
+                };
+                chain_id == id
+            })
+            .map(|(output_id, _)| *output_id)
+            .collect();
+
+        OutputIdsResponse {
+            ledger_index: self.milestone_index,
+            cursor: None,
+            items,
+        }
+    }
+}
+
+// Checks an output against a query parameter of the indexer API, other parameters like `sender` match all outputs
+fn matches_query(output: &Output, name: &str, value: &str) -> bool {
+    let unlock_conditions = output.unlock_conditions();
+    let address = || Bech32Address::try_from_str(value).ok().map(|address| *address.inner());
+    let is_address = |unlock_condition_address: Option<&Address>| {
+        unlock_condition_address.is_some() && unlock_condition_address.copied() == address()
+    };
+    let has = |present: bool| value.parse::<bool>().map_or(true, |expected| present == expected);
+    let expiration = unlock_conditions.and_then(|u| u.expiration()).map(|u| u.timestamp());
+    let native_tokens = output.native_tokens().map_or(0, |native_tokens| native_tokens.len());
+    let timestamp = value.parse::<u32>().ok();
+
+    match name {
+        "address" => is_address(unlock_conditions.and_then(|u| u.address()).map(|u| u.address())),
+        "stateController" => is_address(
+            unlock_conditions
+                .and_then(|u| u.state_controller_address())
+                .map(|u| u.address()),
+        ),
+        "governor" => is_address(
+            unlock_conditions
+                .and_then(|u| u.governor_address())
+                .map(|u| u.address()),
+        ),
+        "aliasAddress" => is_address(
+            unlock_conditions
+                .and_then(|u| u.immutable_alias_address())
+                .map(|u| u.address()),
+        ),
+        "expirationReturnAddress" => is_address(
+            unlock_conditions
+                .and_then(|u| u.expiration())
+                .map(|u| u.return_address()),
+        ),
+        "storageDepositReturnAddress" => is_address(
+            unlock_conditions
+                .and_then(|u| u.storage_deposit_return())
+                .map(|u| u.return_address()),
+        ),
+        "hasExpiration" => has(unlock_conditions.and_then(|u| u.expiration()).is_some()),
+        "hasTimelock" => has(unlock_conditions.and_then(|u| u.timelock()).is_some()),
+        "hasStorageDepositReturn" => has(unlock_conditions.and_then(|u| u.storage_deposit_return()).is_some()),
+        "hasNativeTokens" => has(native_tokens > 0),
+        "expiresBefore" => expiration
+            .zip(timestamp)
+            .map_or(false, |(expiration, timestamp)| expiration < timestamp),
+        "expiresAfter" => expiration
+            .zip(timestamp)
+            .map_or(false, |(expiration, timestamp)| expiration > timestamp),
+        "tag" => output
+            .features()
+            .and_then(|features| features.tag())
+            .map_or(false, |tag| prefix_hex::encode(tag.tag()) == value.to_lowercase()),
+        _ => true,
+    }
+}
+
+fn block_metadata(block: &MockBlock) -> BlockMetadataResponse {
+    let (milestone_index, ledger_inclusion_state, conflict_reason) = match block.state {
+        BlockState::Pending(_) => (None, None, None),
+        BlockState::Included(index) => (Some(index), Some(LedgerInclusionStateDto::Included), None),
+        BlockState::NoTransaction(index) => (Some(index), Some(LedgerInclusionStateDto::NoTransaction), None),
+        BlockState::Conflicting(index, conflict_reason) => (
+            Some(index),
+            Some(LedgerInclusionStateDto::Conflicting),
+            Some(conflict_reason as u8),
+        ),
+    };
+
+    BlockMetadataResponse {
+        block_id: block.block.id().to_string(),
+        parents: block.block.parents().iter().map(BlockId::to_string).collect(),
+        is_solid: true,
+        referenced_by_milestone_index: milestone_index,
+        milestone_index: None,
+        ledger_inclusion_state,
+        conflict_reason,
+        white_flag_index: None,
+        should_promote: milestone_index.is_none().then_some(false),
+        should_reattach: milestone_index.is_none().then_some(false),
+    }
+}
+
+fn milestone_id(milestone_index: u32) -> String {
+    let mut id = [0u8; 32];
+    id[..4].copy_from_slice(&milestone_index.to_le_bytes());
+    prefix_hex::encode(id)
+}
+
+fn json_response(value: &impl Serialize) -> (u16, Vec<u8>) {
+    match serde_json::to_vec(value) {
+        Ok(body) => (200, body),
+        Err(e) => error_response(500, &e.to_string()),
+    }
+}
+
+// Answers with the value or with 404 if it wasn't found
+fn found_response(value: Option<impl Serialize>, item: &str) -> (u16, Vec<u8>) {
+    value.map_or_else(|| not_found(item), |value| json_response(&value))
+}
+
+fn not_found(item: &str) -> (u16, Vec<u8>) {
+    error_response(404, &format!("{item} not found"))
+}
+
+// Errors are returned in the format of the node
+fn error_response(status: u16, message: &str) -> (u16, Vec<u8>) {
+    let body = serde_json::json!({
+        "error": {
+            "code": status.to_string(),
+            "message": message,
+        }
+    });
+
+    (status, body.to_string().into_bytes())
+}
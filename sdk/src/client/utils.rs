@@ -3,10 +3,9 @@
 
 //! Utility functions for IOTA
 
-use std::collections::HashMap;
+use std::{collections::HashMap, time::Duration};
 
 use crypto::{
-    hashes::{blake2b::Blake2b256, Digest},
     keys::{bip39::wordlist, slip10::Seed},
     utils,
 };
@@ -14,10 +13,10 @@ use zeroize::Zeroize;
 
 use super::Client;
 use crate::{
-    client::{Error, Result},
+    client::{constants::FAUCET_POLL_INTERVAL, node_api::indexer::query_parameters::QueryParameter, Error, Result},
     types::block::{
         address::{Address, Ed25519Address},
-        output::{AliasId, NftId},
+        output::{AliasId, NftId, OutputId},
         payload::TaggedDataPayload,
     },
 };
@@ -36,18 +35,34 @@ pub fn bech32_to_hex(bech32: &str) -> Result<String> {
 /// Transforms a hex encoded address to a bech32 encoded address
 pub fn hex_to_bech32(hex: &str, bech32_hrp: &str) -> Result<String> {
     let address: Ed25519Address = hex.parse::<Ed25519Address>()?;
-    Ok(Address::Ed25519(address).to_bech32(bech32_hrp))
+    Ok(Address::Ed25519(address).try_to_bech32(bech32_hrp)?)
 }
 
 /// Transforms a prefix hex encoded public key to a bech32 encoded address
 pub fn hex_public_key_to_bech32_address(hex: &str, bech32_hrp: &str) -> Result<String> {
     let public_key: [u8; Ed25519Address::LENGTH] = prefix_hex::decode(hex)?;
 
-    let address = Blake2b256::digest(public_key)
-        .try_into()
-        .map_err(|_e| Error::Blake2b256("hashing the public key failed."))?;
-    let address: Ed25519Address = Ed25519Address::new(address);
-    Ok(Address::Ed25519(address).to_bech32(bech32_hrp))
+    Ok(Address::Ed25519(Ed25519Address::from_public_key_bytes(public_key)).try_to_bech32(bech32_hrp)?)
+}
+
+/// Transforms an alias id to a bech32 encoded address
+pub fn alias_id_to_bech32(alias_id: AliasId, bech32_hrp: &str) -> Result<String> {
+    Ok(Address::from(alias_id).try_to_bech32(bech32_hrp)?)
+}
+
+/// Transforms an nft id to a bech32 encoded address
+pub fn nft_id_to_bech32(nft_id: NftId, bech32_hrp: &str) -> Result<String> {
+    Ok(Address::from(nft_id).try_to_bech32(bech32_hrp)?)
+}
+
+/// Transforms a bech32 encoded alias address to its alias id, fails if the address has another HRP or kind
+pub fn bech32_to_alias_id(bech32: &str, bech32_hrp: &str) -> Result<AliasId> {
+    Ok(Address::try_from_bech32_with_expected_hrp(bech32, bech32_hrp)?.try_into()?)
+}
+
+/// Transforms a bech32 encoded nft address to its nft id, fails if the address has another HRP or kind
+pub fn bech32_to_nft_id(bech32: &str, bech32_hrp: &str) -> Result<NftId> {
+    Ok(Address::try_from_bech32_with_expected_hrp(bech32, bech32_hrp)?.try_into()?)
 }
 
 /// Generates a new mnemonic.
@@ -73,13 +88,20 @@ pub fn mnemonic_to_hex_seed(mnemonic: &str) -> Result<String> {
 
 /// Returns a seed for a mnemonic.
 pub fn mnemonic_to_seed(mnemonic: &str) -> Result<Seed> {
+    mnemonic_to_seed_with_passphrase(mnemonic, "")
+}
+
+/// Returns a seed for a mnemonic and a BIP-39 passphrase, also known as 25th word.
+pub fn mnemonic_to_seed_with_passphrase(mnemonic: &str, passphrase: &str) -> Result<Seed> {
     // trim because empty spaces could create a different seed https://github.com/iotaledger/crypto.rs/issues/125
     let mnemonic = mnemonic.trim();
     // first we check if the mnemonic is valid to give meaningful errors
     verify_mnemonic(mnemonic)?;
     let mut mnemonic_seed = [0u8; 64];
-    crypto::keys::bip39::mnemonic_to_seed(mnemonic, "", &mut mnemonic_seed);
-    Ok(Seed::from_bytes(&mnemonic_seed))
+    crypto::keys::bip39::mnemonic_to_seed(mnemonic, passphrase, &mut mnemonic_seed);
+    let seed = Seed::from_bytes(&mnemonic_seed);
+    mnemonic_seed.zeroize();
+    Ok(seed)
 }
 
 /// Verifies that a &str is a valid mnemonic.
@@ -120,16 +142,16 @@ impl Client {
         bech32_hrp: Option<&str>,
     ) -> crate::client::Result<String> {
         match bech32_hrp {
-            Some(hrp) => Ok(alias_id.to_bech32(hrp)),
-            None => Ok(alias_id.to_bech32(&self.get_bech32_hrp().await?)),
+            Some(hrp) => alias_id_to_bech32(alias_id, hrp),
+            None => alias_id_to_bech32(alias_id, &self.get_bech32_hrp().await?),
         }
     }
 
     /// Transforms an nft id to a bech32 encoded address
     pub async fn nft_id_to_bech32(&self, nft_id: NftId, bech32_hrp: Option<&str>) -> crate::client::Result<String> {
         match bech32_hrp {
-            Some(hrp) => Ok(nft_id.to_bech32(hrp)),
-            None => Ok(nft_id.to_bech32(&self.get_bech32_hrp().await?)),
+            Some(hrp) => nft_id_to_bech32(nft_id, hrp),
+            None => nft_id_to_bech32(nft_id, &self.get_bech32_hrp().await?),
         }
     }
 
@@ -155,6 +177,11 @@ impl Client {
         mnemonic_to_seed(mnemonic)
     }
 
+    /// Returns a seed for a mnemonic and a BIP-39 passphrase.
+    pub fn mnemonic_to_seed_with_passphrase(mnemonic: &str, passphrase: &str) -> Result<Seed> {
+        mnemonic_to_seed_with_passphrase(mnemonic, passphrase)
+    }
+
     /// Returns a hex encoded seed for a mnemonic.
     pub fn mnemonic_to_hex_seed(mnemonic: &str) -> Result<String> {
         mnemonic_to_hex_seed(mnemonic)
@@ -174,4 +201,46 @@ impl Client {
     pub fn tagged_data_to_utf8(payload: &TaggedDataPayload) -> Result<(String, String)> {
         Ok((Self::tag_to_utf8(payload)?, Self::data_to_utf8(payload)?))
     }
+
+    /// Requests funds from a faucet and waits until they arrive, only meant for testnets and development. The basic
+    /// outputs of the address are polled with the indexer, returns the ids of the outputs that arrived after the
+    /// request. Fails with [`Error::FaucetTimeout`] if no output arrived within the timeout.
+    /// ```ignore
+    /// let output_ids = client
+    ///     .request_funds_from_faucet(faucet_url, &address, Duration::from_secs(60))
+    ///     .await?;
+    /// ```
+    pub async fn request_funds_from_faucet(
+        &self,
+        faucet_url: &str,
+        bech32_address: &str,
+        timeout: Duration,
+    ) -> Result<Vec<OutputId>> {
+        let query_parameters = || vec![QueryParameter::Address(bech32_address.to_string())];
+        let existing_output_ids = self.basic_output_ids(query_parameters()).await?.items;
+
+        let faucet_response = request_funds_from_faucet(faucet_url, bech32_address).await?;
+        log::debug!("[request_funds_from_faucet] {faucet_response}");
+
+        let start = instant::Instant::now();
+        while start.elapsed() < timeout {
+            #[cfg(target_family = "wasm")]
+            gloo_timers::future::TimeoutFuture::new(FAUCET_POLL_INTERVAL.as_millis() as u32).await;
+            #[cfg(not(target_family = "wasm"))]
+            tokio::time::sleep(FAUCET_POLL_INTERVAL).await;
+
+            let new_output_ids = self
+                .basic_output_ids(query_parameters())
+                .await?
+                .items
+                .into_iter()
+                .filter(|output_id| !existing_output_ids.contains(output_id))
+                .collect::<Vec<_>>();
+            if !new_output_ids.is_empty() {
+                return Ok(new_output_ids);
+            }
+        }
+
+        Err(Error::FaucetTimeout(bech32_address.to_string()))
+    }
 }
@@ -18,6 +18,9 @@ extern crate alloc;
 #[cfg(feature = "client")]
 #[cfg_attr(docsrs, doc(cfg(feature = "client")))]
 pub mod client;
+#[cfg(feature = "metrics")]
+#[cfg_attr(docsrs, doc(cfg(feature = "metrics")))]
+pub mod metrics;
 #[cfg(feature = "pow")]
 #[cfg_attr(docsrs, doc(cfg(feature = "pow")))]
 pub mod pow;
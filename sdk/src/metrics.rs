@@ -0,0 +1,300 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Metrics of the requests to the nodes, the account syncing, the proof of work and the client cache, so services
+//! using the SDK can be monitored.
+//!
+//! The metrics are recorded for the whole process, [`gather()`] returns a snapshot of them and
+//! [`Metrics::to_prometheus()`] renders it in the Prometheus text format, to be served on a `/metrics` endpoint or
+//! pushed to a gateway.
+
+use std::{
+    collections::BTreeMap,
+    fmt::{Display, Write},
+    sync::{Mutex, MutexGuard},
+    time::Duration,
+};
+
+use once_cell::sync::Lazy;
+
+static REGISTRY: Lazy<Mutex<Registry>> = Lazy::new(Default::default);
+
+#[derive(Default)]
+struct Registry {
+    // Keyed by the method and the endpoint, so the gathered requests are sorted
+    requests: BTreeMap<(String, String), RequestMetrics>,
+    syncs: Timings,
+    sync_errors: u64,
+    pow: Timings,
+    cache_hits: u64,
+    cache_misses: u64,
+}
+
+/// The number and duration of recorded operations.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct Timings {
+    /// The number of operations.
+    pub count: u64,
+    /// The summed up duration of all operations.
+    pub total: Duration,
+    /// The duration of the slowest operation.
+    pub max: Duration,
+}
+
+impl Timings {
+    fn record(&mut self, duration: Duration) {
+        self.count += 1;
+        self.total += duration;
+        self.max = self.max.max(duration);
+    }
+
+    /// Returns the average duration of the operations, zero if none were recorded.
+    pub fn average(&self) -> Duration {
+        if self.count == 0 {
+            Duration::ZERO
+        } else {
+            self.total.div_f64(self.count as f64)
+        }
+    }
+}
+
+/// The requests sent to an endpoint of the nodes.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RequestMetrics {
+    /// The HTTP method, like `GET`.
+    pub method: String,
+    /// The path of the endpoint, with IDs and indexes replaced by `{id}`, e.g. `/api/core/v2/outputs/{id}`.
+    pub endpoint: String,
+    /// The number and duration of the requests, including failed ones.
+    pub timings: Timings,
+    /// The number of requests that failed or were answered with an error status.
+    pub errors: u64,
+}
+
+/// A snapshot of the recorded metrics, see [`gather()`].
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Metrics {
+    /// The requests to the nodes, sorted by their method and endpoint.
+    pub requests: Vec<RequestMetrics>,
+    /// The number and duration of the account syncs.
+    pub syncs: Timings,
+    /// The number of account syncs that failed.
+    pub sync_errors: u64,
+    /// The number and duration of the local proof of work for blocks.
+    pub pow: Timings,
+    /// The number of lookups that were answered by the client cache.
+    pub cache_hits: u64,
+    /// The number of lookups that required a request to a node, because the client cache didn't have the data.
+    pub cache_misses: u64,
+}
+
+/// Returns a snapshot of the metrics recorded since the start of the process or the last [`reset()`].
+pub fn gather() -> Metrics {
+    let registry = registry();
+
+    Metrics {
+        requests: registry.requests.values().cloned().collect(),
+        syncs: registry.syncs,
+        sync_errors: registry.sync_errors,
+        pow: registry.pow,
+        cache_hits: registry.cache_hits,
+        cache_misses: registry.cache_misses,
+    }
+}
+
+/// Resets all recorded metrics.
+pub fn reset() {
+    *registry() = Registry::default();
+}
+
+impl Metrics {
+    /// Renders the metrics in the Prometheus text format. Durations are in seconds and the timings are exposed as
+    /// summaries with a sum and a count.
+    pub fn to_prometheus(&self) -> String {
+        let mut text = String::new();
+
+        write_header(&mut text, "node_requests_total", "Requests sent to the nodes.");
+        for request in &self.requests {
+            let labels = request_labels(request);
+            write_sample(&mut text, "node_requests_total", &labels, request.timings.count);
+        }
+        write_header(
+            &mut text,
+            "node_request_errors_total",
+            "Requests to the nodes that failed or were answered with an error status.",
+        );
+        for request in &self.requests {
+            let labels = request_labels(request);
+            write_sample(&mut text, "node_request_errors_total", &labels, request.errors);
+        }
+        write_header(
+            &mut text,
+            "node_request_duration_seconds",
+            "Duration of the requests to the nodes.",
+        );
+        for request in &self.requests {
+            let labels = request_labels(request);
+            write_timings(&mut text, "node_request_duration_seconds", &labels, &request.timings);
+        }
+
+        write_header(&mut text, "sync_duration_seconds", "Duration of the account syncs.");
+        write_timings(&mut text, "sync_duration_seconds", "", &self.syncs);
+        write_header(&mut text, "sync_errors_total", "Account syncs that failed.");
+        write_sample(&mut text, "sync_errors_total", "", self.sync_errors);
+
+        write_header(&mut text, "pow_duration_seconds", "Duration of local proof of work.");
+        write_timings(&mut text, "pow_duration_seconds", "", &self.pow);
+
+        write_header(&mut text, "cache_lookups_total", "Lookups in the client cache.");
+        write_sample(&mut text, "cache_lookups_total", "result=\"hit\"", self.cache_hits);
+        write_sample(&mut text, "cache_lookups_total", "result=\"miss\"", self.cache_misses);
+
+        text
+    }
+}
+
+pub(crate) fn record_request(method: &str, path: &str, duration: Duration, success: bool) {
+    let endpoint = endpoint_label(path);
+    let mut registry = registry();
+    let request = registry
+        .requests
+        .entry((method.to_string(), endpoint.clone()))
+        .or_insert_with(|| RequestMetrics {
+            method: method.to_string(),
+            endpoint,
+            timings: Timings::default(),
+            errors: 0,
+        });
+
+    request.timings.record(duration);
+    if !success {
+        request.errors += 1;
+    }
+}
+
+#[cfg_attr(not(feature = "wallet"), allow(dead_code))]
+pub(crate) fn record_sync(duration: Duration, success: bool) {
+    let mut registry = registry();
+    registry.syncs.record(duration);
+    if !success {
+        registry.sync_errors += 1;
+    }
+}
+
+pub(crate) fn record_pow(duration: Duration) {
+    registry().pow.record(duration);
+}
+
+pub(crate) fn record_cache_lookup(hit: bool) {
+    let mut registry = registry();
+    if hit {
+        registry.cache_hits += 1;
+    } else {
+        registry.cache_misses += 1;
+    }
+}
+
+fn registry() -> MutexGuard<'static, Registry> {
+    match REGISTRY.lock() {
+        Ok(registry) => registry,
+        // A poisoned lock only means a recording panicked, the metrics are still usable
+        Err(poisoned) => poisoned.into_inner(),
+    }
+}
+
+// Replaces IDs and indexes in the path, so the metrics are grouped by endpoint and not by requested item
+fn endpoint_label(path: &str) -> String {
+    path.split('/')
+        .map(|segment| {
+            if segment.starts_with("0x") || (!segment.is_empty() && segment.bytes().all(|b| b.is_ascii_digit())) {
+                "{id}"
+            } else {
+                segment
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+fn request_labels(request: &RequestMetrics) -> String {
+    format!(
+        "method=\"{}\",endpoint=\"{}\"",
+        escape_label(&request.method),
+        escape_label(&request.endpoint)
+    )
+}
+
+fn escape_label(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('"', "\\\"").replace('\n', "\\n")
+}
+
+// Timings are exposed as summaries, all other metrics are counters
+fn write_header(text: &mut String, name: &str, help: &str) {
+    let kind = if name.ends_with("_seconds") { "summary" } else { "counter" };
+    writeln!(text, "# HELP iota_sdk_{name} {help}").ok();
+    writeln!(text, "# TYPE iota_sdk_{name} {kind}").ok();
+}
+
+fn write_sample(text: &mut String, name: &str, labels: &str, value: impl Display) {
+    if labels.is_empty() {
+        writeln!(text, "iota_sdk_{name} {value}").ok();
+    } else {
+        writeln!(text, "iota_sdk_{name}{{{labels}}} {value}").ok();
+    }
+}
+
+fn write_timings(text: &mut String, name: &str, labels: &str, timings: &Timings) {
+    write_sample(text, &format!("{name}_sum"), labels, timings.total.as_secs_f64());
+    write_sample(text, &format!("{name}_count"), labels, timings.count);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn endpoint_labels() {
+        assert_eq!(
+            endpoint_label("/api/core/v2/outputs/0x1e857d380f813d80"),
+            "/api/core/v2/outputs/{id}"
+        );
+        assert_eq!(
+            endpoint_label("/api/core/v2/milestones/by-index/42/utxo-changes"),
+            "/api/core/v2/milestones/by-index/{id}/utxo-changes"
+        );
+        assert_eq!(endpoint_label("/api/core/v2/info"), "/api/core/v2/info");
+    }
+
+    #[test]
+    fn prometheus_text() {
+        let mut timings = Timings::default();
+        timings.record(Duration::from_millis(100));
+        timings.record(Duration::from_millis(300));
+        assert_eq!(timings.average(), Duration::from_millis(200));
+        assert_eq!(timings.max, Duration::from_millis(300));
+
+        let metrics = Metrics {
+            requests: vec![RequestMetrics {
+                method: "GET".to_string(),
+                endpoint: "/api/core/v2/info".to_string(),
+                timings,
+                errors: 1,
+            }],
+            cache_hits: 3,
+            ..Default::default()
+        };
+        let text = metrics.to_prometheus();
+
+        for line in [
+            "# TYPE iota_sdk_node_requests_total counter",
+            "iota_sdk_node_requests_total{method=\"GET\",endpoint=\"/api/core/v2/info\"} 2",
+            "iota_sdk_node_request_errors_total{method=\"GET\",endpoint=\"/api/core/v2/info\"} 1",
+            "# TYPE iota_sdk_node_request_duration_seconds summary",
+            "iota_sdk_node_request_duration_seconds_sum{method=\"GET\",endpoint=\"/api/core/v2/info\"} 0.4",
+            "iota_sdk_sync_duration_seconds_count 0",
+            "iota_sdk_cache_lookups_total{result=\"hit\"} 3",
+        ] {
+            assert!(text.lines().any(|l| l == line), "missing line: {line}");
+        }
+    }
+}
@@ -1,18 +1,25 @@
 // Copyright 2020-2023 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use alloc::{boxed::Box, string::String, vec::Vec};
+use alloc::{
+    boxed::Box,
+    string::{String, ToString},
+    vec::Vec,
+};
 
 use crate::types::{
     api::core::dto::{LedgerInclusionStateDto, PeerDto, ReceiptDto},
     block::{
         output::{
             dto::{OutputDto, OutputMetadataDto},
-            OutputWithMetadata,
+            OutputId, OutputWithMetadata, TreasuryOutput,
+        },
+        payload::{
+            dto::MilestonePayloadDto,
+            milestone::{MilestoneId, MilestoneIndex, ReceiptMilestoneOption},
         },
-        payload::dto::MilestonePayloadDto,
         protocol::dto::ProtocolParametersDto,
-        BlockDto,
+        BlockDto, Error,
     },
 };
 
@@ -197,6 +204,21 @@ pub struct BlockMetadataResponse {
     pub should_reattach: Option<bool>,
 }
 
+/// Response of GET /api/core/v2/blocks/{block_id}/children.
+/// Returns the ids of the blocks that directly reference the block, up to `max_results` of them.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "camelCase")
+)]
+pub struct BlockChildrenResponse {
+    pub block_id: String,
+    pub max_results: u32,
+    pub count: u32,
+    pub children: Vec<String>,
+}
+
 /// Response of GET /api/core/v2/outputs/{output_id}.
 /// Returns an output and its metadata.
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -252,6 +274,32 @@ pub struct ReceiptsResponse {
     pub receipts: Vec<ReceiptDto>,
 }
 
+/// A receipt with the migrated funds of the legacy network and the index of the milestone that contained it.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Receipt {
+    pub receipt: ReceiptMilestoneOption,
+    pub milestone_index: MilestoneIndex,
+}
+
+impl Receipt {
+    /// Creates a [`Receipt`] from a [`ReceiptDto`], the funds are verified against the token supply.
+    pub fn try_from_dto(value: &ReceiptDto, token_supply: u64) -> Result<Self, Error> {
+        Ok(Self {
+            receipt: ReceiptMilestoneOption::try_from_dto(&value.receipt, token_supply)?,
+            milestone_index: value.milestone_index.into(),
+        })
+    }
+}
+
+impl From<&Receipt> for ReceiptDto {
+    fn from(value: &Receipt) -> Self {
+        Self {
+            receipt: (&value.receipt).into(),
+            milestone_index: *value.milestone_index,
+        }
+    }
+}
+
 /// Response of GET /api/core/v2/treasury.
 /// Returns all information about the treasury.
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -265,6 +313,39 @@ pub struct TreasuryResponse {
     pub amount: String,
 }
 
+/// The treasury output with the tokens of the legacy network that have not been migrated yet and the id of the
+/// milestone that created it.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Treasury {
+    pub milestone_id: MilestoneId,
+    pub output: TreasuryOutput,
+}
+
+impl Treasury {
+    /// Creates a [`Treasury`] from a [`TreasuryResponse`], the amount is verified against the token supply.
+    pub fn try_from_dto(value: &TreasuryResponse, token_supply: u64) -> Result<Self, Error> {
+        Ok(Self {
+            milestone_id: value
+                .milestone_id
+                .parse::<MilestoneId>()
+                .map_err(|_| Error::InvalidField("milestoneId"))?,
+            output: TreasuryOutput::new(
+                value.amount.parse::<u64>().map_err(|_| Error::InvalidField("amount"))?,
+                token_supply,
+            )?,
+        })
+    }
+}
+
+impl From<&Treasury> for TreasuryResponse {
+    fn from(value: &Treasury) -> Self {
+        Self {
+            milestone_id: value.milestone_id.to_string(),
+            amount: value.output.amount().to_string(),
+        }
+    }
+}
+
 /// Response of GET /api/core/v2/milestone/{milestone_index}.
 /// Returns information about a milestone.
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -293,6 +374,34 @@ pub struct UtxoChangesResponse {
     pub consumed_outputs: Vec<String>,
 }
 
+/// The outputs that were created and consumed by the transactions of a milestone.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct UtxoChanges {
+    pub index: MilestoneIndex,
+    pub created_outputs: Vec<OutputId>,
+    pub consumed_outputs: Vec<OutputId>,
+}
+
+impl TryFrom<&UtxoChangesResponse> for UtxoChanges {
+    type Error = Error;
+
+    fn try_from(value: &UtxoChangesResponse) -> Result<Self, Self::Error> {
+        Ok(Self {
+            index: value.index.into(),
+            created_outputs: value
+                .created_outputs
+                .iter()
+                .map(|output_id| output_id.parse())
+                .collect::<Result<_, _>>()?,
+            consumed_outputs: value
+                .consumed_outputs
+                .iter()
+                .map(|output_id| output_id.parse())
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
 /// Response of GET /api/core/v2/peers.
 /// Returns information about all peers of the node.
 #[derive(Clone, Debug, Eq, PartialEq)]
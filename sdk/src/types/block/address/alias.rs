@@ -5,7 +5,7 @@ use core::str::FromStr;
 
 use derive_more::{AsRef, Deref, From};
 
-use crate::types::block::{output::AliasId, Error};
+use crate::types::block::{address::Address, output::AliasId, Error};
 
 /// An alias address.
 #[derive(Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash, From, AsRef, Deref, packable::Packable)]
@@ -60,6 +60,26 @@ impl core::fmt::Debug for AliasAddress {
     }
 }
 
+impl From<AliasId> for Address {
+    fn from(value: AliasId) -> Self {
+        Self::Alias(AliasAddress::new(value))
+    }
+}
+
+impl TryFrom<Address> for AliasId {
+    type Error = Error;
+
+    fn try_from(address: Address) -> Result<Self, Self::Error> {
+        match address {
+            Address::Alias(address) => Ok(address.into_alias_id()),
+            _ => Err(Error::AddressKindMismatch {
+                expected: AliasAddress::KIND,
+                actual: address.kind(),
+            }),
+        }
+    }
+}
+
 #[allow(missing_docs)]
 pub mod dto {
     use alloc::string::{String, ToString};
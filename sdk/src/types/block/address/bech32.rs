@@ -9,7 +9,7 @@ use derive_more::{AsRef, Deref};
 use crate::types::block::{address::Address, Error};
 
 /// An address and its network type.
-#[derive(Clone, Eq, PartialEq, Hash, AsRef, Deref)]
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, AsRef, Deref)]
 pub struct Bech32Address {
     pub(crate) hrp: String,
     #[as_ref]
@@ -27,9 +27,9 @@ impl FromStr for Bech32Address {
 }
 
 impl Bech32Address {
-    /// Creates a new address wrapper.
+    /// Creates a new address wrapper, fails if the HRP can't be used in a bech32 string.
     pub fn new(hrp: String, inner: Address) -> Result<Self, Error> {
-        // TODO validate HRP
+        inner.try_to_bech32(&hrp)?;
         Ok(Self { hrp, inner })
     }
 
@@ -3,10 +3,13 @@
 
 use core::str::FromStr;
 
-use crypto::signatures::ed25519::PUBLIC_KEY_LENGTH;
+use crypto::{
+    hashes::{blake2b::Blake2b256, Digest},
+    signatures::ed25519::PublicKey,
+};
 use derive_more::{AsRef, Deref, From};
 
-use crate::types::block::Error;
+use crate::types::block::{address::Address, Error};
 
 /// An Ed25519 address.
 #[derive(Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash, From, AsRef, Deref, packable::Packable)]
@@ -17,13 +20,32 @@ impl Ed25519Address {
     /// The [`Address`](crate::types::block::address::Address) kind of an [`Ed25519Address`].
     pub const KIND: u8 = 0;
     /// The length of an [`Ed25519Address`].
-    pub const LENGTH: usize = PUBLIC_KEY_LENGTH;
+    pub const LENGTH: usize = PublicKey::LENGTH;
 
     /// Creates a new [`Ed25519Address`].
     #[inline(always)]
     pub fn new(address: [u8; Self::LENGTH]) -> Self {
         Self::from(address)
     }
+
+    /// Creates the [`Ed25519Address`] of an Ed25519 public key, which is the BLAKE2b-256 hash of the key.
+    pub fn from_public_key_bytes(public_key: [u8; PublicKey::LENGTH]) -> Self {
+        Self::new(Blake2b256::digest(public_key).into())
+    }
+}
+
+impl TryFrom<Address> for Ed25519Address {
+    type Error = Error;
+
+    fn try_from(address: Address) -> Result<Self, Self::Error> {
+        match address {
+            Address::Ed25519(address) => Ok(address),
+            _ => Err(Error::AddressKindMismatch {
+                expected: Self::KIND,
+                actual: address.kind(),
+            }),
+        }
+    }
 }
 
 #[cfg(feature = "serde")]
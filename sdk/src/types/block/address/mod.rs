@@ -125,12 +125,34 @@ impl Address {
         }
     }
 
-    /// Encodes this address to a bech32 string with the given Human Readable Part as prefix.
+    /// Tries to create an [`Address`] from a bech32 encoded string, fails if its HRP isn't `expected_hrp`.
+    pub fn try_from_bech32_with_expected_hrp<T: AsRef<str>>(address: T, expected_hrp: &str) -> Result<Self, Error> {
+        let (hrp, address) = Self::try_from_bech32_with_hrp(address)?;
+
+        if hrp != expected_hrp {
+            return Err(Error::Bech32HrpMismatch {
+                expected: expected_hrp.into(),
+                actual: hrp,
+            });
+        }
+
+        Ok(address)
+    }
+
+    /// Encodes this address to a bech32 string with the given Human Readable Part as prefix. Panics if the HRP is
+    /// malformed, [`Address::try_to_bech32()`] is the checked version.
     pub fn to_bech32<T: AsRef<str>>(&self, hrp: T) -> String {
-        // PANIC: encoding can't fail as `self` has already been validated and built.
+        // PANIC: encoding can only fail with a malformed HRP as `self` has already been validated and built.
         ::bech32::encode(hrp.as_ref(), self.pack_to_vec().to_base32(), Variant::Bech32).unwrap()
     }
 
+    /// Encodes this address to a bech32 string like [`Address::to_bech32()`], but fails instead of panicking if the HRP
+    /// is empty, too long or has invalid characters.
+    pub fn try_to_bech32<T: AsRef<str>>(&self, hrp: T) -> Result<String, Error> {
+        ::bech32::encode(hrp.as_ref(), self.pack_to_vec().to_base32(), Variant::Bech32)
+            .map_err(|_| Error::MalformedBech32Hrp(hrp.as_ref().into()))
+    }
+
     /// Checks if an string is a valid bech32 encoded address.
     #[must_use]
     pub fn is_valid_bech32(address: &str) -> bool {
@@ -158,11 +180,23 @@ impl Address {
 
                 context.unlocked_addresses.insert(*self);
             }
-            (Self::Ed25519(_ed25519_address), Unlock::Reference(_unlock)) => {
-                // TODO actually check that it was unlocked by the same signature.
+            (Self::Ed25519(ed25519_address), Unlock::Reference(unlock)) => {
                 if !context.unlocked_addresses.contains(self) {
                     return Err(ConflictReason::InvalidUnlock);
                 }
+
+                // The referenced signature unlock needs to be the one of the same address, its signature was already
+                // verified when it unlocked its input.
+                match context.unlocks.get(unlock.index() as usize) {
+                    Some(Unlock::Signature(signature_unlock)) => {
+                        let Signature::Ed25519(signature) = signature_unlock.signature();
+
+                        if &signature.address() != ed25519_address {
+                            return Err(ConflictReason::InvalidUnlock);
+                        }
+                    }
+                    _ => return Err(ConflictReason::InvalidUnlock),
+                }
             }
             (Self::Alias(alias_address), Unlock::Alias(unlock)) => {
                 // PANIC: indexing is fine as it is already syntactically verified that indexes reference below.
@@ -5,7 +5,7 @@ use core::str::FromStr;
 
 use derive_more::{AsRef, Deref, From};
 
-use crate::types::block::{output::NftId, Error};
+use crate::types::block::{address::Address, output::NftId, Error};
 
 /// An NFT address.
 #[derive(Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash, From, AsRef, Deref, packable::Packable)]
@@ -60,6 +60,26 @@ impl core::fmt::Debug for NftAddress {
     }
 }
 
+impl From<NftId> for Address {
+    fn from(value: NftId) -> Self {
+        Self::Nft(NftAddress::new(value))
+    }
+}
+
+impl TryFrom<Address> for NftId {
+    type Error = Error;
+
+    fn try_from(address: Address) -> Result<Self, Self::Error> {
+        match address {
+            Address::Nft(address) => Ok(address.into_nft_id()),
+            _ => Err(Error::AddressKindMismatch {
+                expected: NftAddress::KIND,
+                actual: address.kind(),
+            }),
+        }
+    }
+}
+
 #[allow(missing_docs)]
 pub mod dto {
     use alloc::string::{String, ToString};
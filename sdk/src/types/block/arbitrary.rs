@@ -0,0 +1,539 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! [`Arbitrary`] implementations of the block types, so fuzzers and property tests can generate them.
+//!
+//! The values are built with the builders of the types and are syntactically valid for the default
+//! [`ProtocolParameters`], values the builders reject are returned as [`arbitrary::Error::IncorrectFormat`].
+//! Adversarial values can be obtained by mutating the packed bytes of the generated values.
+
+use alloc::vec::Vec;
+use core::ops::RangeInclusive;
+
+use arbitrary::{Arbitrary, Result, Unstructured};
+use primitive_types::U256;
+
+use crate::types::block::{
+    address::{Address, AliasAddress, Ed25519Address, NftAddress},
+    input::{Input, UtxoInput, INPUT_COUNT_MAX},
+    output::{
+        feature::{Feature, IssuerFeature, MetadataFeature, SenderFeature, TagFeature},
+        minimum_storage_deposit,
+        unlock_condition::{
+            AddressUnlockCondition, ExpirationUnlockCondition, GovernorAddressUnlockCondition,
+            ImmutableAliasAddressUnlockCondition, StateControllerAddressUnlockCondition,
+            StorageDepositReturnUnlockCondition, TimelockUnlockCondition, UnlockCondition,
+        },
+        AliasId, AliasOutput, AliasOutputBuilder, BasicOutput, BasicOutputBuilder, FoundryOutput,
+        FoundryOutputBuilder, InputsCommitment, NativeToken, NativeTokens, NftId, NftOutput, NftOutputBuilder, Output,
+        OutputId, SimpleTokenScheme, TokenId, TokenScheme, TreasuryOutput, OUTPUT_COUNT_MAX, OUTPUT_INDEX_RANGE,
+    },
+    payload::{
+        transaction::{RegularTransactionEssence, TransactionEssence, TransactionId, TransactionPayload},
+        TaggedDataPayload,
+    },
+    protocol::ProtocolParameters,
+    signature::{Ed25519Signature, Signature},
+    unlock::{ReferenceUnlock, SignatureUnlock, Unlock, Unlocks},
+    Error,
+};
+
+// Max length of the data of generated tagged data payloads.
+const TAGGED_DATA_LENGTH_MAX: usize = 1024;
+
+fn incorrect_format(_: Error) -> arbitrary::Error {
+    arbitrary::Error::IncorrectFormat
+}
+
+// Returns bytes with a length in the range, shortened to the remaining data if it's running out.
+fn arbitrary_bytes(u: &mut Unstructured<'_>, range: RangeInclusive<usize>) -> Result<Vec<u8>> {
+    let len = u.int_in_range(range.clone())?.min(u.len().max(*range.start()));
+
+    Ok(u.bytes(len)?.to_vec())
+}
+
+// Returns an amount on top of the required one. The additional amount is bounded so the outputs of an essence can't
+// exceed the token supply together.
+fn arbitrary_amount(u: &mut Unstructured<'_>, required: u64, token_supply: u64) -> Result<u64> {
+    Ok(required + u.int_in_range(0..=token_supply / (4 * OUTPUT_COUNT_MAX as u64))?)
+}
+
+fn arbitrary_timestamp(u: &mut Unstructured<'_>) -> Result<u32> {
+    u.int_in_range(1..=u32::MAX)
+}
+
+fn arbitrary_native_tokens(u: &mut Unstructured<'_>, count_max: u8) -> Result<NativeTokens> {
+    let count = u.int_in_range(0..=count_max)?;
+    let native_tokens = (0..count)
+        .map(|_| u.arbitrary())
+        .collect::<Result<Vec<NativeToken>>>()?;
+
+    NativeTokens::from_vec(native_tokens).map_err(incorrect_format)
+}
+
+// Returns the optional unlock conditions of basic and NFT outputs.
+fn arbitrary_optional_unlock_conditions(u: &mut Unstructured<'_>) -> Result<Vec<UnlockCondition>> {
+    let mut unlock_conditions = Vec::new();
+
+    if u.arbitrary()? {
+        unlock_conditions.push(UnlockCondition::from(
+            u.arbitrary::<StorageDepositReturnUnlockCondition>()?,
+        ));
+    }
+    if u.arbitrary()? {
+        unlock_conditions.push(UnlockCondition::from(u.arbitrary::<TimelockUnlockCondition>()?));
+    }
+    if u.arbitrary()? {
+        unlock_conditions.push(UnlockCondition::from(u.arbitrary::<ExpirationUnlockCondition>()?));
+    }
+
+    Ok(unlock_conditions)
+}
+
+// Returns the amount that an output needs to cover its storage deposit return.
+fn storage_deposit_return_amount(unlock_conditions: &[UnlockCondition]) -> u64 {
+    unlock_conditions
+        .iter()
+        .find_map(|unlock_condition| match unlock_condition {
+            UnlockCondition::StorageDepositReturn(unlock_condition) => Some(unlock_condition.amount()),
+            _ => None,
+        })
+        .unwrap_or_default()
+}
+
+// Returns a subset of the sender, issuer, metadata and tag features.
+fn arbitrary_features(u: &mut Unstructured<'_>, sender: bool, issuer: bool, tag: bool) -> Result<Vec<Feature>> {
+    let mut features = Vec::new();
+
+    if sender && u.arbitrary()? {
+        features.push(Feature::from(u.arbitrary::<SenderFeature>()?));
+    }
+    if issuer && u.arbitrary()? {
+        features.push(Feature::from(u.arbitrary::<IssuerFeature>()?));
+    }
+    if u.arbitrary()? {
+        features.push(Feature::from(u.arbitrary::<MetadataFeature>()?));
+    }
+    if tag && u.arbitrary()? {
+        features.push(Feature::from(u.arbitrary::<TagFeature>()?));
+    }
+
+    Ok(features)
+}
+
+// Returns an output that can be created by a regular transaction essence.
+fn arbitrary_essence_output(u: &mut Unstructured<'_>, native_tokens_max: u8) -> Result<Output> {
+    Ok(match u.int_in_range(0..=3)? {
+        0 => arbitrary_basic_output(u, native_tokens_max)?.into(),
+        1 => arbitrary_alias_output(u, native_tokens_max)?.into(),
+        2 => arbitrary_foundry_output(u, native_tokens_max)?.into(),
+        _ => arbitrary_nft_output(u, native_tokens_max)?.into(),
+    })
+}
+
+fn arbitrary_basic_output(u: &mut Unstructured<'_>, native_tokens_max: u8) -> Result<BasicOutput> {
+    let protocol_parameters = ProtocolParameters::default();
+    let token_supply = protocol_parameters.token_supply();
+    let mut unlock_conditions = arbitrary_optional_unlock_conditions(u)?;
+    unlock_conditions.push(UnlockCondition::from(u.arbitrary::<AddressUnlockCondition>()?));
+    let return_amount = storage_deposit_return_amount(&unlock_conditions);
+
+    let output = BasicOutput::build_with_minimum_storage_deposit(*protocol_parameters.rent_structure())
+        .with_native_tokens(arbitrary_native_tokens(u, native_tokens_max)?)
+        .with_unlock_conditions(unlock_conditions)
+        .with_features(arbitrary_features(u, true, false, true)?)
+        .finish(token_supply)
+        .map_err(incorrect_format)?;
+    let amount = arbitrary_amount(u, output.amount().max(return_amount), token_supply)?;
+
+    BasicOutputBuilder::from(&output)
+        .with_amount(amount)
+        .finish(token_supply)
+        .map_err(incorrect_format)
+}
+
+fn arbitrary_alias_output(u: &mut Unstructured<'_>, native_tokens_max: u8) -> Result<AliasOutput> {
+    let protocol_parameters = ProtocolParameters::default();
+    let token_supply = protocol_parameters.token_supply();
+    let alias_id = u.arbitrary::<AliasId>()?;
+    // A new alias output, with a null alias ID, can't have a state index or foundry counter yet.
+    let (state_index, foundry_counter): (u32, u32) = if alias_id.is_null() {
+        (0, 0)
+    } else {
+        (u.arbitrary()?, u.arbitrary()?)
+    };
+
+    let output = AliasOutput::build_with_minimum_storage_deposit(*protocol_parameters.rent_structure(), alias_id)
+        .with_native_tokens(arbitrary_native_tokens(u, native_tokens_max)?)
+        .with_state_index(state_index)
+        .with_state_metadata(arbitrary_bytes(u, 0..=AliasOutput::STATE_METADATA_LENGTH_MAX as usize)?)
+        .with_foundry_counter(foundry_counter)
+        .add_unlock_condition(u.arbitrary::<StateControllerAddressUnlockCondition>()?)
+        .add_unlock_condition(u.arbitrary::<GovernorAddressUnlockCondition>()?)
+        .with_features(arbitrary_features(u, true, false, false)?)
+        .with_immutable_features(arbitrary_features(u, false, true, false)?)
+        .finish(token_supply)
+        .map_err(incorrect_format)?;
+    let amount = arbitrary_amount(u, output.amount(), token_supply)?;
+
+    AliasOutputBuilder::from(&output)
+        .with_amount(amount)
+        .finish(token_supply)
+        .map_err(incorrect_format)
+}
+
+fn arbitrary_foundry_output(u: &mut Unstructured<'_>, native_tokens_max: u8) -> Result<FoundryOutput> {
+    let protocol_parameters = ProtocolParameters::default();
+    let token_supply = protocol_parameters.token_supply();
+
+    let output = FoundryOutput::build_with_minimum_storage_deposit(
+        *protocol_parameters.rent_structure(),
+        u.arbitrary()?,
+        u.arbitrary()?,
+    )
+    .with_native_tokens(arbitrary_native_tokens(u, native_tokens_max)?)
+    .add_unlock_condition(u.arbitrary::<ImmutableAliasAddressUnlockCondition>()?)
+    .with_features(arbitrary_features(u, false, false, false)?)
+    .with_immutable_features(arbitrary_features(u, false, false, false)?)
+    .finish(token_supply)
+    .map_err(incorrect_format)?;
+    let amount = arbitrary_amount(u, output.amount(), token_supply)?;
+
+    FoundryOutputBuilder::from(&output)
+        .with_amount(amount)
+        .finish(token_supply)
+        .map_err(incorrect_format)
+}
+
+fn arbitrary_nft_output(u: &mut Unstructured<'_>, native_tokens_max: u8) -> Result<NftOutput> {
+    let protocol_parameters = ProtocolParameters::default();
+    let token_supply = protocol_parameters.token_supply();
+    let mut unlock_conditions = arbitrary_optional_unlock_conditions(u)?;
+    unlock_conditions.push(UnlockCondition::from(u.arbitrary::<AddressUnlockCondition>()?));
+    let return_amount = storage_deposit_return_amount(&unlock_conditions);
+
+    let output = NftOutput::build_with_minimum_storage_deposit(*protocol_parameters.rent_structure(), u.arbitrary()?)
+        .with_native_tokens(arbitrary_native_tokens(u, native_tokens_max)?)
+        .with_unlock_conditions(unlock_conditions)
+        .with_features(arbitrary_features(u, true, false, true)?)
+        .with_immutable_features(arbitrary_features(u, false, true, false)?)
+        .finish(token_supply)
+        .map_err(incorrect_format)?;
+    let amount = arbitrary_amount(u, output.amount().max(return_amount), token_supply)?;
+
+    NftOutputBuilder::from(&output)
+        .with_amount(amount)
+        .finish(token_supply)
+        .map_err(incorrect_format)
+}
+
+impl<'a> Arbitrary<'a> for Ed25519Address {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(Self::new(u.arbitrary()?))
+    }
+}
+
+impl<'a> Arbitrary<'a> for AliasAddress {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(Self::new(u.arbitrary()?))
+    }
+}
+
+impl<'a> Arbitrary<'a> for NftAddress {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(Self::new(u.arbitrary()?))
+    }
+}
+
+impl<'a> Arbitrary<'a> for Address {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(match u.int_in_range(0..=2)? {
+            0 => Self::Ed25519(u.arbitrary()?),
+            1 => Self::Alias(u.arbitrary()?),
+            _ => Self::Nft(u.arbitrary()?),
+        })
+    }
+}
+
+impl<'a> Arbitrary<'a> for AliasId {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(Self::new(u.arbitrary()?))
+    }
+}
+
+impl<'a> Arbitrary<'a> for NftId {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(Self::new(u.arbitrary()?))
+    }
+}
+
+impl<'a> Arbitrary<'a> for TokenId {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(Self::new(u.arbitrary()?))
+    }
+}
+
+impl<'a> Arbitrary<'a> for TransactionId {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(Self::new(u.arbitrary()?))
+    }
+}
+
+impl<'a> Arbitrary<'a> for OutputId {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Self::new(u.arbitrary()?, u.int_in_range(OUTPUT_INDEX_RANGE)?).map_err(incorrect_format)
+    }
+}
+
+impl<'a> Arbitrary<'a> for UtxoInput {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(Self::from(u.arbitrary::<OutputId>()?))
+    }
+}
+
+impl<'a> Arbitrary<'a> for InputsCommitment {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(Self::from(u.arbitrary::<[u8; Self::LENGTH]>()?))
+    }
+}
+
+impl<'a> Arbitrary<'a> for NativeToken {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let amount = U256::from(u.arbitrary::<[u8; 32]>()?).max(U256::one());
+
+        Self::new(u.arbitrary()?, amount).map_err(incorrect_format)
+    }
+}
+
+impl<'a> Arbitrary<'a> for NativeTokens {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        arbitrary_native_tokens(u, Self::COUNT_MAX)
+    }
+}
+
+impl<'a> Arbitrary<'a> for TokenScheme {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let maximum_supply = U256::from(u.arbitrary::<[u8; 32]>()?).saturating_add(U256::one());
+        let minted_tokens = U256::from(u.arbitrary::<[u8; 32]>()?) % maximum_supply.saturating_add(U256::one());
+        let melted_tokens = U256::from(u.arbitrary::<[u8; 32]>()?) % minted_tokens.saturating_add(U256::one());
+
+        Ok(Self::Simple(
+            SimpleTokenScheme::new(minted_tokens, melted_tokens, maximum_supply).map_err(incorrect_format)?,
+        ))
+    }
+}
+
+impl<'a> Arbitrary<'a> for AddressUnlockCondition {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(Self::new(u.arbitrary()?))
+    }
+}
+
+impl<'a> Arbitrary<'a> for StorageDepositReturnUnlockCondition {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let protocol_parameters = ProtocolParameters::default();
+        let token_supply = protocol_parameters.token_supply();
+        let return_address = u.arbitrary()?;
+        // The return amount has to cover the storage deposit of the output that returns it.
+        let minimum_amount =
+            minimum_storage_deposit(&return_address, *protocol_parameters.rent_structure(), token_supply);
+        let amount = arbitrary_amount(u, minimum_amount, token_supply)?;
+
+        Self::new(return_address, amount, token_supply).map_err(incorrect_format)
+    }
+}
+
+impl<'a> Arbitrary<'a> for TimelockUnlockCondition {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Self::new(arbitrary_timestamp(u)?).map_err(incorrect_format)
+    }
+}
+
+impl<'a> Arbitrary<'a> for ExpirationUnlockCondition {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Self::new(u.arbitrary()?, arbitrary_timestamp(u)?).map_err(incorrect_format)
+    }
+}
+
+impl<'a> Arbitrary<'a> for StateControllerAddressUnlockCondition {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(Self::new(u.arbitrary()?))
+    }
+}
+
+impl<'a> Arbitrary<'a> for GovernorAddressUnlockCondition {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(Self::new(u.arbitrary()?))
+    }
+}
+
+impl<'a> Arbitrary<'a> for ImmutableAliasAddressUnlockCondition {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(Self::new(u.arbitrary()?))
+    }
+}
+
+impl<'a> Arbitrary<'a> for UnlockCondition {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(match u.int_in_range(0..=6)? {
+            0 => Self::Address(u.arbitrary()?),
+            1 => Self::StorageDepositReturn(u.arbitrary()?),
+            2 => Self::Timelock(u.arbitrary()?),
+            3 => Self::Expiration(u.arbitrary()?),
+            4 => Self::StateControllerAddress(u.arbitrary()?),
+            5 => Self::GovernorAddress(u.arbitrary()?),
+            _ => Self::ImmutableAliasAddress(u.arbitrary()?),
+        })
+    }
+}
+
+impl<'a> Arbitrary<'a> for SenderFeature {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(Self::new(u.arbitrary()?))
+    }
+}
+
+impl<'a> Arbitrary<'a> for IssuerFeature {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(Self::new(u.arbitrary()?))
+    }
+}
+
+impl<'a> Arbitrary<'a> for MetadataFeature {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let range = Self::LENGTH_RANGE;
+        let data = arbitrary_bytes(u, *range.start() as usize..=*range.end() as usize)?;
+
+        Self::new(data).map_err(incorrect_format)
+    }
+}
+
+impl<'a> Arbitrary<'a> for TagFeature {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let range = Self::LENGTH_RANGE;
+        let tag = arbitrary_bytes(u, *range.start() as usize..=*range.end() as usize)?;
+
+        Self::new(tag).map_err(incorrect_format)
+    }
+}
+
+impl<'a> Arbitrary<'a> for Feature {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(match u.int_in_range(0..=3)? {
+            0 => Self::Sender(u.arbitrary()?),
+            1 => Self::Issuer(u.arbitrary()?),
+            2 => Self::Metadata(u.arbitrary()?),
+            _ => Self::Tag(u.arbitrary()?),
+        })
+    }
+}
+
+impl<'a> Arbitrary<'a> for TreasuryOutput {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let token_supply = ProtocolParameters::default().token_supply();
+
+        Self::new(u.int_in_range(0..=token_supply)?, token_supply).map_err(incorrect_format)
+    }
+}
+
+impl<'a> Arbitrary<'a> for BasicOutput {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        arbitrary_basic_output(u, NativeTokens::COUNT_MAX)
+    }
+}
+
+impl<'a> Arbitrary<'a> for AliasOutput {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        arbitrary_alias_output(u, NativeTokens::COUNT_MAX)
+    }
+}
+
+impl<'a> Arbitrary<'a> for FoundryOutput {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        arbitrary_foundry_output(u, NativeTokens::COUNT_MAX)
+    }
+}
+
+impl<'a> Arbitrary<'a> for NftOutput {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        arbitrary_nft_output(u, NativeTokens::COUNT_MAX)
+    }
+}
+
+impl<'a> Arbitrary<'a> for Output {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        if u.ratio(1, 5)? {
+            Ok(Self::Treasury(u.arbitrary()?))
+        } else {
+            arbitrary_essence_output(u, NativeTokens::COUNT_MAX)
+        }
+    }
+}
+
+impl<'a> Arbitrary<'a> for RegularTransactionEssence {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let protocol_parameters = ProtocolParameters::default();
+
+        let inputs_count = u.int_in_range(1..=INPUT_COUNT_MAX)?;
+        let mut inputs = Vec::with_capacity(inputs_count.into());
+        for _ in 0..inputs_count {
+            inputs.push(Input::Utxo(u.arbitrary()?));
+        }
+
+        // The native tokens of all outputs are bounded by the max of a single transaction.
+        let outputs_count = u.int_in_range(1..=OUTPUT_COUNT_MAX)?;
+        let mut native_tokens_max = NativeTokens::COUNT_MAX;
+        let mut outputs = Vec::with_capacity(outputs_count.into());
+        while outputs.len() < usize::from(outputs_count) && (outputs.is_empty() || !u.is_empty()) {
+            let output = arbitrary_essence_output(u, native_tokens_max)?;
+            if let Some(native_tokens) = output.native_tokens() {
+                native_tokens_max -= native_tokens.len() as u8;
+            }
+            outputs.push(output);
+        }
+
+        let mut builder = Self::builder(protocol_parameters.network_id(), u.arbitrary()?)
+            .with_inputs(inputs)
+            .with_outputs(outputs);
+        if u.arbitrary()? {
+            let tag = arbitrary_bytes(u, 0..=*TaggedDataPayload::TAG_LENGTH_RANGE.end() as usize)?;
+            let data = arbitrary_bytes(u, 0..=TAGGED_DATA_LENGTH_MAX)?;
+            builder = builder.with_payload(TaggedDataPayload::new(tag, data).map_err(incorrect_format)?);
+        }
+
+        builder.finish(&protocol_parameters).map_err(incorrect_format)
+    }
+}
+
+impl<'a> Arbitrary<'a> for TransactionEssence {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(Self::Regular(u.arbitrary()?))
+    }
+}
+
+impl<'a> Arbitrary<'a> for Ed25519Signature {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(Self::new(u.arbitrary()?, u.arbitrary()?))
+    }
+}
+
+impl<'a> Arbitrary<'a> for TransactionPayload {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let essence = u.arbitrary::<TransactionEssence>()?;
+        let TransactionEssence::Regular(regular) = &essence;
+
+        // Every input is unlocked by a new signature or references the first one.
+        let mut unlocks = Vec::with_capacity(regular.inputs().len());
+        for index in 0..regular.inputs().len() {
+            if index == 0 || u.arbitrary()? {
+                unlocks.push(Unlock::Signature(SignatureUnlock::new(Signature::Ed25519(
+                    u.arbitrary()?,
+                ))));
+            } else {
+                unlocks.push(Unlock::Reference(ReferenceUnlock::new(0).map_err(incorrect_format)?));
+            }
+        }
+
+        Self::new(essence, Unlocks::new(unlocks).map_err(incorrect_format)?).map_err(incorrect_format)
+    }
+}
@@ -4,7 +4,6 @@
 use alloc::vec::Vec;
 use core::ops::Deref;
 
-use crypto::hashes::{blake2b::Blake2b256, Digest};
 use packable::{
     error::{UnexpectedEOF, UnpackError, UnpackErrorExt},
     packer::Packer,
@@ -13,9 +12,10 @@ use packable::{
 };
 
 use crate::types::block::{
+    helper::blake2b256_packed,
     parent::Parents,
     payload::{OptionalPayload, Payload},
-    protocol::ProtocolParameters,
+    protocol::{verify_protocol_version, ProtocolFeature, ProtocolParameters},
     BlockId, Error, PROTOCOL_VERSION,
 };
 
@@ -50,6 +50,12 @@ impl BlockBuilder {
         self
     }
 
+    /// Adds the protocol version of the [`ProtocolParameters`] to a [`BlockBuilder`].
+    #[inline(always)]
+    pub fn with_protocol_parameters(self, protocol_parameters: &ProtocolParameters) -> Self {
+        self.with_protocol_version(protocol_parameters.protocol_version())
+    }
+
     /// Adds a payload to a [`BlockBuilder`].
     #[inline(always)]
     pub fn with_payload(mut self, payload: impl Into<OptionalPayload>) -> Self {
@@ -65,10 +71,13 @@ impl BlockBuilder {
     }
 
     fn _finish(self) -> Result<(Block, Vec<u8>), Error> {
-        verify_payload(self.payload.as_ref())?;
+        let protocol_version = self.protocol_version.unwrap_or(PROTOCOL_VERSION);
+
+        verify_protocol_version(protocol_version)?;
+        verify_payload(self.payload.as_ref(), protocol_version)?;
 
         let block = Block {
-            protocol_version: self.protocol_version.unwrap_or(PROTOCOL_VERSION),
+            protocol_version,
             parents: self.parents,
             payload: self.payload,
             nonce: self.nonce.unwrap_or(Self::DEFAULT_NONCE),
@@ -152,7 +161,13 @@ impl Block {
     /// Computes the identifier of the block.
     #[inline(always)]
     pub fn id(&self) -> BlockId {
-        BlockId::new(Blake2b256::digest(self.pack_to_vec()).into())
+        BlockId::new(blake2b256_packed(self))
+    }
+
+    /// Returns the length of the packed [`Block`] in bytes, it can't exceed [`Block::LENGTH_MAX`].
+    #[inline(always)]
+    pub fn packed_len(&self) -> usize {
+        PackableExt::packed_len(self)
     }
 
     /// Consumes the [`Block`], and returns ownership over its [`Parents`].
@@ -200,6 +215,10 @@ impl Packable for Block {
 
         let protocol_version = u8::unpack::<_, VERIFY>(unpacker, &()).coerce()?;
 
+        if VERIFY {
+            verify_protocol_version(protocol_version).map_err(UnpackError::Packable)?;
+        }
+
         if VERIFY && protocol_version != visitor.protocol_version() {
             return Err(UnpackError::Packable(Error::ProtocolVersionMismatch {
                 expected: visitor.protocol_version(),
@@ -211,7 +230,7 @@ impl Packable for Block {
         let payload = OptionalPayload::unpack::<_, VERIFY>(unpacker, visitor)?;
 
         if VERIFY {
-            verify_payload(payload.deref().as_ref()).map_err(UnpackError::Packable)?;
+            verify_payload(payload.deref().as_ref(), protocol_version).map_err(UnpackError::Packable)?;
         }
 
         let nonce = u64::unpack::<_, VERIFY>(unpacker, &()).coerce()?;
@@ -239,7 +258,19 @@ impl Packable for Block {
     }
 }
 
-fn verify_payload(payload: Option<&Payload>) -> Result<(), Error> {
+fn verify_payload(payload: Option<&Payload>, protocol_version: u8) -> Result<(), Error> {
+    if let Some(Payload::Milestone(milestone)) = payload {
+        if milestone.receipt().is_some() {
+            ProtocolFeature::Treasury.verify(protocol_version)?;
+        }
+    }
+
+    // Unknown payloads are accepted so blocks of future protocol versions can still be inspected.
+    #[cfg(feature = "unknown_payloads")]
+    if matches!(payload, Some(Payload::Unknown(_))) {
+        return Ok(());
+    }
+
     if !matches!(
         payload,
         None | Some(Payload::Transaction(_)) | Some(Payload::Milestone(_)) | Some(Payload::TaggedData(_))
@@ -9,16 +9,18 @@ use prefix_hex::Error as HexError;
 use primitive_types::U256;
 
 use crate::types::block::{
+    address::{Address, Ed25519Address},
     input::UtxoInput,
     output::{
-        feature::FeatureCount, unlock_condition::UnlockConditionCount, AliasId, ChainId, MetadataFeatureLength,
-        NativeTokenCount, NftId, OutputIndex, StateMetadataLength, TagFeatureLength,
+        feature::FeatureCount, unlock_condition::UnlockConditionCount, AliasId, ChainId, InputsCommitment,
+        MetadataFeatureLength, NativeTokenCount, NftId, OutputId, OutputIndex, StateMetadataLength, TagFeatureLength,
     },
     parent::ParentCount,
     payload::{
         milestone::BinaryParametersLength, InputCount, MilestoneMetadataLength, MilestoneOptionCount, OutputCount,
         ReceiptFundsCount, SignatureCount, TagLength, TaggedDataLength,
     },
+    protocol::ProtocolFeature,
     unlock::{UnlockCount, UnlockIndex},
 };
 
@@ -36,6 +38,11 @@ pub enum Error {
     ExpirationUnlockConditionZero,
     FeaturesNotUniqueSorted,
     InputUnlockCountMismatch { input_count: usize, unlock_count: usize },
+    InputCountMismatch { expected: usize, actual: usize },
+    InputOutputIdMismatch { index: usize, expected: OutputId, actual: OutputId },
+    InputsCommitmentMismatch { expected: InputsCommitment, actual: InputsCommitment },
+    AddressKindMismatch { expected: u8, actual: u8 },
+    Bech32HrpMismatch { expected: String, actual: String },
     InvalidAddress,
     InvalidAddressKind(u8),
     InvalidAliasIndex(<UnlockIndex as TryFrom<u16>>::Error),
@@ -52,10 +59,14 @@ pub enum Error {
     InvalidFoundryOutputSupply { minted: U256, melted: U256, max: U256 },
     Hex(HexError),
     InvalidInputKind(u8),
+    InvalidIrc27Metadata(String),
+    InvalidIrc30Metadata(String),
     InvalidInputCount(<InputCount as TryFrom<usize>>::Error),
     InvalidInputOutputIndex(<OutputIndex as TryFrom<u16>>::Error),
     InvalidBech32Hrp(FromUtf8Error),
+    MalformedBech32Hrp(String),
     InvalidBlockLength(usize),
+    InvalidStateLog(String),
     InvalidStateMetadataLength(<StateMetadataLength as TryFrom<usize>>::Error),
     InvalidMetadataFeatureLength(<MetadataFeatureLength as TryFrom<usize>>::Error),
     InvalidMilestoneMetadataLength(<MilestoneMetadataLength as TryFrom<usize>>::Error),
@@ -81,6 +92,7 @@ pub enum Error {
     InvalidTagFeatureLength(<TagFeatureLength as TryFrom<usize>>::Error),
     InvalidTagLength(<TagLength as TryFrom<usize>>::Error),
     InvalidTailTransactionHash,
+    InvalidTokenAmount(String),
     InvalidTokenSchemeKind(u8),
     InvalidTransactionAmountSum(u128),
     InvalidTransactionNativeTokensCount(u16),
@@ -101,6 +113,7 @@ pub enum Error {
     MissingGovernorUnlockCondition,
     MissingPayload,
     MissingRequiredSenderBlock,
+    MissingSignature(Ed25519Address),
     MissingStateControllerUnlockCondition,
     NativeTokensNotUniqueSorted,
     NativeTokensNullAmount,
@@ -118,10 +131,14 @@ pub enum Error {
     StorageDepositReturnOverflow,
     TailTransactionHashNotUnique { previous: usize, current: usize },
     TimelockUnlockConditionZero,
+    TokenSupplyOverflow,
     UnallowedFeature { index: usize, kind: u8 },
     UnallowedUnlockCondition { index: usize, kind: u8 },
+    UnavailableProtocolFeature { feature: ProtocolFeature, protocol_version: u8 },
     UnlockConditionsNotUniqueSorted,
+    UnresolvedUnlockAddress(Address),
     UnsupportedOutputKind(u8),
+    UnsupportedProtocolVersion(u8),
     DuplicateOutputChain(ChainId),
     InvalidField(&'static str),
 }
@@ -157,10 +174,33 @@ impl fmt::Display for Error {
                     "input count and unlock count mismatch: {input_count} != {unlock_count}",
                 )
             }
+            Self::InputCountMismatch { expected, actual } => {
+                write!(f, "input count mismatch: expected {expected} but got {actual}")
+            }
+            Self::InputOutputIdMismatch {
+                index,
+                expected,
+                actual,
+            } => {
+                write!(
+                    f,
+                    "output ID mismatch of input {index}: expected {expected} but got {actual}"
+                )
+            }
+            Self::InputsCommitmentMismatch { expected, actual } => {
+                write!(f, "inputs commitment mismatch: expected {expected} but got {actual}")
+            }
+            Self::AddressKindMismatch { expected, actual } => {
+                write!(f, "address kind mismatch: expected {expected} but got {actual}")
+            }
+            Self::Bech32HrpMismatch { expected, actual } => {
+                write!(f, "bech32 hrp mismatch: expected {expected} but got {actual}")
+            }
             Self::InvalidAddress => write!(f, "invalid address provided"),
             Self::InvalidAddressKind(k) => write!(f, "invalid address kind: {k}"),
             Self::InvalidAliasIndex(index) => write!(f, "invalid alias index: {index}"),
             Self::InvalidBech32Hrp(err) => write!(f, "invalid bech32 hrp: {err}"),
+            Self::MalformedBech32Hrp(hrp) => write!(f, "malformed bech32 hrp: {hrp}"),
             Self::InvalidBinaryParametersLength(length) => {
                 write!(f, "invalid binary parameters length: {length}")
             }
@@ -193,9 +233,12 @@ impl fmt::Display for Error {
             ),
             Self::Hex(error) => write!(f, "hex error: {error}"),
             Self::InvalidInputKind(k) => write!(f, "invalid input kind: {k}"),
+            Self::InvalidIrc27Metadata(reason) => write!(f, "invalid IRC27 metadata: {reason}"),
+            Self::InvalidIrc30Metadata(reason) => write!(f, "invalid IRC30 metadata: {reason}"),
             Self::InvalidInputCount(count) => write!(f, "invalid input count: {count}"),
             Self::InvalidInputOutputIndex(index) => write!(f, "invalid input or output index: {index}"),
             Self::InvalidBlockLength(length) => write!(f, "invalid block length {length}"),
+            Self::InvalidStateLog(reason) => write!(f, "invalid state log: {reason}"),
             Self::InvalidStateMetadataLength(length) => write!(f, "invalid state metadata length {length}"),
             Self::InvalidMetadataFeatureLength(length) => {
                 write!(f, "invalid metadata feature length {length}")
@@ -237,6 +280,7 @@ impl fmt::Display for Error {
                 write!(f, "invalid tag length {length}")
             }
             Self::InvalidTailTransactionHash => write!(f, "invalid tail transaction hash"),
+            Self::InvalidTokenAmount(reason) => write!(f, "invalid token amount: {reason}"),
             Self::InvalidTokenSchemeKind(k) => write!(f, "invalid token scheme kind {k}"),
             Self::InvalidTransactionAmountSum(value) => write!(f, "invalid transaction amount sum: {value}"),
             Self::InvalidTransactionNativeTokensCount(count) => {
@@ -278,6 +322,7 @@ impl fmt::Display for Error {
             Self::MissingGovernorUnlockCondition => write!(f, "missing governor unlock condition"),
             Self::MissingPayload => write!(f, "missing payload"),
             Self::MissingRequiredSenderBlock => write!(f, "missing required sender block"),
+            Self::MissingSignature(address) => write!(f, "missing signature of address {address}"),
             Self::MissingStateControllerUnlockCondition => write!(f, "missing state controller unlock condition"),
             Self::NativeTokensNotUniqueSorted => write!(f, "native tokens are not unique and/or sorted"),
             Self::NativeTokensNullAmount => write!(f, "native tokens null amount"),
@@ -327,14 +372,25 @@ impl fmt::Display for Error {
                     "timelock unlock condition with milestone index and timestamp set to 0",
                 )
             }
+            Self::TokenSupplyOverflow => write!(f, "token supply overflow"),
             Self::UnallowedFeature { index, kind } => {
                 write!(f, "unallowed feature at index {index} with kind {kind}")
             }
             Self::UnallowedUnlockCondition { index, kind } => {
                 write!(f, "unallowed unlock condition at index {index} with kind {kind}")
             }
+            Self::UnavailableProtocolFeature {
+                feature,
+                protocol_version,
+            } => {
+                write!(f, "{feature} is not available in protocol version {protocol_version}")
+            }
             Self::UnlockConditionsNotUniqueSorted => write!(f, "unlock conditions are not unique and/or sorted"),
+            Self::UnresolvedUnlockAddress(address) => {
+                write!(f, "unresolved unlock address {address:?}, no previous input unlocks it")
+            }
             Self::UnsupportedOutputKind(k) => write!(f, "unsupported output kind: {k}"),
+            Self::UnsupportedProtocolVersion(version) => write!(f, "unsupported protocol version: {version}"),
             Self::DuplicateOutputChain(chain_id) => write!(f, "duplicate output chain {chain_id}"),
             Self::InvalidField(field) => write!(f, "invalid field: {field}"),
         }
@@ -1,7 +1,10 @@
 // Copyright 2022 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+use core::{cmp::Ordering, convert::Infallible};
+
 use crypto::hashes::{blake2b::Blake2b256, Digest};
+use packable::{packer::Packer, Packable, PackableExt};
 
 /// Hashes a string network name to a digit network ID.
 pub fn network_name_to_id(network_name: &str) -> u64 {
@@ -9,3 +12,48 @@ pub fn network_name_to_id(network_name: &str) -> u64 {
     // that slice to an array of 8 bytes.
     u64::from_le_bytes(Blake2b256::digest(network_name.as_bytes())[0..8].try_into().unwrap())
 }
+
+/// Compares two values lexically by their packed bytes.
+pub fn cmp_lexically<T: Packable>(a: &T, b: &T) -> Ordering {
+    a.pack_to_vec().cmp(&b.pack_to_vec())
+}
+
+/// Sorts values lexically by their packed bytes, the canonical order to make e.g. the inputs and outputs of a
+/// transaction deterministic.
+pub fn sort_lexically<T: Packable>(values: &mut [T]) {
+    values.sort_by_cached_key(PackableExt::pack_to_vec);
+}
+
+/// Checks if values are sorted lexically by their packed bytes, see [`sort_lexically()`].
+pub fn is_sorted_lexically<T: Packable>(values: &[T]) -> bool {
+    values
+        .windows(2)
+        .all(|pair| cmp_lexically(&pair[0], &pair[1]) != Ordering::Greater)
+}
+
+// A packer that feeds the packed bytes to a hasher instead of collecting them.
+struct HashPacker<'a, D: Digest>(&'a mut D);
+
+impl<D: Digest> Packer for HashPacker<'_, D> {
+    type Error = Infallible;
+
+    fn pack_bytes<B: AsRef<[u8]>>(&mut self, bytes: B) -> Result<(), Self::Error> {
+        self.0.update(bytes);
+
+        Ok(())
+    }
+}
+
+/// Feeds the packed bytes of a value to a hasher without allocating them.
+pub(crate) fn update_packed<D: Digest>(hasher: &mut D, value: &impl Packable) {
+    value.pack(&mut HashPacker(hasher)).unwrap_or_else(|e| match e {})
+}
+
+/// Hashes the packed bytes of a value with Blake2b256 without allocating them.
+pub(crate) fn blake2b256_packed(value: &impl Packable) -> [u8; 32] {
+    let mut hasher = Blake2b256::new();
+
+    update_packed(&mut hasher, value);
+
+    hasher.finalize().into()
+}
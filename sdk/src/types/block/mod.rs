@@ -2,9 +2,14 @@
 // SPDX-License-Identifier: Apache-2.0
 
 //! Core data types for blocks in the tangle.
+//!
+//! The types, their packing and their verification only need `core` and `alloc`, so they can be used in `no_std`
+//! environments like embedded signers. Generators of random and arbitrary values require the `std` feature.
 
 #[macro_use]
 mod r#macro;
+#[cfg(feature = "arbitrary")]
+mod arbitrary;
 mod block_id;
 mod error;
 
@@ -21,6 +26,8 @@ pub mod helper;
 pub mod input;
 /// A module that provides types and syntactic validations of outputs.
 pub mod output;
+/// A module that provides views of packed blocks to read selected fields without unpacking them.
+pub mod packed;
 /// A module that provides types and syntactic validations of parents.
 pub mod parent;
 /// A module that provides types and syntactic validations of payloads.
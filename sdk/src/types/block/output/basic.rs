@@ -11,7 +11,7 @@ use crate::types::block::{
         feature::{verify_allowed_features, Feature, FeatureFlags, Features},
         unlock_condition::{verify_allowed_unlock_conditions, UnlockCondition, UnlockConditionFlags, UnlockConditions},
         verify_output_amount, verify_output_amount_packable, NativeToken, NativeTokens, Output, OutputBuilderAmount,
-        OutputId, Rent, RentStructure,
+        OutputId, RentStructure,
     },
     protocol::ProtocolParameters,
     semantic::{ConflictReason, ValidationContext},
@@ -62,6 +62,505 @@ impl core::fmt::Debug for MetadataFeature {
     }
 }
 
+/// Metadata of native tokens following the IRC30 standard.
+pub mod irc_30 {
+    use alloc::{
+        string::{String, ToString},
+        vec::Vec,
+    };
+
+    use primitive_types::U256;
+    use serde::{Deserialize, Serialize};
+
+    use super::MetadataFeature;
+    use crate::types::block::Error;
+
+    // The maximum value of a `U256` has 78 digits, so more decimals can't be represented.
+    const MAX_DECIMALS: u32 = 78;
+
+    /// The metadata of a native token following the
+    /// [IRC30](https://github.com/iotaledger/tips/blob/main/tips/TIP-0030/tip-0030.md) standard, stored as JSON in the
+    /// immutable [`MetadataFeature`] of its foundry.
+    #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    pub struct Irc30Metadata {
+        /// The standard of the metadata, always `IRC30`.
+        pub standard: String,
+        /// The human readable name of the token.
+        pub name: String,
+        /// The description of the token.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub description: Option<String>,
+        /// The ticker symbol of the token.
+        pub symbol: String,
+        /// The number of decimals the amount of the token is displayed with.
+        pub decimals: u32,
+        /// The URL of the website of the token.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub url: Option<String>,
+        /// The URL of the logo of the token.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub logo_url: Option<String>,
+        /// The logo of the token, as SVG.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub logo: Option<String>,
+    }
+
+    impl Irc30Metadata {
+        /// The standard of the metadata.
+        pub const STANDARD: &'static str = "IRC30";
+
+        /// Creates the metadata of a token, the optional fields can be set directly.
+        pub fn new(name: impl Into<String>, symbol: impl Into<String>, decimals: u32) -> Self {
+            Self {
+                standard: Self::STANDARD.to_string(),
+                name: name.into(),
+                description: None,
+                symbol: symbol.into(),
+                decimals,
+                url: None,
+                logo_url: None,
+                logo: None,
+            }
+        }
+
+        /// Checks that the metadata follows the standard, with a name and a symbol.
+        pub fn validate(&self) -> Result<(), Error> {
+            if self.standard != Self::STANDARD {
+                return Err(Error::InvalidIrc30Metadata(alloc::format!("unsupported standard {}", self.standard)));
+            }
+            if self.name.trim().is_empty() {
+                return Err(Error::InvalidIrc30Metadata("empty name".to_string()));
+            }
+            if self.symbol.trim().is_empty() {
+                return Err(Error::InvalidIrc30Metadata("empty symbol".to_string()));
+            }
+
+            Ok(())
+        }
+
+        /// Encodes the validated metadata as JSON, to be stored in the [`MetadataFeature`] of a foundry.
+        pub fn to_bytes(&self) -> Result<Vec<u8>, Error> {
+            self.validate()?;
+            serde_json::to_vec(self)
+                .map_err(|e| Error::InvalidIrc30Metadata(e.to_string()))
+        }
+
+        /// Formats an amount of the token in its smallest unit as decimal string with the decimals of the metadata,
+        /// e.g. `1234500` with 6 decimals as `1.2345`.
+        pub fn format_amount(&self, amount: U256) -> Result<String, Error> {
+            let decimals = checked_decimals(self.decimals)?;
+            let digits = amount.to_string();
+            if decimals == 0 {
+                return Ok(digits);
+            }
+
+            // Pad with leading zeros, so there's at least one integer digit
+            let digits = alloc::format!("{digits:0>width$}", width = decimals + 1);
+            let (integer, fraction) = digits.split_at(digits.len() - decimals);
+            let fraction = fraction.trim_end_matches('0');
+
+            Ok(if fraction.is_empty() {
+                integer.to_string()
+            } else {
+                alloc::format!("{integer}.{fraction}")
+            })
+        }
+
+        /// Parses a decimal string, e.g. `1.2345`, into an amount of the token in its smallest unit with the decimals
+        /// of the metadata. Fails if the string has more decimals than the token or the amount overflows.
+        pub fn parse_amount(&self, amount: &str) -> Result<U256, Error> {
+            let decimals = checked_decimals(self.decimals)?;
+            let (integer, fraction) = amount.split_once('.').unwrap_or((amount, ""));
+            if (integer.is_empty() && fraction.is_empty())
+                || !integer.chars().chain(fraction.chars()).all(|c| c.is_ascii_digit())
+            {
+                return Err(Error::InvalidTokenAmount(alloc::format!("{amount} is not a decimal number")));
+            }
+            if fraction.len() > decimals {
+                return Err(Error::InvalidTokenAmount(alloc::format!("{amount} has more than {decimals} decimals")));
+            }
+
+            U256::from_dec_str(&alloc::format!("{integer}{fraction:0<decimals$}"))
+                .map_err(|_| Error::InvalidTokenAmount(alloc::format!("{amount} overflows")))
+        }
+    }
+
+    fn checked_decimals(decimals: u32) -> Result<usize, Error> {
+        if decimals > MAX_DECIMALS {
+            return Err(Error::InvalidTokenAmount(alloc::format!("unsupported number of decimals {decimals}")));
+        }
+
+        Ok(decimals as usize)
+    }
+
+    impl TryFrom<&[u8]> for Irc30Metadata {
+        type Error = Error;
+
+        fn try_from(bytes: &[u8]) -> Result<Self, Error> {
+            let metadata: Self = serde_json::from_slice(bytes)
+                .map_err(|e| Error::InvalidIrc30Metadata(e.to_string()))?;
+            metadata.validate()?;
+
+            Ok(metadata)
+        }
+    }
+
+    impl TryFrom<&MetadataFeature> for Irc30Metadata {
+        type Error = Error;
+
+        fn try_from(feature: &MetadataFeature) -> Result<Self, Error> {
+            Self::try_from(feature.data())
+        }
+    }
+
+    impl TryFrom<&Irc30Metadata> for MetadataFeature {
+        type Error = Error;
+
+        fn try_from(metadata: &Irc30Metadata) -> Result<Self, Error> {
+            Self::new(metadata.to_bytes()?)
+        }
+    }
+}
+
+/// Metadata of NFTs following the IRC27 standard.
+pub mod irc_27 {
+    use alloc::{
+        collections::BTreeMap,
+        format,
+        string::{String, ToString},
+        vec::Vec,
+    };
+
+    use serde::{Deserialize, Serialize};
+
+    use super::MetadataFeature;
+    use crate::types::block::{address::Address, Error};
+
+    /// The metadata of an NFT following the
+    /// [IRC27](https://github.com/iotaledger/tips/blob/main/tips/TIP-0027/tip-0027.md) standard, stored as JSON in the
+    /// immutable [`MetadataFeature`] of the NFT output.
+    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    pub struct Irc27Metadata {
+        /// The standard of the metadata, always `IRC27`.
+        pub standard: String,
+        /// The version of the standard, always `v1.0`.
+        pub version: String,
+        /// The MIME type of the media file, e.g. `image/png`.
+        #[serde(rename = "type")]
+        pub media_type: String,
+        /// The URI of the media file, e.g. `https://` or `ipfs://`.
+        pub uri: String,
+        /// The human readable name of the NFT.
+        pub name: String,
+        /// The human readable name of the collection of the NFT.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub collection_name: Option<String>,
+        /// The shares of the royalties of sales, from `0` to `1`, by bech32 address.
+        #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+        pub royalties: BTreeMap<String, f64>,
+        /// The name of the creator of the NFT.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub issuer_name: Option<String>,
+        /// The description of the NFT.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub description: Option<String>,
+        /// The attributes of the NFT.
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        pub attributes: Vec<Irc27Attribute>,
+    }
+
+    /// An attribute of an NFT following the IRC27 standard.
+    #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+    pub struct Irc27Attribute {
+        /// The name of the attribute.
+        pub trait_type: String,
+        /// The value of the attribute.
+        pub value: serde_json::Value,
+    }
+
+    impl Irc27Metadata {
+        /// The standard of the metadata.
+        pub const STANDARD: &'static str = "IRC27";
+        /// The supported version of the standard.
+        pub const VERSION: &'static str = "v1.0";
+
+        /// Creates the metadata of an NFT, the optional fields can be set directly.
+        pub fn new(media_type: impl Into<String>, uri: impl Into<String>, name: impl Into<String>) -> Self {
+            Self {
+                standard: Self::STANDARD.to_string(),
+                version: Self::VERSION.to_string(),
+                media_type: media_type.into(),
+                uri: uri.into(),
+                name: name.into(),
+                collection_name: None,
+                royalties: BTreeMap::new(),
+                issuer_name: None,
+                description: None,
+                attributes: Vec::new(),
+            }
+        }
+
+        /// Checks that the metadata follows the standard, with a MIME type, a URI, a name and royalties to valid
+        /// addresses that add up to at most `1`.
+        pub fn validate(&self) -> Result<(), Error> {
+            if self.standard != Self::STANDARD {
+                return Err(Error::InvalidIrc27Metadata(format!("unsupported standard {}", self.standard)));
+            }
+            if self.version != Self::VERSION {
+                return Err(Error::InvalidIrc27Metadata(format!("unsupported version {}", self.version)));
+            }
+            match self.media_type.split_once('/') {
+                Some((kind, subtype)) if !kind.is_empty() && !subtype.is_empty() => {}
+                _ => {
+                    return Err(Error::InvalidIrc27Metadata(format!("invalid media type {}", self.media_type)));
+                }
+            }
+            if self.uri.trim().is_empty() {
+                return Err(Error::InvalidIrc27Metadata("empty uri".to_string()));
+            }
+            if self.name.trim().is_empty() {
+                return Err(Error::InvalidIrc27Metadata("empty name".to_string()));
+            }
+
+            let mut total_royalties = 0.0;
+            for (address, share) in &self.royalties {
+                if !Address::is_valid_bech32(address) {
+                    return Err(Error::InvalidIrc27Metadata(format!("invalid royalty address {address}")));
+                }
+                if !(0.0..=1.0).contains(share) {
+                    return Err(Error::InvalidIrc27Metadata(format!("invalid royalty share {share}")));
+                }
+                total_royalties += share;
+            }
+            if total_royalties > 1.0 {
+                return Err(Error::InvalidIrc27Metadata(format!("royalties add up to {total_royalties}")));
+            }
+
+            Ok(())
+        }
+
+        /// Returns the URL to fetch the media file from. IPFS URIs like `ipfs://<cid>/<path>` are resolved with the
+        /// gateway, e.g. `https://ipfs.io`, other URIs are returned as they are.
+        pub fn media_url(&self, ipfs_gateway: &str) -> String {
+            let Some(path) = self.uri.strip_prefix("ipfs://") else {
+                return self.uri.clone();
+            };
+
+            format!(
+                "{}/ipfs/{}",
+                ipfs_gateway.trim_end_matches('/'),
+                path.trim_start_matches("ipfs/")
+            )
+        }
+
+        /// Encodes the validated metadata as JSON, to be stored in the [`MetadataFeature`] of an NFT.
+        pub fn to_bytes(&self) -> Result<Vec<u8>, Error> {
+            self.validate()?;
+            serde_json::to_vec(self)
+                .map_err(|e| Error::InvalidIrc27Metadata(e.to_string()))
+        }
+    }
+
+    impl TryFrom<&[u8]> for Irc27Metadata {
+        type Error = Error;
+
+        fn try_from(bytes: &[u8]) -> Result<Self, Error> {
+            let metadata: Self = serde_json::from_slice(bytes)
+                .map_err(|e| Error::InvalidIrc27Metadata(e.to_string()))?;
+            metadata.validate()?;
+
+            Ok(metadata)
+        }
+    }
+
+    impl TryFrom<&MetadataFeature> for Irc27Metadata {
+        type Error = Error;
+
+        fn try_from(feature: &MetadataFeature) -> Result<Self, Error> {
+            Self::try_from(feature.data())
+        }
+    }
+
+    impl TryFrom<&Irc27Metadata> for MetadataFeature {
+        type Error = Error;
+
+        fn try_from(metadata: &Irc27Metadata) -> Result<Self, Error> {
+            Self::new(metadata.to_bytes()?)
+        }
+    }
+}
+
+/// Append-only logs of the state of tracked assets, stored in the mutable metadata of NFTs.
+pub mod state_log {
+    use alloc::{
+        format,
+        string::{String, ToString},
+        vec::Vec,
+    };
+
+    use crypto::hashes::{blake2b::Blake2b256, Digest};
+    use serde::{Deserialize, Serialize};
+
+    use super::MetadataFeature;
+    use crate::types::block::Error;
+
+    /// An update of the state of a tracked asset, chained to the previous update by its hash.
+    #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    pub struct StateUpdate {
+        /// The index of the update in the log, starting at `0`.
+        pub index: u32,
+        /// The hex encoded hash of the previous update, `None` for the first one.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub previous_hash: Option<String>,
+        /// The UNIX timestamp of the update in seconds.
+        pub timestamp: u64,
+        /// The state of the asset, e.g. its location or custodian.
+        pub state: serde_json::Value,
+    }
+
+    impl StateUpdate {
+        /// Returns the Blake2b256 hash of the JSON encoded update, referenced by the next update.
+        pub fn hash(&self) -> Result<[u8; 32], Error> {
+            let bytes = serde_json::to_vec(self).map_err(|e| Error::InvalidStateLog(e.to_string()))?;
+
+            Ok(Blake2b256::digest(bytes).into())
+        }
+    }
+
+    /// The log of the state updates of a tracked asset, stored as JSON in the mutable [`MetadataFeature`] of an NFT,
+    /// so every state transition of the NFT can append an update that can be verified against the previous ones.
+    #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    pub struct StateLog {
+        /// The standard of the log, always `STATE_LOG`.
+        pub standard: String,
+        /// The updates, from the oldest to the latest one.
+        pub updates: Vec<StateUpdate>,
+    }
+
+    impl Default for StateLog {
+        fn default() -> Self {
+            Self::new()
+        }
+    }
+
+    impl StateLog {
+        /// The standard of the log.
+        pub const STANDARD: &'static str = "STATE_LOG";
+
+        /// Creates an empty log.
+        pub fn new() -> Self {
+            Self {
+                standard: Self::STANDARD.to_string(),
+                updates: Vec::new(),
+            }
+        }
+
+        /// Returns the latest update, `None` if the log is empty.
+        pub fn latest(&self) -> Option<&StateUpdate> {
+            self.updates.last()
+        }
+
+        /// Appends an update with the state, chained to the latest update, and returns it.
+        pub fn append(&mut self, state: serde_json::Value, timestamp: u64) -> Result<&StateUpdate, Error> {
+            let previous_hash = self
+                .latest()
+                .map(|update| update.hash().map(prefix_hex::encode))
+                .transpose()?;
+            if let Some(latest) = self.latest() {
+                if timestamp < latest.timestamp {
+                    return Err(Error::InvalidStateLog(format!(
+                        "timestamp {timestamp} is before the latest update at {}",
+                        latest.timestamp
+                    )));
+                }
+            }
+
+            self.updates.push(StateUpdate {
+                index: self.updates.len() as u32,
+                previous_hash,
+                timestamp,
+                state,
+            });
+
+            Ok(&self.updates[self.updates.len() - 1])
+        }
+
+        /// Checks that the log follows the standard and that every update has the next index, isn't older than its
+        /// previous update and references its hash.
+        pub fn validate(&self) -> Result<(), Error> {
+            if self.standard != Self::STANDARD {
+                return Err(Error::InvalidStateLog(format!(
+                    "unsupported standard {}",
+                    self.standard
+                )));
+            }
+
+            let mut previous: Option<&StateUpdate> = None;
+            for (index, update) in self.updates.iter().enumerate() {
+                if update.index as usize != index {
+                    return Err(Error::InvalidStateLog(format!(
+                        "update {index} has the index {}",
+                        update.index
+                    )));
+                }
+                let previous_hash = previous
+                    .map(|previous| previous.hash().map(prefix_hex::encode))
+                    .transpose()?;
+                if update.previous_hash != previous_hash {
+                    return Err(Error::InvalidStateLog(format!(
+                        "update {index} doesn't reference the previous update"
+                    )));
+                }
+                if previous.map_or(false, |previous| update.timestamp < previous.timestamp) {
+                    return Err(Error::InvalidStateLog(format!(
+                        "update {index} is older than the previous update"
+                    )));
+                }
+                previous = Some(update);
+            }
+
+            Ok(())
+        }
+
+        /// Encodes the validated log as JSON, to be stored in the [`MetadataFeature`] of an NFT.
+        pub fn to_bytes(&self) -> Result<Vec<u8>, Error> {
+            self.validate()?;
+            serde_json::to_vec(self).map_err(|e| Error::InvalidStateLog(e.to_string()))
+        }
+    }
+
+    impl TryFrom<&[u8]> for StateLog {
+        type Error = Error;
+
+        fn try_from(bytes: &[u8]) -> Result<Self, Error> {
+            let log: Self = serde_json::from_slice(bytes).map_err(|e| Error::InvalidStateLog(e.to_string()))?;
+            log.validate()?;
+
+            Ok(log)
+        }
+    }
+
+    impl TryFrom<&MetadataFeature> for StateLog {
+        type Error = Error;
+
+        fn try_from(feature: &MetadataFeature) -> Result<Self, Error> {
+            Self::try_from(feature.data())
+        }
+    }
+
+    impl TryFrom<&StateLog> for MetadataFeature {
+        type Error = Error;
+
+        fn try_from(log: &StateLog) -> Result<Self, Error> {
+            Self::new(log.to_bytes()?)
+        }
+    }
+}
+
 #[allow(missing_docs)]
 pub mod dto {
     use alloc::string::String;
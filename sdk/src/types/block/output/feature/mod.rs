@@ -13,7 +13,17 @@ use derive_more::{Deref, From};
 use iterator_sorted::is_unique_sorted;
 use packable::{bounded::BoundedU8, prefix::BoxedSlicePrefix, Packable};
 
-pub use self::{issuer::IssuerFeature, metadata::MetadataFeature, sender::SenderFeature, tag::TagFeature};
+pub use self::{
+    issuer::IssuerFeature,
+    metadata::{
+        irc_27::{Irc27Attribute, Irc27Metadata},
+        irc_30::Irc30Metadata,
+        state_log::{StateLog, StateUpdate},
+        MetadataFeature,
+    },
+    sender::SenderFeature,
+    tag::TagFeature,
+};
 pub(crate) use self::{metadata::MetadataFeatureLength, tag::TagFeatureLength};
 use crate::types::block::{create_bitflags, Error};
 
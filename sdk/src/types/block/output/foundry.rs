@@ -14,10 +14,10 @@ use packable::{
 use crate::types::block::{
     address::{Address, AliasAddress},
     output::{
-        feature::{verify_allowed_features, Feature, FeatureFlags, Features},
+        feature::{verify_allowed_features, Feature, FeatureFlags, Features, Irc30Metadata},
         unlock_condition::{verify_allowed_unlock_conditions, UnlockCondition, UnlockConditionFlags, UnlockConditions},
         verify_output_amount, ChainId, FoundryId, NativeToken, NativeTokens, Output, OutputBuilderAmount, OutputId,
-        Rent, RentStructure, StateTransitionError, StateTransitionVerifier, TokenId, TokenScheme,
+        RentStructure, StateTransitionError, StateTransitionVerifier, TokenId, TokenScheme,
     },
     protocol::ProtocolParameters,
     semantic::{ConflictReason, ValidationContext},
@@ -364,6 +364,15 @@ impl FoundryOutput {
         TokenId::from(self.id())
     }
 
+    /// Returns the [`Irc30Metadata`] of the native token, decoded from the immutable metadata feature, `None` if the
+    /// foundry has no metadata.
+    pub fn irc_30_metadata(&self) -> Result<Option<Irc30Metadata>, Error> {
+        self.immutable_features
+            .metadata()
+            .map(Irc30Metadata::try_from)
+            .transpose()
+    }
+
     ///
     #[inline(always)]
     pub fn chain_id(&self) -> ChainId {
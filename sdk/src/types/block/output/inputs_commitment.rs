@@ -3,9 +3,8 @@
 
 use crypto::hashes::{blake2b::Blake2b256, Digest};
 use derive_more::{Deref, From};
-use packable::PackableExt;
 
-use crate::types::block::output::Output;
+use crate::types::block::{helper::blake2b256_packed, output::Output, Error};
 
 /// Represents a commitment to transaction inputs.
 #[derive(Copy, Clone, Eq, PartialEq, Hash, Ord, PartialOrd, From, Deref, packable::Packable)]
@@ -18,21 +17,46 @@ impl InputsCommitment {
 
     /// Creates a new [`InputsCommitment`] from a sequence of [`Output`]s.
     pub fn new<'a>(inputs: impl Iterator<Item = &'a Output>) -> Self {
+        Self::from_output_hashes(inputs.map(Self::output_hash))
+    }
+
+    /// Creates a new [`InputsCommitment`] from a sequence of [`Output`] hashes, see
+    /// [`InputsCommitment::output_hash()`].
+    pub fn from_output_hashes(output_hashes: impl IntoIterator<Item = [u8; 32]>) -> Self {
         let mut hasher = Blake2b256::new();
 
-        inputs.for_each(|output| hasher.update(Blake2b256::digest(output.pack_to_vec())));
+        output_hashes
+            .into_iter()
+            .for_each(|output_hash| hasher.update(output_hash));
 
         Self(hasher.finalize().into())
     }
+
+    /// Returns the hash of an [`Output`] that an [`InputsCommitment`] commits to.
+    pub fn output_hash(output: &Output) -> [u8; 32] {
+        blake2b256_packed(output)
+    }
+
+    /// Verifies that an [`InputsCommitment`] commits to a sequence of [`Output`]s.
+    pub fn verify<'a>(&self, inputs: impl Iterator<Item = &'a Output>) -> Result<(), Error> {
+        let actual = Self::new(inputs);
+
+        if self != &actual {
+            return Err(Error::InputsCommitmentMismatch {
+                expected: *self,
+                actual,
+            });
+        }
+
+        Ok(())
+    }
 }
 
 impl core::str::FromStr for InputsCommitment {
-    type Err = crate::types::block::Error;
+    type Err = Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        Ok(Self::from(
-            prefix_hex::decode::<[u8; 32]>(s).map_err(crate::types::block::Error::Hex)?,
-        ))
+        Ok(Self::from(prefix_hex::decode::<[u8; 32]>(s).map_err(Error::Hex)?))
     }
 }
 
@@ -368,6 +368,16 @@ impl Output {
         }
     }
 
+    /// Returns the length of the packed [`Output`] in bytes.
+    pub fn packed_len(&self) -> usize {
+        PackableExt::packed_len(self)
+    }
+
+    /// Returns the byte cost of the [`Output`] with the [`RentStructure`], the minimum amount it needs to have.
+    pub fn rent_cost(&self, rent_structure: &RentStructure) -> u64 {
+        Rent::rent_cost(self, rent_structure)
+    }
+
     /// Verifies if a valid storage deposit was made. Each [`Output`] has to have an amount that covers its associated
     /// byte cost, given by [`RentStructure`].
     /// If there is a [`StorageDepositReturnUnlockCondition`](unlock_condition::StorageDepositReturnUnlockCondition),
@@ -480,7 +490,7 @@ pub(crate) fn verify_output_amount_packable<const VERIFY: bool>(
 
 /// Computes the minimum amount that a storage deposit has to match to allow creating a return [`Output`] back to the
 /// sender [`Address`].
-fn minimum_storage_deposit(address: &Address, rent_structure: RentStructure, token_supply: u64) -> u64 {
+pub(crate) fn minimum_storage_deposit(address: &Address, rent_structure: RentStructure, token_supply: u64) -> u64 {
     // PANIC: This can never fail because the amount will always be within the valid range. Also, the actual value is
     // not important, we are only interested in the storage requirements of the type.
     BasicOutputBuilder::new_with_minimum_storage_deposit(rent_structure)
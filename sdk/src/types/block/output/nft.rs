@@ -13,9 +13,9 @@ use packable::{
 use crate::types::block::{
     address::{Address, NftAddress},
     output::{
-        feature::{verify_allowed_features, Feature, FeatureFlags, Features},
+        feature::{verify_allowed_features, Feature, FeatureFlags, Features, Irc27Metadata, StateLog},
         unlock_condition::{verify_allowed_unlock_conditions, UnlockCondition, UnlockConditionFlags, UnlockConditions},
-        verify_output_amount, ChainId, NativeToken, NativeTokens, NftId, Output, OutputBuilderAmount, OutputId, Rent,
+        verify_output_amount, ChainId, NativeToken, NativeTokens, NftId, Output, OutputBuilderAmount, OutputId,
         RentStructure, StateTransitionError, StateTransitionVerifier,
     },
     protocol::ProtocolParameters,
@@ -345,6 +345,21 @@ impl NftOutput {
         NftAddress::new(self.nft_id_non_null(output_id))
     }
 
+    /// Returns the [`Irc27Metadata`] of the NFT, decoded from the immutable metadata feature, `None` if the NFT has no
+    /// metadata.
+    pub fn irc_27_metadata(&self) -> Result<Option<Irc27Metadata>, Error> {
+        self.immutable_features
+            .metadata()
+            .map(Irc27Metadata::try_from)
+            .transpose()
+    }
+
+    /// Returns the [`StateLog`] of the NFT, decoded from the mutable metadata feature, `None` if the NFT has no
+    /// metadata.
+    pub fn state_log(&self) -> Result<Option<StateLog>, Error> {
+        self.features.metadata().map(StateLog::try_from).transpose()
+    }
+
     ///
     pub fn unlock(
         &self,
@@ -3,10 +3,11 @@
 
 use core::str::FromStr;
 
-use crypto::hashes::{blake2b::Blake2b256, Digest};
-use packable::{bounded::BoundedU16, PackableExt};
+use packable::bounded::BoundedU16;
 
-use crate::types::block::{output::OUTPUT_INDEX_RANGE, payload::transaction::TransactionId, Error};
+use crate::types::block::{
+    helper::blake2b256_packed, output::OUTPUT_INDEX_RANGE, payload::transaction::TransactionId, Error,
+};
 
 pub(crate) type OutputIndex = BoundedU16<{ *OUTPUT_INDEX_RANGE.start() }, { *OUTPUT_INDEX_RANGE.end() }>;
 
@@ -61,7 +62,7 @@ impl OutputId {
     /// Hash the [`OutputId`] with BLAKE2b-256.
     #[inline(always)]
     pub fn hash(&self) -> [u8; 32] {
-        Blake2b256::digest(self.pack_to_vec()).into()
+        blake2b256_packed(self)
     }
 }
 
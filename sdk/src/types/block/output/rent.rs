@@ -128,6 +128,12 @@ impl RentStructure {
     pub fn byte_offset(&self) -> u32 {
         self.v_byte_offset
     }
+
+    /// Returns the minimum storage deposit that is required for a value, e.g. an [`Output`](super::Output), with the
+    /// [`RentStructure`].
+    pub fn minimum_storage_deposit(&self, value: &impl Rent) -> u64 {
+        value.rent_cost(self)
+    }
 }
 
 impl Packable for RentStructure {
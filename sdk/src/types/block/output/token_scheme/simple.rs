@@ -62,6 +62,34 @@ impl SimpleTokenScheme {
     pub fn circulating_supply(&self) -> U256 {
         self.minted_tokens - self.melted_tokens
     }
+
+    /// Returns the number of tokens that can still be minted before the circulating supply reaches the maximum supply.
+    #[inline(always)]
+    pub fn mintable_supply(&self) -> U256 {
+        self.maximum_supply - self.circulating_supply()
+    }
+
+    /// Returns the [`SimpleTokenScheme`] of the next foundry state after minting `amount` tokens.
+    /// Fails if the circulating supply would exceed the maximum supply.
+    pub fn mint(&self, amount: U256) -> Result<Self, Error> {
+        let minted_tokens = self
+            .minted_tokens
+            .checked_add(amount)
+            .ok_or(Error::TokenSupplyOverflow)?;
+
+        Self::new(minted_tokens, self.melted_tokens, self.maximum_supply)
+    }
+
+    /// Returns the [`SimpleTokenScheme`] of the next foundry state after melting `amount` tokens.
+    /// Fails if more tokens would be melted than are circulating.
+    pub fn melt(&self, amount: U256) -> Result<Self, Error> {
+        let melted_tokens = self
+            .melted_tokens
+            .checked_add(amount)
+            .ok_or(Error::TokenSupplyOverflow)?;
+
+        Self::new(self.minted_tokens, melted_tokens, self.maximum_supply)
+    }
 }
 
 impl Packable for SimpleTokenScheme {
@@ -0,0 +1,507 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Views of packed blocks and transaction payloads that read selected fields, e.g. the IDs and amounts of the
+//! created outputs, without unpacking the whole block.
+//!
+//! Creating a view verifies the encoding and the bounds of every field. The rules that span several fields, like the
+//! uniqueness of inputs, the references of unlocks or the features allowed in an output, are only verified when
+//! unpacking. Only transaction payloads are verified, other payloads of a block are skipped.
+
+use crypto::hashes::{blake2b::Blake2b256, Digest};
+use packable::{
+    error::{UnexpectedEOF, UnpackError, UnpackErrorExt},
+    unpacker::Unpacker,
+    Packable,
+};
+
+use crate::types::block::{
+    address::Address,
+    input::Input,
+    output::{
+        feature::{FeatureCount, IssuerFeature, MetadataFeature, SenderFeature, TagFeature},
+        unlock_condition::{UnlockCondition, UnlockConditionCount},
+        verify_output_amount, AliasOutput, BasicOutput, FoundryOutput, MetadataFeatureLength, NativeToken,
+        NativeTokenCount, NftOutput, Output, OutputId, StateMetadataLength, TagFeatureLength, TokenScheme,
+        TreasuryOutput,
+    },
+    parent::ParentCount,
+    payload::{
+        transaction::{RegularTransactionEssence, TransactionId, TransactionPayload},
+        InputCount, OutputCount, TagLength, TaggedDataLength, TaggedDataPayload,
+    },
+    protocol::ProtocolParameters,
+    unlock::{Unlock, UnlockCount},
+    Block, BlockId, Error,
+};
+
+type UnpackResult<T> = Result<T, UnpackError<Error, UnexpectedEOF>>;
+
+// An unpacker that reads from a slice and can borrow the read bytes.
+struct SliceReader<'a> {
+    bytes: &'a [u8],
+    offset: usize,
+}
+
+impl<'a> SliceReader<'a> {
+    fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes, offset: 0 }
+    }
+
+    fn read_slice(&mut self, len: usize) -> UnpackResult<&'a [u8]> {
+        self.ensure_bytes(len).map_err(UnpackError::Unpacker)?;
+        let slice = &self.bytes[self.offset..self.offset + len];
+        self.offset += len;
+
+        Ok(slice)
+    }
+
+    fn read<P: Packable>(&mut self, visitor: &P::UnpackVisitor) -> UnpackResult<P>
+    where
+        Error: From<P::UnpackError>,
+    {
+        P::unpack::<_, true>(self, visitor).coerce()
+    }
+}
+
+impl Unpacker for SliceReader<'_> {
+    type Error = UnexpectedEOF;
+
+    fn unpack_bytes<B: AsMut<[u8]>>(&mut self, mut bytes: B) -> Result<(), Self::Error> {
+        let bytes = bytes.as_mut();
+        self.ensure_bytes(bytes.len())?;
+        bytes.copy_from_slice(&self.bytes[self.offset..self.offset + bytes.len()]);
+        self.offset += bytes.len();
+
+        Ok(())
+    }
+
+    fn ensure_bytes(&self, len: usize) -> Result<(), Self::Error> {
+        let had = self.bytes.len() - self.offset;
+
+        if had < len {
+            Err(UnexpectedEOF { required: len, had })
+        } else {
+            Ok(())
+        }
+    }
+
+    fn read_bytes(&self) -> Option<usize> {
+        Some(self.offset)
+    }
+}
+
+fn read_native_tokens(reader: &mut SliceReader<'_>) -> UnpackResult<()> {
+    let count = reader.read::<u8>(&())?;
+    NativeTokenCount::try_from(count as usize).map_err(|e| UnpackError::Packable(Error::InvalidNativeTokenCount(e)))?;
+
+    for _ in 0..count {
+        reader.read::<NativeToken>(&())?;
+    }
+
+    Ok(())
+}
+
+fn read_unlock_conditions(reader: &mut SliceReader<'_>, protocol_parameters: &ProtocolParameters) -> UnpackResult<()> {
+    let count = reader.read::<u8>(&())?;
+    UnlockConditionCount::try_from(count as usize)
+        .map_err(|e| UnpackError::Packable(Error::InvalidUnlockConditionCount(e)))?;
+
+    for _ in 0..count {
+        reader.read::<UnlockCondition>(protocol_parameters)?;
+    }
+
+    Ok(())
+}
+
+// Reads the features without copying the metadata and tags.
+fn read_features(reader: &mut SliceReader<'_>) -> UnpackResult<()> {
+    let count = reader.read::<u8>(&())?;
+    FeatureCount::try_from(count as usize).map_err(|e| UnpackError::Packable(Error::InvalidFeatureCount(e)))?;
+
+    for _ in 0..count {
+        match reader.read::<u8>(&())? {
+            SenderFeature::KIND | IssuerFeature::KIND => {
+                reader.read::<Address>(&())?;
+            }
+            MetadataFeature::KIND => {
+                let len = reader.read::<u16>(&())? as usize;
+                MetadataFeatureLength::try_from(len)
+                    .map_err(|e| UnpackError::Packable(Error::InvalidMetadataFeatureLength(e)))?;
+                reader.read_slice(len)?;
+            }
+            TagFeature::KIND => {
+                let len = reader.read::<u8>(&())? as usize;
+                TagFeatureLength::try_from(len)
+                    .map_err(|e| UnpackError::Packable(Error::InvalidTagFeatureLength(e)))?;
+                reader.read_slice(len)?;
+            }
+            k => return Err(UnpackError::Packable(Error::InvalidFeatureKind(k))),
+        }
+    }
+
+    Ok(())
+}
+
+fn read_output<'a>(
+    reader: &mut SliceReader<'a>,
+    protocol_parameters: &ProtocolParameters,
+) -> UnpackResult<PackedOutput<'a>> {
+    let start = reader.offset;
+    let kind = reader.read::<u8>(&())?;
+    let amount = reader.read::<u64>(&())?;
+
+    if kind == TreasuryOutput::KIND {
+        if amount > protocol_parameters.token_supply() {
+            return Err(UnpackError::Packable(Error::InvalidTreasuryOutputAmount(amount)));
+        }
+    } else {
+        verify_output_amount::<true>(&amount, &protocol_parameters.token_supply()).map_err(UnpackError::Packable)?;
+    }
+
+    match kind {
+        TreasuryOutput::KIND => {}
+        BasicOutput::KIND => {
+            read_native_tokens(reader)?;
+            read_unlock_conditions(reader, protocol_parameters)?;
+            read_features(reader)?;
+        }
+        AliasOutput::KIND => {
+            read_native_tokens(reader)?;
+            // Alias ID and state index.
+            reader.read_slice(32 + 4)?;
+            let len = reader.read::<u16>(&())? as usize;
+            StateMetadataLength::try_from(len)
+                .map_err(|e| UnpackError::Packable(Error::InvalidStateMetadataLength(e)))?;
+            // State metadata and foundry counter.
+            reader.read_slice(len + 4)?;
+            read_unlock_conditions(reader, protocol_parameters)?;
+            read_features(reader)?;
+            read_features(reader)?;
+        }
+        FoundryOutput::KIND => {
+            read_native_tokens(reader)?;
+            // Serial number.
+            reader.read_slice(4)?;
+            reader.read::<TokenScheme>(&())?;
+            read_unlock_conditions(reader, protocol_parameters)?;
+            read_features(reader)?;
+            read_features(reader)?;
+        }
+        NftOutput::KIND => {
+            read_native_tokens(reader)?;
+            // NFT ID.
+            reader.read_slice(32)?;
+            read_unlock_conditions(reader, protocol_parameters)?;
+            read_features(reader)?;
+            read_features(reader)?;
+        }
+        k => return Err(UnpackError::Packable(Error::InvalidOutputKind(k))),
+    }
+
+    Ok(PackedOutput {
+        kind,
+        amount,
+        bytes: &reader.bytes[start..reader.offset],
+    })
+}
+
+// Reads the optional tagged data payload of an essence.
+fn read_essence_payload(reader: &mut SliceReader<'_>) -> UnpackResult<()> {
+    let len = reader.read::<u32>(&())? as usize;
+    if len == 0 {
+        return Ok(());
+    }
+
+    let mut payload_reader = SliceReader::new(reader.read_slice(len)?);
+    let kind = payload_reader.read::<u32>(&())?;
+    if kind != TaggedDataPayload::KIND {
+        return Err(UnpackError::Packable(Error::InvalidPayloadKind(kind)));
+    }
+
+    let tag_len = payload_reader.read::<u8>(&())? as usize;
+    TagLength::try_from(tag_len).map_err(|e| UnpackError::Packable(Error::InvalidTagLength(e)))?;
+    payload_reader.read_slice(tag_len)?;
+    let data_len = payload_reader.read::<u32>(&())? as usize;
+    TaggedDataLength::try_from(data_len).map_err(|e| UnpackError::Packable(Error::InvalidTaggedDataLength(e)))?;
+    payload_reader.read_slice(data_len)?;
+
+    if payload_reader.offset != len {
+        return Err(UnpackError::Packable(Error::InvalidPayloadLength {
+            expected: len,
+            actual: payload_reader.offset,
+        }));
+    }
+
+    Ok(())
+}
+
+/// A view of a packed [`Output`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct PackedOutput<'a> {
+    kind: u8,
+    amount: u64,
+    bytes: &'a [u8],
+}
+
+impl<'a> PackedOutput<'a> {
+    /// Returns the output kind of a [`PackedOutput`].
+    pub fn kind(&self) -> u8 {
+        self.kind
+    }
+
+    /// Returns the amount of a [`PackedOutput`].
+    pub fn amount(&self) -> u64 {
+        self.amount
+    }
+
+    /// Returns the packed bytes of a [`PackedOutput`].
+    pub fn as_bytes(&self) -> &'a [u8] {
+        self.bytes
+    }
+
+    /// Unpacks the [`Output`] of a [`PackedOutput`].
+    pub fn unpack(&self, protocol_parameters: &ProtocolParameters) -> UnpackResult<Output> {
+        Output::unpack::<_, true>(&mut SliceReader::new(self.bytes), protocol_parameters)
+    }
+}
+
+/// A view of a packed [`TransactionPayload`], without the payload kind.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct PackedTransactionPayload<'a> {
+    bytes: &'a [u8],
+    network_id: u64,
+    input_count: u16,
+    inputs: &'a [u8],
+    output_count: u16,
+    outputs: &'a [u8],
+}
+
+impl<'a> PackedTransactionPayload<'a> {
+    /// Creates a [`PackedTransactionPayload`] from the start of the bytes, following bytes are ignored.
+    pub fn new(bytes: &'a [u8], protocol_parameters: &ProtocolParameters) -> UnpackResult<Self> {
+        let mut reader = SliceReader::new(bytes);
+
+        let kind = reader.read::<u8>(&())?;
+        if kind != RegularTransactionEssence::KIND {
+            return Err(UnpackError::Packable(Error::InvalidEssenceKind(kind)));
+        }
+
+        let network_id = reader.read::<u64>(&())?;
+        if network_id != protocol_parameters.network_id() {
+            return Err(UnpackError::Packable(Error::NetworkIdMismatch {
+                expected: protocol_parameters.network_id(),
+                actual: network_id,
+            }));
+        }
+
+        let input_count = reader.read::<u16>(&())?;
+        InputCount::try_from(input_count as usize).map_err(|e| UnpackError::Packable(Error::InvalidInputCount(e)))?;
+        let inputs_start = reader.offset;
+        for _ in 0..input_count {
+            reader.read::<Input>(&())?;
+        }
+        let inputs = &bytes[inputs_start..reader.offset];
+
+        // Inputs commitment.
+        reader.read_slice(32)?;
+
+        let output_count = reader.read::<u16>(&())?;
+        OutputCount::try_from(output_count as usize)
+            .map_err(|e| UnpackError::Packable(Error::InvalidOutputCount(e)))?;
+        let outputs_start = reader.offset;
+        for _ in 0..output_count {
+            read_output(&mut reader, protocol_parameters)?;
+        }
+        let outputs = &bytes[outputs_start..reader.offset];
+
+        read_essence_payload(&mut reader)?;
+
+        let unlock_count = reader.read::<u16>(&())?;
+        UnlockCount::try_from(unlock_count as usize)
+            .map_err(|e| UnpackError::Packable(Error::InvalidUnlockCount(e)))?;
+        if unlock_count != input_count {
+            return Err(UnpackError::Packable(Error::InputUnlockCountMismatch {
+                input_count: input_count as usize,
+                unlock_count: unlock_count as usize,
+            }));
+        }
+        for _ in 0..unlock_count {
+            reader.read::<Unlock>(&())?;
+        }
+
+        Ok(Self {
+            bytes: &bytes[..reader.offset],
+            network_id,
+            input_count,
+            inputs,
+            output_count,
+            outputs,
+        })
+    }
+
+    /// Returns the packed bytes of a [`PackedTransactionPayload`].
+    pub fn as_bytes(&self) -> &'a [u8] {
+        self.bytes
+    }
+
+    /// Computes the identifier of a [`PackedTransactionPayload`].
+    pub fn id(&self) -> TransactionId {
+        let mut hasher = Blake2b256::new();
+
+        hasher.update(TransactionPayload::KIND.to_le_bytes());
+        hasher.update(self.bytes);
+
+        TransactionId::new(hasher.finalize().into())
+    }
+
+    /// Returns the network ID of a [`PackedTransactionPayload`].
+    pub fn network_id(&self) -> u64 {
+        self.network_id
+    }
+
+    /// Returns the inputs of a [`PackedTransactionPayload`].
+    pub fn inputs(&self) -> impl ExactSizeIterator<Item = Input> + 'a {
+        let mut reader = SliceReader::new(self.inputs);
+
+        // PANIC: unwrapping is fine as the inputs were read when the view was created.
+        (0..self.input_count).map(move |_| reader.read::<Input>(&()).unwrap())
+    }
+
+    /// Returns the outputs of a [`PackedTransactionPayload`] with their [`OutputId`]s.
+    pub fn outputs<'p>(
+        &self,
+        protocol_parameters: &'p ProtocolParameters,
+    ) -> impl ExactSizeIterator<Item = (OutputId, PackedOutput<'a>)> + 'p
+    where
+        'a: 'p,
+    {
+        let transaction_id = self.id();
+        let mut reader = SliceReader::new(self.outputs);
+
+        (0..self.output_count).map(move |index| {
+            // PANIC: unwrapping is fine as the outputs were read when the view was created, and their index is in the
+            // range of the output count.
+            (
+                OutputId::new(transaction_id, index).unwrap(),
+                read_output(&mut reader, protocol_parameters).unwrap(),
+            )
+        })
+    }
+
+    /// Unpacks the [`TransactionPayload`] of a [`PackedTransactionPayload`].
+    pub fn unpack(&self, protocol_parameters: &ProtocolParameters) -> UnpackResult<TransactionPayload> {
+        TransactionPayload::unpack::<_, true>(&mut SliceReader::new(self.bytes), protocol_parameters)
+    }
+}
+
+/// A view of a packed [`Block`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct PackedBlock<'a> {
+    bytes: &'a [u8],
+    parents: &'a [u8],
+    payload: Option<(u32, &'a [u8])>,
+    transaction_payload: Option<PackedTransactionPayload<'a>>,
+    nonce: u64,
+}
+
+impl<'a> PackedBlock<'a> {
+    /// Creates a [`PackedBlock`] from the bytes of a block.
+    pub fn new(bytes: &'a [u8], protocol_parameters: &ProtocolParameters) -> UnpackResult<Self> {
+        if bytes.len() > Block::LENGTH_MAX {
+            return Err(UnpackError::Packable(Error::InvalidBlockLength(bytes.len())));
+        }
+
+        let mut reader = SliceReader::new(bytes);
+
+        let protocol_version = reader.read::<u8>(&())?;
+        if protocol_version != protocol_parameters.protocol_version() {
+            return Err(UnpackError::Packable(Error::ProtocolVersionMismatch {
+                expected: protocol_parameters.protocol_version(),
+                actual: protocol_version,
+            }));
+        }
+
+        let parent_count = reader.read::<u8>(&())?;
+        ParentCount::try_from(parent_count as usize)
+            .map_err(|e| UnpackError::Packable(Error::InvalidParentCount(e)))?;
+        let parents = reader.read_slice(parent_count as usize * BlockId::LENGTH)?;
+
+        let payload_len = reader.read::<u32>(&())? as usize;
+        let (payload, transaction_payload) = if payload_len == 0 {
+            (None, None)
+        } else {
+            let mut payload_reader = SliceReader::new(reader.read_slice(payload_len)?);
+            let kind = payload_reader.read::<u32>(&())?;
+            let payload_bytes = &payload_reader.bytes[payload_reader.offset..];
+
+            let transaction_payload = if kind == TransactionPayload::KIND {
+                let transaction_payload = PackedTransactionPayload::new(payload_bytes, protocol_parameters)?;
+                if transaction_payload.bytes.len() != payload_bytes.len() {
+                    return Err(UnpackError::Packable(Error::InvalidPayloadLength {
+                        expected: payload_len,
+                        actual: transaction_payload.bytes.len() + core::mem::size_of::<u32>(),
+                    }));
+                }
+                Some(transaction_payload)
+            } else {
+                None
+            };
+
+            (Some((kind, payload_bytes)), transaction_payload)
+        };
+
+        let nonce = reader.read::<u64>(&())?;
+
+        Ok(Self {
+            bytes: &bytes[..reader.offset],
+            parents,
+            payload,
+            transaction_payload,
+            nonce,
+        })
+    }
+
+    /// Returns the packed bytes of a [`PackedBlock`].
+    pub fn as_bytes(&self) -> &'a [u8] {
+        self.bytes
+    }
+
+    /// Computes the identifier of a [`PackedBlock`].
+    pub fn id(&self) -> BlockId {
+        BlockId::new(Blake2b256::digest(self.bytes).into())
+    }
+
+    /// Returns the parents of a [`PackedBlock`].
+    pub fn parents(&self) -> impl ExactSizeIterator<Item = BlockId> + 'a {
+        // PANIC: unwrapping is fine as the chunks have the length of a block ID.
+        self.parents
+            .chunks_exact(BlockId::LENGTH)
+            .map(|parent| BlockId::new(parent.try_into().unwrap()))
+    }
+
+    /// Returns the kind of the payload of a [`PackedBlock`].
+    pub fn payload_kind(&self) -> Option<u32> {
+        self.payload.map(|(kind, _)| kind)
+    }
+
+    /// Returns the packed payload of a [`PackedBlock`], without the payload kind.
+    pub fn payload_bytes(&self) -> Option<&'a [u8]> {
+        self.payload.map(|(_, bytes)| bytes)
+    }
+
+    /// Returns the transaction payload of a [`PackedBlock`], if it has one.
+    pub fn transaction_payload(&self) -> Option<&PackedTransactionPayload<'a>> {
+        self.transaction_payload.as_ref()
+    }
+
+    /// Returns the nonce of a [`PackedBlock`].
+    pub fn nonce(&self) -> u64 {
+        self.nonce
+    }
+
+    /// Unpacks the [`Block`] of a [`PackedBlock`].
+    pub fn unpack(&self, protocol_parameters: &ProtocolParameters) -> UnpackResult<Block> {
+        Block::unpack::<_, true>(&mut SliceReader::new(self.bytes), protocol_parameters)
+    }
+}
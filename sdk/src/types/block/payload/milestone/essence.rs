@@ -3,25 +3,122 @@
 
 use alloc::vec::Vec;
 
-use crypto::hashes::{blake2b::Blake2b256, Digest};
 use packable::{
     bounded::BoundedU16,
     error::{UnpackError, UnpackErrorExt},
     packer::Packer,
     prefix::BoxedSlicePrefix,
     unpacker::Unpacker,
-    Packable, PackableExt,
+    Packable,
 };
 
 use crate::types::block::{
+    helper::blake2b256_packed,
     parent::Parents,
-    payload::milestone::{MerkleRoot, MilestoneId, MilestoneIndex, MilestoneOptions},
+    payload::milestone::{MerkleRoot, MilestoneId, MilestoneIndex, MilestoneOption, MilestoneOptions},
     protocol::ProtocolParameters,
-    Error,
+    Error, PROTOCOL_VERSION,
 };
 
 pub(crate) type MilestoneMetadataLength = BoundedU16<{ u16::MIN }, { u16::MAX }>;
 
+/// A builder to build a [`MilestoneEssence`].
+#[derive(Clone)]
+#[must_use]
+pub struct MilestoneEssenceBuilder {
+    index: MilestoneIndex,
+    timestamp: u32,
+    protocol_version: Option<u8>,
+    previous_milestone_id: MilestoneId,
+    parents: Parents,
+    inclusion_merkle_root: MerkleRoot,
+    applied_merkle_root: MerkleRoot,
+    metadata: Vec<u8>,
+    options: Vec<MilestoneOption>,
+}
+
+impl MilestoneEssenceBuilder {
+    /// Creates a new [`MilestoneEssenceBuilder`].
+    #[inline(always)]
+    pub fn new(index: impl Into<MilestoneIndex>, timestamp: u32, parents: Parents) -> Self {
+        Self {
+            index: index.into(),
+            timestamp,
+            protocol_version: None,
+            previous_milestone_id: MilestoneId::null(),
+            parents,
+            inclusion_merkle_root: MerkleRoot::null(),
+            applied_merkle_root: MerkleRoot::null(),
+            metadata: Vec::new(),
+            options: Vec::new(),
+        }
+    }
+
+    /// Adds a protocol version to a [`MilestoneEssenceBuilder`].
+    #[inline(always)]
+    pub fn with_protocol_version(mut self, protocol_version: impl Into<Option<u8>>) -> Self {
+        self.protocol_version = protocol_version.into();
+        self
+    }
+
+    /// Adds the previous milestone ID to a [`MilestoneEssenceBuilder`].
+    #[inline(always)]
+    pub fn with_previous_milestone_id(mut self, previous_milestone_id: MilestoneId) -> Self {
+        self.previous_milestone_id = previous_milestone_id;
+        self
+    }
+
+    /// Adds the inclusion merkle root to a [`MilestoneEssenceBuilder`].
+    #[inline(always)]
+    pub fn with_inclusion_merkle_root(mut self, inclusion_merkle_root: MerkleRoot) -> Self {
+        self.inclusion_merkle_root = inclusion_merkle_root;
+        self
+    }
+
+    /// Adds the applied merkle root to a [`MilestoneEssenceBuilder`].
+    #[inline(always)]
+    pub fn with_applied_merkle_root(mut self, applied_merkle_root: MerkleRoot) -> Self {
+        self.applied_merkle_root = applied_merkle_root;
+        self
+    }
+
+    /// Adds metadata to a [`MilestoneEssenceBuilder`].
+    #[inline(always)]
+    pub fn with_metadata(mut self, metadata: impl Into<Vec<u8>>) -> Self {
+        self.metadata = metadata.into();
+        self
+    }
+
+    /// Adds a [`MilestoneOption`] to a [`MilestoneEssenceBuilder`].
+    #[inline(always)]
+    pub fn add_option(mut self, option: MilestoneOption) -> Self {
+        self.options.push(option);
+        self
+    }
+
+    /// Sets the [`MilestoneOption`]s of a [`MilestoneEssenceBuilder`].
+    #[inline(always)]
+    pub fn with_options(mut self, options: impl IntoIterator<Item = MilestoneOption>) -> Self {
+        self.options = options.into_iter().collect();
+        self
+    }
+
+    /// Finishes the [`MilestoneEssenceBuilder`] into a [`MilestoneEssence`].
+    pub fn finish(self) -> Result<MilestoneEssence, Error> {
+        MilestoneEssence::new(
+            self.index,
+            self.timestamp,
+            self.protocol_version.unwrap_or(PROTOCOL_VERSION),
+            self.previous_milestone_id,
+            self.parents,
+            self.inclusion_merkle_root,
+            self.applied_merkle_root,
+            self.metadata,
+            MilestoneOptions::from_vec(self.options)?,
+        )
+    }
+}
+
 /// Essence of a milestone payload.
 /// This is the signed part of a milestone payload.
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -70,6 +167,12 @@ impl MilestoneEssence {
         })
     }
 
+    /// Creates a new [`MilestoneEssenceBuilder`] to construct an instance of a [`MilestoneEssence`].
+    #[inline(always)]
+    pub fn build(index: impl Into<MilestoneIndex>, timestamp: u32, parents: Parents) -> MilestoneEssenceBuilder {
+        MilestoneEssenceBuilder::new(index, timestamp, parents)
+    }
+
     /// Returns the index of a [`MilestoneEssence`].
     pub fn index(&self) -> MilestoneIndex {
         self.index
@@ -117,7 +220,7 @@ impl MilestoneEssence {
 
     /// Hashes the [`MilestoneEssence`] to be signed.
     pub fn hash(&self) -> [u8; 32] {
-        Blake2b256::digest(self.pack_to_vec()).into()
+        blake2b256_packed(self)
     }
 }
 
@@ -4,7 +4,9 @@
 use crate::types::block::Error;
 
 /// A Merkle root of a list of hashes.
-#[derive(Clone, Copy, Eq, PartialEq, packable::Packable, derive_more::From, derive_more::AsRef)]
+#[derive(
+    Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash, packable::Packable, derive_more::From, derive_more::AsRef,
+)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MerkleRoot([u8; Self::LENGTH]);
 
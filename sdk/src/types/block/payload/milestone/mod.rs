@@ -20,7 +20,7 @@ pub(crate) use option::{MilestoneOptionCount, ReceiptFundsCount};
 use packable::{bounded::BoundedU8, prefix::VecPrefix, Packable};
 
 pub use self::{
-    essence::MilestoneEssence,
+    essence::{MilestoneEssence, MilestoneEssenceBuilder},
     index::MilestoneIndex,
     merkle::MerkleRoot,
     milestone_id::MilestoneId,
@@ -87,6 +87,16 @@ impl MilestonePayload {
         &self.signatures
     }
 
+    /// Returns the [`ReceiptMilestoneOption`] with the applied migration receipt of a [`MilestonePayload`], if any.
+    pub fn receipt(&self) -> Option<&ReceiptMilestoneOption> {
+        self.essence.options().receipt()
+    }
+
+    /// Returns the [`ParametersMilestoneOption`] of a [`MilestonePayload`], if any.
+    pub fn parameters(&self) -> Option<&ParametersMilestoneOption> {
+        self.essence.options().parameters()
+    }
+
     /// Computes the identifier of a [`MilestonePayload`].
     pub fn id(&self) -> MilestoneId {
         MilestoneId::new(self.essence().hash())
@@ -6,9 +6,9 @@
 use alloc::vec::Vec;
 use core::ops::RangeInclusive;
 
-use packable::{bounded::BoundedU16, prefix::BoxedSlicePrefix, Packable};
+use packable::{bounded::BoundedU16, prefix::BoxedSlicePrefix, Packable, PackableExt};
 
-use crate::types::block::{payload::milestone::MilestoneIndex, Error};
+use crate::types::block::{payload::milestone::MilestoneIndex, protocol::ProtocolParameters, Error};
 
 pub(crate) type BinaryParametersLength = BoundedU16<
     { *ParametersMilestoneOption::BINARY_PARAMETERS_LENGTH_RANGE.start() },
@@ -65,6 +65,12 @@ impl ParametersMilestoneOption {
     pub fn binary_parameters(&self) -> &[u8] {
         &self.binary_parameters
     }
+
+    /// Returns the protocol parameters that become active at the target milestone index, e.g. a new minimum PoW
+    /// score, unpacked from the binary parameters of a [`ParametersMilestoneOption`].
+    pub fn protocol_parameters(&self) -> Result<ProtocolParameters, Error> {
+        ProtocolParameters::unpack_verified(self.binary_parameters(), &()).map_err(|_| Error::InvalidField("params"))
+    }
 }
 
 #[allow(missing_docs)]
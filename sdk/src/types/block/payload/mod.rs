@@ -7,6 +7,8 @@ pub mod milestone;
 pub mod tagged_data;
 pub mod transaction;
 pub mod treasury_transaction;
+#[cfg(feature = "unknown_payloads")]
+pub mod unknown;
 
 use alloc::boxed::Box;
 use core::ops::Deref;
@@ -29,7 +31,12 @@ pub use self::{
     transaction::TransactionPayload,
     treasury_transaction::TreasuryTransactionPayload,
 };
-use crate::types::block::{protocol::ProtocolParameters, Error};
+#[cfg(feature = "unknown_payloads")]
+pub use self::unknown::UnknownPayload;
+use crate::types::block::{
+    protocol::{ProtocolFeature, ProtocolParameters},
+    Error,
+};
 
 /// A generic payload that can represent different types defining block payloads.
 #[derive(Clone, Eq, PartialEq)]
@@ -47,6 +54,9 @@ pub enum Payload {
     TreasuryTransaction(Box<TreasuryTransactionPayload>),
     /// A tagged data payload.
     TaggedData(Box<TaggedDataPayload>),
+    /// A payload of an unknown kind, kept as opaque bytes.
+    #[cfg(feature = "unknown_payloads")]
+    Unknown(Box<UnknownPayload>),
 }
 
 impl core::fmt::Debug for Payload {
@@ -56,6 +66,8 @@ impl core::fmt::Debug for Payload {
             Self::Milestone(payload) => payload.fmt(f),
             Self::TreasuryTransaction(payload) => payload.fmt(f),
             Self::TaggedData(payload) => payload.fmt(f),
+            #[cfg(feature = "unknown_payloads")]
+            Self::Unknown(payload) => payload.fmt(f),
         }
     }
 }
@@ -84,6 +96,13 @@ impl From<TaggedDataPayload> for Payload {
     }
 }
 
+#[cfg(feature = "unknown_payloads")]
+impl From<UnknownPayload> for Payload {
+    fn from(payload: UnknownPayload) -> Self {
+        Self::Unknown(Box::new(payload))
+    }
+}
+
 impl Payload {
     /// Returns the payload kind of a `Payload`.
     pub fn kind(&self) -> u32 {
@@ -92,6 +111,61 @@ impl Payload {
             Self::Milestone(_) => MilestonePayload::KIND,
             Self::TreasuryTransaction(_) => TreasuryTransactionPayload::KIND,
             Self::TaggedData(_) => TaggedDataPayload::KIND,
+            #[cfg(feature = "unknown_payloads")]
+            Self::Unknown(payload) => payload.kind(),
+        }
+    }
+
+    /// Returns the length of the packed `Payload` in bytes, including its kind.
+    pub fn packed_len(&self) -> usize {
+        PackableExt::packed_len(self)
+    }
+
+    /// Checks whether the payload is an [`UnknownPayload`].
+    #[cfg(feature = "unknown_payloads")]
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, Self::Unknown(_))
+    }
+
+    fn unpack_kind<U: Unpacker, const VERIFY: bool>(
+        kind: u32,
+        unpacker: &mut U,
+        visitor: &ProtocolParameters,
+    ) -> Result<Self, UnpackError<Error, U::Error>> {
+        Ok(match kind {
+            TransactionPayload::KIND => {
+                Self::from(TransactionPayload::unpack::<_, VERIFY>(unpacker, visitor).coerce()?)
+            }
+            MilestonePayload::KIND => Self::from(MilestonePayload::unpack::<_, VERIFY>(unpacker, visitor).coerce()?),
+            TreasuryTransactionPayload::KIND => {
+                if VERIFY {
+                    ProtocolFeature::Treasury
+                        .verify(visitor.protocol_version())
+                        .map_err(UnpackError::Packable)?;
+                }
+                Self::from(TreasuryTransactionPayload::unpack::<_, VERIFY>(unpacker, visitor).coerce()?)
+            }
+            TaggedDataPayload::KIND => Self::from(TaggedDataPayload::unpack::<_, VERIFY>(unpacker, &()).coerce()?),
+            k => return Err(UnpackError::Packable(Error::InvalidPayloadKind(k))),
+        })
+    }
+
+    // Only possible with a known length, the data of an unknown payload is the rest of the length prefixed payload.
+    #[cfg(feature = "unknown_payloads")]
+    fn unpack_with_len<U: Unpacker, const VERIFY: bool>(
+        unpacker: &mut U,
+        visitor: &ProtocolParameters,
+        len: usize,
+    ) -> Result<Self, UnpackError<Error, U::Error>> {
+        let kind = u32::unpack::<_, VERIFY>(unpacker, &()).coerce()?;
+
+        if UnknownPayload::is_known_kind(kind) {
+            Self::unpack_kind::<_, VERIFY>(kind, unpacker, visitor)
+        } else {
+            let mut data = alloc::vec![0u8; len.saturating_sub(core::mem::size_of::<u32>())];
+            unpacker.unpack_bytes(&mut data)?;
+
+            Ok(Self::from(UnknownPayload::new(kind, data).map_err(UnpackError::Packable)?))
         }
     }
 }
@@ -118,6 +192,11 @@ impl Packable for Payload {
                 TaggedDataPayload::KIND.pack(packer)?;
                 tagged_data.pack(packer)
             }
+            #[cfg(feature = "unknown_payloads")]
+            Self::Unknown(unknown) => {
+                unknown.kind().pack(packer)?;
+                packer.pack_bytes(unknown.data())
+            }
         }?;
 
         Ok(())
@@ -127,17 +206,9 @@ impl Packable for Payload {
         unpacker: &mut U,
         visitor: &Self::UnpackVisitor,
     ) -> Result<Self, UnpackError<Self::UnpackError, U::Error>> {
-        Ok(match u32::unpack::<_, VERIFY>(unpacker, &()).coerce()? {
-            TransactionPayload::KIND => {
-                Self::from(TransactionPayload::unpack::<_, VERIFY>(unpacker, visitor).coerce()?)
-            }
-            MilestonePayload::KIND => Self::from(MilestonePayload::unpack::<_, VERIFY>(unpacker, visitor).coerce()?),
-            TreasuryTransactionPayload::KIND => {
-                Self::from(TreasuryTransactionPayload::unpack::<_, VERIFY>(unpacker, visitor).coerce()?)
-            }
-            TaggedDataPayload::KIND => Self::from(TaggedDataPayload::unpack::<_, VERIFY>(unpacker, &()).coerce()?),
-            k => return Err(Error::InvalidPayloadKind(k)).map_err(UnpackError::Packable),
-        })
+        let kind = u32::unpack::<_, VERIFY>(unpacker, &()).coerce()?;
+
+        Self::unpack_kind::<_, VERIFY>(kind, unpacker, visitor)
     }
 }
 
@@ -196,7 +267,10 @@ impl Packable for OptionalPayload {
 
             let start_opt = unpacker.read_bytes();
 
+            #[cfg(not(feature = "unknown_payloads"))]
             let payload = Payload::unpack::<_, VERIFY>(unpacker, visitor)?;
+            #[cfg(feature = "unknown_payloads")]
+            let payload = Payload::unpack_with_len::<_, VERIFY>(unpacker, visitor, len)?;
 
             let actual_len = if let (Some(start), Some(end)) = (start_opt, unpacker.read_bytes()) {
                 end - start
@@ -227,6 +301,8 @@ pub mod dto {
         milestone::dto::MilestonePayloadDto, tagged_data::dto::TaggedDataPayloadDto,
         transaction::dto::TransactionPayloadDto, treasury_transaction::dto::TreasuryTransactionPayloadDto,
     };
+    #[cfg(feature = "unknown_payloads")]
+    pub use super::unknown::dto::UnknownPayloadDto;
     use crate::types::block::Error;
 
     /// Describes all the different payload types.
@@ -236,6 +312,9 @@ pub mod dto {
         Transaction(Box<TransactionPayloadDto>),
         Milestone(Box<MilestonePayloadDto>),
         TreasuryTransaction(Box<TreasuryTransactionPayloadDto>),
+        // Before the tagged data, which has optional fields and would match any unknown payload.
+        #[cfg(feature = "unknown_payloads")]
+        Unknown(Box<UnknownPayloadDto>),
         TaggedData(Box<TaggedDataPayloadDto>),
     }
 
@@ -263,6 +342,13 @@ pub mod dto {
         }
     }
 
+    #[cfg(feature = "unknown_payloads")]
+    impl From<UnknownPayloadDto> for PayloadDto {
+        fn from(payload: UnknownPayloadDto) -> Self {
+            Self::Unknown(Box::new(payload))
+        }
+    }
+
     impl From<&Payload> for PayloadDto {
         fn from(value: &Payload) -> Self {
             match value {
@@ -272,6 +358,8 @@ pub mod dto {
                     Self::TreasuryTransaction(Box::new(TreasuryTransactionPayloadDto::from(p.as_ref())))
                 }
                 Payload::TaggedData(p) => Self::TaggedData(Box::new(TaggedDataPayloadDto::from(p.as_ref()))),
+                #[cfg(feature = "unknown_payloads")]
+                Payload::Unknown(p) => Self::Unknown(Box::new(UnknownPayloadDto::from(p.as_ref()))),
             }
         }
     }
@@ -290,6 +378,8 @@ pub mod dto {
                     protocol_parameters.token_supply(),
                 )?),
                 PayloadDto::TaggedData(p) => Self::from(TaggedDataPayload::try_from(p.as_ref())?),
+                #[cfg(feature = "unknown_payloads")]
+                PayloadDto::Unknown(p) => Self::from(UnknownPayload::try_from(p.as_ref())?),
             })
         }
 
@@ -301,6 +391,8 @@ pub mod dto {
                     Self::from(TreasuryTransactionPayload::try_from_dto_unverified(p.as_ref())?)
                 }
                 PayloadDto::TaggedData(p) => Self::from(TaggedDataPayload::try_from(p.as_ref())?),
+                #[cfg(feature = "unknown_payloads")]
+                PayloadDto::Unknown(p) => Self::from(UnknownPayload::try_from(p.as_ref())?),
             })
         }
     }
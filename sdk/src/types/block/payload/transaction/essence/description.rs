@@ -0,0 +1,245 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use alloc::{string::String, vec::Vec};
+
+use crate::types::block::{
+    address::{Address, Bech32Address},
+    input::Input,
+    output::{NativeToken, Output, UnlockCondition},
+    payload::transaction::TransactionEssence,
+    protocol::ProtocolParameters,
+};
+
+/// A display friendly summary of a [`TransactionEssence`], e.g. to let a user review a transaction before signing it.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde(rename_all = "camelCase"))]
+pub struct TransactionEssenceDescription {
+    /// The network ID of the essence.
+    pub network_id: u64,
+    /// The inputs consumed by the transaction.
+    pub inputs: Vec<Input>,
+    /// The outputs created by the transaction.
+    pub outputs: Vec<OutputDescription>,
+}
+
+/// A display friendly summary of an [`Output`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde(rename_all = "camelCase"))]
+pub struct OutputDescription {
+    /// The kind of the output, e.g. `basic`.
+    pub kind: &'static str,
+    /// The address the output is sent to, not set for treasury outputs.
+    pub address: Option<Bech32Address>,
+    /// The amount of base coins of the output.
+    pub amount: u64,
+    /// The native tokens of the output.
+    pub native_tokens: Vec<NativeToken>,
+    /// The unlock conditions of the output.
+    pub unlock_conditions: Vec<UnlockConditionDescription>,
+}
+
+/// A display friendly summary of an [`UnlockCondition`], the [`Display`](core::fmt::Display) implementation
+/// describes it in plain words.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize),
+    serde(tag = "type", rename_all = "camelCase")
+)]
+pub enum UnlockConditionDescription {
+    /// The output can be unlocked by the address.
+    Address {
+        /// The address that can unlock the output.
+        address: Bech32Address,
+    },
+    /// The amount has to be returned to the address by the transaction that consumes the output.
+    StorageDepositReturn {
+        /// The address the amount has to be returned to.
+        #[cfg_attr(feature = "serde", serde(rename = "returnAddress"))]
+        return_address: Bech32Address,
+        /// The amount that has to be returned.
+        amount: u64,
+    },
+    /// The output can't be unlocked before the timestamp.
+    Timelock {
+        /// The unix timestamp in seconds until which the output is locked.
+        timestamp: u32,
+    },
+    /// The output can only be unlocked by the return address from the timestamp on.
+    Expiration {
+        /// The address that can unlock the output once it expired.
+        #[cfg_attr(feature = "serde", serde(rename = "returnAddress"))]
+        return_address: Bech32Address,
+        /// The unix timestamp in seconds at which the output expires.
+        timestamp: u32,
+    },
+    /// The state of the alias output is controlled by the address.
+    StateControllerAddress {
+        /// The state controller address.
+        address: Bech32Address,
+    },
+    /// The alias output is governed by the address.
+    GovernorAddress {
+        /// The governor address.
+        address: Bech32Address,
+    },
+    /// The foundry output is controlled by the alias address.
+    ImmutableAliasAddress {
+        /// The alias address.
+        address: Bech32Address,
+    },
+}
+
+impl TransactionEssence {
+    /// Returns a display friendly summary of the essence, addresses are bech32 encoded with the HRP of the protocol
+    /// parameters.
+    pub fn describe(&self, protocol_parameters: &ProtocolParameters) -> TransactionEssenceDescription {
+        let hrp = protocol_parameters.bech32_hrp();
+
+        match self {
+            Self::Regular(essence) => TransactionEssenceDescription {
+                network_id: essence.network_id(),
+                inputs: essence.inputs().to_vec(),
+                outputs: essence
+                    .outputs()
+                    .iter()
+                    .map(|output| OutputDescription::new(output, hrp))
+                    .collect(),
+            },
+        }
+    }
+}
+
+impl OutputDescription {
+    /// Creates the summary of an output, addresses are bech32 encoded with the HRP.
+    pub fn new(output: &Output, hrp: &str) -> Self {
+        let kind = match output {
+            Output::Treasury(_) => "treasury",
+            Output::Basic(_) => "basic",
+            Output::Alias(_) => "alias",
+            Output::Foundry(_) => "foundry",
+            Output::Nft(_) => "nft",
+        };
+        let unlock_conditions = output
+            .unlock_conditions()
+            .map(|unlock_conditions| {
+                unlock_conditions
+                    .iter()
+                    .map(|unlock_condition| UnlockConditionDescription::new(unlock_condition, hrp))
+                    .collect::<Vec<_>>()
+            })
+            .unwrap_or_default();
+        // The address of the output is the one that can unlock it, or the one controlling it for chain outputs
+        let address = unlock_conditions.iter().find_map(|unlock_condition| match unlock_condition {
+            UnlockConditionDescription::Address { address }
+            | UnlockConditionDescription::StateControllerAddress { address }
+            | UnlockConditionDescription::ImmutableAliasAddress { address } => Some(address.clone()),
+            _ => None,
+        });
+
+        Self {
+            kind,
+            address,
+            amount: output.amount(),
+            native_tokens: output
+                .native_tokens()
+                .map(|native_tokens| native_tokens.iter().copied().collect())
+                .unwrap_or_default(),
+            unlock_conditions,
+        }
+    }
+}
+
+impl UnlockConditionDescription {
+    /// Creates the summary of an unlock condition, addresses are bech32 encoded with the HRP.
+    pub fn new(unlock_condition: &UnlockCondition, hrp: &str) -> Self {
+        match unlock_condition {
+            UnlockCondition::Address(unlock_condition) => Self::Address {
+                address: bech32_address(unlock_condition.address(), hrp),
+            },
+            UnlockCondition::StorageDepositReturn(unlock_condition) => Self::StorageDepositReturn {
+                return_address: bech32_address(unlock_condition.return_address(), hrp),
+                amount: unlock_condition.amount(),
+            },
+            UnlockCondition::Timelock(unlock_condition) => Self::Timelock {
+                timestamp: unlock_condition.timestamp(),
+            },
+            UnlockCondition::Expiration(unlock_condition) => Self::Expiration {
+                return_address: bech32_address(unlock_condition.return_address(), hrp),
+                timestamp: unlock_condition.timestamp(),
+            },
+            UnlockCondition::StateControllerAddress(unlock_condition) => Self::StateControllerAddress {
+                address: bech32_address(unlock_condition.address(), hrp),
+            },
+            UnlockCondition::GovernorAddress(unlock_condition) => Self::GovernorAddress {
+                address: bech32_address(unlock_condition.address(), hrp),
+            },
+            UnlockCondition::ImmutableAliasAddress(unlock_condition) => Self::ImmutableAliasAddress {
+                address: bech32_address(unlock_condition.address(), hrp),
+            },
+        }
+    }
+}
+
+fn bech32_address(address: &Address, hrp: &str) -> Bech32Address {
+    Bech32Address {
+        hrp: String::from(hrp),
+        inner: *address,
+    }
+}
+
+impl core::fmt::Display for TransactionEssenceDescription {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        writeln!(f, "Inputs:")?;
+        for input in &self.inputs {
+            match input {
+                Input::Utxo(input) => writeln!(f, "- output {input}")?,
+                Input::Treasury(input) => writeln!(f, "- treasury of milestone {input}")?,
+            }
+        }
+        writeln!(f, "Outputs:")?;
+        for output in &self.outputs {
+            writeln!(f, "- {output}")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl core::fmt::Display for OutputDescription {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{} output of {}", self.kind, self.amount)?;
+        if let Some(address) = &self.address {
+            write!(f, " to {address}")?;
+        }
+        for native_token in &self.native_tokens {
+            write!(f, ", {} of native token {}", native_token.amount(), native_token.token_id())?;
+        }
+        for unlock_condition in &self.unlock_conditions {
+            write!(f, ", {unlock_condition}")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl core::fmt::Display for UnlockConditionDescription {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Address { address } => write!(f, "unlockable by {address}"),
+            Self::StorageDepositReturn {
+                return_address,
+                amount,
+            } => write!(f, "{amount} have to be returned to {return_address}"),
+            Self::Timelock { timestamp } => write!(f, "locked until unix timestamp {timestamp}"),
+            Self::Expiration {
+                return_address,
+                timestamp,
+            } => write!(f, "returned to {return_address} at unix timestamp {timestamp}"),
+            Self::StateControllerAddress { address } => write!(f, "state controlled by {address}"),
+            Self::GovernorAddress { address } => write!(f, "governed by {address}"),
+            Self::ImmutableAliasAddress { address } => write!(f, "controlled by alias {address}"),
+        }
+    }
+}
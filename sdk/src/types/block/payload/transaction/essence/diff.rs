@@ -0,0 +1,234 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+
+use crate::types::block::{
+    address::Bech32Address,
+    input::Input,
+    output::Output,
+    payload::transaction::{OutputDescription, TransactionEssence, UnlockConditionDescription},
+    protocol::ProtocolParameters,
+};
+
+/// The differences between two [`TransactionEssence`]s, e.g. between an approved essence and the one that got prepared
+/// again after the data of the node changed. The [`Display`](core::fmt::Display) implementation describes them in plain
+/// words.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde(rename_all = "camelCase"))]
+pub struct TransactionEssenceDiff {
+    /// The network IDs of both essences, if they differ.
+    pub network_id: Option<(u64, u64)>,
+    /// Inputs that are only consumed by the first essence.
+    pub removed_inputs: Vec<Input>,
+    /// Inputs that are only consumed by the second essence.
+    pub added_inputs: Vec<Input>,
+    /// Outputs that differ at the same index of both essences.
+    pub changed_outputs: Vec<OutputDiff>,
+    /// Outputs that are only created by the first essence, because it has more outputs.
+    pub removed_outputs: Vec<OutputDescription>,
+    /// Outputs that are only created by the second essence, because it has more outputs.
+    pub added_outputs: Vec<OutputDescription>,
+    /// Whether the payloads embedded in the essences differ.
+    pub payload_changed: bool,
+}
+
+/// The differences between the outputs at the same index of two essences.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde(rename_all = "camelCase"))]
+pub struct OutputDiff {
+    /// The index of the outputs in the essences.
+    pub index: usize,
+    /// The output of the first essence.
+    pub before: OutputDescription,
+    /// The output of the second essence.
+    pub after: OutputDescription,
+    /// Unlock conditions that only the output of the first essence has.
+    pub removed_unlock_conditions: Vec<UnlockConditionDescription>,
+    /// Unlock conditions that only the output of the second essence has.
+    pub added_unlock_conditions: Vec<UnlockConditionDescription>,
+    /// Whether fields that aren't described differ, like the features, the chain ID or the state of an alias output.
+    pub other_fields_changed: bool,
+}
+
+impl TransactionEssence {
+    /// Returns the differences to another essence. Inputs are compared regardless of their order, outputs by their
+    /// index. Addresses are bech32 encoded with the HRP of the protocol parameters.
+    pub fn diff(&self, other: &Self, protocol_parameters: &ProtocolParameters) -> TransactionEssenceDiff {
+        let hrp = protocol_parameters.bech32_hrp();
+        let (Self::Regular(essence), Self::Regular(other_essence)) = (self, other);
+        let (outputs, other_outputs) = (essence.outputs(), other_essence.outputs());
+        let network_id = (essence.network_id() != other_essence.network_id())
+            .then_some((essence.network_id(), other_essence.network_id()));
+
+        TransactionEssenceDiff {
+            network_id,
+            removed_inputs: essence
+                .inputs()
+                .iter()
+                .filter(|input| !other_essence.inputs().contains(input))
+                .cloned()
+                .collect(),
+            added_inputs: other_essence
+                .inputs()
+                .iter()
+                .filter(|input| !essence.inputs().contains(input))
+                .cloned()
+                .collect(),
+            changed_outputs: outputs
+                .iter()
+                .zip(other_outputs)
+                .enumerate()
+                .filter(|(_, (output, other_output))| output != other_output)
+                .map(|(index, (output, other_output))| OutputDiff::new(index, output, other_output, hrp))
+                .collect(),
+            removed_outputs: outputs
+                .iter()
+                .skip(other_outputs.len())
+                .map(|output| OutputDescription::new(output, hrp))
+                .collect(),
+            added_outputs: other_outputs
+                .iter()
+                .skip(outputs.len())
+                .map(|output| OutputDescription::new(output, hrp))
+                .collect(),
+            payload_changed: essence.payload() != other_essence.payload(),
+        }
+    }
+}
+
+impl TransactionEssenceDiff {
+    /// Returns true if the essences don't differ.
+    pub fn is_empty(&self) -> bool {
+        self.network_id.is_none()
+            && self.removed_inputs.is_empty()
+            && self.added_inputs.is_empty()
+            && self.changed_outputs.is_empty()
+            && self.removed_outputs.is_empty()
+            && self.added_outputs.is_empty()
+            && !self.payload_changed
+    }
+}
+
+impl OutputDiff {
+    /// Creates the differences between two outputs at the same index, addresses are bech32 encoded with the HRP.
+    pub fn new(index: usize, output: &Output, other_output: &Output, hrp: &str) -> Self {
+        let before = OutputDescription::new(output, hrp);
+        let after = OutputDescription::new(other_output, hrp);
+        let removed_unlock_conditions = before
+            .unlock_conditions
+            .iter()
+            .filter(|unlock_condition| !after.unlock_conditions.contains(unlock_condition))
+            .cloned()
+            .collect();
+        let added_unlock_conditions = after
+            .unlock_conditions
+            .iter()
+            .filter(|unlock_condition| !before.unlock_conditions.contains(unlock_condition))
+            .cloned()
+            .collect();
+        let state_changed = match (output, other_output) {
+            (Output::Alias(alias), Output::Alias(other_alias)) => {
+                alias.state_index() != other_alias.state_index()
+                    || alias.state_metadata() != other_alias.state_metadata()
+                    || alias.foundry_counter() != other_alias.foundry_counter()
+            }
+            (Output::Foundry(foundry), Output::Foundry(other_foundry)) => {
+                foundry.token_scheme() != other_foundry.token_scheme()
+            }
+            _ => false,
+        };
+
+        Self {
+            index,
+            before,
+            after,
+            removed_unlock_conditions,
+            added_unlock_conditions,
+            other_fields_changed: state_changed
+                || output.features() != other_output.features()
+                || output.immutable_features() != other_output.immutable_features()
+                || output.chain_id() != other_output.chain_id(),
+        }
+    }
+}
+
+impl core::fmt::Display for TransactionEssenceDiff {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if self.is_empty() {
+            return writeln!(f, "No differences");
+        }
+        if let Some((network_id, other_network_id)) = self.network_id {
+            writeln!(f, "- network ID changed from {network_id} to {other_network_id}")?;
+        }
+        for input in &self.removed_inputs {
+            write_input(f, input, "removed")?;
+        }
+        for input in &self.added_inputs {
+            write_input(f, input, "added")?;
+        }
+        for output_diff in &self.changed_outputs {
+            writeln!(f, "- {output_diff}")?;
+        }
+        for output in &self.removed_outputs {
+            writeln!(f, "- {output} removed")?;
+        }
+        for output in &self.added_outputs {
+            writeln!(f, "- {output} added")?;
+        }
+        if self.payload_changed {
+            writeln!(f, "- payload changed")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl core::fmt::Display for OutputDiff {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "output {}:", self.index)?;
+        let mut changes = Vec::new();
+        if self.before.kind != self.after.kind {
+            changes.push(format!("kind changed from {} to {}", self.before.kind, self.after.kind));
+        }
+        if self.before.address != self.after.address {
+            changes.push(format!(
+                "address changed from {} to {}",
+                describe_address(self.before.address.as_ref()),
+                describe_address(self.after.address.as_ref())
+            ));
+        }
+        if self.before.amount != self.after.amount {
+            changes.push(format!("amount changed from {} to {}", self.before.amount, self.after.amount));
+        }
+        if self.before.native_tokens != self.after.native_tokens {
+            changes.push("native tokens changed".to_string());
+        }
+        for unlock_condition in &self.removed_unlock_conditions {
+            changes.push(format!("no longer {unlock_condition}"));
+        }
+        for unlock_condition in &self.added_unlock_conditions {
+            changes.push(format!("now {unlock_condition}"));
+        }
+        if self.other_fields_changed {
+            changes.push("features or state changed".to_string());
+        }
+
+        write!(f, " {}", changes.join(", "))
+    }
+}
+
+fn write_input(f: &mut core::fmt::Formatter<'_>, input: &Input, change: &str) -> core::fmt::Result {
+    match input {
+        Input::Utxo(input) => writeln!(f, "- input {input} {change}"),
+        Input::Treasury(input) => writeln!(f, "- treasury input of milestone {input} {change}"),
+    }
+}
+
+fn describe_address(address: Option<&Bech32Address>) -> String {
+    address.map_or_else(|| "no address".to_string(), ToString::to_string)
+}
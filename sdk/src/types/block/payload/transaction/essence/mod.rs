@@ -1,15 +1,19 @@
 // Copyright 2020-2021 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+mod description;
+mod diff;
 mod regular;
 
-use crypto::hashes::{blake2b::Blake2b256, Digest};
 use derive_more::From;
-use packable::PackableExt;
 
 pub(crate) use self::regular::{InputCount, OutputCount};
-pub use self::regular::{RegularTransactionEssence, RegularTransactionEssenceBuilder};
-use crate::types::block::Error;
+pub use self::{
+    description::{OutputDescription, TransactionEssenceDescription, UnlockConditionDescription},
+    diff::{OutputDiff, TransactionEssenceDiff},
+    regular::{RegularTransactionEssence, RegularTransactionEssenceBuilder},
+};
+use crate::types::block::{helper::blake2b256_packed, Error};
 
 /// A generic essence that can represent different types defining transaction essences.
 #[derive(Clone, Debug, Eq, PartialEq, From, packable::Packable)]
@@ -34,9 +38,9 @@ impl TransactionEssence {
         }
     }
 
-    /// Return the Blake2b hash of an [`TransactionEssence`].
+    /// Return the Blake2b hash of an [`TransactionEssence`], the message signed by the unlocks of a transaction.
     pub fn hash(&self) -> [u8; 32] {
-        Blake2b256::digest(self.pack_to_vec()).into()
+        blake2b256_packed(self)
     }
 }
 
@@ -6,15 +6,29 @@
 mod essence;
 mod transaction_id;
 
+use alloc::vec::Vec;
+
 use crypto::hashes::{blake2b::Blake2b256, Digest};
-use packable::{error::UnpackError, packer::Packer, unpacker::Unpacker, Packable, PackableExt};
+use hashbrown::HashMap;
+use packable::{error::UnpackError, packer::Packer, unpacker::Unpacker, Packable};
 
 pub(crate) use self::essence::{InputCount, OutputCount};
 pub use self::{
-    essence::{RegularTransactionEssence, RegularTransactionEssenceBuilder, TransactionEssence},
+    essence::{
+        OutputDescription, OutputDiff, RegularTransactionEssence, RegularTransactionEssenceBuilder,
+        TransactionEssence, TransactionEssenceDescription, TransactionEssenceDiff, UnlockConditionDescription,
+    },
     transaction_id::TransactionId,
 };
-use crate::types::block::{protocol::ProtocolParameters, unlock::Unlocks, Error};
+use crate::types::block::{
+    address::Address,
+    helper::update_packed,
+    output::Output,
+    protocol::ProtocolParameters,
+    signature::{Ed25519Signature, Signature},
+    unlock::{AliasUnlock, NftUnlock, ReferenceUnlock, SignatureUnlock, Unlock, Unlocks},
+    Error,
+};
 
 /// A transaction to move funds.
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -35,6 +49,42 @@ impl TransactionPayload {
         Ok(Self { essence, unlocks })
     }
 
+    /// Creates a new [`TransactionPayload`] from an essence and the signatures of its hash, one per input and in the
+    /// order of the inputs. Signatures of a public key that already signed a previous input are replaced by reference
+    /// unlocks. Inputs that need alias or NFT unlocks aren't supported, their unlocks can be built for
+    /// [`TransactionPayload::new()`].
+    pub fn from_essence_and_signatures(
+        essence: TransactionEssence,
+        signatures: Vec<Ed25519Signature>,
+    ) -> Result<Self, Error> {
+        let TransactionEssence::Regular(ref regular) = essence;
+
+        if regular.inputs().len() != signatures.len() {
+            return Err(Error::InputUnlockCountMismatch {
+                input_count: regular.inputs().len(),
+                unlock_count: signatures.len(),
+            });
+        }
+
+        let essence_hash = essence.hash();
+        let mut signature_indexes = HashMap::<[u8; Ed25519Signature::PUBLIC_KEY_LENGTH], u16>::new();
+        let mut unlocks = Vec::with_capacity(signatures.len());
+
+        for (index, signature) in signatures.into_iter().enumerate() {
+            match signature_indexes.get(signature.public_key()) {
+                Some(signature_index) => unlocks.push(Unlock::Reference(ReferenceUnlock::new(*signature_index)?)),
+                None => {
+                    signature.is_valid(&essence_hash, &signature.address())?;
+                    // The index fits as the signature count is the input count.
+                    signature_indexes.insert(*signature.public_key(), index as u16);
+                    unlocks.push(Unlock::Signature(SignatureUnlock::new(Signature::from(signature))));
+                }
+            }
+        }
+
+        Self::new(essence, Unlocks::new(unlocks)?)
+    }
+
     /// Return the essence of a [`TransactionPayload`].
     pub fn essence(&self) -> &TransactionEssence {
         &self.essence
@@ -50,7 +100,7 @@ impl TransactionPayload {
         let mut hasher = Blake2b256::new();
 
         hasher.update(Self::KIND.to_le_bytes());
-        hasher.update(self.pack_to_vec());
+        update_packed(&mut hasher, self);
 
         TransactionId::new(hasher.finalize().into())
     }
@@ -97,6 +147,77 @@ fn verify_essence_unlocks(essence: &TransactionEssence, unlocks: &Unlocks) -> Re
     Ok(())
 }
 
+/// Computes the [`Unlocks`] of an essence from the outputs consumed by its inputs and the addresses that unlock them,
+/// both in the order of the inputs.
+///
+/// The first input of an Ed25519 address gets a signature unlock with the signature of the essence hash by this
+/// address, the following inputs of this address get reference unlocks. Inputs of an alias or NFT address get alias or
+/// NFT unlocks referencing the input that consumes the alias or NFT output, which therefore needs to be a previous
+/// input.
+pub fn compute_unlocks(
+    essence: &TransactionEssence,
+    inputs: &[(&Output, Address)],
+    signatures: &[Ed25519Signature],
+) -> Result<Unlocks, Error> {
+    let TransactionEssence::Regular(regular) = essence;
+
+    if regular.inputs().len() != inputs.len() {
+        return Err(Error::InputUnlockCountMismatch {
+            input_count: regular.inputs().len(),
+            unlock_count: inputs.len(),
+        });
+    }
+
+    let essence_hash = essence.hash();
+    let mut unlock_indexes = HashMap::<Address, u16>::new();
+    let mut unlocks = Vec::with_capacity(inputs.len());
+
+    for (index, (input, (output, address))) in regular.inputs().iter().zip(inputs).enumerate() {
+        // The index fits as the input count is bounded.
+        let index = index as u16;
+
+        match unlock_indexes.get(address) {
+            Some(unlock_index) => unlocks.push(match address {
+                Address::Ed25519(_) => Unlock::Reference(ReferenceUnlock::new(*unlock_index)?),
+                Address::Alias(_) => Unlock::Alias(AliasUnlock::new(*unlock_index)?),
+                Address::Nft(_) => Unlock::Nft(NftUnlock::new(*unlock_index)?),
+            }),
+            None => {
+                let Address::Ed25519(ed25519_address) = address else {
+                    return Err(Error::UnresolvedUnlockAddress(*address));
+                };
+                let signature = signatures
+                    .iter()
+                    .find(|signature| signature.address() == *ed25519_address)
+                    .ok_or(Error::MissingSignature(*ed25519_address))?;
+
+                signature.is_valid(&essence_hash, ed25519_address)?;
+                unlocks.push(Unlock::from(SignatureUnlock::from(Signature::from(signature.clone()))));
+                unlock_indexes.insert(*address, index);
+            }
+        }
+
+        // Alias and NFT outputs unlock the following inputs of their address.
+        // PANIC: the inputs of a regular essence are UTXO inputs.
+        let output_id = input.as_utxo().output_id();
+        match output {
+            Output::Alias(alias_output) => {
+                unlock_indexes.insert(Address::Alias(alias_output.alias_address(output_id)), index);
+            }
+            Output::Nft(nft_output) => {
+                unlock_indexes.insert(Address::Nft(nft_output.nft_address(output_id)), index);
+            }
+            _ => {}
+        }
+    }
+
+    let unlocks = Unlocks::new(unlocks)?;
+
+    verify_essence_unlocks(essence, &unlocks)?;
+
+    Ok(unlocks)
+}
+
 #[allow(missing_docs)]
 pub mod dto {
     use alloc::vec::Vec;
@@ -35,12 +35,8 @@ impl TreasuryTransactionPayload {
 
     /// Returns the input of a [`TreasuryTransactionPayload`].
     pub fn input(&self) -> &TreasuryInput {
-        if let Input::Treasury(ref input) = self.input {
-            input
-        } else {
-            // It has already been validated at construction that `input` is a `TreasuryInput`.
-            unreachable!()
-        }
+        // It has already been validated at construction that `input` is a `TreasuryInput`.
+        self.input.as_treasury()
     }
 
     /// Returns the output of a [`TreasuryTransactionPayload`].
@@ -98,6 +94,11 @@ pub mod dto {
 
     impl TreasuryTransactionPayload {
         fn _try_from_dto(value: &TreasuryTransactionPayloadDto, output: TreasuryOutput) -> Result<Self, Error> {
+            // The payload DTO is untagged, so the kind needs to be checked explicitly.
+            if value.kind != Self::KIND {
+                return Err(Error::InvalidPayloadKind(value.kind));
+            }
+
             Self::new(
                 if let InputDto::Treasury(ref input) = value.input {
                     input.try_into()?
@@ -0,0 +1,104 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Module describing payloads of unknown kinds.
+
+use alloc::vec::Vec;
+
+use crate::types::block::{
+    payload::{MilestonePayload, TaggedDataPayload, TransactionPayload, TreasuryTransactionPayload},
+    Error,
+};
+
+/// A payload of a kind that is unknown to this version of the library, e.g. introduced by a future protocol version.
+/// The payload is kept as opaque bytes, so blocks containing it can still be parsed and packed again unchanged.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct UnknownPayload {
+    kind: u32,
+    data: Vec<u8>,
+}
+
+impl UnknownPayload {
+    /// Creates a new [`UnknownPayload`], the kind must not be one of the known payload kinds.
+    pub fn new(kind: u32, data: impl Into<Vec<u8>>) -> Result<Self, Error> {
+        if Self::is_known_kind(kind) {
+            return Err(Error::InvalidPayloadKind(kind));
+        }
+
+        Ok(Self {
+            kind,
+            data: data.into(),
+        })
+    }
+
+    /// Returns true if the payload kind is known to this version of the library.
+    pub fn is_known_kind(kind: u32) -> bool {
+        matches!(
+            kind,
+            TransactionPayload::KIND
+                | MilestonePayload::KIND
+                | TreasuryTransactionPayload::KIND
+                | TaggedDataPayload::KIND
+        )
+    }
+
+    /// Returns the payload kind of an [`UnknownPayload`].
+    pub fn kind(&self) -> u32 {
+        self.kind
+    }
+
+    /// Returns the opaque data of an [`UnknownPayload`], the packed payload without its kind.
+    pub fn data(&self) -> &[u8] {
+        &self.data
+    }
+}
+
+#[allow(missing_docs)]
+pub mod dto {
+    use alloc::string::String;
+
+    use serde::{de, Deserialize, Deserializer, Serialize};
+
+    use super::*;
+    use crate::types::block::Error;
+
+    /// The payload type to define a payload of an unknown kind.
+    #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+    pub struct UnknownPayloadDto {
+        // The payload DTO is untagged, so known kinds must be rejected to not shadow their actual DTOs.
+        #[serde(rename = "type", deserialize_with = "deserialize_unknown_kind")]
+        pub kind: u32,
+        pub data: String,
+    }
+
+    fn deserialize_unknown_kind<'de, D: Deserializer<'de>>(deserializer: D) -> Result<u32, D::Error> {
+        let kind = u32::deserialize(deserializer)?;
+
+        if UnknownPayload::is_known_kind(kind) {
+            Err(de::Error::custom("known payload kind"))
+        } else {
+            Ok(kind)
+        }
+    }
+
+    impl From<&UnknownPayload> for UnknownPayloadDto {
+        fn from(value: &UnknownPayload) -> Self {
+            Self {
+                kind: value.kind(),
+                data: prefix_hex::encode(value.data()),
+            }
+        }
+    }
+
+    impl TryFrom<&UnknownPayloadDto> for UnknownPayload {
+        type Error = Error;
+
+        fn try_from(value: &UnknownPayloadDto) -> Result<Self, Self::Error> {
+            Self::new(
+                value.kind,
+                prefix_hex::decode::<Vec<u8>>(&value.data).map_err(|_| Error::InvalidField("data"))?,
+            )
+        }
+    }
+}
@@ -2,12 +2,68 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use alloc::string::String;
-use core::borrow::Borrow;
+use core::{borrow::Borrow, fmt};
 
 use packable::{prefix::StringPrefix, Packable};
 
 use crate::types::block::{helper::network_name_to_id, output::RentStructure, Error, PROTOCOL_VERSION};
 
+/// The protocol versions blocks can be packed and unpacked for. Version 3 no longer has the treasury of the Chrysalis
+/// migration.
+pub const SUPPORTED_PROTOCOL_VERSIONS: [u8; 2] = [2, 3];
+
+/// Parts of the protocol that are not available in all supported protocol versions.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ProtocolFeature {
+    /// The treasury of the Chrysalis migration, with treasury transaction payloads and receipt milestone options.
+    Treasury,
+}
+
+impl ProtocolFeature {
+    /// Returns true if the feature is available in the protocol version.
+    pub fn is_available(&self, protocol_version: u8) -> bool {
+        match self {
+            Self::Treasury => protocol_version == 2,
+        }
+    }
+
+    /// Returns an error if the feature is not available in the protocol version.
+    pub fn verify(&self, protocol_version: u8) -> Result<(), Error> {
+        if self.is_available(protocol_version) {
+            Ok(())
+        } else {
+            Err(Error::UnavailableProtocolFeature {
+                feature: *self,
+                protocol_version,
+            })
+        }
+    }
+}
+
+impl fmt::Display for ProtocolFeature {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Treasury => write!(f, "treasury"),
+        }
+    }
+}
+
+/// Returns an error if the protocol version is not one of the [`SUPPORTED_PROTOCOL_VERSIONS`].
+pub fn verify_protocol_version(protocol_version: u8) -> Result<(), Error> {
+    if SUPPORTED_PROTOCOL_VERSIONS.contains(&protocol_version) {
+        Ok(())
+    } else {
+        Err(Error::UnsupportedProtocolVersion(protocol_version))
+    }
+}
+
+const IOTA_MAINNET_NETWORK_NAME: &str = "iota-mainnet";
+const SHIMMER_NETWORK_NAME: &str = "shimmer";
+const TESTNET_NETWORK_NAME: &str = "testnet";
+
+/// The names of the networks with preset [`ProtocolParameters`], see [`ProtocolParameters::from_network_name()`].
+pub const KNOWN_NETWORK_NAMES: [&str; 3] = [IOTA_MAINNET_NETWORK_NAME, SHIMMER_NETWORK_NAME, TESTNET_NETWORK_NAME];
+
 /// Defines the parameters of the protocol.
 #[derive(Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Packable)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -47,17 +103,7 @@ impl Borrow<()> for ProtocolParameters {
 
 impl Default for ProtocolParameters {
     fn default() -> Self {
-        // PANIC: These values are known to be correct.
-        Self::new(
-            PROTOCOL_VERSION,
-            String::from("shimmer"),
-            String::from("smr"),
-            1500,
-            15,
-            RentStructure::default(),
-            1_813_620_509_061_365,
-        )
-        .unwrap()
+        Self::shimmer()
     }
 }
 
@@ -83,11 +129,77 @@ impl ProtocolParameters {
         })
     }
 
+    /// Returns the [`ProtocolParameters`] of the IOTA mainnet.
+    pub fn mainnet() -> Self {
+        // PANIC: These values are known to be correct.
+        Self::new(
+            PROTOCOL_VERSION,
+            String::from(IOTA_MAINNET_NETWORK_NAME),
+            String::from("iota"),
+            1500,
+            15,
+            RentStructure::new(250, 10, 1),
+            4_600_000_000_000_000,
+        )
+        .unwrap()
+    }
+
+    /// Returns the [`ProtocolParameters`] of the Shimmer mainnet.
+    pub fn shimmer() -> Self {
+        // PANIC: These values are known to be correct.
+        Self::new(
+            PROTOCOL_VERSION,
+            String::from(SHIMMER_NETWORK_NAME),
+            String::from("smr"),
+            1500,
+            15,
+            RentStructure::new(100, 10, 1),
+            1_813_620_509_061_365,
+        )
+        .unwrap()
+    }
+
+    /// Returns the [`ProtocolParameters`] of the public Shimmer testnet.
+    pub fn testnet() -> Self {
+        // PANIC: These values are known to be correct.
+        Self::new(
+            PROTOCOL_VERSION,
+            String::from(TESTNET_NETWORK_NAME),
+            String::from("rms"),
+            1500,
+            15,
+            RentStructure::new(100, 10, 1),
+            1_813_620_509_061_365,
+        )
+        .unwrap()
+    }
+
+    /// Returns the preset [`ProtocolParameters`] of a network in [`KNOWN_NETWORK_NAMES`], `None` for other networks.
+    /// The parameters of a network can change with a protocol upgrade, the ones of the node take precedence.
+    pub fn from_network_name(network_name: &str) -> Option<Self> {
+        match network_name {
+            IOTA_MAINNET_NETWORK_NAME => Some(Self::mainnet()),
+            SHIMMER_NETWORK_NAME => Some(Self::shimmer()),
+            TESTNET_NETWORK_NAME => Some(Self::testnet()),
+            _ => None,
+        }
+    }
+
     /// Returns the protocol version of the [`ProtocolParameters`].
     pub fn protocol_version(&self) -> u8 {
         self.protocol_version
     }
 
+    /// Returns true if the protocol version of the [`ProtocolParameters`] is supported.
+    pub fn is_supported_version(&self) -> bool {
+        verify_protocol_version(self.protocol_version).is_ok()
+    }
+
+    /// Returns true if the feature is available in the protocol version of the [`ProtocolParameters`].
+    pub fn is_available(&self, feature: ProtocolFeature) -> bool {
+        feature.is_available(self.protocol_version)
+    }
+
     /// Returns the network name of the [`ProtocolParameters`].
     pub fn network_name(&self) -> &str {
         &self.network_name
@@ -8,8 +8,9 @@ use primitive_types::U256;
 
 use crate::types::block::{
     address::Address,
+    input::Input,
     output::{ChainId, FoundryId, InputsCommitment, NativeTokens, Output, OutputId, TokenId},
-    payload::transaction::{RegularTransactionEssence, TransactionEssence, TransactionId},
+    payload::transaction::{RegularTransactionEssence, TransactionEssence, TransactionId, TransactionPayload},
     unlock::Unlocks,
     Error,
 };
@@ -368,3 +369,37 @@ pub fn semantic_validation(
 
     Ok(ConflictReason::None)
 }
+
+/// Validates a transaction payload like a node does when a milestone with the timestamp confirms it, against the
+/// outputs it consumes, in the order of the inputs of its essence. This covers the unlocks, the amounts and native
+/// tokens, the state transitions of the chains and the timelocks and expirations. Returns the [`ConflictReason`] the
+/// node would reject the transaction with, [`ConflictReason::None`] if it's valid.
+pub fn validate_transaction_payload(
+    transaction_payload: &TransactionPayload,
+    inputs: &[(OutputId, &Output)],
+    milestone_timestamp: u32,
+) -> Result<ConflictReason, Error> {
+    let TransactionEssence::Regular(essence) = transaction_payload.essence();
+
+    // The consumed outputs need to be the ones the inputs reference, in the same order.
+    if essence.inputs().len() != inputs.len()
+        || essence
+            .inputs()
+            .iter()
+            .zip(inputs)
+            .any(|(input, (output_id, _))| !matches!(input, Input::Utxo(utxo) if utxo.output_id() == output_id))
+    {
+        return Ok(ConflictReason::InputUtxoNotFound);
+    }
+
+    let transaction_id = transaction_payload.id();
+    let context = ValidationContext::new(
+        &transaction_id,
+        essence,
+        inputs.iter().map(|(output_id, output)| (output_id, *output)),
+        transaction_payload.unlocks(),
+        milestone_timestamp,
+    );
+
+    semantic_validation(context, inputs, transaction_payload.unlocks())
+}
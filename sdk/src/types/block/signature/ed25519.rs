@@ -1,12 +1,9 @@
 // Copyright 2020-2021 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use core::{fmt, ops::Deref};
+use core::fmt;
 
-use crypto::{
-    hashes::{blake2b::Blake2b256, Digest},
-    signatures::ed25519::{PublicKey, Signature, PUBLIC_KEY_LENGTH, SIGNATURE_LENGTH},
-};
+use crypto::signatures::ed25519::{PublicKey, Signature};
 
 use crate::types::block::{address::Ed25519Address, Error};
 
@@ -23,9 +20,9 @@ impl Ed25519Signature {
     /// The signature kind of an [`Ed25519Signature`].
     pub const KIND: u8 = 0;
     /// Length of an ED25519 public key.
-    pub const PUBLIC_KEY_LENGTH: usize = PUBLIC_KEY_LENGTH;
+    pub const PUBLIC_KEY_LENGTH: usize = PublicKey::LENGTH;
     /// Length of an ED25519 signature.
-    pub const SIGNATURE_LENGTH: usize = SIGNATURE_LENGTH;
+    pub const SIGNATURE_LENGTH: usize = Signature::LENGTH;
 
     /// Creates a new [`Ed25519Signature`].
     pub fn new(public_key: [u8; Self::PUBLIC_KEY_LENGTH], signature: [u8; Self::SIGNATURE_LENGTH]) -> Self {
@@ -42,14 +39,19 @@ impl Ed25519Signature {
         &self.signature
     }
 
+    /// Returns the [`Ed25519Address`] of the public key of an [`Ed25519Signature`].
+    pub fn address(&self) -> Ed25519Address {
+        Ed25519Address::from_public_key_bytes(self.public_key)
+    }
+
     /// Verifies the [`Ed25519Signature`] for a message against an [`Ed25519Address`].
     pub fn is_valid(&self, message: &[u8], address: &Ed25519Address) -> Result<(), Error> {
-        let signature_address: [u8; PUBLIC_KEY_LENGTH] = Blake2b256::digest(self.public_key).into();
+        let signature_address = self.address();
 
-        if address.deref() != &signature_address {
+        if address != &signature_address {
             return Err(Error::SignaturePublicKeyMismatch {
                 expected: prefix_hex::encode(address.as_ref()),
-                actual: prefix_hex::encode(signature_address),
+                actual: prefix_hex::encode(signature_address.as_ref()),
             });
         }
 
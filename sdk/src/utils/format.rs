@@ -0,0 +1,83 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Hooks to format amounts and timestamps in texts for humans, like the description of a transaction summary.
+//!
+//! Applications can implement [`Formatter`] to plug in the number and date formatting of their locale, e.g. from ICU,
+//! instead of post-processing the texts. [`DefaultFormatter`] covers the separators of common locales with
+//! [`AmountFormat::for_locale()`] and writes timestamps as ISO 8601 dates in UTC.
+
+use alloc::{format, string::String};
+
+use crate::utils::unit::{format_amount, AmountFormat, Unit};
+
+/// Formats amounts and timestamps in texts for humans.
+pub trait Formatter {
+    /// Formats an amount of base units of the base coin.
+    fn format_amount(&self, amount: u64) -> String;
+
+    /// Formats a unix timestamp in milliseconds, as ISO 8601 date in UTC like `2023-04-05T12:30:00Z` by default.
+    fn format_timestamp(&self, timestamp_millis: u128) -> String {
+        to_iso_8601(timestamp_millis)
+    }
+}
+
+/// Formats amounts as decimal number of a unit with [`format_amount()`] and timestamps as ISO 8601 dates in UTC.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct DefaultFormatter {
+    /// The unit amounts are formatted in.
+    pub unit: Unit,
+    /// The separators and options of the formatted amounts.
+    pub amount_format: AmountFormat,
+}
+
+impl DefaultFormatter {
+    /// Creates a formatter for amounts in the unit, with the separators of a locale, given as language tag like `de`
+    /// or `en-US`.
+    pub fn for_locale(unit: Unit, locale: &str) -> Self {
+        Self {
+            unit,
+            amount_format: AmountFormat::for_locale(locale),
+        }
+    }
+}
+
+impl Default for DefaultFormatter {
+    /// Formats amounts in base units without separators.
+    fn default() -> Self {
+        Self {
+            unit: Unit::new("", 0),
+            amount_format: AmountFormat::default(),
+        }
+    }
+}
+
+impl Formatter for DefaultFormatter {
+    fn format_amount(&self, amount: u64) -> String {
+        format_amount(amount, self.unit, &self.amount_format)
+    }
+}
+
+/// Formats a unix timestamp in milliseconds as ISO 8601 date in UTC like `2023-04-05T12:30:00Z`.
+pub fn to_iso_8601(timestamp_millis: u128) -> String {
+    let seconds = timestamp_millis / 1000;
+    let seconds_of_day = seconds % 86_400;
+
+    // Civil date of the days since the epoch, see http://howardhinnant.github.io/date_algorithms.html#civil_from_days
+    let days = seconds / 86_400 + 719_468;
+    let era = days / 146_097;
+    let day_of_era = days % 146_097;
+    let year_of_era = (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+    let month = if month_index < 10 { month_index + 3 } else { month_index - 9 };
+    let year = era * 400 + year_of_era + u128::from(month <= 2);
+
+    format!(
+        "{year:04}-{month:02}-{day:02}T{:02}:{:02}:{:02}Z",
+        seconds_of_day / 3600,
+        seconds_of_day / 60 % 60,
+        seconds_of_day % 60
+    )
+}
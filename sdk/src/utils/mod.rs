@@ -1,6 +1,10 @@
 // Copyright 2023 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+pub mod format;
+pub mod payment_uri;
+pub mod unit;
+
 #[cfg(feature = "instant")]
 pub fn unix_timestamp_now() -> core::time::Duration {
     instant::SystemTime::now()
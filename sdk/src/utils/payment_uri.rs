@@ -0,0 +1,252 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Payment URIs like `iota://rms1...?amount=1000000&tag=invoice-42`, to request a payment, e.g. with a QR code.
+//!
+//! The bech32 address is followed by the optional parameters `amount` in base units, `tag` and `note`, which are
+//! percent encoded, and `expiration`, the unix timestamp after which the request shouldn't be paid anymore. Unknown
+//! parameters are ignored, so parameters can be added without breaking older wallets.
+
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::{fmt, str::FromStr};
+
+use crate::types::block::{
+    address::Bech32Address,
+    output::feature::{MetadataFeature, TagFeature},
+};
+
+/// The scheme of payment URIs.
+pub const PAYMENT_URI_SCHEME: &str = "iota";
+
+// The tag and the note have to fit into a tag and a metadata feature
+const TAG_LENGTH_MAX: usize = *TagFeature::LENGTH_RANGE.end() as usize;
+const NOTE_LENGTH_MAX: usize = *MetadataFeature::LENGTH_RANGE.end() as usize;
+
+/// A request to pay an address, that can be shared as URI.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PaymentUri {
+    /// The address to pay.
+    pub address: Bech32Address,
+    /// The requested amount in base units, left to the payer if not set.
+    pub amount: Option<u64>,
+    /// A tag to identify the payment, e.g. an invoice number, at most 64 bytes.
+    pub tag: Option<String>,
+    /// A note to the payer, at most 8192 bytes.
+    pub note: Option<String>,
+    /// The unix timestamp after which the request expires.
+    pub expiration: Option<u32>,
+}
+
+impl PaymentUri {
+    /// Creates a request to pay the address.
+    pub fn new(address: Bech32Address) -> Self {
+        Self {
+            address,
+            amount: None,
+            tag: None,
+            note: None,
+            expiration: None,
+        }
+    }
+
+    /// Sets the requested amount in base units.
+    pub fn with_amount(mut self, amount: impl Into<Option<u64>>) -> Self {
+        self.amount = amount.into();
+        self
+    }
+
+    /// Sets the tag to identify the payment.
+    pub fn with_tag(mut self, tag: impl Into<Option<String>>) -> Self {
+        self.tag = tag.into();
+        self
+    }
+
+    /// Sets the note to the payer.
+    pub fn with_note(mut self, note: impl Into<Option<String>>) -> Self {
+        self.note = note.into();
+        self
+    }
+
+    /// Sets the unix timestamp after which the request expires.
+    pub fn with_expiration(mut self, expiration: impl Into<Option<u32>>) -> Self {
+        self.expiration = expiration.into();
+        self
+    }
+
+    /// Parses a payment URI and checks that the address has the HRP of the expected network.
+    pub fn parse_with_hrp(uri: &str, hrp: &str) -> Result<Self, PaymentUriError> {
+        let payment_uri = Self::from_str(uri)?;
+
+        if payment_uri.address.hrp() != hrp {
+            return Err(PaymentUriError::InvalidHrp {
+                expected: hrp.to_string(),
+                found: payment_uri.address.hrp().to_string(),
+            });
+        }
+
+        Ok(payment_uri)
+    }
+
+    /// Returns true if the request expired before the unix timestamp.
+    pub fn is_expired(&self, timestamp: u32) -> bool {
+        matches!(self.expiration, Some(expiration) if timestamp > expiration)
+    }
+}
+
+impl fmt::Display for PaymentUri {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{PAYMENT_URI_SCHEME}://{}", self.address)?;
+
+        let mut parameters = Vec::new();
+        if let Some(amount) = self.amount {
+            parameters.push(format!("amount={amount}"));
+        }
+        if let Some(tag) = &self.tag {
+            parameters.push(format!("tag={}", percent_encode(tag)));
+        }
+        if let Some(note) = &self.note {
+            parameters.push(format!("note={}", percent_encode(note)));
+        }
+        if let Some(expiration) = self.expiration {
+            parameters.push(format!("expiration={expiration}"));
+        }
+        if !parameters.is_empty() {
+            write!(f, "?{}", parameters.join("&"))?;
+        }
+
+        Ok(())
+    }
+}
+
+impl FromStr for PaymentUri {
+    type Err = PaymentUriError;
+
+    fn from_str(uri: &str) -> Result<Self, Self::Err> {
+        let uri = uri.trim();
+        let rest = uri
+            .split_once("://")
+            .filter(|(scheme, _)| scheme.eq_ignore_ascii_case(PAYMENT_URI_SCHEME))
+            .map(|(_, rest)| rest)
+            .ok_or_else(|| PaymentUriError::InvalidScheme(uri.to_string()))?;
+        let (address, query) = rest.split_once('?').unwrap_or((rest, ""));
+        let address = address.trim_end_matches('/');
+        let address = Bech32Address::try_from_str(address)
+            .map_err(|_| PaymentUriError::InvalidAddress(address.to_string()))?;
+
+        let mut payment_uri = Self::new(address);
+        for parameter in query.split('&').filter(|parameter| !parameter.is_empty()) {
+            let (name, value) = parameter.split_once('=').unwrap_or((parameter, ""));
+            match name {
+                "amount" => set_parameter(&mut payment_uri.amount, name, value, value.parse().ok())?,
+                "tag" => set_parameter(&mut payment_uri.tag, name, value, decode_text(value, TAG_LENGTH_MAX))?,
+                "note" => set_parameter(&mut payment_uri.note, name, value, decode_text(value, NOTE_LENGTH_MAX))?,
+                "expiration" => set_parameter(&mut payment_uri.expiration, name, value, value.parse().ok())?,
+                _ => {}
+            }
+        }
+
+        Ok(payment_uri)
+    }
+}
+
+/// Errors of parsing a payment URI.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum PaymentUriError {
+    /// The URI doesn't start with `iota://`.
+    InvalidScheme(String),
+    /// The address isn't a valid bech32 address.
+    InvalidAddress(String),
+    /// The address is for another network.
+    InvalidHrp {
+        /// The HRP of the expected network.
+        expected: String,
+        /// The HRP of the address.
+        found: String,
+    },
+    /// A parameter has an invalid value.
+    InvalidParameter {
+        /// The name of the parameter.
+        name: String,
+        /// The value of the parameter.
+        value: String,
+    },
+    /// A parameter is set more than once.
+    DuplicateParameter(String),
+    /// The request expired at the unix timestamp.
+    Expired(u32),
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for PaymentUriError {}
+
+impl fmt::Display for PaymentUriError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidScheme(uri) => write!(f, "invalid scheme, expected {PAYMENT_URI_SCHEME}://: {uri}"),
+            Self::InvalidAddress(address) => write!(f, "invalid address: {address}"),
+            Self::InvalidHrp { expected, found } => write!(f, "invalid HRP: expected {expected}, found {found}"),
+            Self::InvalidParameter { name, value } => write!(f, "invalid {name}: {value}"),
+            Self::DuplicateParameter(name) => write!(f, "duplicate parameter: {name}"),
+            Self::Expired(expiration) => write!(f, "payment request expired at unix timestamp {expiration}"),
+        }
+    }
+}
+
+// Sets a parameter that can only be set once, to its parsed value
+fn set_parameter<T>(
+    parameter: &mut Option<T>,
+    name: &str,
+    value: &str,
+    parsed_value: Option<T>,
+) -> Result<(), PaymentUriError> {
+    if parameter.is_some() {
+        return Err(PaymentUriError::DuplicateParameter(name.to_string()));
+    }
+    *parameter = Some(parsed_value.ok_or_else(|| PaymentUriError::InvalidParameter {
+        name: name.to_string(),
+        value: value.to_string(),
+    })?);
+
+    Ok(())
+}
+
+// Decodes a percent encoded text that isn't empty and has at most `max_len` bytes
+fn decode_text(value: &str, max_len: usize) -> Option<String> {
+    percent_decode(value).filter(|text| !text.is_empty() && text.len() <= max_len)
+}
+
+// Percent encodes all bytes except the unreserved characters of RFC 3986
+fn percent_encode(text: &str) -> String {
+    let mut encoded = String::with_capacity(text.len());
+    for byte in text.bytes() {
+        if byte.is_ascii_alphanumeric() || matches!(byte, b'-' | b'.' | b'_' | b'~') {
+            encoded.push(byte as char);
+        } else {
+            encoded.push_str(&format!("%{byte:02X}"));
+        }
+    }
+    encoded
+}
+
+// Decodes a percent encoded text, `+` is decoded as space like in HTML forms
+fn percent_decode(text: &str) -> Option<String> {
+    let hex_value = |digit: u8| (digit as char).to_digit(16).map(|value| value as u8);
+    let mut bytes = Vec::with_capacity(text.len());
+    let mut text_bytes = text.bytes();
+    while let Some(byte) = text_bytes.next() {
+        match byte {
+            b'%' => {
+                let high = hex_value(text_bytes.next()?)?;
+                let low = hex_value(text_bytes.next()?)?;
+                bytes.push((high << 4) | low);
+            }
+            b'+' => bytes.push(b' '),
+            _ => bytes.push(byte),
+        }
+    }
+    String::from_utf8(bytes).ok()
+}
@@ -0,0 +1,275 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Conversion between amounts in base units and decimal denominations, like [`Unit::SMR`] or [`Unit::MI`].
+//!
+//! Amounts are converted with integer arithmetic only, so `1.1 SMR` is always exactly `1_100_000` base units, which
+//! can't be guaranteed when converting with floats.
+
+use alloc::{
+    format,
+    string::{String, ToString},
+};
+use core::fmt;
+
+/// A denomination of amounts, worth `10^decimals` base units.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub struct Unit {
+    symbol: &'static str,
+    decimals: u32,
+}
+
+impl Unit {
+    /// The base unit of the legacy IOTA network.
+    pub const I: Self = Self::new("i", 0);
+    /// 1 thousand base units of the legacy IOTA network.
+    pub const KI: Self = Self::new("Ki", 3);
+    /// 1 million base units of the legacy IOTA network.
+    pub const MI: Self = Self::new("Mi", 6);
+    /// 1 billion base units of the legacy IOTA network.
+    pub const GI: Self = Self::new("Gi", 9);
+    /// 1 trillion base units of the legacy IOTA network.
+    pub const TI: Self = Self::new("Ti", 12);
+    /// 1 quadrillion base units of the legacy IOTA network.
+    pub const PI: Self = Self::new("Pi", 15);
+    /// The IOTA token, worth 1 million base units.
+    pub const IOTA: Self = Self::new("IOTA", 6);
+    /// The legacy name of the IOTA token, worth 1 million base units.
+    pub const MIOTA: Self = Self::new("MIOTA", 6);
+    /// The base unit of the Shimmer network.
+    pub const GLOW: Self = Self::new("glow", 0);
+    /// The Shimmer token, worth 1 million glow.
+    pub const SMR: Self = Self::new("SMR", 6);
+
+    /// All known units, used to look up a unit by its symbol.
+    pub const ALL: [Self; 10] = [
+        Self::I,
+        Self::KI,
+        Self::MI,
+        Self::GI,
+        Self::TI,
+        Self::PI,
+        Self::IOTA,
+        Self::MIOTA,
+        Self::GLOW,
+        Self::SMR,
+    ];
+
+    /// Creates a unit worth `10^decimals` base units.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `decimals` is larger than 19, because the unit wouldn't fit into a `u64` anymore.
+    pub const fn new(symbol: &'static str, decimals: u32) -> Self {
+        assert!(decimals <= 19, "a unit can have at most 19 decimals");
+        Self { symbol, decimals }
+    }
+
+    /// Returns the known unit with the symbol, ignoring the case, e.g. `mi` for [`Unit::MI`].
+    pub fn from_symbol(symbol: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|unit| unit.symbol.eq_ignore_ascii_case(symbol))
+    }
+
+    /// Returns the symbol of the unit.
+    pub const fn symbol(&self) -> &'static str {
+        self.symbol
+    }
+
+    /// Returns the number of decimals of the unit, the power of ten of base units it's worth.
+    pub const fn decimals(&self) -> u32 {
+        self.decimals
+    }
+
+    /// Returns the number of base units the unit is worth.
+    pub const fn base_units(&self) -> u64 {
+        10u64.pow(self.decimals)
+    }
+}
+
+impl fmt::Display for Unit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.symbol)
+    }
+}
+
+/// Errors of parsing an amount.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum UnitError {
+    /// The amount isn't a decimal number.
+    InvalidNumber(String),
+    /// The symbol isn't a known unit.
+    UnknownUnit(String),
+    /// The amount has more decimals than the unit, so it isn't a whole number of base units.
+    TooManyDecimals {
+        /// The symbol of the unit.
+        unit: &'static str,
+        /// The number of decimals of the unit.
+        decimals: u32,
+    },
+    /// The amount is larger than `u64::MAX` base units.
+    Overflow,
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for UnitError {}
+
+impl fmt::Display for UnitError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidNumber(number) => write!(f, "invalid amount: {number}"),
+            Self::UnknownUnit(symbol) => write!(f, "unknown unit: {symbol}"),
+            Self::TooManyDecimals { unit, decimals } => {
+                write!(f, "too many decimals: {unit} has {decimals} decimals")
+            }
+            Self::Overflow => write!(f, "amount overflow"),
+        }
+    }
+}
+
+/// Options of [`format_amount()`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct AmountFormat {
+    /// The separator between the whole units and the fraction.
+    pub decimal_separator: char,
+    /// The separator between groups of three digits of the whole units, no grouping if not set.
+    pub group_separator: Option<char>,
+    /// The minimum number of digits of the fraction, it's padded with zeros to it. Trailing zeros beyond it are
+    /// removed.
+    pub min_decimals: u32,
+    /// Appends the symbol of the unit, separated by a space.
+    pub with_symbol: bool,
+}
+
+impl Default for AmountFormat {
+    fn default() -> Self {
+        Self {
+            decimal_separator: '.',
+            group_separator: None,
+            min_decimals: 0,
+            with_symbol: false,
+        }
+    }
+}
+
+impl AmountFormat {
+    /// Returns the format with the separators of a locale, given as language tag like `de` or `en-US`, with the
+    /// symbol of the unit. Unknown locales use the English separators.
+    pub fn for_locale(locale: &str) -> Self {
+        let mut subtags = locale.split(|c| c == '-' || c == '_');
+        let language = subtags.next().unwrap_or_default().to_ascii_lowercase();
+        let region = subtags.next().unwrap_or_default().to_ascii_uppercase();
+
+        let (decimal_separator, group_separator) = match (language.as_str(), region.as_str()) {
+            (_, "CH" | "LI") => ('.', '\''),
+            (
+                "da" | "de" | "el" | "es" | "hr" | "id" | "it" | "nl" | "pt" | "ro" | "sl" | "sr" | "tr" | "vi",
+                _,
+            ) => (',', '.'),
+            (
+                "bg" | "cs" | "et" | "fi" | "fr" | "hu" | "lt" | "lv" | "nb" | "nn" | "no" | "pl" | "ru" | "sk" | "sv"
+                | "uk",
+                _,
+            ) => (',', '\u{a0}'),
+            _ => ('.', ','),
+        };
+
+        Self {
+            decimal_separator,
+            group_separator: Some(group_separator),
+            min_decimals: 0,
+            with_symbol: true,
+        }
+    }
+}
+
+/// Formats an amount of base units as a decimal number of the unit, e.g. `1.5` for `1_500_000` base units and
+/// [`Unit::SMR`], without trailing zeros in the fraction.
+pub fn to_decimal_string(amount: u64, unit: Unit) -> String {
+    format_amount(amount, unit, &AmountFormat::default())
+}
+
+/// Formats an amount of base units as a decimal number of the unit, with the separators and options of the format.
+pub fn format_amount(amount: u64, unit: Unit, format: &AmountFormat) -> String {
+    let base_units = unit.base_units();
+    let whole = (amount / base_units).to_string();
+    let mut fraction = if unit.decimals == 0 {
+        String::new()
+    } else {
+        format!("{:0width$}", amount % base_units, width = unit.decimals as usize)
+    };
+    let trimmed_len = fraction.trim_end_matches('0').len().max(format.min_decimals as usize);
+    fraction.truncate(trimmed_len);
+    while fraction.len() < format.min_decimals as usize {
+        fraction.push('0');
+    }
+
+    let mut formatted = String::with_capacity(whole.len() * 2 + fraction.len() + unit.symbol.len() + 2);
+    for (index, digit) in whole.chars().enumerate() {
+        if let Some(group_separator) = format.group_separator {
+            if index > 0 && (whole.len() - index) % 3 == 0 {
+                formatted.push(group_separator);
+            }
+        }
+        formatted.push(digit);
+    }
+    if !fraction.is_empty() {
+        formatted.push(format.decimal_separator);
+        formatted.push_str(&fraction);
+    }
+    if format.with_symbol && !unit.symbol.is_empty() {
+        formatted.push(' ');
+        formatted.push_str(unit.symbol);
+    }
+
+    formatted
+}
+
+/// Parses an amount entered by a user, like `1.5 Mi`, `1.5SMR` or `1_000`, into base units. The decimal separator is
+/// `.` and digits can be grouped with `_`. Amounts without a unit are in `default_unit`.
+pub fn parse_amount(input: &str, default_unit: Unit) -> Result<u64, UnitError> {
+    let input = input.trim();
+    let number_len = input
+        .find(|c: char| !(c.is_ascii_digit() || c == '.' || c == '_'))
+        .unwrap_or(input.len());
+    let (number, symbol) = input.split_at(number_len);
+
+    let digits = number.replace('_', "");
+    let (whole, fraction) = digits.split_once('.').unwrap_or((&digits, ""));
+    if (whole.is_empty() && fraction.is_empty()) || fraction.contains('.') {
+        return Err(UnitError::InvalidNumber(input.to_string()));
+    }
+
+    let symbol = symbol.trim();
+    let unit = if symbol.is_empty() {
+        default_unit
+    } else {
+        Unit::from_symbol(symbol).ok_or_else(|| UnitError::UnknownUnit(symbol.to_string()))?
+    };
+
+    let fraction = fraction.trim_end_matches('0');
+    if fraction.len() > unit.decimals as usize {
+        return Err(UnitError::TooManyDecimals {
+            unit: unit.symbol,
+            decimals: unit.decimals,
+        });
+    }
+
+    // Only digits are left, so parsing can only fail because of an overflow
+    let whole = if whole.is_empty() {
+        0
+    } else {
+        whole.parse::<u64>().map_err(|_| UnitError::Overflow)?
+    };
+    let fraction = if fraction.is_empty() {
+        0
+    } else {
+        fraction.parse::<u64>().map_err(|_| UnitError::Overflow)? * 10u64.pow(unit.decimals - fraction.len() as u32)
+    };
+
+    whole
+        .checked_mul(unit.base_units())
+        .and_then(|amount| amount.checked_add(fraction))
+        .ok_or(UnitError::Overflow)
+}
@@ -14,9 +14,12 @@ use crate::wallet::events::EventEmitter;
 use crate::wallet::storage::manager::StorageManager;
 use crate::{
     client::secret::{SecretManage, SecretManager},
-    types::block::address::{Address, Bech32Address},
+    types::block::address::{Address, Bech32Address, Ed25519Address},
     wallet::{
-        account::{types::AccountAddress, Account, AccountDetails},
+        account::{
+            lock::Shared, types::AccountAddress, Account, AccountDetails, DustProtection, OutputDefaults,
+            PrivacyOptions,
+        },
         ClientOptions, Error,
     },
 };
@@ -26,9 +29,12 @@ pub struct AccountBuilder {
     addresses: Option<Vec<AccountAddress>>,
     alias: Option<String>,
     bech32_hrp: Option<String>,
+    external_id: Option<String>,
+    watch_only_addresses: Option<Vec<Address>>,
     client_options: Arc<RwLock<ClientOptions>>,
     coin_type: u32,
     secret_manager: Arc<RwLock<SecretManager>>,
+    address_generation_lock: Arc<tokio::sync::Mutex<()>>,
     accounts: Arc<RwLock<Vec<Account>>>,
     #[cfg(feature = "events")]
     event_emitter: Arc<tokio::sync::Mutex<EventEmitter>>,
@@ -43,6 +49,7 @@ impl AccountBuilder {
         client_options: Arc<RwLock<ClientOptions>>,
         coin_type: u32,
         secret_manager: Arc<RwLock<SecretManager>>,
+        address_generation_lock: Arc<tokio::sync::Mutex<()>>,
         #[cfg(feature = "events")] event_emitter: Arc<tokio::sync::Mutex<EventEmitter>>,
         #[cfg(feature = "storage")] storage_manager: Arc<tokio::sync::Mutex<StorageManager>>,
     ) -> Self {
@@ -50,9 +57,12 @@ impl AccountBuilder {
             addresses: None,
             alias: None,
             bech32_hrp: None,
+            external_id: None,
+            watch_only_addresses: None,
             client_options,
             coin_type,
             secret_manager,
+            address_generation_lock,
             accounts,
             #[cfg(feature = "events")]
             event_emitter,
@@ -80,12 +90,51 @@ impl AccountBuilder {
         self
     }
 
+    /// Set the identifier of the account in an external system, e.g. the ID of a user. If an account was already
+    /// created for the identifier, [`AccountBuilder::finish()`] returns it instead of creating another one
+    pub fn with_external_id(mut self, external_id: impl Into<Option<String>>) -> Self {
+        self.external_id = external_id.into();
+        self
+    }
+
+    /// Create a watch-only account with the addresses, the secret manager isn't used for it. The account can be synced
+    /// and its balance and history can be read, but signing transactions and generating addresses return
+    /// [`Error::WatchOnlyAccount`]. The addresses are stored with the HRP of the network.
+    pub fn with_watch_only_addresses(mut self, addresses: impl IntoIterator<Item = Bech32Address>) -> Self {
+        self.watch_only_addresses = Some(addresses.into_iter().map(|address| address.inner).collect());
+        self
+    }
+
+    /// Create a watch-only account with the Ed25519 addresses of the public keys, see
+    /// [`AccountBuilder::with_watch_only_addresses()`]
+    pub fn with_watch_only_public_keys(mut self, public_keys: impl IntoIterator<Item = [u8; 32]>) -> Self {
+        self.watch_only_addresses = Some(
+            public_keys
+                .into_iter()
+                .map(|public_key| Address::Ed25519(Ed25519Address::from_public_key_bytes(public_key)))
+                .collect(),
+        );
+        self
+    }
+
     /// Build the Account and add it to the accounts from Wallet
     /// Also generates the first address of the account and if it's not the first account, the address for the first
-    /// account will also be generated and compared, so no accounts get generated with different seeds
+    /// account will also be generated and compared, so no accounts get generated with different seeds. The account gets
+    /// the lowest index that isn't used, which is the index of a removed account if there is one
     pub async fn finish(&mut self) -> crate::wallet::Result<Account> {
         let mut accounts = self.accounts.write().await;
-        let account_index = accounts.len() as u32;
+
+        // The accounts are locked, so concurrent calls can't create two accounts for the same external identifier
+        if let Some(external_id) = &self.external_id {
+            for account in accounts.iter() {
+                if account.read().await.external_id.as_ref() == Some(external_id) {
+                    log::debug!("[ACCOUNT BUILDER] account for external id {external_id} already exists");
+                    return Ok(account.clone());
+                }
+            }
+        }
+
+        let account_index = next_account_index(&accounts).await;
         // If no alias is provided, the account index will be set as alias
         let account_alias = self.alias.clone().unwrap_or_else(|| account_index.to_string());
         log::debug!(
@@ -114,17 +163,53 @@ impl AccountBuilder {
 
         // If addresses are provided we will use them directly without the additional checks, because then we assume
         // that it's for offline signing and the secretManager can't be used
-        let addresses = match &self.addresses {
-            Some(addresses) => addresses.clone(),
-            None => {
+        let addresses = match (&self.watch_only_addresses, &self.addresses) {
+            (Some(watch_only_addresses), _) => {
+                if watch_only_addresses.is_empty() {
+                    return Err(Error::MissingParameter("watch_only_addresses"));
+                }
+                let bech32_hrp = match self.bech32_hrp.clone() {
+                    Some(bech32_hrp) => bech32_hrp,
+                    None => client.get_bech32_hrp().await?,
+                };
+
+                watch_only_addresses
+                    .iter()
+                    .enumerate()
+                    .map(|(key_index, address)| {
+                        Ok(AccountAddress {
+                            address: Bech32Address::new(bech32_hrp.clone(), *address)?,
+                            key_index: key_index as u32,
+                            internal: false,
+                            used: false,
+                            frozen: false,
+                            label: None,
+                        })
+                    })
+                    .collect::<crate::wallet::Result<Vec<_>>>()?
+            }
+            (None, Some(addresses)) => addresses.clone(),
+            (None, None) => {
                 let mut bech32_hrp = self.bech32_hrp.clone();
-                if let Some(first_account) = accounts.first() {
-                    let first_account_coin_type = *first_account.read().await.coin_type();
+                // Watch-only accounts don't have the addresses of the seed
+                let mut first_account = None;
+                for account in accounts.iter() {
+                    if !account.read().await.watch_only {
+                        first_account = Some(account);
+                        break;
+                    }
+                }
+                if let Some(first_account) = first_account {
+                    let (first_account_coin_type, first_account_index) = {
+                        let first_account = first_account.read().await;
+                        (first_account.coin_type, first_account.index)
+                    };
                     // Generate the first address of the first account and compare it to the stored address from the
                     // first account to prevent having multiple accounts created with different
                     // seeds
                     let first_account_public_address =
-                        get_first_public_address(&self.secret_manager, first_account_coin_type, 0).await?;
+                        get_first_public_address(&self.secret_manager, first_account_coin_type, first_account_index)
+                            .await?;
                     let first_account_addresses = first_account.public_addresses().await;
 
                     if first_account_public_address
@@ -163,6 +248,8 @@ impl AccountBuilder {
                     key_index: 0,
                     internal: false,
                     used: false,
+                    frozen: false,
+                    label: None,
                 };
 
                 vec![first_public_account_address]
@@ -173,23 +260,36 @@ impl AccountBuilder {
             index: account_index,
             coin_type: self.coin_type,
             alias: account_alias,
+            external_id: self.external_id.clone(),
             public_addresses: addresses,
             internal_addresses: Vec::new(),
             addresses_with_unspent_outputs: Vec::new(),
-            outputs: HashMap::new(),
+            outputs: Shared::default(),
             locked_outputs: HashSet::new(),
-            unspent_outputs: HashMap::new(),
-            transactions: HashMap::new(),
+            unspent_outputs: Shared::default(),
+            transactions: Shared::default(),
             pending_transactions: HashSet::new(),
-            incoming_transactions: HashMap::new(),
+            incoming_transactions: Shared::default(),
             inaccessible_incoming_transactions: HashSet::new(),
             native_token_foundries: HashMap::new(),
+            output_notes: HashMap::new(),
+            expected_payment_references: HashSet::new(),
+            asset_quarantine: false,
+            quarantined_assets: HashSet::new(),
+            accepted_assets: HashSet::new(),
+            watch_only: self.watch_only_addresses.is_some(),
+            metadata: HashMap::new(),
+            privacy_options: PrivacyOptions::default(),
+            output_defaults: OutputDefaults::default(),
+            dust_protection: DustProtection::default(),
         };
 
         let account = Account::new(
             account,
             client,
             self.secret_manager.clone(),
+            self.address_generation_lock.clone(),
+            Arc::downgrade(&self.accounts),
             #[cfg(feature = "events")]
             self.event_emitter.clone(),
             #[cfg(feature = "storage")]
@@ -216,3 +316,17 @@ pub(crate) async fn get_first_public_address(
         .generate_addresses(coin_type, account_index, 0..1, None)
         .await?[0])
 }
+
+/// Returns the lowest account index that isn't used, so the index of a removed account is used again
+pub(crate) async fn next_account_index(accounts: &[Account]) -> u32 {
+    let mut account_indexes = HashSet::with_capacity(accounts.len());
+    for account in accounts {
+        account_indexes.insert(*account.read().await.index());
+    }
+
+    let mut account_index = 0;
+    while account_indexes.contains(&account_index) {
+        account_index += 1;
+    }
+    account_index
+}
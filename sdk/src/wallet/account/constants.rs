@@ -15,5 +15,8 @@ pub(crate) const PARALLEL_REQUESTS_AMOUNT: usize = 500;
 /// this is done to prevent unnecessary simultaneous synchronizations
 pub(crate) const MIN_SYNC_INTERVAL: u128 = 5;
 
+/// Time after which the inputs selected for a transaction are released again, if the transaction wasn't submitted
+pub(crate) const DEFAULT_OUTPUT_RESERVATION_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(600);
+
 // Default expiration time for [ExpirationUnlockCondition] when sending native tokens, one day in seconds
 pub(crate) const DEFAULT_EXPIRATION_TIME: u32 = 86400;
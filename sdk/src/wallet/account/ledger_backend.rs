@@ -0,0 +1,107 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use primitive_types::U256;
+use serde::{Deserialize, Serialize};
+
+use crate::wallet::account::{
+    types::{AccountBalance, InclusionState, Transaction},
+    AccountDetails,
+};
+
+/// The identifier of the layer 1 ledger in [`LedgerBalance`]s and [`LedgerTransaction`]s.
+pub const LAYER_1_LEDGER_ID: &str = "layer1";
+
+/// A ledger next to the layer 1, like an EVM based layer 2 chain, that keeps a balance and a history for an account.
+///
+/// Backends are added with [`Account::add_ledger_backend()`](crate::wallet::Account::add_ledger_backend), then
+/// [`Account::ledger_balances()`](crate::wallet::Account::ledger_balances) and
+/// [`Account::ledger_transactions()`](crate::wallet::Account::ledger_transactions) return the entries of all ledgers
+/// of the account in the same format.
+#[async_trait::async_trait]
+pub trait LedgerBackend: std::fmt::Debug + Send + Sync {
+    /// The identifier of the ledger, like `evm-1074`, that must be unique among the ledgers of an account.
+    fn id(&self) -> String;
+
+    /// Gets the balance of the account on the ledger.
+    async fn balance(&self, account: &AccountDetails) -> crate::wallet::Result<LedgerBalance>;
+
+    /// Gets the transactions of the account on the ledger.
+    async fn transactions(&self, account: &AccountDetails) -> crate::wallet::Result<Vec<LedgerTransaction>>;
+}
+
+/// The balance of an account on a ledger.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LedgerBalance {
+    /// The identifier of the ledger.
+    pub ledger_id: String,
+    /// Total amount of the base coin in the smallest unit of the ledger.
+    pub total: U256,
+    /// Amount of the base coin that can currently be spent.
+    pub available: U256,
+    /// Balances of the tokens on the ledger.
+    pub tokens: Vec<LedgerTokenBalance>,
+}
+
+/// The balance of a token on a ledger, like a native token on the layer 1 or an ERC-20 token on an EVM chain.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LedgerTokenBalance {
+    /// The identifier of the token on the ledger, like the token ID or the address of the contract.
+    pub token_id: String,
+    /// Total amount.
+    pub total: U256,
+    /// Amount that can currently be spent.
+    pub available: U256,
+}
+
+/// A transaction of an account on a ledger.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LedgerTransaction {
+    /// The identifier of the ledger.
+    pub ledger_id: String,
+    /// The identifier of the transaction on the ledger.
+    pub transaction_id: String,
+    /// Unix timestamp in milliseconds at which the transaction was created or received.
+    pub timestamp: u128,
+    /// True if the transaction was sent by someone else.
+    pub incoming: bool,
+    /// The inclusion state of the transaction.
+    pub inclusion_state: InclusionState,
+    /// A note of the transaction.
+    pub note: Option<String>,
+}
+
+impl From<&AccountBalance> for LedgerBalance {
+    fn from(balance: &AccountBalance) -> Self {
+        Self {
+            ledger_id: LAYER_1_LEDGER_ID.to_string(),
+            total: U256::from(balance.base_coin().total()),
+            available: U256::from(balance.base_coin().available()),
+            tokens: balance
+                .native_tokens()
+                .iter()
+                .map(|native_token| LedgerTokenBalance {
+                    token_id: native_token.token_id().to_string(),
+                    total: native_token.total(),
+                    available: native_token.available(),
+                })
+                .collect(),
+        }
+    }
+}
+
+impl From<&Transaction> for LedgerTransaction {
+    fn from(transaction: &Transaction) -> Self {
+        Self {
+            ledger_id: LAYER_1_LEDGER_ID.to_string(),
+            transaction_id: transaction.transaction_id.to_string(),
+            timestamp: transaction.timestamp,
+            incoming: transaction.incoming,
+            inclusion_state: transaction.inclusion_state,
+            note: transaction.note.clone(),
+        }
+    }
+}
@@ -0,0 +1,175 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::{
+    ops::{Deref, DerefMut},
+    sync::{Arc, PoisonError, RwLock as SyncRwLock},
+};
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use tokio::sync::{watch, RwLock, RwLockReadGuard, RwLockWriteGuard};
+
+use super::AccountDetails;
+
+/// The lock of the [`AccountDetails`] of an [`Account`](super::Account).
+///
+/// [`AccountDetailsLock::read()`] and [`AccountDetailsLock::write()`] work like the ones of a [`RwLock`], so a read
+/// waits until a running write is finished, e.g. the update of the account after a sync. Next to the lock a snapshot of
+/// the details is kept, that is replaced every time a write guard is dropped. [`AccountDetailsLock::snapshot()`]
+/// returns it without waiting for a running write, so the returned details are always consistent, but don't contain
+/// the changes of a running write yet.
+#[derive(Debug)]
+pub struct AccountDetailsLock {
+    details: RwLock<AccountDetails>,
+    // The details as of the last dropped write guard, only locked to replace or clone the `Arc`
+    snapshot: SyncRwLock<Arc<AccountDetails>>,
+    // Notified every time the snapshot is replaced
+    changes: watch::Sender<()>,
+}
+
+impl AccountDetailsLock {
+    pub(crate) fn new(details: AccountDetails) -> Self {
+        Self {
+            snapshot: SyncRwLock::new(Arc::new(details.clone())),
+            details: RwLock::new(details),
+            changes: watch::channel(()).0,
+        }
+    }
+
+    /// Locks the details for reading, waits until a running write is finished.
+    pub async fn read(&self) -> RwLockReadGuard<'_, AccountDetails> {
+        self.details.read().await
+    }
+
+    /// Locks the details for writing, waits until all running reads and writes are finished. The snapshot is
+    /// replaced when the returned guard is dropped.
+    pub async fn write(&self) -> AccountDetailsWriteGuard<'_> {
+        AccountDetailsWriteGuard {
+            guard: self.details.write().await,
+            snapshot: &self.snapshot,
+            changes: &self.changes,
+        }
+    }
+
+    /// Returns the details as of the last finished write, without waiting for a running write.
+    pub fn snapshot(&self) -> Arc<AccountDetails> {
+        self.snapshot.read().unwrap_or_else(PoisonError::into_inner).clone()
+    }
+
+    // Returns a receiver that is notified every time a write is finished and the snapshot is replaced
+    pub(crate) fn changes(&self) -> watch::Receiver<()> {
+        self.changes.subscribe()
+    }
+}
+
+/// Write guard of an [`AccountDetailsLock`], it replaces the snapshot of the lock with the changed details when it's
+/// dropped.
+#[derive(Debug)]
+pub struct AccountDetailsWriteGuard<'a> {
+    guard: RwLockWriteGuard<'a, AccountDetails>,
+    snapshot: &'a SyncRwLock<Arc<AccountDetails>>,
+    changes: &'a watch::Sender<()>,
+}
+
+impl Deref for AccountDetailsWriteGuard<'_> {
+    type Target = AccountDetails;
+
+    fn deref(&self) -> &Self::Target {
+        &self.guard
+    }
+}
+
+impl DerefMut for AccountDetailsWriteGuard<'_> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.guard
+    }
+}
+
+impl Drop for AccountDetailsWriteGuard<'_> {
+    fn drop(&mut self) {
+        // Cloned before the write lock is released, so no later write can be overwritten by this snapshot. The large
+        // maps are `Shared`, so only the ones changed since the last snapshot were copied and the clone is cheap.
+        let snapshot = Arc::new(self.guard.clone());
+        *self.snapshot.write().unwrap_or_else(PoisonError::into_inner) = snapshot;
+        self.changes.send_replace(());
+    }
+}
+
+/// Copy-on-write wrapper for the large collections of the [`AccountDetails`], so the details and their snapshot share
+/// them. Cloning only clones the `Arc`, the collection is copied the first time it's changed while it's shared.
+#[derive(Debug, Default, Eq, PartialEq)]
+pub(crate) struct Shared<T>(Arc<T>);
+
+impl<T> Clone for Shared<T> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<T> From<T> for Shared<T> {
+    fn from(value: T) -> Self {
+        Self(Arc::new(value))
+    }
+}
+
+impl<T> Deref for Shared<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T: Clone> DerefMut for Shared<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        Arc::make_mut(&mut self.0)
+    }
+}
+
+impl<'a, T> IntoIterator for &'a Shared<T>
+where
+    &'a T: IntoIterator,
+{
+    type Item = <&'a T as IntoIterator>::Item;
+    type IntoIter = <&'a T as IntoIterator>::IntoIter;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.as_ref().into_iter()
+    }
+}
+
+impl<T: Serialize> Serialize for Shared<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.0.serialize(serializer)
+    }
+}
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for Shared<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        T::deserialize(deserializer).map(Self::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::*;
+
+    #[test]
+    fn shared_copy_on_write() {
+        let mut details = Shared::from(HashMap::from([(0, "output")]));
+        let snapshot = details.clone();
+        assert!(Arc::ptr_eq(&details.0, &snapshot.0));
+
+        details.insert(1, "new output");
+        assert!(!Arc::ptr_eq(&details.0, &snapshot.0));
+        assert_eq!(details.len(), 2);
+        assert_eq!(*snapshot, HashMap::from([(0, "output")]));
+
+        // Not shared anymore, so it's changed in place
+        let ptr = Arc::as_ptr(&details.0);
+        details.remove(&0);
+        assert_eq!(Arc::as_ptr(&details.0), ptr);
+    }
+}
@@ -5,6 +5,10 @@
 pub(crate) mod builder;
 /// Constants used for the account and account operations.
 pub(crate) mod constants;
+/// The extension point for additional ledgers of an account, like an EVM based layer 2 chain.
+pub mod ledger_backend;
+/// The lock of the account details with lock-free snapshots.
+pub(crate) mod lock;
 /// The account operations like address generation, syncing and creating transactions.
 pub(crate) mod operations;
 /// Types used in an account and returned from methods.
@@ -12,29 +16,52 @@ pub mod types;
 /// Methods to update the account state.
 pub(crate) mod update;
 
+#[cfg(feature = "storage")]
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::{
     collections::{HashMap, HashSet},
+    future::Future,
     ops::Deref,
     str::FromStr,
-    sync::Arc,
+    sync::{Arc, Weak},
 };
 
+use crypto::keys::slip10::Chain;
+use futures::{Stream, TryStreamExt};
 use getset::{Getters, Setters};
+use instant::Instant;
 use serde::{de, Deserialize, Deserializer, Serialize};
 use tokio::sync::{Mutex, RwLock};
 
 #[cfg(feature = "participation")]
 pub use self::operations::participation::{AccountParticipationOverview, ParticipationEventWithNodes};
-use self::types::{
-    address::{AccountAddress, AddressWithUnspentOutputs},
-    AccountBalance, OutputData, Transaction,
+use self::{
+    ledger_backend::LedgerBackend,
+    lock::Shared,
+    types::{
+        address::{AccountAddress, AddressWithUnspentOutputs, ChainAddress},
+        asset::assets_in_output,
+        AccountBalance, AssetId, OutputData, OutputNote, PaymentReference, Transaction,
+    },
 };
 pub use self::{
+    lock::{AccountDetailsLock, AccountDetailsWriteGuard},
     operations::{
+        address_generation::AddressGenerationOptions,
+        changes::{AccountChanges, SyncToken},
+        dust_protection::{DustProtection, DustRemainderAction},
+        health::AccountHealth,
+        inclusion_stats::InclusionStats,
+        integrity::IntegrityReport,
+        message_signing::verify_message_proof,
         output_claiming::OutputsToClaim,
+        output_age::{AddressAge, AgeAttestation, OutputAge},
+        privacy::{AddressReuse, PrivacyOptions},
+        pruning::{PrunedRecords, PrunedRecordsDto},
+        spending_policy::{OutgoingTransfer, SpendingPolicy, SpendingPolicyViolation, TransactionApprover},
         syncing::{
             options::{AccountSyncOptions, AliasSyncOptions, NftSyncOptions},
-            SyncOptions,
+            SyncOptions, UtxoSnapshot,
         },
         transaction::{
             high_level::{
@@ -46,25 +73,35 @@ pub use self::{
                     mint_native_token::{MintTokenTransactionDto, NativeTokenOptions, NativeTokenOptionsDto},
                     mint_nfts::{NftOptions, NftOptionsDto},
                 },
+                send_all::SendAllOptions,
+                send_to_l2::L2Deposit,
             },
             prepare_output::{
-                Assets, Features, OutputOptions, OutputOptionsDto, ReturnStrategy, StorageDeposit, Unlocks,
+                Assets, Features, OutputDefaults, OutputOptions, OutputOptionsDto, ReturnStrategy, StorageDeposit,
+                Unlocks,
             },
-            RemainderValueStrategy, TransactionOptions, TransactionOptionsDto,
+            RemainderValueStrategy, TransactionDryRun, TransactionDryRunDto, TransactionOptions, TransactionOptionsDto,
+            TransactionSummary,
         },
+        watchdog::{StallAction, StallDiagnostics, WatchdogOptions, WatchedOperation},
     },
     types::OutputDataDto,
 };
 #[cfg(feature = "events")]
 use crate::wallet::events::EventEmitter;
 #[cfg(feature = "storage")]
-use crate::wallet::storage::manager::StorageManager;
+use crate::wallet::storage::{event_log::AccountStateEvent, manager::StorageManager};
 use crate::{
     client::{secret::SecretManager, Client},
     types::{
         api::core::response::OutputWithMetadataResponse,
         block::{
-            output::{AliasId, FoundryId, FoundryOutput, NftId, Output, OutputId, TokenId},
+            address::{Address, AliasAddress, Bech32Address, NftAddress},
+            input::Input,
+            output::{
+                feature::{Irc27Metadata, Irc30Metadata},
+                AliasId, FoundryId, FoundryOutput, NftId, Output, OutputId, OutputMetadata, TokenId,
+            },
             payload::{
                 transaction::{TransactionEssence, TransactionId},
                 TransactionPayload,
@@ -91,6 +128,120 @@ pub struct FilterOptions {
     pub foundry_ids: Option<HashSet<FoundryId>>,
     /// Return all nft outputs matching these IDs.
     pub nft_ids: Option<HashSet<NftId>>,
+    /// Filter all outputs that are spent or unspent.
+    #[serde(default)]
+    pub is_spent: Option<bool>,
+    /// Filter all outputs with an amount below the specified amount.
+    #[serde(default)]
+    pub lower_bound_amount: Option<u64>,
+    /// Filter all outputs with an amount above the specified amount.
+    #[serde(default)]
+    pub upper_bound_amount: Option<u64>,
+    /// Filter all outputs that don't belong to the specified address.
+    #[serde(default)]
+    pub address: Option<Bech32Address>,
+}
+
+impl FilterOptions {
+    // Returns true if the output passes the filter, the id filters include outputs regardless of the other filters
+    fn matches(&self, output: &OutputData) -> bool {
+        match &output.output {
+            Output::Alias(alias) => {
+                if let Some(alias_ids) = &self.alias_ids {
+                    if alias_ids.contains(&alias.alias_id_non_null(&output.output_id)) {
+                        return true;
+                    }
+                }
+            }
+            Output::Foundry(foundry) => {
+                if let Some(foundry_ids) = &self.foundry_ids {
+                    if foundry_ids.contains(&foundry.id()) {
+                        return true;
+                    }
+                }
+            }
+            Output::Nft(nft) => {
+                if let Some(nft_ids) = &self.nft_ids {
+                    if nft_ids.contains(&nft.nft_id_non_null(&output.output_id)) {
+                        return true;
+                    }
+                }
+            }
+            _ => {}
+        }
+
+        if let Some(lower_bound_booked_timestamp) = self.lower_bound_booked_timestamp {
+            if output.metadata.milestone_timestamp_booked() < lower_bound_booked_timestamp {
+                return false;
+            }
+        }
+        if let Some(upper_bound_booked_timestamp) = self.upper_bound_booked_timestamp {
+            if output.metadata.milestone_timestamp_booked() > upper_bound_booked_timestamp {
+                return false;
+            }
+        }
+
+        if let Some(output_types) = &self.output_types {
+            if !output_types.contains(&output.output.kind()) {
+                return false;
+            }
+        }
+
+        if let Some(is_spent) = self.is_spent {
+            if output.is_spent != is_spent {
+                return false;
+            }
+        }
+
+        if let Some(lower_bound_amount) = self.lower_bound_amount {
+            if output.output.amount() < lower_bound_amount {
+                return false;
+            }
+        }
+        if let Some(upper_bound_amount) = self.upper_bound_amount {
+            if output.output.amount() > upper_bound_amount {
+                return false;
+            }
+        }
+
+        if let Some(address) = &self.address {
+            if address.inner() != &output.address {
+                return false;
+            }
+        }
+
+        true
+    }
+}
+
+/// Options to filter transactions
+#[derive(Debug, Default, Clone, Serialize, Deserialize, Eq, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionFilterOptions {
+    /// Filter all transactions created before the specified unix timestamp in milliseconds
+    pub lower_bound_timestamp: Option<u128>,
+    /// Filter all transactions created after the specified unix timestamp in milliseconds
+    pub upper_bound_timestamp: Option<u128>,
+    /// Filter all transactions for the provided inclusion states.
+    pub inclusion_states: Option<Vec<InclusionState>>,
+    /// Filter all transactions that are incoming or outgoing.
+    pub incoming: Option<bool>,
+}
+
+impl TransactionFilterOptions {
+    // Returns true if the transaction passes the filter
+    fn matches(&self, transaction: &Transaction) -> bool {
+        self.lower_bound_timestamp
+            .map_or(true, |lower_bound| transaction.timestamp >= lower_bound)
+            && self
+                .upper_bound_timestamp
+                .map_or(true, |upper_bound| transaction.timestamp <= upper_bound)
+            && self
+                .inclusion_states
+                .as_ref()
+                .map_or(true, |inclusion_states| inclusion_states.contains(&transaction.inclusion_state))
+            && self.incoming.map_or(true, |incoming| transaction.incoming == incoming)
+    }
 }
 
 /// Details of an account.
@@ -99,11 +250,15 @@ pub struct FilterOptions {
 #[serde(rename_all = "camelCase")]
 pub struct AccountDetails {
     /// The account index
+    #[getset(set = "pub(crate)")]
     index: u32,
     /// The coin type
     coin_type: u32,
     /// The account alias.
     alias: String,
+    /// The identifier of the account in an external system, e.g. the ID of a user, unique within the wallet
+    #[serde(default)]
+    external_id: Option<String>,
     /// Public addresses
     pub(crate) public_addresses: Vec<AccountAddress>,
     /// Internal addresses
@@ -114,25 +269,29 @@ pub struct AccountDetails {
     addresses_with_unspent_outputs: Vec<AddressWithUnspentOutputs>,
     /// Outputs
     // stored separated from the account for performance?
-    outputs: HashMap<OutputId, OutputData>,
+    #[getset(skip)]
+    outputs: Shared<HashMap<OutputId, OutputData>>,
     /// Unspent outputs that are currently used as input for transactions
     // outputs used in transactions should be locked here so they don't get used again, which would result in a
     // conflicting transaction
     pub(crate) locked_outputs: HashSet<OutputId>,
     /// Unspent outputs
     // have unspent outputs in a separated hashmap so we don't need to iterate over all outputs we have
-    unspent_outputs: HashMap<OutputId, OutputData>,
+    #[getset(skip)]
+    unspent_outputs: Shared<HashMap<OutputId, OutputData>>,
     /// Sent transactions
     // stored separated from the account for performance and only the transaction id here? where to add the network id?
     // transactions: HashSet<TransactionId>,
-    transactions: HashMap<TransactionId, types::Transaction>,
+    #[getset(skip)]
+    transactions: Shared<HashMap<TransactionId, types::Transaction>>,
     /// Pending transactions
     // Maybe pending transactions even additionally separated?
     pending_transactions: HashSet<TransactionId>,
     /// Transaction payloads for received outputs with inputs when not pruned before syncing, can be used to determine
     /// the sender address/es
     #[serde(deserialize_with = "deserialize_or_convert")]
-    incoming_transactions: HashMap<TransactionId, Transaction>,
+    #[getset(skip)]
+    incoming_transactions: Shared<HashMap<TransactionId, Transaction>>,
     /// Some incoming transactions can be pruned by the node before we requested them, then this node can never return
     /// it. To avoid useless requests, these transaction ids are stored here and cleared when new client options are
     /// set, because another node might still have them.
@@ -141,28 +300,347 @@ pub struct AccountDetails {
     /// Foundries for native tokens in outputs
     #[serde(default)]
     native_token_foundries: HashMap<FoundryId, FoundryOutput>,
+    /// Local notes attached to outputs, stored separately so they don't get lost when outputs are synced again
+    #[serde(default)]
+    output_notes: HashMap<OutputId, OutputNote>,
+    /// Payment references for which a `ReferenceMatched` event is emitted when an incoming output contains them
+    #[serde(default)]
+    expected_payment_references: HashSet<PaymentReference>,
+    /// If received NFTs and native tokens that weren't accepted before are quarantined
+    #[serde(default)]
+    asset_quarantine: bool,
+    /// Received assets that need to be accepted before they're part of the balance and can be spent
+    #[serde(default)]
+    quarantined_assets: HashSet<AssetId>,
+    /// Assets that aren't quarantined when they're received
+    #[serde(default)]
+    accepted_assets: HashSet<AssetId>,
+    /// If the addresses were imported without keys in the secret manager, then the account can be synced, but can't
+    /// sign transactions or generate addresses
+    #[serde(default)]
+    watch_only: bool,
+    /// Metadata of integrators, stored with the account, e.g. references to other systems
+    #[serde(default)]
+    metadata: HashMap<String, Vec<u8>>,
+    /// Options to improve the privacy of the account
+    #[serde(default)]
+    privacy_options: PrivacyOptions,
+    /// Defaults for outputs to addresses that can't cover the storage deposit
+    #[serde(default)]
+    output_defaults: OutputDefaults,
+    /// Options against outputs with amounts too small to be of use
+    #[serde(default)]
+    dust_protection: DustProtection,
+}
+
+impl AccountDetails {
+    /// Outputs
+    pub fn outputs(&self) -> &HashMap<OutputId, OutputData> {
+        &self.outputs
+    }
+
+    /// Unspent outputs
+    pub fn unspent_outputs(&self) -> &HashMap<OutputId, OutputData> {
+        &self.unspent_outputs
+    }
+
+    /// Sent transactions
+    pub fn transactions(&self) -> &HashMap<TransactionId, types::Transaction> {
+        &self.transactions
+    }
+
+    /// Transaction payloads for received outputs with inputs when not pruned before syncing, can be used to determine
+    /// the sender address/es
+    pub fn incoming_transactions(&self) -> &HashMap<TransactionId, Transaction> {
+        &self.incoming_transactions
+    }
+
+    /// Returns an estimate of the memory used by the outputs and transactions of the account in bytes, the details that
+    /// are evicted by the account cache.
+    #[cfg(feature = "storage")]
+    pub(crate) fn evictable_size(&self) -> usize {
+        use std::mem::size_of;
+
+        use packable::PackableExt;
+
+        let outputs_size = |outputs: &HashMap<OutputId, OutputData>| {
+            outputs
+                .values()
+                .map(|output_data| size_of::<(OutputId, OutputData)>() + output_data.output.packed_len())
+                .sum::<usize>()
+        };
+        let transactions_size = |transactions: &HashMap<TransactionId, Transaction>| {
+            transactions
+                .values()
+                .map(|transaction| {
+                    size_of::<(TransactionId, Transaction)>()
+                        + transaction.payload.packed_len()
+                        + transaction.inputs.len() * size_of::<OutputWithMetadataResponse>()
+                })
+                .sum::<usize>()
+        };
+
+        outputs_size(&self.outputs)
+            + outputs_size(&self.unspent_outputs)
+            + transactions_size(&self.transactions)
+            + transactions_size(&self.incoming_transactions)
+            + self.inaccessible_incoming_transactions.len() * size_of::<TransactionId>()
+            + self
+                .native_token_foundries
+                .values()
+                .map(|foundry| size_of::<(FoundryId, FoundryOutput)>() + foundry.packed_len())
+                .sum::<usize>()
+    }
+
+    /// Returns the BIP32 chain of the key of an address of the account, `None` if the account didn't generate it.
+    pub(crate) fn address_chain(&self, address: &Bech32Address) -> Option<Chain> {
+        self.public_addresses
+            .iter()
+            .chain(self.internal_addresses.iter())
+            .find(|account_address| &account_address.address == address)
+            .map(|account_address| {
+                Chain::from_u32_hardened(vec![
+                    44,
+                    self.coin_type,
+                    self.index,
+                    account_address.internal as u32,
+                    account_address.key_index,
+                ])
+            })
+    }
+
+    /// Returns the ids of the unspent outputs owned by frozen addresses or holding quarantined assets, these must not
+    /// be used as inputs.
+    pub(crate) fn frozen_output_ids(&self) -> HashSet<OutputId> {
+        let frozen_addresses = self
+            .public_addresses
+            .iter()
+            .chain(self.internal_addresses.iter())
+            .filter(|address| address.frozen)
+            .map(|address| *address.address.inner())
+            .collect::<HashSet<_>>();
+
+        let mut frozen_output_ids = self.quarantined_output_ids();
+        if frozen_addresses.is_empty() {
+            return frozen_output_ids;
+        }
+
+        frozen_output_ids.extend(
+            self.unspent_outputs
+                .values()
+                .filter(|output_data| frozen_addresses.contains(&output_data.address))
+                .map(|output_data| output_data.output_id),
+        );
+        frozen_output_ids
+    }
+
+    /// Returns the ids of the unspent outputs holding quarantined assets, these aren't part of the balance.
+    pub(crate) fn quarantined_output_ids(&self) -> HashSet<OutputId> {
+        if self.quarantined_assets.is_empty() {
+            return HashSet::new();
+        }
+
+        self.unspent_outputs
+            .values()
+            .filter(|output_data| {
+                assets_in_output(&output_data.output_id, &output_data.output)
+                    .iter()
+                    .any(|asset| self.quarantined_assets.contains(asset))
+            })
+            .map(|output_data| output_data.output_id)
+            .collect()
+    }
+
+    /// Returns the addresses of the unspent alias and NFT outputs of the account, outputs locked to these addresses are
+    /// owned by the account as well.
+    pub(crate) fn alias_and_nft_addresses(&self) -> Vec<Address> {
+        self.unspent_outputs
+            .values()
+            .filter_map(|output_data| match &output_data.output {
+                Output::Alias(alias_output) => Some(Address::Alias(AliasAddress::from(
+                    alias_output.alias_id_non_null(&output_data.output_id),
+                ))),
+                Output::Nft(nft_output) => Some(Address::Nft(NftAddress::from(
+                    nft_output.nft_id_non_null(&output_data.output_id),
+                ))),
+                _ => None,
+            })
+            .collect()
+    }
+
+    // Quarantines the assets of a new output that weren't accepted before, if the quarantine is enabled. The assets of
+    // outputs created by the account's own transactions are accepted.
+    pub(crate) fn quarantine_new_assets(&mut self, output_data: &OutputData) {
+        let assets = assets_in_output(&output_data.output_id, &output_data.output);
+        let own_output = self
+            .transactions
+            .get(output_data.output_id.transaction_id())
+            .map_or(false, |transaction| !transaction.incoming);
+
+        for asset in assets {
+            if own_output {
+                self.quarantined_assets.remove(&asset);
+                self.accepted_assets.insert(asset);
+            } else if self.asset_quarantine && !self.accepted_assets.contains(&asset) {
+                self.quarantined_assets.insert(asset);
+            }
+        }
+    }
+
+    /// Returns the outputs created by pending transactions of the account that can be used as inputs before the
+    /// transactions are confirmed. Only outputs that are unlocked by an address unlock condition alone, with a not
+    /// frozen address of the account, and that aren't used by another transaction are returned.
+    pub(crate) fn unconfirmed_outputs(&self) -> Vec<OutputData> {
+        let mut unconfirmed_outputs = Vec::new();
+
+        for transaction_id in &self.pending_transactions {
+            let Some(transaction) = self.transactions.get(transaction_id) else {
+                continue;
+            };
+            let TransactionEssence::Regular(essence) = transaction.payload.essence();
+
+            for (index, output) in essence.outputs().iter().enumerate() {
+                // The number of outputs is limited by the transaction essence
+                let Ok(output_id) = OutputId::new(*transaction_id, index as u16) else {
+                    continue;
+                };
+                if self.outputs.contains_key(&output_id) || self.locked_outputs.contains(&output_id) {
+                    continue;
+                }
+                let Some(unlock_conditions) = output.unlock_conditions().filter(|u| u.len() == 1) else {
+                    continue;
+                };
+                let Some(address) = unlock_conditions.address().map(|u| *u.address()) else {
+                    continue;
+                };
+                let Some(account_address) = self
+                    .public_addresses
+                    .iter()
+                    .chain(self.internal_addresses.iter())
+                    .find(|a| a.address.inner == address && !a.frozen)
+                else {
+                    continue;
+                };
+
+                // The output isn't booked yet, so the metadata only references the block of the transaction
+                let metadata = OutputMetadata::new(
+                    transaction.block_id.unwrap_or_else(BlockId::null),
+                    output_id,
+                    false,
+                    None,
+                    None,
+                    None,
+                    0,
+                    0,
+                    0,
+                );
+                unconfirmed_outputs.push(OutputData {
+                    output_id,
+                    metadata,
+                    output: output.clone(),
+                    is_spent: false,
+                    address,
+                    network_id: transaction.network_id,
+                    remainder: true,
+                    chain: Some(Chain::from_u32_hardened(vec![
+                        44,
+                        self.coin_type,
+                        self.index,
+                        account_address.internal as u32,
+                        account_address.key_index,
+                    ])),
+                });
+            }
+        }
+
+        unconfirmed_outputs
+    }
+
+    /// Returns the pending transactions of the account whose outputs are spent by the transaction.
+    pub(crate) fn pending_parent_transactions(&self, payload: &TransactionPayload) -> Vec<TransactionId> {
+        let TransactionEssence::Regular(essence) = payload.essence();
+        let mut parents = essence
+            .inputs()
+            .iter()
+            .filter_map(|input| match input {
+                Input::Utxo(input) => Some(*input.output_id().transaction_id()),
+                Input::Treasury(_) => None,
+            })
+            .filter(|transaction_id| self.pending_transactions.contains(transaction_id))
+            .collect::<Vec<_>>();
+        parents.sort_unstable();
+        parents.dedup();
+
+        parents
+    }
+
+    /// Returns the pending transactions of the account that spend outputs of the transaction, directly or through
+    /// other pending transactions.
+    pub(crate) fn pending_child_transactions(&self, transaction_id: &TransactionId) -> Vec<TransactionId> {
+        let mut children = Vec::new();
+        let mut parents = vec![*transaction_id];
+
+        while let Some(parent) = parents.pop() {
+            for (child_id, child) in self
+                .pending_transactions
+                .iter()
+                .filter_map(|child_id| self.transactions.get(child_id).map(|child| (child_id, child)))
+            {
+                if !children.contains(child_id) && self.pending_parent_transactions(&child.payload).contains(&parent) {
+                    children.push(*child_id);
+                    parents.push(*child_id);
+                }
+            }
+        }
+
+        children
+    }
 }
 
 /// A thread guard over an account, so we can lock the account during operations.
+///
+/// Clones of an account are cheap handles of the same account: they share the details, the sync state, the client
+/// and the secret manager, so a change made with one handle is visible to all others. All handles can be used from
+/// multiple tasks at the same time. The details are locked with an [`AccountDetailsLock`], the getters of the account,
+/// like [`Account::balance()`], [`Account::addresses()`], [`Account::outputs()`] and [`Account::transactions()`], read
+/// its [`AccountDetailsLock::snapshot()`], so they never wait for a running write like the update after a sync, but
+/// return the state before it.
 #[derive(Debug, Clone)]
 pub struct Account {
-    details: Arc<RwLock<AccountDetails>>,
+    details: Arc<AccountDetailsLock>,
     pub(crate) client: Client,
     pub(crate) secret_manager: Arc<RwLock<SecretManager>>,
+    // shared by all accounts of the wallet, so accounts backed by the same seed don't derive addresses at the same time
+    pub(crate) address_generation_lock: Arc<Mutex<()>>,
+    // the accounts of the wallet, to check that aliases are unique, weak so the accounts don't keep each other alive
+    pub(crate) wallet_accounts: Weak<RwLock<Vec<Self>>>,
     // mutex to prevent multiple sync calls at the same or almost the same time, the u128 is a timestamp
     // if the last synced time was < `MIN_SYNC_INTERVAL` second ago, we don't sync, but only calculate the balance
     // again, because sending transactions can change that
     pub(crate) last_synced: Arc<Mutex<u128>>,
     pub(crate) default_sync_options: Arc<Mutex<SyncOptions>>,
+    pub(crate) health: Arc<Mutex<AccountHealth>>,
+    // the additional ledgers of the account, they aren't stored and need to be added again after a restart
+    pub(crate) ledger_backends: Arc<RwLock<Vec<Arc<dyn LedgerBackend>>>>,
+    // outputs that are locked without being used in a submitted transaction, with the time they are released at
+    pub(crate) output_reservations: Arc<Mutex<HashMap<OutputId, Instant>>>,
+    // the limits and the approver of transactions, they aren't stored and need to be set again after a restart
+    pub(crate) spending_policy: Arc<Mutex<SpendingPolicy>>,
+    pub(crate) transaction_approver: Arc<RwLock<Option<Arc<dyn TransactionApprover>>>>,
+    // the timeouts of stalled operations, they aren't stored and need to be set again after a restart
+    pub(crate) watchdog_options: Arc<Mutex<WatchdogOptions>>,
     #[cfg(feature = "events")]
     pub(crate) event_emitter: Arc<Mutex<EventEmitter>>,
     #[cfg(feature = "storage")]
     pub(crate) storage_manager: Arc<Mutex<StorageManager>>,
+    // true if the outputs and transactions were evicted from memory by the account cache and are only in the storage
+    #[cfg(feature = "storage")]
+    pub(crate) details_evicted: Arc<AtomicBool>,
 }
 
 // impl Deref so we can use `account.read()` instead of `account.details.read()`
 impl Deref for Account {
-    type Target = RwLock<AccountDetails>;
+    type Target = AccountDetailsLock;
 
     fn deref(&self) -> &Self::Target {
         self.details.deref()
@@ -175,6 +653,8 @@ impl Account {
         details: AccountDetails,
         client: Client,
         secret_manager: Arc<RwLock<SecretManager>>,
+        address_generation_lock: Arc<Mutex<()>>,
+        wallet_accounts: Weak<RwLock<Vec<Self>>>,
         #[cfg(feature = "events")] event_emitter: Arc<Mutex<EventEmitter>>,
         #[cfg(feature = "storage")] storage_manager: Arc<Mutex<StorageManager>>,
     ) -> Result<Self> {
@@ -188,21 +668,60 @@ impl Account {
         #[cfg(not(feature = "storage"))]
         let default_sync_options = Default::default();
 
+        let health = AccountHealth::new(*details.index());
+
+        #[cfg(all(feature = "events", feature = "storage"))]
+        if let Some(preferences) = storage_manager
+            .lock()
+            .await
+            .get_notification_preferences(*details.index())
+            .await?
+        {
+            event_emitter
+                .lock()
+                .await
+                .set_notification_preferences(*details.index(), preferences);
+        }
+
         Ok(Self {
-            details: Arc::new(RwLock::new(details)),
+            details: Arc::new(AccountDetailsLock::new(details)),
             client,
             secret_manager,
+            address_generation_lock,
+            wallet_accounts,
             last_synced: Default::default(),
             default_sync_options: Arc::new(Mutex::new(default_sync_options)),
+            health: Arc::new(Mutex::new(health)),
+            ledger_backends: Default::default(),
+            output_reservations: Default::default(),
+            spending_policy: Default::default(),
+            transaction_approver: Default::default(),
+            watchdog_options: Default::default(),
             #[cfg(feature = "events")]
             event_emitter,
             #[cfg(feature = "storage")]
             storage_manager,
+            #[cfg(feature = "storage")]
+            details_evicted: Default::default(),
         })
     }
 
     pub async fn alias(&self) -> String {
-        self.read().await.alias.clone()
+        self.snapshot().alias.clone()
+    }
+
+    // Returns an error for operations that need the keys of the account in the secret manager
+    pub(crate) fn check_not_watch_only(&self) -> Result<()> {
+        let account_details = self.snapshot();
+        if account_details.watch_only {
+            return Err(crate::wallet::Error::WatchOnlyAccount(account_details.index));
+        }
+        Ok(())
+    }
+
+    /// Returns the identifier of the account in an external system, if it was created for one
+    pub async fn external_id(&self) -> Option<String> {
+        self.snapshot().external_id.clone()
     }
 
     // Get the Client
@@ -212,7 +731,22 @@ impl Account {
 
     /// Get the [`OutputData`] of an output stored in the account
     pub async fn get_output(&self, output_id: &OutputId) -> Option<OutputData> {
-        self.read().await.outputs().get(output_id).cloned()
+        self.snapshot().outputs().get(output_id).cloned()
+    }
+
+    /// Get the [`OutputNote`] attached to an output
+    pub async fn get_output_note(&self, output_id: &OutputId) -> Option<OutputNote> {
+        self.snapshot().output_notes().get(output_id).cloned()
+    }
+
+    /// Returns all notes attached to outputs of the account
+    pub async fn output_notes(&self) -> HashMap<OutputId, OutputNote> {
+        self.snapshot().output_notes().clone()
+    }
+
+    /// Returns the payment references the account is waiting for
+    pub async fn expected_payment_references(&self) -> HashSet<PaymentReference> {
+        self.snapshot().expected_payment_references().clone()
     }
 
     /// Get the [`Output`] that minted a native token by the token ID. First try to get it
@@ -220,7 +754,7 @@ impl Account {
     pub async fn get_foundry_output(&self, native_token_id: TokenId) -> Result<Output> {
         let foundry_id = FoundryId::from(native_token_id);
 
-        for output_data in self.read().await.outputs().values() {
+        for output_data in self.snapshot().outputs().values() {
             if let Output::Foundry(foundry_output) = &output_data.output {
                 if foundry_output.id() == foundry_id {
                     return Ok(output_data.output.clone());
@@ -235,33 +769,132 @@ impl Account {
         Ok(output_response.output().to_owned())
     }
 
+    /// Get the IRC30 metadata of a native token, `None` if its foundry has no metadata. The foundry is taken from the
+    /// account or requested from the node and cached in the account. Fails if the metadata doesn't follow the
+    /// standard.
+    pub async fn get_native_token_metadata(&self, token_id: TokenId) -> Result<Option<Irc30Metadata>> {
+        let foundry_id = FoundryId::from(token_id);
+
+        if let Some(foundry) = self.snapshot().native_token_foundries.get(&foundry_id) {
+            return Ok(foundry.irc_30_metadata()?);
+        }
+
+        let foundry_output_id = self.client.foundry_output_id(foundry_id).await?;
+        let foundry_output = self.client.get_output(&foundry_output_id).await?;
+        let Output::Foundry(foundry) = foundry_output.output().clone() else {
+            return Err(crate::wallet::Error::InvalidOutputKind(format!("{foundry_output_id} isn't a foundry")));
+        };
+        let metadata = foundry.irc_30_metadata()?;
+
+        let mut account_details = self.write().await;
+        account_details.native_token_foundries.insert(foundry_id, foundry);
+        #[cfg(feature = "storage")]
+        self.save(Some(&account_details)).await?;
+
+        Ok(metadata)
+    }
+
+    /// Get the IRC27 metadata of an NFT, `None` if the NFT has no metadata. The NFT output is taken from the unspent
+    /// outputs of the account or requested from the node. Fails if the metadata doesn't follow the standard.
+    pub async fn get_nft_metadata(&self, nft_id: NftId) -> Result<Option<Irc27Metadata>> {
+        for output_data in self.snapshot().unspent_outputs().values() {
+            if let Output::Nft(nft_output) = &output_data.output {
+                if nft_output.nft_id_non_null(&output_data.output_id) == nft_id {
+                    return Ok(nft_output.irc_27_metadata()?);
+                }
+            }
+        }
+
+        // The NFT isn't owned by the account, try to get it from the node
+        Ok(self.client.nft_metadata(nft_id).await?)
+    }
+
     /// Get the [`Transaction`] of a transaction stored in the account
     pub async fn get_transaction(&self, transaction_id: &TransactionId) -> Option<Transaction> {
-        self.read().await.transactions().get(transaction_id).cloned()
+        self.snapshot().transactions().get(transaction_id).cloned()
     }
 
     /// Get the transaction with inputs of an incoming transaction stored in the account
     /// List might not be complete, if the node pruned the data already
     pub async fn get_incoming_transaction_data(&self, transaction_id: &TransactionId) -> Option<Transaction> {
-        self.read().await.incoming_transactions().get(transaction_id).cloned()
+        self.snapshot().incoming_transactions().get(transaction_id).cloned()
+    }
+
+    /// Returns the number of milestones that confirmed a transaction stored in the account, counting the one that
+    /// included it, or `None` if the transaction isn't confirmed yet. The inclusion of the transaction is only updated
+    /// by syncing the account.
+    pub async fn transaction_confirmations(&self, transaction_id: &TransactionId) -> Result<Option<u32>> {
+        let transaction = self
+            .get_transaction(transaction_id)
+            .await
+            .ok_or(crate::wallet::Error::TransactionNotFound(*transaction_id))?;
+        // No need to request the node info if the transaction isn't confirmed
+        if transaction.milestone_index.is_none() {
+            return Ok(None);
+        }
+        let confirmed_milestone_index = self.client.get_info().await?.node_info.status.confirmed_milestone.index;
+
+        Ok(transaction.confirmations(confirmed_milestone_index))
     }
 
     /// Returns all addresses of the account
     pub async fn addresses(&self) -> Result<Vec<AccountAddress>> {
-        let account_details = self.read().await;
+        let account_details = self.snapshot();
         let mut all_addresses = account_details.public_addresses().clone();
         all_addresses.extend(account_details.internal_addresses().clone());
         Ok(all_addresses.to_vec())
     }
 
+    /// Returns the addresses of the alias and NFT outputs owned by the account as of the last sync, so funds can be
+    /// received directly on them.
+    pub async fn chain_addresses(&self) -> Result<Vec<ChainAddress>> {
+        let bech32_hrp = self.client.get_bech32_hrp().await?;
+        let account_details = self.snapshot();
+
+        let mut chain_addresses = Vec::new();
+        for output_data in account_details.unspent_outputs().values() {
+            let address = match &output_data.output {
+                Output::Alias(alias_output) => {
+                    Address::Alias(AliasAddress::from(alias_output.alias_id_non_null(&output_data.output_id)))
+                }
+                Output::Nft(nft_output) => {
+                    Address::Nft(NftAddress::from(nft_output.nft_id_non_null(&output_data.output_id)))
+                }
+                _ => continue,
+            };
+            chain_addresses.push(ChainAddress {
+                address: Bech32Address::new(bech32_hrp.clone(), address)?,
+                output_id: output_data.output_id,
+                owner: Bech32Address::new(bech32_hrp.clone(), output_data.address)?,
+            });
+        }
+
+        Ok(chain_addresses)
+    }
+
+    /// Returns all addresses of the account with the provided label
+    pub async fn addresses_with_label(&self, label: &str) -> Result<Vec<AccountAddress>> {
+        Ok(self
+            .addresses()
+            .await?
+            .into_iter()
+            .filter(|address| address.label().as_deref() == Some(label))
+            .collect())
+    }
+
+    /// Returns all frozen addresses of the account
+    pub async fn frozen_addresses(&self) -> Result<Vec<AccountAddress>> {
+        Ok(self.addresses().await?.into_iter().filter(|address| address.frozen).collect())
+    }
+
     /// Returns all public addresses of the account
     pub(crate) async fn public_addresses(&self) -> Vec<AccountAddress> {
-        self.read().await.public_addresses().to_vec()
+        self.snapshot().public_addresses().to_vec()
     }
 
     /// Returns only addresses of the account with balance
     pub async fn addresses_with_unspent_outputs(&self) -> Result<Vec<AddressWithUnspentOutputs>> {
-        Ok(self.read().await.addresses_with_unspent_outputs().to_vec())
+        Ok(self.snapshot().addresses_with_unspent_outputs().to_vec())
     }
 
     fn filter_outputs<'a>(
@@ -272,61 +905,7 @@ impl Account {
         let filter = filter.into();
 
         if let Some(filter) = filter {
-            let mut filtered_outputs = Vec::new();
-
-            for output in outputs {
-                match &output.output {
-                    Output::Alias(alias) => {
-                        if let Some(alias_ids) = &filter.alias_ids {
-                            let alias_id = alias.alias_id_non_null(&output.output_id);
-                            if alias_ids.contains(&alias_id) {
-                                filtered_outputs.push(output.clone());
-                                continue;
-                            }
-                        }
-                    }
-                    Output::Foundry(foundry) => {
-                        if let Some(foundry_ids) = &filter.foundry_ids {
-                            let foundry_id = foundry.id();
-                            if foundry_ids.contains(&foundry_id) {
-                                filtered_outputs.push(output.clone());
-                                continue;
-                            }
-                        }
-                    }
-                    Output::Nft(nft) => {
-                        if let Some(nft_ids) = &filter.nft_ids {
-                            let nft_id = nft.nft_id_non_null(&output.output_id);
-                            if nft_ids.contains(&nft_id) {
-                                filtered_outputs.push(output.clone());
-                                continue;
-                            }
-                        }
-                    }
-                    _ => {}
-                }
-
-                if let Some(lower_bound_booked_timestamp) = filter.lower_bound_booked_timestamp {
-                    if output.metadata.milestone_timestamp_booked() < lower_bound_booked_timestamp {
-                        continue;
-                    }
-                }
-                if let Some(upper_bound_booked_timestamp) = filter.upper_bound_booked_timestamp {
-                    if output.metadata.milestone_timestamp_booked() > upper_bound_booked_timestamp {
-                        continue;
-                    }
-                }
-
-                if let Some(output_types) = &filter.output_types {
-                    if !output_types.contains(&output.output.kind()) {
-                        continue;
-                    }
-                }
-
-                filtered_outputs.push(output.clone());
-            }
-
-            Ok(filtered_outputs)
+            Ok(outputs.filter(|output| filter.matches(output)).cloned().collect())
         } else {
             Ok(outputs.cloned().collect())
         }
@@ -334,12 +913,49 @@ impl Account {
 
     /// Returns outputs of the account
     pub async fn outputs(&self, filter: impl Into<Option<FilterOptions>> + Send) -> Result<Vec<OutputData>> {
-        self.filter_outputs(self.read().await.outputs.values(), filter)
+        self.filter_outputs(self.snapshot().outputs.values(), filter)
     }
 
     /// Returns unspent outputs of the account
     pub async fn unspent_outputs(&self, filter: impl Into<Option<FilterOptions>> + Send) -> Result<Vec<OutputData>> {
-        self.filter_outputs(self.read().await.unspent_outputs.values(), filter)
+        self.filter_outputs(self.snapshot().unspent_outputs.values(), filter)
+    }
+
+    /// Returns a page of the filtered outputs of the account, ordered from the newest to the oldest booked output.
+    /// Only the outputs of the page are cloned, so this is cheaper than [`Account::outputs()`] for large accounts.
+    pub async fn outputs_page(
+        &self,
+        filter: impl Into<Option<FilterOptions>> + Send,
+        offset: usize,
+        limit: usize,
+    ) -> Result<Vec<OutputData>> {
+        let filter = filter.into().unwrap_or_default();
+        let account_details = self.snapshot();
+
+        let mut outputs = account_details
+            .outputs
+            .values()
+            .filter(|output| filter.matches(output))
+            .collect::<Vec<_>>();
+        outputs.sort_unstable_by(|a, b| {
+            b.metadata
+                .milestone_timestamp_booked()
+                .cmp(&a.metadata.milestone_timestamp_booked())
+                .then_with(|| a.output_id.cmp(&b.output_id))
+        });
+
+        Ok(outputs.into_iter().skip(offset).take(limit).cloned().collect())
+    }
+
+    /// Returns a stream of the filtered outputs of the account, in the order of [`Account::outputs_page()`]. The
+    /// outputs are lazily fetched in pages of `page_size`, so the account isn't locked while the stream is consumed.
+    pub fn outputs_stream(
+        &self,
+        filter: impl Into<Option<FilterOptions>>,
+        page_size: usize,
+    ) -> impl Stream<Item = Result<OutputData>> + '_ {
+        let filter = filter.into();
+        paginate(page_size, move |offset| self.outputs_page(filter.clone(), offset, page_size))
     }
 
     /// Gets the unspent alias output matching the given ID.
@@ -374,18 +990,55 @@ impl Account {
 
     /// Returns all incoming transactions of the account
     pub async fn incoming_transactions(&self) -> Result<HashMap<TransactionId, Transaction>> {
-        Ok(self.read().await.incoming_transactions.clone())
+        Ok(self.snapshot().incoming_transactions().clone())
     }
 
     /// Returns all transactions of the account
     pub async fn transactions(&self) -> Result<Vec<Transaction>> {
-        Ok(self.read().await.transactions.values().cloned().collect())
+        Ok(self.snapshot().transactions.values().cloned().collect())
+    }
+
+    /// Returns a page of the filtered transactions of the account, ordered from the newest to the oldest transaction.
+    pub async fn transactions_page(
+        &self,
+        filter: impl Into<Option<TransactionFilterOptions>> + Send,
+        offset: usize,
+        limit: usize,
+    ) -> Result<Vec<Transaction>> {
+        let filter = filter.into().unwrap_or_default();
+        let account_details = self.snapshot();
+
+        let mut transactions = account_details
+            .transactions
+            .values()
+            .filter(|transaction| filter.matches(transaction))
+            .collect::<Vec<_>>();
+        transactions.sort_unstable_by(|a, b| {
+            b.timestamp
+                .cmp(&a.timestamp)
+                .then_with(|| a.transaction_id.cmp(&b.transaction_id))
+        });
+
+        Ok(transactions.into_iter().skip(offset).take(limit).cloned().collect())
+    }
+
+    /// Returns a stream of the filtered transactions of the account, in the order of
+    /// [`Account::transactions_page()`]. The transactions are lazily fetched in pages of `page_size`.
+    pub fn transactions_stream(
+        &self,
+        filter: impl Into<Option<TransactionFilterOptions>>,
+        page_size: usize,
+    ) -> impl Stream<Item = Result<Transaction>> + '_ {
+        let filter = filter.into();
+        paginate(page_size, move |offset| {
+            self.transactions_page(filter.clone(), offset, page_size)
+        })
     }
 
     /// Returns all pending transactions of the account
     pub async fn pending_transactions(&self) -> Result<Vec<Transaction>> {
         let mut transactions = Vec::new();
-        let account_details = self.read().await;
+        let account_details = self.snapshot();
 
         for transaction_id in &account_details.pending_transactions {
             if let Some(transaction) = account_details.transactions.get(transaction_id) {
@@ -399,6 +1052,7 @@ impl Account {
     /// Save the account to the database, accepts the updated_account as option so we don't need to drop it before
     /// saving
     #[cfg(feature = "storage")]
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip_all))]
     pub(crate) async fn save(&self, updated_account: Option<&AccountDetails>) -> Result<()> {
         log::debug!("[save] saving account to database");
         match updated_account {
@@ -417,10 +1071,125 @@ impl Account {
         }
         Ok(())
     }
+
+    /// Drops the outputs and transactions of the account from memory, they're loaded from the storage again with
+    /// [`Account::load_details()`]. Returns false if the account is in use, then nothing is evicted.
+    #[cfg(feature = "storage")]
+    pub(crate) async fn evict_details(&self) -> Result<bool> {
+        // The wallet holds one handle of every account, any other handle means the account is in use. Checked first to
+        // not wait for the lock of an account in use.
+        if Arc::strong_count(&self.details) > 1 || self.details_evicted.load(Ordering::Acquire) {
+            return Ok(false);
+        }
+
+        let mut account_details = self.write().await;
+        // Checked again under the lock, a handle could have been cloned while waiting for it. Marked as evicted right
+        // away, so `load_details()` of a handle cloned from now on waits for the lock and loads the details again.
+        if Arc::strong_count(&self.details) > 1 || self.details_evicted.swap(true, Ordering::AcqRel) {
+            return Ok(false);
+        }
+        // Make sure the storage is up to date, as it will be the only place with the details
+        if let Err(err) = self.save(Some(&account_details)).await {
+            self.details_evicted.store(false, Ordering::Release);
+            return Err(err);
+        }
+
+        account_details.outputs = Shared::default();
+        account_details.unspent_outputs = Shared::default();
+        account_details.transactions = Shared::default();
+        account_details.incoming_transactions = Shared::default();
+        account_details.inaccessible_incoming_transactions = HashSet::new();
+        account_details.native_token_foundries = HashMap::new();
+        log::debug!("[evict_details] evicted details of account {}", account_details.index());
+
+        Ok(true)
+    }
+
+    /// Loads the outputs and transactions of the account from the storage again, if they were evicted by
+    /// [`Account::evict_details()`].
+    #[cfg(feature = "storage")]
+    pub(crate) async fn load_details(&self) -> Result<()> {
+        if !self.details_evicted.load(Ordering::Acquire) {
+            return Ok(());
+        }
+
+        let mut account_details = self.write().await;
+        // Another task could have loaded them while waiting for the lock
+        if !self.details_evicted.load(Ordering::Acquire) {
+            return Ok(());
+        }
+
+        let account_index = *account_details.index();
+        let stored_account = self
+            .storage_manager
+            .lock()
+            .await
+            .get_account(account_index)
+            .await?
+            .ok_or_else(|| crate::wallet::Error::AccountNotFound(account_index.to_string()))?;
+
+        account_details.outputs = stored_account.outputs;
+        account_details.unspent_outputs = stored_account.unspent_outputs;
+        account_details.transactions = stored_account.transactions;
+        account_details.incoming_transactions = stored_account.incoming_transactions;
+        account_details.inaccessible_incoming_transactions = stored_account.inaccessible_incoming_transactions;
+        account_details.native_token_foundries = stored_account.native_token_foundries;
+        self.details_evicted.store(false, Ordering::Release);
+        log::debug!("[load_details] loaded details of account {account_index}");
+
+        Ok(())
+    }
+
+    /// Returns the events of the account state, they're only appended in the event sourced storage mode, see
+    /// [`WalletBuilder::with_event_sourcing()`](crate::wallet::WalletBuilder::with_event_sourcing).
+    #[cfg(feature = "storage")]
+    pub async fn state_events(&self) -> Result<Vec<AccountStateEvent>> {
+        let account_index = *self.snapshot().index();
+
+        self.storage_manager.lock().await.get_account_events(account_index).await
+    }
+
+    /// Reconstructs the account as it was at the unix timestamp in milliseconds, from the events of the event sourced
+    /// storage mode.
+    #[cfg(feature = "storage")]
+    pub async fn state_at(&self, timestamp: u128) -> Result<Option<AccountDetails>> {
+        let account_index = *self.snapshot().index();
+
+        self.storage_manager
+            .lock()
+            .await
+            .get_account_at(account_index, timestamp)
+            .await
+    }
+}
+
+// Lazily requests pages starting at offset 0, until a page has less than `page_size` items
+fn paginate<'a, T, F, Fut>(page_size: usize, fetch_page: F) -> impl Stream<Item = Result<T>> + 'a
+where
+    T: 'a,
+    F: FnMut(usize) -> Fut + 'a,
+    Fut: Future<Output = Result<Vec<T>>> + 'a,
+{
+    futures::stream::try_unfold((Some(0), fetch_page), move |(offset, mut fetch_page)| async move {
+        // No offset means the last page wasn't full
+        match offset {
+            Some(offset) if page_size > 0 => fetch_page(offset).await.map(|page| {
+                let next_offset = (page.len() == page_size).then_some(offset + page_size);
+                Some((
+                    futures::stream::iter(page.into_iter().map(Result::Ok)),
+                    (next_offset, fetch_page),
+                ))
+            }),
+            _ => Ok(None),
+        }
+    })
+    .try_flatten()
 }
 
 // Custom deserialization to stay backwards compatible
-fn deserialize_or_convert<'de, D>(deserializer: D) -> std::result::Result<HashMap<TransactionId, Transaction>, D::Error>
+fn deserialize_or_convert<'de, D>(
+    deserializer: D,
+) -> std::result::Result<Shared<HashMap<TransactionId, Transaction>>, D::Error>
 where
     D: Deserializer<'de>,
 {
@@ -429,7 +1198,7 @@ where
     type NewType = HashMap<TransactionId, Transaction>;
     type OldType = HashMap<TransactionId, (TransactionPayload, Vec<OutputWithMetadataResponse>)>;
 
-    Ok(match serde_json::from_value::<NewType>(value.clone()) {
+    Ok(Shared::from(match serde_json::from_value::<NewType>(value.clone()) {
         Ok(r) => r,
         Err(_) => {
             let v = serde_json::from_value::<OldType>(value).map_err(de::Error::custom)?;
@@ -442,7 +1211,7 @@ where
             }
             new
         }
-    })
+    }))
 }
 
 pub(crate) fn build_transaction_from_payload_and_inputs(
@@ -465,6 +1234,9 @@ pub(crate) fn build_transaction_from_payload_and_inputs(
         network_id: tx_essence.network_id(),
         incoming: true,
         note: None,
+        milestone_index: inputs.first().and_then(|i| i.metadata.milestone_index_spent),
+        confirmed_at: None,
+        attachments: Vec::new(),
         inputs,
     })
 }
@@ -540,6 +1312,9 @@ fn serialize() {
         incoming: false,
         note: None,
         inputs: Vec::new(),
+        milestone_index: None,
+        confirmed_at: None,
+        attachments: Vec::new(),
     };
 
     let mut incoming_transactions = HashMap::new();
@@ -552,18 +1327,120 @@ fn serialize() {
         index: 0,
         coin_type: 4218,
         alias: "0".to_string(),
+        external_id: None,
         public_addresses: Vec::new(),
         internal_addresses: Vec::new(),
         addresses_with_unspent_outputs: Vec::new(),
-        outputs: HashMap::new(),
+        outputs: Shared::default(),
         locked_outputs: HashSet::new(),
-        unspent_outputs: HashMap::new(),
-        transactions: HashMap::new(),
+        unspent_outputs: Shared::default(),
+        transactions: Shared::default(),
         pending_transactions: HashSet::new(),
-        incoming_transactions,
+        incoming_transactions: incoming_transactions.into(),
         inaccessible_incoming_transactions: HashSet::new(),
         native_token_foundries: HashMap::new(),
+        output_notes: HashMap::new(),
+        expected_payment_references: HashSet::new(),
+        asset_quarantine: false,
+        quarantined_assets: HashSet::new(),
+        accepted_assets: HashSet::new(),
+        watch_only: false,
+        metadata: HashMap::new(),
+        privacy_options: PrivacyOptions::default(),
+        output_defaults: OutputDefaults::default(),
+        dust_protection: DustProtection::default(),
     };
 
     serde_json::from_str::<AccountDetails>(&serde_json::to_string(&account).unwrap()).unwrap();
 }
+
+#[test]
+fn filter_options() {
+    use crate::types::block::{
+        address::{Address, Ed25519Address},
+        output::{unlock_condition::AddressUnlockCondition, BasicOutput, OutputMetadata},
+    };
+
+    let address = Address::from(Ed25519Address::new([1; 32]));
+    let other_address = Address::from(Ed25519Address::new([2; 32]));
+    let output_data = |index: u16, amount: u64, is_spent: bool, booked: u32| {
+        let output_id = OutputId::new(TransactionId::null(), index).unwrap();
+        OutputData {
+            output_id,
+            metadata: OutputMetadata::new(BlockId::null(), output_id, is_spent, None, None, None, 0, booked, 0),
+            output: Output::Basic(
+                BasicOutput::build_with_amount(amount)
+                    .add_unlock_condition(AddressUnlockCondition::new(address))
+                    .finish(1_813_620_509_061_365)
+                    .unwrap(),
+            ),
+            is_spent,
+            address,
+            network_id: 0,
+            remainder: false,
+            chain: None,
+        }
+    };
+
+    let outputs = [
+        output_data(0, 1_000_000, false, 100),
+        output_data(1, 2_000_000, true, 200),
+        output_data(2, 3_000_000, false, 300),
+    ];
+    let matching = |filter: FilterOptions| {
+        outputs
+            .iter()
+            .filter(|output| filter.matches(output))
+            .map(|output| output.output_id.index())
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(matching(FilterOptions::default()), [0, 1, 2]);
+    assert_eq!(
+        matching(FilterOptions {
+            is_spent: Some(false),
+            ..Default::default()
+        }),
+        [0, 2]
+    );
+    assert_eq!(
+        matching(FilterOptions {
+            lower_bound_amount: Some(1_500_000),
+            upper_bound_amount: Some(3_000_000),
+            ..Default::default()
+        }),
+        [1, 2]
+    );
+    assert_eq!(
+        matching(FilterOptions {
+            lower_bound_booked_timestamp: Some(150),
+            upper_bound_booked_timestamp: Some(250),
+            ..Default::default()
+        }),
+        [1]
+    );
+    assert_eq!(
+        matching(FilterOptions {
+            output_types: Some(vec![crate::types::block::output::NftOutput::KIND]),
+            ..Default::default()
+        }),
+        Vec::<u16>::new()
+    );
+    assert_eq!(
+        matching(FilterOptions {
+            address: Some(Bech32Address::new("rms".to_string(), other_address).unwrap()),
+            ..Default::default()
+        }),
+        Vec::<u16>::new()
+    );
+
+    let transaction_filter = TransactionFilterOptions {
+        lower_bound_timestamp: Some(100),
+        incoming: Some(false),
+        inclusion_states: Some(vec![InclusionState::Confirmed]),
+        ..Default::default()
+    };
+    let deserialized: TransactionFilterOptions =
+        serde_json::from_str(&serde_json::to_string(&transaction_filter).unwrap()).unwrap();
+    assert_eq!(deserialized, transaction_filter);
+}
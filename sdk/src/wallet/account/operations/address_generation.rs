@@ -1,24 +1,76 @@
 // Copyright 2021 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+use serde::{Deserialize, Serialize};
+
 #[cfg(all(feature = "events", any(feature = "ledger_nano", feature = "ledger_nano")))]
-use crate::wallet::events::types::{AddressData, WalletEvent};
+use crate::wallet::events::types::{AddressData, LedgerAddressDisplay, LedgerDisplayEvent, WalletEvent};
 use crate::{
     client::secret::{GenerateAddressOptions, SecretManage, SecretManager},
     types::block::address::Bech32Address,
-    wallet::account::{types::address::AccountAddress, Account},
+    wallet::account::{operations::privacy::check_address_reuse, types::address::AccountAddress, Account},
 };
 
+/// Options to generate addresses with [`Account::generate_addresses()`].
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct AddressGenerationOptions {
+    /// Generate internal (change) addresses instead of public ones.
+    pub internal: bool,
+    /// The key index of the first address, by default the index after the highest one of the account. Addresses with
+    /// an index that the account already has are returned, but not stored again.
+    pub start_index: Option<u32>,
+    /// The bech32 HRP of the returned addresses, by default the one of the network. The addresses are always stored
+    /// with the HRP of the network.
+    pub bech32_hrp: Option<String>,
+    /// Display the addresses on ledger devices.
+    pub ledger_nano_prompt: bool,
+}
+
+impl AddressGenerationOptions {
+    /// Options to generate internal addresses.
+    pub fn internal() -> Self {
+        Self {
+            internal: true,
+            ..Default::default()
+        }
+    }
+}
+
+impl From<GenerateAddressOptions> for AddressGenerationOptions {
+    fn from(value: GenerateAddressOptions) -> Self {
+        Self {
+            internal: value.internal,
+            ledger_nano_prompt: value.ledger_nano_prompt,
+            ..Default::default()
+        }
+    }
+}
+
+impl From<&AddressGenerationOptions> for GenerateAddressOptions {
+    fn from(value: &AddressGenerationOptions) -> Self {
+        Self {
+            internal: value.internal,
+            ledger_nano_prompt: value.ledger_nano_prompt,
+        }
+    }
+}
+
 impl Account {
     /// Generate addresses and stores them in the account
     /// ```ignore
     /// let public_addresses = account.generate_addresses(2, None).await?;
     /// // internal addresses are used for remainder outputs, if the RemainderValueStrategy for transactions is set to ChangeAddress
     /// let internal_addresses = account
+    ///     .generate_addresses(1, Some(AddressGenerationOptions::internal()))
+    ///     .await?;
+    /// // regenerate the first public address with the HRP of another network
+    /// let address = account
     ///     .generate_addresses(
     ///         1,
-    ///         Some(GenerateAddressOptions {
-    ///             internal: true,
+    ///         Some(AddressGenerationOptions {
+    ///             start_index: Some(0),
+    ///             bech32_hrp: Some("rms".to_string()),
     ///             ..Default::default()
     ///         }),
     ///     )
@@ -27,25 +79,31 @@ impl Account {
     pub async fn generate_addresses(
         &self,
         amount: u32,
-        options: Option<GenerateAddressOptions>,
+        options: Option<AddressGenerationOptions>,
     ) -> crate::wallet::Result<Vec<AccountAddress>> {
         let options = options.unwrap_or_default();
         log::debug!(
-            "[ADDRESS GENERATION] generating {amount} addresses, internal: {}",
-            options.internal
+            "[ADDRESS GENERATION] generating {amount} addresses, internal: {}, start index: {:?}",
+            options.internal,
+            options.start_index
         );
         if amount == 0 {
             return Ok(vec![]);
         }
+        self.check_not_watch_only()?;
 
+        // held until the addresses are stored, so concurrent calls don't generate the same indexes
+        let address_generation_guard = self.address_generation_lock.lock().await;
         let account_details = self.read().await;
 
         // get the highest index for the public or internal addresses
-        let highest_current_index_plus_one = if options.internal {
-            account_details.internal_addresses.len() as u32
+        let addresses = if options.internal {
+            &account_details.internal_addresses
         } else {
-            account_details.public_addresses.len() as u32
+            &account_details.public_addresses
         };
+        let highest_current_index_plus_one = addresses.last().map_or(0, |address| address.key_index + 1);
+        let start_index = options.start_index.unwrap_or(highest_current_index_plus_one);
 
         // get bech32_hrp
         let bech32_hrp = {
@@ -55,7 +113,8 @@ impl Account {
             }
         };
 
-        let address_range = highest_current_index_plus_one..highest_current_index_plus_one + amount;
+        let address_range = start_index..start_index.saturating_add(amount);
+        let secret_manager_options = GenerateAddressOptions::from(&options);
 
         let addresses = match &*self.secret_manager.read().await {
             #[cfg(feature = "ledger_nano")]
@@ -67,7 +126,7 @@ impl Account {
                     #[cfg(feature = "events")]
                     let changed_options = {
                         // Change options so ledger will not show the prompt the first time
-                        let mut changed_options = options;
+                        let mut changed_options = secret_manager_options;
                         changed_options.ledger_nano_prompt = false;
                         changed_options
                     };
@@ -85,11 +144,22 @@ impl Account {
                                     Some(changed_options),
                                 )
                                 .await?;
-                            self.event_emitter.lock().await.emit(
+                            let address = address[0].to_bech32(bech32_hrp.clone());
+                            let display = LedgerAddressDisplay::new(
+                                account_details.coin_type,
                                 account_details.index,
-                                WalletEvent::LedgerAddressGeneration(AddressData {
-                                    address: address[0].to_bech32(bech32_hrp.clone()),
-                                }),
+                                options.internal,
+                                address_index,
+                                address.clone(),
+                            );
+                            let mut event_emitter = self.event_emitter.lock().await;
+                            event_emitter.emit(
+                                account_details.index,
+                                WalletEvent::LedgerAddressGeneration(AddressData { address }),
+                            );
+                            event_emitter.emit(
+                                account_details.index,
+                                WalletEvent::LedgerDisplay(Box::new(LedgerDisplayEvent::Address(display))),
                             );
                         }
                         // Generate with prompt so the user can verify
@@ -98,7 +168,7 @@ impl Account {
                                 account_details.coin_type,
                                 account_details.index,
                                 address_index..address_index + 1,
-                                Some(options),
+                                Some(secret_manager_options),
                             )
                             .await?;
                         addresses.push(address[0]);
@@ -110,7 +180,7 @@ impl Account {
                             account_details.coin_type,
                             account_details.index,
                             address_range.clone(),
-                            Some(options),
+                            Some(secret_manager_options),
                         )
                         .await?
                 }
@@ -122,7 +192,18 @@ impl Account {
                         account_details.coin_type,
                         account_details.index,
                         address_range,
-                        Some(options),
+                        Some(secret_manager_options),
+                    )
+                    .await?
+            }
+            #[cfg(feature = "pkcs11")]
+            SecretManager::Pkcs11(pkcs11) => {
+                pkcs11
+                    .generate_addresses(
+                        account_details.coin_type,
+                        account_details.index,
+                        address_range,
+                        Some(secret_manager_options),
                     )
                     .await?
             }
@@ -132,36 +213,58 @@ impl Account {
                         account_details.coin_type,
                         account_details.index,
                         address_range,
-                        Some(options),
+                        Some(secret_manager_options),
                     )
                     .await?
             }
             SecretManager::Placeholder(_) => vec![],
         };
 
-        drop(account_details);
-
         let generate_addresses: Vec<AccountAddress> = addresses
             .into_iter()
             .enumerate()
             .map(|(index, address)| AccountAddress {
                 address: Bech32Address::new(bech32_hrp.clone(), address).unwrap(),
-                key_index: highest_current_index_plus_one + index as u32,
+                key_index: start_index + index as u32,
                 internal: options.internal,
                 used: false,
+                frozen: false,
+                label: None,
             })
             .collect();
 
+        let known_addresses = if options.internal {
+            &account_details.internal_addresses
+        } else {
+            &account_details.public_addresses
+        };
+        check_address_reuse(
+            account_details.privacy_options.address_reuse,
+            known_addresses,
+            &generate_addresses,
+        )?;
+        drop(account_details);
+
         self.update_account_addresses(options.internal, generate_addresses.clone())
             .await?;
+        drop(address_generation_guard);
 
-        Ok(generate_addresses)
+        match options.bech32_hrp {
+            Some(bech32_hrp) => Ok(generate_addresses
+                .into_iter()
+                .map(|mut address| {
+                    address.address.hrp = bech32_hrp.clone();
+                    address
+                })
+                .collect()),
+            None => Ok(generate_addresses),
+        }
     }
 
     /// Generate an internal address and store in the account, internal addresses are used for remainder outputs
     pub(crate) async fn generate_remainder_address(&self) -> crate::wallet::Result<AccountAddress> {
         let result = self
-            .generate_addresses(1, Some(GenerateAddressOptions::internal()))
+            .generate_addresses(1, Some(AddressGenerationOptions::internal()))
             .await?
             .first()
             .ok_or(crate::wallet::Error::FailedToGetRemainder)?
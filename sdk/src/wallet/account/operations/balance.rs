@@ -1,14 +1,19 @@
 // Copyright 2022 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+use std::{collections::HashSet, time::Duration};
+
+use futures::Stream;
 use primitive_types::U256;
+use tokio::sync::watch;
 
 use crate::{
-    types::block::output::{unlock_condition::UnlockCondition, FoundryId, NativeTokensBuilder, Output, Rent},
+    types::block::output::{unlock_condition::UnlockCondition, FoundryId, NativeTokensBuilder, Output},
+    utils::unix_timestamp_now,
     wallet::account::{
-        operations::helpers::time::can_output_be_unlocked_forever_from_now_on,
-        types::{AccountBalance, NativeTokensBalance},
-        Account, OutputsToClaim,
+        operations::helpers::time::{can_output_be_unlocked_forever_from_now_on, can_output_be_unlocked_now},
+        types::{AccountBalance, HistoricalBalance, NativeTokensBalance},
+        Account,
     },
 };
 
@@ -22,30 +27,54 @@ impl Account {
             account_balance.base_coin.voting_power = self.get_voting_power().await?;
         }
 
-        let unlockable_outputs_with_multiple_unlock_conditions = self
-            .get_unlockable_outputs_with_additional_unlock_conditions(OutputsToClaim::All)
-            .await?;
-
         let account_addresses = self.addresses().await?;
 
         let network_id = self.client.get_network_id().await?;
         let rent_structure = self.client.get_rent_structure().await?;
 
-        let local_time = self.client.get_time_checked().await?;
+        let current_time = self.balance_time().await?;
 
         let mut total_rent_amount = 0;
         let mut total_native_tokens = NativeTokensBuilder::new();
 
-        let account_details = self.read().await;
+        // Read from the snapshot, so a running sync or transaction doesn't block the balance
+        let account_details = self.snapshot();
+        // Outputs with quarantined assets aren't part of the balance until the assets are accepted
+        let quarantined_outputs = account_details.quarantined_output_ids();
+        // Outputs locked to the alias and nft outputs of the account are owned by it as well
+        let alias_and_nft_addresses = account_details.alias_and_nft_addresses();
+
+        // The amount of an output that belongs to us, without a storage deposit that needs to be sent back
+        let claimable_amount = |output: &Output| {
+            output
+                .unlock_conditions()
+                .and_then(|u| u.storage_deposit_return())
+                .map_or_else(
+                    || output.amount(),
+                    |sdr| {
+                        if account_addresses
+                            .iter()
+                            .any(|a| a.address.inner == *sdr.return_address())
+                            || alias_and_nft_addresses.contains(sdr.return_address())
+                        {
+                            // sending to ourself, we get the full amount
+                            output.amount()
+                        } else {
+                            // Sending to someone else
+                            output.amount() - sdr.amount()
+                        }
+                    },
+                )
+        };
 
         let relevant_unspent_outputs = account_details
             .unspent_outputs
             .values()
             // Check if output is from the network we're currently connected to
-            .filter(|data| data.network_id == network_id)
-            .map(|data| (&data.output_id, &data.output));
+            .filter(|data| data.network_id == network_id && !quarantined_outputs.contains(&data.output_id));
 
-        for (output_id, output) in relevant_unspent_outputs {
+        for output_data in relevant_unspent_outputs {
+            let (output_id, output) = (&output_data.output_id, &output_data.output);
             let rent = output.rent_cost(&rent_structure);
 
             // Add alias and foundry outputs here because they can't have a [`StorageDepositReturnUnlockCondition`]
@@ -122,9 +151,39 @@ impl Account {
                     } else {
                         // if we have multiple unlock conditions for basic or nft outputs, then we might can't spend the
                         // balance at the moment or in the future
-
-                        let output_can_be_unlocked_now =
-                            unlockable_outputs_with_multiple_unlock_conditions.contains(output_id);
+                        let unlock_conditions = output
+                            .unlock_conditions()
+                            .expect("output needs to have unlock conditions");
+
+                        // Outputs that are locked for other transactions aren't claimable
+                        let output_can_be_unlocked_at = |time| {
+                            Ok::<_, crate::wallet::Error>(
+                                !account_details.locked_outputs.contains(output_id)
+                                    && can_output_be_unlocked_now(
+                                        // We use the addresses with unspent outputs, because other addresses of the
+                                        // account without unspent outputs can't be related to this output
+                                        &account_details.addresses_with_unspent_outputs,
+                                        &alias_and_nft_addresses,
+                                        output_data,
+                                        time,
+                                        // Only relevant for alias outputs, which are handled above
+                                        None,
+                                    )?,
+                            )
+                        };
+                        let output_can_be_unlocked_now = output_can_be_unlocked_at(current_time)?;
+
+                        // Outputs with a timelock that didn't elapse yet are locked, if they're for us once it elapsed
+                        if let Some(timelock) = unlock_conditions
+                            .timelock()
+                            .filter(|timelock| current_time < timelock.timestamp())
+                        {
+                            if output_can_be_unlocked_at(timelock.timestamp())? {
+                                account_balance.base_coin.locked += claimable_amount(output);
+                            }
+                            account_balance.potentially_locked_outputs.insert(*output_id, false);
+                            continue;
+                        }
 
                         // For outputs that are expired or have a timelock unlock condition, but no expiration unlock
                         // condition and we then can unlock them, then they can never be not available for us anymore
@@ -136,31 +195,15 @@ impl Account {
                                 // We use the addresses with unspent outputs, because other addresses of the
                                 // account without unspent outputs can't be related to this output
                                 &account_details.addresses_with_unspent_outputs,
+                                &alias_and_nft_addresses,
                                 output,
-                                local_time,
+                                current_time,
                             );
 
                             if output_can_be_unlocked_now_and_in_future {
                                 // If output has a StorageDepositReturnUnlockCondition, the amount of it should be
                                 // subtracted, because this part needs to be sent back
-                                let amount = output
-                                    .unlock_conditions()
-                                    .and_then(|u| u.storage_deposit_return())
-                                    .map_or_else(
-                                        || output.amount(),
-                                        |sdr| {
-                                            if account_addresses
-                                                .iter()
-                                                .any(|a| a.address.inner == *sdr.return_address())
-                                            {
-                                                // sending to ourself, we get the full amount
-                                                output.amount()
-                                            } else {
-                                                // Sending to someone else
-                                                output.amount() - sdr.amount()
-                                            }
-                                        },
-                                    );
+                                let amount = claimable_amount(output);
 
                                 // add nft_id for nft outputs
                                 if let Output::Nft(output) = &output {
@@ -196,18 +239,16 @@ impl Account {
                                     total_native_tokens.add_native_tokens(native_tokens.clone())?;
                                 }
                             } else {
+                                // An expiration that still favors us, the output can only be claimed until it expires
+                                account_balance.base_coin.expiring_soon += claimable_amount(output);
                                 // only add outputs that can't be locked now and at any point in the future
                                 account_balance.potentially_locked_outputs.insert(*output_id, true);
                             }
                         } else {
                             // Don't add expired outputs that can't ever be unlocked by us
-                            if let Some(expiration) = output
-                                .unlock_conditions()
-                                .expect("output needs to have unlock conditions")
-                                .expiration()
-                            {
+                            if let Some(expiration) = unlock_conditions.expiration() {
                                 // Not expired, could get unlockable when it's expired, so we insert it
-                                if local_time < expiration.timestamp() {
+                                if current_time < expiration.timestamp() {
                                     account_balance.potentially_locked_outputs.insert(*output_id, false);
                                 }
                             } else {
@@ -259,15 +300,19 @@ impl Account {
                 }
             });
 
-            let metadata = account_details
+            let foundry = account_details
                 .native_token_foundries
-                .get(&FoundryId::from(*native_token.token_id()))
+                .get(&FoundryId::from(*native_token.token_id()));
+            let metadata = foundry
                 .and_then(|foundry| foundry.immutable_features().metadata())
                 .cloned();
+            // Metadata that doesn't follow the standard is still available undecoded
+            let irc_30_metadata = foundry.and_then(|foundry| foundry.irc_30_metadata().ok().flatten());
 
             account_balance.native_tokens.push(NativeTokensBalance {
                 token_id: *native_token.token_id(),
                 metadata,
+                irc_30_metadata,
                 total: native_token.amount(),
                 available: native_token.amount() - *locked_native_token_amount.unwrap_or(&U256::from(0u8)),
             })
@@ -288,4 +333,155 @@ impl Account {
 
         Ok(account_balance)
     }
+
+    /// Reconstructs the balance of the account at a past unix timestamp in seconds, from the timestamps of the
+    /// milestones that booked and spent the stored outputs. Only outputs that are still stored are considered, so
+    /// the balance is incomplete for timestamps before pruned outputs were spent.
+    pub async fn balance_at(&self, timestamp: u32) -> crate::wallet::Result<HistoricalBalance> {
+        log::debug!("[BALANCE] get balance at {timestamp}");
+        let network_id = self.client.get_network_id().await?;
+
+        let account_details = self.snapshot();
+        let account_addresses = account_details
+            .public_addresses()
+            .iter()
+            .chain(account_details.internal_addresses())
+            .map(|address| address.address.inner)
+            .collect::<HashSet<_>>();
+
+        let mut historical_balance = HistoricalBalance {
+            timestamp,
+            ..Default::default()
+        };
+        let mut native_tokens = NativeTokensBuilder::new();
+
+        for output_data in account_details
+            .outputs
+            .values()
+            .filter(|data| data.network_id == network_id)
+        {
+            let (metadata, output) = (&output_data.metadata, &output_data.output);
+            // Outputs that were spent by a transaction of the wallet, but not synced since, have no spent timestamp yet
+            // and count as unspent
+            let unspent_at_timestamp = metadata.milestone_timestamp_booked() <= timestamp
+                && metadata
+                    .milestone_timestamp_spent()
+                    .map_or(true, |spent| timestamp < spent);
+            if !unspent_at_timestamp {
+                continue;
+            }
+
+            let amount = match output.unlock_conditions() {
+                Some(unlock_conditions) => match unlock_conditions.address() {
+                    Some(address_unlock_condition) => {
+                        let locked_address =
+                            unlock_conditions.locked_address(address_unlock_condition.address(), timestamp);
+                        if unlock_conditions.is_time_locked(timestamp) || !account_addresses.contains(locked_address) {
+                            continue;
+                        }
+                        // The storage deposit that needs to be sent back to someone else wasn't ours
+                        let storage_deposit_return = unlock_conditions
+                            .storage_deposit_return()
+                            .filter(|sdr| !account_addresses.contains(sdr.return_address()))
+                            .map_or(0, |sdr| sdr.amount());
+                        output.amount() - storage_deposit_return
+                    }
+                    // Alias and foundry outputs can't have time related unlock conditions
+                    None => output.amount(),
+                },
+                None => output.amount(),
+            };
+
+            historical_balance.base_coin += amount;
+            if let Some(output_native_tokens) = output.native_tokens() {
+                native_tokens.add_native_tokens(output_native_tokens.clone())?;
+            }
+            historical_balance.outputs.push(output_data.output_id);
+        }
+
+        historical_balance.native_tokens = native_tokens.finish_vec()?;
+
+        Ok(historical_balance)
+    }
+
+    /// Returns a stream that yields the balance of the account and then the new balance every time it changes with an
+    /// update of the account, e.g. by a sync, a sent transaction or MQTT. Changes that only depend on the time, like
+    /// an elapsed timelock or an expired output, are yielded once the latest milestone passed their timestamp, without
+    /// a sync. If the balance can't be computed, the error is yielded and the stream continues with the next update.
+    pub fn balance_stream(&self) -> impl Stream<Item = crate::wallet::Result<AccountBalance>> + '_ {
+        futures::stream::unfold(
+            (self.changes(), None, true),
+            move |(mut changes, mut last_balance, first)| async move {
+                // The first balance is yielded without waiting for an update
+                if !first {
+                    balance_changed(&mut changes, self.next_balance_change().await).await?;
+                }
+                loop {
+                    match self.balance().await {
+                        Ok(balance) if last_balance.as_ref() == Some(&balance) => {}
+                        Ok(balance) => {
+                            last_balance = Some(balance.clone());
+                            return Some((Ok(balance), (changes, last_balance, false)));
+                        }
+                        Err(error) => return Some((Err(error), (changes, last_balance, false))),
+                    }
+                    balance_changed(&mut changes, self.next_balance_change().await).await?;
+                }
+            },
+        )
+    }
+
+    // The time the unlock conditions are checked against, the latest milestone timestamp if it's known
+    async fn balance_time(&self) -> crate::wallet::Result<u32> {
+        let local_time = self.client.get_time_checked().await?;
+
+        Ok(self
+            .client
+            .get_network_info()
+            .await?
+            .latest_milestone_timestamp
+            .unwrap_or(local_time))
+    }
+
+    // The next timestamp of a timelock or expiration of the unspent outputs, after which the balance changes
+    async fn next_balance_change(&self) -> Option<u32> {
+        let current_time = self.balance_time().await.ok()?;
+
+        self.snapshot()
+            .unspent_outputs
+            .values()
+            .filter_map(|output_data| output_data.output.unlock_conditions())
+            .flat_map(|unlock_conditions| {
+                [
+                    unlock_conditions.timelock().map(|timelock| timelock.timestamp()),
+                    unlock_conditions.expiration().map(|expiration| expiration.timestamp()),
+                ]
+            })
+            .flatten()
+            .filter(|timestamp| *timestamp > current_time)
+            .min()
+    }
+}
+
+// Waits until the account changed or the time of the next change passed, returns `None` if the account was dropped
+async fn balance_changed(changes: &mut watch::Receiver<()>, next_change: Option<u32>) -> Option<()> {
+    let Some(next_change) = next_change else {
+        return changes.changed().await.ok();
+    };
+    // The latest milestone timestamp lags behind the local time, so the balance is checked again at least every second
+    // until it passed the timestamp
+    let delay = Duration::from_secs(
+        u64::from(next_change)
+            .saturating_sub(unix_timestamp_now().as_secs())
+            .max(1),
+    );
+    #[cfg(target_family = "wasm")]
+    let sleep = gloo_timers::future::TimeoutFuture::new(delay.as_millis() as u32);
+    #[cfg(not(target_family = "wasm"))]
+    let sleep = tokio::time::sleep(delay);
+
+    tokio::select! {
+        changed = changes.changed() => changed.ok(),
+        _ = sleep => Some(()),
+    }
 }
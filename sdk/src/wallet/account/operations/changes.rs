@@ -0,0 +1,154 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    types::block::{output::OutputId, payload::transaction::TransactionId},
+    wallet::account::{
+        types::{InclusionState, OutputData, Transaction},
+        Account, AccountDetails,
+    },
+};
+
+/// A compact summary of the state of an account, returned with the [`AccountChanges`] of
+/// [`Account::changes_since()`], to get only the changes since this state with the next call. A default token
+/// represents an empty account, so all outputs and transactions are reported as new.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SyncToken {
+    /// The known outputs and if they were spent.
+    outputs: HashMap<OutputId, bool>,
+    /// The known transactions, sent and incoming, with their inclusion state.
+    transactions: HashMap<TransactionId, InclusionState>,
+    /// The total amount of the base coin.
+    total_balance: u64,
+    /// The available amount of the base coin.
+    available_balance: u64,
+    /// The unix timestamp in milliseconds at which the token was created.
+    timestamp: u128,
+}
+
+impl SyncToken {
+    fn new(account_details: &AccountDetails, total_balance: u64, available_balance: u64) -> Self {
+        Self {
+            outputs: account_details
+                .outputs()
+                .iter()
+                .map(|(output_id, output_data)| (*output_id, output_data.is_spent))
+                .collect(),
+            transactions: account_details
+                .transactions()
+                .iter()
+                .chain(account_details.incoming_transactions())
+                .map(|(transaction_id, transaction)| (*transaction_id, transaction.inclusion_state))
+                .collect(),
+            total_balance,
+            available_balance,
+            timestamp: crate::utils::unix_timestamp_now().as_millis(),
+        }
+    }
+
+    /// Returns the unix timestamp in milliseconds at which the token was created.
+    pub fn timestamp(&self) -> u128 {
+        self.timestamp
+    }
+}
+
+/// The changes of an account since a [`SyncToken`], so views can be updated incrementally.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountChanges {
+    /// Outputs that weren't known yet, including ones that got already spent in the meantime.
+    pub new_outputs: Vec<OutputData>,
+    /// Known outputs that were unspent and are now spent or got pruned.
+    pub spent_outputs: Vec<OutputId>,
+    /// Transactions, sent and incoming, that weren't known yet.
+    pub new_transactions: Vec<Transaction>,
+    /// Known transactions with a changed inclusion state.
+    pub updated_transactions: Vec<Transaction>,
+    /// The change of the total amount of the base coin.
+    pub total_balance_delta: i128,
+    /// The change of the available amount of the base coin.
+    pub available_balance_delta: i128,
+    /// The token of the current state, to be passed to the next call of [`Account::changes_since()`].
+    pub sync_token: SyncToken,
+}
+
+impl AccountChanges {
+    /// Returns true if nothing changed since the previous token.
+    pub fn is_empty(&self) -> bool {
+        self.new_outputs.is_empty()
+            && self.spent_outputs.is_empty()
+            && self.new_transactions.is_empty()
+            && self.updated_transactions.is_empty()
+            && self.total_balance_delta == 0
+            && self.available_balance_delta == 0
+    }
+}
+
+impl Account {
+    /// Returns the changes of the account since the state of the provided [`SyncToken`], from the latest sync. Pass
+    /// `SyncToken::default()` the first time and the returned `sync_token` afterwards.
+    pub async fn changes_since(&self, sync_token: &SyncToken) -> crate::wallet::Result<AccountChanges> {
+        log::debug!("[changes_since] {}", sync_token.timestamp);
+        let balance = self.balance().await?;
+        // Read from the same snapshot, so the changes are consistent with the new token
+        let account_details = self.snapshot();
+
+        let changes = diff(
+            &account_details,
+            sync_token,
+            SyncToken::new(
+                &account_details,
+                balance.base_coin.total,
+                balance.base_coin.available,
+            ),
+        );
+
+        Ok(changes)
+    }
+}
+
+fn diff(account_details: &AccountDetails, previous: &SyncToken, current: SyncToken) -> AccountChanges {
+    let mut new_outputs = Vec::new();
+    for (output_id, output_data) in account_details.outputs() {
+        if !previous.outputs.contains_key(output_id) {
+            new_outputs.push(output_data.clone());
+        }
+    }
+    let spent_outputs = previous
+        .outputs
+        .iter()
+        .filter(|(output_id, was_spent)| !**was_spent && current.outputs.get(output_id) != Some(&false))
+        .map(|(output_id, _)| *output_id)
+        .collect();
+
+    let mut new_transactions = Vec::new();
+    let mut updated_transactions = Vec::new();
+    for (transaction_id, transaction) in account_details
+        .transactions()
+        .iter()
+        .chain(account_details.incoming_transactions())
+    {
+        match previous.transactions.get(transaction_id) {
+            None => new_transactions.push(transaction.clone()),
+            Some(inclusion_state) if *inclusion_state != transaction.inclusion_state => {
+                updated_transactions.push(transaction.clone())
+            }
+            Some(_) => {}
+        }
+    }
+
+    AccountChanges {
+        new_outputs,
+        spent_outputs,
+        new_transactions,
+        updated_transactions,
+        total_balance_delta: current.total_balance as i128 - previous.total_balance as i128,
+        available_balance_delta: current.available_balance as i128 - previous.available_balance as i128,
+        sync_token: current,
+    }
+}
@@ -0,0 +1,279 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::HashSet;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    client::api::input_selection::{Burn, Selected},
+    types::block::{
+        address::Address,
+        output::{
+            unlock_condition::{AddressUnlockCondition, StorageDepositReturnUnlockCondition},
+            BasicOutput, BasicOutputBuilder, Output, OutputId,
+        },
+    },
+    wallet::account::{
+        types::{OutputData, Transaction},
+        Account, TransactionOptions,
+    },
+};
+
+/// What happens with a remainder below the minimum remainder amount of the [`DustProtection`]. Remainders with native
+/// tokens are always kept.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum DustRemainderAction {
+    /// The smallest unspent output of the account that lifts the remainder to the minimum is used as additional
+    /// input. If there is none, the remainder is kept.
+    #[default]
+    MergeWithOutput,
+    /// The remainder is added to an output with a storage deposit return to the account, the return amount is
+    /// increased by the same amount, so the remainder comes back when the recipient claims the output. Merged with
+    /// another output if the transaction has no such output.
+    AddToDepositReturn,
+    /// The remainder is added to the first basic output to an address of another account or wallet. Merged with
+    /// another output if the transaction has no such output.
+    DonateToRecipient,
+}
+
+/// Options against outputs with amounts too small to be of use, they're stored with the account.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct DustProtection {
+    /// Remainders of transactions with a smaller amount are handled with the `remainder_action`, 0 disables it.
+    pub min_remainder_amount: u64,
+    /// What happens with remainders below the minimum remainder amount.
+    pub remainder_action: DustRemainderAction,
+    /// Basic outputs with a smaller amount are merged by [`Account::sweep_dust()`], which the background syncing also
+    /// calls after every sync of the account, 0 disables it.
+    pub sweep_threshold: u64,
+}
+
+impl Account {
+    /// Sets the dust protection of the account.
+    pub async fn set_dust_protection(&self, options: DustProtection) -> crate::wallet::Result<()> {
+        let mut account_details = self.write().await;
+        account_details.dust_protection = options;
+        #[cfg(feature = "storage")]
+        self.save(Some(&account_details)).await?;
+        Ok(())
+    }
+
+    /// Returns the dust protection of the account.
+    pub async fn dust_protection(&self) -> DustProtection {
+        self.snapshot().dust_protection
+    }
+
+    /// Merges the basic outputs with an amount below the sweep threshold of the [`DustProtection`] into a single output
+    /// on the address of the first of them, so their amount can be used again. Only outputs without native tokens,
+    /// features and unlock conditions other than the address are swept. Returns `None` if there are less than two
+    /// such outputs.
+    pub async fn sweep_dust(&self) -> crate::wallet::Result<Option<Transaction>> {
+        let sweep_threshold = self.dust_protection().await.sweep_threshold;
+        if sweep_threshold == 0 {
+            return Ok(None);
+        }
+
+        let current_time = self.client.get_time_checked().await?;
+        let token_supply = self.client.get_token_supply().await?;
+        let max_inputs = self.max_inputs_per_transaction().await;
+        let mut dust_outputs = self
+            .spendable_plain_outputs(&HashSet::new(), current_time)
+            .await?
+            .into_iter()
+            .filter(|output_data| output_data.output.amount() < sweep_threshold)
+            .collect::<Vec<_>>();
+        dust_outputs.truncate(max_inputs.into());
+
+        if dust_outputs.len() < 2 {
+            return Ok(None);
+        }
+
+        log::debug!("[DUST_PROTECTION] sweeping {} outputs", dust_outputs.len());
+
+        let sweep_output = BasicOutputBuilder::new_with_amount(dust_outputs.iter().map(|o| o.output.amount()).sum())
+            .add_unlock_condition(AddressUnlockCondition::new(dust_outputs[0].address))
+            .finish_output(token_supply)?;
+
+        let transaction = self
+            .finish_transaction(
+                vec![sweep_output],
+                Some(TransactionOptions {
+                    custom_inputs: Some(dust_outputs.iter().map(|o| o.output_id).collect()),
+                    ..Default::default()
+                }),
+            )
+            .await?;
+
+        Ok(Some(transaction))
+    }
+
+    /// Handles a remainder of the selected inputs that is below the minimum remainder amount, the inputs of a changed
+    /// selection are locked like the ones of the original selection.
+    pub(crate) async fn protect_from_dust(
+        &self,
+        selected: Selected,
+        outputs: Vec<Output>,
+        remainder_address: Option<Address>,
+        burn: Option<&Burn>,
+        allow_unconfirmed_inputs: bool,
+    ) -> crate::wallet::Result<Selected> {
+        let dust_protection = self.dust_protection().await;
+        let Some(remainder) = &selected.remainder else {
+            return Ok(selected);
+        };
+        let remainder_amount = remainder.output.amount();
+        let has_native_tokens = remainder
+            .output
+            .native_tokens()
+            .map_or(false, |native_tokens| !native_tokens.is_empty());
+        if remainder_amount >= dust_protection.min_remainder_amount || has_native_tokens {
+            return Ok(selected);
+        }
+
+        let token_supply = self.client.get_token_supply().await?;
+        let account_addresses = {
+            let account_details = self.snapshot();
+            account_details
+                .public_addresses()
+                .iter()
+                .chain(account_details.internal_addresses().iter())
+                .map(|address| *address.address.inner())
+                .collect::<HashSet<_>>()
+        };
+        let remainder_index = selected.outputs.iter().position(|output| output == &remainder.output);
+
+        // The output that gets the remainder amount instead of the remainder
+        let mut receiving_output = None;
+        for (index, output) in selected.outputs.iter().enumerate() {
+            let Output::Basic(basic) = output else {
+                continue;
+            };
+            if Some(index) == remainder_index {
+                continue;
+            }
+            let unlock_conditions = basic.unlock_conditions();
+            let output = match dust_protection.remainder_action {
+                DustRemainderAction::MergeWithOutput => break,
+                DustRemainderAction::AddToDepositReturn => match unlock_conditions.storage_deposit_return() {
+                    Some(sdruc) if account_addresses.contains(sdruc.return_address()) => {
+                        let sdruc = StorageDepositReturnUnlockCondition::new(
+                            *sdruc.return_address(),
+                            sdruc.amount() + remainder_amount,
+                            token_supply,
+                        )?;
+                        with_added_amount(basic, remainder_amount, Some(sdruc), token_supply)?
+                    }
+                    _ => continue,
+                },
+                DustRemainderAction::DonateToRecipient => match unlock_conditions.address() {
+                    Some(address) if !account_addresses.contains(address.address()) => {
+                        with_added_amount(basic, remainder_amount, None, token_supply)?
+                    }
+                    _ => continue,
+                },
+            };
+            receiving_output = Some((index, output));
+            break;
+        }
+
+        if let (Some((index, output)), Some(remainder_index)) = (receiving_output, remainder_index) {
+            log::debug!("[DUST_PROTECTION] adding remainder of {remainder_amount} to output {index}");
+            let mut selected = selected;
+            selected.outputs[index] = output;
+            selected.outputs.remove(remainder_index);
+            selected.remainder = None;
+            return Ok(selected);
+        }
+
+        // Merge with the smallest output that lifts the remainder to the minimum
+        let input_ids = selected
+            .inputs
+            .iter()
+            .map(|input| *input.output_id())
+            .collect::<HashSet<_>>();
+        let current_time = self.client.get_time_checked().await?;
+        let missing_amount = dust_protection.min_remainder_amount - remainder_amount;
+        let additional_input = self
+            .spendable_plain_outputs(&input_ids, current_time)
+            .await?
+            .into_iter()
+            .filter(|output_data| output_data.output.amount() >= missing_amount)
+            .min_by_key(|output_data| output_data.output.amount());
+
+        let Some(additional_input) = additional_input else {
+            log::debug!("[DUST_PROTECTION] no output to merge the remainder of {remainder_amount} with");
+            return Ok(selected);
+        };
+
+        log::debug!(
+            "[DUST_PROTECTION] merging remainder of {remainder_amount} with output {}",
+            additional_input.output_id
+        );
+        self.unlock_inputs(&selected.inputs).await?;
+        let mut custom_inputs = input_ids;
+        custom_inputs.insert(additional_input.output_id);
+
+        self.select_inputs(
+            outputs,
+            Some(custom_inputs),
+            None,
+            remainder_address,
+            burn,
+            allow_unconfirmed_inputs,
+        )
+        .await
+    }
+
+    // Returns the unlocked basic outputs of the account that only have an address unlock condition and neither native
+    // tokens nor features, except the excluded ones
+    async fn spendable_plain_outputs(
+        &self,
+        excluded: &HashSet<OutputId>,
+        current_time: u32,
+    ) -> crate::wallet::Result<Vec<OutputData>> {
+        let account_details = self.read().await;
+        let frozen_outputs = account_details.frozen_output_ids();
+        let mut outputs = Vec::new();
+
+        for (output_id, output_data) in account_details.unspent_outputs() {
+            let Output::Basic(basic) = &output_data.output else {
+                continue;
+            };
+            if excluded.contains(output_id)
+                || account_details.locked_outputs.contains(output_id)
+                || frozen_outputs.contains(output_id)
+                || basic.unlock_conditions().len() != 1
+                || !basic.native_tokens().is_empty()
+                || !basic.features().is_empty()
+            {
+                continue;
+            }
+            if self.should_consolidate_output(
+                output_data,
+                current_time,
+                &account_details.addresses_with_unspent_outputs,
+            )? {
+                outputs.push(output_data.clone());
+            }
+        }
+
+        Ok(outputs)
+    }
+}
+
+// Returns the basic output with the added amount and optionally a replaced storage deposit return
+fn with_added_amount(
+    basic: &BasicOutput,
+    amount: u64,
+    storage_deposit_return: Option<StorageDepositReturnUnlockCondition>,
+    token_supply: u64,
+) -> crate::wallet::Result<Output> {
+    let mut builder = BasicOutputBuilder::from(basic).with_amount(basic.amount() + amount);
+    if let Some(storage_deposit_return) = storage_deposit_return {
+        builder = builder.replace_unlock_condition(storage_deposit_return);
+    }
+    Ok(builder.finish_output(token_supply)?)
+}
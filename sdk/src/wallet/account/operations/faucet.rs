@@ -0,0 +1,40 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::time::Duration;
+
+use crate::wallet::account::{operations::syncing::SyncOptions, Account, AccountBalance};
+
+impl Account {
+    /// Requests funds from a faucet to the first public address of the account and syncs the account once they
+    /// arrived, only meant for testnets and development. Fails with
+    /// [`Error::FaucetTimeout`](crate::client::Error::FaucetTimeout) if no funds arrived within the timeout.
+    /// ```ignore
+    /// let balance = account
+    ///     .request_funds_from_faucet("http://localhost:8091/api/enqueue", Duration::from_secs(60))
+    ///     .await?;
+    /// ```
+    pub async fn request_funds_from_faucet(
+        &self,
+        faucet_url: &str,
+        timeout: Duration,
+    ) -> crate::wallet::Result<AccountBalance> {
+        let address = self
+            .snapshot()
+            .public_addresses()
+            .first()
+            .expect("account needs to have a public address")
+            .address()
+            .to_string();
+        self.client
+            .request_funds_from_faucet(faucet_url, &address, timeout)
+            .await?;
+
+        // The account could have been synced just before, so the sync is forced to get the new outputs
+        self.sync(Some(SyncOptions {
+            force_syncing: true,
+            ..self.default_sync_options().await
+        }))
+        .await
+    }
+}
@@ -0,0 +1,61 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use serde::{Deserialize, Serialize};
+
+use crate::wallet::account::{Account, AccountBalance};
+
+/// The health of an account, updated by every sync, including the ones of the background syncing.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountHealth {
+    /// The index of the account.
+    pub account_index: u32,
+    /// The unix timestamp in milliseconds of the last successful sync.
+    pub last_successful_sync: Option<u128>,
+    /// The unix timestamp in milliseconds of the last failed sync.
+    pub last_failed_sync: Option<u128>,
+    /// The error of the last failed sync.
+    pub last_error: Option<String>,
+    /// The number of syncs that failed since the last successful one.
+    pub consecutive_failures: u32,
+}
+
+impl AccountHealth {
+    pub(crate) fn new(account_index: u32) -> Self {
+        Self {
+            account_index,
+            ..Default::default()
+        }
+    }
+
+    /// Returns true if the last sync of the account didn't fail.
+    pub fn is_healthy(&self) -> bool {
+        self.consecutive_failures == 0
+    }
+
+    pub(crate) fn record_success(&mut self) {
+        self.last_successful_sync = Some(crate::utils::unix_timestamp_now().as_millis());
+        self.consecutive_failures = 0;
+    }
+
+    pub(crate) fn record_failure(&mut self, error: impl ToString) {
+        self.last_failed_sync = Some(crate::utils::unix_timestamp_now().as_millis());
+        self.last_error = Some(error.to_string());
+        self.consecutive_failures = self.consecutive_failures.saturating_add(1);
+    }
+
+    pub(crate) fn record(&mut self, result: &crate::wallet::Result<AccountBalance>) {
+        match result {
+            Ok(_) => self.record_success(),
+            Err(e) => self.record_failure(e),
+        }
+    }
+}
+
+impl Account {
+    /// Returns the health of the account.
+    pub async fn health(&self) -> AccountHealth {
+        self.health.lock().await.clone()
+    }
+}
@@ -0,0 +1,33 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{types::block::address::Address, wallet::account::Account};
+
+impl Account {
+    /// Parses a bech32 address and checks that its HRP matches the one of the network the client is connected to, so
+    /// funds are never sent to an address of another network.
+    pub(crate) async fn address_from_bech32(
+        &self,
+        bech32_address: impl AsRef<str> + Send,
+    ) -> crate::wallet::Result<Address> {
+        let (found, address) = Address::try_from_bech32_with_hrp(bech32_address)?;
+        let expected = self.client.get_bech32_hrp().await?;
+        if found != expected {
+            return Err(crate::wallet::Error::InvalidNetworkAddress { expected, found });
+        }
+
+        Ok(address)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn address_from_bech32_is_send() {
+        fn assert_send<T: Send>(_: &T) {}
+        // Only type checked, the account methods using it must stay Send
+        let _ = |account: &Account| assert_send(&account.address_from_bech32("rms1"));
+    }
+}
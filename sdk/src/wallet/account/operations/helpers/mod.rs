@@ -1,5 +1,7 @@
 // Copyright 2022 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+/// The module to parse and validate addresses
+pub(crate) mod address;
 /// The module for time related functions
 pub(crate) mod time;
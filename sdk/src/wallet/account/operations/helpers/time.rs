@@ -42,6 +42,7 @@ pub(crate) fn can_output_be_unlocked_forever_from_now_on(
     // We use the addresses with unspent outputs, because other addresses of the account without unspent outputs can't
     // be related to this output
     account_addresses: &[AddressWithUnspentOutputs],
+    alias_and_nft_addresses: &[Address],
     output: &Output,
     current_time: u32,
 ) -> bool {
@@ -51,10 +52,12 @@ pub(crate) fn can_output_be_unlocked_forever_from_now_on(
         }
 
         // If there is an expiration unlock condition, we can only unlock it forever from now on, if it's expired and
-        // the return address belongs to the account
+        // the return address belongs to the account or to one of its alias and nft outputs
         if let Some(expiration) = unlock_conditions.expiration() {
             if let Some(return_address) = expiration.return_address_expired(current_time) {
-                if !account_addresses.iter().any(|a| a.address.inner == *return_address) {
+                if !account_addresses.iter().any(|a| a.address.inner == *return_address)
+                    && !alias_and_nft_addresses.contains(return_address)
+                {
                     return false;
                 };
             } else {
@@ -0,0 +1,79 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use serde::{Deserialize, Serialize};
+
+use crate::wallet::account::{types::InclusionState, Account};
+
+/// Statistics of the time it took transactions from their submission until the wallet noticed their confirmation,
+/// in milliseconds. The latency includes the time until the next sync, so it depends on the sync interval.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InclusionStats {
+    /// The number of confirmed transactions the statistics are based on.
+    pub confirmed_transactions: usize,
+    /// The number of transactions that are still pending.
+    pub pending_transactions: usize,
+    /// The shortest latency.
+    pub min_latency: Option<u128>,
+    /// The longest latency.
+    pub max_latency: Option<u128>,
+    /// The average latency.
+    pub mean_latency: Option<u128>,
+    /// The latency half of the transactions were confirmed within.
+    pub median_latency: Option<u128>,
+    /// The latency 90% of the transactions were confirmed within.
+    pub p90_latency: Option<u128>,
+}
+
+impl InclusionStats {
+    pub(crate) fn new(mut latencies: Vec<u128>, pending_transactions: usize) -> Self {
+        latencies.sort_unstable();
+
+        Self {
+            confirmed_transactions: latencies.len(),
+            pending_transactions,
+            min_latency: latencies.first().copied(),
+            max_latency: latencies.last().copied(),
+            mean_latency: (!latencies.is_empty())
+                .then(|| latencies.iter().sum::<u128>() / latencies.len() as u128),
+            median_latency: percentile(&latencies, 50),
+            p90_latency: percentile(&latencies, 90),
+        }
+    }
+}
+
+impl Account {
+    /// Returns statistics of the time it took the transactions of the account from their submission until they got
+    /// confirmed. Only transactions sent by the account that got confirmed while they were pending are included.
+    pub async fn inclusion_stats(&self) -> InclusionStats {
+        let (latencies, pending_transactions) = self.inclusion_latencies().await;
+        InclusionStats::new(latencies, pending_transactions)
+    }
+
+    // Returns the inclusion latencies of the confirmed transactions and the number of pending transactions
+    pub(crate) async fn inclusion_latencies(&self) -> (Vec<u128>, usize) {
+        let account_details = self.snapshot();
+        let latencies = account_details
+            .transactions
+            .values()
+            .filter(|transaction| transaction.inclusion_state == InclusionState::Confirmed)
+            .filter_map(|transaction| {
+                transaction
+                    .confirmed_at
+                    .map(|confirmed_at| confirmed_at.saturating_sub(transaction.timestamp))
+            })
+            .collect();
+
+        (latencies, account_details.pending_transactions.len())
+    }
+}
+
+// Nearest-rank percentile of sorted values
+fn percentile(sorted_values: &[u128], percent: usize) -> Option<u128> {
+    if sorted_values.is_empty() {
+        return None;
+    }
+    // The index of rank ceil(len * percent / 100)
+    sorted_values.get((sorted_values.len() * percent - 1) / 100).copied()
+}
@@ -0,0 +1,249 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::{collections::HashSet, str::FromStr};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    client::Error as ClientError,
+    types::{
+        api::core::dto::LedgerInclusionStateDto,
+        block::{
+            input::Input,
+            output::{dto::OutputMetadataDto, OutputId, OutputMetadata},
+            payload::transaction::{TransactionEssence, TransactionId},
+            BlockId,
+        },
+    },
+    wallet::account::{
+        types::{AddressWithUnspentOutputs, InclusionState, OutputData},
+        Account, AccountDetails,
+    },
+};
+
+// Number of output metadata requests that are sent at the same time
+const PARALLEL_REQUESTS: usize = 50;
+
+/// The changes made by [`Account::verify_integrity()`] to repair the local state of an account.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IntegrityReport {
+    /// The number of outputs that were checked with the node.
+    pub checked_outputs: usize,
+    /// Outputs that were stored as unspent, but are spent on the node.
+    pub marked_spent: Vec<OutputId>,
+    /// Outputs that were stored as spent, but are unspent on the node.
+    pub marked_unspent: Vec<OutputId>,
+    /// Unspent outputs that don't exist on the node, they got removed from the account.
+    pub removed_outputs: Vec<OutputId>,
+    /// Conflicting transactions or transactions with an unknown state that are included on the node.
+    pub confirmed_transactions: Vec<TransactionId>,
+    /// Confirmed transactions with inputs that are unspent on the node.
+    pub conflicting_transactions: Vec<TransactionId>,
+}
+
+impl IntegrityReport {
+    /// Returns true if the local state matched the node, so nothing got repaired.
+    pub fn is_consistent(&self) -> bool {
+        self.marked_spent.is_empty()
+            && self.marked_unspent.is_empty()
+            && self.removed_outputs.is_empty()
+            && self.confirmed_transactions.is_empty()
+            && self.conflicting_transactions.is_empty()
+    }
+}
+
+impl Account {
+    /// Cross-checks the stored outputs and transactions of the account with the node, to detect a divergence of the
+    /// local state, e.g. after switching to another node or after the node pruned data. The local state is repaired:
+    /// outputs are marked as spent or unspent like on the node, unspent outputs that don't exist on the node are
+    /// removed, included transactions are marked as confirmed and confirmed transactions with unspent inputs as
+    /// conflicting. Spent outputs that got pruned by the node and records of other networks are kept. Returns a report
+    /// of the changes.
+    pub async fn verify_integrity(&self) -> crate::wallet::Result<IntegrityReport> {
+        log::debug!("[verify_integrity]");
+        let network_id = self.client.get_network_id().await?;
+
+        let (output_ids, unconfirmed_transaction_ids) = {
+            let account_details = self.read().await;
+            let output_ids = account_details
+                .outputs
+                .values()
+                .filter(|output_data| output_data.network_id == network_id)
+                .map(|output_data| output_data.output_id)
+                .collect::<Vec<_>>();
+            let unconfirmed_transaction_ids = account_details
+                .transactions
+                .values()
+                .filter(|transaction| {
+                    transaction.network_id == network_id
+                        && matches!(
+                            transaction.inclusion_state,
+                            InclusionState::Conflicting | InclusionState::UnknownPruned
+                        )
+                })
+                .map(|transaction| transaction.transaction_id)
+                .collect::<Vec<_>>();
+            (output_ids, unconfirmed_transaction_ids)
+        };
+
+        let mut output_metadata = Vec::with_capacity(output_ids.len());
+        for output_ids_chunk in output_ids.chunks(PARALLEL_REQUESTS) {
+            let results = futures::future::try_join_all(
+                output_ids_chunk
+                    .iter()
+                    .map(|output_id| self.request_output_metadata(*output_id)),
+            )
+            .await?;
+            output_metadata.extend(results);
+        }
+
+        let mut included_transactions = Vec::new();
+        for transaction_id in unconfirmed_transaction_ids {
+            match self.client.get_included_block_metadata(&transaction_id).await {
+                Ok(metadata) => {
+                    if metadata.ledger_inclusion_state == Some(LedgerInclusionStateDto::Included) {
+                        included_transactions.push((
+                            transaction_id,
+                            BlockId::from_str(&metadata.block_id)?,
+                            metadata.referenced_by_milestone_index,
+                        ));
+                    }
+                }
+                // Not included or pruned, nothing to repair
+                Err(ClientError::NotFound(_)) => {}
+                Err(e) => return Err(e.into()),
+            }
+        }
+
+        let mut report = IntegrityReport {
+            checked_outputs: output_metadata.len(),
+            ..Default::default()
+        };
+        let mut account_details = self.write().await;
+
+        for (output_id, metadata) in output_metadata {
+            // The output could have been removed since it was requested
+            let Some(output_data) = account_details.outputs.get(&output_id) else {
+                continue;
+            };
+            match metadata {
+                Some(metadata) => {
+                    let metadata = OutputMetadata::try_from(&metadata)?;
+                    if metadata.is_spent() == output_data.is_spent {
+                        continue;
+                    }
+                    let mut output_data = output_data.clone();
+                    output_data.is_spent = metadata.is_spent();
+                    output_data.metadata = metadata;
+
+                    if output_data.is_spent {
+                        log::debug!("[verify_integrity] output {output_id} is spent");
+                        remove_unspent_output(&mut account_details, &output_id);
+                        report.marked_spent.push(output_id);
+                    } else {
+                        log::debug!("[verify_integrity] output {output_id} is unspent");
+                        insert_unspent_output(&mut account_details, &output_data);
+                        report.marked_unspent.push(output_id);
+                    }
+                    account_details.outputs.insert(output_id, output_data);
+                }
+                // Spent outputs can get pruned by the node, but unspent outputs always exist
+                None if !output_data.is_spent => {
+                    log::debug!("[verify_integrity] output {output_id} doesn't exist");
+                    remove_unspent_output(&mut account_details, &output_id);
+                    account_details.outputs.remove(&output_id);
+                    report.removed_outputs.push(output_id);
+                }
+                None => {}
+            }
+        }
+
+        for (transaction_id, block_id, milestone_index) in included_transactions {
+            if let Some(transaction) = account_details.transactions.get_mut(&transaction_id) {
+                log::debug!("[verify_integrity] transaction {transaction_id} is confirmed");
+                transaction.inclusion_state = InclusionState::Confirmed;
+                transaction.block_id = Some(block_id);
+                transaction.milestone_index = milestone_index;
+                report.confirmed_transactions.push(transaction_id);
+            }
+        }
+
+        // A transaction can't be confirmed if one of its inputs is unspent
+        let unspent_inputs = report.marked_unspent.iter().collect::<HashSet<_>>();
+        for transaction in account_details.transactions.values_mut() {
+            let TransactionEssence::Regular(essence) = transaction.payload.essence();
+            if transaction.inclusion_state == InclusionState::Confirmed
+                && essence.inputs().iter().any(|input| match input {
+                    Input::Utxo(input) => unspent_inputs.contains(input.output_id()),
+                    Input::Treasury(_) => false,
+                })
+            {
+                log::debug!("[verify_integrity] transaction {} is conflicting", transaction.transaction_id);
+                transaction.inclusion_state = InclusionState::Conflicting;
+                transaction.milestone_index = None;
+                report.conflicting_transactions.push(transaction.transaction_id);
+            }
+        }
+
+        #[cfg(feature = "storage")]
+        if !report.is_consistent() {
+            self.save(Some(&account_details)).await?;
+        }
+
+        Ok(report)
+    }
+
+    // Requests the metadata of an output, `None` if the output doesn't exist on the node
+    async fn request_output_metadata(
+        &self,
+        output_id: OutputId,
+    ) -> crate::wallet::Result<(OutputId, Option<OutputMetadataDto>)> {
+        match self.client.get_output_metadata(&output_id).await {
+            Ok(metadata) => Ok((output_id, Some(metadata))),
+            Err(ClientError::NotFound(_)) => Ok((output_id, None)),
+            Err(e) => Err(e.into()),
+        }
+    }
+}
+
+// Removes an output from the unspent and locked outputs and from the address it's owned by
+fn remove_unspent_output(account_details: &mut AccountDetails, output_id: &OutputId) {
+    account_details.unspent_outputs.remove(output_id);
+    account_details.locked_outputs.remove(output_id);
+    for address in account_details.addresses_with_unspent_outputs.iter_mut() {
+        address.output_ids.retain(|id| id != output_id);
+    }
+    account_details
+        .addresses_with_unspent_outputs
+        .retain(|address| !address.output_ids.is_empty());
+}
+
+// Adds an output to the unspent outputs and to the address it's owned by
+fn insert_unspent_output(account_details: &mut AccountDetails, output_data: &OutputData) {
+    account_details
+        .unspent_outputs
+        .insert(output_data.output_id, output_data.clone());
+
+    if let Some(address) = account_details
+        .addresses_with_unspent_outputs
+        .iter_mut()
+        .find(|address| address.address.inner == output_data.address)
+    {
+        address.output_ids.push(output_data.output_id);
+    } else if let Some(account_address) = account_details
+        .public_addresses
+        .iter()
+        .chain(account_details.internal_addresses.iter())
+        .find(|address| address.address.inner == output_data.address)
+    {
+        let address = AddressWithUnspentOutputs {
+            address: account_address.address.clone(),
+            key_index: account_address.key_index,
+            internal: account_address.internal,
+            output_ids: vec![output_data.output_id],
+        };
+        account_details.addresses_with_unspent_outputs.push(address);
+    }
+}
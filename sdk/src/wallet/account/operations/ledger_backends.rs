@@ -0,0 +1,80 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::sync::Arc;
+
+use crate::wallet::account::{
+    ledger_backend::{LedgerBackend, LedgerBalance, LedgerTransaction, LAYER_1_LEDGER_ID},
+    Account,
+};
+
+impl Account {
+    /// Adds a ledger next to the layer 1, so its balance and transactions are returned by
+    /// [`Account::ledger_balances()`] and [`Account::ledger_transactions()`]. The backends aren't stored and need to be
+    /// added again after a restart.
+    pub async fn add_ledger_backend(&self, backend: Arc<dyn LedgerBackend>) -> crate::wallet::Result<()> {
+        let ledger_id = backend.id();
+        let mut ledger_backends = self.ledger_backends.write().await;
+
+        if ledger_id == LAYER_1_LEDGER_ID || ledger_backends.iter().any(|b| b.id() == ledger_id) {
+            return Err(crate::wallet::Error::LedgerBackend(format!(
+                "a ledger with the id {ledger_id} already exists"
+            )));
+        }
+        ledger_backends.push(backend);
+
+        Ok(())
+    }
+
+    /// Removes the ledger with the id, returns true if it was added before.
+    pub async fn remove_ledger_backend(&self, ledger_id: &str) -> bool {
+        let mut ledger_backends = self.ledger_backends.write().await;
+        let len = ledger_backends.len();
+        ledger_backends.retain(|b| b.id() != ledger_id);
+
+        ledger_backends.len() != len
+    }
+
+    /// Returns the ids of all ledgers of the account, the layer 1 first.
+    pub async fn ledger_ids(&self) -> Vec<String> {
+        let ledger_backends = self.ledger_backends.read().await;
+
+        std::iter::once(LAYER_1_LEDGER_ID.to_string())
+            .chain(ledger_backends.iter().map(|b| b.id()))
+            .collect()
+    }
+
+    /// Returns the balance of the account on all ledgers, the layer 1 balance as of the last sync first, followed by
+    /// the balances of the added ledger backends in the order they were added.
+    pub async fn ledger_balances(&self) -> crate::wallet::Result<Vec<LedgerBalance>> {
+        let mut balances = vec![LedgerBalance::from(&self.balance().await?)];
+
+        let account_details = self.snapshot();
+        // Clone the backends, so the lock isn't held during the requests
+        let ledger_backends = self.ledger_backends.read().await.clone();
+        for backend in ledger_backends {
+            balances.push(backend.balance(&account_details).await?);
+        }
+
+        Ok(balances)
+    }
+
+    /// Returns the transactions of the account on all ledgers, the newest first.
+    pub async fn ledger_transactions(&self) -> crate::wallet::Result<Vec<LedgerTransaction>> {
+        let account_details = self.snapshot();
+        let mut transactions = account_details
+            .transactions()
+            .values()
+            .chain(account_details.incoming_transactions().values())
+            .map(LedgerTransaction::from)
+            .collect::<Vec<_>>();
+
+        let ledger_backends = self.ledger_backends.read().await.clone();
+        for backend in ledger_backends {
+            transactions.extend(backend.transactions(&account_details).await?);
+        }
+        transactions.sort_by(|a, b| b.timestamp.cmp(&a.timestamp));
+
+        Ok(transactions)
+    }
+}
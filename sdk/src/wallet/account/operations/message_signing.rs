@@ -0,0 +1,43 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{
+    client::secret::SecretManage,
+    types::block::address::Bech32Address,
+    wallet::{account::Account, OwnershipProof},
+};
+
+impl Account {
+    /// Signs an arbitrary message with the key of an address of the account, so a verifier like an exchange can check
+    /// that the user controls the address without an on-chain transaction. The proof contains the address, the hex
+    /// encoded message and the signature with the public key, it can be verified with [`verify_message_proof()`].
+    pub async fn sign_message(&self, address: &Bech32Address, message: &[u8]) -> crate::wallet::Result<OwnershipProof> {
+        log::debug!("[sign_message] with {address}");
+        self.check_not_watch_only()?;
+
+        let chain = self
+            .read()
+            .await
+            .address_chain(address)
+            .ok_or_else(|| crate::wallet::Error::AddressNotFoundInAccount(address.to_string()))?;
+
+        let signature = self
+            .secret_manager
+            .read()
+            .await
+            .sign_ed25519(&OwnershipProof::signing_message(message), &chain)
+            .await?;
+
+        Ok(OwnershipProof {
+            address: address.clone(),
+            challenge: prefix_hex::encode(message),
+            signature,
+        })
+    }
+}
+
+/// Verifies a proof created with [`Account::sign_message()`], that it was created for the message and that the
+/// signature is valid for the address of the proof. No node or wallet is needed.
+pub fn verify_message_proof(proof: &OwnershipProof, message: &[u8]) -> crate::wallet::Result<()> {
+    proof.verify(message)
+}
@@ -5,22 +5,53 @@
 pub(crate) mod address_generation;
 /// The module to get the accounts balance
 pub(crate) mod balance;
+/// The module for the changes of an account since a previous state
+pub(crate) mod changes;
+/// The module for the protection against dust outputs
+pub(crate) mod dust_protection;
+/// The module for requesting funds from a faucet
+pub(crate) mod faucet;
+/// The module for the health of an account
+pub(crate) mod health;
+/// The module to verify and repair the local state of an account
+pub(crate) mod integrity;
 /// Helper functions
 pub(crate) mod helpers;
+/// The module for the inclusion latency statistics of transactions
+pub(crate) mod inclusion_stats;
+/// The module for the balances and transactions of the account on all ledgers
+pub(crate) mod ledger_backends;
 /// The module for claiming of outputs with
 /// [`UnlockCondition`](crate::types::block::output::UnlockCondition)s that aren't only
 /// [`AddressUnlockCondition`](crate::types::block::output::unlock_condition::AddressUnlockCondition)
 pub(crate) mod output_claiming;
+/// The module for the preferences which wallet events are emitted
+#[cfg(feature = "events")]
+pub(crate) mod notification_preferences;
+/// The module for signing messages to prove the ownership of addresses
+pub(crate) mod message_signing;
+/// The module for the age of unspent outputs, e.g. for holder-based airdrops
+pub(crate) mod output_age;
 /// The module for the output consolidation
 pub(crate) mod output_consolidation;
 /// The module to find additional addresses with unspent outputs
 pub(crate) mod output_finder;
+/// The module for the reservation of outputs, so they aren't selected by concurrent operations
+pub(crate) mod output_reservation;
 /// The module for participation
 #[cfg(feature = "participation")]
 pub(crate) mod participation;
+/// The module for the privacy options
+pub(crate) mod privacy;
+/// The module for pruning of old records
+pub(crate) mod pruning;
 /// The module for retrying blocks or transactions
 pub(crate) mod retry;
+/// The module for the limits and the approval of transactions
+pub(crate) mod spending_policy;
 /// The module for synchronization of an account
 pub(crate) mod syncing;
 /// The module for transactions
 pub(crate) mod transaction;
+/// The module for the watchdog of stalled operations
+pub(crate) mod watchdog;
@@ -0,0 +1,34 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::wallet::{account::Account, events::types::NotificationPreferences};
+
+impl Account {
+    /// Set which wallet events are emitted for the account, e.g. to mute event types or to only notify about outputs
+    /// above an amount. If storage is enabled, will persist during restarts.
+    pub async fn set_notification_preferences(
+        &self,
+        preferences: NotificationPreferences,
+    ) -> crate::wallet::Result<()> {
+        let index = *self.read().await.index();
+
+        #[cfg(feature = "storage")]
+        self.storage_manager
+            .lock()
+            .await
+            .set_notification_preferences(index, &preferences)
+            .await?;
+
+        self.event_emitter
+            .lock()
+            .await
+            .set_notification_preferences(index, preferences);
+        Ok(())
+    }
+
+    /// Get the preferences which wallet events are emitted for the account.
+    pub async fn notification_preferences(&self) -> NotificationPreferences {
+        let index = *self.read().await.index();
+        self.event_emitter.lock().await.notification_preferences(index)
+    }
+}
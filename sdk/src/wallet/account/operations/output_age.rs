@@ -0,0 +1,283 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::HashMap;
+
+use crypto::{
+    hashes::{blake2b::Blake2b256, Digest},
+    keys::slip10::Chain,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    client::{secret::SecretManage, Client},
+    types::block::{address::Bech32Address, output::OutputId},
+    wallet::{account::Account, OwnershipProof},
+};
+
+// Prefix for the challenge the ownership proofs of an attestation are signed over
+const AGE_ATTESTATION_DOMAIN: &[u8] = b"IOTA-SDK-AGE-ATTESTATION";
+
+/// How long an unspent output has been held, based on the timestamp of the milestone that booked it.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OutputAge {
+    /// The output id.
+    pub output_id: OutputId,
+    /// The amount of the output.
+    pub amount: u64,
+    /// The index of the milestone that booked the output.
+    pub milestone_index_booked: u32,
+    /// The unix timestamp in seconds of the milestone that booked the output.
+    pub milestone_timestamp_booked: u32,
+    /// The number of seconds the output has been held.
+    pub age: u32,
+}
+
+/// The unspent outputs of an address with their age.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AddressAge {
+    /// The address.
+    pub address: Bech32Address,
+    /// The sum of the amounts of the outputs.
+    pub amount: u64,
+    /// The outputs, the oldest first.
+    pub outputs: Vec<OutputAge>,
+}
+
+impl AddressAge {
+    /// Returns the amount that has been held for at least the number of seconds.
+    pub fn amount_held_for(&self, seconds: u32) -> u64 {
+        self.outputs
+            .iter()
+            .filter(|output| output.age >= seconds)
+            .map(|output| output.amount)
+            .sum()
+    }
+
+    /// Returns the average age of the outputs in seconds, weighted by their amounts, so a small recent deposit only
+    /// slightly lowers the age of an old balance.
+    pub fn weighted_age(&self) -> u32 {
+        if self.amount == 0 {
+            return 0;
+        }
+        let weighted_sum = self
+            .outputs
+            .iter()
+            .map(|output| output.amount as u128 * output.age as u128)
+            .sum::<u128>();
+
+        (weighted_sum / self.amount as u128) as u32
+    }
+
+    /// Returns the age of the oldest output in seconds.
+    pub fn oldest_age(&self) -> u32 {
+        self.outputs.iter().map(|output| output.age).max().unwrap_or_default()
+    }
+}
+
+/// An exportable attestation of the age of the outputs of addresses, e.g. to prove the eligibility for a holder-based
+/// airdrop. The ownership proofs are signed over the challenge of the verifier and the attested outputs, so they can't
+/// be altered.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AgeAttestation {
+    /// The unix timestamp in seconds the ages were computed at.
+    pub attested_at: u32,
+    /// The addresses with their outputs.
+    pub addresses: Vec<AddressAge>,
+    /// The proofs that the addresses are controlled by the attester.
+    pub ownership_proofs: Vec<OwnershipProof>,
+}
+
+impl AgeAttestation {
+    /// Returns the challenge the ownership proofs are signed over, committing to the challenge of the verifier, the
+    /// timestamp and the outputs.
+    pub fn signing_challenge(challenge: &[u8], attested_at: u32, addresses: &[AddressAge]) -> [u8; 32] {
+        let mut hasher = Blake2b256::new();
+        hasher.update(AGE_ATTESTATION_DOMAIN);
+        hasher.update((challenge.len() as u64).to_le_bytes());
+        hasher.update(challenge);
+        hasher.update(attested_at.to_le_bytes());
+        for address in addresses {
+            let address_bech32 = address.address.to_string();
+            hasher.update((address_bech32.len() as u64).to_le_bytes());
+            hasher.update(address_bech32.as_bytes());
+            hasher.update((address.outputs.len() as u64).to_le_bytes());
+            for output in &address.outputs {
+                hasher.update(output.output_id.hash());
+                hasher.update(output.amount.to_le_bytes());
+                hasher.update(output.milestone_timestamp_booked.to_le_bytes());
+            }
+        }
+
+        hasher.finalize().into()
+    }
+
+    /// Verifies that every address has a valid ownership proof for the challenge and that the amounts and ages match
+    /// the outputs. The outputs themselves can be checked with [`AgeAttestation::verify_outputs()`].
+    pub fn verify(&self, challenge: &[u8]) -> crate::wallet::Result<()> {
+        let signing_challenge = Self::signing_challenge(challenge, self.attested_at, &self.addresses);
+
+        for address in &self.addresses {
+            let ownership_proof = self
+                .ownership_proofs
+                .iter()
+                .find(|proof| proof.address == address.address)
+                .ok_or_else(|| {
+                    crate::wallet::Error::InvalidAgeAttestation(format!("no ownership proof for {}", address.address))
+                })?;
+            ownership_proof.verify(&signing_challenge)?;
+
+            if address.amount != address.outputs.iter().map(|output| output.amount).sum::<u64>() {
+                return Err(crate::wallet::Error::InvalidAgeAttestation(format!(
+                    "amount of {} doesn't match its outputs",
+                    address.address
+                )));
+            }
+            if let Some(output) = address
+                .outputs
+                .iter()
+                .find(|output| self.attested_at.saturating_sub(output.milestone_timestamp_booked) != output.age)
+            {
+                return Err(crate::wallet::Error::InvalidAgeAttestation(format!(
+                    "age of output {} doesn't match its booking",
+                    output.output_id
+                )));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Verifies with a node that the outputs were booked at the attested milestones and weren't spent before the
+    /// attestation. Requires a node that didn't prune the outputs yet.
+    pub async fn verify_outputs(&self, client: &Client) -> crate::wallet::Result<()> {
+        for output in self.addresses.iter().flat_map(|address| address.outputs.iter()) {
+            let metadata = client.get_output_metadata(&output.output_id).await?;
+
+            if metadata.milestone_index_booked != output.milestone_index_booked
+                || metadata.milestone_timestamp_booked != output.milestone_timestamp_booked
+            {
+                return Err(crate::wallet::Error::InvalidAgeAttestation(format!(
+                    "output {} was booked at milestone {}",
+                    output.output_id, metadata.milestone_index_booked
+                )));
+            }
+            if metadata
+                .milestone_timestamp_spent
+                .map_or(false, |spent| spent <= self.attested_at)
+            {
+                return Err(crate::wallet::Error::InvalidAgeAttestation(format!(
+                    "output {} was spent before the attestation",
+                    output.output_id
+                )));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Account {
+    /// Returns the age of the unspent outputs per address, based on the timestamps of the milestones that booked
+    /// them. Only addresses with unspent outputs are returned, the account should be synced before.
+    pub async fn output_ages(&self) -> crate::wallet::Result<Vec<AddressAge>> {
+        let current_time = self.client.get_time_checked().await?;
+        Ok(self.output_ages_at(current_time).await)
+    }
+
+    /// Creates an attestation of the age of the unspent outputs of all addresses, signed over the challenge of the
+    /// verifier. The account should be synced before.
+    pub async fn age_attestation(&self, challenge: &[u8]) -> crate::wallet::Result<AgeAttestation> {
+        self.check_not_watch_only()?;
+        let attested_at = self.client.get_time_checked().await?;
+        let addresses = self.output_ages_at(attested_at).await;
+        log::debug!("[age_attestation] for {} addresses", addresses.len());
+
+        let signing_challenge = AgeAttestation::signing_challenge(challenge, attested_at, &addresses);
+        let message = OwnershipProof::signing_message(&signing_challenge);
+        let chains = {
+            let account_details = self.read().await;
+            addresses
+                .iter()
+                .map(|address_age| {
+                    account_details
+                        .addresses_with_unspent_outputs
+                        .iter()
+                        .find(|a| a.address == address_age.address)
+                        .map(|a| {
+                            Chain::from_u32_hardened(vec![
+                                44,
+                                account_details.coin_type,
+                                account_details.index,
+                                a.internal as u32,
+                                a.key_index,
+                            ])
+                        })
+                        .ok_or_else(|| crate::wallet::Error::AddressNotFoundInAccount(address_age.address.to_string()))
+                })
+                .collect::<crate::wallet::Result<Vec<_>>>()?
+        };
+
+        let mut ownership_proofs = Vec::with_capacity(addresses.len());
+        for (address_age, chain) in addresses.iter().zip(chains) {
+            let signature = self.secret_manager.read().await.sign_ed25519(&message, &chain).await?;
+            ownership_proofs.push(OwnershipProof {
+                address: address_age.address.clone(),
+                challenge: prefix_hex::encode(signing_challenge),
+                signature,
+            });
+        }
+
+        Ok(AgeAttestation {
+            attested_at,
+            addresses,
+            ownership_proofs,
+        })
+    }
+
+    // Returns the ages of the unspent outputs of the Ed25519 addresses of the account at the timestamp
+    async fn output_ages_at(&self, timestamp: u32) -> Vec<AddressAge> {
+        let account_details = self.snapshot();
+        let mut addresses = HashMap::<Bech32Address, AddressAge>::new();
+
+        for address in &account_details.addresses_with_unspent_outputs {
+            for output_id in &address.output_ids {
+                let Some(output_data) = account_details.unspent_outputs.get(output_id) else {
+                    continue;
+                };
+                let address_age = addresses.entry(address.address.clone()).or_insert_with(|| AddressAge {
+                    address: address.address.clone(),
+                    amount: 0,
+                    outputs: Vec::new(),
+                });
+                address_age.amount = address_age.amount.saturating_add(output_data.output.amount());
+                address_age.outputs.push(OutputAge {
+                    output_id: *output_id,
+                    amount: output_data.output.amount(),
+                    milestone_index_booked: output_data.metadata.milestone_index_booked(),
+                    milestone_timestamp_booked: output_data.metadata.milestone_timestamp_booked(),
+                    age: timestamp.saturating_sub(output_data.metadata.milestone_timestamp_booked()),
+                });
+            }
+        }
+        drop(account_details);
+
+        let mut addresses = addresses.into_values().collect::<Vec<_>>();
+        for address_age in &mut addresses {
+            address_age
+                .outputs
+                .sort_by_key(|output| (output.milestone_timestamp_booked, output.output_id));
+        }
+        // The oldest holders first
+        addresses.sort_by(|a, b| {
+            b.oldest_age()
+                .cmp(&a.oldest_age())
+                .then_with(|| a.address.inner().cmp(b.address.inner()))
+        });
+
+        addresses
+    }
+}
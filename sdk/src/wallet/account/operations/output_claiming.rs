@@ -46,6 +46,9 @@ impl Account {
 
         let local_time = self.client.get_time_checked().await?;
 
+        // Outputs locked to the alias and nft outputs of the account can be claimed as well
+        let alias_and_nft_addresses = account_details.alias_and_nft_addresses();
+
         // Get outputs for the claim
         let mut output_ids_to_claim: HashSet<OutputId> = HashSet::new();
         for (output_id, output_data) in account_details
@@ -64,11 +67,10 @@ impl Account {
                             // We use the addresses with unspent outputs, because other addresses of the
                             // account without unspent outputs can't be related to this output
                             &account_details.addresses_with_unspent_outputs,
-                            // outputs controlled by an alias or nft are currently not considered
-                            &[],
+                            &alias_and_nft_addresses,
                             output_data,
                             local_time,
-                            // Not relevant without alias addresses
+                            // Only relevant for alias outputs, which aren't claimed
                             None,
                         )?
                     {
@@ -30,7 +30,7 @@ use crate::wallet::{
 };
 
 impl Account {
-    fn should_consolidate_output(
+    pub(crate) fn should_consolidate_output(
         &self,
         output_data: &OutputData,
         current_time: u32,
@@ -59,6 +59,32 @@ impl Account {
         })
     }
 
+    /// Returns the maximum number of inputs for a transaction, with a ledger nano it's limited by its buffer size.
+    pub(crate) async fn max_inputs_per_transaction(&self) -> u16 {
+        match &*self.secret_manager.read().await {
+            #[cfg(feature = "ledger_nano")]
+            SecretManager::LedgerNano(ledger) => {
+                let ledger_nano_status = ledger.get_ledger_nano_status().await;
+                // With blind signing we are only limited by the protocol
+                if ledger_nano_status.blind_signing_enabled() {
+                    INPUT_COUNT_MAX
+                } else {
+                    ledger_nano_status
+                        .buffer_size()
+                        .map(|buffer_size| {
+                            // Calculate how many inputs we can have with this ledger, buffer size is different for
+                            // different ledger types
+                            let available_buffer_size_for_inputs =
+                                buffer_size - ESSENCE_SIZE_WITHOUT_IN_AND_OUTPUTS - MIN_OUTPUT_SIZE_IN_ESSENCE;
+                            (available_buffer_size_for_inputs / INPUT_SIZE) as u16
+                        })
+                        .unwrap_or(INPUT_COUNT_MAX)
+                }
+            }
+            _ => INPUT_COUNT_MAX,
+        }
+    }
+
     /// Consolidate basic outputs with only an [AddressUnlockCondition] from an account by sending them to an own
     /// address again if the output amount is >= the output_consolidation_threshold. When `force` is set to `true`, the
     /// threshold is ignored. Only consolidates the amount of outputs that fit into a single transaction.
@@ -75,6 +101,7 @@ impl Account {
         let mut outputs_to_consolidate = Vec::new();
         let account_details = self.read().await;
         let account_addresses = &account_details.addresses_with_unspent_outputs[..];
+        let frozen_outputs = account_details.frozen_output_ids();
 
         for (output_id, output_data) in account_details.unspent_outputs() {
             #[cfg(feature = "participation")]
@@ -84,7 +111,8 @@ impl Account {
                     continue;
                 }
             }
-            let is_locked_output = account_details.locked_outputs.contains(output_id);
+            let is_locked_output =
+                account_details.locked_outputs.contains(output_id) || frozen_outputs.contains(output_id);
             let should_consolidate_output =
                 self.should_consolidate_output(output_data, current_time, account_addresses)?;
             if !is_locked_output && should_consolidate_output {
@@ -117,28 +145,7 @@ impl Account {
             });
         }
 
-        let max_inputs = match &*self.secret_manager.read().await {
-            #[cfg(feature = "ledger_nano")]
-            SecretManager::LedgerNano(ledger) => {
-                let ledger_nano_status = ledger.get_ledger_nano_status().await;
-                // With blind signing we are only limited by the protocol
-                if ledger_nano_status.blind_signing_enabled() {
-                    INPUT_COUNT_MAX
-                } else {
-                    ledger_nano_status
-                        .buffer_size()
-                        .map(|buffer_size| {
-                            // Calculate how many inputs we can have with this ledger, buffer size is different for
-                            // different ledger types
-                            let available_buffer_size_for_inputs =
-                                buffer_size - ESSENCE_SIZE_WITHOUT_IN_AND_OUTPUTS - MIN_OUTPUT_SIZE_IN_ESSENCE;
-                            (available_buffer_size_for_inputs / INPUT_SIZE) as u16
-                        })
-                        .unwrap_or(INPUT_COUNT_MAX)
-                }
-            }
-            _ => INPUT_COUNT_MAX,
-        };
+        let max_inputs = self.max_inputs_per_transaction().await;
 
         let mut total_amount = 0;
         let mut custom_inputs = Vec::with_capacity(max_inputs.into());
@@ -3,9 +3,10 @@
 
 use std::cmp;
 
-use crate::{
-    client::secret::GenerateAddressOptions,
-    wallet::account::{operations::syncing::SyncOptions, types::AddressWithUnspentOutputs, Account},
+use crate::wallet::account::{
+    operations::{address_generation::AddressGenerationOptions, syncing::SyncOptions},
+    types::AddressWithUnspentOutputs,
+    Account,
 };
 
 impl Account {
@@ -63,7 +64,7 @@ impl Account {
             log::debug!(
                 "[search_addresses_with_outputs] generate {address_amount_to_generate} internal addresses below the start index"
             );
-            self.generate_addresses(address_amount_to_generate, Some(GenerateAddressOptions::internal()))
+            self.generate_addresses(address_amount_to_generate, Some(AddressGenerationOptions::internal()))
                 .await?;
         }
 
@@ -90,7 +91,7 @@ impl Account {
             // generate public and internal addresses
             let addresses = self.generate_addresses(address_gap_limit, None).await?;
             let internal_addresses = self
-                .generate_addresses(address_gap_limit_internal, Some(GenerateAddressOptions::internal()))
+                .generate_addresses(address_gap_limit_internal, Some(AddressGenerationOptions::internal()))
                 .await?;
 
             let address_start_index = addresses
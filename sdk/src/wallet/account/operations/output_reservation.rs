@@ -0,0 +1,98 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::time::Duration;
+
+use instant::Instant;
+
+use crate::{
+    client::secret::types::InputSigningData,
+    types::block::output::OutputId,
+    wallet::account::{constants::DEFAULT_OUTPUT_RESERVATION_TIMEOUT, Account, AccountDetails},
+};
+
+impl Account {
+    /// Reserves unspent outputs, so they aren't selected as inputs by other operations until they are released with
+    /// [`Account::release_outputs()`] or the timeout elapsed. Fails if one of the outputs is already reserved or used
+    /// in a transaction.
+    pub async fn reserve_outputs(&self, output_ids: &[OutputId], timeout: Duration) -> crate::wallet::Result<()> {
+        let mut account_details = self.write().await;
+        self.release_expired_reservations(&mut account_details).await;
+
+        for output_id in output_ids {
+            if !account_details.unspent_outputs.contains_key(output_id) {
+                return Err(crate::wallet::Error::OutputNotFoundInAccount(*output_id));
+            }
+            if account_details.locked_outputs.contains(output_id) {
+                return Err(crate::wallet::Error::OutputReserved(*output_id));
+            }
+        }
+
+        let reserved_until = Instant::now() + timeout;
+        let mut output_reservations = self.output_reservations.lock().await;
+        for output_id in output_ids {
+            log::debug!("[TRANSACTION] reserving {output_id}");
+            account_details.locked_outputs.insert(*output_id);
+            output_reservations.insert(*output_id, reserved_until);
+        }
+
+        Ok(())
+    }
+
+    /// Releases reserved outputs, so they can be selected as inputs again. Outputs that are used in a submitted
+    /// transaction stay locked until the transaction is confirmed or conflicting.
+    pub async fn release_outputs(&self, output_ids: &[OutputId]) {
+        let mut account_details = self.write().await;
+        let mut output_reservations = self.output_reservations.lock().await;
+
+        for output_id in output_ids {
+            if output_reservations.remove(output_id).is_some() {
+                log::debug!("[TRANSACTION] releasing {output_id}");
+                account_details.locked_outputs.remove(output_id);
+            }
+        }
+    }
+
+    /// Returns the ids of the reserved outputs, which aren't used in a submitted transaction yet.
+    pub async fn reserved_outputs(&self) -> Vec<OutputId> {
+        let mut account_details = self.write().await;
+        self.release_expired_reservations(&mut account_details).await;
+        drop(account_details);
+
+        self.output_reservations.lock().await.keys().copied().collect()
+    }
+
+    // Reserves the inputs selected for a transaction, until the transaction is submitted
+    pub(crate) async fn reserve_inputs(&self, inputs: &[InputSigningData]) {
+        let reserved_until = Instant::now() + DEFAULT_OUTPUT_RESERVATION_TIMEOUT;
+        let mut output_reservations = self.output_reservations.lock().await;
+
+        for input in inputs {
+            output_reservations.insert(*input.output_id(), reserved_until);
+        }
+    }
+
+    // Removes the reservations of outputs that are used in a submitted transaction or unlocked, without unlocking them
+    pub(crate) async fn remove_reservations(&self, output_ids: &[OutputId]) {
+        let mut output_reservations = self.output_reservations.lock().await;
+
+        for output_id in output_ids {
+            output_reservations.remove(output_id);
+        }
+    }
+
+    // Unlocks the outputs whose reservation expired, the account details need to be locked by the caller
+    pub(crate) async fn release_expired_reservations(&self, account_details: &mut AccountDetails) {
+        let now = Instant::now();
+        let mut output_reservations = self.output_reservations.lock().await;
+
+        output_reservations.retain(|output_id, reserved_until| {
+            let expired = *reserved_until <= now;
+            if expired {
+                log::debug!("[TRANSACTION] reservation of {output_id} expired");
+                account_details.locked_outputs.remove(output_id);
+            }
+            !expired
+        });
+    }
+}
@@ -0,0 +1,78 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use serde::{Deserialize, Serialize};
+
+use crate::wallet::account::{types::address::AccountAddress, Account};
+
+/// How [`Account::generate_addresses()`] handles addresses that already received funds. These can only be returned if
+/// a start index of existing addresses is provided.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum AddressReuse {
+    /// The addresses are returned.
+    #[default]
+    Allow,
+    /// The addresses are returned and a warning is logged.
+    Warn,
+    /// The addresses aren't returned, [`Error::AddressReused`](crate::wallet::Error::AddressReused) is returned
+    /// instead.
+    Refuse,
+}
+
+/// Options to improve the privacy of an account, they're stored with the account.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct PrivacyOptions {
+    /// How generated addresses that already received funds are handled.
+    pub address_reuse: AddressReuse,
+    /// Remainders are sent to a new internal address instead of an address of the inputs, so the remainder can't be
+    /// told apart from the sent output. Custom remainder addresses of the transaction options are still used.
+    pub fresh_remainder_addresses: bool,
+}
+
+impl Account {
+    /// Sets the privacy options of the account. Large amounts can additionally be sent with
+    /// [`Account::send_amount_split()`], so the addresses of the account aren't linked by a single transaction.
+    pub async fn set_privacy_options(&self, options: PrivacyOptions) -> crate::wallet::Result<()> {
+        let mut account_details = self.write().await;
+        account_details.privacy_options = options;
+        #[cfg(feature = "storage")]
+        self.save(Some(&account_details)).await?;
+        Ok(())
+    }
+
+    /// Returns the privacy options of the account.
+    pub async fn privacy_options(&self) -> PrivacyOptions {
+        self.snapshot().privacy_options.clone()
+    }
+}
+
+// Checks that none of the generated addresses already received funds, if the account doesn't allow it
+pub(crate) fn check_address_reuse(
+    address_reuse: AddressReuse,
+    known_addresses: &[AccountAddress],
+    generated_addresses: &[AccountAddress],
+) -> crate::wallet::Result<()> {
+    if address_reuse == AddressReuse::Allow {
+        return Ok(());
+    }
+
+    for address in generated_addresses {
+        let used = known_addresses
+            .iter()
+            .any(|known_address| known_address.key_index == address.key_index && known_address.used);
+        if !used {
+            continue;
+        }
+        if address_reuse == AddressReuse::Refuse {
+            return Err(crate::wallet::Error::AddressReused(address.address.to_string()));
+        }
+        log::warn!(
+            "[ADDRESS GENERATION] address {} already received funds",
+            address.address
+        );
+    }
+
+    Ok(())
+}
@@ -0,0 +1,198 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::{collections::HashSet, time::Duration};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    types::block::payload::transaction::TransactionId,
+    wallet::account::{
+        types::{OutputData, OutputDataDto, Transaction, TransactionDto},
+        Account,
+    },
+};
+
+/// The records that got removed from an account by [`Account::prune()`].
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PrunedRecords {
+    /// The removed spent outputs.
+    pub outputs: Vec<OutputData>,
+    /// The removed sent transactions.
+    pub transactions: Vec<Transaction>,
+    /// The removed incoming transactions.
+    pub incoming_transactions: Vec<Transaction>,
+}
+
+impl PrunedRecords {
+    /// Returns true if no records were removed.
+    pub fn is_empty(&self) -> bool {
+        self.outputs.is_empty() && self.transactions.is_empty() && self.incoming_transactions.is_empty()
+    }
+}
+
+/// Dto for [`PrunedRecords`].
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PrunedRecordsDto {
+    /// The removed spent outputs.
+    pub outputs: Vec<OutputDataDto>,
+    /// The removed sent transactions.
+    pub transactions: Vec<TransactionDto>,
+    /// The removed incoming transactions.
+    pub incoming_transactions: Vec<TransactionDto>,
+}
+
+impl From<&PrunedRecords> for PrunedRecordsDto {
+    fn from(value: &PrunedRecords) -> Self {
+        Self {
+            outputs: value.outputs.iter().map(OutputDataDto::from).collect(),
+            transactions: value.transactions.iter().map(TransactionDto::from).collect(),
+            incoming_transactions: value.incoming_transactions.iter().map(TransactionDto::from).collect(),
+        }
+    }
+}
+
+impl Account {
+    /// Removes spent outputs and transactions that are older than `keep_spent_outputs_for` from the account, so they
+    /// don't accumulate forever. Unspent and locked outputs, pending transactions and transactions that created an
+    /// unspent output are always kept, so the balance doesn't change. If the wallet has a
+    /// [`CompactionPolicy`](crate::wallet::storage::compaction::CompactionPolicy) with an archive path, the removed
+    /// records are appended to an archive file of the account there. Returns the removed records.
+    pub async fn prune(&self, keep_spent_outputs_for: Duration) -> crate::wallet::Result<PrunedRecords> {
+        log::debug!("[PRUNE] prune records older than {keep_spent_outputs_for:?}");
+        let cutoff = crate::utils::unix_timestamp_now().saturating_sub(keep_spent_outputs_for);
+        let cutoff_secs = cutoff.as_secs();
+        let cutoff_millis = cutoff.as_millis();
+
+        let mut account_details = self.write().await;
+        let mut pruned_records = PrunedRecords::default();
+
+        let prunable_output_ids = account_details
+            .outputs
+            .values()
+            .filter(|output_data| {
+                let spent_timestamp = output_data
+                    .metadata
+                    .milestone_timestamp_spent()
+                    .unwrap_or_else(|| output_data.metadata.milestone_timestamp_booked());
+                output_data.is_spent
+                    && !account_details.unspent_outputs.contains_key(&output_data.output_id)
+                    && !account_details.locked_outputs.contains(&output_data.output_id)
+                    && u64::from(spent_timestamp) < cutoff_secs
+            })
+            .map(|output_data| output_data.output_id)
+            .collect::<Vec<_>>();
+        for output_id in prunable_output_ids {
+            if let Some(output_data) = account_details.outputs.remove(&output_id) {
+                pruned_records.outputs.push(output_data);
+            }
+        }
+
+        // Transactions that created an unspent output are needed to know if the output is a remainder
+        let needed_transaction_ids = account_details
+            .unspent_outputs
+            .keys()
+            .map(|output_id| *output_id.transaction_id())
+            .collect::<HashSet<TransactionId>>();
+        let is_prunable = |transaction: &Transaction| {
+            !account_details.pending_transactions.contains(&transaction.transaction_id)
+                && !needed_transaction_ids.contains(&transaction.transaction_id)
+                && transaction.timestamp < cutoff_millis
+        };
+        let prunable_transaction_ids = account_details
+            .transactions
+            .values()
+            .filter(|transaction| is_prunable(transaction))
+            .map(|transaction| transaction.transaction_id)
+            .collect::<Vec<_>>();
+        let prunable_incoming_transaction_ids = account_details
+            .incoming_transactions
+            .values()
+            .filter(|transaction| is_prunable(transaction))
+            .map(|transaction| transaction.transaction_id)
+            .collect::<Vec<_>>();
+        for transaction_id in prunable_transaction_ids {
+            if let Some(transaction) = account_details.transactions.remove(&transaction_id) {
+                pruned_records.transactions.push(transaction);
+            }
+        }
+        for transaction_id in prunable_incoming_transaction_ids {
+            if let Some(transaction) = account_details.incoming_transactions.remove(&transaction_id) {
+                pruned_records.incoming_transactions.push(transaction);
+            }
+        }
+        log::debug!(
+            "[PRUNE] pruned {} outputs, {} transactions and {} incoming transactions",
+            pruned_records.outputs.len(),
+            pruned_records.transactions.len(),
+            pruned_records.incoming_transactions.len()
+        );
+
+        if pruned_records.is_empty() {
+            return Ok(pruned_records);
+        }
+
+        #[cfg(all(feature = "storage", not(target_family = "wasm")))]
+        {
+            let archive_path = self
+                .storage_manager
+                .lock()
+                .await
+                .compaction_policy()
+                .and_then(|policy| policy.archive_path.clone());
+            // Archived before the records are removed from the storage, so they can't get lost
+            if let Some(archive_path) = archive_path {
+                append_to_archive(&archive_path, account_details.index, &pruned_records)?;
+            }
+        }
+
+        #[cfg(feature = "storage")]
+        self.save(Some(&account_details)).await?;
+
+        Ok(pruned_records)
+    }
+
+    /// Prunes the account with the compaction policy of the wallet, if it has more records than the policy allows.
+    #[cfg(feature = "storage")]
+    pub(crate) async fn compact(&self) -> crate::wallet::Result<()> {
+        let compaction_policy = self.storage_manager.lock().await.compaction_policy().cloned();
+        if let Some(compaction_policy) = compaction_policy {
+            let record_count = {
+                let account_details = self.read().await;
+                account_details.outputs.len()
+                    + account_details.transactions.len()
+                    + account_details.incoming_transactions.len()
+            };
+            if record_count > compaction_policy.max_records {
+                log::debug!(
+                    "[PRUNE] {record_count} records exceed the limit of {}",
+                    compaction_policy.max_records
+                );
+                self.prune(compaction_policy.keep_spent_outputs_for).await?;
+            }
+        }
+        Ok(())
+    }
+}
+
+// Appends the pruned records as one JSON line to the archive file of the account
+#[cfg(all(feature = "storage", not(target_family = "wasm")))]
+fn append_to_archive(
+    archive_path: &std::path::Path,
+    account_index: u32,
+    pruned_records: &PrunedRecords,
+) -> crate::wallet::Result<()> {
+    use std::io::Write;
+
+    std::fs::create_dir_all(archive_path)?;
+    let mut file = std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(archive_path.join(format!("account-{account_index}.jsonl")))?;
+    let mut line = serde_json::to_vec(pruned_records)?;
+    line.push(b'\n');
+    file.write_all(&line)?;
+    Ok(())
+}
@@ -4,12 +4,12 @@
 use crate::{
     types::{
         api::core::dto::LedgerInclusionStateDto,
-        block::{
-            payload::{transaction::TransactionId, Payload},
-            Block, BlockId,
-        },
+        block::{payload::transaction::TransactionId, Block, BlockId},
+    },
+    wallet::account::{
+        types::{InclusionState, Transaction},
+        Account,
     },
-    wallet::account::{types::InclusionState, Account},
 };
 
 const DEFAULT_RETRY_UNTIL_INCLUDED_INTERVAL: u64 = 1;
@@ -62,12 +62,7 @@ impl Account {
 
             let block_id = match transaction.block_id {
                 Some(block_id) => block_id,
-                None => self
-                    .client
-                    .block()
-                    .finish_block(Some(Payload::Transaction(Box::new(transaction.payload.clone()))))
-                    .await?
-                    .id(),
+                None => self.reattach_transaction(&transaction).await?,
             };
 
             // Attachments of the Block to check inclusion state
@@ -76,11 +71,7 @@ impl Account {
                 let duration =
                     std::time::Duration::from_secs(interval.unwrap_or(DEFAULT_RETRY_UNTIL_INCLUDED_INTERVAL));
 
-                #[cfg(target_family = "wasm")]
-                gloo_timers::future::TimeoutFuture::new(duration.as_millis() as u32).await;
-
-                #[cfg(not(target_family = "wasm"))]
-                tokio::time::sleep(duration).await;
+                self.client.cancellation_token().sleep(duration).await?;
 
                 // Check inclusion state for each attachment
                 let block_ids_len = block_ids.len();
@@ -103,12 +94,7 @@ impl Account {
                             // Safe to unwrap since we iterate over it
                             self.client.promote_unchecked(block_ids.last().unwrap()).await?;
                         } else if block_metadata.should_reattach.unwrap_or(false) {
-                            let reattached_block = self
-                                .client
-                                .block()
-                                .finish_block(Some(Payload::Transaction(Box::new(transaction.payload.clone()))))
-                                .await?;
-                            block_ids.push(reattached_block.id());
+                            block_ids.push(self.reattach_transaction(&transaction).await?);
                         }
                     }
                 }
@@ -124,4 +110,20 @@ impl Account {
             Err(crate::wallet::Error::TransactionNotFound(*transaction_id))
         }
     }
+
+    /// Submits the transaction in a new block and records the attachment on the stored transaction.
+    async fn reattach_transaction(&self, transaction: &Transaction) -> crate::wallet::Result<BlockId> {
+        let attachment = self.submit_transaction_payload(transaction.payload.clone()).await?;
+        let block_id = attachment.block_id;
+
+        let mut account_details = self.write().await;
+        account_details
+            .transactions
+            .entry(transaction.transaction_id)
+            .and_modify(|t| t.add_attachment(attachment));
+        #[cfg(feature = "storage")]
+        self.save(Some(&account_details)).await?;
+
+        Ok(block_id)
+    }
 }
@@ -0,0 +1,229 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::{collections::HashSet, sync::Arc};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    client::api::PreparedTransactionData,
+    types::block::{
+        address::{Address, Bech32Address},
+        output::Output,
+        payload::transaction::TransactionEssence,
+    },
+    wallet::account::{types::InclusionState, Account, AccountDetails},
+};
+
+// The duration in milliseconds for which the sent amounts are summed up for the daily limit
+const DAY_IN_MILLIS: u128 = 24 * 60 * 60 * 1000;
+
+/// Limits for the transactions of an account, checked before a transaction is signed. Only the outputs that are sent
+/// to addresses outside of the account count, so remainders and transfers to the own addresses aren't limited.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SpendingPolicy {
+    /// The maximum amount that can be sent in a single transaction, not limited by default.
+    #[serde(default)]
+    pub max_amount_per_transaction: Option<u64>,
+    /// The maximum amount that can be sent in all transactions of the last 24 hours, not limited by default.
+    #[serde(default)]
+    pub max_amount_per_day: Option<u64>,
+    /// The only addresses that can receive outputs, all addresses can if `None`.
+    #[serde(default)]
+    pub allowed_addresses: Option<HashSet<Bech32Address>>,
+    /// Addresses that can't receive outputs.
+    #[serde(default)]
+    pub denied_addresses: HashSet<Bech32Address>,
+}
+
+/// The reason a transaction was refused by the [`SpendingPolicy`] or the [`TransactionApprover`] of an account.
+#[derive(Clone, Debug, Eq, PartialEq, thiserror::Error)]
+pub enum SpendingPolicyViolation {
+    /// The transaction sends more than the limit per transaction.
+    #[error("transaction amount {amount} exceeds the limit of {limit}")]
+    TransactionLimitExceeded { amount: u64, limit: u64 },
+    /// The transaction and the ones of the last 24 hours send more than the daily limit.
+    #[error("amount {amount} sent within 24 hours exceeds the limit of {limit}")]
+    DailyLimitExceeded { amount: u64, limit: u64 },
+    /// An output is sent to a denied address.
+    #[error("address {0} is denied")]
+    AddressDenied(Bech32Address),
+    /// An output is sent to an address that isn't allowed.
+    #[error("address {0} is not allowed")]
+    AddressNotAllowed(Bech32Address),
+    /// The transaction approver didn't approve the transaction.
+    #[error("transaction was rejected by the approver")]
+    Rejected,
+}
+
+/// What a transaction sends to addresses outside of the account, passed to the [`TransactionApprover`].
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OutgoingTransfer {
+    /// The amount of the outputs that are sent to addresses outside of the account.
+    pub amount: u64,
+    /// The amount sent in the transactions of the last 24 hours, without this one.
+    pub amount_last_day: u64,
+    /// The addresses outside of the account that receive outputs.
+    pub recipients: Vec<Bech32Address>,
+}
+
+/// A check of an integration that is called before a transaction of the account is signed, e.g. to require the
+/// approval of a second person. The transaction is only signed if the approver returns true.
+///
+/// The approver is set with [`Account::set_transaction_approver()`] and called after the [`SpendingPolicy`] is
+/// checked.
+#[async_trait::async_trait]
+pub trait TransactionApprover: std::fmt::Debug + Send + Sync {
+    /// Returns true if the transaction can be signed.
+    async fn approve(
+        &self,
+        account: &AccountDetails,
+        prepared_transaction_data: &PreparedTransactionData,
+        transfer: &OutgoingTransfer,
+    ) -> crate::wallet::Result<bool>;
+}
+
+impl Account {
+    /// Sets the limits for the transactions of the account. The policy isn't stored and needs to be set again after a
+    /// restart.
+    pub async fn set_spending_policy(&self, policy: SpendingPolicy) {
+        *self.spending_policy.lock().await = policy;
+    }
+
+    /// Returns the limits for the transactions of the account.
+    pub async fn spending_policy(&self) -> SpendingPolicy {
+        self.spending_policy.lock().await.clone()
+    }
+
+    /// Sets the approver that is called before a transaction of the account is signed, or removes it with `None`. The
+    /// approver isn't stored and needs to be set again after a restart.
+    pub async fn set_transaction_approver(&self, approver: Option<Arc<dyn TransactionApprover>>) {
+        *self.transaction_approver.write().await = approver;
+    }
+
+    // Checks the transaction against the spending policy and calls the approver
+    pub(crate) async fn check_spending_policy(
+        &self,
+        prepared_transaction_data: &PreparedTransactionData,
+    ) -> crate::wallet::Result<()> {
+        let policy = self.spending_policy().await;
+        // Clone the approver, so the lock isn't held while it's called
+        let approver = self.transaction_approver.read().await.clone();
+        if policy == SpendingPolicy::default() && approver.is_none() {
+            return Ok(());
+        }
+
+        let bech32_hrp = self.client.get_bech32_hrp().await?;
+        let account_details = self.snapshot();
+        let account_addresses = account_details
+            .public_addresses
+            .iter()
+            .chain(account_details.internal_addresses.iter())
+            .map(|address| *address.address.inner())
+            .collect::<HashSet<_>>();
+
+        let (amount, recipients) = outgoing_outputs(&prepared_transaction_data.essence, &account_addresses);
+        let recipients = recipients
+            .into_iter()
+            .map(|inner| Bech32Address {
+                hrp: bech32_hrp.clone(),
+                inner,
+            })
+            .collect::<Vec<_>>();
+
+        for recipient in &recipients {
+            if policy
+                .denied_addresses
+                .iter()
+                .any(|denied| denied.inner() == recipient.inner())
+            {
+                return Err(SpendingPolicyViolation::AddressDenied(recipient.clone()).into());
+            }
+            if let Some(allowed_addresses) = &policy.allowed_addresses {
+                if !allowed_addresses
+                    .iter()
+                    .any(|allowed| allowed.inner() == recipient.inner())
+                {
+                    return Err(SpendingPolicyViolation::AddressNotAllowed(recipient.clone()).into());
+                }
+            }
+        }
+
+        if let Some(limit) = policy.max_amount_per_transaction {
+            if amount > limit {
+                return Err(SpendingPolicyViolation::TransactionLimitExceeded { amount, limit }.into());
+            }
+        }
+
+        let since = crate::utils::unix_timestamp_now()
+            .as_millis()
+            .saturating_sub(DAY_IN_MILLIS);
+        let amount_last_day = account_details
+            .transactions()
+            .values()
+            // Conflicting transactions didn't send anything
+            .filter(|transaction| {
+                !transaction.incoming
+                    && transaction.timestamp >= since
+                    && transaction.inclusion_state != InclusionState::Conflicting
+            })
+            .fold(0u64, |sum, transaction| {
+                sum.saturating_add(outgoing_outputs(transaction.payload.essence(), &account_addresses).0)
+            });
+        if let Some(limit) = policy.max_amount_per_day {
+            let amount = amount_last_day.saturating_add(amount);
+            if amount > limit {
+                return Err(SpendingPolicyViolation::DailyLimitExceeded { amount, limit }.into());
+            }
+        }
+
+        if let Some(approver) = approver {
+            let transfer = OutgoingTransfer {
+                amount,
+                amount_last_day,
+                recipients,
+            };
+            if !approver
+                .approve(&account_details, prepared_transaction_data, &transfer)
+                .await?
+            {
+                return Err(SpendingPolicyViolation::Rejected.into());
+            }
+        }
+
+        Ok(())
+    }
+}
+
+// Returns the amount and the distinct addresses of the outputs that aren't sent to the account, foundry outputs stay
+// with their alias and aren't counted
+fn outgoing_outputs(essence: &TransactionEssence, account_addresses: &HashSet<Address>) -> (u64, Vec<Address>) {
+    let TransactionEssence::Regular(essence) = essence;
+
+    let mut amount = 0u64;
+    let mut recipients = Vec::new();
+    for output in essence.outputs() {
+        let recipient = match output {
+            Output::Alias(alias_output) => Some(*alias_output.governor_address()),
+            Output::Foundry(_) | Output::Treasury(_) => None,
+            _ => output
+                .unlock_conditions()
+                .and_then(|unlock_conditions| unlock_conditions.address())
+                .map(|unlock_condition| *unlock_condition.address()),
+        };
+        let Some(recipient) = recipient else {
+            continue;
+        };
+        if account_addresses.contains(&recipient) {
+            continue;
+        }
+        amount = amount.saturating_add(output.amount());
+        if !recipients.contains(&recipient) {
+            recipients.push(recipient);
+        }
+    }
+
+    (amount, recipients)
+}
@@ -15,6 +15,7 @@ impl Account {
     /// Get the addresses that should be synced with the current known unspent output ids
     /// Also adds alias and nft addresses from unspent alias or nft outputs that have no Timelock, Expiration or
     /// StorageDepositReturn [`UnlockCondition`]
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip_all))]
     pub(crate) async fn get_addresses_to_sync(
         &self,
         options: &SyncOptions,
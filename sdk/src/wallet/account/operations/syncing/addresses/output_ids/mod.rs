@@ -9,13 +9,14 @@ use std::collections::HashSet;
 
 #[cfg(not(target_family = "wasm"))]
 use futures::FutureExt;
+use futures::StreamExt;
 use instant::Instant;
 
 use crate::{
     types::block::{address::Address, output::OutputId},
-    wallet::account::{
-        constants::PARALLEL_REQUESTS_AMOUNT, operations::syncing::SyncOptions,
-        types::address::AddressWithUnspentOutputs, Account,
+    wallet::{
+        account::{operations::syncing::SyncOptions, types::address::AddressWithUnspentOutputs, Account},
+        task,
     },
 };
 
@@ -161,60 +162,41 @@ impl Account {
         let mut addresses_with_outputs = Vec::new();
         // spent outputs or alias/nft/foundries that don't get synced anymore, because of other sync options
         let mut spent_or_not_anymore_synced_outputs = Vec::new();
-        // We split the addresses into chunks so we don't get timeouts if we have thousands
-        for addresses_chunk in &mut addresses_with_unspent_outputs
-            .chunks(PARALLEL_REQUESTS_AMOUNT)
-            .map(|x: &[AddressWithUnspentOutputs]| x.to_vec())
-        {
-            let results;
-            #[cfg(target_family = "wasm")]
-            {
-                let mut tasks = Vec::new();
-                for address in addresses_chunk {
-                    let output_ids = self.get_output_ids_for_address(address.address.inner, &options).await?;
-                    tasks.push(crate::wallet::Result::Ok((address, output_ids)));
-                }
-                results = tasks;
-            }
-
-            #[cfg(not(target_family = "wasm"))]
-            {
-                let mut tasks = Vec::new();
-                for address in addresses_chunk {
-                    let account = self.clone();
-                    let sync_options = options.clone();
-                    tasks.push(async move {
-                        tokio::spawn(async move {
-                            let output_ids = account
-                                .get_output_ids_for_address(address.address.inner, &sync_options)
-                                .await?;
-                            crate::wallet::Result::Ok((address, output_ids))
-                        })
-                        .await
-                    });
+        // Only a limited amount of addresses is requested at the same time, so we don't get timeouts if we have
+        // thousands, a new request starts as soon as a previous one finished
+        let mut results = futures::stream::iter(addresses_with_unspent_outputs)
+            .map(|address| {
+                let account = self.clone();
+                let sync_options = options.clone();
+                async move {
+                    task::spawn(async move {
+                        let output_ids = account
+                            .get_output_ids_for_address(address.address.inner, &sync_options)
+                            .await?;
+                        crate::wallet::Result::Ok((address, output_ids))
+                    })
+                    .await?
                 }
-
-                results = futures::future::try_join_all(tasks).await?;
-            }
-
-            for res in results {
-                let (mut address, output_ids): (AddressWithUnspentOutputs, Vec<OutputId>) = res?;
-                // only return addresses with outputs
-                if !output_ids.is_empty() {
-                    // outputs we had before, but now not anymore, got spent or are alias/nft/foundries that don't get
-                    // synced anymore because of other sync options
-                    for output_id in address.output_ids {
-                        if !output_ids.contains(&output_id) {
-                            spent_or_not_anymore_synced_outputs.push(output_id);
-                        }
+            })
+            .buffer_unordered(options.max_parallel_api_requests.max(1));
+
+        while let Some(res) = results.next().await {
+            let (mut address, output_ids): (AddressWithUnspentOutputs, Vec<OutputId>) = res?;
+            // only return addresses with outputs
+            if !output_ids.is_empty() {
+                // outputs we had before, but now not anymore, got spent or are alias/nft/foundries that don't get
+                // synced anymore because of other sync options
+                for output_id in address.output_ids {
+                    if !output_ids.contains(&output_id) {
+                        spent_or_not_anymore_synced_outputs.push(output_id);
                     }
-                    address.output_ids = output_ids;
-                    addresses_with_outputs.push(address);
-                } else {
-                    // outputs we had before, but now not anymore, got spent or are alias/nft/foundries that don't get
-                    // synced anymore because of other sync options
-                    spent_or_not_anymore_synced_outputs.extend(address.output_ids.into_iter());
                 }
+                address.output_ids = output_ids;
+                addresses_with_outputs.push(address);
+            } else {
+                // outputs we had before, but now not anymore, got spent or are alias/nft/foundries that don't get
+                // synced anymore because of other sync options
+                spent_or_not_anymore_synced_outputs.extend(address.output_ids.into_iter());
             }
         }
 
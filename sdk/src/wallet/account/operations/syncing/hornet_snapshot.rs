@@ -0,0 +1,341 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Import of the outputs of an account from the full and delta ledger snapshots of a Hornet node.
+//!
+//! A full snapshot contains the ledger state at its ledger milestone index, delta snapshots contain the milestone diffs
+//! with the created and consumed outputs since the full snapshot. All numbers are little endian.
+//!
+//! Full snapshot: version (u8), type (u8, 0), genesis milestone index (u32), target milestone index (u32), target
+//! milestone timestamp (u32), target milestone id (32 bytes), ledger milestone index (u32), treasury output milestone
+//! id (32 bytes), treasury output amount (u64), protocol parameters milestone option length (u16) and option, output
+//! count (u64), milestone diff count (u32), solid entry point count (u16), outputs, milestone diffs, solid entry
+//! points.
+//!
+//! Delta snapshot: version (u8), type (u8, 1), genesis milestone index (u32), target milestone index (u32), target
+//! milestone timestamp (u32), target milestone id (32 bytes), full snapshot target milestone id (32 bytes), solid
+//! entry point file offset (u64), milestone diff count (u32), solid entry point count (u16), milestone diffs, solid
+//! entry points.
+//!
+//! Output: output id (34 bytes), block id (32 bytes), milestone index booked (u32), milestone timestamp booked (u32),
+//! output length (u32) and output. A consumed output is followed by the target transaction id (32 bytes), the
+//! milestone index spent (u32) and the milestone timestamp spent (u32).
+//!
+//! Milestone diff: milestone diff length (u32), milestone payload length (u32) and payload, the treasury input
+//! milestone id (32 bytes) and treasury output amount (u64) if the milestone has a receipt, created output count (u32)
+//! and outputs, consumed output count (u32) and outputs.
+
+use std::{
+    collections::{HashMap, HashSet},
+    fs::File,
+    io::{BufReader, Read},
+    path::{Path, PathBuf},
+};
+
+use packable::PackableExt;
+
+use crate::{
+    types::{
+        api::core::response::OutputWithMetadataResponse,
+        block::{
+            address::Address,
+            output::{Output, OutputId, OutputMetadata, OutputWithMetadata},
+            payload::{milestone::option::ParametersMilestoneOption, Payload},
+            protocol::ProtocolParameters,
+            BlockId,
+        },
+    },
+    wallet::account::{
+        operations::{address_generation::AddressGenerationOptions, syncing::snapshot::owner_addresses},
+        Account, UtxoSnapshot,
+    },
+};
+
+const SNAPSHOT_VERSION: u8 = 2;
+const FULL_SNAPSHOT_TYPE: u8 = 0;
+const DELTA_SNAPSHOT_TYPE: u8 = 1;
+
+impl Account {
+    /// Imports the outputs of the account from the full snapshot of a Hornet node and optional delta snapshots, which
+    /// need to be in the order of their milestones, so old accounts with many outputs can be restored without
+    /// requesting all of them from the nodes. The snapshots are read offline and only outputs that could be owned by
+    /// the account are kept, before they're imported like with [`Account::import_utxo_snapshot()`], so the account
+    /// should be synced afterwards to reconcile it with the node. If an address gap limit is provided, public and
+    /// internal addresses are generated until that many consecutive addresses don't own outputs in the snapshots, which
+    /// requires reading the snapshots twice. Returns the number of imported outputs.
+    pub async fn import_hornet_snapshot(
+        &self,
+        full_snapshot_path: impl AsRef<Path> + Send,
+        delta_snapshot_paths: &[PathBuf],
+        address_gap_limit: Option<u32>,
+    ) -> crate::wallet::Result<usize> {
+        log::debug!("[SYNC] import_hornet_snapshot");
+        let full_snapshot_path = full_snapshot_path.as_ref();
+
+        if let Some(address_gap_limit) = address_gap_limit.filter(|limit| *limit > 0) {
+            let mut snapshot_addresses = HashSet::new();
+            HornetLedger::read(full_snapshot_path, delta_snapshot_paths, |output| {
+                snapshot_addresses.extend(owner_addresses(output).filter(|address| address.is_ed25519()).copied());
+                false
+            })?;
+            self.generate_snapshot_addresses(&snapshot_addresses, address_gap_limit)
+                .await?;
+        }
+
+        let account_details = self.read().await;
+        let addresses = account_details
+            .public_addresses
+            .iter()
+            .chain(account_details.internal_addresses.iter())
+            .map(|address| address.address.inner)
+            .collect::<HashSet<_>>();
+        drop(account_details);
+
+        // Outputs owned by aliases and NFTs are kept as well, the import resolves if they're owned by the account
+        let ledger = HornetLedger::read(full_snapshot_path, delta_snapshot_paths, |output| {
+            owner_addresses(output).any(|address| !address.is_ed25519() || addresses.contains(address))
+        })?;
+
+        let snapshot = UtxoSnapshot {
+            network_id: ledger.protocol_parameters.network_id().to_string(),
+            ledger_index: ledger.ledger_index,
+            outputs: ledger.outputs.values().map(OutputWithMetadataResponse::from).collect(),
+        };
+        self.import_utxo_snapshot(&snapshot).await
+    }
+
+    // Generates public and internal addresses until the gap limit of consecutive addresses without outputs is reached
+    async fn generate_snapshot_addresses(
+        &self,
+        snapshot_addresses: &HashSet<Address>,
+        address_gap_limit: u32,
+    ) -> crate::wallet::Result<()> {
+        for internal in [false, true] {
+            loop {
+                let addresses = self
+                    .generate_addresses(
+                        address_gap_limit,
+                        Some(AddressGenerationOptions {
+                            internal,
+                            ..Default::default()
+                        }),
+                    )
+                    .await?;
+                if !addresses
+                    .iter()
+                    .any(|address| snapshot_addresses.contains(address.address.inner()))
+                {
+                    break;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+// The unspent outputs of the ledger after applying the delta snapshots to the full snapshot
+struct HornetLedger {
+    protocol_parameters: ProtocolParameters,
+    ledger_index: u32,
+    outputs: HashMap<OutputId, OutputWithMetadata>,
+}
+
+impl HornetLedger {
+    // Only the unspent outputs matching the filter are kept, so the full ledger doesn't need to be held in memory
+    fn read(
+        full_snapshot_path: &Path,
+        delta_snapshot_paths: &[PathBuf],
+        mut filter: impl FnMut(&Output) -> bool,
+    ) -> crate::wallet::Result<Self> {
+        let mut reader = BufReader::new(File::open(full_snapshot_path)?);
+        read_header(&mut reader, FULL_SNAPSHOT_TYPE)?;
+        let ledger_index = read_u32(&mut reader)?;
+        // Treasury output
+        read_array::<_, 32>(&mut reader)?;
+        read_u64(&mut reader)?;
+
+        let parameters_option_length = read_u16(&mut reader)?;
+        let parameters_option = read_bytes(&mut reader, parameters_option_length.into())?;
+        let protocol_parameters = match parameters_option.split_first() {
+            Some((&ParametersMilestoneOption::KIND, parameters_option)) => {
+                ParametersMilestoneOption::unpack_verified(parameters_option, &())
+                    .map_err(|e| invalid_snapshot(format!("invalid protocol parameters milestone option: {e:?}")))?
+                    .protocol_parameters()?
+            }
+            _ => return Err(invalid_snapshot("missing protocol parameters milestone option")),
+        };
+
+        let output_count = read_u64(&mut reader)?;
+        // The milestone diffs of the full snapshot lead back to its target milestone, they're not needed because the
+        // outputs are the ledger state at the ledger milestone index
+        read_u32(&mut reader)?;
+        read_u16(&mut reader)?;
+
+        let mut ledger = Self {
+            protocol_parameters,
+            ledger_index,
+            outputs: HashMap::new(),
+        };
+
+        for _ in 0..output_count {
+            let output = ledger.read_output(&mut reader)?;
+            if filter(output.output()) {
+                ledger.outputs.insert(*output.metadata().output_id(), output);
+            }
+        }
+        log::debug!(
+            "[SYNC] read {output_count} outputs of the full Hornet snapshot at ledger index {ledger_index}, kept {}",
+            ledger.outputs.len()
+        );
+
+        for delta_snapshot_path in delta_snapshot_paths {
+            let mut reader = BufReader::new(File::open(delta_snapshot_path)?);
+            read_header(&mut reader, DELTA_SNAPSHOT_TYPE)?;
+            // Full snapshot target milestone id and solid entry point file offset
+            read_array::<_, 32>(&mut reader)?;
+            read_u64(&mut reader)?;
+            let milestone_diff_count = read_u32(&mut reader)?;
+            read_u16(&mut reader)?;
+
+            for _ in 0..milestone_diff_count {
+                ledger.apply_milestone_diff(&mut reader, &mut filter)?;
+            }
+        }
+
+        Ok(ledger)
+    }
+
+    fn read_output(&self, reader: &mut impl Read) -> crate::wallet::Result<OutputWithMetadata> {
+        let output_id = OutputId::try_from(read_array::<_, { OutputId::LENGTH }>(reader)?)
+            .map_err(|e| invalid_snapshot(format!("invalid output id: {e}")))?;
+        let block_id = BlockId::new(read_array(reader)?);
+        let milestone_index_booked = read_u32(reader)?;
+        let milestone_timestamp_booked = read_u32(reader)?;
+        let output_length = read_u32(reader)?;
+        let output = Output::unpack_verified(read_bytes(reader, output_length as usize)?, &self.protocol_parameters)
+            .map_err(|e| invalid_snapshot(format!("invalid output {output_id}: {e:?}")))?;
+
+        Ok(OutputWithMetadata::new(
+            output,
+            OutputMetadata::new(
+                block_id,
+                output_id,
+                false,
+                None,
+                None,
+                None,
+                milestone_index_booked,
+                milestone_timestamp_booked,
+                self.ledger_index,
+            ),
+        ))
+    }
+
+    // Applies the created and consumed outputs of a milestone diff, diffs that are already part of the ledger state are
+    // skipped
+    fn apply_milestone_diff(
+        &mut self,
+        reader: &mut impl Read,
+        filter: &mut impl FnMut(&Output) -> bool,
+    ) -> crate::wallet::Result<()> {
+        // Milestone diff length
+        read_u32(reader)?;
+        let milestone_length = read_u32(reader)?;
+        let milestone = match Payload::unpack_verified(
+            read_bytes(reader, milestone_length as usize)?,
+            &self.protocol_parameters,
+        ) {
+            Ok(Payload::Milestone(milestone)) => milestone,
+            _ => return Err(invalid_snapshot("invalid milestone payload of a milestone diff")),
+        };
+        if milestone.essence().options().receipt().is_some() {
+            // Treasury input milestone id and treasury output amount
+            read_array::<_, 32>(reader)?;
+            read_u64(reader)?;
+        }
+
+        let milestone_index = *milestone.essence().index();
+        let skip = milestone_index <= self.ledger_index;
+        if !skip {
+            self.ledger_index = milestone_index;
+        }
+
+        let created_count = read_u32(reader)?;
+        for _ in 0..created_count {
+            let output = self.read_output(reader)?;
+            if !skip && filter(output.output()) {
+                self.outputs.insert(*output.metadata().output_id(), output);
+            }
+        }
+
+        let consumed_count = read_u32(reader)?;
+        for _ in 0..consumed_count {
+            let output = self.read_output(reader)?;
+            // Target transaction id, milestone index and timestamp spent
+            read_array::<_, 32>(reader)?;
+            read_u32(reader)?;
+            read_u32(reader)?;
+            if !skip {
+                self.outputs.remove(output.metadata().output_id());
+            }
+        }
+
+        Ok(())
+    }
+}
+
+// Reads the header fields that full and delta snapshots have in common
+fn read_header(reader: &mut impl Read, snapshot_type: u8) -> crate::wallet::Result<()> {
+    let version = read_u8(reader)?;
+    if version != SNAPSHOT_VERSION {
+        return Err(invalid_snapshot(format!(
+            "unsupported Hornet snapshot version {version}"
+        )));
+    }
+    let actual_type = read_u8(reader)?;
+    if actual_type != snapshot_type {
+        return Err(invalid_snapshot(format!(
+            "expected Hornet snapshot type {snapshot_type}, found {actual_type}"
+        )));
+    }
+    // Genesis milestone index, target milestone index, timestamp and id
+    read_u32(reader)?;
+    read_u32(reader)?;
+    read_u32(reader)?;
+    read_array::<_, 32>(reader)?;
+
+    Ok(())
+}
+
+fn invalid_snapshot(message: impl Into<String>) -> crate::wallet::Error {
+    crate::wallet::Error::InvalidUtxoSnapshot(message.into())
+}
+
+fn read_array<R: Read, const N: usize>(reader: &mut R) -> crate::wallet::Result<[u8; N]> {
+    let mut bytes = [0; N];
+    reader.read_exact(&mut bytes)?;
+    Ok(bytes)
+}
+
+fn read_bytes(reader: &mut impl Read, length: usize) -> crate::wallet::Result<Vec<u8>> {
+    let mut bytes = vec![0; length];
+    reader.read_exact(&mut bytes)?;
+    Ok(bytes)
+}
+
+fn read_u8(reader: &mut impl Read) -> crate::wallet::Result<u8> {
+    Ok(u8::from_le_bytes(read_array(reader)?))
+}
+
+fn read_u16(reader: &mut impl Read) -> crate::wallet::Result<u16> {
+    Ok(u16::from_le_bytes(read_array(reader)?))
+}
+
+fn read_u32(reader: &mut impl Read) -> crate::wallet::Result<u32> {
+    Ok(u32::from_le_bytes(read_array(reader)?))
+}
+
+fn read_u64(reader: &mut impl Read) -> crate::wallet::Result<u64> {
+    Ok(u64::from_le_bytes(read_array(reader)?))
+}
@@ -3,13 +3,16 @@
 
 pub(crate) mod addresses;
 pub(crate) mod foundries;
+#[cfg(not(target_family = "wasm"))]
+pub(crate) mod hornet_snapshot;
 pub(crate) mod options;
 pub(crate) mod outputs;
+pub(crate) mod snapshot;
 pub(crate) mod transactions;
 
 use std::collections::{HashMap, HashSet};
 
-pub use self::options::SyncOptions;
+pub use self::{options::SyncOptions, snapshot::UtxoSnapshot};
 use crate::{
     types::block::{
         address::{Address, AliasAddress, NftAddress},
@@ -18,7 +21,7 @@ use crate::{
     wallet::account::{
         constants::MIN_SYNC_INTERVAL,
         types::{AddressWithUnspentOutputs, OutputData},
-        Account, AccountBalance,
+        Account, AccountBalance, WatchedOperation,
     },
 };
 
@@ -44,6 +47,10 @@ impl Account {
 
     /// Sync the account by fetching new information from the nodes. Will also retry pending transactions
     /// if necessary. A custom default can be set using set_default_sync_options.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "sync", skip_all, fields(account_alias = %self.snapshot().alias()))
+    )]
     pub async fn sync(&self, options: Option<SyncOptions>) -> crate::wallet::Result<AccountBalance> {
         let options = match options {
             Some(opt) => opt,
@@ -51,6 +58,8 @@ impl Account {
         };
 
         log::debug!("[SYNC] start syncing with {:?}", options);
+        // Fail before any work if the wallet was stopped
+        self.client.cancellation_token().check()?;
         let syc_start_time = instant::Instant::now();
 
         // Prevent syncing the account multiple times simultaneously
@@ -67,7 +76,24 @@ impl Account {
             return self.balance().await;
         }
 
-        self.sync_internal(&options).await?;
+        let result = self
+            .watch(WatchedOperation::Sync, || self.sync_and_save(&options))
+            .await;
+        self.health.lock().await.record(&result);
+        #[cfg(feature = "metrics")]
+        crate::metrics::record_sync(syc_start_time.elapsed(), result.is_ok());
+        let account_balance = result?;
+
+        // Update last_synced mutex
+        let time_now = crate::utils::unix_timestamp_now().as_millis();
+        *last_synced = time_now;
+        log::debug!("[SYNC] finished syncing in {:.2?}", syc_start_time.elapsed());
+        Ok(account_balance)
+    }
+
+    // Syncs the account and saves it, returns the new balance
+    async fn sync_and_save(&self, options: &SyncOptions) -> crate::wallet::Result<AccountBalance> {
+        self.sync_internal(options).await?;
 
         // Sync transactions after updating account with outputs, so we can use them to check the transaction
         // status
@@ -76,18 +102,22 @@ impl Account {
             // Sync again if we don't know the output yet, to prevent having no unspent outputs after syncing
             if confirmed_tx_with_unknown_output {
                 log::debug!("[SYNC] a transaction for which no output is known got confirmed, syncing outputs again");
-                self.sync_internal(&options).await?;
+                self.sync_internal(options).await?;
             }
         };
 
-        let account_balance = self.balance().await?;
-        // Update last_synced mutex
-        let time_now = crate::utils::unix_timestamp_now().as_millis();
-        *last_synced = time_now;
-        log::debug!("[SYNC] finished syncing in {:.2?}", syc_start_time.elapsed());
-        Ok(account_balance)
+        // All changes of the sync are saved together, so a sync is never stored partially
+        #[cfg(feature = "storage")]
+        {
+            log::debug!("[SYNC] storing account {} with new synced data", self.alias().await);
+            self.save(None).await?;
+            self.compact().await?;
+        }
+
+        self.balance().await
     }
 
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip_all))]
     async fn sync_internal(&self, options: &SyncOptions) -> crate::wallet::Result<()> {
         log::debug!("[SYNC] sync_internal");
 
@@ -153,6 +183,7 @@ impl Account {
     // First request all outputs directly related to the ed25519 addresses, then for each nft and alias output we got,
     // request all outputs that are related to their alias/nft addresses in a loop until no new alias or nft outputs is
     // found
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip_all))]
     async fn request_outputs_recursively(
         &self,
         addresses_to_sync: Vec<AddressWithUnspentOutputs>,
@@ -173,7 +204,7 @@ impl Account {
                 spent_or_not_synced_output_ids = spent_or_not_synced_output_ids_inner;
                 // Get outputs for addresses and add them also the the addresses_with_unspent_outputs
                 let (addresses_with_unspent_outputs_inner, outputs_data_inner) = self
-                    .get_outputs_from_address_output_ids(addresses_with_output_ids)
+                    .get_outputs_from_address_output_ids(options, addresses_with_output_ids)
                     .await?;
                 addresses_with_unspent_outputs = addresses_with_unspent_outputs_inner;
                 outputs_data.extend(outputs_data_inner.clone().into_iter());
@@ -3,6 +3,8 @@
 
 use serde::{Deserialize, Serialize};
 
+use crate::wallet::account::constants::PARALLEL_REQUESTS_AMOUNT;
+
 const DEFAULT_ADDRESS_START_INDEX: u32 = 0;
 const DEFAULT_FORCE_SYNCING: bool = false;
 const DEFAULT_SYNC_INCOMING_TRANSACTIONS: bool = false;
@@ -54,6 +56,10 @@ pub struct SyncOptions {
     /// Sync native token foundries, so their metadata can be returned in the balance.
     #[serde(default = "default_sync_native_token_foundries")]
     pub sync_native_token_foundries: bool,
+    /// Max amount of addresses that are synced in parallel, lower it for slow connections or nodes with strict rate
+    /// limits.
+    #[serde(default = "default_max_parallel_api_requests")]
+    pub max_parallel_api_requests: usize,
 }
 
 fn default_address_start_index() -> u32 {
@@ -80,6 +86,10 @@ fn default_sync_native_token_foundries() -> bool {
     DEFAULT_SYNC_NATIVE_TOKEN_FOUNDRIES
 }
 
+fn default_max_parallel_api_requests() -> usize {
+    PARALLEL_REQUESTS_AMOUNT
+}
+
 impl Default for SyncOptions {
     fn default() -> Self {
         Self {
@@ -94,6 +104,7 @@ impl Default for SyncOptions {
             sync_only_most_basic_outputs: default_sync_only_most_basic_outputs(),
             sync_native_token_foundries: default_sync_native_token_foundries(),
             force_syncing: default_force_syncing(),
+            max_parallel_api_requests: default_max_parallel_api_requests(),
         }
     }
 }
@@ -128,10 +139,10 @@ pub struct AliasSyncOptions {
 }
 
 impl Default for AliasSyncOptions {
-    // Sync only foundries
+    // Sync basic outputs controlled by the alias and foundries
     fn default() -> Self {
         Self {
-            basic_outputs: false,
+            basic_outputs: true,
             nft_outputs: false,
             alias_outputs: false,
             foundry_outputs: true,
@@ -140,10 +151,21 @@ impl Default for AliasSyncOptions {
 }
 
 /// Sync options for addresses from NFT outputs
-#[derive(Debug, Default, Clone, Eq, PartialEq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Serialize, Deserialize)]
 #[serde(default, rename_all = "camelCase")]
 pub struct NftSyncOptions {
     pub basic_outputs: bool,
     pub nft_outputs: bool,
     pub alias_outputs: bool,
 }
+
+impl Default for NftSyncOptions {
+    // Sync basic outputs controlled by the NFT
+    fn default() -> Self {
+        Self {
+            basic_outputs: true,
+            nft_outputs: false,
+            alias_outputs: false,
+        }
+    }
+}
@@ -18,7 +18,11 @@ use crate::{
         },
     },
     wallet::{
-        account::{build_transaction_from_payload_and_inputs, types::OutputData, Account, AddressWithUnspentOutputs},
+        account::{
+            build_transaction_from_payload_and_inputs,
+            types::{InclusionState, OutputData},
+            Account, AddressWithUnspentOutputs,
+        },
         task,
     },
 };
@@ -104,7 +108,18 @@ impl Account {
         drop(account_details);
 
         if !unknown_outputs.is_empty() {
-            outputs.extend(self.client.get_outputs(unknown_outputs).await?);
+            let outputs_with_errors = self.client.get_outputs_with_errors(unknown_outputs).await;
+            outputs.extend(outputs_with_errors.outputs);
+
+            for (output_id, error) in outputs_with_errors.errors {
+                match error {
+                    // The output got pruned after its output id was requested, so it's not unspent anymore
+                    crate::client::Error::NotFound(_) | crate::client::Error::ResponseError { code: 404, .. } => {
+                        log::debug!("[SYNC] output {output_id} not found");
+                    }
+                    error => return Err(error.into()),
+                }
+            }
         }
 
         log::debug!(
@@ -118,6 +133,7 @@ impl Account {
     // Try to get transactions and inputs for received outputs
     // Because the transactions and outputs are pruned, we might can not get them anymore, in that case errors are not
     // returned
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip_all))]
     pub(crate) async fn request_incoming_transaction_data(
         &self,
         transaction_ids: Vec<TransactionId>,
@@ -130,9 +146,15 @@ impl Account {
             let account_details = self.read().await;
 
             for transaction_id in transaction_ids_chunk {
-                // Don't request known or inaccessible transactions again
+                // Don't request known or inaccessible transactions again, pending incoming transactions from other
+                // accounts of the wallet are requested once their outputs are received
                 if account_details.transactions.contains_key(&transaction_id)
-                    || account_details.incoming_transactions.contains_key(&transaction_id)
+                    || account_details
+                        .incoming_transactions
+                        .get(&transaction_id)
+                        .map_or(false, |transaction| {
+                            transaction.inclusion_state != InclusionState::Pending
+                        })
                     || account_details
                         .inaccessible_incoming_transactions
                         .contains(&transaction_id)
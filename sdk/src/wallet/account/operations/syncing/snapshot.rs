@@ -0,0 +1,215 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::{hash_map::Entry, HashMap};
+
+use crypto::keys::slip10::Chain;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    types::{
+        api::core::response::OutputWithMetadataResponse,
+        block::{
+            address::{Address, AliasAddress, NftAddress},
+            output::{Output, OutputMetadata, OutputWithMetadata},
+        },
+    },
+    wallet::account::{
+        types::{address::AccountAddress, AddressWithUnspentOutputs, OutputData},
+        Account,
+    },
+};
+
+/// Unspent outputs of an account, exported from a trusted wallet instance or a node dump, to warm-start the sync of a
+/// new wallet instance, see [`Account::import_utxo_snapshot()`].
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UtxoSnapshot {
+    /// The network id of the outputs.
+    pub network_id: String,
+    /// The ledger index at which the snapshot was taken.
+    pub ledger_index: u32,
+    /// The unspent outputs with their metadata, in the format of the node API.
+    pub outputs: Vec<OutputWithMetadataResponse>,
+}
+
+impl UtxoSnapshot {
+    /// Reads a snapshot from a JSON file.
+    #[cfg(not(target_family = "wasm"))]
+    pub fn read_from_file(path: impl AsRef<std::path::Path>) -> crate::wallet::Result<Self> {
+        Ok(serde_json::from_slice(&std::fs::read(path)?)?)
+    }
+
+    /// Writes the snapshot to a JSON file.
+    #[cfg(not(target_family = "wasm"))]
+    pub fn write_to_file(&self, path: impl AsRef<std::path::Path>) -> crate::wallet::Result<()> {
+        Ok(std::fs::write(path, serde_json::to_vec(self)?)?)
+    }
+}
+
+impl Account {
+    /// Exports the unspent outputs of the account as of the last sync, so another wallet instance with the same
+    /// mnemonic can import them with [`Account::import_utxo_snapshot()`].
+    pub async fn utxo_snapshot(&self) -> crate::wallet::Result<UtxoSnapshot> {
+        let network_id = self.client.get_network_id().await?;
+        let account_details = self.read().await;
+
+        let ledger_index = account_details
+            .unspent_outputs
+            .values()
+            .map(|output_data| output_data.metadata.ledger_index())
+            .max()
+            .unwrap_or_default();
+        let outputs = account_details
+            .unspent_outputs
+            .values()
+            .map(|output_data| {
+                OutputWithMetadataResponse::from(&OutputWithMetadata::new(
+                    output_data.output.clone(),
+                    output_data.metadata.clone(),
+                ))
+            })
+            .collect();
+
+        Ok(UtxoSnapshot {
+            network_id: network_id.to_string(),
+            ledger_index,
+            outputs,
+        })
+    }
+
+    /// Imports the unspent outputs of a trusted snapshot, so the next sync only requests the outputs that were created
+    /// after the snapshot, instead of all outputs. Imported outputs that got spent in the meantime are detected by the
+    /// sync like any other spent output, so it should be called right after the import. Only outputs owned by
+    /// generated addresses of the account, or by alias and NFT outputs owned by them, are imported; the addresses need
+    /// to be generated before. Returns the number of imported outputs.
+    pub async fn import_utxo_snapshot(&self, snapshot: &UtxoSnapshot) -> crate::wallet::Result<usize> {
+        log::debug!("[SYNC] import_utxo_snapshot");
+        let network_id = self.client.get_network_id().await?;
+        if snapshot.network_id != network_id.to_string() {
+            return Err(crate::wallet::Error::InvalidUtxoSnapshot(format!(
+                "network id {} doesn't match the network id {network_id} of the client",
+                snapshot.network_id
+            )));
+        }
+        let token_supply = self.client.get_token_supply().await?;
+
+        let mut outputs = Vec::with_capacity(snapshot.outputs.len());
+        for output_response in &snapshot.outputs {
+            let metadata = OutputMetadata::try_from(&output_response.metadata)?;
+            if !metadata.is_spent() {
+                outputs.push((Output::try_from_dto(&output_response.output, token_supply)?, metadata));
+            }
+        }
+
+        let mut account_details = self.write().await;
+        let mut owners: HashMap<Address, AccountAddress> = account_details
+            .public_addresses
+            .iter()
+            .chain(account_details.internal_addresses.iter())
+            .map(|address| (address.address.inner, address.clone()))
+            .collect();
+
+        // Outputs can be owned by aliases and NFTs of the account, so owners are resolved until no new one is found
+        let mut owned_outputs = Vec::new();
+        loop {
+            let mut unresolved_outputs = Vec::new();
+            for (output, metadata) in outputs {
+                let owner = owner_addresses(&output).find_map(|address| owners.get(address)).cloned();
+                match owner {
+                    Some(owner) => owned_outputs.push((output, metadata, owner)),
+                    None => unresolved_outputs.push((output, metadata)),
+                }
+            }
+            outputs = unresolved_outputs;
+
+            let mut new_owner = false;
+            for (output, metadata, owner) in &owned_outputs {
+                let chain_address = match output {
+                    Output::Alias(alias_output) => {
+                        Address::Alias(AliasAddress::from(alias_output.alias_id_non_null(metadata.output_id())))
+                    }
+                    Output::Nft(nft_output) => {
+                        Address::Nft(NftAddress::from(nft_output.nft_id_non_null(metadata.output_id())))
+                    }
+                    _ => continue,
+                };
+                if let Entry::Vacant(entry) = owners.entry(chain_address) {
+                    entry.insert(owner.clone());
+                    new_owner = true;
+                }
+            }
+            if !new_owner || outputs.is_empty() {
+                break;
+            }
+        }
+        log::debug!("[SYNC] {} outputs of the snapshot are not owned by the account", outputs.len());
+
+        let mut imported = 0;
+        for (output, metadata, owner) in owned_outputs {
+            let output_id = *metadata.output_id();
+            if account_details.outputs.contains_key(&output_id) {
+                continue;
+            }
+
+            let remainder = account_details
+                .transactions
+                .get(metadata.transaction_id())
+                .map_or(false, |tx| !tx.incoming);
+            // 44 is for BIP 44 (HD wallets) and 4218 is the registered index for IOTA https://github.com/satoshilabs/slips/blob/master/slip-0044.md
+            let chain = Chain::from_u32_hardened(vec![
+                44,
+                account_details.coin_type,
+                account_details.index,
+                owner.internal as u32,
+                owner.key_index,
+            ]);
+            let output_data = OutputData {
+                output_id,
+                metadata,
+                output,
+                is_spent: false,
+                address: owner.address.inner,
+                network_id,
+                remainder,
+                chain: Some(chain),
+            };
+
+            let addresses_with_unspent_outputs = &mut account_details.addresses_with_unspent_outputs;
+            match addresses_with_unspent_outputs
+                .iter_mut()
+                .find(|address| address.address == owner.address)
+            {
+                Some(address) => address.output_ids.push(output_id),
+                None => addresses_with_unspent_outputs.push(AddressWithUnspentOutputs {
+                    address: owner.address.clone(),
+                    key_index: owner.key_index,
+                    internal: owner.internal,
+                    output_ids: vec![output_id],
+                }),
+            }
+            account_details.outputs.insert(output_id, output_data.clone());
+            account_details.unspent_outputs.insert(output_id, output_data);
+            imported += 1;
+        }
+        log::debug!("[SYNC] imported {imported} outputs of the snapshot");
+
+        #[cfg(feature = "storage")]
+        self.save(Some(&account_details)).await?;
+
+        Ok(imported)
+    }
+}
+
+// The addresses that can own an output, an alias output is owned by its state controller
+pub(crate) fn owner_addresses(output: &Output) -> impl Iterator<Item = &Address> {
+    output.unlock_conditions().into_iter().flat_map(|unlock_conditions| {
+        [
+            unlock_conditions.address().map(|uc| uc.address()),
+            unlock_conditions.state_controller_address().map(|uc| uc.address()),
+            unlock_conditions.governor_address().map(|uc| uc.address()),
+        ]
+        .into_iter()
+        .flatten()
+    })
+}
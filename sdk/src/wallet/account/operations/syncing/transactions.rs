@@ -26,12 +26,13 @@ impl Account {
     /// output ids that don't need to be locked anymore
     /// Return true if a transaction got confirmed for which we don't have an output already, based on this outputs will
     /// be synced again
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip_all))]
     pub(crate) async fn sync_pending_transactions(&self) -> crate::wallet::Result<bool> {
         log::debug!("[SYNC] sync pending transactions");
         let account_details = self.read().await;
 
         // only set to true if a transaction got confirmed for which we don't have an output
-        // (transaction_output.is_none())
+        // (confirmed_output_data.is_none())
         let mut confirmed_unknown_output = false;
 
         if account_details.pending_transactions.is_empty() {
@@ -52,8 +53,7 @@ impl Account {
             let transaction = account_details
                 .transactions
                 .get(transaction_id)
-                // panic during development to easier detect if something is wrong, should be handled different later
-                .expect("transaction id stored, but transaction is missing")
+                .ok_or(crate::wallet::Error::TransactionNotFound(*transaction_id))?
                 .clone();
 
             // only check transaction from the network we're connected to
@@ -63,14 +63,12 @@ impl Account {
 
             // check if we have an output (remainder, if not sending to an own address) that got created by this
             // transaction, if that's the case, then the transaction got confirmed
-            let transaction_output = account_details
+            let confirmed_output_data = account_details
                 .outputs
-                .keys()
-                .find(|o| o.transaction_id() == transaction_id);
+                .values()
+                .find(|o| o.output_id.transaction_id() == transaction_id);
 
-            if let Some(transaction_output) = transaction_output {
-                // Save to unwrap, we just got the output
-                let confirmed_output_data = account_details.outputs.get(transaction_output).expect("output exists");
+            if let Some(confirmed_output_data) = confirmed_output_data {
                 log::debug!(
                     "[SYNC] confirmed transaction {transaction_id} in block {}",
                     confirmed_output_data.metadata.block_id()
@@ -78,6 +76,7 @@ impl Account {
                 updated_transaction_and_outputs(
                     transaction,
                     Some(*confirmed_output_data.metadata.block_id()),
+                    Some(confirmed_output_data.metadata.milestone_index_booked()),
                     InclusionState::Confirmed,
                     &mut updated_transactions,
                     &mut spent_output_ids,
@@ -112,6 +111,7 @@ impl Account {
                                     updated_transaction_and_outputs(
                                         transaction,
                                         Some(BlockId::from_str(&metadata.block_id)?),
+                                        metadata.referenced_by_milestone_index,
                                         InclusionState::Confirmed,
                                         &mut updated_transactions,
                                         &mut spent_output_ids,
@@ -120,13 +120,14 @@ impl Account {
                                 LedgerInclusionStateDto::Conflicting => {
                                     // try to get the included block, because maybe only this attachment is
                                     // conflicting because it got confirmed in another block
-                                    if let Ok(included_block) =
-                                        self.client.get_included_block(&transaction.payload.id()).await
+                                    if let Ok(included_block_metadata) =
+                                        self.client.get_included_block_metadata(&transaction.payload.id()).await
                                     {
                                         confirmed_unknown_output = true;
                                         updated_transaction_and_outputs(
                                             transaction,
-                                            Some(included_block.id()),
+                                            Some(BlockId::from_str(&included_block_metadata.block_id)?),
+                                            included_block_metadata.referenced_by_milestone_index,
                                             // block metadata was Conflicting, but it's confirmed in another attachment
                                             InclusionState::Confirmed,
                                             &mut updated_transactions,
@@ -137,6 +138,7 @@ impl Account {
                                         updated_transaction_and_outputs(
                                             transaction,
                                             None,
+                                            None,
                                             InclusionState::Conflicting,
                                             &mut updated_transactions,
                                             &mut spent_output_ids,
@@ -198,13 +200,67 @@ impl Account {
                 }
             }
         }
+
+        // Transactions that spend outputs of a conflicting transaction can't get confirmed anymore
+        let conflicting_transaction_ids = updated_transactions
+            .iter()
+            .filter(|transaction| transaction.inclusion_state == InclusionState::Conflicting)
+            .map(|transaction| transaction.transaction_id)
+            .collect::<Vec<_>>();
+        for transaction_id in conflicting_transaction_ids {
+            for child_id in account_details.pending_child_transactions(&transaction_id) {
+                if updated_transactions.iter().any(|t| t.transaction_id == child_id) {
+                    continue;
+                }
+                let Some(child) = account_details.transactions.get(&child_id) else {
+                    continue;
+                };
+                log::debug!("[SYNC] conflicting transaction {child_id}, spends outputs of {transaction_id}");
+                transactions_to_reattach.retain(|t| t.transaction_id != child_id);
+                let mut child = child.clone();
+                let TransactionEssence::Regular(essence) = child.payload.essence();
+                for input in essence.inputs() {
+                    if let Input::Utxo(input) = input {
+                        output_ids_to_unlock.push(*input.output_id());
+                    }
+                }
+                child.inclusion_state = InclusionState::Conflicting;
+                updated_transactions.push(child);
+            }
+        }
         drop(account_details);
 
-        for mut transaction in transactions_to_reattach {
-            log::debug!("[SYNC] reattach transaction");
-            let reattached_block = self.submit_transaction_payload(transaction.payload.clone()).await?;
-            transaction.block_id.replace(reattached_block);
-            updated_transactions.push(transaction);
+        // Transactions are reattached after the pending transactions they depend on, so their blocks can be referenced
+        while !transactions_to_reattach.is_empty() {
+            let mut deferred_transactions = Vec::new();
+            let transaction_count = transactions_to_reattach.len();
+
+            for mut transaction in transactions_to_reattach {
+                log::debug!("[SYNC] reattach transaction");
+                let attachment = match self.submit_transaction_payload(transaction.payload.clone()).await {
+                    Ok(attachment) => attachment,
+                    Err(crate::wallet::Error::ParentTransactionNotSubmitted(_)) => {
+                        deferred_transactions.push(transaction);
+                        continue;
+                    }
+                    Err(e) => return Err(e),
+                };
+                transaction.add_attachment(attachment.clone());
+                // Update the block id right away, so it can be referenced by transactions depending on this one
+                self.write()
+                    .await
+                    .transactions
+                    .entry(transaction.transaction_id)
+                    .and_modify(|t| t.add_attachment(attachment));
+                updated_transactions.push(transaction);
+            }
+
+            // Stop if no transaction could be reattached, the parents of the remaining ones weren't submitted
+            if deferred_transactions.len() == transaction_count {
+                log::debug!("[SYNC] couldn't reattach {transaction_count} transactions, parents weren't submitted");
+                break;
+            }
+            transactions_to_reattach = deferred_transactions;
         }
 
         // updates account with balances, output ids, outputs
@@ -215,15 +271,84 @@ impl Account {
     }
 }
 
+impl Account {
+    /// Tries to resolve the inclusion state of transactions with [`InclusionState::UnknownPruned`], which is possible
+    /// if a permanode is configured, because it still has the data that got pruned by the other nodes. Returns the
+    /// transactions with an updated inclusion state.
+    pub async fn resolve_unknown_pruned_transactions(&self) -> crate::wallet::Result<Vec<Transaction>> {
+        log::debug!("[SYNC] resolve unknown pruned transactions");
+        let unknown_pruned_transactions = self
+            .read()
+            .await
+            .transactions
+            .values()
+            .filter(|transaction| transaction.inclusion_state == InclusionState::UnknownPruned)
+            .cloned()
+            .collect::<Vec<_>>();
+
+        let mut updated_transactions = Vec::new();
+        let mut spent_output_ids = Vec::new();
+
+        for transaction in unknown_pruned_transactions {
+            match self
+                .client
+                .get_included_block_metadata(&transaction.transaction_id)
+                .await
+            {
+                Ok(metadata) => {
+                    if metadata.ledger_inclusion_state == Some(LedgerInclusionStateDto::Included) {
+                        log::debug!(
+                            "[SYNC] resolved pruned transaction {} as confirmed in block {}",
+                            transaction.transaction_id,
+                            metadata.block_id
+                        );
+                        updated_transaction_and_outputs(
+                            transaction,
+                            Some(BlockId::from_str(&metadata.block_id)?),
+                            metadata.referenced_by_milestone_index,
+                            InclusionState::Confirmed,
+                            &mut updated_transactions,
+                            &mut spent_output_ids,
+                        );
+                    }
+                }
+                // Still not available, keep the unknown state
+                Err(ClientError::NotFound(_)) => {}
+                Err(e) => return Err(e.into()),
+            }
+        }
+
+        let resolved_transactions = updated_transactions.clone();
+        if !updated_transactions.is_empty() {
+            self.update_account_with_transactions(updated_transactions, spent_output_ids, Vec::new())
+                .await?;
+            // Not called during a sync, so the account has to be saved here
+            #[cfg(feature = "storage")]
+            {
+                log::debug!("[SYNC] storing account {} with resolved transactions", self.alias().await);
+                self.save(None).await?;
+            }
+        }
+
+        Ok(resolved_transactions)
+    }
+}
+
 // Set the outputs as spent so they will not be used as input again
 fn updated_transaction_and_outputs(
     mut transaction: Transaction,
     block_id: Option<BlockId>,
+    milestone_index: Option<u32>,
     inclusion_state: InclusionState,
     updated_transactions: &mut Vec<Transaction>,
     spent_output_ids: &mut Vec<OutputId>,
 ) {
     transaction.block_id = block_id;
+    transaction.milestone_index = milestone_index;
+    // Only a transaction that was pending can be measured, others were already resolved before this sync
+    if transaction.inclusion_state == InclusionState::Pending && inclusion_state == InclusionState::Confirmed {
+        transaction.confirmed_at = Some(unix_timestamp_now().as_millis());
+    }
     transaction.inclusion_state = inclusion_state;
     // get spent inputs
     let TransactionEssence::Regular(essence) = transaction.payload.essence();
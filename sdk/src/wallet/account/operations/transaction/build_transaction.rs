@@ -29,7 +29,7 @@ impl Account {
         log::debug!("[TRANSACTION] build_transaction");
 
         let build_transaction_essence_start_time = Instant::now();
-        let protocol_parameters = self.client.get_protocol_parameters().await?;
+        let protocol_parameters = self.client.get_supported_protocol_parameters().await?;
 
         let mut inputs_for_essence: Vec<Input> = Vec::new();
         let mut inputs_for_signing: Vec<InputSigningData> = Vec::new();
@@ -69,6 +69,8 @@ impl Account {
             remainder: selected_transaction_data.remainder,
         };
 
+        prepared_transaction_data.verify_inputs_commitment()?;
+
         log::debug!(
             "[TRANSACTION] finished build_transaction in {:.2?}",
             build_transaction_essence_start_time.elapsed()
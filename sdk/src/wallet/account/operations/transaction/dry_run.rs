@@ -0,0 +1,196 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    client::api::{PreparedTransactionData, PreparedTransactionDataDto},
+    types::block::{
+        output::{Output, OutputId, RentStructure},
+        payload::transaction::TransactionEssence,
+    },
+    utils::format::{DefaultFormatter, Formatter},
+    wallet::{
+        account::{Account, TransactionOptions},
+        AddressWithAmount,
+    },
+};
+
+/// Summary of a prepared transaction, so it can be reviewed before it's signed and sent.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionSummary {
+    /// The outputs consumed by the transaction.
+    pub inputs: Vec<OutputId>,
+    /// The amount of the consumed outputs.
+    pub consumed_amount: u64,
+    /// The amount of the created outputs, including the remainder.
+    pub created_amount: u64,
+    /// The amount of the created outputs without the remainder.
+    pub sent_amount: u64,
+    /// The amount of the remainder output, 0 if there is none.
+    pub remainder_amount: u64,
+    /// The number of created outputs, including the remainder.
+    pub outputs_count: usize,
+    /// The storage deposit that is required for the consumed outputs.
+    pub consumed_storage_deposit: u64,
+    /// The storage deposit that is required for the created outputs.
+    pub created_storage_deposit: u64,
+    /// The change of the storage deposit, positive if more storage deposit is required after the transaction.
+    pub storage_deposit_delta: i64,
+}
+
+impl TransactionSummary {
+    /// Summarizes a prepared transaction, the storage deposits are computed with the rent structure.
+    pub fn new(prepared_transaction_data: &PreparedTransactionData, rent_structure: &RentStructure) -> Self {
+        let TransactionEssence::Regular(essence) = &prepared_transaction_data.essence;
+
+        let inputs = prepared_transaction_data
+            .inputs_data
+            .iter()
+            .map(|input| *input.output_id())
+            .collect();
+        let consumed_amount = sum_amounts(prepared_transaction_data.inputs_data.iter().map(|input| &input.output));
+        let consumed_storage_deposit = sum_storage_deposits(
+            prepared_transaction_data.inputs_data.iter().map(|input| &input.output),
+            rent_structure,
+        );
+
+        let created_amount = sum_amounts(essence.outputs().iter());
+        let created_storage_deposit = sum_storage_deposits(essence.outputs().iter(), rent_structure);
+        let remainder_amount = prepared_transaction_data
+            .remainder
+            .as_ref()
+            .map_or(0, |remainder| remainder.output.amount());
+
+        Self {
+            inputs,
+            consumed_amount,
+            created_amount,
+            sent_amount: created_amount.saturating_sub(remainder_amount),
+            remainder_amount,
+            outputs_count: essence.outputs().len(),
+            consumed_storage_deposit,
+            created_storage_deposit,
+            // Storage deposits are bound by the token supply, so they fit into an i64
+            storage_deposit_delta: created_storage_deposit as i64 - consumed_storage_deposit as i64,
+        }
+    }
+
+    /// Describes the summary in plain words, with the amounts formatted by the formatter, e.g. for the locale of the
+    /// user. The [`Display`](fmt::Display) implementation uses the [`DefaultFormatter`], so amounts are in base units.
+    pub fn to_string_with(&self, formatter: &dyn Formatter) -> String {
+        let storage_deposit_sign = if self.storage_deposit_delta < 0 { '-' } else { '+' };
+
+        format!(
+            "consumes {} input(s) with {}, creates {} output(s) with {} ({} sent, {} remainder), storage deposit delta \
+             {}{}",
+            self.inputs.len(),
+            formatter.format_amount(self.consumed_amount),
+            self.outputs_count,
+            formatter.format_amount(self.created_amount),
+            formatter.format_amount(self.sent_amount),
+            formatter.format_amount(self.remainder_amount),
+            storage_deposit_sign,
+            formatter.format_amount(self.storage_deposit_delta.unsigned_abs()),
+        )
+    }
+}
+
+impl fmt::Display for TransactionSummary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.to_string_with(&DefaultFormatter::default()))
+    }
+}
+
+fn sum_amounts<'a>(outputs: impl Iterator<Item = &'a Output>) -> u64 {
+    outputs.fold(0u64, |sum, output| sum.saturating_add(output.amount()))
+}
+
+fn sum_storage_deposits<'a>(outputs: impl Iterator<Item = &'a Output>, rent_structure: &RentStructure) -> u64 {
+    outputs.fold(0u64, |sum, output| sum.saturating_add(output.rent_cost(rent_structure)))
+}
+
+/// A fully built, but unsigned and unsubmitted transaction with its summary.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TransactionDryRun {
+    /// The prepared transaction, it can still be signed and sent with
+    /// [`Account::sign_and_submit_transaction()`].
+    pub prepared_transaction_data: PreparedTransactionData,
+    /// The summary of the prepared transaction.
+    pub summary: TransactionSummary,
+}
+
+/// Dto for [`TransactionDryRun`].
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionDryRunDto {
+    /// The prepared transaction.
+    pub prepared_transaction_data: PreparedTransactionDataDto,
+    /// The summary of the prepared transaction.
+    pub summary: TransactionSummary,
+}
+
+impl From<&TransactionDryRun> for TransactionDryRunDto {
+    fn from(value: &TransactionDryRun) -> Self {
+        Self {
+            prepared_transaction_data: PreparedTransactionDataDto::from(&value.prepared_transaction_data),
+            summary: value.summary.clone(),
+        }
+    }
+}
+
+impl Account {
+    /// Summarizes a prepared transaction, e.g. the one returned by [`Account::prepare_send_amount()`].
+    pub async fn summarize_transaction(
+        &self,
+        prepared_transaction_data: &PreparedTransactionData,
+    ) -> crate::wallet::Result<TransactionSummary> {
+        let rent_structure = self.client.get_rent_structure().await?;
+
+        Ok(TransactionSummary::new(prepared_transaction_data, &rent_structure))
+    }
+
+    /// Does input selection and builds the transaction for the outputs like [`Account::prepare_transaction()`], but
+    /// doesn't keep the inputs locked, so they are still available for other transactions. Nothing gets signed or
+    /// sent.
+    pub async fn dry_run_transaction(
+        &self,
+        outputs: Vec<Output>,
+        options: impl Into<Option<TransactionOptions>> + Send,
+    ) -> crate::wallet::Result<TransactionDryRun> {
+        log::debug!("[TRANSACTION] dry_run_transaction");
+        let prepared_transaction_data = self.prepare_transaction(outputs, options).await?;
+
+        self.finish_dry_run(prepared_transaction_data).await
+    }
+
+    /// Like [`Account::prepare_send_amount()`], but the inputs don't stay locked, so it can be used for a review
+    /// before the amount gets sent.
+    pub async fn dry_run_send_amount(
+        &self,
+        addresses_with_amount: Vec<AddressWithAmount>,
+        options: impl Into<Option<TransactionOptions>> + Send,
+    ) -> crate::wallet::Result<TransactionDryRun> {
+        log::debug!("[TRANSACTION] dry_run_send_amount");
+        let prepared_transaction_data = self.prepare_send_amount(addresses_with_amount, options).await?;
+
+        self.finish_dry_run(prepared_transaction_data).await
+    }
+
+    async fn finish_dry_run(
+        &self,
+        prepared_transaction_data: PreparedTransactionData,
+    ) -> crate::wallet::Result<TransactionDryRun> {
+        // Unlock the inputs first, so they are also released if the summary fails
+        self.unlock_inputs(&prepared_transaction_data.inputs_data).await?;
+        let summary = self.summarize_transaction(&prepared_transaction_data).await?;
+
+        Ok(TransactionDryRun {
+            prepared_transaction_data,
+            summary,
+        })
+    }
+}
@@ -4,9 +4,7 @@
 use primitive_types::U256;
 
 use crate::{
-    types::block::output::{
-        AliasOutputBuilder, FoundryId, FoundryOutputBuilder, Output, SimpleTokenScheme, TokenId, TokenScheme,
-    },
+    types::block::output::{AliasOutputBuilder, FoundryId, FoundryOutputBuilder, Output, TokenId, TokenScheme},
     wallet::account::{operations::transaction::Transaction, Account, TransactionOptions},
 };
 
@@ -45,11 +43,7 @@ impl Account {
             let outputs = vec![
                 alias_output,
                 FoundryOutputBuilder::from(&existing_foundry_output)
-                    .with_token_scheme(TokenScheme::Simple(SimpleTokenScheme::new(
-                        token_scheme.minted_tokens(),
-                        token_scheme.melted_tokens() + melt_amount,
-                        token_scheme.maximum_supply(),
-                    )?))
+                    .with_token_scheme(TokenScheme::Simple(token_scheme.melt(melt_amount)?))
                     .finish_output(token_supply)?,
             ];
             // Input selection will detect that we're melting native tokens and add the required inputs if available
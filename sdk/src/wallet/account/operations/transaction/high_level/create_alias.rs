@@ -6,7 +6,6 @@ use serde::{Deserialize, Serialize};
 use crate::{
     client::api::PreparedTransactionData,
     types::block::{
-        address::Address,
         output::{
             feature::MetadataFeature,
             unlock_condition::{GovernorAddressUnlockCondition, StateControllerAddressUnlockCondition},
@@ -112,11 +111,7 @@ impl Account {
             .as_ref()
             .and_then(|options| options.address.as_ref())
         {
-            Some(bech32_address) => {
-                let (bech32_hrp, address) = Address::try_from_bech32_with_hrp(bech32_address)?;
-                self.client.bech32_hrp_matches(&bech32_hrp).await?;
-                address
-            }
+            Some(bech32_address) => self.address_from_bech32(bech32_address).await?,
             None => {
                 self.public_addresses()
                     .await
@@ -0,0 +1,116 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::cmp::Reverse;
+
+use crate::{
+    types::block::output::{
+        unlock_condition::AddressUnlockCondition, BasicOutputBuilder, NativeTokens, NativeTokensBuilder, Output,
+    },
+    wallet::account::{
+        operations::{
+            helpers::time::{can_output_be_unlocked_forever_from_now_on, can_output_be_unlocked_now},
+            output_claiming::get_new_native_token_count,
+        },
+        Account,
+    },
+};
+
+impl Account {
+    /// Returns the largest amount that can be sent to the address with
+    /// [`Account::send_amount()`](crate::wallet::Account::send_amount) in a single transaction, e.g. for a "send max"
+    /// button. Only the basic outputs that input selection uses are counted, so locked, frozen and time locked outputs,
+    /// outputs with a storage deposit return or expiration that didn't expire yet and the voting output are left out.
+    /// The storage deposit that the remainder needs for the native tokens of the inputs stays in the account. Returns
+    /// 0 if the amount doesn't cover the storage deposit of an output to the address.
+    /// ```ignore
+    /// let amount = account
+    ///     .max_sendable_amount("rms1qpszqzadsym6wpppd6z037dvlejmjuke7s24hm95s9fg9vpua7vluaw60xu")
+    ///     .await?;
+    /// ```
+    pub async fn max_sendable_amount(&self, address: &str) -> crate::wallet::Result<u64> {
+        log::debug!("[TRANSACTION] max_sendable_amount");
+        let address = self.address_from_bech32(address).await?;
+
+        #[cfg(feature = "participation")]
+        let voting_output = self.get_voting_output().await?;
+        let current_time = self.client.get_time_checked().await?;
+        let network_id = self.client.get_network_id().await?;
+        let rent_structure = self.client.get_rent_structure().await?;
+        let token_supply = self.client.get_token_supply().await?;
+        let max_inputs = self.max_inputs_per_transaction().await as usize;
+
+        // Read from the snapshot, so a running sync or transaction doesn't block the calculation
+        let account_details = self.snapshot();
+        let account_addresses = &account_details.addresses_with_unspent_outputs[..];
+        let frozen_outputs = account_details.frozen_output_ids();
+
+        let mut inputs = Vec::new();
+        for (output_id, output_data) in account_details.unspent_outputs() {
+            #[cfg(feature = "participation")]
+            if let Some(ref voting_output) = voting_output {
+                // Sending the voting output would reduce the voting power
+                if output_data.output_id == voting_output.output_id {
+                    continue;
+                }
+            }
+            let output = &output_data.output;
+            let Output::Basic(basic_output) = output else {
+                continue;
+            };
+            let unlock_conditions = basic_output.unlock_conditions();
+            if output_data.network_id != network_id
+                || account_details.locked_outputs.contains(output_id)
+                || frozen_outputs.contains(output_id)
+                // The storage deposit would need to be returned, unless the output expired
+                || (unlock_conditions.storage_deposit_return().is_some() && !unlock_conditions.is_expired(current_time))
+                // Outputs controlled by an alias or nft would need it as additional input
+                || !can_output_be_unlocked_forever_from_now_on(account_addresses, &[], output, current_time)
+                || !can_output_be_unlocked_now(account_addresses, &[], output_data, current_time, None)?
+            {
+                continue;
+            }
+            inputs.push(basic_output);
+        }
+
+        // The largest outputs are used if there are more than fit into a transaction
+        inputs.sort_by_key(|input| Reverse(input.amount()));
+
+        let mut amount = 0;
+        let mut input_count = 0;
+        let mut native_tokens = NativeTokensBuilder::new();
+        for input in inputs {
+            if input_count == max_inputs {
+                break;
+            }
+            // The native tokens need to fit into a single remainder
+            if get_new_native_token_count(&native_tokens, input.native_tokens())? > NativeTokens::COUNT_MAX.into() {
+                continue;
+            }
+            native_tokens.add_native_tokens(input.native_tokens().clone())?;
+            amount += input.amount();
+            input_count += 1;
+        }
+
+        let native_tokens = native_tokens.finish()?;
+        if !native_tokens.is_empty() {
+            let remainder_address = *account_details
+                .public_addresses()
+                .first()
+                .expect("account needs to have a public address")
+                .address()
+                .inner();
+            let remainder = BasicOutputBuilder::new_with_minimum_storage_deposit(rent_structure)
+                .add_unlock_condition(AddressUnlockCondition::new(remainder_address))
+                .with_native_tokens(native_tokens)
+                .finish_output(token_supply)?;
+            amount = amount.saturating_sub(remainder.amount());
+        }
+
+        let minimum_output = BasicOutputBuilder::new_with_minimum_storage_deposit(rent_structure)
+            .add_unlock_condition(AddressUnlockCondition::new(address))
+            .finish_output(token_supply)?;
+
+        Ok(if amount < minimum_output.amount() { 0 } else { amount })
+    }
+}
@@ -10,7 +10,7 @@ use crate::{
             unlock_condition::{
                 AddressUnlockCondition, ExpirationUnlockCondition, StorageDepositReturnUnlockCondition,
             },
-            BasicOutputBuilder, NativeToken, Output, Rent, RentStructure, TokenId,
+            BasicOutputBuilder, NativeToken, Output, RentStructure, TokenId,
         },
     },
     wallet::Result,
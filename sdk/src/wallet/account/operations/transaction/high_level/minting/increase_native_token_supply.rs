@@ -5,7 +5,7 @@ use primitive_types::U256;
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    types::block::output::{AliasOutputBuilder, FoundryOutputBuilder, Output, SimpleTokenScheme, TokenId, TokenScheme},
+    types::block::output::{AliasOutputBuilder, FoundryOutputBuilder, Output, TokenId, TokenScheme},
     wallet::{
         account::{
             operations::transaction::high_level::minting::mint_native_token::MintTokenTransaction, Account,
@@ -115,11 +115,7 @@ impl Account {
 
         let TokenScheme::Simple(token_scheme) = foundry_output.token_scheme();
 
-        let updated_token_scheme = TokenScheme::Simple(SimpleTokenScheme::new(
-            token_scheme.minted_tokens() + mint_amount,
-            token_scheme.melted_tokens(),
-            token_scheme.maximum_supply(),
-        )?);
+        let updated_token_scheme = TokenScheme::Simple(token_scheme.mint(mint_amount)?);
 
         let new_foundry_output_builder =
             FoundryOutputBuilder::from(&foundry_output).with_token_scheme(updated_token_scheme);
@@ -6,7 +6,6 @@ use serde::{Deserialize, Serialize};
 use crate::{
     client::api::PreparedTransactionData,
     types::block::{
-        address::Address,
         output::{
             feature::{IssuerFeature, MetadataFeature, SenderFeature, TagFeature},
             unlock_condition::AddressUnlockCondition,
@@ -134,11 +133,7 @@ impl Account {
 
         for nft_options in nfts_options {
             let address = match nft_options.address {
-                Some(address) => {
-                    let (bech32_hrp, address) = Address::try_from_bech32_with_hrp(address)?;
-                    self.client.bech32_hrp_matches(&bech32_hrp).await?;
-                    address
-                }
+                Some(address) => self.address_from_bech32(address).await?,
                 // todo other error message
                 None => {
                     account_addresses
@@ -155,7 +150,7 @@ impl Account {
                 .add_unlock_condition(AddressUnlockCondition::new(address));
 
             if let Some(sender) = nft_options.sender {
-                nft_builder = nft_builder.add_feature(SenderFeature::new(Address::try_from_bech32(sender)?));
+                nft_builder = nft_builder.add_feature(SenderFeature::new(self.address_from_bech32(sender).await?));
             }
 
             if let Some(metadata) = nft_options.metadata {
@@ -167,7 +162,8 @@ impl Account {
             }
 
             if let Some(issuer) = nft_options.issuer {
-                nft_builder = nft_builder.add_immutable_feature(IssuerFeature::new(Address::try_from_bech32(issuer)?));
+                nft_builder =
+                    nft_builder.add_immutable_feature(IssuerFeature::new(self.address_from_bech32(issuer).await?));
             }
 
             if let Some(immutable_metadata) = nft_options.immutable_metadata {
@@ -3,8 +3,13 @@
 
 pub(crate) mod burning_melting;
 pub(crate) mod create_alias;
+pub(crate) mod max_sendable_amount;
 pub(crate) mod minimum_storage_deposit;
 pub(crate) mod minting;
+pub(crate) mod nft_state_log;
+pub(crate) mod payment_uri;
+pub(crate) mod send_all;
 pub(crate) mod send_amount;
 pub(crate) mod send_native_tokens;
 pub(crate) mod send_nft;
+pub(crate) mod send_to_l2;
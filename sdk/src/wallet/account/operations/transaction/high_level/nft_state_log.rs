@@ -0,0 +1,81 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{
+    client::api::PreparedTransactionData,
+    types::block::output::{
+        feature::{MetadataFeature, StateUpdate},
+        NftId, NftOutputBuilder,
+    },
+    wallet::account::{operations::transaction::Transaction, Account, TransactionOptions},
+};
+
+impl Account {
+    /// Appends an update with the state to the [`StateLog`](crate::types::block::output::feature::StateLog) in the
+    /// mutable metadata of an NFT of the account, by sending the NFT to itself. A log is started if the NFT has no
+    /// metadata yet, other metadata isn't replaced. The amount of the NFT is increased if the longer log requires a
+    /// higher storage deposit.
+    /// ```ignore
+    /// let transaction = account
+    ///     .append_nft_state(nft_id, serde_json::json!({ "location": "warehouse" }), None)
+    ///     .await?;
+    /// ```
+    pub async fn append_nft_state(
+        &self,
+        nft_id: NftId,
+        state: serde_json::Value,
+        options: impl Into<Option<TransactionOptions>> + Send,
+    ) -> crate::wallet::Result<Transaction> {
+        let prepared_transaction = self.prepare_append_nft_state(nft_id, state, options).await?;
+        self.sign_and_submit_transaction(prepared_transaction).await
+    }
+
+    /// Function to prepare the transaction for
+    /// [Account.append_nft_state()](crate::account::Account.append_nft_state)
+    async fn prepare_append_nft_state(
+        &self,
+        nft_id: NftId,
+        state: serde_json::Value,
+        options: impl Into<Option<TransactionOptions>> + Send,
+    ) -> crate::wallet::Result<PreparedTransactionData> {
+        log::debug!("[TRANSACTION] prepare_append_nft_state");
+
+        let output_data = self
+            .unspent_nft_output(&nft_id)
+            .await?
+            .ok_or(crate::wallet::Error::NftNotFoundInUnspentOutputs)?;
+        let nft_output = output_data.output.as_nft();
+        let mut state_log = nft_output.state_log()?.unwrap_or_default();
+        state_log.append(state, crate::utils::unix_timestamp_now().as_secs())?;
+
+        let protocol_parameters = self.client.get_protocol_parameters().await?;
+        let nft_builder = NftOutputBuilder::from(nft_output)
+            .with_nft_id(nft_id)
+            .replace_feature(MetadataFeature::try_from(&state_log)?);
+        let mut output = nft_builder.clone().finish_output(protocol_parameters.token_supply())?;
+        let required_amount = output.rent_cost(protocol_parameters.rent_structure());
+        if output.amount() < required_amount {
+            output = nft_builder
+                .with_amount(required_amount)
+                .finish_output(protocol_parameters.token_supply())?;
+        }
+
+        self.prepare_transaction(vec![output], options).await
+    }
+
+    /// Returns the state updates in the mutable metadata of an NFT of the account, from the oldest to the latest one.
+    /// The chain of the updates is verified, the history is empty if the NFT has no metadata.
+    pub async fn nft_state_history(&self, nft_id: NftId) -> crate::wallet::Result<Vec<StateUpdate>> {
+        let output_data = self
+            .unspent_nft_output(&nft_id)
+            .await?
+            .ok_or(crate::wallet::Error::NftNotFoundInUnspentOutputs)?;
+
+        Ok(output_data
+            .output
+            .as_nft()
+            .state_log()?
+            .map(|state_log| state_log.updates)
+            .unwrap_or_default())
+    }
+}
@@ -0,0 +1,60 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use core::str::FromStr;
+
+use crate::{
+    client::api::PreparedTransactionData,
+    utils::{
+        payment_uri::{PaymentUri, PaymentUriError},
+        unix_timestamp_now,
+    },
+    wallet::{
+        account::{Account, Features, OutputOptions, TransactionOptions},
+        Error,
+    },
+};
+
+impl Account {
+    /// Parses a payment URI, e.g. scanned from a QR code, and prepares a transaction that pays it, so it can be reviewed
+    /// before it gets signed and submitted with [`Account::sign_and_submit_transaction()`]. The tag of the URI is added
+    /// as tag feature and the note as metadata feature. Fails if the address is for another network, the request
+    /// expired or it has no amount.
+    pub async fn prepare_payment_uri(
+        &self,
+        uri: &str,
+        options: impl Into<Option<TransactionOptions>> + Send,
+    ) -> crate::wallet::Result<PreparedTransactionData> {
+        log::debug!("[TRANSACTION] prepare_payment_uri {uri}");
+        let payment_uri = PaymentUri::from_str(uri)?;
+
+        if let Some(expiration) = payment_uri.expiration {
+            if payment_uri.is_expired(unix_timestamp_now().as_secs() as u32) {
+                return Err(PaymentUriError::Expired(expiration).into());
+            }
+        }
+        let amount = payment_uri.amount.ok_or(Error::MissingParameter("amount"))?;
+
+        let options = options.into();
+        let output = self
+            .prepare_output(
+                OutputOptions {
+                    recipient_address: payment_uri.address.to_string(),
+                    amount,
+                    assets: None,
+                    features: Some(Features {
+                        tag: payment_uri.tag.map(|tag| prefix_hex::encode(tag.as_bytes())),
+                        metadata: payment_uri.note.map(|note| prefix_hex::encode(note.as_bytes())),
+                        issuer: None,
+                        sender: None,
+                    }),
+                    unlocks: None,
+                    storage_deposit: None,
+                },
+                options.clone(),
+            )
+            .await?;
+
+        self.prepare_transaction(vec![output], options).await
+    }
+}
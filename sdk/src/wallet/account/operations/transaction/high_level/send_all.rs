@@ -0,0 +1,221 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "events")]
+use crate::wallet::events::types::{SendAllProgress, TransactionProgressEvent, WalletEvent};
+use crate::{
+    types::block::{
+        address::Address,
+        output::{
+            unlock_condition::AddressUnlockCondition, BasicOutputBuilder, NativeTokens, NativeTokensBuilder,
+            NftOutputBuilder, Output, OutputId, OUTPUT_COUNT_MAX,
+        },
+    },
+    wallet::account::{
+        operations::{helpers::time::can_output_be_unlocked_now, output_claiming::get_new_native_token_count},
+        types::{OutputData, Transaction},
+        Account, AddressWithUnspentOutputs, TransactionOptions,
+    },
+};
+
+/// Options for [`Account::send_all()`].
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SendAllOptions {
+    /// Also send the NFT outputs of the account, otherwise only basic outputs are sent.
+    #[serde(default)]
+    pub include_nfts: bool,
+    /// The maximum number of inputs per transaction, by default the maximum that the protocol and the secret manager
+    /// allow.
+    #[serde(default)]
+    pub max_inputs: Option<u16>,
+    /// Optional note for the transactions, that is only stored locally.
+    #[serde(default)]
+    pub note: Option<String>,
+}
+
+// The inputs and outputs of one transaction of `send_all()`
+#[derive(Default)]
+struct Chunk {
+    inputs: Vec<OutputId>,
+    amount: u64,
+    native_tokens: NativeTokensBuilder,
+    nft_outputs: Vec<Output>,
+}
+
+impl Account {
+    /// Sends all basic outputs of the account, optionally also the NFT outputs, to a single address, e.g. to empty an
+    /// account before it's decommissioned. Native tokens in the outputs are sent with them. If there are more outputs
+    /// than fit into a single transaction, multiple transactions are sent, with `events` enabled a
+    /// [`TransactionProgressEvent::SendAll`](crate::wallet::events::types::TransactionProgressEvent::SendAll) event is
+    /// emitted before each of them. Locked, frozen and time locked outputs, outputs with a storage deposit return that
+    /// didn't expire yet and the voting output are not sent.
+    /// ```ignore
+    /// let transactions = account
+    ///     .send_all("rms1qpszqzadsym6wpppd6z037dvlejmjuke7s24hm95s9fg9vpua7vluaw60xu", None)
+    ///     .await?;
+    /// ```
+    pub async fn send_all(
+        &self,
+        address: &str,
+        options: impl Into<Option<SendAllOptions>> + Send,
+    ) -> crate::wallet::Result<Vec<Transaction>> {
+        log::debug!("[TRANSACTION] send_all");
+        let options = options.into().unwrap_or_default();
+
+        let address = self.address_from_bech32(address).await?;
+
+        let token_supply = self.client.get_token_supply().await?;
+        let chunks = self.send_all_chunks(&address, &options).await?;
+        if chunks.is_empty() {
+            return Err(crate::wallet::Error::NoOutputsToSend);
+        }
+
+        let transaction_count = chunks.len();
+        let mut transactions = Vec::with_capacity(transaction_count);
+        for (index, chunk) in chunks.into_iter().enumerate() {
+            log::debug!("[TRANSACTION] send_all transaction {}/{transaction_count}", index + 1);
+            #[cfg(feature = "events")]
+            {
+                let account_index = *self.read().await.index();
+                self.event_emitter.lock().await.emit(
+                    account_index,
+                    WalletEvent::TransactionProgress(TransactionProgressEvent::SendAll(SendAllProgress {
+                        transaction: index + 1,
+                        transaction_count,
+                    })),
+                );
+            }
+
+            let mut outputs = chunk.nft_outputs;
+            if chunk.amount > 0 {
+                outputs.push(
+                    BasicOutputBuilder::new_with_amount(chunk.amount)
+                        .add_unlock_condition(AddressUnlockCondition::new(address))
+                        .with_native_tokens(chunk.native_tokens.finish()?)
+                        .finish_output(token_supply)?,
+                );
+            }
+
+            // The inputs of the previous transactions are locked, so every transaction can be sent right away
+            transactions.push(
+                self.finish_transaction(
+                    outputs,
+                    Some(TransactionOptions {
+                        custom_inputs: Some(chunk.inputs),
+                        note: options.note.clone(),
+                        ..Default::default()
+                    }),
+                )
+                .await?,
+            );
+        }
+
+        Ok(transactions)
+    }
+
+    // Splits the outputs that can be sent into chunks that fit into a transaction
+    async fn send_all_chunks(&self, address: &Address, options: &SendAllOptions) -> crate::wallet::Result<Vec<Chunk>> {
+        #[cfg(feature = "participation")]
+        let voting_output = self.get_voting_output().await?;
+        let current_time = self.client.get_time_checked().await?;
+        let token_supply = self.client.get_token_supply().await?;
+        let max_inputs_per_transaction = self.max_inputs_per_transaction().await;
+        let max_inputs = options
+            .max_inputs
+            .map_or(max_inputs_per_transaction, |max_inputs| {
+                max_inputs.clamp(1, max_inputs_per_transaction)
+            }) as usize;
+
+        let account_details = self.read().await;
+        let account_addresses = &account_details.addresses_with_unspent_outputs[..];
+        let frozen_outputs = account_details.frozen_output_ids();
+
+        let mut chunks = Vec::new();
+        let mut chunk = Chunk::default();
+        for (output_id, output_data) in account_details.unspent_outputs() {
+            #[cfg(feature = "participation")]
+            if let Some(ref voting_output) = voting_output {
+                // Sending the voting output would stop the participation
+                if output_data.output_id == voting_output.output_id {
+                    continue;
+                }
+            }
+            if account_details.locked_outputs.contains(output_id)
+                || frozen_outputs.contains(output_id)
+                || !can_be_sent(output_data, current_time, account_addresses, options)?
+            {
+                continue;
+            }
+
+            let (native_tokens, nft_output) = match &output_data.output {
+                Output::Basic(basic_output) => (Some(basic_output.native_tokens()), None),
+                Output::Nft(nft) => (
+                    None,
+                    Some(
+                        NftOutputBuilder::from(nft)
+                            .with_nft_id(nft.nft_id_non_null(output_id))
+                            .with_unlock_conditions([AddressUnlockCondition::new(*address)])
+                            .finish_output(token_supply)?,
+                    ),
+                ),
+                _ => continue,
+            };
+
+            // Start a new chunk if the output doesn't fit into the current one anymore, one output is reserved for the
+            // basic output
+            let exceeds_native_tokens = match native_tokens {
+                Some(native_tokens) => {
+                    get_new_native_token_count(&chunk.native_tokens, native_tokens)? > NativeTokens::COUNT_MAX.into()
+                }
+                None => false,
+            };
+            let exceeds_outputs = nft_output.is_some() && chunk.nft_outputs.len() + 1 >= OUTPUT_COUNT_MAX.into();
+            if chunk.inputs.len() >= max_inputs || exceeds_native_tokens || exceeds_outputs {
+                chunks.push(std::mem::take(&mut chunk));
+            }
+
+            chunk.inputs.push(*output_id);
+            match nft_output {
+                Some(nft_output) => chunk.nft_outputs.push(nft_output),
+                None => {
+                    chunk.amount += output_data.output.amount();
+                    if let Some(native_tokens) = native_tokens {
+                        chunk.native_tokens.add_native_tokens(native_tokens.clone())?;
+                    }
+                }
+            }
+        }
+        if !chunk.inputs.is_empty() {
+            chunks.push(chunk);
+        }
+
+        Ok(chunks)
+    }
+}
+
+// Checks if an output can be unlocked now and sent without other outputs
+fn can_be_sent(
+    output_data: &OutputData,
+    current_time: u32,
+    account_addresses: &[AddressWithUnspentOutputs],
+    options: &SendAllOptions,
+) -> crate::wallet::Result<bool> {
+    let unlock_conditions = match &output_data.output {
+        Output::Basic(basic_output) => basic_output.unlock_conditions(),
+        Output::Nft(nft_output) if options.include_nfts => nft_output.unlock_conditions(),
+        _ => return Ok(false),
+    };
+
+    if unlock_conditions.is_time_locked(current_time) {
+        return Ok(false);
+    }
+    // The storage deposit would need to be returned, unless the output expired
+    if unlock_conditions.storage_deposit_return().is_some() && !unlock_conditions.is_expired(current_time) {
+        return Ok(false);
+    }
+
+    can_output_be_unlocked_now(account_addresses, &[], output_data, current_time, None)
+}
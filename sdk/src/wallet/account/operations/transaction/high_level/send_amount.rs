@@ -1,15 +1,15 @@
 // Copyright 2022 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+use std::collections::HashMap;
+
 use crate::{
     client::api::PreparedTransactionData,
     types::block::{
         address::Address,
         output::{
-            unlock_condition::{
-                AddressUnlockCondition, ExpirationUnlockCondition, StorageDepositReturnUnlockCondition,
-            },
-            BasicOutputBuilder,
+            unlock_condition::{AddressUnlockCondition, ExpirationUnlockCondition, StorageDepositReturnUnlockCondition},
+            BasicOutputBuilder, Output, OutputId, OUTPUT_COUNT_MAX,
         },
     },
     wallet::{
@@ -37,7 +37,8 @@ pub struct AddressWithAmount {
     return_address: Option<String>,
     /// Expiration in seconds, after which the output will be available for the sender again, if not spent by the
     /// receiver already. The expiration will only be used if one is necessary given the provided amount. If an
-    /// expiration is needed but not provided, it will default to one day.
+    /// expiration is needed but not provided, it will default to
+    /// [`TransactionOptions::micro_amount_expiration`] or one day.
     expiration: Option<u32>,
 }
 
@@ -88,6 +89,122 @@ impl Account {
         self.sign_and_submit_transaction(prepared_transaction).await
     }
 
+    /// Like [`Account::send_amount()`], but for any number of addresses, e.g. for payouts. The outputs are split into
+    /// as many transactions as needed, so every transaction has at most [`OUTPUT_COUNT_MAX`] outputs including the
+    /// remainder. All transactions are prepared and signed before the first one is submitted, if one of them fails,
+    /// the inputs of all of them are unlocked again and nothing is sent. Remainders can't be used as inputs for the
+    /// following transactions, so the funds need to be available in enough outputs. The options are used for every
+    /// transaction, so they can't contain inputs or a burn.
+    pub async fn send_amount_batched(
+        &self,
+        addresses_with_amount: Vec<AddressWithAmount>,
+        options: impl Into<Option<TransactionOptions>> + Send,
+    ) -> crate::wallet::Result<Vec<Transaction>> {
+        log::debug!("[TRANSACTION] send_amount_batched");
+        let options = options.into();
+        if let Some(options) = &options {
+            if options.custom_inputs.is_some() || options.mandatory_inputs.is_some() || options.burn.is_some() {
+                return Err(Error::CustomInput(
+                    "inputs and burns can't be provided for batched transactions".to_string(),
+                ));
+            }
+        }
+
+        let outputs = self.send_amount_outputs(addresses_with_amount, options.as_ref()).await?;
+        // One output is reserved for the remainder
+        let max_outputs_per_transaction = OUTPUT_COUNT_MAX as usize - 1;
+
+        let mut locked_inputs = Vec::new();
+        let mut signed_transactions = Vec::new();
+        for outputs in outputs.chunks(max_outputs_per_transaction) {
+            let signed_transaction = match self.prepare_transaction(outputs.to_vec(), options.clone()).await {
+                Ok(prepared_transaction) => {
+                    locked_inputs.extend(prepared_transaction.inputs_data.iter().cloned());
+                    self.sign_transaction_essence(&prepared_transaction).await
+                }
+                Err(err) => Err(err),
+            };
+            match signed_transaction {
+                Ok(signed_transaction) => signed_transactions.push(signed_transaction),
+                Err(err) => {
+                    // Nothing got submitted yet, so the inputs of all transactions are available again
+                    self.unlock_inputs(&locked_inputs).await?;
+                    return Err(err);
+                }
+            }
+        }
+        log::debug!("[TRANSACTION] send_amount_batched submitting {} transactions", signed_transactions.len());
+
+        let mut transactions = Vec::with_capacity(signed_transactions.len());
+        for signed_transaction in signed_transactions {
+            transactions.push(self.submit_and_store_transaction(signed_transaction).await?);
+        }
+
+        Ok(transactions)
+    }
+
+    /// Like [`Account::send_amount()`] for a single address, but the amount is split into several transactions that
+    /// each only use the outputs of one address of the account, so a large send doesn't link the addresses of the
+    /// account. The addresses with the largest balances are used first, remainders stay on the address they came from
+    /// unless the options or the [`PrivacyOptions`](crate::wallet::account::PrivacyOptions) of the account define
+    /// otherwise. Only basic outputs without native tokens and other unlock conditions are used. All transactions are
+    /// prepared and signed before the first one is submitted, if one of them fails, the inputs of all of them are
+    /// unlocked again and nothing is sent. The options are used for every transaction, so they can't contain inputs or
+    /// a burn.
+    pub async fn send_amount_split(
+        &self,
+        address_with_amount: AddressWithAmount,
+        options: impl Into<Option<TransactionOptions>> + Send,
+    ) -> crate::wallet::Result<Vec<Transaction>> {
+        log::debug!("[TRANSACTION] send_amount_split");
+        let options = options.into();
+        if let Some(options) = &options {
+            if options.custom_inputs.is_some() || options.mandatory_inputs.is_some() || options.burn.is_some() {
+                return Err(Error::CustomInput(
+                    "inputs and burns can't be provided for split transactions".to_string(),
+                ));
+            }
+        }
+
+        let parts = self.send_amount_split_parts(&address_with_amount).await?;
+
+        let mut locked_inputs = Vec::new();
+        let mut signed_transactions = Vec::new();
+        for (inputs, amount) in parts {
+            let options = TransactionOptions {
+                custom_inputs: Some(inputs),
+                ..options.clone().unwrap_or_default()
+            };
+            let address_with_amount = AddressWithAmount {
+                amount,
+                ..address_with_amount.clone()
+            };
+            let signed_transaction = match self.prepare_send_amount(vec![address_with_amount], options).await {
+                Ok(prepared_transaction) => {
+                    locked_inputs.extend(prepared_transaction.inputs_data.iter().cloned());
+                    self.sign_transaction_essence(&prepared_transaction).await
+                }
+                Err(err) => Err(err),
+            };
+            match signed_transaction {
+                Ok(signed_transaction) => signed_transactions.push(signed_transaction),
+                Err(err) => {
+                    // Nothing got submitted yet, so the inputs of all transactions are available again
+                    self.unlock_inputs(&locked_inputs).await?;
+                    return Err(err);
+                }
+            }
+        }
+        log::debug!("[TRANSACTION] send_amount_split submitting {} transactions", signed_transactions.len());
+
+        let mut transactions = Vec::with_capacity(signed_transactions.len());
+        for signed_transaction in signed_transactions {
+            transactions.push(self.submit_and_store_transaction(signed_transaction).await?);
+        }
+
+        Ok(transactions)
+    }
+
     /// Function to prepare the transaction for
     /// [Account.send_amount()](crate::account::Account.send_amount)
     pub async fn prepare_send_amount(
@@ -97,6 +214,103 @@ impl Account {
     ) -> crate::wallet::Result<PreparedTransactionData> {
         log::debug!("[TRANSACTION] prepare_send_amount");
         let options = options.into();
+        let outputs = self.send_amount_outputs(addresses_with_amount, options.as_ref()).await?;
+
+        self.prepare_transaction(outputs, options).await
+    }
+
+    // Splits the amount for `send_amount_split()` into parts with the outputs of a single address as inputs. Every part
+    // and every remainder needs to be able to cover the storage deposit of an output on its own.
+    async fn send_amount_split_parts(
+        &self,
+        address_with_amount: &AddressWithAmount,
+    ) -> crate::wallet::Result<Vec<(Vec<OutputId>, u64)>> {
+        #[cfg(feature = "participation")]
+        let voting_output = self.get_voting_output().await?;
+        let rent_structure = self.client.get_rent_structure().await?;
+        let token_supply = self.client.get_token_supply().await?;
+        let network_id = self.client.get_network_id().await?;
+
+        let address = self.address_from_bech32(&address_with_amount.address).await?;
+        let minimum_amount = BasicOutputBuilder::new_with_minimum_storage_deposit(rent_structure)
+            .add_unlock_condition(AddressUnlockCondition::new(address))
+            .finish_output(token_supply)?
+            .amount();
+
+        let account_details = self.read().await;
+        let frozen_outputs = account_details.frozen_output_ids();
+
+        let mut outputs_per_address: HashMap<Address, (Vec<OutputId>, u64)> = HashMap::new();
+        for (output_id, output_data) in account_details.unspent_outputs() {
+            #[cfg(feature = "participation")]
+            if let Some(ref voting_output) = voting_output {
+                if output_data.output_id == voting_output.output_id {
+                    continue;
+                }
+            }
+            if account_details.locked_outputs.contains(output_id)
+                || frozen_outputs.contains(output_id)
+                || output_data.network_id != network_id
+            {
+                continue;
+            }
+            if let Output::Basic(basic_output) = &output_data.output {
+                let unlock_conditions = basic_output.unlock_conditions();
+                if let (Some(address_unlock_condition), 1, true) = (
+                    unlock_conditions.address(),
+                    unlock_conditions.len(),
+                    basic_output.native_tokens().is_empty(),
+                ) {
+                    let (inputs, balance) = outputs_per_address
+                        .entry(*address_unlock_condition.address())
+                        .or_default();
+                    inputs.push(*output_id);
+                    *balance += basic_output.amount();
+                }
+            }
+        }
+        drop(account_details);
+
+        let mut outputs_per_address = outputs_per_address.into_values().collect::<Vec<_>>();
+        outputs_per_address.sort_by(|(_, a), (_, b)| b.cmp(a));
+
+        let mut parts = Vec::new();
+        let mut remaining_amount = address_with_amount.amount;
+        for (inputs, balance) in outputs_per_address {
+            if remaining_amount == 0 {
+                break;
+            }
+            let mut amount = remaining_amount.min(balance);
+            // Leave enough for another part
+            let rest = remaining_amount - amount;
+            if rest != 0 && rest < minimum_amount {
+                amount = amount.saturating_sub(minimum_amount - rest);
+            }
+            let remainder = balance - amount;
+            if amount < minimum_amount || (remainder != 0 && remainder < minimum_amount) {
+                continue;
+            }
+            parts.push((inputs, amount));
+            remaining_amount -= amount;
+        }
+
+        if remaining_amount != 0 {
+            return Err(Error::InsufficientFunds {
+                available: address_with_amount.amount - remaining_amount,
+                required: address_with_amount.amount,
+            });
+        }
+
+        Ok(parts)
+    }
+
+    // Builds the outputs for `send_amount()`, with a storage deposit return for amounts below the minimum storage
+    // deposit
+    async fn send_amount_outputs(
+        &self,
+        addresses_with_amount: Vec<AddressWithAmount>,
+        options: Option<&TransactionOptions>,
+    ) -> crate::wallet::Result<Vec<Output>> {
         let rent_structure = self.client.get_rent_structure().await?;
         let token_supply = self.client.get_token_supply().await?;
 
@@ -104,6 +318,9 @@ impl Account {
         let default_return_address = account_addresses.first().ok_or(Error::FailedToGetRemainder)?;
 
         let local_time = self.client.get_time_checked().await?;
+        let default_expiration = options
+            .and_then(|options| options.micro_amount_expiration)
+            .unwrap_or(DEFAULT_EXPIRATION_TIME);
 
         let mut outputs = Vec::new();
         for AddressWithAmount {
@@ -113,21 +330,11 @@ impl Account {
             expiration,
         } in addresses_with_amount
         {
-            let (bech32_hrp, address) = Address::try_from_bech32_with_hrp(address)?;
-            self.client.bech32_hrp_matches(&bech32_hrp).await?;
-            let return_address = return_address
-                .map(|address| {
-                    let (hrp, address) = Address::try_from_bech32_with_hrp(address)?;
-                    if bech32_hrp != hrp {
-                        Err(crate::client::Error::InvalidBech32Hrp {
-                            provided: hrp,
-                            expected: bech32_hrp,
-                        })?;
-                    }
-                    Ok::<_, Error>(address)
-                })
-                .transpose()?
-                .unwrap_or(default_return_address.address.inner);
+            let address = self.address_from_bech32(address).await?;
+            let return_address = match return_address {
+                Some(return_address) => self.address_from_bech32(return_address).await?,
+                None => default_return_address.address.inner,
+            };
 
             // Get the minimum required amount for an output assuming it does not need a storage deposit.
             let output = BasicOutputBuilder::new_with_minimum_storage_deposit(rent_structure)
@@ -141,9 +348,7 @@ impl Account {
                         .finish_output(token_supply)?,
                 )
             } else {
-                let expiration_time = expiration.map_or(local_time + DEFAULT_EXPIRATION_TIME, |expiration_time| {
-                    local_time + expiration_time
-                });
+                let expiration_time = local_time + expiration.unwrap_or(default_expiration);
 
                 // Since it does need a storage deposit, calculate how much that should be
                 let storage_deposit_amount = minimum_storage_deposit_basic_native_tokens(
@@ -154,7 +359,7 @@ impl Account {
                     token_supply,
                 )?;
 
-                if !options.as_ref().map(|o| o.allow_micro_amount).unwrap_or_default() {
+                if !options.map(|o| o.allow_micro_amount).unwrap_or_default() {
                     return Err(Error::InsufficientFunds {
                         available: amount,
                         required: amount + storage_deposit_amount,
@@ -181,6 +386,6 @@ impl Account {
             }
         }
 
-        self.prepare_transaction(outputs, options).await
+        Ok(outputs)
     }
 }
@@ -6,14 +6,9 @@ use serde::{Deserialize, Serialize};
 
 use crate::{
     client::api::PreparedTransactionData,
-    types::block::{
-        address::Address,
-        output::{
-            unlock_condition::{
-                AddressUnlockCondition, ExpirationUnlockCondition, StorageDepositReturnUnlockCondition,
-            },
-            BasicOutputBuilder, NativeToken, TokenId,
-        },
+    types::block::output::{
+        unlock_condition::{AddressUnlockCondition, ExpirationUnlockCondition, StorageDepositReturnUnlockCondition},
+        BasicOutputBuilder, NativeToken, NativeTokensBuilder, TokenId,
     },
     wallet::{
         account::{
@@ -23,17 +18,17 @@ use crate::{
             },
             Account, TransactionOptions,
         },
-        Error, Result,
+        Error,
     },
 };
 
-/// Address, amount and native tokens for `send_native_tokens()`
+/// Parameters for `send_native_tokens()`
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 #[serde(rename_all = "camelCase")]
-pub struct AddressNativeTokens {
+pub struct SendNativeTokensParams {
     /// Bech32 encoded address
     pub address: String,
-    /// Native tokens
+    /// Native tokens, amounts of the same token id are summed up
     pub native_tokens: Vec<(TokenId, U256)>,
     /// Bech32 encoded address return address, to which the storage deposit will be returned. Default will use the
     /// first address of the account
@@ -41,17 +36,23 @@ pub struct AddressNativeTokens {
     /// Expiration in seconds, after which the output will be available for the sender again, if not spent by the
     /// receiver before. Default is 1 day
     pub expiration: Option<u32>,
+    /// Gift the storage deposit to the receiver, the output then has neither a storage deposit return nor an
+    /// expiration and the return address and expiration are ignored
+    #[serde(default)]
+    pub gift_storage_deposit: bool,
 }
 
 impl Account {
     /// Function to send native tokens in basic outputs with a [StorageDepositReturnUnlockCondition] and
     /// [ExpirationUnlockCondition], so the storage deposit gets back to the sender and also that the sender gets access
-    /// to the output again after a defined time (default 1 day),
+    /// to the output again after a defined time (default 1 day), unless the storage deposit is gifted.
+    /// Inputs with the native tokens are selected automatically and the native tokens that aren't sent are returned to
+    /// the account in the remainder output.
     /// Calls [Account.send()](crate::account::Account.send) internally, the options can define the
     /// RemainderValueStrategy or custom inputs.
     /// Address needs to be Bech32 encoded
     /// ```ignore
-    /// let outputs = vec![AddressNativeTokens {
+    /// let params = vec![SendNativeTokensParams {
     ///     address: "rms1qpszqzadsym6wpppd6z037dvlejmjuke7s24hm95s9fg9vpua7vluaw60xu".to_string(),
     ///     native_tokens: vec![(
     ///         TokenId::from_str("08e68f7616cd4948efebc6a77c4f93aed770ac53860100000000000000000000000000000000")?,
@@ -60,7 +61,7 @@ impl Account {
     ///     ..Default::default()
     /// }];
     ///
-    /// let tx = account.send_native_tokens(outputs, None).await?;
+    /// let tx = account.send_native_tokens(params, None).await?;
     /// println!("Transaction created: {}", tx.transaction_id);
     /// if let Some(block_id) = tx.block_id {
     ///     println!("Block sent: {}", block_id);
@@ -68,12 +69,10 @@ impl Account {
     /// ```
     pub async fn send_native_tokens(
         &self,
-        addresses_and_native_tokens: Vec<AddressNativeTokens>,
+        params: Vec<SendNativeTokensParams>,
         options: Option<TransactionOptions>,
     ) -> crate::wallet::Result<Transaction> {
-        let prepared_transaction = self
-            .prepare_send_native_tokens(addresses_and_native_tokens, options)
-            .await?;
+        let prepared_transaction = self.prepare_send_native_tokens(params, options).await?;
         self.sign_and_submit_transaction(prepared_transaction).await
     }
 
@@ -81,7 +80,7 @@ impl Account {
     /// [Account.send_native_tokens()](crate::account::Account.send_native_tokens)
     async fn prepare_send_native_tokens(
         &self,
-        addresses_and_native_tokens: Vec<AddressNativeTokens>,
+        params: Vec<SendNativeTokensParams>,
         options: Option<TransactionOptions>,
     ) -> crate::wallet::Result<PreparedTransactionData> {
         log::debug!("[TRANSACTION] prepare_send_native_tokens");
@@ -89,61 +88,97 @@ impl Account {
         let token_supply = self.client.get_token_supply().await?;
 
         let account_addresses = self.addresses().await?;
-        let return_address = account_addresses.first().ok_or(Error::FailedToGetRemainder)?;
+        let default_return_address = account_addresses.first().ok_or(Error::FailedToGetRemainder)?;
 
         let local_time = self.client.get_time_checked().await?;
 
         let mut outputs = Vec::new();
-        for address_with_amount in addresses_and_native_tokens {
-            let (bech32_hrp, address) = Address::try_from_bech32_with_hrp(address_with_amount.address)?;
-            self.client.bech32_hrp_matches(&bech32_hrp).await?;
+        let mut required_native_tokens = NativeTokensBuilder::new();
+        for SendNativeTokensParams {
+            address,
+            native_tokens,
+            return_address,
+            expiration,
+            gift_storage_deposit,
+        } in params
+        {
+            if native_tokens.is_empty() {
+                return Err(Error::MissingParameter("native tokens"));
+            }
+            let address = self.address_from_bech32(&address).await?;
+
+            let mut output_native_tokens = NativeTokensBuilder::new();
+            for (token_id, amount) in native_tokens {
+                output_native_tokens.add_native_token(NativeToken::new(token_id, amount)?)?;
+            }
+            required_native_tokens.merge(output_native_tokens.clone())?;
+            let native_tokens = output_native_tokens.finish()?;
+
+            if gift_storage_deposit {
+                outputs.push(
+                    BasicOutputBuilder::new_with_minimum_storage_deposit(rent_structure)
+                        .with_native_tokens(native_tokens)
+                        .add_unlock_condition(AddressUnlockCondition::new(address))
+                        .finish_output(token_supply)?,
+                );
+                continue;
+            }
+
+            let return_address = match return_address {
+                Some(return_address) => self.address_from_bech32(&return_address).await?,
+                None => default_return_address.address.inner,
+            };
             // get minimum required amount for such an output, so we don't lock more than required
             // We have to check it for every output individually, because different address types and amount of
             // different native tokens require a different storage deposit
             let storage_deposit_amount = minimum_storage_deposit_basic_native_tokens(
                 &rent_structure,
                 &address,
-                &return_address.address.inner,
-                Some(address_with_amount.native_tokens.clone()),
+                &return_address,
+                Some(
+                    native_tokens
+                        .iter()
+                        .map(|native_token| (*native_token.token_id(), native_token.amount()))
+                        .collect(),
+                ),
                 token_supply,
             )?;
 
-            let expiration_time = address_with_amount
-                .expiration
-                .map_or(local_time + DEFAULT_EXPIRATION_TIME, |expiration_time| {
-                    local_time + expiration_time
-                });
+            let expiration_time = expiration.map_or(local_time + DEFAULT_EXPIRATION_TIME, |expiration_time| {
+                local_time + expiration_time
+            });
 
             outputs.push(
                 BasicOutputBuilder::new_with_amount(storage_deposit_amount)
-                    .with_native_tokens(
-                        address_with_amount
-                            .native_tokens
-                            .into_iter()
-                            .map(|(id, amount)| {
-                                NativeToken::new(id, amount)
-                                    .map_err(|e| crate::wallet::Error::Client(Box::new(e.into())))
-                            })
-                            .collect::<Result<Vec<NativeToken>>>()?,
-                    )
+                    .with_native_tokens(native_tokens)
                     .add_unlock_condition(AddressUnlockCondition::new(address))
                     .add_unlock_condition(
                         // We send the full storage_deposit_amount back to the sender, so only the native tokens are
                         // sent
-                        StorageDepositReturnUnlockCondition::new(
-                            return_address.address.inner,
-                            storage_deposit_amount,
-                            token_supply,
-                        )?,
+                        StorageDepositReturnUnlockCondition::new(return_address, storage_deposit_amount, token_supply)?,
                     )
-                    .add_unlock_condition(ExpirationUnlockCondition::new(
-                        return_address.address.inner,
-                        expiration_time,
-                    )?)
+                    .add_unlock_condition(ExpirationUnlockCondition::new(return_address, expiration_time)?)
                     .finish_output(token_supply)?,
             )
         }
 
+        // Fail with the missing token instead of a failed input selection
+        let balance = self.balance().await?;
+        for (token_id, required) in required_native_tokens.iter() {
+            let available = balance
+                .native_tokens()
+                .iter()
+                .find(|native_token| native_token.token_id() == token_id)
+                .map_or_else(U256::zero, |native_token| native_token.available());
+            if available < *required {
+                return Err(Error::InsufficientNativeTokens {
+                    token_id: *token_id,
+                    available,
+                    required: *required,
+                });
+            }
+        }
+
         self.prepare_transaction(outputs, options).await
     }
 }
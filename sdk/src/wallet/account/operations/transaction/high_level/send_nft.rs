@@ -1,43 +1,88 @@
 // Copyright 2022 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-// use primitive_types::U256;
 use serde::{Deserialize, Serialize};
 
 use crate::{
     client::api::PreparedTransactionData,
-    types::block::{
-        address::Address,
-        output::{unlock_condition::AddressUnlockCondition, NftId, NftOutputBuilder, Output},
+    types::block::output::{
+        unlock_condition::{AddressUnlockCondition, ExpirationUnlockCondition, StorageDepositReturnUnlockCondition},
+        NftId, NftOutputBuilder, Output, OutputId, UnlockCondition,
+    },
+    wallet::{
+        account::{operations::transaction::Transaction, Account, TransactionOptions},
+        Error,
     },
-    wallet::account::{operations::transaction::Transaction, Account, TransactionOptions},
 };
 
-/// Address and nft for `send_nft()`
+/// Parameters for `send_nft()`
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
-pub struct AddressAndNftId {
+pub struct SendNftParams {
     /// Bech32 encoded address
     pub address: String,
     /// Nft id
     pub nft_id: NftId,
+    /// Bech32 encoded return address, to which the nft and the storage deposit return. Default will use the first
+    /// address of the account
+    pub return_address: Option<String>,
+    /// Expiration in seconds, sends the nft as offer that the receiver has to accept with
+    /// [`Account::accept_nft_offer()`] before it expires, the nft returns to the return address afterwards
+    pub expiration: Option<u32>,
+    /// The receiver has to return the amount of the nft output to the return address when accepting the offer, so the
+    /// sender gets the storage deposit back
+    #[serde(default)]
+    pub return_storage_deposit: bool,
+}
+
+/// Parameters for `send_nft()`
+#[deprecated(since = "0.4.0", note = "use `SendNftParams` instead")]
+pub type AddressAndNftId = SendNftParams;
+
+impl SendNftParams {
+    pub fn new(address: String, nft_id: NftId) -> Self {
+        Self {
+            address,
+            nft_id,
+            return_address: None,
+            expiration: None,
+            return_storage_deposit: false,
+        }
+    }
+
+    pub fn with_return_address(mut self, address: impl Into<Option<String>>) -> Self {
+        self.return_address = address.into();
+        self
+    }
+
+    pub fn with_expiration(mut self, expiration: impl Into<Option<u32>>) -> Self {
+        self.expiration = expiration.into();
+        self
+    }
+
+    pub fn with_return_storage_deposit(mut self, return_storage_deposit: bool) -> Self {
+        self.return_storage_deposit = return_storage_deposit;
+        self
+    }
 }
 
 impl Account {
-    /// Function to send native tokens in basic outputs with a
-    /// [`StorageDepositReturnUnlockCondition`](crate::types::block::output::unlock_condition::StorageDepositReturnUnlockCondition) and
-    /// [`ExpirationUnlockCondition`](crate::types::block::output::unlock_condition::ExpirationUnlockCondition), so the
-    /// storage deposit gets back to the sender and also that the sender gets access to the output again after a
-    /// defined time (default 1 day), Calls [Account.send()](crate::wallet::account::Account.send)
+    /// Function to send nfts, optionally as offers with an
+    /// [`ExpirationUnlockCondition`](crate::types::block::output::unlock_condition::ExpirationUnlockCondition) that
+    /// the receiver has to accept with [`Account::accept_nft_offer()`] before it expires, and a
+    /// [`StorageDepositReturnUnlockCondition`](crate::types::block::output::unlock_condition::StorageDepositReturnUnlockCondition),
+    /// so the storage deposit gets back to the sender. Calls [Account.send()](crate::wallet::account::Account.send)
     /// internally, the options can define the RemainderValueStrategy. Custom inputs will be replaced with the
     /// required nft inputs. Address needs to be Bech32 encoded
     /// ```ignore
-    /// let outputs = vec![AddressAndNftId {
-    ///     address: "rms1qpszqzadsym6wpppd6z037dvlejmjuke7s24hm95s9fg9vpua7vluaw60xu".to_string(),
-    ///     nft_id: NftId::from_str("04f9b54d488d2e83a6c90db08ae4b39651bbba8a")?,
-    /// }];
+    /// let params = vec![SendNftParams::new(
+    ///     "rms1qpszqzadsym6wpppd6z037dvlejmjuke7s24hm95s9fg9vpua7vluaw60xu".to_string(),
+    ///     NftId::from_str("04f9b54d488d2e83a6c90db08ae4b39651bbba8a")?,
+    /// )
+    /// // An offer that has to be accepted within a day
+    /// .with_expiration(86400)];
     ///
-    /// let transaction = account.send_nft(outputs, None).await?;
+    /// let transaction = account.send_nft(params, None).await?;
     ///
     /// println!(
     ///     "Transaction sent: {}/transaction/{}",
@@ -47,18 +92,37 @@ impl Account {
     /// ```
     pub async fn send_nft(
         &self,
-        addresses_and_nft_ids: Vec<AddressAndNftId>,
+        params: Vec<SendNftParams>,
         options: Option<TransactionOptions>,
     ) -> crate::wallet::Result<Transaction> {
-        let prepared_transaction = self.prepare_send_nft(addresses_and_nft_ids, options).await?;
+        let prepared_transaction = self.prepare_send_nft(params, options).await?;
         self.sign_and_submit_transaction(prepared_transaction).await
     }
 
+    /// Accepts an nft that was sent with an expiration by [`Account::send_nft()`], returns the storage deposit if the
+    /// offer requires it. Fails if the offer expired, then the nft belongs to the sender again.
+    pub async fn accept_nft_offer(&self, output_id: OutputId) -> crate::wallet::Result<Transaction> {
+        log::debug!("[TRANSACTION] accept_nft_offer");
+        let output_data = self.snapshot().unspent_outputs().get(&output_id).cloned();
+        let Some(Output::Nft(nft_output)) = output_data.map(|output_data| output_data.output) else {
+            return Err(Error::NftNotFoundInUnspentOutputs);
+        };
+
+        if let Some(expiration) = nft_output.unlock_conditions().expiration() {
+            let current_time = self.client.get_time_checked().await?;
+            if expiration.return_address_expired(current_time).is_some() {
+                return Err(Error::NftOfferExpired(output_id));
+            }
+        }
+
+        self.claim_outputs(vec![output_id]).await
+    }
+
     /// Function to prepare the transaction for
     /// [Account.send_nft()](crate::account::Account.send_nft)
     async fn prepare_send_nft(
         &self,
-        addresses_and_nft_ids: Vec<AddressAndNftId>,
+        params: Vec<SendNftParams>,
         options: Option<TransactionOptions>,
     ) -> crate::wallet::Result<PreparedTransactionData> {
         log::debug!("[TRANSACTION] prepare_send_nft");
@@ -66,30 +130,50 @@ impl Account {
         let unspent_outputs = self.unspent_outputs(None).await?;
         let token_supply = self.client.get_token_supply().await?;
 
+        let account_addresses = self.addresses().await?;
+        let default_return_address = account_addresses.first().ok_or(Error::FailedToGetRemainder)?;
+
+        let local_time = self.client.get_time_checked().await?;
+
         let mut outputs = Vec::new();
 
-        for address_and_nft_id in addresses_and_nft_ids {
-            let (bech32_hrp, address) = Address::try_from_bech32_with_hrp(address_and_nft_id.address)?;
-            self.client.bech32_hrp_matches(&bech32_hrp).await?;
+        for SendNftParams {
+            address,
+            nft_id,
+            return_address,
+            expiration,
+            return_storage_deposit,
+        } in params
+        {
+            let address = self.address_from_bech32(&address).await?;
+            let return_address = match return_address {
+                Some(return_address) => self.address_from_bech32(&return_address).await?,
+                None => default_return_address.address.inner,
+            };
 
             // Find nft output from the inputs
-            if let Some(nft_output_data) = unspent_outputs.iter().find(|o| {
-                if let Output::Nft(nft_output) = &o.output {
-                    address_and_nft_id.nft_id == nft_output.nft_id_non_null(&o.output_id)
-                } else {
-                    false
-                }
-            }) {
-                if let Output::Nft(nft_output) = &nft_output_data.output {
-                    // Set the nft id and new address unlock condition
-                    let nft_builder = NftOutputBuilder::from(nft_output)
-                        .with_nft_id(address_and_nft_id.nft_id)
-                        .with_unlock_conditions(vec![AddressUnlockCondition::new(address)]);
-                    outputs.push(nft_builder.finish_output(token_supply)?);
-                }
-            } else {
-                return Err(crate::wallet::Error::NftNotFoundInUnspentOutputs);
-            };
+            let nft_output = unspent_outputs
+                .iter()
+                .find_map(|o| match &o.output {
+                    Output::Nft(nft_output) if nft_id == nft_output.nft_id_non_null(&o.output_id) => Some(nft_output),
+                    _ => None,
+                })
+                .ok_or(Error::NftNotFoundInUnspentOutputs)?;
+
+            // Set the nft id and new address unlock condition
+            let mut unlock_conditions = vec![UnlockCondition::from(AddressUnlockCondition::new(address))];
+            if let Some(expiration) = expiration {
+                unlock_conditions.push(ExpirationUnlockCondition::new(return_address, local_time + expiration)?.into());
+            }
+            if return_storage_deposit {
+                unlock_conditions.push(
+                    StorageDepositReturnUnlockCondition::new(return_address, nft_output.amount(), token_supply)?.into(),
+                );
+            }
+            let nft_builder = NftOutputBuilder::from(nft_output)
+                .with_nft_id(nft_id)
+                .with_unlock_conditions(unlock_conditions);
+            outputs.push(nft_builder.finish_output(token_supply)?);
         }
 
         self.prepare_transaction(outputs, options).await
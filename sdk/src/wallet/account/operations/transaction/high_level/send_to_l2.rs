@@ -0,0 +1,194 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::HashMap;
+
+use crate::{
+    client::{api::PreparedTransactionData, secret::EvmAddress},
+    types::block::{
+        address::Address,
+        output::{
+            feature::{Feature, MetadataFeature, SenderFeature},
+            unlock_condition::AddressUnlockCondition,
+            BasicOutputBuilder, NativeToken, NftId, NftOutputBuilder, Output,
+        },
+    },
+    wallet::{
+        account::{operations::transaction::Transaction, Account, TransactionOptions},
+        Error,
+    },
+};
+
+// Hname of the `accounts` core contract of ISC chains
+const ACCOUNTS_CONTRACT_HNAME: u32 = 0x3c4b5e02;
+// Hname of the `transferAllowanceTo` entry point of the `accounts` contract
+const TRANSFER_ALLOWANCE_TO_HNAME: u32 = 0x23f4e3a1;
+// Parameter of `transferAllowanceTo` with the agent id of the receiver
+const AGENT_ID_PARAMETER: &[u8] = b"a";
+// Kind of the agent ids of EVM addresses
+const EVM_AGENT_ID_KIND: u8 = 3;
+
+/// The assets of a deposit with [`Account::send_to_l2()`].
+#[derive(Debug, Clone, Default)]
+pub struct L2Deposit {
+    /// The base coins the receiver gets on the chain.
+    pub base_coins: u64,
+    /// The native tokens the receiver gets on the chain.
+    pub native_tokens: Vec<NativeToken>,
+    /// The NFT the receiver gets on the chain.
+    pub nft_id: Option<NftId>,
+}
+
+impl Account {
+    /// Deposits base coins, native tokens and an NFT to an EVM address on an ISC chain, like IOTA EVM or ShimmerEVM.
+    /// The output is sent to the alias address of the chain with a sender feature and the request metadata, that
+    /// calls `transferAllowanceTo` of the `accounts` contract to move the assets from the on-chain account of the
+    /// sender to the receiver. The gas budget is added to the base coins of the output, so the fees don't reduce the
+    /// amount the receiver gets. If the output needs a higher storage deposit, the difference stays on the on-chain
+    /// account of the sender.
+    /// ```ignore
+    /// let transaction = account
+    ///     .send_to_l2(
+    ///         "rms1pr59qm43mjtvhcajfmupqf23x29llam88yecn6pyul80rx099krmv2fnnux",
+    ///         L2Deposit {
+    ///             base_coins: 1_000_000,
+    ///             ..Default::default()
+    ///         },
+    ///         "0xf39Fd6e51aad88F6F4ce6aB8827279cffFb92266".parse()?,
+    ///         500_000,
+    ///         None,
+    ///     )
+    ///     .await?;
+    /// ```
+    pub async fn send_to_l2(
+        &self,
+        chain_address: &str,
+        deposit: L2Deposit,
+        l2_receiver: EvmAddress,
+        gas_budget: u64,
+        options: impl Into<Option<TransactionOptions>> + Send,
+    ) -> crate::wallet::Result<Transaction> {
+        let prepared_transaction = self
+            .prepare_send_to_l2(chain_address, deposit, l2_receiver, gas_budget, options)
+            .await?;
+        self.sign_and_submit_transaction(prepared_transaction).await
+    }
+
+    /// Function to prepare the transaction for [`Account::send_to_l2()`].
+    pub async fn prepare_send_to_l2(
+        &self,
+        chain_address: &str,
+        deposit: L2Deposit,
+        l2_receiver: EvmAddress,
+        gas_budget: u64,
+        options: impl Into<Option<TransactionOptions>> + Send,
+    ) -> crate::wallet::Result<PreparedTransactionData> {
+        log::debug!("[TRANSACTION] prepare_send_to_l2");
+        let chain_address = match self.address_from_bech32(chain_address).await? {
+            address @ Address::Alias(_) => address,
+            _ => return Err(Error::InvalidChainAddress(chain_address.to_string())),
+        };
+        let rent_structure = self.client.get_rent_structure().await?;
+        let token_supply = self.client.get_token_supply().await?;
+
+        let metadata = MetadataFeature::new(isc_request_metadata(&deposit, &l2_receiver, gas_budget))?;
+        let amount = deposit.base_coins + gas_budget;
+        let unspent_outputs = self.unspent_outputs(None).await?;
+
+        let output = match deposit.nft_id {
+            Some(nft_id) => {
+                let (nft_output_data, nft_output) = unspent_outputs
+                    .iter()
+                    .find_map(|output_data| match &output_data.output {
+                        Output::Nft(nft_output) if nft_output.nft_id_non_null(&output_data.output_id) == nft_id => {
+                            Some((output_data, nft_output))
+                        }
+                        _ => None,
+                    })
+                    .ok_or(Error::NftNotFoundInUnspentOutputs)?;
+
+                // The NFT is moved, so its address is unlocked in the transaction
+                let output = NftOutputBuilder::from(nft_output)
+                    .with_minimum_storage_deposit(rent_structure)
+                    .with_nft_id(nft_id)
+                    .with_unlock_conditions([AddressUnlockCondition::new(chain_address)])
+                    .with_features([
+                        Feature::Sender(SenderFeature::new(nft_output_data.address)),
+                        Feature::Metadata(metadata),
+                    ])
+                    .with_native_tokens(deposit.native_tokens)
+                    .finish_output(token_supply)?;
+                NftOutputBuilder::from(output.as_nft())
+                    .with_amount(amount.max(output.amount()))
+                    .finish_output(token_supply)?
+            }
+            None => {
+                // The sender is the address with the most base coins, so the sender requirement is most likely met by
+                // the inputs that are needed anyway
+                let mut balances = HashMap::new();
+                for output_data in &unspent_outputs {
+                    if let Output::Basic(basic_output) = &output_data.output {
+                        *balances.entry(output_data.address).or_insert(0) += basic_output.amount();
+                    }
+                }
+                let sender = balances
+                    .into_iter()
+                    .max_by_key(|(_, balance)| *balance)
+                    .map(|(address, _)| address)
+                    .ok_or(Error::InsufficientFunds {
+                        available: 0,
+                        required: amount,
+                    })?;
+
+                let output = BasicOutputBuilder::new_with_minimum_storage_deposit(rent_structure)
+                    .add_unlock_condition(AddressUnlockCondition::new(chain_address))
+                    .add_feature(SenderFeature::new(sender))
+                    .add_feature(metadata)
+                    .with_native_tokens(deposit.native_tokens)
+                    .finish_output(token_supply)?;
+                BasicOutputBuilder::from(output.as_basic())
+                    .with_amount(amount.max(output.amount()))
+                    .finish_output(token_supply)?
+            }
+        };
+
+        self.prepare_transaction(vec![output], options).await
+    }
+}
+
+// Encodes the metadata of an ISC request that calls `accounts::transferAllowanceTo`, all integers are little endian:
+// - sender contract, target contract and entry point hnames as u32
+// - gas budget as u64
+// - parameters with u32 count, each with u16 key length, key, u32 value length and value
+// - allowance with base coins as u64, u16 native token count, each with token id and u256 amount, u16 NFT count and
+//   the NFT ids
+fn isc_request_metadata(deposit: &L2Deposit, l2_receiver: &EvmAddress, gas_budget: u64) -> Vec<u8> {
+    let mut metadata = Vec::new();
+    metadata.extend(0u32.to_le_bytes());
+    metadata.extend(ACCOUNTS_CONTRACT_HNAME.to_le_bytes());
+    metadata.extend(TRANSFER_ALLOWANCE_TO_HNAME.to_le_bytes());
+    metadata.extend(gas_budget.to_le_bytes());
+
+    let mut agent_id = vec![EVM_AGENT_ID_KIND];
+    agent_id.extend(l2_receiver.as_bytes());
+    metadata.extend(1u32.to_le_bytes());
+    metadata.extend((AGENT_ID_PARAMETER.len() as u16).to_le_bytes());
+    metadata.extend(AGENT_ID_PARAMETER);
+    metadata.extend((agent_id.len() as u32).to_le_bytes());
+    metadata.extend(agent_id);
+
+    metadata.extend(deposit.base_coins.to_le_bytes());
+    metadata.extend((deposit.native_tokens.len() as u16).to_le_bytes());
+    for native_token in &deposit.native_tokens {
+        let mut amount = [0; 32];
+        native_token.amount().to_little_endian(&mut amount);
+        metadata.extend(native_token.token_id().as_ref());
+        metadata.extend(amount);
+    }
+    metadata.extend((deposit.nft_id.is_some() as u16).to_le_bytes());
+    if let Some(nft_id) = &deposit.nft_id {
+        metadata.extend(nft_id.as_ref());
+    }
+
+    metadata
+}
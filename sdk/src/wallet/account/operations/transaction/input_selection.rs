@@ -21,6 +21,7 @@ use crate::{
 
 impl Account {
     /// Selects inputs for a transaction and locks them in the account, so they don't get used again
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip_all))]
     pub(crate) async fn select_inputs(
         &self,
         outputs: Vec<Output>,
@@ -28,6 +29,7 @@ impl Account {
         mandatory_inputs: Option<HashSet<OutputId>>,
         remainder_address: Option<Address>,
         burn: Option<&Burn>,
+        allow_unconfirmed_inputs: bool,
     ) -> crate::wallet::Result<Selected> {
         log::debug!("[TRANSACTION] select_inputs");
         // Voting output needs to be requested before to prevent a deadlock
@@ -35,6 +37,7 @@ impl Account {
         let voting_output = self.get_voting_output().await?;
         // lock so the same inputs can't be selected in multiple transactions
         let mut account_details = self.write().await;
+        self.release_expired_reservations(&mut account_details).await;
         let protocol_parameters = self.client.get_protocol_parameters().await?;
 
         #[cfg(feature = "events")]
@@ -44,8 +47,9 @@ impl Account {
         );
 
         let current_time = self.client.get_time_checked().await?;
-        #[allow(unused_mut)]
+        let frozen_outputs = account_details.frozen_output_ids();
         let mut forbidden_inputs = account_details.locked_outputs.clone();
+        forbidden_inputs.extend(frozen_outputs.iter().copied());
 
         let addresses = account_details
             .public_addresses()
@@ -67,7 +71,7 @@ impl Account {
 
         // Filter inputs to not include inputs that require additional outputs for storage deposit return or could be
         // still locked.
-        let available_outputs_signing_data = filter_inputs(
+        let mut available_outputs_signing_data = filter_inputs(
             &account_details,
             account_details.unspent_outputs.values(),
             current_time,
@@ -77,6 +81,19 @@ impl Account {
             mandatory_inputs.as_ref(),
         )?;
 
+        // Outputs of pending transactions are unlocked by an address of the account alone, so they don't need to be
+        // filtered
+        if allow_unconfirmed_inputs {
+            for output_data in account_details.unconfirmed_outputs() {
+                let alias_state_transition = alias_state_transition(&output_data, &outputs, burn)?;
+                if let Some(input) =
+                    output_data.input_signing_data(&account_details, current_time, alias_state_transition)?
+                {
+                    available_outputs_signing_data.push(input);
+                }
+            }
+        }
+
         // if custom inputs are provided we should only use them (validate if we have the outputs in this account and
         // that the amount is enough)
         if let Some(custom_inputs) = custom_inputs {
@@ -87,6 +104,11 @@ impl Account {
                         "provided custom input {input} is already used in another transaction",
                     )));
                 }
+                if frozen_outputs.contains(input) {
+                    return Err(crate::wallet::Error::CustomInput(format!(
+                        "provided custom input {input} belongs to a frozen address",
+                    )));
+                }
             }
 
             let mut input_selection = InputSelection::new(
@@ -112,6 +134,7 @@ impl Account {
             for output in &selected_transaction_data.inputs {
                 account_details.locked_outputs.insert(*output.output_id());
             }
+            self.reserve_inputs(&selected_transaction_data.inputs).await;
 
             return Ok(selected_transaction_data);
         } else if let Some(mandatory_inputs) = mandatory_inputs {
@@ -122,6 +145,11 @@ impl Account {
                         "provided custom input {input} is already used in another transaction",
                     )));
                 }
+                if frozen_outputs.contains(input) {
+                    return Err(crate::wallet::Error::CustomInput(format!(
+                        "provided custom input {input} belongs to a frozen address",
+                    )));
+                }
             }
 
             let mut input_selection = InputSelection::new(
@@ -152,6 +180,7 @@ impl Account {
             for output in &selected_transaction_data.inputs {
                 account_details.locked_outputs.insert(*output.output_id());
             }
+            self.reserve_inputs(&selected_transaction_data.inputs).await;
 
             return Ok(selected_transaction_data);
         }
@@ -194,6 +223,7 @@ impl Account {
             log::debug!("[TRANSACTION] locking: {}", output.output_id());
             account_details.locked_outputs.insert(*output.output_id());
         }
+        self.reserve_inputs(&selected_transaction_data.inputs).await;
 
         Ok(selected_transaction_data)
     }
@@ -228,6 +258,8 @@ fn filter_inputs(
     mandatory_inputs: Option<&HashSet<OutputId>>,
 ) -> crate::wallet::Result<Vec<InputSigningData>> {
     let mut available_outputs_signing_data = Vec::new();
+    // Outputs locked to the alias and nft outputs of the account can be unlocked by transitioning these outputs
+    let alias_and_nft_addresses = account.alias_and_nft_addresses();
 
     for output_data in available_outputs {
         if !custom_inputs
@@ -241,6 +273,7 @@ fn filter_inputs(
                 // We use the addresses with unspent outputs, because other addresses of the
                 // account without unspent outputs can't be related to this output
                 &account.addresses_with_unspent_outputs,
+                &alias_and_nft_addresses,
                 &output_data.output,
                 current_time,
             );
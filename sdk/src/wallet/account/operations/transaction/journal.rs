@@ -0,0 +1,99 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{
+    types::block::{output::OutputId, payload::transaction::TransactionEssence},
+    wallet::{
+        account::{Account, AccountDetails},
+        storage::{
+            manager::StorageManager,
+            transaction_journal::{TransactionJournalEntry, TransactionStage},
+        },
+    },
+};
+
+impl Account {
+    // Records the stage of a transaction in the journal of the account, so it can be recovered if the wallet stops
+    // before the transaction is stored
+    pub(crate) async fn journal_transaction(
+        &self,
+        account_index: u32,
+        essence: &TransactionEssence,
+        inputs: Vec<OutputId>,
+        stage: TransactionStage,
+    ) -> crate::wallet::Result<()> {
+        let essence_hash = prefix_hex::encode(essence.hash());
+        let mut storage_manager = self.storage_manager.lock().await;
+        let mut journal = storage_manager.get_transaction_journal(account_index).await?;
+
+        journal.retain(|entry| entry.essence_hash != essence_hash);
+        journal.push(TransactionJournalEntry {
+            essence_hash,
+            inputs,
+            stage,
+        });
+
+        storage_manager.set_transaction_journal(account_index, &journal).await
+    }
+
+    // Removes the transactions that use one of the inputs from the journal, after they were stored or rolled back
+    pub(crate) async fn remove_journal_entries(
+        &self,
+        account_index: u32,
+        inputs: &[OutputId],
+    ) -> crate::wallet::Result<()> {
+        let mut storage_manager = self.storage_manager.lock().await;
+        let mut journal = storage_manager.get_transaction_journal(account_index).await?;
+        let journal_len = journal.len();
+
+        journal.retain(|entry| !entry.inputs.iter().any(|input| inputs.contains(input)));
+
+        if journal.len() == journal_len {
+            return Ok(());
+        }
+        storage_manager.set_transaction_journal(account_index, &journal).await
+    }
+}
+
+/// Replays the journal of the account when the wallet is loaded. Prepared transactions are rolled back by unlocking
+/// their inputs, signed and submitted ones are stored as pending transactions, so syncing submits them again if they
+/// didn't get included. Transactions that were already stored, because the wallet stopped before the journal was
+/// updated, are skipped.
+pub(crate) async fn recover_transactions(
+    storage_manager: &mut StorageManager,
+    account: &mut AccountDetails,
+) -> crate::wallet::Result<()> {
+    let journal = storage_manager.get_transaction_journal(account.index).await?;
+    if journal.is_empty() {
+        return Ok(());
+    }
+
+    for entry in journal {
+        match entry.stage {
+            TransactionStage::Prepared => {
+                log::debug!(
+                    "[recover_transactions] rolling back prepared transaction {}",
+                    entry.essence_hash
+                );
+                for input in &entry.inputs {
+                    account.locked_outputs.remove(input);
+                }
+            }
+            TransactionStage::Signed(transaction) | TransactionStage::Submitted(transaction) => {
+                if account.transactions.contains_key(&transaction.transaction_id) {
+                    continue;
+                }
+                log::debug!(
+                    "[recover_transactions] recovering transaction {}",
+                    transaction.transaction_id
+                );
+                account.locked_outputs.extend(entry.inputs);
+                account.pending_transactions.insert(transaction.transaction_id);
+                account.transactions.insert(transaction.transaction_id, *transaction);
+            }
+        }
+    }
+
+    storage_manager.save_account(account).await?;
+    storage_manager.set_transaction_journal(account.index, &[]).await
+}
@@ -2,15 +2,23 @@
 // SPDX-License-Identifier: Apache-2.0
 
 mod build_transaction;
+mod dry_run;
 pub(crate) mod high_level;
 mod input_selection;
+#[cfg(feature = "storage")]
+pub(crate) mod journal;
 mod options;
 pub(crate) mod prepare_output;
 mod prepare_transaction;
 mod sign_transaction;
 pub(crate) mod submit_transaction;
 
-pub use self::options::{RemainderValueStrategy, TransactionOptions, TransactionOptionsDto};
+pub use self::{
+    dry_run::{TransactionDryRun, TransactionDryRunDto, TransactionSummary},
+    options::{RemainderValueStrategy, TransactionOptions, TransactionOptionsDto},
+};
+#[cfg(feature = "storage")]
+use crate::wallet::storage::transaction_journal::TransactionStage;
 use crate::{
     client::{
         api::{verify_semantic, PreparedTransactionData, SignedTransactionData},
@@ -81,6 +89,10 @@ impl Account {
 
     /// Separated function from send, so syncing isn't called recursively with the consolidation function, which sends
     /// transactions
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "transaction", skip_all, fields(account_alias = %self.snapshot().alias()))
+    )]
     pub async fn finish_transaction(
         &self,
         outputs: Vec<Output>,
@@ -113,6 +125,14 @@ impl Account {
     }
 
     /// Validate the transaction, submit it to a node and store it in the account
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            level = "debug",
+            skip_all,
+            fields(transaction_id = %signed_transaction_data.transaction_payload.id())
+        )
+    )]
     pub async fn submit_and_store_transaction(
         &self,
         signed_transaction_data: SignedTransactionData,
@@ -141,23 +161,14 @@ impl Account {
             return Err(Error::TransactionSemantic(conflict).into());
         }
 
-        // Ignore errors from sending, we will try to send it again during [`sync_pending_transactions`]
-        let block_id = match self
-            .submit_transaction_payload(signed_transaction_data.transaction_payload.clone())
-            .await
-        {
-            Ok(block_id) => Some(block_id),
-            Err(err) => {
-                log::error!("Failed to submit_transaction_payload {}", err);
-                None
-            }
-        };
-
         let transaction_id = signed_transaction_data.transaction_payload.id();
-
-        // store transaction payload to account (with db feature also store the account to the db)
         let network_id = self.client.get_network_id().await?;
 
+        let input_ids = signed_transaction_data
+            .inputs_data
+            .iter()
+            .map(|input| *input.output_id())
+            .collect::<Vec<_>>();
         let inputs = signed_transaction_data
             .inputs_data
             .into_iter()
@@ -167,34 +178,72 @@ impl Account {
             })
             .collect();
 
-        let transaction = Transaction {
+        let mut transaction = Transaction {
             transaction_id,
             payload: signed_transaction_data.transaction_payload,
-            block_id,
+            block_id: None,
             network_id,
             timestamp: crate::utils::unix_timestamp_now().as_millis(),
             inclusion_state: InclusionState::Pending,
             incoming: false,
             note: None,
             inputs,
+            milestone_index: None,
+            confirmed_at: None,
+            attachments: Vec::new(),
         };
 
+        #[cfg(feature = "storage")]
+        let account_index = self.read().await.index;
+        // Journal the signed transaction before it's submitted, so it isn't lost if the wallet stops
+        #[cfg(feature = "storage")]
+        self.journal_transaction(
+            account_index,
+            transaction.payload.essence(),
+            input_ids.clone(),
+            TransactionStage::Signed(Box::new(transaction.clone())),
+        )
+        .await?;
+
+        // Ignore errors from sending, we will try to send it again during [`sync_pending_transactions`]
+        match self.submit_transaction_payload(transaction.payload.clone()).await {
+            Ok(attachment) => transaction.add_attachment(attachment),
+            Err(err) => log::error!("Failed to submit_transaction_payload {}", err),
+        }
+
+        #[cfg(feature = "storage")]
+        if transaction.block_id.is_some() {
+            self.journal_transaction(
+                account_index,
+                transaction.payload.essence(),
+                input_ids.clone(),
+                TransactionStage::Submitted(Box::new(transaction.clone())),
+            )
+            .await?;
+        }
+
         let mut account_details = self.write().await;
 
         account_details.transactions.insert(transaction_id, transaction.clone());
         account_details.pending_transactions.insert(transaction_id);
+        // The inputs stay locked until the transaction is confirmed or conflicting, so they don't expire anymore
+        self.remove_reservations(&input_ids).await;
         #[cfg(feature = "storage")]
         {
             log::debug!("[TRANSACTION] storing account {}", account_details.index());
             self.save(Some(&account_details)).await?;
+            // The transaction is acknowledged once it's stored
+            self.remove_journal_entries(account_details.index, &input_ids).await?;
         }
 
         Ok(transaction)
     }
 
     // unlock outputs
-    async fn unlock_inputs(&self, inputs: &[InputSigningData]) -> crate::wallet::Result<()> {
+    pub(crate) async fn unlock_inputs(&self, inputs: &[InputSigningData]) -> crate::wallet::Result<()> {
         let mut account_details = self.write().await;
+        let output_ids = inputs.iter().map(|input| *input.output_id()).collect::<Vec<_>>();
+        self.remove_reservations(&output_ids).await;
         for input_signing_data in inputs {
             let output_id = input_signing_data.output_id();
             account_details.locked_outputs.remove(output_id);
@@ -203,6 +252,9 @@ impl Account {
                 output_id
             );
         }
+        // Roll the transaction back in the journal, so the inputs aren't locked again when the wallet is loaded
+        #[cfg(feature = "storage")]
+        self.remove_journal_entries(account_details.index, &output_ids).await?;
         Ok(())
     }
 }
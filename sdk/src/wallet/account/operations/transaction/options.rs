@@ -29,8 +29,19 @@ pub struct TransactionOptions {
     pub mandatory_inputs: Option<Vec<OutputId>>,
     pub burn: Option<Burn>,
     pub note: Option<String>,
+    /// Allows sending amounts below the minimum storage deposit, the missing storage deposit is added and returned to
+    /// the sender with a storage deposit return and an expiration unlock condition.
     #[serde(default)]
     pub allow_micro_amount: bool,
+    /// Expiration in seconds for outputs with amounts below the minimum storage deposit, if not set per address.
+    /// Default is one day.
+    #[serde(default)]
+    pub micro_amount_expiration: Option<u32>,
+    /// Allows using outputs of pending transactions of the account as inputs, so transactions can be chained without
+    /// waiting for confirmations. The transaction is submitted after the ones it depends on and becomes conflicting if
+    /// one of them conflicts.
+    #[serde(default)]
+    pub allow_unconfirmed_inputs: bool,
 }
 
 impl TransactionOptions {
@@ -48,6 +59,8 @@ impl TransactionOptions {
             burn: value.burn.as_ref().map(Burn::try_from).transpose()?,
             note: value.note.clone(),
             allow_micro_amount: value.allow_micro_amount,
+            micro_amount_expiration: value.micro_amount_expiration,
+            allow_unconfirmed_inputs: value.allow_unconfirmed_inputs,
         })
     }
 }
@@ -68,8 +81,19 @@ pub struct TransactionOptionsDto {
     pub mandatory_inputs: Option<Vec<OutputId>>,
     pub burn: Option<BurnDto>,
     pub note: Option<String>,
+    /// Allows sending amounts below the minimum storage deposit, the missing storage deposit is added and returned to
+    /// the sender with a storage deposit return and an expiration unlock condition.
     #[serde(default)]
     pub allow_micro_amount: bool,
+    /// Expiration in seconds for outputs with amounts below the minimum storage deposit, if not set per address.
+    /// Default is one day.
+    #[serde(default)]
+    pub micro_amount_expiration: Option<u32>,
+    /// Allows using outputs of pending transactions of the account as inputs, so transactions can be chained without
+    /// waiting for confirmations. The transaction is submitted after the ones it depends on and becomes conflicting if
+    /// one of them conflicts.
+    #[serde(default)]
+    pub allow_unconfirmed_inputs: bool,
 }
 
 #[allow(clippy::enum_variant_names)]
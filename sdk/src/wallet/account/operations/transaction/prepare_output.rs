@@ -14,9 +14,9 @@ use crate::{
             feature::{IssuerFeature, MetadataFeature, SenderFeature, TagFeature},
             unlock_condition::{
                 AddressUnlockCondition, ExpirationUnlockCondition, StorageDepositReturnUnlockCondition,
-                TimelockUnlockCondition,
+                TimelockUnlockCondition, UnlockConditions,
             },
-            BasicOutputBuilder, NativeToken, NftId, NftOutput, NftOutputBuilder, Output, Rent,
+            BasicOutputBuilder, NativeToken, NftId, NftOutput, NftOutputBuilder, Output,
         },
         Error,
     },
@@ -28,6 +28,9 @@ impl Account {
     /// If the amount is below the minimum required storage deposit, by default the remaining amount will automatically
     /// be added with a StorageDepositReturn UnlockCondition, when setting the ReturnStrategy to `gift`, the full
     /// minimum required storage deposit will be sent to the recipient.
+    /// Without a ReturnStrategy in the options, the default return strategy of the account is used, see
+    /// [`Account::set_output_defaults()`]. Returned storage deposits also get the default expiration of the account,
+    /// if it's set and the unlocks of the options don't have an expiration.
     /// When the assets contain an nft_id, the data from the existing nft output will be used, just with the address
     /// unlock conditions replaced
     pub async fn prepare_output(
@@ -38,8 +41,7 @@ impl Account {
         log::debug!("[OUTPUT] prepare_output {options:?}");
         let token_supply = self.client.get_token_supply().await?;
 
-        let (bech32_hrp, recipient_address) = Address::try_from_bech32_with_hrp(&options.recipient_address)?;
-        self.client.bech32_hrp_matches(&bech32_hrp).await?;
+        let recipient_address = self.address_from_bech32(&options.recipient_address).await?;
 
         if let Some(assets) = &options.assets {
             if let Some(nft_id) = assets.nft_id {
@@ -78,18 +80,19 @@ impl Account {
 
             if let Some(sender) = features.sender {
                 first_output_builder =
-                    first_output_builder.add_feature(SenderFeature::new(Address::try_from_bech32(sender)?))
+                    first_output_builder.add_feature(SenderFeature::new(self.address_from_bech32(sender).await?))
             }
         }
 
         if let Some(unlocks) = options.unlocks {
-            if let Some(expiration_unix_time) = unlocks.expiration_unix_time {
+            let (expiration_unix_time, timelock_unix_time) = self.unlock_unix_times(&unlocks).await?;
+            if let Some(expiration_unix_time) = expiration_unix_time {
                 let remainder_address = self.get_remainder_address(transaction_options.clone()).await?;
 
                 first_output_builder = first_output_builder
                     .add_unlock_condition(ExpirationUnlockCondition::new(remainder_address, expiration_unix_time)?);
             }
-            if let Some(timelock_unix_time) = unlocks.timelock_unix_time {
+            if let Some(timelock_unix_time) = timelock_unix_time {
                 first_output_builder =
                     first_output_builder.add_unlock_condition(TimelockUnlockCondition::new(timelock_unix_time)?);
             }
@@ -107,11 +110,25 @@ impl Account {
             }
             Ordering::Less => {
                 let storage_deposit = options.storage_deposit.unwrap_or_default();
+                let output_defaults = self.output_defaults().await;
                 // Gift return strategy doesn't need a change, since the amount is already the minimum storage
                 // deposit
-                if storage_deposit.return_strategy.unwrap_or_default() == ReturnStrategy::Return {
+                if storage_deposit
+                    .return_strategy
+                    .unwrap_or(output_defaults.default_return_strategy)
+                    == ReturnStrategy::Return
+                {
                     let remainder_address = self.get_remainder_address(transaction_options).await?;
 
+                    if let Some(expiration_unix_time) = self
+                        .default_expiration_unix_time(&output_defaults, first_output.unlock_conditions())
+                        .await?
+                    {
+                        second_output_builder = second_output_builder.add_unlock_condition(
+                            ExpirationUnlockCondition::new(remainder_address, expiration_unix_time)?,
+                        );
+                    }
+
                     // Calculate the minimum storage deposit to be returned
                     let min_storage_deposit_return_amount =
                         minimum_storage_deposit_basic_output(&rent_structure, &None, token_supply)?;
@@ -221,7 +238,7 @@ impl Account {
 
         // Set new address unlock condition
         first_output_builder = first_output_builder.with_unlock_conditions(vec![AddressUnlockCondition::new(
-            Address::try_from_bech32(options.recipient_address.clone())?,
+            self.address_from_bech32(&options.recipient_address).await?,
         )]);
 
         if let Some(assets) = options.assets {
@@ -245,23 +262,24 @@ impl Account {
 
             if let Some(sender) = features.sender {
                 first_output_builder =
-                    first_output_builder.add_feature(SenderFeature::new(Address::try_from_bech32(sender)?))
+                    first_output_builder.add_feature(SenderFeature::new(self.address_from_bech32(sender).await?))
             }
 
             if let Some(issuer) = features.issuer {
-                first_output_builder =
-                    first_output_builder.add_immutable_feature(IssuerFeature::new(Address::try_from_bech32(issuer)?));
+                first_output_builder = first_output_builder
+                    .add_immutable_feature(IssuerFeature::new(self.address_from_bech32(issuer).await?));
             }
         }
 
         if let Some(unlocks) = options.unlocks {
-            if let Some(expiration_unix_time) = unlocks.expiration_unix_time {
+            let (expiration_unix_time, timelock_unix_time) = self.unlock_unix_times(&unlocks).await?;
+            if let Some(expiration_unix_time) = expiration_unix_time {
                 let remainder_address = self.get_remainder_address(transaction_options.clone()).await?;
 
                 first_output_builder = first_output_builder
                     .add_unlock_condition(ExpirationUnlockCondition::new(remainder_address, expiration_unix_time)?);
             }
-            if let Some(timelock_unix_time) = unlocks.timelock_unix_time {
+            if let Some(timelock_unix_time) = timelock_unix_time {
                 first_output_builder =
                     first_output_builder.add_unlock_condition(TimelockUnlockCondition::new(timelock_unix_time)?);
             }
@@ -281,11 +299,25 @@ impl Account {
             }
             Ordering::Less => {
                 let storage_deposit = options.storage_deposit.unwrap_or_default();
+                let output_defaults = self.output_defaults().await;
                 // Gift return strategy doesn't need a change, since the amount is already the minimum storage
                 // deposit
-                if storage_deposit.return_strategy.unwrap_or_default() == ReturnStrategy::Return {
+                if storage_deposit
+                    .return_strategy
+                    .unwrap_or(output_defaults.default_return_strategy)
+                    == ReturnStrategy::Return
+                {
                     let remainder_address = self.get_remainder_address(transaction_options).await?;
 
+                    if let Some(expiration_unix_time) = self
+                        .default_expiration_unix_time(&output_defaults, first_output.unlock_conditions())
+                        .await?
+                    {
+                        second_output_builder = second_output_builder.add_unlock_condition(
+                            ExpirationUnlockCondition::new(remainder_address, expiration_unix_time)?,
+                        );
+                    }
+
                     // Calculate the amount to be returned
                     let min_storage_deposit_return_amount =
                         minimum_storage_deposit_basic_output(&rent_structure, &None, token_supply)?;
@@ -353,6 +385,61 @@ impl Account {
         Ok(third_output_builder.finish_output(token_supply)?)
     }
 
+    // Returns the expiration and timelock unix times of the unlocks, an expiration in seconds is added to the current
+    // time
+    async fn unlock_unix_times(&self, unlocks: &Unlocks) -> crate::wallet::Result<(Option<u32>, Option<u32>)> {
+        let expiration_unix_time = match (unlocks.expiration_unix_time, unlocks.expiration_in_seconds) {
+            (Some(_), Some(_)) => {
+                return Err(crate::wallet::Error::InvalidOutputOptions(
+                    "expiration_unix_time and expiration_in_seconds can't be set together".to_string(),
+                ));
+            }
+            (None, Some(expiration_in_seconds)) => {
+                let current_time = self.client.get_time_checked().await?;
+                Some(current_time.checked_add(expiration_in_seconds).ok_or_else(|| {
+                    crate::wallet::Error::InvalidOutputOptions(format!(
+                        "expiration in {expiration_in_seconds} seconds exceeds the maximum unix time"
+                    ))
+                })?)
+            }
+            (expiration_unix_time, None) => expiration_unix_time,
+        };
+
+        let timelock_unix_time = unlocks.timelock_unix_time;
+
+        // The recipient could never unlock an output that expires before its timelock ends
+        if let (Some(expiration_unix_time), Some(timelock_unix_time)) = (expiration_unix_time, timelock_unix_time) {
+            if expiration_unix_time <= timelock_unix_time {
+                return Err(crate::wallet::Error::InvalidOutputOptions(format!(
+                    "expiration {expiration_unix_time} must be after the timelock {timelock_unix_time}"
+                )));
+            }
+        }
+
+        Ok((expiration_unix_time, timelock_unix_time))
+    }
+
+    // Returns the expiration unix time of the default expiration, so the storage deposit of an output that the
+    // recipient doesn't claim gets back to the sender, unless the output already expires
+    async fn default_expiration_unix_time(
+        &self,
+        output_defaults: &OutputDefaults,
+        unlock_conditions: &UnlockConditions,
+    ) -> crate::wallet::Result<Option<u32>> {
+        match output_defaults.default_expiration {
+            Some(expiration_in_seconds) if unlock_conditions.expiration().is_none() => {
+                let (expiration_unix_time, _) = self
+                    .unlock_unix_times(&Unlocks {
+                        expiration_in_seconds: Some(expiration_in_seconds),
+                        ..Default::default()
+                    })
+                    .await?;
+                Ok(expiration_unix_time)
+            }
+            _ => Ok(None),
+        }
+    }
+
     // Get a remainder address based on transaction_options or use the first account address
     async fn get_remainder_address(
         &self,
@@ -390,6 +477,7 @@ impl Account {
 }
 
 #[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct OutputOptions {
     pub recipient_address: String,
     pub amount: u64,
@@ -407,6 +495,7 @@ pub struct Assets {
 }
 
 #[derive(Debug, Default, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct Features {
     pub tag: Option<String>,
     pub metadata: Option<String>,
@@ -419,6 +508,8 @@ pub struct Features {
 pub struct Unlocks {
     pub expiration_unix_time: Option<u32>,
     pub timelock_unix_time: Option<u32>,
+    // Expiration relative to the current time, can't be set together with `expiration_unix_time`
+    pub expiration_in_seconds: Option<u32>,
 }
 
 #[derive(Debug, Default, Clone, Eq, PartialEq, Serialize, Deserialize)]
@@ -430,6 +521,18 @@ pub struct StorageDeposit {
     pub use_excess_if_low: Option<bool>,
 }
 
+/// Defaults of an account for outputs to addresses that can't cover the storage deposit, used by
+/// [`Account::prepare_output()`] if the [`StorageDeposit`] options and [`Unlocks`] of an output don't define them.
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct OutputDefaults {
+    /// Expiration in seconds for outputs with a storage deposit return, so the storage deposit gets back to the sender
+    /// if the recipient doesn't claim the output. Outputs don't expire if it's `None`.
+    pub default_expiration: Option<u32>,
+    /// The return strategy for outputs with an amount below the minimum storage deposit.
+    pub default_return_strategy: ReturnStrategy,
+}
+
 #[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Serialize, Deserialize)]
 pub enum ReturnStrategy {
     // A storage deposit return unlock condition will be added with the required minimum storage deposit
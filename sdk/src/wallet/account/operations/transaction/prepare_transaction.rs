@@ -8,6 +8,8 @@ use packable::bounded::TryIntoBoundedU16Error;
 
 #[cfg(feature = "events")]
 use crate::wallet::events::types::{AddressData, TransactionProgressEvent, WalletEvent};
+#[cfg(feature = "storage")]
+use crate::wallet::storage::transaction_journal::TransactionStage;
 use crate::{
     client::api::PreparedTransactionData,
     types::block::{
@@ -22,6 +24,7 @@ use crate::{
 
 impl Account {
     /// Get inputs and build the transaction essence
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip_all))]
     pub async fn prepare_transaction(
         &self,
         outputs: Vec<Output>,
@@ -63,38 +66,47 @@ impl Account {
             }
         }
 
-        let remainder_address = match &options {
-            Some(options) => {
-                match &options.remainder_value_strategy {
-                    RemainderValueStrategy::ReuseAddress => {
-                        // select_inputs will select an address from the inputs if it's none
-                        None
-                    }
-                    RemainderValueStrategy::ChangeAddress => {
-                        let remainder_address = self.generate_remainder_address().await?;
-                        #[cfg(feature = "events")]
-                        {
-                            let account_index = self.read().await.index;
-                            self.event_emitter.lock().await.emit(
-                                account_index,
-                                WalletEvent::TransactionProgress(
-                                    TransactionProgressEvent::GeneratingRemainderDepositAddress(AddressData {
-                                        address: remainder_address.address.to_string(),
-                                    }),
-                                ),
-                            );
-                        }
-                        Some(remainder_address.address().inner)
-                    }
-                    RemainderValueStrategy::CustomAddress(address) => Some(address.address().inner),
+        // Accounts with fresh remainder addresses don't send remainders back to an address of the inputs
+        let fresh_remainder_addresses = self.read().await.privacy_options.fresh_remainder_addresses;
+        let remainder_value_strategy = match options.as_ref().map(|options| &options.remainder_value_strategy) {
+            None | Some(RemainderValueStrategy::ReuseAddress) if fresh_remainder_addresses => {
+                &RemainderValueStrategy::ChangeAddress
+            }
+            Some(remainder_value_strategy) => remainder_value_strategy,
+            None => &RemainderValueStrategy::ReuseAddress,
+        };
+
+        let remainder_address = match remainder_value_strategy {
+            RemainderValueStrategy::ReuseAddress => {
+                // select_inputs will select an address from the inputs if it's none
+                None
+            }
+            RemainderValueStrategy::ChangeAddress => {
+                let remainder_address = self.generate_remainder_address().await?;
+                #[cfg(feature = "events")]
+                {
+                    let account_index = self.read().await.index;
+                    self.event_emitter.lock().await.emit(
+                        account_index,
+                        WalletEvent::TransactionProgress(TransactionProgressEvent::GeneratingRemainderDepositAddress(
+                            AddressData {
+                                address: remainder_address.address.to_string(),
+                            },
+                        )),
+                    );
                 }
+                Some(remainder_address.address().inner)
             }
-            None => None,
+            RemainderValueStrategy::CustomAddress(address) => Some(address.address().inner),
         };
 
+        let burn = options.as_ref().and_then(|options| options.burn.as_ref());
+        let allow_unconfirmed_inputs = options
+            .as_ref()
+            .map_or(false, |options| options.allow_unconfirmed_inputs);
         let selected_transaction_data = self
             .select_inputs(
-                outputs,
+                outputs.clone(),
                 options
                     .as_ref()
                     .and_then(|options| options.custom_inputs.as_ref())
@@ -104,9 +116,27 @@ impl Account {
                     .and_then(|options| options.mandatory_inputs.as_ref())
                     .map(|inputs| HashSet::from_iter(inputs.clone())),
                 remainder_address,
-                options.as_ref().and_then(|options| options.burn.as_ref()),
+                burn,
+                allow_unconfirmed_inputs,
             )
             .await?;
+        // Remainders below the minimum of the dust protection are added to other outputs or merged
+        let selected_transaction_data = match self
+            .protect_from_dust(
+                selected_transaction_data.clone(),
+                outputs,
+                remainder_address,
+                burn,
+                allow_unconfirmed_inputs,
+            )
+            .await
+        {
+            Ok(res) => res,
+            Err(err) => {
+                self.unlock_inputs(&selected_transaction_data.inputs).await?;
+                return Err(err);
+            }
+        };
 
         let prepared_transaction_data = match self
             .build_transaction_essence(selected_transaction_data.clone(), options)
@@ -120,6 +150,24 @@ impl Account {
             }
         };
 
+        // Journal the prepared transaction, so its inputs are unlocked if the wallet stops before it's signed
+        #[cfg(feature = "storage")]
+        {
+            let account_index = self.read().await.index;
+            let inputs = prepared_transaction_data
+                .inputs_data
+                .iter()
+                .map(|input| *input.output_id())
+                .collect();
+            self.journal_transaction(
+                account_index,
+                &prepared_transaction_data.essence,
+                inputs,
+                TransactionStage::Prepared,
+            )
+            .await?;
+        }
+
         log::debug!(
             "[TRANSACTION] finished prepare_transaction in {:.2?}",
             prepare_transaction_start_time.elapsed()
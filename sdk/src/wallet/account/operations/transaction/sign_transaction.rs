@@ -3,15 +3,20 @@
 
 #[cfg(all(feature = "events", feature = "ledger_nano"))]
 use {
-    crate::client::api::PreparedTransactionDataDto, crate::client::secret::ledger_nano::needs_blind_signing,
+    crate::client::api::PreparedTransactionDataDto,
+    crate::client::secret::ledger_nano::needs_blind_signing,
     crate::client::secret::SecretManager,
+    crate::wallet::events::types::{derivation_path, LedgerAddressDisplay, LedgerDisplayEvent, LedgerEssenceDisplay},
 };
 
 #[cfg(feature = "events")]
 use crate::wallet::events::types::{TransactionProgressEvent, WalletEvent};
 use crate::{
     client::{
-        api::{transaction::validate_transaction_payload_length, PreparedTransactionData, SignedTransactionData},
+        api::{
+            transaction::validate_transaction_payload_length, verify_network_id, PreparedTransactionData,
+            SignedTransactionData,
+        },
         secret::SecretManageExt,
     },
     wallet::account::{operations::transaction::TransactionPayload, Account},
@@ -19,12 +24,27 @@ use crate::{
 
 impl Account {
     /// Function to sign a transaction essence
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip_all))]
     pub async fn sign_transaction_essence(
         &self,
         prepared_transaction_data: &PreparedTransactionData,
     ) -> crate::wallet::Result<SignedTransactionData> {
         log::debug!("[TRANSACTION] sign_transaction_essence");
         log::debug!("[TRANSACTION] prepared_transaction_data {prepared_transaction_data:?}");
+        if let Err(err) = self.check_not_watch_only() {
+            // unlock outputs so they are available for a new transaction
+            self.unlock_inputs(&prepared_transaction_data.inputs_data).await?;
+            return Err(err);
+        }
+        // Transactions are only signed for supported protocol versions, as the blocks would be invalid otherwise
+        let protocol_parameters = self.client.get_supported_protocol_parameters().await?;
+        // Prepared or imported transactions for another network couldn't be included
+        verify_network_id(&prepared_transaction_data.essence, &protocol_parameters)?;
+        if let Err(err) = self.check_spending_policy(prepared_transaction_data).await {
+            // unlock outputs so they are available for a new transaction
+            self.unlock_inputs(&prepared_transaction_data.inputs_data).await?;
+            return Err(err);
+        }
         #[cfg(feature = "events")]
         self.event_emitter.lock().await.emit(
             self.read().await.index,
@@ -35,7 +55,34 @@ impl Account {
         if let SecretManager::LedgerNano(ledger) = &*self.secret_manager.read().await {
             let ledger_nano_status = ledger.get_ledger_nano_status().await;
             if let Some(buffer_size) = ledger_nano_status.buffer_size() {
-                if needs_blind_signing(prepared_transaction_data, buffer_size) {
+                let blind_signing = needs_blind_signing(prepared_transaction_data, buffer_size);
+                // The device doesn't display the remainder if it blind signs
+                let remainder = match &prepared_transaction_data.remainder {
+                    Some(remainder) if !blind_signing => {
+                        let bech32_hrp = self.client.get_bech32_hrp().await?;
+                        remainder.chain.as_ref().map(|chain| LedgerAddressDisplay {
+                            derivation_path: derivation_path(chain),
+                            address: remainder.address.to_bech32(bech32_hrp),
+                        })
+                    }
+                    _ => None,
+                };
+                let display = LedgerEssenceDisplay {
+                    essence_hash: prefix_hex::encode(prepared_transaction_data.essence.hash()),
+                    blind_signing,
+                    input_derivation_paths: prepared_transaction_data
+                        .inputs_data
+                        .iter()
+                        .filter_map(|input| input.chain.as_ref().map(derivation_path))
+                        .collect(),
+                    remainder,
+                };
+                self.event_emitter.lock().await.emit(
+                    self.read().await.index,
+                    WalletEvent::LedgerDisplay(Box::new(LedgerDisplayEvent::Essence(display))),
+                );
+
+                if blind_signing {
                     self.event_emitter.lock().await.emit(
                         self.read().await.index,
                         WalletEvent::TransactionProgress(TransactionProgressEvent::PreparedTransactionEssenceHash(
@@ -4,16 +4,27 @@
 #[cfg(feature = "events")]
 use crate::wallet::events::types::{TransactionProgressEvent, WalletEvent};
 use crate::{
-    types::block::{payload::Payload, BlockId},
-    wallet::account::{operations::transaction::TransactionPayload, Account},
+    types::block::{parent::Parents, payload::Payload},
+    wallet::account::{operations::transaction::TransactionPayload, types::BlockAttachment, Account, WatchedOperation},
 };
 
 impl Account {
-    /// Submits a payload in a block
+    /// Submits a payload in a block, returns the [`BlockAttachment`] with the parents and PoW duration of the block
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip_all))]
     pub(crate) async fn submit_transaction_payload(
         &self,
         transaction_payload: TransactionPayload,
-    ) -> crate::wallet::Result<BlockId> {
+    ) -> crate::wallet::Result<BlockAttachment> {
+        self.watch(WatchedOperation::Submission, || {
+            self.submit_transaction_payload_once(transaction_payload.clone())
+        })
+        .await
+    }
+
+    async fn submit_transaction_payload_once(
+        &self,
+        transaction_payload: TransactionPayload,
+    ) -> crate::wallet::Result<BlockAttachment> {
         log::debug!("[TRANSACTION] send_payload");
         #[cfg(feature = "events")]
         let account_index = self.read().await.index;
@@ -27,10 +38,14 @@ impl Account {
                 WalletEvent::TransactionProgress(TransactionProgressEvent::PerformingPow),
             );
         }
+        let parents = self.parents_for_pending_inputs(&transaction_payload).await?;
+        let pow_start = crate::utils::unix_timestamp_now();
         let block = self
             .client
-            .finish_block_builder(None, Some(Payload::from(transaction_payload)))
+            .finish_block_builder(parents, Some(Payload::from(transaction_payload)))
             .await?;
+        // Without local PoW the nonce is computed by the node when the block is posted
+        let pow_duration = local_pow.then(|| crate::utils::unix_timestamp_now().saturating_sub(pow_start).as_millis());
 
         #[cfg(feature = "events")]
         self.event_emitter.lock().await.emit(
@@ -39,6 +54,52 @@ impl Account {
         );
         let block_id = self.client.post_block(&block).await?;
         log::debug!("[TRANSACTION] submitted block {}", block_id);
-        Ok(block_id)
+        Ok(BlockAttachment {
+            block_id,
+            parents: block.parents().iter().copied().collect(),
+            pow_duration,
+            timestamp: crate::utils::unix_timestamp_now().as_millis(),
+        })
+    }
+
+    /// Returns the parents for the block of a transaction that spends outputs of pending transactions, so the block
+    /// references the blocks of these transactions. Returns `None` if the transaction doesn't depend on pending
+    /// transactions, so tips from the node are used.
+    pub(crate) async fn parents_for_pending_inputs(
+        &self,
+        transaction_payload: &TransactionPayload,
+    ) -> crate::wallet::Result<Option<Parents>> {
+        let mut parents = Vec::new();
+        {
+            let account_details = self.read().await;
+            for transaction_id in account_details.pending_parent_transactions(transaction_payload) {
+                match account_details
+                    .transactions
+                    .get(&transaction_id)
+                    .and_then(|transaction| transaction.block_id)
+                {
+                    Some(block_id) => parents.push(block_id),
+                    None => return Err(crate::wallet::Error::ParentTransactionNotSubmitted(transaction_id)),
+                }
+            }
+        }
+
+        if parents.is_empty() {
+            return Ok(None);
+        }
+
+        log::debug!("[TRANSACTION] referencing blocks of pending transactions {parents:?}");
+        let max_parents = *Parents::COUNT_RANGE.end() as usize;
+        parents.truncate(max_parents);
+        for tip in self.client.get_tips().await? {
+            if parents.len() == max_parents {
+                break;
+            }
+            if !parents.contains(&tip) {
+                parents.push(tip);
+            }
+        }
+
+        Ok(Some(Parents::from_vec(parents)?))
     }
 }
@@ -0,0 +1,172 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::{future::Future, time::Duration};
+
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "events")]
+use crate::wallet::events::types::WalletEvent;
+use crate::{
+    types::block::payload::transaction::TransactionId,
+    wallet::account::{Account, AccountHealth},
+};
+
+/// An operation of an account that is watched for stalls.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum WatchedOperation {
+    /// Syncing the account, including the pending transactions.
+    Sync,
+    /// Submitting a transaction in a block, including the proof of work.
+    Submission,
+}
+
+/// What happens with an operation that takes longer than its timeout.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", content = "value", rename_all = "camelCase")]
+pub enum StallAction {
+    /// The operation continues, only the stall is reported.
+    #[default]
+    Continue,
+    /// The operation is aborted and returns [`Error::OperationStalled`](crate::wallet::Error::OperationStalled).
+    Abort,
+    /// The operation is aborted and started again, up to the number of retries before it's aborted.
+    Retry(u32),
+}
+
+/// Timeouts after which operations of an account are considered stalled, reported with
+/// [`WalletEvent::OperationStalled`](crate::wallet::events::types::WalletEvent::OperationStalled) and handled with the
+/// [`StallAction`]. Operations are only aborted at an await point, a sync only stores its changes when it's
+/// finished.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WatchdogOptions {
+    /// The duration after which a sync is stalled, not watched by default.
+    #[serde(default)]
+    pub sync_timeout: Option<Duration>,
+    /// The duration after which the submission of a transaction is stalled, not watched by default.
+    #[serde(default)]
+    pub submission_timeout: Option<Duration>,
+    /// What happens with stalled operations.
+    #[serde(default)]
+    pub action: StallAction,
+}
+
+impl WatchdogOptions {
+    fn timeout(&self, operation: WatchedOperation) -> Option<Duration> {
+        match operation {
+            WatchedOperation::Sync => self.sync_timeout,
+            WatchedOperation::Submission => self.submission_timeout,
+        }
+    }
+}
+
+/// The state of an account when one of its operations stalled, to diagnose the cause.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StallDiagnostics {
+    /// The stalled operation.
+    pub operation: WatchedOperation,
+    /// The number of the attempt that stalled, starting at 1.
+    pub attempt: u32,
+    /// The timeout that was exceeded, in milliseconds.
+    pub timeout: u128,
+    /// The health of the account, with the result of the last finished sync.
+    pub health: AccountHealth,
+    /// The pending transactions of the account.
+    pub pending_transactions: Vec<TransactionId>,
+    /// The number of outputs that are locked in transactions or reserved.
+    pub locked_outputs: usize,
+    /// The number of unspent outputs.
+    pub unspent_outputs: usize,
+}
+
+impl Account {
+    /// Sets the timeouts after which operations of the account are considered stalled and how they are handled. The
+    /// options aren't stored and need to be set again after a restart.
+    pub async fn set_watchdog_options(&self, options: WatchdogOptions) {
+        *self.watchdog_options.lock().await = options;
+    }
+
+    /// Returns the watchdog options of the account.
+    pub async fn watchdog_options(&self) -> WatchdogOptions {
+        *self.watchdog_options.lock().await
+    }
+
+    // Runs the operation, reports it if it exceeds its timeout and aborts or retries it according to the options
+    pub(crate) async fn watch<T, F, Fut>(&self, operation: WatchedOperation, run: F) -> crate::wallet::Result<T>
+    where
+        T: Send,
+        F: Fn() -> Fut + Send + Sync,
+        Fut: Future<Output = crate::wallet::Result<T>> + Send,
+    {
+        let options = self.watchdog_options().await;
+        let Some(timeout) = options.timeout(operation) else {
+            return run().await;
+        };
+
+        let mut attempt = 1;
+        loop {
+            let future = run();
+            tokio::pin!(future);
+            tokio::select! {
+                result = &mut future => return result,
+                _ = tokio::time::sleep(timeout) => {}
+            }
+
+            let diagnostics = self.stall_diagnostics(operation, attempt, timeout).await;
+            log::warn!("[WATCHDOG] operation stalled: {diagnostics:?}");
+            #[cfg(feature = "events")]
+            self.event_emitter.lock().await.emit(
+                self.snapshot().index,
+                WalletEvent::OperationStalled(Box::new(diagnostics)),
+            );
+
+            match options.action {
+                StallAction::Continue => return future.await,
+                StallAction::Retry(retries) if attempt <= retries => {
+                    log::debug!("[WATCHDOG] retrying {operation:?}");
+                    attempt += 1;
+                }
+                StallAction::Abort | StallAction::Retry(_) => {
+                    return Err(crate::wallet::Error::OperationStalled { operation, timeout });
+                }
+            }
+        }
+    }
+
+    // The account details are read from a snapshot, because the stalled operation may hold the lock
+    async fn stall_diagnostics(
+        &self,
+        operation: WatchedOperation,
+        attempt: u32,
+        timeout: Duration,
+    ) -> StallDiagnostics {
+        let account_details = self.snapshot();
+
+        StallDiagnostics {
+            operation,
+            attempt,
+            timeout: timeout.as_millis(),
+            health: self.health().await,
+            pending_transactions: account_details.pending_transactions.iter().copied().collect(),
+            locked_outputs: account_details.locked_outputs.len(),
+            unspent_outputs: account_details.unspent_outputs.len(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn watched_operations_are_send() {
+        fn assert_send<T: Send>(_: &T) {}
+        // Only type checked, the account methods running watched operations must stay Send
+        let _ = |account: &Account| {
+            assert_send(&account.watch(WatchedOperation::Sync, || async { Ok(()) }));
+        };
+    }
+}
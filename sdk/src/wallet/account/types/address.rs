@@ -24,6 +24,26 @@ pub struct AccountAddress {
     // do we want this field? Could be useful if we don't store spent output ids and because of that wouldn't know if
     // an address was used or not just by looking at it
     pub(crate) used: bool,
+    /// Outputs of frozen addresses are excluded from input selection.
+    #[serde(default)]
+    pub(crate) frozen: bool,
+    /// An optional label to organize addresses, like "deposit", "vault" or "customer:123".
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) label: Option<String>,
+}
+
+/// The address of an alias or NFT output owned by the account. Funds sent to it can be unlocked by the account, like
+/// the ones sent to its Ed25519 addresses.
+#[derive(Debug, Getters, Clone, Serialize, Deserialize, Eq, PartialEq, Hash)]
+#[serde(rename_all = "camelCase")]
+#[getset(get = "pub")]
+pub struct ChainAddress {
+    /// The alias or NFT address.
+    pub(crate) address: Bech32Address,
+    /// The id of the unspent alias or NFT output.
+    pub(crate) output_id: OutputId,
+    /// The address of the account that owns the alias or NFT output, the state controller for aliases.
+    pub(crate) owner: Bech32Address,
 }
 
 /// An account address with unspent output_ids for unspent outputs.
@@ -0,0 +1,45 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use serde::{Deserialize, Serialize};
+
+use crate::types::block::output::{NftId, Output, OutputId, TokenId};
+
+/// An NFT or a native token, which can be quarantined when it's received, see
+/// [`Account::set_asset_quarantine()`](crate::wallet::Account::set_asset_quarantine).
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", content = "id", rename_all = "camelCase")]
+pub enum AssetId {
+    /// An NFT.
+    Nft(NftId),
+    /// A native token.
+    NativeToken(TokenId),
+}
+
+impl From<NftId> for AssetId {
+    fn from(nft_id: NftId) -> Self {
+        Self::Nft(nft_id)
+    }
+}
+
+impl From<TokenId> for AssetId {
+    fn from(token_id: TokenId) -> Self {
+        Self::NativeToken(token_id)
+    }
+}
+
+// Returns the NFT and the native tokens of an output
+pub(crate) fn assets_in_output(output_id: &OutputId, output: &Output) -> Vec<AssetId> {
+    let mut assets = Vec::new();
+    if let Output::Nft(nft_output) = output {
+        assets.push(AssetId::Nft(nft_output.nft_id_non_null(output_id)));
+    }
+    if let Some(native_tokens) = output.native_tokens() {
+        assets.extend(
+            native_tokens
+                .iter()
+                .map(|native_token| AssetId::NativeToken(*native_token.token_id())),
+        );
+    }
+    assets
+}
@@ -9,7 +9,11 @@ use serde::{Deserialize, Serialize};
 
 use crate::types::block::{
     dto::U256Dto,
-    output::{dto::TokenIdDto, feature::MetadataFeature, AliasId, FoundryId, NftId, OutputId, TokenId},
+    output::{
+        dto::TokenIdDto,
+        feature::{Irc30Metadata, MetadataFeature},
+        AliasId, FoundryId, NativeToken, NftId, OutputId, TokenId,
+    },
 };
 
 /// The balance of an account, returned from [`crate::wallet::account::Account::sync()`] and
@@ -54,9 +58,9 @@ impl std::ops::AddAssign for AccountBalance {
             }
         }
 
-        self.nfts.extend(rhs.nfts.into_iter());
-        self.aliases.extend(rhs.aliases.into_iter());
-        self.foundries.extend(rhs.foundries.into_iter());
+        self.nfts.extend(rhs.nfts);
+        self.aliases.extend(rhs.aliases);
+        self.foundries.extend(rhs.foundries);
     }
 }
 
@@ -111,6 +115,11 @@ pub struct BaseCoinBalance {
     pub(crate) total: u64,
     /// Balance that can currently be spent
     pub(crate) available: u64,
+    /// Amount of outputs with a timelock that didn't elapse yet, which can be spent after it, not included in the total
+    pub(crate) locked: u64,
+    /// Amount of outputs with an expiration that didn't pass yet, which can only be spent until then, not included in
+    /// the total
+    pub(crate) expiring_soon: u64,
     /// Voting power
     #[cfg(feature = "participation")]
     pub(crate) voting_power: u64,
@@ -120,6 +129,8 @@ impl std::ops::AddAssign for BaseCoinBalance {
     fn add_assign(&mut self, rhs: Self) {
         self.total += rhs.total;
         self.available += rhs.available;
+        self.locked += rhs.locked;
+        self.expiring_soon += rhs.expiring_soon;
         #[cfg(feature = "participation")]
         {
             self.voting_power += rhs.voting_power;
@@ -135,6 +146,11 @@ pub struct BaseCoinBalanceDto {
     pub total: String,
     /// Balance that can currently be spent
     pub available: String,
+    /// Amount of outputs with a timelock that didn't elapse yet, which can be spent after it, not included in the total
+    pub locked: String,
+    /// Amount of outputs with an expiration that didn't pass yet, which can only be spent until then, not included in
+    /// the total
+    pub expiring_soon: String,
     /// Voting power
     #[cfg(feature = "participation")]
     pub voting_power: String,
@@ -145,6 +161,8 @@ impl From<&BaseCoinBalance> for BaseCoinBalanceDto {
         Self {
             total: value.total.to_string(),
             available: value.available.to_string(),
+            locked: value.locked.to_string(),
+            expiring_soon: value.expiring_soon.to_string(),
             #[cfg(feature = "participation")]
             voting_power: value.voting_power.to_string(),
         }
@@ -199,6 +217,10 @@ pub struct NativeTokensBalance {
     /// Token foundry immutable metadata
     #[getset(get = "pub")]
     pub(crate) metadata: Option<MetadataFeature>,
+    /// Token metadata decoded following the IRC30 standard, not set if the metadata doesn't follow it
+    #[serde(default)]
+    #[getset(get = "pub")]
+    pub(crate) irc_30_metadata: Option<Irc30Metadata>,
     /// Total amount
     #[getset(get_copy = "pub")]
     pub(crate) total: U256,
@@ -212,6 +234,7 @@ impl Default for NativeTokensBalance {
         Self {
             token_id: TokenId::null(),
             metadata: None,
+            irc_30_metadata: None,
             total: U256::from(0u8),
             available: U256::from(0u8),
         }
@@ -223,6 +246,9 @@ impl std::ops::AddAssign for NativeTokensBalance {
         if self.metadata.is_none() {
             self.metadata = rhs.metadata;
         }
+        if self.irc_30_metadata.is_none() {
+            self.irc_30_metadata = rhs.irc_30_metadata;
+        }
         self.total += rhs.total;
         self.available += rhs.available;
     }
@@ -236,6 +262,8 @@ pub struct NativeTokensBalanceDto {
     pub token_id: TokenIdDto,
     /// Token foundry immutable metadata
     pub metadata: Option<String>,
+    /// Token metadata decoded following the IRC30 standard
+    pub irc_30_metadata: Option<Irc30Metadata>,
     /// Total amount
     pub total: U256Dto,
     /// Balance that can currently be spent
@@ -247,8 +275,28 @@ impl From<&NativeTokensBalance> for NativeTokensBalanceDto {
         Self {
             token_id: TokenIdDto::from(&value.token_id),
             metadata: value.metadata.as_ref().map(|m| prefix_hex::encode(m.data())),
+            irc_30_metadata: value.irc_30_metadata.clone(),
             total: U256Dto::from(&value.total),
             available: U256Dto::from(&value.available),
         }
     }
 }
+
+/// The balance of an account at a past point in time, returned from
+/// [`crate::wallet::account::Account::balance_at()`].
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize, Getters, CopyGetters)]
+#[serde(rename_all = "camelCase")]
+pub struct HistoricalBalance {
+    /// The unix timestamp in seconds the balance was reconstructed for
+    #[getset(get_copy = "pub")]
+    pub(crate) timestamp: u32,
+    /// Amount of the base coin that could be claimed at the timestamp
+    #[getset(get_copy = "pub")]
+    pub(crate) base_coin: u64,
+    /// Native tokens
+    #[getset(get = "pub")]
+    pub(crate) native_tokens: Vec<NativeToken>,
+    /// The outputs that were unspent and owned by the account at the timestamp
+    #[getset(get = "pub")]
+    pub(crate) outputs: Vec<OutputId>,
+}
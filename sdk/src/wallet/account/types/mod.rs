@@ -3,9 +3,11 @@
 
 /// Address types used in the account
 pub(crate) mod address;
+pub(crate) mod asset;
 pub(crate) mod balance;
 #[cfg(feature = "participation")]
 pub mod participation;
+pub(crate) mod payment_reference;
 
 use std::str::FromStr;
 
@@ -13,11 +15,13 @@ use crypto::keys::slip10::Chain;
 use serde::{de, Deserialize, Deserializer, Serialize};
 
 pub use self::{
-    address::{AccountAddress, AddressWithUnspentOutputs},
+    address::{AccountAddress, AddressWithUnspentOutputs, ChainAddress},
+    asset::AssetId,
     balance::{
-        AccountBalance, AccountBalanceDto, BaseCoinBalance, BaseCoinBalanceDto, NativeTokensBalance,
-        NativeTokensBalanceDto, RequiredStorageDeposit,
+        AccountBalance, AccountBalanceDto, BaseCoinBalance, BaseCoinBalanceDto, HistoricalBalance,
+        NativeTokensBalance, NativeTokensBalanceDto, RequiredStorageDeposit,
     },
+    payment_reference::PaymentReference,
 };
 use crate::{
     client::secret::types::InputSigningData,
@@ -54,6 +58,7 @@ pub struct OutputData {
     pub network_id: u64,
     pub remainder: bool,
     // bip32 path
+    #[serde(default, with = "crate::client::secret::types::chain_serde::option")]
     pub chain: Option<Chain>,
 }
 
@@ -134,6 +139,7 @@ pub struct OutputDataDto {
     /// Remainder
     pub remainder: bool,
     /// Bip32 path
+    #[serde(default, with = "crate::client::secret::types::chain_serde::option")]
     pub chain: Option<Chain>,
 }
 
@@ -172,6 +178,78 @@ pub struct Transaction {
     // serde(default) is needed so it doesn't break with old dbs
     #[serde(default)]
     pub inputs: Vec<OutputWithMetadataResponse>,
+    /// The index of the milestone that confirmed the transaction, set once it's confirmed.
+    #[serde(default)]
+    pub milestone_index: Option<u32>,
+    /// The unix timestamp in milliseconds at which the wallet noticed the confirmation of the pending transaction.
+    #[serde(default)]
+    pub confirmed_at: Option<u128>,
+    /// The blocks the transaction was attached with, the first one and all reattachments, in submission order.
+    #[serde(default)]
+    pub attachments: Vec<BlockAttachment>,
+}
+
+impl Transaction {
+    /// Returns the number of milestones that confirmed the transaction, counting the one that included it, or `None`
+    /// if the transaction isn't confirmed yet.
+    pub fn confirmations(&self, latest_milestone_index: u32) -> Option<u32> {
+        if self.inclusion_state != InclusionState::Confirmed {
+            return None;
+        }
+        self.milestone_index
+            .and_then(|milestone_index| latest_milestone_index.checked_sub(milestone_index))
+            .map(|milestones_since_inclusion| milestones_since_inclusion + 1)
+    }
+
+    /// Records a block the transaction was attached with and sets it as the current block id.
+    pub(crate) fn add_attachment(&mut self, attachment: BlockAttachment) {
+        self.block_id = Some(attachment.block_id);
+        self.attachments.push(attachment);
+    }
+
+    /// Returns the number of times the transaction was reattached after its first submission.
+    pub fn reattachment_count(&self) -> usize {
+        self.attachments.len().saturating_sub(1)
+    }
+}
+
+/// A block a transaction was attached with.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BlockAttachment {
+    /// The id of the block.
+    pub block_id: BlockId,
+    /// The parents the block was built with.
+    pub parents: Vec<BlockId>,
+    /// The duration of the local PoW in milliseconds, `None` if the PoW was done by the node.
+    pub pow_duration: Option<u128>,
+    /// The unix timestamp in milliseconds at which the block was submitted.
+    pub timestamp: u128,
+}
+
+/// Dto for a block a transaction was attached with
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BlockAttachmentDto {
+    /// The id of the block
+    pub block_id: BlockId,
+    /// The parents the block was built with
+    pub parents: Vec<BlockId>,
+    /// The duration of the local PoW in milliseconds
+    pub pow_duration: Option<String>,
+    /// Timestamp at which the block was submitted
+    pub timestamp: String,
+}
+
+impl From<&BlockAttachment> for BlockAttachmentDto {
+    fn from(value: &BlockAttachment) -> Self {
+        Self {
+            block_id: value.block_id,
+            parents: value.parents.clone(),
+            pow_duration: value.pow_duration.map(|pow_duration| pow_duration.to_string()),
+            timestamp: value.timestamp.to_string(),
+        }
+    }
 }
 
 /// Dto for a transaction with metadata
@@ -193,6 +271,12 @@ pub struct TransactionDto {
     pub incoming: bool,
     pub note: Option<String>,
     pub inputs: Vec<OutputWithMetadataResponse>,
+    /// The index of the milestone that confirmed the transaction
+    pub milestone_index: Option<u32>,
+    /// Timestamp at which the wallet noticed the confirmation
+    pub confirmed_at: Option<String>,
+    /// The blocks the transaction was attached with
+    pub attachments: Vec<BlockAttachmentDto>,
 }
 
 impl From<&Transaction> for TransactionDto {
@@ -207,8 +291,38 @@ impl From<&Transaction> for TransactionDto {
             incoming: value.incoming,
             note: value.note.clone(),
             inputs: value.inputs.clone(),
+            milestone_index: value.milestone_index,
+            confirmed_at: value.confirmed_at.map(|confirmed_at| confirmed_at.to_string()),
+            attachments: value.attachments.iter().map(BlockAttachmentDto::from).collect(),
+        }
+    }
+}
+
+/// A local note attached to an output, e.g. the invoice id of a deposit, to simplify reconciliation.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OutputNote {
+    /// Free text note.
+    pub note: Option<String>,
+    /// References to documents like invoices or receipts, e.g. file paths, URLs or hashes.
+    #[serde(default)]
+    pub attachments: Vec<String>,
+}
+
+impl OutputNote {
+    /// Creates a new [`OutputNote`] with a text note.
+    pub fn new(note: impl Into<String>) -> Self {
+        Self {
+            note: Some(note.into()),
+            attachments: Vec::new(),
         }
     }
+
+    /// Adds a document reference to the [`OutputNote`].
+    pub fn with_attachment(mut self, attachment: impl Into<String>) -> Self {
+        self.attachments.push(attachment.into());
+        self
+    }
 }
 
 /// Possible InclusionStates for transactions
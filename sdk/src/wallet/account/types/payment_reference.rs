@@ -0,0 +1,167 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::{fmt, str::FromStr};
+
+use crypto::hashes::{blake2b::Blake2b256, Digest};
+use serde::{Deserialize, Serialize};
+
+use crate::types::block::{output::feature::TagFeature, payload::TaggedDataPayload};
+#[cfg(feature = "events")]
+use crate::types::block::{
+    output::Output,
+    payload::{
+        transaction::{TransactionEssence, TransactionPayload},
+        Payload,
+    },
+};
+
+const PREFIX: &str = "RF";
+const ALPHABET: &[u8; 36] = b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+/// A structured payment reference with check digits, following the ISO 11649 creditor reference format:
+/// `RF` + 2 check digits + up to 21 alphanumeric characters, e.g. `RF18539007547034`.
+/// The check digits allow to detect typos, so a payment can be matched to an invoice reliably.
+#[derive(Clone, Debug, Eq, PartialEq, Hash, Ord, PartialOrd, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct PaymentReference(String);
+
+impl PaymentReference {
+    /// Max length of the reference without the prefix and check digits.
+    pub const REFERENCE_LENGTH_MAX: usize = 21;
+
+    /// Creates a [`PaymentReference`] from an alphanumeric reference, e.g. an invoice number, by calculating the
+    /// check digits for it.
+    pub fn new(reference: &str) -> crate::wallet::Result<Self> {
+        let reference = normalize(reference);
+        if reference.is_empty()
+            || reference.len() > Self::REFERENCE_LENGTH_MAX
+            || !reference.chars().all(|c| c.is_ascii_alphanumeric())
+        {
+            return Err(crate::wallet::Error::InvalidPaymentReference(reference));
+        }
+        let check_digits = 98 - mod97(reference.chars().chain(PREFIX.chars()).chain("00".chars()));
+
+        Ok(Self(format!("{PREFIX}{check_digits:02}{reference}")))
+    }
+
+    /// Deterministically generates a [`PaymentReference`] from arbitrary data, e.g. an account index and an invoice
+    /// counter, so the same reference can be derived again without storing it.
+    pub fn generate(data: impl AsRef<[u8]>) -> Self {
+        let reference = Blake2b256::digest(data.as_ref())
+            .iter()
+            .take(Self::REFERENCE_LENGTH_MAX)
+            .map(|byte| ALPHABET[*byte as usize % ALPHABET.len()] as char)
+            .collect::<String>();
+        // Can't fail, the reference is always alphanumeric and has the max length
+        Self::new(&reference).expect("valid generated reference")
+    }
+
+    /// Returns the reference without prefix and check digits.
+    pub fn reference(&self) -> &str {
+        &self.0[4..]
+    }
+
+    /// Returns the reference as [`TagFeature`], to embed it in an output.
+    pub fn to_tag_feature(&self) -> crate::wallet::Result<TagFeature> {
+        Ok(TagFeature::new(self.0.as_bytes().to_vec())?)
+    }
+
+    /// Returns the reference as [`TaggedDataPayload`] with the reference as tag, to embed it in a transaction.
+    pub fn to_tagged_data_payload(&self) -> crate::wallet::Result<TaggedDataPayload> {
+        Ok(TaggedDataPayload::new(self.0.as_bytes().to_vec(), Vec::new())?)
+    }
+
+    /// Tries to parse a [`PaymentReference`] from bytes, as found in tags or metadata.
+    pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        Self::from_str(std::str::from_utf8(bytes).ok()?).ok()
+    }
+}
+
+impl FromStr for PaymentReference {
+    type Err = crate::wallet::Error;
+
+    /// Parses and verifies a [`PaymentReference`], spaces are ignored and lowercase letters are accepted.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let normalized = normalize(s);
+        match normalized.strip_prefix(PREFIX) {
+            Some(rest) if rest.len() > 2 && rest.get(..2).map_or(false, |d| d.chars().all(|c| c.is_ascii_digit())) => {
+                let payment_reference = Self::new(&rest[2..])?;
+                if payment_reference.0 == normalized {
+                    Ok(payment_reference)
+                } else {
+                    Err(crate::wallet::Error::InvalidPaymentReference(normalized))
+                }
+            }
+            _ => Err(crate::wallet::Error::InvalidPaymentReference(normalized)),
+        }
+    }
+}
+
+impl TryFrom<String> for PaymentReference {
+    type Error = crate::wallet::Error;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Self::from_str(&value)
+    }
+}
+
+impl From<PaymentReference> for String {
+    fn from(value: PaymentReference) -> Self {
+        value.0
+    }
+}
+
+impl fmt::Display for PaymentReference {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// Returns the payment references found in the tag and metadata features of an output and in the tagged data payload
+/// of the transaction that created it.
+#[cfg(feature = "events")]
+pub(crate) fn payment_references_in_output(
+    output: &Output,
+    transaction_payload: Option<&TransactionPayload>,
+) -> Vec<PaymentReference> {
+    let mut payment_references = Vec::new();
+
+    if let Some(features) = output.features() {
+        if let Some(tag) = features.tag() {
+            payment_references.extend(PaymentReference::from_bytes(tag.tag()));
+        }
+        if let Some(metadata) = features.metadata() {
+            payment_references.extend(PaymentReference::from_bytes(metadata.data()));
+        }
+    }
+
+    if let Some(Payload::TaggedData(tagged_data)) = transaction_payload.and_then(|tx| {
+        let TransactionEssence::Regular(essence) = tx.essence();
+        essence.payload()
+    }) {
+        payment_references.extend(PaymentReference::from_bytes(tagged_data.tag()));
+        payment_references.extend(PaymentReference::from_bytes(tagged_data.data()));
+    }
+
+    payment_references.dedup();
+    payment_references
+}
+
+// Uppercase and remove whitespace, references are often printed in groups of four characters
+fn normalize(reference: &str) -> String {
+    reference
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .map(|c| c.to_ascii_uppercase())
+        .collect()
+}
+
+// ISO 7064 mod 97-10, letters are converted to numbers with A = 10 ... Z = 35
+fn mod97(chars: impl Iterator<Item = char>) -> u32 {
+    chars.fold(0, |remainder, c| match c.to_digit(36) {
+        Some(value) if value < 10 => (remainder * 10 + value) % 97,
+        Some(value) => (remainder * 100 + value) % 97,
+        None => remainder,
+    })
+}
@@ -1,29 +1,55 @@
 // Copyright 2022 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use std::collections::HashMap;
+use std::{
+    collections::{HashMap, HashSet},
+    sync::Arc,
+};
+
+use futures::future::OptionFuture;
 
 use crate::{
     client::Client,
-    types::block::output::{dto::OutputMetadataDto, OutputId},
+    types::block::{
+        address::Bech32Address,
+        output::{dto::OutputMetadataDto, OutputId},
+    },
     wallet::account::{
         operations::syncing::options::SyncOptions,
-        types::{address::AddressWithUnspentOutputs, InclusionState, OutputData, Transaction},
-        Account, AccountAddress,
+        types::{
+            address::AddressWithUnspentOutputs, asset::assets_in_output, AssetId, InclusionState, OutputData,
+            OutputNote, PaymentReference, Transaction,
+        },
+        Account, AccountAddress, OutputDefaults,
     },
+    utils::unix_timestamp_now,
 };
 #[cfg(feature = "events")]
 use crate::{
     types::{api::core::response::OutputWithMetadataResponse, block::payload::transaction::dto::TransactionPayloadDto},
     wallet::{
-        account::types::OutputDataDto,
-        events::types::{NewOutputEvent, SpentOutputEvent, TransactionInclusionEvent, WalletEvent},
+        account::types::{payment_reference::payment_references_in_output, OutputDataDto},
+        events::types::{
+            AddressFreezeEvent, NewOutputEvent, ReferenceMatchedEvent, SpentOutputEvent, TransactionInclusionEvent,
+            WalletEvent,
+        },
     },
 };
 
 impl Account {
-    /// Set the alias for the account
+    /// Set the alias for the account, it needs to be unique within the wallet, regardless of the case
     pub async fn set_alias(&self, alias: &str) -> crate::wallet::Result<()> {
+        // The accounts are locked until the alias is set, so concurrent calls can't set the same alias for two accounts
+        let wallet_accounts = self.wallet_accounts.upgrade();
+        let accounts = OptionFuture::from(wallet_accounts.as_ref().map(|accounts| accounts.write())).await;
+        for account in accounts.iter().flat_map(|accounts| accounts.iter()) {
+            if !Arc::ptr_eq(&account.details, &self.details)
+                && account.read().await.alias().to_lowercase() == alias.to_lowercase()
+            {
+                return Err(crate::wallet::Error::AccountAliasAlreadyExists(alias.to_string()));
+            }
+        }
+
         let mut account_details = self.write().await;
         account_details.alias = alias.to_string();
         #[cfg(feature = "storage")]
@@ -31,7 +57,206 @@ impl Account {
         Ok(())
     }
 
-    /// Update account with newly synced data and emit events for outputs
+    /// Set or remove the label of an address of the account
+    pub async fn set_address_label(
+        &self,
+        address: &Bech32Address,
+        label: impl Into<Option<String>> + Send,
+    ) -> crate::wallet::Result<()> {
+        let mut account_details = self.write().await;
+        let details = &mut *account_details;
+        let account_address = details
+            .public_addresses
+            .iter_mut()
+            .chain(details.internal_addresses.iter_mut())
+            .find(|a| &a.address == address)
+            .ok_or_else(|| crate::wallet::Error::AddressNotFoundInAccount(address.to_string()))?;
+        account_address.label = label.into();
+        #[cfg(feature = "storage")]
+        self.save(Some(&account_details)).await?;
+        Ok(())
+    }
+
+    /// Attach a note to an output of the account, replacing an existing one
+    pub async fn set_output_note(&self, output_id: OutputId, note: OutputNote) -> crate::wallet::Result<()> {
+        let mut account_details = self.write().await;
+        if !account_details.outputs.contains_key(&output_id) {
+            return Err(crate::wallet::Error::OutputNotFoundInAccount(output_id));
+        }
+        account_details.output_notes.insert(output_id, note);
+        #[cfg(feature = "storage")]
+        self.save(Some(&account_details)).await?;
+        Ok(())
+    }
+
+    /// Remove the note of an output, returns the removed note
+    pub async fn remove_output_note(&self, output_id: &OutputId) -> crate::wallet::Result<Option<OutputNote>> {
+        let mut account_details = self.write().await;
+        let note = account_details.output_notes.remove(output_id);
+        #[cfg(feature = "storage")]
+        if note.is_some() {
+            self.save(Some(&account_details)).await?;
+        }
+        Ok(note)
+    }
+
+    /// Stores a value of an integration with the account, replacing the value of an existing key. The metadata is
+    /// saved and backed up with the account, keys can be namespaced with a prefix, e.g. `kyc/reference`, so
+    /// integrations don't overwrite each other.
+    pub async fn set_metadata(&self, key: impl Into<String> + Send, value: Vec<u8>) -> crate::wallet::Result<()> {
+        let mut account_details = self.write().await;
+        account_details.metadata.insert(key.into(), value);
+        #[cfg(feature = "storage")]
+        self.save(Some(&account_details)).await?;
+        Ok(())
+    }
+
+    /// Returns the value stored with [`Account::set_metadata()`] for the key
+    pub async fn get_metadata(&self, key: &str) -> Option<Vec<u8>> {
+        self.snapshot().metadata.get(key).cloned()
+    }
+
+    /// Returns the sorted metadata keys that start with the prefix, all keys for an empty prefix
+    pub async fn metadata_keys(&self, prefix: &str) -> Vec<String> {
+        let mut keys = self
+            .read()
+            .await
+            .metadata
+            .keys()
+            .filter(|key| key.starts_with(prefix))
+            .cloned()
+            .collect::<Vec<_>>();
+        keys.sort();
+        keys
+    }
+
+    /// Removes the value of the key from the metadata, returns the removed value
+    pub async fn remove_metadata(&self, key: &str) -> crate::wallet::Result<Option<Vec<u8>>> {
+        let mut account_details = self.write().await;
+        let value = account_details.metadata.remove(key);
+        #[cfg(feature = "storage")]
+        if value.is_some() {
+            self.save(Some(&account_details)).await?;
+        }
+        Ok(value)
+    }
+
+    /// Sets the defaults for outputs of [`Account::prepare_output()`] to addresses that can't cover the storage deposit,
+    /// the storage deposit options and unlocks of an output override them
+    pub async fn set_output_defaults(&self, output_defaults: OutputDefaults) -> crate::wallet::Result<()> {
+        let mut account_details = self.write().await;
+        account_details.output_defaults = output_defaults;
+        #[cfg(feature = "storage")]
+        self.save(Some(&account_details)).await?;
+        Ok(())
+    }
+
+    /// Returns the defaults for outputs set with [`Account::set_output_defaults()`]
+    pub async fn output_defaults(&self) -> OutputDefaults {
+        self.snapshot().output_defaults
+    }
+
+    /// Add a payment reference the account is waiting for, a `ReferenceMatched` event will be emitted when an
+    /// incoming output contains it
+    pub async fn add_expected_payment_reference(&self, reference: PaymentReference) -> crate::wallet::Result<()> {
+        let mut account_details = self.write().await;
+        account_details.expected_payment_references.insert(reference);
+        #[cfg(feature = "storage")]
+        self.save(Some(&account_details)).await?;
+        Ok(())
+    }
+
+    /// Remove an expected payment reference, returns true if it was expected
+    pub async fn remove_expected_payment_reference(
+        &self,
+        reference: &PaymentReference,
+    ) -> crate::wallet::Result<bool> {
+        let mut account_details = self.write().await;
+        let removed = account_details.expected_payment_references.remove(reference);
+        #[cfg(feature = "storage")]
+        if removed {
+            self.save(Some(&account_details)).await?;
+        }
+        Ok(removed)
+    }
+
+    /// Enables or disables the quarantine of received assets. While it's enabled, received NFTs and native tokens that
+    /// weren't accepted before are quarantined until they're accepted with [`Account::accept_asset()`], outputs
+    /// holding them aren't part of the balance and can't be spent. The assets the account holds when the quarantine is
+    /// enabled and the ones of outputs created by its own transactions are accepted.
+    pub async fn set_asset_quarantine(&self, enabled: bool) -> crate::wallet::Result<()> {
+        let mut account_details = self.write().await;
+        if enabled && !account_details.asset_quarantine {
+            let held_assets = account_details
+                .unspent_outputs
+                .values()
+                .flat_map(|output_data| assets_in_output(&output_data.output_id, &output_data.output))
+                .filter(|asset| !account_details.quarantined_assets.contains(asset))
+                .collect::<Vec<_>>();
+            account_details.accepted_assets.extend(held_assets);
+        }
+        account_details.asset_quarantine = enabled;
+        #[cfg(feature = "storage")]
+        self.save(Some(&account_details)).await?;
+        Ok(())
+    }
+
+    /// Returns the quarantined assets, which need to be accepted before they're part of the balance and can be spent
+    pub async fn quarantined_assets(&self) -> HashSet<AssetId> {
+        self.snapshot().quarantined_assets().clone()
+    }
+
+    /// Accepts an asset, so it's released from the quarantine and isn't quarantined when it's received again
+    pub async fn accept_asset(&self, asset: impl Into<AssetId> + Send) -> crate::wallet::Result<()> {
+        let asset = asset.into();
+        let mut account_details = self.write().await;
+        account_details.quarantined_assets.remove(&asset);
+        account_details.accepted_assets.insert(asset);
+        #[cfg(feature = "storage")]
+        self.save(Some(&account_details)).await?;
+        Ok(())
+    }
+
+    /// Freeze an address of the account, its outputs will be excluded from input selection until it gets unfrozen
+    pub async fn freeze_address(&self, address: &Bech32Address) -> crate::wallet::Result<()> {
+        self.set_address_frozen(address, true).await
+    }
+
+    /// Unfreeze an address of the account, so its outputs can be used as inputs again
+    pub async fn unfreeze_address(&self, address: &Bech32Address) -> crate::wallet::Result<()> {
+        self.set_address_frozen(address, false).await
+    }
+
+    async fn set_address_frozen(&self, address: &Bech32Address, frozen: bool) -> crate::wallet::Result<()> {
+        let mut account_details = self.write().await;
+        let details = &mut *account_details;
+        let account_address = details
+            .public_addresses
+            .iter_mut()
+            .chain(details.internal_addresses.iter_mut())
+            .find(|a| &a.address == address)
+            .ok_or_else(|| crate::wallet::Error::AddressNotFoundInAccount(address.to_string()))?;
+        if account_address.frozen == frozen {
+            return Ok(());
+        }
+        account_address.frozen = frozen;
+        #[cfg(feature = "storage")]
+        self.save(Some(&account_details)).await?;
+
+        #[cfg(feature = "events")]
+        self.event_emitter.lock().await.emit(
+            account_details.index,
+            WalletEvent::AddressFreeze(AddressFreezeEvent {
+                address: address.clone(),
+                frozen,
+            }),
+        );
+
+        Ok(())
+    }
+
+    /// Update account with newly synced data and emit events for outputs, the account isn't saved
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip_all))]
     pub(crate) async fn update_account(
         &self,
         addresses_with_unspent_outputs: Vec<AddressWithUnspentOutputs>,
@@ -135,12 +360,25 @@ impl Account {
 
         // Add new synced outputs
         for output_data in unspent_outputs {
+            // A pending transaction from another account of the wallet is confirmed once its outputs are synced
+            if let Some(transaction) = account_details
+                .incoming_transactions
+                .get_mut(output_data.output_id.transaction_id())
+            {
+                if transaction.inclusion_state == InclusionState::Pending {
+                    transaction.inclusion_state = InclusionState::Confirmed;
+                    transaction.block_id = Some(*output_data.metadata.block_id());
+                    transaction.milestone_index = Some(output_data.metadata.milestone_index_booked());
+                    transaction.confirmed_at = Some(unix_timestamp_now().as_millis());
+                }
+            }
             // Insert output, if it's unknown emit the NewOutputEvent
             if account_details
                 .outputs
                 .insert(output_data.output_id, output_data.clone())
                 .is_none()
             {
+                account_details.quarantine_new_assets(&output_data);
                 #[cfg(feature = "events")]
                 {
                     let transaction = account_details
@@ -160,6 +398,24 @@ impl Account {
                             }),
                         })),
                     );
+                    for reference in
+                        payment_references_in_output(&output_data.output, transaction.map(|tx| &tx.payload))
+                    {
+                        if account_details.expected_payment_references.contains(&reference) {
+                            log::debug!(
+                                "[SYNC] output {} matched payment reference {reference}",
+                                output_data.output_id
+                            );
+                            self.event_emitter.lock().await.emit(
+                                account_index,
+                                WalletEvent::ReferenceMatched(ReferenceMatchedEvent {
+                                    reference,
+                                    output_id: output_data.output_id,
+                                    amount: output_data.output.amount(),
+                                }),
+                            );
+                        }
+                    }
                 }
             };
             if !output_data.is_spent {
@@ -169,18 +425,11 @@ impl Account {
             }
         }
 
-        #[cfg(feature = "storage")]
-        {
-            log::debug!(
-                "[SYNC] storing account {} with new synced data",
-                account_details.alias()
-            );
-            self.save(Some(&account_details)).await?;
-        }
+        // The account is saved once at the end of the sync
         Ok(())
     }
 
-    /// Update account with newly synced transactions
+    /// Update account with newly synced transactions, the account isn't saved
     pub(crate) async fn update_account_with_transactions(
         &self,
         updated_transactions: Vec<Transaction>,
@@ -235,14 +484,7 @@ impl Account {
             );
         }
 
-        #[cfg(feature = "storage")]
-        {
-            log::debug!(
-                "[SYNC] storing account {} with new synced transactions",
-                account_details.alias()
-            );
-            self.save(Some(&account_details)).await?;
-        }
+        // The account is saved once at the end of the sync
         Ok(())
     }
 
@@ -256,12 +498,18 @@ impl Account {
 
         let mut account_details = self.write().await;
 
-        // add addresses to the account
-        if internal {
-            account_details.internal_addresses.extend(new_addresses);
+        // add addresses to the account, addresses with an explicit start index can already exist or leave a gap
+        let addresses = if internal {
+            &mut account_details.internal_addresses
         } else {
-            account_details.public_addresses.extend(new_addresses);
+            &mut account_details.public_addresses
         };
+        for new_address in new_addresses {
+            if !addresses.iter().any(|address| address.key_index == new_address.key_index) {
+                addresses.push(new_address);
+            }
+        }
+        addresses.sort_by_key(|address| address.key_index);
 
         #[cfg(feature = "storage")]
         {
@@ -271,9 +519,43 @@ impl Account {
         Ok(())
     }
 
+    /// Update account with a pending transaction from another account of the wallet, until its outputs are synced
+    pub(crate) async fn update_account_with_incoming_transaction(
+        &self,
+        transaction: &Transaction,
+    ) -> crate::wallet::Result<()> {
+        log::debug!(
+            "[update_account_with_incoming_transaction] {}",
+            transaction.transaction_id
+        );
+
+        let mut account_details = self.write().await;
+        account_details.incoming_transactions.insert(
+            transaction.transaction_id,
+            Transaction {
+                inclusion_state: InclusionState::Pending,
+                incoming: true,
+                ..transaction.clone()
+            },
+        );
+
+        #[cfg(feature = "storage")]
+        {
+            log::debug!(
+                "[update_account_with_incoming_transaction] storing account {}",
+                account_details.index()
+            );
+            self.save(Some(&account_details)).await?;
+        }
+        Ok(())
+    }
+
     // Should only be called from the Wallet so all accounts are on the same state
     // Will update the addresses with a possible new Bech32 HRP and clear the inaccessible_incoming_transactions.
     pub(crate) async fn update_account_with_new_client(&mut self, client: Client) -> crate::wallet::Result<()> {
+        // The account gets saved below, so evicted details need to be loaded first to not overwrite them
+        #[cfg(feature = "storage")]
+        self.load_details().await?;
         self.client = client;
         let bech32_hrp = self.client.get_bech32_hrp().await?;
         log::debug!("[UPDATE ACCOUNT WITH NEW CLIENT] new bech32_hrp: {}", bech32_hrp);
@@ -3,12 +3,19 @@
 
 use std::fmt::Debug;
 
+use primitive_types::U256;
 use serde::{
     ser::{SerializeMap, Serializer},
     Serialize,
 };
 
-use crate::types::block::payload::transaction::TransactionId;
+use crate::{
+    client::ErrorContext,
+    types::block::{
+        output::{OutputId, TokenId},
+        payload::transaction::TransactionId,
+    },
+};
 
 /// The wallet error type.
 #[derive(Debug, thiserror::Error)]
@@ -16,24 +23,30 @@ pub enum Error {
     /// Account alias must be unique.
     #[error("can't create account: account alias {0} already exists")]
     AccountAliasAlreadyExists(String),
+    /// Account can't be removed, because it has unspent outputs
+    #[error("account {0} can't be removed, it has unspent outputs")]
+    AccountHasFunds(u32),
     /// Account not found
     #[error("account {0} not found")]
     AccountNotFound(String),
     /// Address not found in account
     #[error("address {0} not found in account")]
     AddressNotFoundInAccount(String),
+    /// A generated address already received funds and the account refuses to reuse addresses
+    #[error("address {0} already received funds")]
+    AddressReused(String),
     /// Errors during backup creation or restoring
     #[error("backup failed {0}")]
     Backup(&'static str),
     /// Error from block crate.
     #[error("{0}")]
-    Block(Box<crate::types::block::Error>),
+    Block(#[source] Box<crate::types::block::Error>),
     /// Burning or melting failed
     #[error("burning or melting failed: {0}")]
     BurningOrMeltingFailed(String),
     /// Client error.
     #[error("`{0}`")]
-    Client(Box<crate::client::Error>),
+    Client(#[source] Box<crate::client::Error>),
     /// Funds are spread over too many outputs
     #[error("funds are spread over too many outputs {output_count}/{output_count_max}, consolidation required")]
     ConsolidationRequired { output_count: usize, output_count_max: u16 },
@@ -49,6 +62,19 @@ pub enum Error {
     /// Insufficient funds to send transaction.
     #[error("insufficient funds {available}/{required} available")]
     InsufficientFunds { available: u64, required: u64 },
+    /// Insufficient native tokens to send transaction.
+    #[error("insufficient native token {token_id} {available}/{required} available")]
+    InsufficientNativeTokens {
+        token_id: TokenId,
+        available: U256,
+        required: U256,
+    },
+    /// Invalid age attestation
+    #[error("invalid age attestation: {0}")]
+    InvalidAgeAttestation(String),
+    /// The address of an ISC chain isn't an alias address
+    #[error("invalid chain address {0}, chains have alias addresses")]
+    InvalidChainAddress(String),
     /// Invalid coin type, all accounts need to have the same coin type
     #[error("invalid coin type for new account: {new_coin_type}, existing coin type is: {existing_coin_type}")]
     InvalidCoinType {
@@ -58,15 +84,40 @@ pub enum Error {
     /// Invalid mnemonic error
     #[error("invalid mnemonic: {0}")]
     InvalidMnemonic(String),
+    /// Address of another network than the one the client is connected to
+    #[error("invalid network address: expected bech32 HRP {expected}, found {found}")]
+    InvalidNetworkAddress { expected: String, found: String },
     /// Invalid output kind.
     #[error("invalid output kind: {0}")]
     InvalidOutputKind(String),
+    /// Invalid output options
+    #[error("invalid output options: {0}")]
+    InvalidOutputOptions(String),
+    /// Invalid ownership proof
+    #[error("invalid ownership proof: {0}")]
+    InvalidOwnershipProof(String),
+    /// Invalid payment reference
+    #[error("invalid payment reference: {0}")]
+    InvalidPaymentReference(String),
+    /// Invalid payment URI
+    #[error("invalid payment URI: {0}")]
+    InvalidPaymentUri(#[from] crate::utils::payment_uri::PaymentUriError),
+    /// Invalid UTXO snapshot
+    #[error("invalid UTXO snapshot: {0}")]
+    InvalidUtxoSnapshot(String),
+    /// Invalid webhook options
+    #[cfg(feature = "webhooks")]
+    #[error("invalid webhook: {0}")]
+    InvalidWebhook(String),
     /// IO error. (storage, backup, restore)
     #[error("`{0}`")]
     Io(#[from] std::io::Error),
     /// serde_json error.
     #[error("`{0}`")]
     Json(#[from] serde_json::error::Error),
+    /// Error of an additional ledger of an account
+    #[error("ledger backend error: {0}")]
+    LedgerBackend(String),
     /// Minting failed
     #[error("minting failed {0}")]
     MintingFailed(String),
@@ -76,6 +127,9 @@ pub enum Error {
     /// Nft not found in unspent outputs
     #[error("nft not found in unspent outputs")]
     NftNotFoundInUnspentOutputs,
+    /// The nft offer expired, the nft belongs to the sender again
+    #[error("nft offer {0} expired")]
+    NftOfferExpired(OutputId),
     // TODO more precise error
     /// Voting error
     #[cfg(feature = "participation")]
@@ -97,6 +151,30 @@ pub enum Error {
         /// The consolidation threshold.
         consolidation_threshold: usize,
     },
+    /// No outputs available to send
+    #[error("no outputs available to send")]
+    NoOutputsToSend,
+    /// An operation took longer than its watchdog timeout and was aborted
+    #[error("{operation:?} operation stalled for longer than {timeout:?}")]
+    OperationStalled {
+        operation: crate::wallet::account::WatchedOperation,
+        timeout: std::time::Duration,
+    },
+    /// Output not found in account
+    #[error("output {0} not found in account")]
+    OutputNotFoundInAccount(OutputId),
+    /// Output is reserved or used in a transaction
+    #[error("output {0} is already reserved or used in a transaction")]
+    OutputReserved(OutputId),
+    /// A transaction spends outputs of a pending transaction that wasn't submitted yet
+    #[error("parent transaction {0} wasn't submitted yet")]
+    ParentTransactionNotSubmitted(TransactionId),
+    /// Proof of reserves error
+    #[error("proof of reserves error: {0}")]
+    ProofOfReserves(String),
+    /// A transaction was refused by the spending policy or the transaction approver of the account
+    #[error("spending policy violation: {0}")]
+    SpendingPolicyViolation(#[from] crate::wallet::account::SpendingPolicyViolation),
     /// Storage access error.
     #[error("error accessing storage: {0}")]
     Storage(String),
@@ -109,26 +187,122 @@ pub enum Error {
     /// Transaction not found
     #[error("transaction {0} not found")]
     TransactionNotFound(TransactionId),
+    /// Sender and receiver of a transfer between accounts are the same account
+    #[error("can't transfer between the same account {0}")]
+    TransferToSameAccount(u32),
+    /// A watch-only account can't sign or generate addresses
+    #[error("account {0} is watch-only and can't sign or generate addresses")]
+    WatchOnlyAccount(u32),
 }
 
-// Serialize type with Display error
+impl Error {
+    /// Returns the kind of the error, the variant name in camelCase, that is stable and can be matched by bindings.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Self::AccountAliasAlreadyExists(_) => "accountAliasAlreadyExists",
+            Self::AccountHasFunds(_) => "accountHasFunds",
+            Self::AccountNotFound(_) => "accountNotFound",
+            Self::AddressNotFoundInAccount(_) => "addressNotFoundInAccount",
+            Self::AddressReused(_) => "addressReused",
+            Self::Backup(_) => "backup",
+            Self::Block(_) => "block",
+            Self::BurningOrMeltingFailed(_) => "burningOrMeltingFailed",
+            Self::Client(_) => "client",
+            Self::ConsolidationRequired { .. } => "consolidationRequired",
+            Self::Crypto(_) => "crypto",
+            Self::CustomInput(_) => "customInput",
+            Self::FailedToGetRemainder => "failedToGetRemainder",
+            Self::InsufficientFunds { .. } => "insufficientFunds",
+            Self::InsufficientNativeTokens { .. } => "insufficientNativeTokens",
+            Self::InvalidAgeAttestation(_) => "invalidAgeAttestation",
+            Self::InvalidChainAddress(_) => "invalidChainAddress",
+            Self::InvalidCoinType { .. } => "invalidCoinType",
+            Self::InvalidMnemonic(_) => "invalidMnemonic",
+            Self::InvalidNetworkAddress { .. } => "invalidNetworkAddress",
+            Self::InvalidOutputKind(_) => "invalidOutputKind",
+            Self::InvalidOutputOptions(_) => "invalidOutputOptions",
+            Self::InvalidOwnershipProof(_) => "invalidOwnershipProof",
+            Self::InvalidPaymentReference(_) => "invalidPaymentReference",
+            Self::InvalidPaymentUri(_) => "invalidPaymentUri",
+            Self::InvalidUtxoSnapshot(_) => "invalidUtxoSnapshot",
+            #[cfg(feature = "webhooks")]
+            Self::InvalidWebhook(_) => "invalidWebhook",
+            Self::Io(_) => "io",
+            Self::Json(_) => "json",
+            Self::LedgerBackend(_) => "ledgerBackend",
+            Self::MintingFailed(_) => "mintingFailed",
+            Self::MissingParameter(_) => "missingParameter",
+            Self::NftNotFoundInUnspentOutputs => "nftNotFoundInUnspentOutputs",
+            Self::NftOfferExpired(_) => "nftOfferExpired",
+            #[cfg(feature = "participation")]
+            Self::Voting(_) => "voting",
+            #[cfg(feature = "participation")]
+            Self::Participation(_) => "participation",
+            Self::NoOutputsToConsolidate { .. } => "noOutputsToConsolidate",
+            Self::NoOutputsToSend => "noOutputsToSend",
+            Self::OperationStalled { .. } => "operationStalled",
+            Self::OutputNotFoundInAccount(_) => "outputNotFoundInAccount",
+            Self::OutputReserved(_) => "outputReserved",
+            Self::ParentTransactionNotSubmitted(_) => "parentTransactionNotSubmitted",
+            Self::ProofOfReserves(_) => "proofOfReserves",
+            Self::SpendingPolicyViolation(_) => "spendingPolicyViolation",
+            Self::Storage(_) => "storage",
+            Self::StorageIsEncrypted => "storageIsEncrypted",
+            Self::TaskJoin(_) => "taskJoin",
+            Self::TransactionNotFound(_) => "transactionNotFound",
+            Self::TransferToSameAccount(_) => "transferToSameAccount",
+            Self::WatchOnlyAccount(_) => "watchOnlyAccount",
+        }
+    }
+
+    /// Returns the machine-readable code of the error, the kinds of the error and the client error it wraps separated
+    /// by a dot, e.g. `client.responseError`.
+    pub fn code(&self) -> String {
+        match self {
+            Self::Client(error) => format!("{}.{}", self.kind(), error.kind()),
+            _ => self.kind().to_string(),
+        }
+    }
+
+    /// Returns the contextual data of the error, like the offending output id or the URL and status code of a failed
+    /// node request.
+    pub fn context(&self) -> ErrorContext {
+        match self {
+            Self::Client(error) => error.context(),
+            Self::NftOfferExpired(output_id)
+            | Self::OutputNotFoundInAccount(output_id)
+            | Self::OutputReserved(output_id) => ErrorContext {
+                output_id: Some(*output_id),
+                ..Default::default()
+            },
+            Self::ParentTransactionNotSubmitted(transaction_id) => ErrorContext {
+                transaction_id: Some(*transaction_id),
+                ..Default::default()
+            },
+            Self::TransactionNotFound(transaction_id) => ErrorContext {
+                transaction_id: Some(*transaction_id),
+                ..Default::default()
+            },
+            _ => ErrorContext::default(),
+        }
+    }
+}
+
+// Serialize type with kind, code of wrapped errors, Display error and context
 impl Serialize for Error {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
     {
-        let mut seq = serializer.serialize_map(Some(2))?;
-        let mut kind_dbg = format!("{self:?}");
-        // Convert first char to lowercase
-        if let Some(r) = kind_dbg.get_mut(0..1) {
-            r.make_ascii_lowercase();
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("type", self.kind())?;
+        // The code only differs from the kind if the error wraps another one
+        if let Self::Client(_) = self {
+            map.serialize_entry("code", &self.code())?;
         }
-        // Split by whitespace for struct variants and split by `(` for tuple variants
-        // Safe to unwrap because kind_dbg is never an empty string
-        let kind = kind_dbg.split([' ', '(']).next().unwrap();
-        seq.serialize_entry("type", &kind)?;
-        seq.serialize_entry("error", &self.to_string())?;
-        seq.end()
+        map.serialize_entry("error", &self.to_string())?;
+        self.context().serialize_entries(&mut map)?;
+        map.end()
     }
 }
 
@@ -164,6 +338,13 @@ impl From<crate::client::secret::ledger_nano::Error> for Error {
     }
 }
 
+#[cfg(feature = "pkcs11")]
+impl From<crate::client::secret::pkcs11::Error> for Error {
+    fn from(error: crate::client::secret::pkcs11::Error) -> Self {
+        Self::Client(Box::new(crate::client::Error::Pkcs11(error)))
+    }
+}
+
 #[cfg(feature = "rocksdb")]
 impl From<rocksdb::Error> for Error {
     fn from(error: rocksdb::Error) -> Self {
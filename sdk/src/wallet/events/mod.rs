@@ -2,18 +2,35 @@
 // SPDX-License-Identifier: Apache-2.0
 
 pub mod types;
+/// Webhook sink for wallet events.
+#[cfg(all(feature = "webhooks", not(target_family = "wasm")))]
+#[cfg_attr(docsrs, doc(cfg(feature = "webhooks")))]
+pub mod webhook;
 
 use std::{
     collections::HashMap,
     fmt::{Debug, Formatter, Result},
 };
+#[cfg(feature = "storage")]
+use std::sync::{
+    atomic::{AtomicU64, Ordering},
+    Arc, Mutex,
+};
 
-use self::types::{Event, WalletEvent, WalletEventType};
+use self::types::{Event, NotificationPreferences, WalletEvent, WalletEventType};
+#[cfg(feature = "storage")]
+use crate::{
+    utils::unix_timestamp_now,
+    wallet::storage::{event_history::StoredWalletEvent, manager::StorageManager},
+};
 
 type Handler<T> = Box<dyn Fn(&T) + Send + Sync + 'static>;
 
 pub struct EventEmitter {
     handlers: HashMap<WalletEventType, Vec<Handler<Event>>>,
+    notification_preferences: HashMap<u32, NotificationPreferences>,
+    #[cfg(feature = "storage")]
+    recorder: Option<EventRecorder>,
 }
 
 impl EventEmitter {
@@ -21,9 +38,38 @@ impl EventEmitter {
     pub fn new() -> Self {
         Self {
             handlers: HashMap::new(),
+            notification_preferences: HashMap::new(),
+            #[cfg(feature = "storage")]
+            recorder: None,
         }
     }
 
+    /// Appends every emitted event to the history in the storage.
+    #[cfg(feature = "storage")]
+    pub(crate) fn with_recorder(mut self, recorder: EventRecorder) -> Self {
+        self.recorder = Some(recorder);
+        self
+    }
+
+    /// Returns the recorder of the emitted events, if their persistence is enabled.
+    #[cfg(feature = "storage")]
+    pub(crate) fn recorder(&self) -> Option<&EventRecorder> {
+        self.recorder.as_ref()
+    }
+
+    /// Sets which events of an account are emitted to the listeners.
+    pub fn set_notification_preferences(&mut self, account_index: u32, preferences: NotificationPreferences) {
+        self.notification_preferences.insert(account_index, preferences);
+    }
+
+    /// Returns which events of an account are emitted to the listeners, all events if no preferences were set.
+    pub fn notification_preferences(&self, account_index: u32) -> NotificationPreferences {
+        self.notification_preferences
+            .get(&account_index)
+            .cloned()
+            .unwrap_or_default()
+    }
+
     /// Registers function `handler` as a listener for a `WalletEventType`. There may be
     /// multiple listeners for a single event.
     pub fn on<F>(&mut self, events: Vec<WalletEventType>, handler: F)
@@ -34,13 +80,20 @@ impl EventEmitter {
         if events.is_empty() {
             // we could use a crate like strum or a macro to iterate over all values, but not sure if it's worth it
             for event_type in &[
+                WalletEventType::AddressFreeze,
                 WalletEventType::NewOutput,
+                WalletEventType::OperationStalled,
+                WalletEventType::ReferenceMatched,
                 WalletEventType::SpentOutput,
                 WalletEventType::TransactionInclusion,
                 WalletEventType::TransactionProgress,
                 WalletEventType::ConsolidationRequired,
                 #[cfg(feature = "ledger_nano")]
                 WalletEventType::LedgerAddressGeneration,
+                #[cfg(feature = "ledger_nano")]
+                WalletEventType::LedgerDisplay,
+                #[cfg(feature = "ledger_nano")]
+                WalletEventType::LedgerQueuePosition,
             ] {
                 let event_handlers = self.handlers.entry(*event_type).or_insert_with(Vec::new);
                 event_handlers.push(Box::new(handler.clone()));
@@ -65,18 +118,19 @@ impl EventEmitter {
     }
 
     /// Invokes all listeners of `event`, passing a reference to `payload` as an
-    /// argument to each of them.
+    /// argument to each of them. Events that the notification preferences of the account don't allow are dropped.
     pub fn emit(&self, account_index: u32, event: WalletEvent) {
-        let event_type = match &event {
-            WalletEvent::NewOutput(_) => WalletEventType::NewOutput,
-            WalletEvent::SpentOutput(_) => WalletEventType::SpentOutput,
-            WalletEvent::TransactionInclusion(_) => WalletEventType::TransactionInclusion,
-            WalletEvent::TransactionProgress(_) => WalletEventType::TransactionProgress,
-            WalletEvent::ConsolidationRequired => WalletEventType::ConsolidationRequired,
-            #[cfg(feature = "ledger_nano")]
-            WalletEvent::LedgerAddressGeneration(_) => WalletEventType::LedgerAddressGeneration,
-        };
+        if let Some(preferences) = self.notification_preferences.get(&account_index) {
+            if !preferences.allows(&event) {
+                return;
+            }
+        }
+        let event_type = event.event_type();
         let event = Event { account_index, event };
+        #[cfg(feature = "storage")]
+        if let Some(recorder) = &self.recorder {
+            recorder.record(&event);
+        }
         if let Some(handlers) = self.handlers.get(&event_type) {
             for handler in handlers {
                 handler(&event);
@@ -85,6 +139,80 @@ impl EventEmitter {
     }
 }
 
+/// Appends the emitted events to the history in the storage.
+///
+/// Events are emitted from sync code, so they are queued and written by a spawned task. The queue is drained while
+/// the storage manager is locked, so the events are stored in the order of their sequence numbers, and it's drained
+/// again before the history is read, so no emitted event is missing. Events that are still queued when the wallet is
+/// dropped are lost. On wasm, where no task can be spawned, the queued events are only written when the history is
+/// read or cleared.
+#[cfg(feature = "storage")]
+#[derive(Clone, Debug)]
+pub(crate) struct EventRecorder {
+    next_sequence: Arc<AtomicU64>,
+    queue: Arc<Mutex<Vec<StoredWalletEvent>>>,
+    storage_manager: Arc<tokio::sync::Mutex<StorageManager>>,
+}
+
+#[cfg(feature = "storage")]
+impl EventRecorder {
+    /// Creates a recorder that continues the history at `next_sequence`.
+    pub(crate) fn new(storage_manager: Arc<tokio::sync::Mutex<StorageManager>>, next_sequence: u64) -> Self {
+        Self {
+            next_sequence: Arc::new(AtomicU64::new(next_sequence)),
+            queue: Arc::new(Mutex::new(Vec::new())),
+            storage_manager,
+        }
+    }
+
+    fn record(&self, event: &Event) {
+        {
+            // The sequence number is taken with the queue locked, so the queue is always ordered
+            let mut queue = self.queue.lock().unwrap();
+            queue.push(StoredWalletEvent {
+                sequence: self.next_sequence.fetch_add(1, Ordering::Relaxed),
+                timestamp: unix_timestamp_now().as_millis(),
+                event: event.clone(),
+            });
+        }
+
+        #[cfg(not(target_family = "wasm"))]
+        {
+            // The task must not keep the storage open once the wallet is dropped
+            let storage_manager = Arc::downgrade(&self.storage_manager);
+            let queue = self.queue.clone();
+            tokio::spawn(async move {
+                if let Some(storage_manager) = storage_manager.upgrade() {
+                    if let Err(err) = Self::write_queue(&storage_manager, &queue).await {
+                        log::error!("[EventRecorder] failed to store events: {err}");
+                    }
+                }
+            });
+        }
+    }
+
+    /// Writes the queued events to the storage.
+    pub(crate) async fn flush(&self) -> crate::wallet::Result<()> {
+        Self::write_queue(&self.storage_manager, &self.queue).await
+    }
+
+    async fn write_queue(
+        storage_manager: &tokio::sync::Mutex<StorageManager>,
+        queue: &Mutex<Vec<StoredWalletEvent>>,
+    ) -> crate::wallet::Result<()> {
+        let mut storage_manager = storage_manager.lock().await;
+        let mut events = std::mem::take(&mut *queue.lock().unwrap());
+        if let Err(err) = storage_manager.append_wallet_events(&events).await {
+            // Queued again in front of newer events, so they are written with the next events
+            let mut queue = queue.lock().unwrap();
+            events.append(&mut queue);
+            *queue = events;
+            return Err(err);
+        }
+        Ok(())
+    }
+}
+
 impl Default for EventEmitter {
     fn default() -> Self {
         Self::new()
@@ -104,6 +232,7 @@ impl Debug for EventEmitter {
 #[cfg(test)]
 mod tests {
     use std::{
+        collections::HashSet,
         str::FromStr,
         sync::{
             atomic::{AtomicUsize, Ordering},
@@ -112,10 +241,16 @@ mod tests {
     };
 
     use super::{
-        types::{TransactionInclusionEvent, TransactionProgressEvent, WalletEvent, WalletEventType},
+        types::{
+            NotificationPreferences, ReferenceMatchedEvent, TransactionInclusionEvent, TransactionProgressEvent,
+            WalletEvent, WalletEventType,
+        },
         EventEmitter,
     };
-    use crate::{types::block::payload::transaction::TransactionId, wallet::account::types::InclusionState};
+    use crate::{
+        types::block::{output::OutputId, payload::transaction::TransactionId},
+        wallet::account::types::{InclusionState, PaymentReference},
+    };
 
     #[test]
     fn events() {
@@ -202,4 +337,52 @@ mod tests {
         }
         assert_eq!(1_000_003, event_counter.load(Ordering::SeqCst));
     }
+
+    #[test]
+    fn notification_preferences() {
+        let mut emitter = EventEmitter::new();
+        let event_counter = Arc::new(AtomicUsize::new(0));
+        let event_counter_clone = Arc::clone(&event_counter);
+        emitter.on(vec![], move |_name| {
+            event_counter_clone.fetch_add(1, Ordering::SeqCst);
+        });
+        emitter.set_notification_preferences(
+            1,
+            NotificationPreferences {
+                muted_events: HashSet::from([WalletEventType::ConsolidationRequired]),
+                min_amount: Some(1_000_000),
+            },
+        );
+        let reference_matched = |amount| {
+            WalletEvent::ReferenceMatched(ReferenceMatchedEvent {
+                reference: PaymentReference::generate("invoice-1"),
+                output_id: OutputId::null(),
+                amount,
+            })
+        };
+
+        // Muted event and amount below the threshold
+        emitter.emit(1, WalletEvent::ConsolidationRequired);
+        emitter.emit(1, reference_matched(999_999));
+        assert_eq!(0, event_counter.load(Ordering::SeqCst));
+
+        emitter.emit(1, reference_matched(1_000_000));
+        // The preferences only apply to their account
+        emitter.emit(0, WalletEvent::ConsolidationRequired);
+        assert_eq!(2, event_counter.load(Ordering::SeqCst));
+        assert_eq!(emitter.notification_preferences(0), NotificationPreferences::default());
+    }
+
+    #[cfg(feature = "ledger_nano")]
+    #[test]
+    fn ledger_address_display() {
+        use super::types::{derivation_path, LedgerAddressDisplay};
+        use crate::client::constants::IOTA_COIN_TYPE;
+
+        let display = LedgerAddressDisplay::new(IOTA_COIN_TYPE, 2, true, 5, "iota1".to_string());
+        assert_eq!(display.derivation_path, "m/44'/4218'/2'/1'/5'");
+
+        let chain = crypto::keys::slip10::Chain::from_u32(vec![44, 4218]);
+        assert_eq!(derivation_path(&chain), "m/44/4218");
+    }
 }
@@ -1,19 +1,29 @@
 // Copyright 2021 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+use std::collections::HashSet;
+
+#[cfg(feature = "ledger_nano")]
+use crypto::keys::slip10::Chain;
 use getset::Getters;
 use serde::{Deserialize, Serialize};
 
+#[cfg(feature = "ledger_nano")]
+use crate::client::secret::signing_queue::QueuePosition;
 use crate::{
     client::api::PreparedTransactionDataDto,
     types::{
         api::core::response::OutputWithMetadataResponse,
         block::{
             address::Bech32Address,
+            output::{dto::OutputDto, OutputId},
             payload::transaction::{dto::TransactionPayloadDto, TransactionId},
         },
     },
-    wallet::account::types::{InclusionState, OutputDataDto},
+    wallet::account::{
+        types::{InclusionState, OutputDataDto, PaymentReference},
+        StallDiagnostics,
+    },
 };
 #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -26,11 +36,20 @@ pub struct Event {
 
 #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
 pub enum WalletEvent {
+    AddressFreeze(AddressFreezeEvent),
     ConsolidationRequired,
     #[cfg(feature = "ledger_nano")]
     #[cfg_attr(docsrs, doc(cfg(feature = "ledger_nano")))]
     LedgerAddressGeneration(AddressData),
+    #[cfg(feature = "ledger_nano")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "ledger_nano")))]
+    LedgerDisplay(Box<LedgerDisplayEvent>),
+    #[cfg(feature = "ledger_nano")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "ledger_nano")))]
+    LedgerQueuePosition(QueuePosition),
     NewOutput(Box<NewOutputEvent>),
+    OperationStalled(Box<StallDiagnostics>),
+    ReferenceMatched(ReferenceMatchedEvent),
     SpentOutput(Box<SpentOutputEvent>),
     TransactionInclusion(TransactionInclusionEvent),
     TransactionProgress(TransactionProgressEvent),
@@ -38,11 +57,20 @@ pub enum WalletEvent {
 
 #[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq, Hash)]
 pub enum WalletEventType {
+    AddressFreeze,
     ConsolidationRequired,
     #[cfg(feature = "ledger_nano")]
     #[cfg_attr(docsrs, doc(cfg(feature = "ledger_nano")))]
     LedgerAddressGeneration,
+    #[cfg(feature = "ledger_nano")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "ledger_nano")))]
+    LedgerDisplay,
+    #[cfg(feature = "ledger_nano")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "ledger_nano")))]
+    LedgerQueuePosition,
     NewOutput,
+    OperationStalled,
+    ReferenceMatched,
     SpentOutput,
     TransactionInclusion,
     TransactionProgress,
@@ -53,10 +81,17 @@ impl TryFrom<&str> for WalletEventType {
 
     fn try_from(value: &str) -> Result<Self, Self::Error> {
         let event_type = match value {
+            "AddressFreeze" => Self::AddressFreeze,
             "ConsolidationRequired" => Self::ConsolidationRequired,
             #[cfg(feature = "ledger_nano")]
             "LedgerAddressGeneration" => Self::LedgerAddressGeneration,
+            #[cfg(feature = "ledger_nano")]
+            "LedgerDisplay" => Self::LedgerDisplay,
+            #[cfg(feature = "ledger_nano")]
+            "LedgerQueuePosition" => Self::LedgerQueuePosition,
             "NewOutput" => Self::NewOutput,
+            "OperationStalled" => Self::OperationStalled,
+            "ReferenceMatched" => Self::ReferenceMatched,
             "SpentOutput" => Self::SpentOutput,
             "TransactionInclusion" => Self::TransactionInclusion,
             "TransactionProgress" => Self::TransactionProgress,
@@ -66,6 +101,73 @@ impl TryFrom<&str> for WalletEventType {
     }
 }
 
+impl WalletEvent {
+    /// Returns the type of the event.
+    pub fn event_type(&self) -> WalletEventType {
+        match self {
+            Self::AddressFreeze(_) => WalletEventType::AddressFreeze,
+            Self::NewOutput(_) => WalletEventType::NewOutput,
+            Self::OperationStalled(_) => WalletEventType::OperationStalled,
+            Self::ReferenceMatched(_) => WalletEventType::ReferenceMatched,
+            Self::SpentOutput(_) => WalletEventType::SpentOutput,
+            Self::TransactionInclusion(_) => WalletEventType::TransactionInclusion,
+            Self::TransactionProgress(_) => WalletEventType::TransactionProgress,
+            Self::ConsolidationRequired => WalletEventType::ConsolidationRequired,
+            #[cfg(feature = "ledger_nano")]
+            Self::LedgerAddressGeneration(_) => WalletEventType::LedgerAddressGeneration,
+            #[cfg(feature = "ledger_nano")]
+            Self::LedgerDisplay(_) => WalletEventType::LedgerDisplay,
+            #[cfg(feature = "ledger_nano")]
+            Self::LedgerQueuePosition(_) => WalletEventType::LedgerQueuePosition,
+        }
+    }
+}
+
+/// Which events of an account are emitted to the listeners, see
+/// [`Account::set_notification_preferences()`](crate::wallet::Account::set_notification_preferences).
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NotificationPreferences {
+    /// Event types that aren't emitted.
+    #[serde(default)]
+    pub muted_events: HashSet<WalletEventType>,
+    /// Events of new and spent outputs and matched payment references are only emitted if the amount is at least this
+    /// many base units, e.g. to suppress events of incoming dust.
+    #[serde(default)]
+    pub min_amount: Option<u64>,
+}
+
+impl NotificationPreferences {
+    /// Returns true if the event is emitted with these preferences.
+    pub fn allows(&self, event: &WalletEvent) -> bool {
+        if self.muted_events.contains(&event.event_type()) {
+            return false;
+        }
+
+        let amount = match event {
+            WalletEvent::NewOutput(event) => output_amount(&event.output.output),
+            WalletEvent::SpentOutput(event) => output_amount(&event.output.output),
+            WalletEvent::ReferenceMatched(event) => Some(event.amount),
+            _ => None,
+        };
+        match (self.min_amount, amount) {
+            (Some(min_amount), Some(amount)) => amount >= min_amount,
+            _ => true,
+        }
+    }
+}
+
+fn output_amount(output: &OutputDto) -> Option<u64> {
+    let amount = match output {
+        OutputDto::Treasury(output) => &output.amount,
+        OutputDto::Basic(output) => &output.amount,
+        OutputDto::Alias(output) => &output.amount,
+        OutputDto::Foundry(output) => &output.amount,
+        OutputDto::Nft(output) => &output.amount,
+    };
+    amount.parse().ok()
+}
+
 #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct NewOutputEvent {
@@ -85,6 +187,25 @@ pub struct SpentOutputEvent {
     pub output: OutputDataDto,
 }
 
+#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReferenceMatchedEvent {
+    /// The expected payment reference that was found.
+    pub reference: PaymentReference,
+    /// The output that contains the reference, in its features or the tagged data of its transaction.
+    pub output_id: OutputId,
+    /// The amount of the output.
+    pub amount: u64,
+}
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub struct AddressFreezeEvent {
+    /// The frozen or unfrozen address.
+    pub address: Bech32Address,
+    /// If the address is frozen now.
+    pub frozen: bool,
+}
+
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct TransactionInclusionEvent {
@@ -108,6 +229,18 @@ pub enum TransactionProgressEvent {
     PerformingPow,
     /// Broadcasting.
     Broadcasting,
+    /// Sending one of the transactions of [`Account::send_all()`](crate::wallet::Account::send_all).
+    SendAll(SendAllProgress),
+}
+
+/// Progress of [`Account::send_all()`](crate::wallet::Account::send_all).
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SendAllProgress {
+    /// The number of the transaction that is sent next, starting at 1.
+    pub transaction: usize,
+    /// The number of transactions that are needed to send all outputs.
+    pub transaction_count: usize,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
@@ -124,3 +257,77 @@ pub struct AddressData {
     #[getset(get = "pub")]
     pub address: String,
 }
+
+/// What a ledger nano displays to the user for verification, so a companion UI can mirror it.
+#[cfg(feature = "ledger_nano")]
+#[cfg_attr(docsrs, doc(cfg(feature = "ledger_nano")))]
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub enum LedgerDisplayEvent {
+    /// An address is displayed.
+    Address(LedgerAddressDisplay),
+    /// A transaction essence is displayed for signing.
+    Essence(LedgerEssenceDisplay),
+}
+
+/// An address displayed by a ledger nano.
+#[cfg(feature = "ledger_nano")]
+#[cfg_attr(docsrs, doc(cfg(feature = "ledger_nano")))]
+#[derive(Clone, Debug, Eq, PartialEq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LedgerAddressDisplay {
+    /// The BIP32 derivation path of the address, e.g. `m/44'/4218'/0'/0'/0'`.
+    pub derivation_path: String,
+    /// The bech32 encoded address.
+    pub address: String,
+}
+
+#[cfg(feature = "ledger_nano")]
+impl LedgerAddressDisplay {
+    pub(crate) fn new(coin_type: u32, account_index: u32, internal: bool, address_index: u32, address: String) -> Self {
+        Self {
+            // 44 is for BIP 44 (HD wallets)
+            derivation_path: derivation_path(&Chain::from_u32_hardened(vec![
+                44,
+                coin_type,
+                account_index,
+                internal as u32,
+                address_index,
+            ])),
+            address,
+        }
+    }
+}
+
+/// A transaction essence displayed by a ledger nano.
+#[cfg(feature = "ledger_nano")]
+#[cfg_attr(docsrs, doc(cfg(feature = "ledger_nano")))]
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LedgerEssenceDisplay {
+    /// The hex encoded essence hash, the device only displays the hash if it needs to blind sign.
+    pub essence_hash: String,
+    /// If the essence is too large or contains features the device can't display, so it's blind signed.
+    pub blind_signing: bool,
+    /// The BIP32 derivation paths of the inputs, in the order of the essence.
+    pub input_derivation_paths: Vec<String>,
+    /// The remainder address, which the device displays as owned by the account.
+    pub remainder: Option<LedgerAddressDisplay>,
+}
+
+/// Formats a BIP32 chain like `m/44'/4218'/0'/0'/0'`.
+#[cfg(feature = "ledger_nano")]
+pub(crate) fn derivation_path(chain: &Chain) -> String {
+    const HARDENED: u32 = 0x8000_0000;
+
+    let mut path = "m".to_string();
+    for segment in chain.segments() {
+        // The segments are stored as "ser32(i)", with the hardened bit set
+        let index = u32::from_be_bytes(segment.bs());
+        if index & HARDENED == 0 {
+            path.push_str(&format!("/{index}"));
+        } else {
+            path.push_str(&format!("/{}'", index & !HARDENED));
+        }
+    }
+    path
+}
@@ -0,0 +1,259 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Webhook sink for wallet events.
+//!
+//! The sink posts the emitted events as JSON to an HTTPS endpoint, so server-side integrations don't need to keep a
+//! listener running. Every request is signed with HMAC-SHA256 over `{timestamp}.{body}` and a shared secret, the hex
+//! encoded signature is sent in the [`SIGNATURE_HEADER`] and the Unix timestamp in seconds in the [`TIMESTAMP_HEADER`],
+//! so the receiver can verify that the request comes from the wallet and reject replayed requests. Failed deliveries
+//! are retried with exponential backoff, events are delivered one after the other in the order they were emitted.
+
+use std::{
+    fmt::{Debug, Formatter},
+    time::Duration,
+};
+
+use crypto::macs::hmac::HMAC_SHA256;
+use reqwest::{header::CONTENT_TYPE, StatusCode};
+use tokio::sync::mpsc::{unbounded_channel, UnboundedReceiver};
+
+use crate::{
+    client::{constants::DEFAULT_USER_AGENT, node_manager::http_client::retry_after},
+    utils::unix_timestamp_now,
+    wallet::events::types::{Event, WalletEventType},
+    Url,
+};
+
+/// The header with the hex encoded HMAC-SHA256 signature of a request.
+pub const SIGNATURE_HEADER: &str = "X-Iota-Signature";
+/// The header with the Unix timestamp in seconds when a request was signed.
+pub const TIMESTAMP_HEADER: &str = "X-Iota-Timestamp";
+
+/// Options of a webhook.
+#[derive(Clone)]
+pub struct WebhookOptions {
+    url: Url,
+    secret: String,
+    event_types: Vec<WalletEventType>,
+    max_retries: u32,
+    retry_interval: Duration,
+    max_retry_interval: Duration,
+    timeout: Duration,
+}
+
+impl WebhookOptions {
+    const DEFAULT_MAX_RETRIES: u32 = 5;
+    const DEFAULT_RETRY_INTERVAL: Duration = Duration::from_secs(1);
+    const DEFAULT_MAX_RETRY_INTERVAL: Duration = Duration::from_secs(60);
+    const DEFAULT_TIMEOUT: Duration = Duration::from_secs(10);
+
+    /// Creates the options of a webhook that posts the confirmed transactions and new outputs to `url`, signed with
+    /// `secret`. The endpoint needs to use HTTPS, only local endpoints can use HTTP.
+    pub fn new(url: &str, secret: impl Into<String>) -> crate::wallet::Result<Self> {
+        let url = Url::parse(url).map_err(|err| crate::wallet::Error::InvalidWebhook(err.to_string()))?;
+        let is_local = matches!(url.host_str(), Some("localhost" | "127.0.0.1" | "[::1]"));
+        if url.scheme() != "https" && !(url.scheme() == "http" && is_local) {
+            return Err(crate::wallet::Error::InvalidWebhook(format!("{url} doesn't use HTTPS")));
+        }
+        let secret = secret.into();
+        if secret.is_empty() {
+            return Err(crate::wallet::Error::InvalidWebhook("empty secret".to_string()));
+        }
+
+        Ok(Self {
+            url,
+            secret,
+            event_types: vec![WalletEventType::TransactionInclusion, WalletEventType::NewOutput],
+            max_retries: Self::DEFAULT_MAX_RETRIES,
+            retry_interval: Self::DEFAULT_RETRY_INTERVAL,
+            max_retry_interval: Self::DEFAULT_MAX_RETRY_INTERVAL,
+            timeout: Self::DEFAULT_TIMEOUT,
+        })
+    }
+
+    /// Sets the types of the posted events, an empty vec posts all events.
+    pub fn with_event_types(mut self, event_types: Vec<WalletEventType>) -> Self {
+        self.event_types = event_types;
+        self
+    }
+
+    /// Sets how often a failed delivery is retried before the event is dropped.
+    pub fn with_max_retries(mut self, max_retries: u32) -> Self {
+        self.max_retries = max_retries;
+        self
+    }
+
+    /// Sets the delay before the first retry, it doubles with every further retry up to `max_retry_interval`.
+    pub fn with_retry_interval(mut self, retry_interval: Duration, max_retry_interval: Duration) -> Self {
+        self.retry_interval = retry_interval;
+        self.max_retry_interval = max_retry_interval;
+        self
+    }
+
+    /// Sets the timeout of a single request.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Returns the URL of the endpoint.
+    pub fn url(&self) -> &Url {
+        &self.url
+    }
+
+    /// Returns the types of the posted events.
+    pub fn event_types(&self) -> &[WalletEventType] {
+        &self.event_types
+    }
+
+    // The delay before a retry, `retry` starts at 1
+    fn backoff(&self, retry: u32) -> Duration {
+        self.retry_interval
+            .saturating_mul(2u32.saturating_pow(retry - 1))
+            .min(self.max_retry_interval)
+    }
+}
+
+// The secret isn't printed
+impl Debug for WebhookOptions {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("WebhookOptions")
+            .field("url", &self.url.as_str())
+            .field("event_types", &self.event_types)
+            .field("max_retries", &self.max_retries)
+            .field("retry_interval", &self.retry_interval)
+            .field("max_retry_interval", &self.max_retry_interval)
+            .field("timeout", &self.timeout)
+            .finish()
+    }
+}
+
+/// Returns the hex encoded HMAC-SHA256 signature of a request body signed at `timestamp`, receivers compute it with
+/// their copy of the secret and compare it to the [`SIGNATURE_HEADER`].
+pub fn webhook_signature(secret: &str, timestamp: u64, body: &str) -> String {
+    let mut mac = [0; 32];
+    HMAC_SHA256(format!("{timestamp}.{body}").as_bytes(), secret.as_bytes(), &mut mac);
+    prefix_hex::encode(mac)
+}
+
+/// Spawns the task that delivers the events and returns the handler that queues them for it. The task ends once the
+/// handler is removed from the event emitter.
+pub(crate) fn spawn_webhook(options: WebhookOptions) -> crate::wallet::Result<impl Fn(&Event) + Clone + Send + Sync> {
+    let client = reqwest::Client::builder()
+        .timeout(options.timeout)
+        .user_agent(DEFAULT_USER_AGENT)
+        .build()
+        .map_err(|err| crate::wallet::Error::InvalidWebhook(err.to_string()))?;
+    let (sender, receiver) = unbounded_channel();
+
+    tokio::spawn(deliver_events(client, options, receiver));
+
+    Ok(move |event: &Event| {
+        // Only fails if the task ended, then there is nobody to deliver the event
+        sender.send(event.clone()).ok();
+    })
+}
+
+async fn deliver_events(client: reqwest::Client, options: WebhookOptions, mut receiver: UnboundedReceiver<Event>) {
+    while let Some(event) = receiver.recv().await {
+        let body = match serde_json::to_string(&event) {
+            Ok(body) => body,
+            Err(err) => {
+                log::error!("[webhook] failed to serialize event: {err}");
+                continue;
+            }
+        };
+        if let Err(reason) = deliver(&client, &options, &body).await {
+            log::error!(
+                "[webhook] dropped {:?} event of account {}: {reason}",
+                event.event.event_type(),
+                event.account_index
+            );
+        }
+    }
+}
+
+// Returns why the event couldn't be delivered
+async fn deliver(client: &reqwest::Client, options: &WebhookOptions, body: &str) -> Result<(), String> {
+    let mut delay = None;
+
+    for retry in 0..=options.max_retries {
+        if let Some(delay) = delay.take() {
+            tokio::time::sleep(delay).await;
+        }
+
+        let timestamp = unix_timestamp_now().as_secs();
+        let response = client
+            .post(options.url.clone())
+            .header(CONTENT_TYPE, "application/json")
+            .header(TIMESTAMP_HEADER, timestamp.to_string())
+            .header(SIGNATURE_HEADER, webhook_signature(&options.secret, timestamp, body))
+            .body(body.to_string())
+            .send()
+            .await;
+        let backoff = options.backoff(retry + 1);
+
+        match response {
+            Ok(response) if response.status().is_success() => return Ok(()),
+            // Other client errors won't change when retried, e.g. a rejected signature
+            Ok(response)
+                if response.status().is_client_error() && response.status() != StatusCode::TOO_MANY_REQUESTS =>
+            {
+                return Err(format!("{} rejected it with {}", options.url, response.status()));
+            }
+            Ok(response) => {
+                log::debug!("[webhook] {} responded with {}", options.url, response.status());
+                // The endpoint can ask for a longer delay, but not for a shorter one
+                delay = Some(retry_after(&response).map_or(backoff, |retry_after| retry_after.max(backoff)));
+            }
+            Err(err) => {
+                log::debug!("[webhook] failed to post to {}: {err}", options.url);
+                delay = Some(backoff);
+            }
+        }
+    }
+
+    Err(format!("{} retries failed", options.max_retries))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::{webhook_signature, WebhookOptions};
+
+    #[test]
+    fn only_https_endpoints() {
+        assert!(WebhookOptions::new("https://example.com/events", "secret").is_ok());
+        assert!(WebhookOptions::new("http://localhost:8080/events", "secret").is_ok());
+        assert!(WebhookOptions::new("http://example.com/events", "secret").is_err());
+        assert!(WebhookOptions::new("https://example.com/events", "").is_err());
+        assert!(WebhookOptions::new("example.com", "secret").is_err());
+    }
+
+    #[test]
+    fn exponential_backoff() {
+        let options = WebhookOptions::new("https://example.com/events", "secret")
+            .unwrap()
+            .with_retry_interval(Duration::from_secs(1), Duration::from_secs(10));
+
+        assert_eq!(
+            (1..=6).map(|retry| options.backoff(retry).as_secs()).collect::<Vec<_>>(),
+            [1, 2, 4, 8, 10, 10]
+        );
+    }
+
+    #[test]
+    fn signature() {
+        // Computed with Python: hmac.new(b"secret", b'1700000000.{"accountIndex":0}', hashlib.sha256).hexdigest()
+        assert_eq!(
+            webhook_signature("secret", 1_700_000_000, r#"{"accountIndex":0}"#),
+            "0xfceffe1ce73f5cd3f136d41b1ee6f051ec59b09158a45e184ea3e99ab6474604"
+        );
+        assert_ne!(
+            webhook_signature("secret", 1_700_000_001, r#"{"accountIndex":0}"#),
+            webhook_signature("secret", 1_700_000_000, r#"{"accountIndex":0}"#)
+        );
+    }
+}
@@ -11,11 +11,9 @@ use crate::{
     types::api::plugins::participation::types::{ParticipationEventId, ParticipationEventType},
 };
 use crate::{
-    client::{
-        api::{PreparedTransactionDataDto, SignedTransactionDataDto},
-        secret::GenerateAddressOptions,
-    },
+    client::api::{PreparedTransactionDataDto, SignedTransactionDataDto},
     types::block::{
+        address::Bech32Address,
         dto::U256Dto,
         output::{
             dto::{AliasIdDto, NativeTokenDto, NftIdDto, OutputDto, TokenIdDto, TokenSchemeDto},
@@ -28,6 +26,7 @@ use crate::{
     wallet::{
         account::{
             operations::{
+                address_generation::AddressGenerationOptions,
                 output_claiming::OutputsToClaim,
                 syncing::SyncOptions,
                 transaction::{
@@ -45,7 +44,7 @@ use crate::{
             FilterOptions,
         },
         message_interface::dtos::AddressWithAmountDto,
-        AddressAndNftId, AddressNativeTokens,
+        SendNativeTokensParams, SendNftParams,
     },
 };
 
@@ -163,7 +162,7 @@ pub enum AccountMethod {
     /// Expected response: [`GeneratedAddress`](crate::wallet::message_interface::Response::GeneratedAddress)
     GenerateAddresses {
         amount: u32,
-        options: Option<GenerateAddressOptions>,
+        options: Option<AddressGenerationOptions>,
     },
     /// Get the [`OutputData`](crate::wallet::account::types::OutputData) of an output stored in the account
     /// Expected response: [`OutputData`](crate::wallet::message_interface::Response::OutputData)
@@ -305,16 +304,22 @@ pub enum AccountMethod {
     /// Expected response: [`SentTransaction`](crate::wallet::message_interface::Response::SentTransaction)
     #[serde(rename_all = "camelCase")]
     SendNativeTokens {
-        addresses_and_native_tokens: Vec<AddressNativeTokens>,
+        addresses_and_native_tokens: Vec<SendNativeTokensParams>,
         options: Option<TransactionOptionsDto>,
     },
     /// Send nft.
     /// Expected response: [`SentTransaction`](crate::wallet::message_interface::Response::SentTransaction)
     #[serde(rename_all = "camelCase")]
     SendNft {
-        addresses_and_nft_ids: Vec<AddressAndNftId>,
+        addresses_and_nft_ids: Vec<SendNftParams>,
         options: Option<TransactionOptionsDto>,
     },
+    /// Freeze an address, its outputs will be excluded from input selection until it gets unfrozen.
+    /// Expected response: [`Ok`](crate::wallet::message_interface::Response::Ok)
+    FreezeAddress { address: Bech32Address },
+    /// Unfreeze an address, so its outputs can be used as inputs again.
+    /// Expected response: [`Ok`](crate::wallet::message_interface::Response::Ok)
+    UnfreezeAddress { address: Bech32Address },
     /// Set the alias of the account.
     /// Expected response: [`Ok`](crate::wallet::message_interface::Response::Ok)
     SetAlias { alias: String },
@@ -344,6 +349,10 @@ pub enum AccountMethod {
     /// Expected response: [`SentTransaction`](crate::wallet::message_interface::Response::SentTransaction)
     #[serde(rename_all = "camelCase")]
     ClaimOutputs { output_ids_to_claim: Vec<OutputId> },
+    /// Accept an nft offer.
+    /// Expected response: [`SentTransaction`](crate::wallet::message_interface::Response::SentTransaction)
+    #[serde(rename_all = "camelCase")]
+    AcceptNftOffer { output_id: OutputId },
     /// Vote for a participation event.
     /// Expected response: [`SentTransaction`](crate::wallet::message_interface::Response::SentTransaction)
     #[cfg(feature = "participation")]
@@ -18,8 +18,8 @@ use crate::{
     },
     wallet::{
         account::{
-            types::{AccountAddress, AddressWithUnspentOutputs, TransactionDto},
-            AccountDetails, OutputDataDto,
+            types::{AccountAddress, AddressWithUnspentOutputs, AssetId, OutputNote, PaymentReference, TransactionDto},
+            AccountDetails, DustProtection, OutputDataDto, OutputDefaults, PrivacyOptions,
         },
         AddressWithAmount,
     },
@@ -79,16 +79,27 @@ impl From<&AddressWithUnspentOutputs> for AddressWithUnspentOutputsDto {
     }
 }
 
+/// The version of the serialization schema of [`AccountDetailsDto`], increased with breaking changes of its format.
+/// The DTO is camelCase and unknown fields are ignored when it's deserialized, so DTOs of newer versions with additional
+/// fields can still be read.
+pub const DTO_VERSION: u8 = 1;
+
 /// Dto for an Account.
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct AccountDetailsDto {
+    /// The version of the schema, see [`DTO_VERSION`]. It's 0 for DTOs serialized before the version was added.
+    #[serde(default)]
+    pub dto_version: u8,
     /// The account index
     pub index: u32,
     /// The coin type
     pub coin_type: u32,
     /// The account alias.
     pub alias: String,
+    /// The identifier of the account in an external system
+    #[serde(default)]
+    pub external_id: Option<String>,
     /// Public addresses
     pub public_addresses: Vec<AccountAddress>,
     /// Internal addresses
@@ -110,14 +121,46 @@ pub struct AccountDetailsDto {
     /// Foundries for native tokens in outputs
     #[serde(default)]
     pub native_token_foundries: HashMap<FoundryId, FoundryOutputDto>,
+    /// Local notes attached to outputs
+    #[serde(default)]
+    pub output_notes: HashMap<OutputId, OutputNote>,
+    /// Payment references the account is waiting for
+    #[serde(default)]
+    pub expected_payment_references: HashSet<PaymentReference>,
+    /// If received assets that weren't accepted before are quarantined
+    #[serde(default)]
+    pub asset_quarantine: bool,
+    /// Received assets that need to be accepted
+    #[serde(default)]
+    pub quarantined_assets: HashSet<AssetId>,
+    /// Assets that aren't quarantined when they're received
+    #[serde(default)]
+    pub accepted_assets: HashSet<AssetId>,
+    /// If the account only has addresses without keys in the secret manager
+    #[serde(default)]
+    pub watch_only: bool,
+    /// Metadata of integrators
+    #[serde(default)]
+    pub metadata: HashMap<String, Vec<u8>>,
+    /// Options to improve the privacy of the account
+    #[serde(default)]
+    pub privacy_options: PrivacyOptions,
+    /// Defaults for outputs to addresses that can't cover the storage deposit
+    #[serde(default)]
+    pub output_defaults: OutputDefaults,
+    /// Options against outputs with amounts too small to be of use
+    #[serde(default)]
+    pub dust_protection: DustProtection,
 }
 
 impl From<&AccountDetails> for AccountDetailsDto {
     fn from(value: &AccountDetails) -> Self {
         Self {
+            dto_version: DTO_VERSION,
             index: *value.index(),
             coin_type: *value.coin_type(),
             alias: value.alias().clone(),
+            external_id: value.external_id().clone(),
             public_addresses: value.public_addresses.clone(),
             internal_addresses: value.internal_addresses.clone(),
             addresses_with_unspent_outputs: value
@@ -152,6 +195,16 @@ impl From<&AccountDetails> for AccountDetailsDto {
                 .iter()
                 .map(|(id, foundry)| (*id, FoundryOutputDto::from(foundry)))
                 .collect(),
+            output_notes: value.output_notes().clone(),
+            expected_payment_references: value.expected_payment_references().clone(),
+            asset_quarantine: *value.asset_quarantine(),
+            quarantined_assets: value.quarantined_assets().clone(),
+            accepted_assets: value.accepted_assets().clone(),
+            watch_only: *value.watch_only(),
+            metadata: value.metadata().clone(),
+            privacy_options: value.privacy_options().clone(),
+            output_defaults: *value.output_defaults(),
+            dust_protection: *value.dust_protection(),
         }
     }
 }
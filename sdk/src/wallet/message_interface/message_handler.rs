@@ -19,13 +19,12 @@ use crate::wallet::events::types::{Event, WalletEventType};
 use crate::{
     client::{
         api::{PreparedTransactionData, PreparedTransactionDataDto, SignedTransactionData, SignedTransactionDataDto},
-        constants::SHIMMER_TESTNET_BECH32_HRP,
         request_funds_from_faucet, utils, Client, NodeInfoWrapper,
     },
     types::block::{
         output::{
             dto::{OutputBuilderAmountDto, OutputDto},
-            AliasId, AliasOutput, BasicOutput, FoundryOutput, NftId, NftOutput, Output, Rent, TokenId,
+            AliasId, AliasOutput, BasicOutput, FoundryOutput, NftId, NftOutput, Output, TokenId,
         },
         Error,
     },
@@ -350,10 +349,7 @@ impl WalletMessageHandler {
                 convert_async_panics(|| async {
                     let bech32_hrp = match bech32_hrp {
                         Some(bech32_hrp) => bech32_hrp,
-                        None => match self.wallet.get_node_info().await {
-                            Ok(node_info_wrapper) => node_info_wrapper.node_info.protocol.bech32_hrp,
-                            Err(_) => SHIMMER_TESTNET_BECH32_HRP.into(),
-                        },
+                        None => self.wallet.get_bech32_hrp().await?,
                     };
 
                     Ok(Response::Bech32Address(utils::hex_to_bech32(&hex, &bech32_hrp)?))
@@ -387,6 +383,7 @@ impl WalletMessageHandler {
         response
     }
 
+    #[allow(clippy::large_stack_frames)]
     async fn call_account_method(&self, account_id: &AccountIdentifier, method: AccountMethod) -> Result<Response> {
         let account = self.wallet.get_account(account_id.clone()).await?;
 
@@ -860,6 +857,14 @@ impl WalletMessageHandler {
                 })
                 .await
             }
+            AccountMethod::FreezeAddress { address } => {
+                account.freeze_address(&address).await?;
+                Ok(Response::Ok(()))
+            }
+            AccountMethod::UnfreezeAddress { address } => {
+                account.unfreeze_address(&address).await?;
+                Ok(Response::Ok(()))
+            }
             AccountMethod::SetAlias { alias } => {
                 convert_async_panics(|| async {
                     account.set_alias(&alias).await?;
@@ -926,6 +931,13 @@ impl WalletMessageHandler {
                 })
                 .await
             }
+            AccountMethod::AcceptNftOffer { output_id } => {
+                convert_async_panics(|| async {
+                    let transaction = account.accept_nft_offer(output_id).await?;
+                    Ok(Response::SentTransaction(TransactionDto::from(&transaction)))
+                })
+                .await
+            }
             #[cfg(feature = "participation")]
             AccountMethod::Vote { event_id, answers } => {
                 convert_async_panics(|| async {
@@ -1046,7 +1058,7 @@ impl WalletMessageHandler {
         match builder.finish().await {
             Ok(account) => {
                 let account = account.read().await;
-                Ok(Response::Account(AccountDetailsDto::from(&*account)))
+                Ok(Response::Account(Box::new(AccountDetailsDto::from(&*account))))
             }
             Err(e) => Err(e),
         }
@@ -1055,7 +1067,7 @@ impl WalletMessageHandler {
     async fn get_account(&self, account_id: &AccountIdentifier) -> Result<Response> {
         let account = self.wallet.get_account(account_id.clone()).await?;
         let account = account.read().await;
-        Ok(Response::Account(AccountDetailsDto::from(&*account)))
+        Ok(Response::Account(Box::new(AccountDetailsDto::from(&*account))))
     }
 
     async fn get_accounts(&self) -> Result<Response> {
@@ -43,6 +43,13 @@ where
             #[cfg(feature = "ledger_nano")]
             SecretManagerDto::LedgerNano(is_simulator) => s.serialize_str(&format!("ledgerNano({is_simulator})")),
             SecretManagerDto::Mnemonic(_) => s.serialize_str("mnemonic(<omitted>)"),
+            #[cfg(feature = "pkcs11")]
+            SecretManagerDto::Pkcs11(pkcs11) => {
+                let mut pkcs11_dto = pkcs11.clone();
+                // Remove PIN
+                pkcs11_dto.pin = None;
+                s.serialize_str(&format!("{pkcs11_dto:?}"))
+            }
             SecretManagerDto::Placeholder => s.serialize_str("placeholder"),
             #[cfg(feature = "stronghold")]
             SecretManagerDto::Stronghold(stronghold) => {
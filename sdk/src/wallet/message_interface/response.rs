@@ -41,7 +41,7 @@ pub enum Response {
     /// Response for
     /// [`CreateAccount`](crate::wallet::message_interface::Message::CreateAccount),
     /// [`GetAccount`](crate::wallet::message_interface::Message::GetAccount)
-    Account(AccountDetailsDto),
+    Account(Box<AccountDetailsDto>),
     /// Response for [`GetAccountIndexes`](crate::wallet::message_interface::Message::GetAccountIndexes)
     AccountIndexes(Vec<u32>),
     /// Response for [`GetAccounts`](crate::wallet::message_interface::Message::GetAccounts)
@@ -110,6 +110,7 @@ pub enum Response {
     /// Response for
     /// [`ConsolidateOutputs`](crate::wallet::message_interface::AccountMethod::ConsolidateOutputs)
     /// [`ClaimOutputs`](crate::wallet::message_interface::AccountMethod::ClaimOutputs)
+    /// [`AcceptNftOffer`](crate::wallet::message_interface::AccountMethod::AcceptNftOffer)
     /// [`CreateAliasOutput`](crate::wallet::message_interface::AccountMethod::CreateAliasOutput)
     /// [`SendAmount`](crate::wallet::message_interface::AccountMethod::SendAmount),
     /// [`MintNfts`](crate::wallet::message_interface::AccountMethod::MintNfts),
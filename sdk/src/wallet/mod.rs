@@ -28,6 +28,8 @@ pub mod events;
 #[cfg(feature = "storage")]
 #[cfg_attr(docsrs, doc(cfg(feature = "storage")))]
 pub mod storage;
+/// The proof of reserves module.
+pub mod proof_of_reserves;
 /// The module for spawning tasks on a thread
 pub(crate) mod task;
 
@@ -39,14 +41,26 @@ pub use self::{
                 mint_nfts::NftOptions,
             },
             send_amount::AddressWithAmount,
-            send_native_tokens::AddressNativeTokens,
-            send_nft::AddressAndNftId,
+            send_native_tokens::SendNativeTokensParams,
+            send_nft::SendNftParams,
         },
         Account,
     },
     error::Error,
-    wallet::{Wallet, WalletBuilder},
+    wallet::{
+        operations::{
+            find_account::AddressOwner,
+            firefly_import::{FireflyAccountData, FireflyImportOptions, FireflyNetwork, FireflyProfile},
+            health::WalletHealth,
+            ownership_proof::OwnershipProof,
+            search::{SearchItem, SearchResult},
+        },
+        Wallet, WalletBuilder,
+    },
 };
 
+#[allow(deprecated)]
+pub use self::account::operations::transaction::high_level::send_nft::AddressAndNftId;
+
 /// The wallet Result type.
 pub type Result<T> = std::result::Result<T, Error>;
@@ -0,0 +1,278 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Merkle-sum trees for proof-of-reserves attestations.
+//!
+//! The liabilities, the balances of all customers, are committed to in a Merkle-sum tree, where every node commits to
+//! the hash and the sum of its children. The root is published together with a [`ReservesSnapshot`] of the on-chain
+//! holdings, which contains [`OwnershipProof`]s for the addresses. Every customer gets an [`InclusionProof`] to verify
+//! that their balance is included in the published root.
+
+use std::collections::HashSet;
+
+use crypto::hashes::{blake2b::Blake2b256, Digest};
+use serde::{Deserialize, Serialize};
+
+use crate::wallet::{OwnershipProof, Result};
+
+const LEAF_PREFIX: u8 = 0;
+const NODE_PREFIX: u8 = 1;
+
+/// The balance of a customer, a leaf of the [`MerkleSumTree`].
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CustomerBalance {
+    /// Unique identifier of the customer, e.g. a hashed account id.
+    pub customer_id: String,
+    /// Random nonce, so other customers can't guess the balance from a sibling hash.
+    pub nonce: String,
+    /// The balance owed to the customer.
+    pub balance: u64,
+}
+
+impl CustomerBalance {
+    /// Creates a new [`CustomerBalance`].
+    pub fn new(customer_id: impl Into<String>, nonce: impl Into<String>, balance: u64) -> Self {
+        Self {
+            customer_id: customer_id.into(),
+            nonce: nonce.into(),
+            balance,
+        }
+    }
+
+    fn leaf(&self) -> MerkleSumNode {
+        let mut hasher = Blake2b256::new();
+        hasher.update([LEAF_PREFIX]);
+        hasher.update((self.customer_id.len() as u64).to_le_bytes());
+        hasher.update(self.customer_id.as_bytes());
+        hasher.update((self.nonce.len() as u64).to_le_bytes());
+        hasher.update(self.nonce.as_bytes());
+        hasher.update(self.balance.to_le_bytes());
+
+        MerkleSumNode {
+            hash: hasher.finalize().into(),
+            sum: self.balance,
+        }
+    }
+}
+
+/// A node of the [`MerkleSumTree`], committing to a hash and the sum of all balances below it.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MerkleSumNode {
+    /// The hash of the node.
+    #[serde(with = "hash_hex")]
+    pub hash: [u8; 32],
+    /// The sum of all balances below this node.
+    pub sum: u64,
+}
+
+impl MerkleSumNode {
+    fn parent(left: &Self, right: &Self) -> Result<Self> {
+        let sum = left
+            .sum
+            .checked_add(right.sum)
+            .ok_or_else(|| crate::wallet::Error::ProofOfReserves("sum of balances overflows".to_string()))?;
+
+        let mut hasher = Blake2b256::new();
+        hasher.update([NODE_PREFIX]);
+        hasher.update(left.hash);
+        hasher.update(left.sum.to_le_bytes());
+        hasher.update(right.hash);
+        hasher.update(right.sum.to_le_bytes());
+
+        Ok(Self {
+            hash: hasher.finalize().into(),
+            sum,
+        })
+    }
+}
+
+/// A sibling on the path from a leaf to the root.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProofStep {
+    /// The sibling node.
+    pub sibling: MerkleSumNode,
+    /// If the sibling is the left child of the parent.
+    pub sibling_is_left: bool,
+}
+
+/// Proof that a [`CustomerBalance`] is included in the root of a [`MerkleSumTree`].
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InclusionProof {
+    /// The balance of the customer.
+    pub customer_balance: CustomerBalance,
+    /// The siblings from the leaf up to the root.
+    pub path: Vec<ProofStep>,
+}
+
+impl InclusionProof {
+    /// Verifies that the customer balance is included in the root. The sums of the siblings can't be negative, so
+    /// the balance can't be hidden by other branches.
+    pub fn verify(&self, root: &MerkleSumNode) -> Result<()> {
+        let mut node = self.customer_balance.leaf();
+        for step in &self.path {
+            node = if step.sibling_is_left {
+                MerkleSumNode::parent(&step.sibling, &node)?
+            } else {
+                MerkleSumNode::parent(&node, &step.sibling)?
+            };
+        }
+
+        if &node == root {
+            Ok(())
+        } else {
+            Err(crate::wallet::Error::ProofOfReserves(format!(
+                "customer {} is not included in the root",
+                self.customer_balance.customer_id
+            )))
+        }
+    }
+}
+
+/// A Merkle-sum tree of customer balances.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MerkleSumTree {
+    customer_balances: Vec<CustomerBalance>,
+    // All levels of the tree, starting with the leaves, the last level only contains the root
+    levels: Vec<Vec<MerkleSumNode>>,
+}
+
+impl MerkleSumTree {
+    /// Builds the tree from the customer balances, the customer ids must be unique.
+    pub fn new(customer_balances: Vec<CustomerBalance>) -> Result<Self> {
+        if customer_balances.is_empty() {
+            return Err(crate::wallet::Error::ProofOfReserves("no customer balances".to_string()));
+        }
+        let mut customer_ids = HashSet::new();
+        if let Some(duplicate) = customer_balances
+            .iter()
+            .find(|customer_balance| !customer_ids.insert(&customer_balance.customer_id))
+        {
+            return Err(crate::wallet::Error::ProofOfReserves(format!(
+                "duplicated customer id {}",
+                duplicate.customer_id
+            )));
+        }
+
+        let mut levels = vec![customer_balances.iter().map(CustomerBalance::leaf).collect::<Vec<_>>()];
+        while levels[levels.len() - 1].len() > 1 {
+            let level = &levels[levels.len() - 1];
+            let mut next_level = Vec::with_capacity((level.len() + 1) / 2);
+            for pair in level.chunks(2) {
+                match pair {
+                    [left, right] => next_level.push(MerkleSumNode::parent(left, right)?),
+                    // An odd node is moved up unchanged, duplicating it would also duplicate its sum
+                    [single] => next_level.push(*single),
+                    _ => unreachable!("chunks of two"),
+                }
+            }
+            levels.push(next_level);
+        }
+
+        Ok(Self {
+            customer_balances,
+            levels,
+        })
+    }
+
+    /// Returns the root, which commits to all customer balances and their total sum.
+    pub fn root(&self) -> MerkleSumNode {
+        self.levels[self.levels.len() - 1][0]
+    }
+
+    /// Returns the sum of all customer balances.
+    pub fn total_liabilities(&self) -> u64 {
+        self.root().sum
+    }
+
+    /// Returns the inclusion proof for a customer.
+    pub fn inclusion_proof(&self, customer_id: &str) -> Option<InclusionProof> {
+        let leaf_index = self
+            .customer_balances
+            .iter()
+            .position(|customer_balance| customer_balance.customer_id == customer_id)?;
+
+        let mut index = leaf_index;
+        let mut path = Vec::new();
+        for level in &self.levels[..self.levels.len() - 1] {
+            let sibling_index = index ^ 1;
+            // No sibling if the node was moved up unchanged
+            if let Some(sibling) = level.get(sibling_index) {
+                path.push(ProofStep {
+                    sibling: *sibling,
+                    sibling_is_left: sibling_index < index,
+                });
+            }
+            index /= 2;
+        }
+
+        Some(InclusionProof {
+            customer_balance: self.customer_balances[leaf_index].clone(),
+            path,
+        })
+    }
+
+    /// Returns the inclusion proofs for all customers.
+    pub fn inclusion_proofs(&self) -> Vec<InclusionProof> {
+        self.customer_balances
+            .iter()
+            .filter_map(|customer_balance| self.inclusion_proof(&customer_balance.customer_id))
+            .collect()
+    }
+}
+
+/// The on-chain holdings at the time of the attestation, with ownership proofs for the addresses.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReservesSnapshot {
+    /// The proofs that the addresses are controlled by the custodian.
+    pub ownership_proofs: Vec<OwnershipProof>,
+    /// The sum of the unspent outputs of the addresses.
+    pub total_holdings: u64,
+    /// Unix timestamp in seconds of the snapshot.
+    pub timestamp: u64,
+}
+
+/// A proof-of-reserves attestation, commits to the liabilities and the on-chain holdings.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProofOfReserves {
+    /// The root of the [`MerkleSumTree`] of the customer balances.
+    pub liabilities_root: MerkleSumNode,
+    /// The on-chain holdings.
+    pub reserves: ReservesSnapshot,
+}
+
+impl ProofOfReserves {
+    /// Creates a new [`ProofOfReserves`].
+    pub fn new(tree: &MerkleSumTree, reserves: ReservesSnapshot) -> Self {
+        Self {
+            liabilities_root: tree.root(),
+            reserves,
+        }
+    }
+
+    /// Verifies the ownership proofs for the challenge and returns true if the holdings cover the liabilities.
+    pub fn verify(&self, challenge: &[u8]) -> Result<bool> {
+        for ownership_proof in &self.reserves.ownership_proofs {
+            ownership_proof.verify(challenge)?;
+        }
+        Ok(self.reserves.total_holdings >= self.liabilities_root.sum)
+    }
+}
+
+mod hash_hex {
+    use serde::{de, Deserialize, Deserializer, Serializer};
+
+    pub(super) fn serialize<S: Serializer>(hash: &[u8; 32], serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&prefix_hex::encode(&hash[..]))
+    }
+
+    pub(super) fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<[u8; 32], D::Error> {
+        let hash = String::deserialize(deserializer)?;
+        prefix_hex::decode(hash).map_err(|_| de::Error::custom("invalid hash"))
+    }
+}
@@ -31,7 +31,7 @@ impl StorageAdapter for Memory {
 
     /// Batch writes records to the storage.
     async fn batch_set(&mut self, records: HashMap<String, String>) -> crate::wallet::Result<()> {
-        self.0.extend(records.into_iter());
+        self.0.extend(records);
         Ok(())
     }
 
@@ -28,4 +28,9 @@ pub trait StorageAdapter: std::fmt::Debug {
 
     /// Removes a record from the storage.
     async fn remove(&mut self, key: &str) -> crate::wallet::Result<()>;
+
+    /// Writes buffered records to the disk, called when the wallet is stopped. Does nothing by default.
+    async fn flush(&mut self) -> crate::wallet::Result<()> {
+        Ok(())
+    }
 }
@@ -68,4 +68,10 @@ impl StorageAdapter for RocksdbStorageAdapter {
         self.db.lock().await.delete(key.as_bytes())?;
         Ok(())
     }
+
+    /// Writes the memtables to the disk.
+    async fn flush(&mut self) -> crate::wallet::Result<()> {
+        self.db.lock().await.flush()?;
+        Ok(())
+    }
 }
@@ -0,0 +1,36 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::{path::PathBuf, time::Duration};
+
+use serde::{Deserialize, Serialize};
+
+/// Policy to compact the stored accounts automatically after they got synced, see
+/// [`Account::prune()`](crate::wallet::Account::prune).
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CompactionPolicy {
+    /// An account is pruned when it has more outputs and transactions than this number.
+    pub max_records: usize,
+    /// Spent outputs and transactions are kept for this duration before they are pruned.
+    pub keep_spent_outputs_for: Duration,
+    /// Directory to which the pruned records are archived. If not set, they are discarded.
+    pub archive_path: Option<PathBuf>,
+}
+
+impl CompactionPolicy {
+    /// Creates a new [`CompactionPolicy`], that doesn't archive the pruned records.
+    pub fn new(max_records: usize, keep_spent_outputs_for: Duration) -> Self {
+        Self {
+            max_records,
+            keep_spent_outputs_for,
+            archive_path: None,
+        }
+    }
+
+    /// Sets the directory to which the pruned records are archived.
+    pub fn with_archive_path(mut self, archive_path: impl Into<PathBuf>) -> Self {
+        self.archive_path = Some(archive_path.into());
+        self
+    }
+}
@@ -24,9 +24,21 @@ pub(crate) const ACCOUNT_INDEXATION_KEY: &str = "iota-wallet-account-";
 
 pub(crate) const ACCOUNT_SYNC_OPTIONS: &str = "sync-options";
 
-pub(crate) const DATABASE_SCHEMA_VERSION: u8 = 1;
+#[cfg(feature = "events")]
+pub(crate) const ACCOUNT_NOTIFICATION_PREFERENCES: &str = "notification-preferences";
+
+pub(crate) const ACCOUNT_EVENT_LOG: &str = "event-log";
+
+#[cfg(feature = "events")]
+pub(crate) const WALLET_EVENT_HISTORY_KEY: &str = "iota-wallet-event-history";
+
+pub(crate) const ACCOUNT_TRANSACTION_JOURNAL: &str = "transaction-journal";
+
+pub(crate) const DATABASE_SCHEMA_VERSION: u8 = 2;
 pub(crate) const DATABASE_SCHEMA_VERSION_KEY: &str = "database-schema-version";
 
+pub(crate) const STORAGE_ENCRYPTION_KEY: &str = "storage-encryption";
+
 #[cfg(feature = "participation")]
 pub(crate) const PARTICIPATION_EVENTS: &str = "participation-events";
 #[cfg(feature = "participation")]
@@ -0,0 +1,120 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::num::NonZeroU32;
+
+use crypto::{ciphers::chacha, keys::pbkdf, utils::rand};
+use serde::{Deserialize, Serialize};
+
+// Iterations of PBKDF2 to derive a key from a password
+const PBKDF_ITERATIONS: NonZeroU32 = match NonZeroU32::new(100_000) {
+    Some(iterations) => iterations,
+    None => unreachable!(),
+};
+// Encrypted with the key, to detect a wrong key or password
+const CHECK_VALUE: &[u8] = b"iota-sdk-storage-encryption";
+
+/// The key to encrypt every record of the wallet storage with XChaCha20-Poly1305, so the accounts, outputs and
+/// transactions can't be read from the disk.
+#[derive(Clone, PartialEq, Eq)]
+pub enum StorageEncryption {
+    /// A key, that is used as it is.
+    Key([u8; 32]),
+    /// A password, the key is derived from it with PBKDF2 and a random salt that is stored unencrypted in the storage.
+    Password(String),
+}
+
+impl std::fmt::Debug for StorageEncryption {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Key(_) => f.write_str("Key(<omitted>)"),
+            Self::Password(_) => f.write_str("Password(<omitted>)"),
+        }
+    }
+}
+
+/// How the storage is encrypted, stored unencrypted so it can be read before the key is known.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct EncryptionMetadata {
+    /// The salt to derive the key from a password, not set if the storage is encrypted with a key.
+    salt: Option<[u8; 32]>,
+    /// A known value encrypted with the key, not set if the storage isn't encrypted.
+    check: Option<Vec<u8>>,
+}
+
+impl EncryptionMetadata {
+    /// Creates the metadata for a storage that gets encrypted, returns it together with the key.
+    pub(crate) fn new(encryption: &StorageEncryption) -> crate::wallet::Result<([u8; 32], Self)> {
+        let (key, salt) = match encryption {
+            StorageEncryption::Key(key) => (*key, None),
+            StorageEncryption::Password(password) => {
+                let mut salt = [0u8; 32];
+                rand::fill(&mut salt)?;
+                (derive_key(password, &salt), Some(salt))
+            }
+        };
+        let check = Some(chacha::aead_encrypt(&key, CHECK_VALUE)?);
+
+        Ok((key, Self { salt, check }))
+    }
+
+    /// Returns true if the records of the storage are encrypted.
+    pub(crate) fn is_encrypted(&self) -> bool {
+        self.check.is_some()
+    }
+
+    /// Returns the key of an encrypted storage, fails if the key or password is wrong.
+    pub(crate) fn key(&self, encryption: &StorageEncryption) -> crate::wallet::Result<[u8; 32]> {
+        let key = match (encryption, &self.salt) {
+            (StorageEncryption::Key(key), None) => *key,
+            (StorageEncryption::Password(password), Some(salt)) => derive_key(password, salt),
+            (StorageEncryption::Key(_), Some(_)) => {
+                return Err(crate::wallet::Error::Storage(
+                    "the storage is encrypted with a password, not a key".to_string(),
+                ));
+            }
+            (StorageEncryption::Password(_), None) => {
+                return Err(crate::wallet::Error::Storage(
+                    "the storage is encrypted with a key, not a password".to_string(),
+                ));
+            }
+        };
+
+        let check = self.check.as_deref().unwrap_or_default();
+        match chacha::aead_decrypt(&key, check) {
+            Ok(value) if value == CHECK_VALUE => Ok(key),
+            _ => Err(crate::wallet::Error::Storage("wrong storage encryption key or password".to_string())),
+        }
+    }
+}
+
+/// Derives a key from a password with PBKDF2, also used to encrypt exported accounts.
+pub(crate) fn derive_key(password: &str, salt: &[u8]) -> [u8; 32] {
+    let mut buffer = [0u8; 64];
+    pbkdf::PBKDF2_HMAC_SHA512(password.as_bytes(), salt, PBKDF_ITERATIONS, &mut buffer);
+
+    let mut key = [0u8; 32];
+    key.copy_from_slice(&buffer[..32]);
+    key
+}
+
+/// Encrypts a record, encrypted records are stored as JSON array of the bytes.
+pub(crate) fn encrypt_record(key: Option<&[u8; 32]>, record: String) -> crate::wallet::Result<String> {
+    match key {
+        Some(key) => Ok(serde_json::to_string(&chacha::aead_encrypt(key, record.as_bytes())?)?),
+        None => Ok(record),
+    }
+}
+
+/// Decrypts a record that was encrypted with [`encrypt_record()`].
+pub(crate) fn decrypt_record(key: Option<&[u8; 32]>, record: String) -> crate::wallet::Result<String> {
+    match key {
+        Some(key) => {
+            let ciphertext = serde_json::from_str::<Vec<u8>>(&record)?;
+            String::from_utf8(chacha::aead_decrypt(key, &ciphertext)?)
+                .map_err(|_| crate::wallet::Error::Storage("decrypted record is not valid UTF-8".to_string()))
+        }
+        None => Ok(record),
+    }
+}
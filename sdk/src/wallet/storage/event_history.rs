@@ -0,0 +1,62 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! History of the emitted wallet events.
+//!
+//! When the persistence of events is enabled, every event that the wallet emits gets a sequence number and a
+//! timestamp and is appended to the history in the storage. Applications that were offline can replay the events they
+//! missed, starting after the sequence number of the last event they handled, and auditors get a log of all
+//! activities of the wallet. Old events are only removed with an explicit `clear_events()`, which never reuses their
+//! sequence numbers.
+
+use serde::{Deserialize, Serialize};
+
+use crate::wallet::events::types::{Event, WalletEventType};
+
+/// An event in the history of the wallet.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StoredWalletEvent {
+    /// The position of the event in the history, starting at 0.
+    pub sequence: u64,
+    /// Unix timestamp in milliseconds when the event was emitted.
+    pub timestamp: u128,
+    /// The emitted event.
+    pub event: Event,
+}
+
+/// Selects the events of the history that are returned, all events match the default filter.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WalletEventFilter {
+    /// Only return the events of these accounts.
+    #[serde(default)]
+    pub account_indexes: Option<Vec<u32>>,
+    /// Only return events of these types.
+    #[serde(default)]
+    pub event_types: Option<Vec<WalletEventType>>,
+}
+
+impl WalletEventFilter {
+    /// Returns whether a stored event matches the filter.
+    pub fn matches(&self, event: &StoredWalletEvent) -> bool {
+        if let Some(account_indexes) = &self.account_indexes {
+            if !account_indexes.contains(&event.event.account_index) {
+                return false;
+            }
+        }
+        if let Some(event_types) = &self.event_types {
+            if !event_types.contains(&event.event.event.event_type()) {
+                return false;
+            }
+        }
+        true
+    }
+}
+
+/// The sequence numbers of the stored events, events before `start` were cleared.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub(crate) struct EventHistoryRange {
+    pub(crate) start: u64,
+    pub(crate) end: u64,
+}
@@ -0,0 +1,202 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Events of the event sourced storage mode.
+//!
+//! Every time an account is saved, the changes compared to the previously saved state are appended as an
+//! [`AccountStateEvent`] to the log of the account. The stored account is the projection of all events, so the state at
+//! any earlier point in time can be reconstructed by replaying the events until then.
+
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+/// An appended change of the account state.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountStateEvent {
+    /// The position of the event in the log of the account, starting at 0.
+    pub sequence: u64,
+    /// Unix timestamp in milliseconds when the event was appended.
+    pub timestamp: u128,
+    /// The changed fields of the account, the first event of a log contains all fields.
+    pub changes: Vec<AccountStateChange>,
+}
+
+/// A change of a field of the serialized account.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum AccountStateChange {
+    /// The field got a new value.
+    Set {
+        /// The name of the field.
+        field: String,
+        /// The new value.
+        value: Value,
+    },
+    /// The field got removed.
+    Unset {
+        /// The name of the field.
+        field: String,
+    },
+    /// Entries of a map field got inserted or updated, e.g. new outputs.
+    Upsert {
+        /// The name of the field.
+        field: String,
+        /// The inserted or updated entries.
+        entries: Map<String, Value>,
+    },
+    /// Entries of a map field got removed.
+    Remove {
+        /// The name of the field.
+        field: String,
+        /// The keys of the removed entries.
+        keys: Vec<String>,
+    },
+}
+
+/// Returns the changes that turn the previous into the current serialized account.
+pub(crate) fn diff(previous: &Map<String, Value>, current: &Map<String, Value>) -> Vec<AccountStateChange> {
+    let mut changes = Vec::new();
+
+    for (field, value) in current {
+        match (previous.get(field), value) {
+            (Some(previous_value), _) if previous_value == value => {}
+            // Only the changed entries of maps are stored, so a new output doesn't duplicate all other outputs
+            (Some(Value::Object(previous_entries)), Value::Object(entries)) => {
+                let upserted = entries
+                    .iter()
+                    .filter(|(key, entry)| previous_entries.get(*key) != Some(*entry))
+                    .map(|(key, entry)| (key.clone(), entry.clone()))
+                    .collect::<Map<_, _>>();
+                let removed = previous_entries
+                    .keys()
+                    .filter(|key| !entries.contains_key(*key))
+                    .cloned()
+                    .collect::<Vec<_>>();
+
+                if !upserted.is_empty() {
+                    changes.push(AccountStateChange::Upsert {
+                        field: field.clone(),
+                        entries: upserted,
+                    });
+                }
+                if !removed.is_empty() {
+                    changes.push(AccountStateChange::Remove {
+                        field: field.clone(),
+                        keys: removed,
+                    });
+                }
+            }
+            _ => changes.push(AccountStateChange::Set {
+                field: field.clone(),
+                value: value.clone(),
+            }),
+        }
+    }
+
+    for field in previous.keys().filter(|field| !current.contains_key(*field)) {
+        changes.push(AccountStateChange::Unset { field: field.clone() });
+    }
+
+    changes
+}
+
+/// Applies the changes of an event to the serialized account.
+pub(crate) fn apply(state: &mut Map<String, Value>, changes: &[AccountStateChange]) {
+    for change in changes {
+        match change {
+            AccountStateChange::Set { field, value } => {
+                state.insert(field.clone(), value.clone());
+            }
+            AccountStateChange::Unset { field } => {
+                state.remove(field);
+            }
+            AccountStateChange::Upsert { field, entries } => {
+                let value = state
+                    .entry(field.clone())
+                    .or_insert_with(|| Value::Object(Map::new()));
+                if !value.is_object() {
+                    *value = Value::Object(Map::new());
+                }
+                if let Value::Object(map) = value {
+                    map.extend(entries.clone());
+                }
+            }
+            AccountStateChange::Remove { field, keys } => {
+                if let Some(Value::Object(map)) = state.get_mut(field) {
+                    for key in keys {
+                        map.remove(key);
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    fn object(value: Value) -> Map<String, Value> {
+        match value {
+            Value::Object(map) => map,
+            _ => panic!("not an object"),
+        }
+    }
+
+    #[test]
+    fn diff_and_apply() {
+        let previous = object(json!({
+            "alias": "Alice",
+            "outputs": { "0x01": 1, "0x02": 2 },
+            "lockedOutputs": ["0x01"],
+            "removed": true,
+        }));
+        let current = object(json!({
+            "alias": "Bob",
+            "outputs": { "0x02": 3, "0x03": 4 },
+            "lockedOutputs": ["0x01"],
+            "added": 5,
+        }));
+
+        let changes = diff(&previous, &current);
+        let expected_changes = [
+            AccountStateChange::Set {
+                field: "added".to_string(),
+                value: json!(5),
+            },
+            AccountStateChange::Set {
+                field: "alias".to_string(),
+                value: json!("Bob"),
+            },
+            AccountStateChange::Upsert {
+                field: "outputs".to_string(),
+                entries: object(json!({ "0x02": 3, "0x03": 4 })),
+            },
+            AccountStateChange::Remove {
+                field: "outputs".to_string(),
+                keys: vec!["0x01".to_string()],
+            },
+            AccountStateChange::Unset {
+                field: "removed".to_string(),
+            },
+        ];
+        assert_eq!(changes.len(), expected_changes.len());
+        for expected_change in &expected_changes {
+            assert!(changes.contains(expected_change));
+        }
+
+        let mut state = previous;
+        apply(&mut state, &changes);
+        assert_eq!(state, current);
+
+        // The first event contains everything
+        let mut state = Map::new();
+        apply(&mut state, &diff(&Map::new(), &current));
+        assert_eq!(state, current);
+
+        assert!(diff(&current, &current).is_empty());
+    }
+}
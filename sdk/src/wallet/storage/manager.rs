@@ -1,16 +1,31 @@
 // Copyright 2021 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use std::sync::Arc;
+use std::{collections::HashMap, sync::Arc};
 
 use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
 use tokio::sync::RwLock;
 
+#[cfg(feature = "events")]
+use crate::wallet::{
+    events::types::NotificationPreferences,
+    storage::event_history::{EventHistoryRange, StoredWalletEvent, WalletEventFilter},
+};
 use crate::{
     client::secret::{SecretManager, SecretManagerDto},
+    utils::unix_timestamp_now,
     wallet::{
         account::{AccountDetails, SyncOptions},
-        storage::{constants::*, Storage, StorageAdapter},
+        storage::{
+            compaction::CompactionPolicy,
+            constants::*,
+            encryption::{encrypt_record, EncryptionMetadata, StorageEncryption},
+            event_log::{self, AccountStateEvent},
+            migration::migrate,
+            transaction_journal::TransactionJournalEntry,
+            Storage, StorageAdapter,
+        },
         WalletBuilder,
     },
 };
@@ -45,24 +60,61 @@ pub struct StorageManager {
     pub(crate) storage: Storage,
     // account indexes for accounts in the database
     account_indexes: Vec<u32>,
+    // append the changes of every saved account to its event log
+    event_sourcing: bool,
+    // prune synced accounts with too many records
+    compaction_policy: Option<CompactionPolicy>,
 }
 
 impl StorageManager {
     pub(crate) async fn new(
         storage: impl StorageAdapter + Send + Sync + 'static,
-        encryption_key: impl Into<Option<[u8; 32]>> + Send,
+        encryption: Option<StorageEncryption>,
     ) -> crate::wallet::Result<Self> {
         let mut storage = Storage {
             inner: Box::new(storage) as _,
-            encryption_key: encryption_key.into(),
+            encryption_key: None,
+        };
+        // The metadata is stored unencrypted, so the key can be checked before any record is decrypted
+        let encryption_metadata = match storage.inner.get(STORAGE_ENCRYPTION_KEY).await? {
+            Some(record) => serde_json::from_str::<EncryptionMetadata>(&record)?,
+            None => EncryptionMetadata::default(),
         };
-        // Get the db version or set it
+        let new_storage = storage.inner.get(DATABASE_SCHEMA_VERSION_KEY).await?.is_none();
+        match (encryption, encryption_metadata.is_encrypted()) {
+            (None, false) => {}
+            (None, true) => {
+                return Err(crate::wallet::Error::Storage(
+                    "the storage is encrypted, a key or password is required".to_string(),
+                ));
+            }
+            (Some(encryption), true) => storage.encryption_key = Some(encryption_metadata.key(&encryption)?),
+            (Some(encryption), false) => {
+                // Existing records need to be encrypted first, which is done by `Wallet::set_storage_encryption()`
+                if !new_storage {
+                    return Err(crate::wallet::Error::Storage(
+                        "the storage is not encrypted, it can be encrypted with `set_storage_encryption()`".to_string(),
+                    ));
+                }
+                let (encryption_key, encryption_metadata) = EncryptionMetadata::new(&encryption)?;
+                storage
+                    .inner
+                    .set(STORAGE_ENCRYPTION_KEY, serde_json::to_string(&encryption_metadata)?)
+                    .await?;
+                storage.encryption_key = Some(encryption_key);
+            }
+        }
+
+        // Get the db version and migrate older records, or set it
         if let Some(db_schema_version) = storage.get::<u8>(DATABASE_SCHEMA_VERSION_KEY).await? {
-            if db_schema_version != DATABASE_SCHEMA_VERSION {
+            if db_schema_version > DATABASE_SCHEMA_VERSION {
                 return Err(crate::wallet::Error::Storage(format!(
                     "unsupported database schema version {db_schema_version}"
                 )));
             }
+            if db_schema_version < DATABASE_SCHEMA_VERSION {
+                migrate(&mut storage, db_schema_version).await?;
+            }
         } else {
             storage
                 .set(DATABASE_SCHEMA_VERSION_KEY, DATABASE_SCHEMA_VERSION)
@@ -74,11 +126,30 @@ impl StorageManager {
         let storage_manager = Self {
             storage,
             account_indexes,
+            event_sourcing: false,
+            compaction_policy: None,
         };
 
         Ok(storage_manager)
     }
 
+    /// Enables the event sourced storage mode, where the changes of every saved account are appended to its event log.
+    pub(crate) fn with_event_sourcing(mut self, event_sourcing: bool) -> Self {
+        self.event_sourcing = event_sourcing;
+        self
+    }
+
+    /// Sets the policy to compact synced accounts.
+    pub(crate) fn with_compaction_policy(mut self, compaction_policy: Option<CompactionPolicy>) -> Self {
+        self.compaction_policy = compaction_policy;
+        self
+    }
+
+    /// Returns the policy to compact synced accounts.
+    pub(crate) fn compaction_policy(&self) -> Option<&CompactionPolicy> {
+        self.compaction_policy.as_ref()
+    }
+
     pub fn id(&self) -> &'static str {
         self.storage.id()
     }
@@ -92,6 +163,77 @@ impl StorageManager {
         self.storage.get(key).await
     }
 
+    /// Writes the records buffered by the storage adapter to the disk.
+    pub(crate) async fn flush(&mut self) -> crate::wallet::Result<()> {
+        self.storage.flush().await
+    }
+
+    /// Encrypts all records with a new key or password, or decrypts them if `None` is provided. The records are
+    /// rewritten in a single batch, so either all or none of them use the new key.
+    pub(crate) async fn set_encryption(&mut self, encryption: Option<StorageEncryption>) -> crate::wallet::Result<()> {
+        let (encryption_key, encryption_metadata) = match &encryption {
+            Some(encryption) => {
+                let (encryption_key, encryption_metadata) = EncryptionMetadata::new(encryption)?;
+                (Some(encryption_key), encryption_metadata)
+            }
+            None => (None, EncryptionMetadata::default()),
+        };
+
+        let mut records = HashMap::new();
+        for key in self.record_keys().await? {
+            if let Some(record) = self.storage.get_decrypted(&key).await? {
+                records.insert(key, encrypt_record(encryption_key.as_ref(), record)?);
+            }
+        }
+        records.insert(
+            STORAGE_ENCRYPTION_KEY.to_string(),
+            serde_json::to_string(&encryption_metadata)?,
+        );
+
+        self.storage.inner.batch_set(records).await?;
+        self.storage.encryption_key = encryption_key;
+        Ok(())
+    }
+
+    // Returns the keys of all records that the wallet stores
+    async fn record_keys(&self) -> crate::wallet::Result<Vec<String>> {
+        let mut keys = vec![
+            DATABASE_SCHEMA_VERSION_KEY.to_string(),
+            WALLET_INDEXATION_KEY.to_string(),
+            SECRET_MANAGER_KEY.to_string(),
+            ACCOUNTS_INDEXATION_KEY.to_string(),
+        ];
+        let account_indexes = self
+            .storage
+            .get::<Vec<u32>>(ACCOUNTS_INDEXATION_KEY)
+            .await?
+            .unwrap_or_default();
+        for account_index in account_indexes {
+            let account_key = format!("{ACCOUNT_INDEXATION_KEY}{account_index}");
+            let event_log_key = format!("{account_key}-{ACCOUNT_EVENT_LOG}");
+            let event_log_len = self.storage.get::<u64>(&event_log_key).await?.unwrap_or_default();
+            keys.extend((0..event_log_len).map(|sequence| format!("{event_log_key}-{sequence}")));
+            keys.push(event_log_key);
+            keys.push(format!("{account_key}-{ACCOUNT_SYNC_OPTIONS}"));
+            keys.push(format!("{account_key}-{ACCOUNT_TRANSACTION_JOURNAL}"));
+            #[cfg(feature = "events")]
+            keys.push(format!("{account_key}-{ACCOUNT_NOTIFICATION_PREFERENCES}"));
+            #[cfg(feature = "participation")]
+            keys.extend([
+                format!("{PARTICIPATION_EVENTS}{account_index}"),
+                format!("{PARTICIPATION_CACHED_OUTPUTS}{account_index}"),
+            ]);
+            keys.push(account_key);
+        }
+        #[cfg(feature = "events")]
+        if let Some(range) = self.storage.get::<EventHistoryRange>(WALLET_EVENT_HISTORY_KEY).await? {
+            keys.extend((range.start..range.end).map(|sequence| format!("{WALLET_EVENT_HISTORY_KEY}-{sequence}")));
+            keys.push(WALLET_EVENT_HISTORY_KEY.to_string());
+        }
+
+        Ok(keys)
+    }
+
     pub async fn save_wallet_data(&mut self, wallet_builder: &WalletBuilder) -> crate::wallet::Result<()> {
         log::debug!("save_wallet_data");
         self.storage.set(WALLET_INDEXATION_KEY, wallet_builder).await?;
@@ -158,24 +300,57 @@ impl StorageManager {
         Ok(accounts)
     }
 
+    pub async fn get_account(&self, account_index: u32) -> crate::wallet::Result<Option<AccountDetails>> {
+        self.get(&format!("{ACCOUNT_INDEXATION_KEY}{account_index}")).await
+    }
+
+    /// Saves the account, all records are written in a single batch, so either all or none of them are stored.
     pub async fn save_account(&mut self, account: &AccountDetails) -> crate::wallet::Result<()> {
+        // Needs to happen before the account is saved, because the changes are computed from the saved account
+        let mut records = if self.event_sourcing {
+            self.account_event_records(account).await?
+        } else {
+            HashMap::new()
+        };
+
         // Only add account index if not already present
         if !self.account_indexes.contains(account.index()) {
             self.account_indexes.push(*account.index());
         }
 
-        self.storage
-            .set(ACCOUNTS_INDEXATION_KEY, self.account_indexes.clone())
-            .await?;
-        self.storage
-            .set(&format!("{ACCOUNT_INDEXATION_KEY}{}", account.index()), account)
-            .await
+        records.insert(
+            ACCOUNTS_INDEXATION_KEY.to_string(),
+            serde_json::to_string(&self.account_indexes)?,
+        );
+        records.insert(
+            format!("{ACCOUNT_INDEXATION_KEY}{}", account.index()),
+            serde_json::to_string(account)?,
+        );
+
+        self.storage.batch_set(records).await
     }
 
     pub async fn remove_account(&mut self, account_index: u32) -> crate::wallet::Result<()> {
         self.storage
             .remove(&format!("{ACCOUNT_INDEXATION_KEY}{account_index}"))
             .await?;
+        let event_log_key = format!("{ACCOUNT_INDEXATION_KEY}{account_index}-{ACCOUNT_EVENT_LOG}");
+        if let Some(event_log_len) = self.storage.get::<u64>(&event_log_key).await? {
+            for sequence in 0..event_log_len {
+                self.storage.remove(&format!("{event_log_key}-{sequence}")).await?;
+            }
+            self.storage.remove(&event_log_key).await?;
+        }
+        // The index can be used again for a new account, which must not get the options of the removed one
+        let sync_options_key = format!("{ACCOUNT_INDEXATION_KEY}{account_index}-{ACCOUNT_SYNC_OPTIONS}");
+        self.storage.remove(&sync_options_key).await?;
+        let journal_key = format!("{ACCOUNT_INDEXATION_KEY}{account_index}-{ACCOUNT_TRANSACTION_JOURNAL}");
+        self.storage.remove(&journal_key).await?;
+        #[cfg(feature = "events")]
+        {
+            let key = format!("{ACCOUNT_INDEXATION_KEY}{account_index}-{ACCOUNT_NOTIFICATION_PREFERENCES}");
+            self.storage.remove(&key).await?;
+        }
         self.account_indexes.retain(|a| a != &account_index);
         self.storage
             .set(ACCOUNTS_INDEXATION_KEY, self.account_indexes.clone())
@@ -195,4 +370,189 @@ impl StorageManager {
         let key = format!("{ACCOUNT_INDEXATION_KEY}{account_index}-{ACCOUNT_SYNC_OPTIONS}");
         self.storage.get(&key).await
     }
+
+    pub(crate) async fn get_transaction_journal(
+        &self,
+        account_index: u32,
+    ) -> crate::wallet::Result<Vec<TransactionJournalEntry>> {
+        let key = format!("{ACCOUNT_INDEXATION_KEY}{account_index}-{ACCOUNT_TRANSACTION_JOURNAL}");
+        Ok(self.storage.get(&key).await?.unwrap_or_default())
+    }
+
+    // The record is removed once the journal is empty, so the storage of accounts without transactions in progress
+    // doesn't change
+    pub(crate) async fn set_transaction_journal(
+        &mut self,
+        account_index: u32,
+        journal: &[TransactionJournalEntry],
+    ) -> crate::wallet::Result<()> {
+        let key = format!("{ACCOUNT_INDEXATION_KEY}{account_index}-{ACCOUNT_TRANSACTION_JOURNAL}");
+        if journal.is_empty() {
+            self.storage.remove(&key).await
+        } else {
+            self.storage.set(&key, journal).await
+        }
+    }
+
+    #[cfg(feature = "events")]
+    pub async fn set_notification_preferences(
+        &mut self,
+        account_index: u32,
+        preferences: &NotificationPreferences,
+    ) -> crate::wallet::Result<()> {
+        let key = format!("{ACCOUNT_INDEXATION_KEY}{account_index}-{ACCOUNT_NOTIFICATION_PREFERENCES}");
+        self.storage.set(&key, preferences.clone()).await
+    }
+
+    #[cfg(feature = "events")]
+    pub async fn get_notification_preferences(
+        &self,
+        account_index: u32,
+    ) -> crate::wallet::Result<Option<NotificationPreferences>> {
+        let key = format!("{ACCOUNT_INDEXATION_KEY}{account_index}-{ACCOUNT_NOTIFICATION_PREFERENCES}");
+        self.storage.get(&key).await
+    }
+
+    /// Returns the sequence numbers of the stored wallet events.
+    #[cfg(feature = "events")]
+    pub(crate) async fn get_wallet_event_range(&self) -> crate::wallet::Result<EventHistoryRange> {
+        Ok(self.storage.get(WALLET_EVENT_HISTORY_KEY).await?.unwrap_or_default())
+    }
+
+    /// Appends emitted events to the history, all records are written in a single batch.
+    #[cfg(feature = "events")]
+    pub(crate) async fn append_wallet_events(&mut self, events: &[StoredWalletEvent]) -> crate::wallet::Result<()> {
+        let Some(last_event) = events.last() else {
+            return Ok(());
+        };
+        let mut range = self.get_wallet_event_range().await?;
+        range.end = range.end.max(last_event.sequence + 1);
+
+        let mut records = events
+            .iter()
+            .map(|event| {
+                Ok((
+                    format!("{WALLET_EVENT_HISTORY_KEY}-{}", event.sequence),
+                    serde_json::to_string(event)?,
+                ))
+            })
+            .collect::<crate::wallet::Result<HashMap<_, _>>>()?;
+        records.insert(WALLET_EVENT_HISTORY_KEY.to_string(), serde_json::to_string(&range)?);
+
+        self.storage.batch_set(records).await
+    }
+
+    /// Returns the stored wallet events with a sequence number of at least `since` that match the filter.
+    #[cfg(feature = "events")]
+    pub async fn get_wallet_events(
+        &self,
+        since: u64,
+        filter: &WalletEventFilter,
+    ) -> crate::wallet::Result<Vec<StoredWalletEvent>> {
+        let range = self.get_wallet_event_range().await?;
+
+        let mut events = Vec::new();
+        for sequence in range.start.max(since)..range.end {
+            if let Some(event) = self
+                .storage
+                .get::<StoredWalletEvent>(&format!("{WALLET_EVENT_HISTORY_KEY}-{sequence}"))
+                .await?
+            {
+                if filter.matches(&event) {
+                    events.push(event);
+                }
+            }
+        }
+
+        Ok(events)
+    }
+
+    /// Removes the stored wallet events with a sequence number lower than `before`, the sequence numbers of later
+    /// events don't change.
+    #[cfg(feature = "events")]
+    pub async fn clear_wallet_events(&mut self, before: u64) -> crate::wallet::Result<()> {
+        let mut range = self.get_wallet_event_range().await?;
+        let start = before.min(range.end);
+        if start <= range.start {
+            return Ok(());
+        }
+
+        for sequence in range.start..start {
+            self.storage
+                .remove(&format!("{WALLET_EVENT_HISTORY_KEY}-{sequence}"))
+                .await?;
+        }
+        range.start = start;
+        self.storage.set(WALLET_EVENT_HISTORY_KEY, range).await
+    }
+
+    // Returns the records to append the changes compared to the saved account to the event log, the first event
+    // contains the full account.
+    async fn account_event_records(&self, account: &AccountDetails) -> crate::wallet::Result<HashMap<String, String>> {
+        let account_key = format!("{ACCOUNT_INDEXATION_KEY}{}", account.index());
+        let event_log_key = format!("{account_key}-{ACCOUNT_EVENT_LOG}");
+        let sequence = self.storage.get::<u64>(&event_log_key).await?.unwrap_or_default();
+
+        let previous = if sequence == 0 {
+            Map::new()
+        } else {
+            self.storage
+                .get::<Map<String, Value>>(&account_key)
+                .await?
+                .unwrap_or_default()
+        };
+        let current = match serde_json::to_value(account)? {
+            Value::Object(current) => current,
+            _ => return Err(crate::wallet::Error::Storage("account is not serialized as object".to_string())),
+        };
+
+        let changes = event_log::diff(&previous, &current);
+        if changes.is_empty() {
+            return Ok(HashMap::new());
+        }
+
+        let event = AccountStateEvent {
+            sequence,
+            timestamp: unix_timestamp_now().as_millis(),
+            changes,
+        };
+
+        Ok(HashMap::from([
+            (format!("{event_log_key}-{sequence}"), serde_json::to_string(&event)?),
+            (event_log_key, serde_json::to_string(&(sequence + 1))?),
+        ]))
+    }
+
+    /// Returns the event log of an account, it's only appended to in the event sourced storage mode.
+    pub async fn get_account_events(&self, account_index: u32) -> crate::wallet::Result<Vec<AccountStateEvent>> {
+        let event_log_key = format!("{ACCOUNT_INDEXATION_KEY}{account_index}-{ACCOUNT_EVENT_LOG}");
+        let event_log_len = self.storage.get::<u64>(&event_log_key).await?.unwrap_or_default();
+
+        let mut events = Vec::new();
+        for sequence in 0..event_log_len {
+            if let Some(event) = self.storage.get(&format!("{event_log_key}-{sequence}")).await? {
+                events.push(event);
+            }
+        }
+
+        Ok(events)
+    }
+
+    /// Reconstructs an account as it was at the timestamp in milliseconds, by replaying its event log. Returns `None`
+    /// if there is no event until then.
+    pub async fn get_account_at(
+        &self,
+        account_index: u32,
+        timestamp: u128,
+    ) -> crate::wallet::Result<Option<AccountDetails>> {
+        let mut state = None;
+        for event in self.get_account_events(account_index).await? {
+            if event.timestamp > timestamp {
+                break;
+            }
+            event_log::apply(state.get_or_insert_with(Map::new), &event.changes);
+        }
+
+        Ok(state.map(|state| serde_json::from_value(Value::Object(state))).transpose()?)
+    }
 }
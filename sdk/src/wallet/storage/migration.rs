@@ -0,0 +1,164 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use serde_json::{Map, Value};
+
+use crate::wallet::storage::{
+    constants::{DATABASE_SCHEMA_VERSION, DATABASE_SCHEMA_VERSION_KEY, WALLET_INDEXATION_KEY},
+    Storage,
+};
+
+/// A migration of a stored record from a database schema version to the next one.
+struct Migration {
+    /// The schema version of the records the migration is applied to.
+    from_version: u8,
+    /// The key of the migrated record.
+    key: &'static str,
+    /// Rewrites the JSON of the record in the format of the next schema version.
+    migrate: fn(&mut Value),
+}
+
+/// The migrations in the order they're applied.
+const MIGRATIONS: &[Migration] = &[Migration {
+    from_version: 1,
+    key: WALLET_INDEXATION_KEY,
+    migrate: camel_case_wallet_data,
+}];
+
+/// Migrates the records stored with an older database schema version to the current one, so stored wallets keep
+/// working after an upgrade.
+pub(crate) async fn migrate(storage: &mut Storage, from_version: u8) -> crate::wallet::Result<()> {
+    for migration in MIGRATIONS
+        .iter()
+        .filter(|migration| migration.from_version >= from_version)
+    {
+        log::debug!(
+            "[STORAGE] migrating {} from schema version {}",
+            migration.key,
+            migration.from_version
+        );
+        if let Some(mut record) = storage.get::<Value>(migration.key).await? {
+            (migration.migrate)(&mut record);
+            storage.set(migration.key, record).await?;
+        }
+    }
+
+    storage
+        .set(DATABASE_SCHEMA_VERSION_KEY, DATABASE_SCHEMA_VERSION)
+        .await
+}
+
+// Version 1 stored the wallet data and its storage options with snake_case keys, the client options were already
+// camelCase
+fn camel_case_wallet_data(wallet_data: &mut Value) {
+    if let Value::Object(wallet_data) = wallet_data {
+        camel_case_keys(wallet_data);
+        if let Some(Value::Object(storage_options)) = wallet_data.get_mut("storageOptions") {
+            camel_case_keys(storage_options);
+        }
+    }
+}
+
+fn camel_case_keys(map: &mut Map<String, Value>) {
+    for (key, value) in core::mem::take(map) {
+        map.insert(to_camel_case(&key), value);
+    }
+}
+
+fn to_camel_case(key: &str) -> String {
+    let mut parts = key.split('_');
+    let mut camel_case = parts.next().unwrap_or_default().to_string();
+    for part in parts {
+        let mut chars = part.chars();
+        if let Some(first) = chars.next() {
+            camel_case.extend(first.to_uppercase());
+            camel_case.push_str(chars.as_str());
+        }
+    }
+    camel_case
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::wallet::{
+        storage::{
+            adapter::{memory::Memory, StorageAdapter},
+            manager::StorageManager,
+        },
+        WalletBuilder,
+    };
+
+    #[test]
+    fn camel_case() {
+        assert_eq!(to_camel_case("coin_type"), "coinType");
+        assert_eq!(to_camel_case("storage_file_name"), "storageFileName");
+        assert_eq!(to_camel_case("alreadyCamelCase"), "alreadyCamelCase");
+    }
+
+    #[test]
+    fn wallet_data_v1() {
+        let mut wallet_data = json!({
+            "client_options": { "apiTimeout": { "secs": 15, "nanos": 0 } },
+            "coin_type": 4219,
+            "storage_options": {
+                "storage_path": "walletdb",
+                "storage_file_name": null,
+                "manager_store": "Memory",
+                "event_sourcing": true,
+            },
+        });
+
+        camel_case_wallet_data(&mut wallet_data);
+
+        assert_eq!(
+            wallet_data,
+            json!({
+                "clientOptions": { "apiTimeout": { "secs": 15, "nanos": 0 } },
+                "coinType": 4219,
+                "storageOptions": {
+                    "storagePath": "walletdb",
+                    "storageFileName": null,
+                    "managerStore": "Memory",
+                    "eventSourcing": true,
+                },
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn migrate_storage_v1() {
+        let mut memory = Memory::default();
+        memory
+            .set(DATABASE_SCHEMA_VERSION_KEY, "1".to_string())
+            .await
+            .unwrap();
+        memory
+            .set(
+                WALLET_INDEXATION_KEY,
+                json!({
+                    "coin_type": 4219,
+                    "storage_options": { "storage_path": "walletdb", "manager_store": "Memory" },
+                })
+                .to_string(),
+            )
+            .await
+            .unwrap();
+
+        let storage_manager = StorageManager::new(memory, None).await.unwrap();
+
+        assert_eq!(
+            storage_manager.get::<u8>(DATABASE_SCHEMA_VERSION_KEY).await.unwrap(),
+            Some(DATABASE_SCHEMA_VERSION)
+        );
+        let wallet_data = storage_manager
+            .get::<Value>(WALLET_INDEXATION_KEY)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(wallet_data["coinType"], json!(4219));
+        serde_json::from_value::<WalletBuilder>(wallet_data).unwrap();
+    }
+}
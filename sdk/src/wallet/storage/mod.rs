@@ -3,10 +3,24 @@
 
 /// Storage adapter.
 pub mod adapter;
+/// Compaction of the stored accounts.
+pub mod compaction;
 /// Storage constants.
 pub mod constants;
+/// Encryption of the storage at rest.
+pub mod encryption;
+/// History of the emitted wallet events.
+#[cfg(feature = "events")]
+#[cfg_attr(docsrs, doc(cfg(feature = "events")))]
+pub mod event_history;
+/// Events of the event sourced storage mode.
+pub mod event_log;
 /// Storage manager.
 pub mod manager;
+/// Migrations of the stored records from older schema versions.
+mod migration;
+/// Journal of the transactions that aren't stored in their account yet.
+pub mod transaction_journal;
 /// Storage functions related to participation.
 #[cfg(feature = "participation")]
 #[cfg_attr(docsrs, doc(cfg(feature = "participation")))]
@@ -14,10 +28,12 @@ mod participation;
 
 use std::collections::HashMap;
 
-use crypto::ciphers::chacha;
 use serde::{Deserialize, Serialize};
 
-use self::adapter::StorageAdapter;
+use self::{
+    adapter::StorageAdapter,
+    encryption::{decrypt_record, encrypt_record},
+};
 
 #[derive(Debug)]
 pub(crate) struct Storage {
@@ -31,58 +47,41 @@ impl Storage {
     }
 
     async fn get<T: for<'de> Deserialize<'de>>(&self, key: &str) -> crate::wallet::Result<Option<T>> {
-        match self.inner.get(key).await? {
-            Some(record) => {
-                if let Some(key) = &self.encryption_key {
-                    if serde_json::from_str::<Vec<u8>>(&record).is_ok() {
-                        Ok(Some(serde_json::from_str(&String::from_utf8_lossy(
-                            &chacha::aead_decrypt(key, record.as_bytes())?,
-                        ))?))
-                    } else {
-                        Ok(Some(serde_json::from_str(&record)?))
-                    }
-                } else {
-                    Ok(Some(serde_json::from_str(&record)?))
-                }
-            }
+        match self.get_decrypted(key).await? {
+            Some(record) => Ok(Some(serde_json::from_str(&record)?)),
             None => Ok(None),
         }
     }
 
-    async fn set<T: Serialize + Send>(&mut self, key: &str, record: T) -> crate::wallet::Result<()> {
-        let record = serde_json::to_string(&record)?;
+    // Gets the JSON of a record, decrypted if the storage is encrypted
+    async fn get_decrypted(&self, key: &str) -> crate::wallet::Result<Option<String>> {
         self.inner
-            .set(
-                key,
-                if let Some(key) = &self.encryption_key {
-                    let output = chacha::aead_encrypt(key, record.as_bytes())?;
-                    serde_json::to_string(&output)?
-                } else {
-                    record
-                },
-            )
-            .await
+            .get(key)
+            .await?
+            .map(|record| decrypt_record(self.encryption_key.as_ref(), record))
+            .transpose()
+    }
+
+    async fn set<T: Serialize + Send>(&mut self, key: &str, record: T) -> crate::wallet::Result<()> {
+        let record = encrypt_record(self.encryption_key.as_ref(), serde_json::to_string(&record)?)?;
+        self.inner.set(key, record).await
     }
 
-    #[allow(dead_code)]
     async fn batch_set(&mut self, records: HashMap<String, String>) -> crate::wallet::Result<()> {
-        self.inner
-            .batch_set(if let Some(key) = &self.encryption_key {
-                let mut encrypted_records = HashMap::new();
-                for (id, record) in records {
-                    let output = chacha::aead_encrypt(key, record.as_bytes())?;
-                    encrypted_records.insert(id, serde_json::to_string(&output)?);
-                }
-                encrypted_records
-            } else {
-                records
-            })
-            .await
+        let records = records
+            .into_iter()
+            .map(|(key, record)| Ok((key, encrypt_record(self.encryption_key.as_ref(), record)?)))
+            .collect::<crate::wallet::Result<_>>()?;
+        self.inner.batch_set(records).await
     }
 
     async fn remove(&mut self, key: &str) -> crate::wallet::Result<()> {
         self.inner.remove(key).await
     }
+
+    async fn flush(&mut self) -> crate::wallet::Result<()> {
+        self.inner.flush().await
+    }
 }
 
 impl Drop for Storage {
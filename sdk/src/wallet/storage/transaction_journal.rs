@@ -0,0 +1,37 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Journal of the transactions that aren't stored in their account yet.
+//!
+//! A transaction is added to the journal of its account when it's prepared and updated when it's signed and
+//! submitted. Once the transaction is stored in the account, it's acknowledged and removed from the journal. If the
+//! wallet stops in between, the journal is replayed when the wallet is loaded again, so a signed transaction is never
+//! lost and the inputs of a transaction that was never signed don't stay locked.
+
+use serde::{Deserialize, Serialize};
+
+use crate::{types::block::output::OutputId, wallet::account::types::Transaction};
+
+/// A transaction in the journal of an account.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionJournalEntry {
+    /// The hash of the transaction essence, which identifies the transaction before it's signed.
+    pub essence_hash: String,
+    /// The inputs of the transaction, they are locked until the transaction is stored or rolled back.
+    pub inputs: Vec<OutputId>,
+    /// The last stage the transaction reached.
+    pub stage: TransactionStage,
+}
+
+/// The stage of a transaction in the journal.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", content = "transaction", rename_all = "camelCase")]
+pub enum TransactionStage {
+    /// The transaction was prepared, but not signed, so it can't have been submitted.
+    Prepared,
+    /// The transaction was signed, but maybe not submitted.
+    Signed(Box<Transaction>),
+    /// The block with the transaction was submitted, but the transaction wasn't stored in the account.
+    Submitted(Box<Transaction>),
+}
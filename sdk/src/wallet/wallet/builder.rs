@@ -12,17 +12,22 @@ use futures::{future::try_join_all, FutureExt};
 use serde::{Deserialize, Serialize};
 use tokio::sync::RwLock;
 
+#[cfg(all(feature = "events", feature = "storage"))]
+use crate::wallet::events::EventRecorder;
 #[cfg(feature = "events")]
 use crate::wallet::events::EventEmitter;
 #[cfg(all(feature = "storage", not(feature = "rocksdb")))]
 use crate::wallet::storage::adapter::memory::Memory;
 #[cfg(feature = "storage")]
 use crate::wallet::{
-    account::AccountDetails,
+    account::{operations::transaction::journal::recover_transactions, AccountDetails},
     storage::{
+        compaction::CompactionPolicy,
         constants::default_storage_path,
+        encryption::StorageEncryption,
         manager::{ManagerStorage, StorageManager},
     },
+    wallet::operations::account_cache::AccountCache,
 };
 use crate::{
     client::secret::SecretManager,
@@ -30,6 +35,7 @@ use crate::{
 };
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
 /// Builder for the wallet.
 pub struct WalletBuilder {
     client_options: Option<ClientOptions>,
@@ -41,13 +47,25 @@ pub struct WalletBuilder {
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 #[cfg(feature = "storage")]
 #[cfg_attr(docsrs, doc(cfg(feature = "storage")))]
 pub(crate) struct StorageOptions {
     pub(crate) storage_path: PathBuf,
     pub(crate) storage_file_name: Option<String>,
-    pub(crate) storage_encryption_key: Option<[u8; 32]>,
+    // Never stored, it needs to be provided every time the wallet is built
+    #[serde(skip)]
+    pub(crate) storage_encryption: Option<StorageEncryption>,
     pub(crate) manager_store: ManagerStorage,
+    #[serde(default)]
+    pub(crate) event_sourcing: bool,
+    #[serde(default)]
+    pub(crate) account_cache_budget: Option<usize>,
+    #[serde(default)]
+    pub(crate) compaction_policy: Option<CompactionPolicy>,
+    #[cfg(feature = "events")]
+    #[serde(default)]
+    pub(crate) event_persistence: bool,
 }
 
 #[cfg(feature = "storage")]
@@ -56,8 +74,13 @@ impl Default for StorageOptions {
         Self {
             storage_path: default_storage_path().into(),
             storage_file_name: None,
-            storage_encryption_key: None,
+            storage_encryption: None,
             manager_store: ManagerStorage::default(),
+            event_sourcing: false,
+            account_cache_budget: None,
+            compaction_policy: None,
+            #[cfg(feature = "events")]
+            event_persistence: false,
         }
     }
 }
@@ -100,10 +123,57 @@ impl WalletBuilder {
     #[cfg(feature = "storage")]
     #[cfg_attr(docsrs, doc(cfg(feature = "storage")))]
     pub fn with_storage_path(mut self, path: &str) -> Self {
-        self.storage_options = Some(StorageOptions {
-            storage_path: path.into(),
-            ..Default::default()
-        });
+        self.storage_options.get_or_insert_with(Default::default).storage_path = path.into();
+        self
+    }
+
+    /// Encrypts every record of the storage with XChaCha20-Poly1305, so the accounts, outputs and transactions can't
+    /// be read from the disk. The key or password is never stored and needs to be provided every time the wallet is
+    /// built. An existing unencrypted storage needs to be encrypted with
+    /// [`Wallet::set_storage_encryption()`](crate::wallet::Wallet::set_storage_encryption) first.
+    #[cfg(feature = "storage")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "storage")))]
+    pub fn with_storage_encryption(mut self, encryption: StorageEncryption) -> Self {
+        self.storage_options.get_or_insert_with(Default::default).storage_encryption = Some(encryption);
+        self
+    }
+
+    /// Enables the event sourced storage mode, where every change of an account is appended to an event log, so
+    /// earlier states of the account can be reconstructed.
+    #[cfg(feature = "storage")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "storage")))]
+    pub fn with_event_sourcing(mut self, event_sourcing: bool) -> Self {
+        self.storage_options.get_or_insert_with(Default::default).event_sourcing = event_sourcing;
+        self
+    }
+
+    /// Enables the persistence of events, where every emitted event is appended to a history in the storage, so
+    /// applications can replay the events they missed with
+    /// [`Wallet::get_events()`](crate::wallet::Wallet::get_events).
+    #[cfg(all(feature = "storage", feature = "events"))]
+    #[cfg_attr(docsrs, doc(cfg(all(feature = "storage", feature = "events"))))]
+    pub fn with_event_persistence(mut self, event_persistence: bool) -> Self {
+        self.storage_options.get_or_insert_with(Default::default).event_persistence = event_persistence;
+        self
+    }
+
+    /// Set the maximum memory in bytes used by the outputs and transactions of the accounts, estimated from their
+    /// packed size. The ones of the least recently used accounts are evicted until the rest fits and loaded from the
+    /// storage again when needed, the ones of the used account are always kept. Useful for wallets with thousands of
+    /// accounts, by default all accounts are kept in memory.
+    #[cfg(feature = "storage")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "storage")))]
+    pub fn with_account_cache_budget(mut self, max_bytes: usize) -> Self {
+        self.storage_options.get_or_insert_with(Default::default).account_cache_budget = Some(max_bytes);
+        self
+    }
+
+    /// Set a policy to prune accounts with many records automatically after they got synced, so the storage of
+    /// long-running wallets doesn't grow forever. Pruned records can be archived to a separate directory.
+    #[cfg(feature = "storage")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "storage")))]
+    pub fn with_compaction_policy(mut self, compaction_policy: CompactionPolicy) -> Self {
+        self.storage_options.get_or_insert_with(Default::default).compaction_policy = Some(compaction_policy);
         self
     }
 
@@ -135,7 +205,12 @@ impl WalletBuilder {
         let storage = Memory::default();
 
         #[cfg(feature = "storage")]
-        let mut storage_manager = Arc::new(tokio::sync::Mutex::new(StorageManager::new(storage, None).await?));
+        let mut storage_manager = Arc::new(tokio::sync::Mutex::new(
+            StorageManager::new(storage, storage_options.storage_encryption.clone())
+                .await?
+                .with_event_sourcing(storage_options.event_sourcing)
+                .with_compaction_policy(storage_options.compaction_policy.clone()),
+        ));
 
         #[cfg(feature = "storage")]
         let read_manager_builder = storage_manager.lock().await.get_wallet_data().await?;
@@ -189,11 +264,27 @@ impl WalletBuilder {
             .finish()?;
 
         #[cfg(feature = "events")]
-        let event_emitter = Arc::new(tokio::sync::Mutex::new(EventEmitter::new()));
+        let mut event_emitter = EventEmitter::new();
+        #[cfg(all(feature = "events", feature = "storage"))]
+        if storage_options.event_persistence {
+            let next_sequence = storage_manager.lock().await.get_wallet_event_range().await?.end;
+            event_emitter = event_emitter.with_recorder(EventRecorder::new(storage_manager.clone(), next_sequence));
+        }
+        #[cfg(feature = "events")]
+        let event_emitter = Arc::new(tokio::sync::Mutex::new(event_emitter));
+        let address_generation_lock = Arc::new(tokio::sync::Mutex::new(()));
+        // Created before the accounts, so they can check the aliases of each other
+        let wallet_accounts = Arc::new(RwLock::new(Vec::new()));
 
         #[cfg(feature = "storage")]
         let mut accounts = storage_manager.lock().await.get_accounts().await.unwrap_or_default();
 
+        // Transactions that were in progress when the wallet stopped are recovered or rolled back from the journals
+        #[cfg(feature = "storage")]
+        for account in accounts.iter_mut() {
+            recover_transactions(&mut *storage_manager.lock().await, account).await?;
+        }
+
         // It happened that inputs got locked, the transaction failed, but they weren't unlocked again, so we do this
         // here
         #[cfg(feature = "storage")]
@@ -207,6 +298,8 @@ impl WalletBuilder {
                 self.secret_manager
                     .clone()
                     .expect("secret_manager needs to be provided"),
+                address_generation_lock.clone(),
+                Arc::downgrade(&wallet_accounts),
                 #[cfg(feature = "events")]
                 event_emitter.clone(),
                 #[cfg(feature = "storage")]
@@ -224,8 +317,22 @@ impl WalletBuilder {
             }
         }
 
-        Ok(Wallet {
-            accounts: Arc::new(RwLock::new(accounts)),
+        #[cfg(feature = "storage")]
+        let mut loaded_accounts = Vec::with_capacity(accounts.len());
+        #[cfg(feature = "storage")]
+        for account in &accounts {
+            let account_details = account.read().await;
+            loaded_accounts.push((*account_details.index(), account_details.evictable_size()));
+        }
+        *wallet_accounts.write().await = accounts;
+
+        let wallet = Wallet {
+            #[cfg(feature = "storage")]
+            account_cache: Arc::new(tokio::sync::Mutex::new(AccountCache::new(
+                storage_options.account_cache_budget,
+                loaded_accounts,
+            ))),
+            accounts: wallet_accounts,
             background_syncing_status: Arc::new(AtomicUsize::new(0)),
             client_options: Arc::new(RwLock::new(
                 self.client_options
@@ -237,13 +344,18 @@ impl WalletBuilder {
             secret_manager: self
                 .secret_manager
                 .ok_or(crate::wallet::Error::MissingParameter("secret_manager"))?,
+            address_generation_lock,
             #[cfg(feature = "events")]
             event_emitter,
             #[cfg(feature = "storage")]
             storage_options,
             #[cfg(feature = "storage")]
             storage_manager,
-        })
+        };
+        #[cfg(all(feature = "events", feature = "ledger_nano"))]
+        wallet.emit_signing_queue_positions().await;
+
+        Ok(wallet)
     }
 
     #[cfg(feature = "storage")]
@@ -12,19 +12,26 @@ use std::sync::{
 use tokio::sync::RwLock;
 
 #[cfg(feature = "storage")]
-use self::builder::StorageOptions;
+use self::{builder::StorageOptions, operations::account_cache::AccountCache};
 pub use self::builder::WalletBuilder;
 #[cfg(feature = "events")]
 use crate::wallet::events::{
     types::{Event, WalletEventType},
     EventEmitter,
 };
+#[cfg(all(feature = "webhooks", not(target_family = "wasm")))]
+use crate::wallet::events::webhook::{spawn_webhook, WebhookOptions};
 #[cfg(feature = "storage")]
 use crate::wallet::storage::manager::StorageManager;
 use crate::{
     client::{secret::SecretManager, verify_mnemonic, Client},
     wallet::{
-        account::{builder::AccountBuilder, operations::syncing::SyncOptions, types::AccountBalance, Account},
+        account::{
+            builder::AccountBuilder,
+            operations::syncing::SyncOptions,
+            types::{AccountBalance, AccountIdentifier},
+            Account, AccountHealth, InclusionStats,
+        },
         ClientOptions,
     },
 };
@@ -40,12 +47,16 @@ pub struct Wallet {
     pub(crate) client_options: Arc<RwLock<ClientOptions>>,
     pub(crate) coin_type: Arc<AtomicU32>,
     pub(crate) secret_manager: Arc<RwLock<SecretManager>>,
+    // shared with the accounts, so they don't derive addresses from the same seed at the same time
+    pub(crate) address_generation_lock: Arc<tokio::sync::Mutex<()>>,
     #[cfg(feature = "events")]
     pub(crate) event_emitter: Arc<tokio::sync::Mutex<EventEmitter>>,
     #[cfg(feature = "storage")]
     pub(crate) storage_options: StorageOptions,
     #[cfg(feature = "storage")]
     pub(crate) storage_manager: Arc<tokio::sync::Mutex<StorageManager>>,
+    #[cfg(feature = "storage")]
+    pub(crate) account_cache: Arc<tokio::sync::Mutex<AccountCache>>,
 }
 
 impl Wallet {
@@ -62,6 +73,7 @@ impl Wallet {
             self.client_options.clone(),
             self.coin_type.load(Ordering::Relaxed),
             self.secret_manager.clone(),
+            self.address_generation_lock.clone(),
             #[cfg(feature = "events")]
             self.event_emitter.clone(),
             #[cfg(feature = "storage")]
@@ -71,7 +83,7 @@ impl Wallet {
 
     /// Get all accounts
     pub async fn get_accounts(&self) -> crate::wallet::Result<Vec<Account>> {
-        Ok(self.accounts.read().await.clone())
+        self.load_accounts(&self.accounts.read().await).await
     }
 
     /// Get all account aliases
@@ -84,6 +96,33 @@ impl Wallet {
         Ok(aliases)
     }
 
+    /// Get the health of all accounts, updated by every sync of an account
+    pub async fn get_accounts_health(&self) -> Vec<AccountHealth> {
+        let accounts = self.accounts.read().await;
+        let mut accounts_health = Vec::with_capacity(accounts.len());
+        for handle in accounts.iter() {
+            accounts_health.push(handle.health().await);
+        }
+        accounts_health
+    }
+
+    /// Get the inclusion latency statistics of the transactions of all accounts, to compare nodes or tune the retry
+    /// intervals.
+    pub async fn inclusion_stats(&self) -> crate::wallet::Result<InclusionStats> {
+        let mut all_latencies = Vec::new();
+        let mut all_pending_transactions = 0;
+        let accounts = self.accounts.read().await;
+
+        for account in accounts.iter() {
+            let account = self.load_account(&accounts, account).await?;
+            let (latencies, pending_transactions) = account.inclusion_latencies().await;
+            all_latencies.extend(latencies);
+            all_pending_transactions += pending_transactions;
+        }
+
+        Ok(InclusionStats::new(all_latencies, all_pending_transactions))
+    }
+
     /// Removes the latest account (account with the largest account index).
     pub async fn remove_latest_account(&self) -> crate::wallet::Result<()> {
         let mut largest_account_index_opt = None;
@@ -122,6 +161,50 @@ impl Wallet {
         Ok(())
     }
 
+    /// Removes an account and its data in the storage. Fails with [`Error::AccountHasFunds`](crate::wallet::Error)
+    /// if the account had unspent outputs at its last sync, unless `force` is true. The index of a removed account is
+    /// used again for the next account that is created, which then has the same addresses, so a forcibly removed
+    /// account can be recreated with its funds.
+    pub async fn remove_account<I: Into<AccountIdentifier> + Send>(
+        &self,
+        identifier: I,
+        force: bool,
+    ) -> crate::wallet::Result<()> {
+        // Loads the details, so the unspent outputs are known
+        let account = self.get_account(identifier).await?;
+        let mut accounts = self.accounts.write().await;
+
+        let account_index = {
+            let account_details = account.read().await;
+            if !force && !account_details.unspent_outputs().is_empty() {
+                return Err(crate::wallet::Error::AccountHasFunds(*account_details.index()));
+            }
+            *account_details.index()
+        };
+        log::debug!("[remove_account] {account_index}");
+
+        let mut position = None;
+        for (i, account) in accounts.iter().enumerate() {
+            if *account.read().await.index() == account_index {
+                position = Some(i);
+                break;
+            }
+        }
+        // The account was removed concurrently
+        let Some(position) = position else {
+            return Ok(());
+        };
+        accounts.remove(position);
+
+        #[cfg(feature = "storage")]
+        {
+            self.account_cache.lock().await.remove(account_index);
+            self.storage_manager.lock().await.remove_account(account_index).await?;
+        }
+
+        Ok(())
+    }
+
     /// Get the [SecretManager]
     pub fn get_secret_manager(&self) -> Arc<RwLock<SecretManager>> {
         self.secret_manager.clone()
@@ -133,7 +216,7 @@ impl Wallet {
         let accounts = self.accounts.read().await;
 
         for account in accounts.iter() {
-            balance += account.balance().await?;
+            balance += self.load_account(&accounts, account).await?.balance().await?;
         }
 
         Ok(balance)
@@ -143,8 +226,10 @@ impl Wallet {
     pub async fn sync(&self, options: Option<SyncOptions>) -> crate::wallet::Result<AccountBalance> {
         let mut balance = AccountBalance::default();
 
-        for account in self.accounts.read().await.iter() {
-            balance += account.sync(options.clone()).await?;
+        let accounts = self.accounts.read().await;
+
+        for account in accounts.iter() {
+            balance += self.load_account(&accounts, account).await?.sync(options.clone()).await?;
         }
 
         Ok(balance)
@@ -161,6 +246,19 @@ impl Wallet {
         emitter.on(events, handler);
     }
 
+    /// Posts the wallet events of the types in the options to a webhook, each delivery is signed and retried as
+    /// described in [`webhook`](crate::wallet::events::webhook). The webhook is removed together with the listeners of
+    /// its event types by [`Wallet::clear_listeners()`].
+    #[cfg(all(feature = "webhooks", not(target_family = "wasm")))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "webhooks")))]
+    pub async fn add_webhook(&self, options: WebhookOptions) -> crate::wallet::Result<()> {
+        log::debug!("[add_webhook] {}", options.url());
+        let event_types = options.event_types().to_vec();
+        let handler = spawn_webhook(options)?;
+        self.event_emitter.lock().await.on(event_types, handler);
+        Ok(())
+    }
+
     /// Remove wallet event listeners, empty vec will remove all listeners
     #[cfg(feature = "events")]
     #[cfg_attr(docsrs, doc(cfg(feature = "events")))]
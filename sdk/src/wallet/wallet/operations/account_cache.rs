@@ -0,0 +1,106 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+#[cfg(feature = "storage")]
+use std::collections::VecDeque;
+
+use crate::wallet::{account::Account, Wallet};
+
+/// Keeps the outputs and transactions of the accounts in memory only up to a budget of bytes, the ones of the least
+/// recently used accounts are evicted and loaded from the storage again when the account is used.
+#[cfg(feature = "storage")]
+#[derive(Debug, Default)]
+pub(crate) struct AccountCache {
+    // the maximum estimated size of the loaded details in bytes, unlimited if `None`
+    budget: Option<usize>,
+    // indexes and estimated sizes of the accounts with loaded details, the least recently used one first
+    loaded: VecDeque<(u32, usize)>,
+}
+
+#[cfg(feature = "storage")]
+impl AccountCache {
+    pub(crate) fn new(budget: Option<usize>, loaded: impl IntoIterator<Item = (u32, usize)>) -> Self {
+        Self {
+            budget,
+            loaded: loaded.into_iter().collect(),
+        }
+    }
+
+    /// Loads the details of the account if they were evicted and evicts the details of the least recently used
+    /// accounts until the loaded details fit into the budget. The size of the details of an account is estimated every
+    /// time it's used. Returns a handle of the account, the details of the used account and of accounts with a handle
+    /// outside of the wallet are never evicted.
+    pub(crate) async fn load(&mut self, accounts: &[Account], account: &Account) -> crate::wallet::Result<Account> {
+        // Cloning first prevents the account from being evicted below
+        let account = account.clone();
+        let Some(budget) = self.budget else {
+            return Ok(account);
+        };
+
+        account.load_details().await?;
+        let (account_index, size) = {
+            let account_details = account.read().await;
+            (*account_details.index(), account_details.evictable_size())
+        };
+        self.loaded.retain(|(index, _)| *index != account_index);
+        self.loaded.push_back((account_index, size));
+
+        let mut loaded_size = self.loaded.iter().map(|(_, size)| size).sum::<usize>();
+        let mut in_use = Vec::new();
+        while loaded_size > budget && self.loaded.len() > 1 {
+            let Some((least_recently_used, size)) = self.loaded.pop_front() else {
+                break;
+            };
+            // The details of removed accounts don't use any memory anymore
+            let mut evicted = true;
+            for cached_account in accounts {
+                if *cached_account.read().await.index() == least_recently_used {
+                    evicted = cached_account.evict_details().await?;
+                    break;
+                }
+            }
+            if evicted {
+                loaded_size -= size;
+            } else {
+                in_use.push((least_recently_used, size));
+            }
+        }
+        // Accounts in use stay loaded and are the first ones to be evicted next time
+        for loaded_account in in_use.into_iter().rev() {
+            self.loaded.push_front(loaded_account);
+        }
+
+        Ok(account)
+    }
+
+    /// Forgets a removed account, so its details don't count towards the budget.
+    pub(crate) fn remove(&mut self, account_index: u32) {
+        self.loaded.retain(|(index, _)| *index != account_index);
+    }
+}
+
+impl Wallet {
+    /// Returns a handle of an account of the wallet, with the details loaded again if they were evicted by the account
+    /// cache.
+    #[cfg(feature = "storage")]
+    pub(crate) async fn load_account(&self, accounts: &[Account], account: &Account) -> crate::wallet::Result<Account> {
+        self.account_cache.lock().await.load(accounts, account).await
+    }
+
+    /// Returns a handle of an account of the wallet, without storage the details are never evicted.
+    #[cfg(not(feature = "storage"))]
+    pub(crate) async fn load_account(&self, _accounts: &[Account], account: &Account) -> crate::wallet::Result<Account> {
+        Ok(account.clone())
+    }
+
+    /// Returns handles of all accounts of the wallet, with their details loaded.
+    pub(crate) async fn load_accounts(&self, accounts: &[Account]) -> crate::wallet::Result<Vec<Account>> {
+        let mut loaded_accounts = Vec::with_capacity(accounts.len());
+        for account in accounts {
+            loaded_accounts.push(self.load_account(accounts, account).await?);
+        }
+
+        Ok(loaded_accounts)
+    }
+}
+
@@ -0,0 +1,121 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::sync::{atomic::Ordering, Arc};
+
+use crypto::{ciphers::chacha, utils::rand};
+use serde::{Deserialize, Serialize};
+
+use crate::wallet::{
+    account::{
+        builder::{get_first_public_address, next_account_index},
+        types::AccountIdentifier,
+        Account, AccountDetails,
+    },
+    storage::encryption::derive_key,
+    Error, Wallet,
+};
+
+/// An exported account, the details are encrypted with a key derived from the password and the salt.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct EncryptedAccount {
+    salt: [u8; 32],
+    ciphertext: Vec<u8>,
+}
+
+impl Wallet {
+    /// Exports an account with its addresses, outputs, transactions and metadata, so it can be imported into another
+    /// wallet with [`Wallet::import_account()`]. The account is encrypted with a key derived from the password,
+    /// the returned JSON is self-contained and can be stored or transferred as it is.
+    pub async fn export_account<I: Into<AccountIdentifier> + Send>(
+        &self,
+        identifier: I,
+        password: &str,
+    ) -> crate::wallet::Result<String> {
+        let account = self.get_account(identifier).await?;
+        let plaintext = serde_json::to_vec(&*account.read().await)?;
+        log::debug!("[export_account] {}", account.alias().await);
+
+        let mut salt = [0u8; 32];
+        rand::fill(&mut salt)?;
+        let ciphertext = chacha::aead_encrypt(&derive_key(password, &salt), &plaintext)?;
+
+        Ok(serde_json::to_string(&EncryptedAccount { salt, ciphertext })?)
+    }
+
+    /// Imports an account that was exported with [`Wallet::export_account()`]. The coin type needs to match the one
+    /// of the wallet and, unless the account is watch-only, its first address needs to be derived from the seed of
+    /// the wallet. The account keeps its index, so the addresses stay the same, and the missing accounts below it
+    /// are created. Watch-only accounts get the lowest free index instead.
+    pub async fn import_account(&self, exported_account: &str, password: &str) -> crate::wallet::Result<Account> {
+        let encrypted_account = serde_json::from_str::<EncryptedAccount>(exported_account)?;
+        let plaintext = chacha::aead_decrypt(
+            &derive_key(password, &encrypted_account.salt),
+            &encrypted_account.ciphertext,
+        )
+        .map_err(|_| Error::Backup("wrong password or invalid exported account"))?;
+        let mut details = serde_json::from_slice::<AccountDetails>(&plaintext)?;
+        log::debug!("[import_account] {} with index {}", details.alias(), details.index());
+
+        let coin_type = self.coin_type.load(Ordering::Relaxed);
+        if *details.coin_type() != coin_type {
+            return Err(Error::InvalidCoinType {
+                new_coin_type: *details.coin_type(),
+                existing_coin_type: coin_type,
+            });
+        }
+
+        if !*details.watch_only() {
+            let first_public_address =
+                get_first_public_address(&self.secret_manager, coin_type, *details.index()).await?;
+            if details.public_addresses.first().map(|address| address.address.inner) != Some(first_public_address) {
+                return Err(Error::InvalidMnemonic(
+                    "exported account address used another seed".to_string(),
+                ));
+            }
+
+            if self.get_account(*details.index()).await.is_ok() {
+                return Err(Error::Backup(
+                    "an account with the index of the exported account already exists",
+                ));
+            }
+            // The accounts below the index need to exist, so new accounts don't get the same index
+            while next_account_index(&self.accounts.read().await).await < *details.index() {
+                self.create_account().finish().await?;
+            }
+        }
+
+        let mut accounts = self.accounts.write().await;
+        let next_account_index = next_account_index(&accounts).await;
+        if *details.watch_only() {
+            details.set_index(next_account_index);
+        } else if next_account_index != *details.index() {
+            return Err(Error::Backup(
+                "an account with the index of the exported account already exists",
+            ));
+        }
+        for account in accounts.iter() {
+            if account.read().await.alias().to_lowercase() == details.alias().to_lowercase() {
+                return Err(Error::AccountAliasAlreadyExists(details.alias().clone()));
+            }
+        }
+
+        let client = self.client_options.read().await.clone().finish()?;
+        let account = Account::new(
+            details,
+            client,
+            self.secret_manager.clone(),
+            self.address_generation_lock.clone(),
+            Arc::downgrade(&self.accounts),
+            #[cfg(feature = "events")]
+            self.event_emitter.clone(),
+            self.storage_manager.clone(),
+        )
+        .await?;
+        account.save(None).await?;
+        accounts.push(account.clone());
+
+        Ok(account)
+    }
+}
@@ -36,10 +36,12 @@ impl Wallet {
         let mut max_account_index_to_keep = None;
 
         // Search for addresses in current accounts
-        for account in self.accounts.read().await.iter() {
+        let accounts = self.accounts.read().await;
+        for account in accounts.iter() {
             // If the gap limit is 0, there is no need to search for funds
             if address_gap_limit > 0 {
-                account
+                self.load_account(&accounts, account)
+                    .await?
                     .search_addresses_with_outputs(address_gap_limit, sync_options.clone())
                     .await?;
             }
@@ -53,6 +55,7 @@ impl Wallet {
                 None => max_account_index_to_keep = Some(account_index),
             }
         }
+        drop(accounts);
 
         // Create accounts below account_start_index, because we don't want to have gaps in the accounts, but we also
         // don't want to sync them
@@ -103,7 +106,7 @@ impl Wallet {
         new_accounts_discovery_result?;
 
         log::debug!("[recover_accounts] finished in {:?}", start_time.elapsed());
-        Ok(self.accounts.read().await.clone())
+        self.load_accounts(&self.accounts.read().await).await
     }
 
     /// Generate new accounts and search for unspent outputs
@@ -4,7 +4,7 @@
 use std::sync::atomic::Ordering;
 
 #[cfg(all(feature = "events", feature = "ledger_nano"))]
-use crate::wallet::events::types::{AddressData, WalletEvent};
+use crate::wallet::events::types::{AddressData, LedgerAddressDisplay, LedgerDisplayEvent, WalletEvent};
 use crate::{
     client::secret::{GenerateAddressOptions, SecretManage, SecretManager},
     types::block::address::Address,
@@ -54,12 +54,23 @@ impl Wallet {
                             .await?;
 
                         let bech32_hrp = self.get_bech32_hrp().await?;
+                        let address = address[0].to_bech32(bech32_hrp);
+                        let display = LedgerAddressDisplay::new(
+                            self.coin_type.load(Ordering::Relaxed),
+                            account_index,
+                            options.as_ref().map_or(false, |options| options.internal),
+                            address_index,
+                            address.clone(),
+                        );
 
-                        self.event_emitter.lock().await.emit(
+                        let mut event_emitter = self.event_emitter.lock().await;
+                        event_emitter.emit(
                             account_index,
-                            WalletEvent::LedgerAddressGeneration(AddressData {
-                                address: address[0].to_bech32(bech32_hrp),
-                            }),
+                            WalletEvent::LedgerAddressGeneration(AddressData { address }),
+                        );
+                        event_emitter.emit(
+                            account_index,
+                            WalletEvent::LedgerDisplay(Box::new(LedgerDisplayEvent::Address(display))),
                         );
                     }
 
@@ -94,6 +105,17 @@ impl Wallet {
                     )
                     .await?
             }
+            #[cfg(feature = "pkcs11")]
+            SecretManager::Pkcs11(pkcs11) => {
+                pkcs11
+                    .generate_addresses(
+                        self.coin_type.load(Ordering::Relaxed),
+                        account_index,
+                        address_index..address_index + 1,
+                        options,
+                    )
+                    .await?
+            }
             SecretManager::Mnemonic(mnemonic) => {
                 mnemonic
                     .generate_addresses(
@@ -1,7 +1,7 @@
 // Copyright 2021 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use std::{sync::atomic::Ordering, time::Duration};
+use std::{collections::HashMap, sync::atomic::Ordering, time::Duration};
 
 use tokio::time::sleep;
 
@@ -9,9 +9,14 @@ use crate::wallet::{account::operations::syncing::SyncOptions, Wallet};
 
 /// The default interval for background syncing
 pub(crate) const DEFAULT_BACKGROUNDSYNCING_INTERVAL: Duration = Duration::from_secs(7);
+/// The maximum number of rounds an account is skipped after failed syncs
+pub(crate) const MAX_SKIPPED_ROUNDS: u32 = 10;
 
 impl Wallet {
-    /// Start the background syncing process for all accounts, default interval is 7 seconds
+    /// Start the background syncing process for all accounts, default interval is 7 seconds. Every account is synced
+    /// in its own task, failed syncs are recorded in the health of the account, which is then skipped for as many
+    /// rounds as its syncs failed in a row, up to 10. After a sync, the dust outputs of the account are swept with
+    /// [`Account::sweep_dust()`](crate::wallet::account::Account::sweep_dust).
     pub async fn start_background_syncing(
         &self,
         options: Option<SyncOptions>,
@@ -30,6 +35,8 @@ impl Wallet {
 
         background_syncing_status.store(1, Ordering::Relaxed);
         let accounts = self.accounts.clone();
+        #[cfg(feature = "storage")]
+        let account_cache = self.account_cache.clone();
         let _background_syncing = std::thread::spawn(move || {
             #[cfg(not(target_family = "wasm"))]
             let runtime = tokio::runtime::Builder::new_multi_thread()
@@ -42,18 +49,62 @@ impl Wallet {
                 .build()
                 .unwrap();
             runtime.block_on(async {
+                // Rounds an account was skipped since its last failed sync
+                let mut skipped_rounds = HashMap::<u32, u32>::new();
                 'outer: loop {
                     log::debug!("[background_syncing]: syncing accounts");
-                    for account in accounts.read().await.iter() {
+                    let mut position = 0;
+                    loop {
                         // Check if the process should stop before syncing each account so it stops faster
                         if background_syncing_status.load(Ordering::Relaxed) == 2 {
                             log::debug!("[background_syncing]: stopping");
                             break 'outer;
                         }
-                        match account.sync(options.clone()).await {
-                            Ok(_) => {}
-                            Err(err) => log::debug!("[background_syncing] error: {}", err),
+                        // The accounts are only locked to get the next one, so other operations, like creating an
+                        // account, don't have to wait until all accounts are synced
+                        let accounts = accounts.read().await;
+                        let Some(account) = accounts.get(position) else {
+                            break;
                         };
+                        position += 1;
+                        // Syncing saves the account, so evicted details need to be loaded first
+                        #[cfg(feature = "storage")]
+                        let account = match account_cache.lock().await.load(&accounts, account).await {
+                            Ok(account) => account,
+                            Err(err) => {
+                                log::debug!("[background_syncing] error: {}", err);
+                                continue;
+                            }
+                        };
+                        #[cfg(not(feature = "storage"))]
+                        let account = account.clone();
+                        drop(accounts);
+
+                        // Accounts that failed to sync are synced less often, so they don't delay the others
+                        let health = account.health().await;
+                        let skipped = skipped_rounds.entry(health.account_index).or_default();
+                        if *skipped < health.consecutive_failures.min(MAX_SKIPPED_ROUNDS) {
+                            *skipped += 1;
+                            continue;
+                        }
+                        *skipped = 0;
+
+                        // Sync in a separate task, so a panic only fails this sync instead of stopping the process.
+                        // Accounts with a sweep threshold sweep their dust outputs after the sync.
+                        let sync_account = account.clone();
+                        let sync_options = options.clone();
+                        let sync = async move {
+                            sync_account.sync(sync_options).await?;
+                            sync_account.sweep_dust().await
+                        };
+                        match tokio::spawn(sync).await {
+                            Ok(Ok(_)) => {}
+                            Ok(Err(err)) => log::debug!("[background_syncing] error: {}", err),
+                            Err(err) => {
+                                log::debug!("[background_syncing] sync task failed: {}", err);
+                                account.health.lock().await.record_failure(err);
+                            }
+                        }
                     }
                     // split interval syncing to seconds so stopping the process doesn't have to wait long
                     let seconds = interval.unwrap_or(DEFAULT_BACKGROUNDSYNCING_INTERVAL).as_secs();
@@ -15,15 +15,19 @@ use crate::{
 };
 
 impl Wallet {
-    /// Sets the client options for all accounts and sets the new bech32_hrp for the addresses.
+    /// Sets the client options for all accounts and sets the new bech32_hrp for the addresses. The options are
+    /// validated with [`ClientOptions::validate()`] and protocol parameters set in them need to match the network of
+    /// the nodes, invalid options are rejected without changing the wallet.
     pub async fn set_client_options(&self, options: ClientOptions) -> crate::wallet::Result<()> {
         log::debug!("[set_client_options]");
 
-        let mut client_options = self.client_options.write().await;
-        *client_options = options.clone();
-        drop(client_options);
-
+        // Invalid options are rejected before anything is changed
+        options.validate()?;
         let new_client = options.clone().finish()?;
+        #[cfg(not(target_family = "wasm"))]
+        new_client.verify_network_of_nodes()?;
+
+        *self.client_options.write().await = options.clone();
 
         for account in self.accounts.write().await.iter_mut() {
             account.update_account_with_new_client(new_client.clone()).await?;
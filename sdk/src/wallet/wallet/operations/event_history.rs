@@ -0,0 +1,44 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::wallet::{
+    storage::event_history::{StoredWalletEvent, WalletEventFilter},
+    Wallet,
+};
+
+impl Wallet {
+    /// Returns the stored events with a sequence number of at least `since` that match the filter, in the order they
+    /// were emitted. Events are only stored if the persistence of events was enabled with
+    /// [`WalletBuilder::with_event_persistence()`](crate::wallet::WalletBuilder::with_event_persistence), an
+    /// application can replay the events it missed by passing the sequence number after the last event it handled.
+    pub async fn get_events(
+        &self,
+        since: u64,
+        filter: WalletEventFilter,
+    ) -> crate::wallet::Result<Vec<StoredWalletEvent>> {
+        log::debug!("[get_events] since {since}");
+        self.flush_events().await?;
+        self.storage_manager
+            .lock()
+            .await
+            .get_wallet_events(since, &filter)
+            .await
+    }
+
+    /// Removes the stored events with a sequence number lower than `before`. The sequence numbers of later events
+    /// don't change and are never used again.
+    pub async fn clear_events(&self, before: u64) -> crate::wallet::Result<()> {
+        log::debug!("[clear_events] before {before}");
+        self.flush_events().await?;
+        self.storage_manager.lock().await.clear_wallet_events(before).await
+    }
+
+    // Writes the events that were emitted, but not stored yet
+    async fn flush_events(&self) -> crate::wallet::Result<()> {
+        let recorder = self.event_emitter.lock().await.recorder().cloned();
+        match recorder {
+            Some(recorder) => recorder.flush().await,
+            None => Ok(()),
+        }
+    }
+}
@@ -0,0 +1,50 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::HashMap;
+
+use crate::wallet::{account::Account, Error, Wallet};
+
+impl Wallet {
+    /// Creates an account for an identifier of an external system, e.g. the ID of a user of a platform, or returns the
+    /// account that was already created for it. The identifier is stored with the account, so the same identifier is
+    /// always mapped to the same account index and calling this again, also concurrently or after a restart, never
+    /// creates a second account.
+    pub async fn create_account_for(&self, external_id: impl Into<String> + Send) -> crate::wallet::Result<Account> {
+        let account = self
+            .create_account()
+            .with_external_id(external_id.into())
+            .finish()
+            .await?;
+        self.load_account(&self.accounts.read().await, &account).await
+    }
+
+    /// Get the account that was created for an identifier of an external system.
+    pub async fn get_account_for(&self, external_id: &str) -> crate::wallet::Result<Account> {
+        let accounts = self.accounts.read().await;
+
+        for account in accounts.iter() {
+            if account.read().await.external_id().as_deref() == Some(external_id) {
+                return self.load_account(&accounts, account).await;
+            }
+        }
+
+        Err(Error::AccountNotFound(external_id.to_string()))
+    }
+
+    /// Get the account indexes of all accounts that were created for an identifier of an external system, by their
+    /// identifier.
+    pub async fn get_external_ids(&self) -> HashMap<String, u32> {
+        let accounts = self.accounts.read().await;
+        let mut external_ids = HashMap::new();
+
+        for account in accounts.iter() {
+            let account_details = account.read().await;
+            if let Some(external_id) = account_details.external_id() {
+                external_ids.insert(external_id.clone(), *account_details.index());
+            }
+        }
+
+        external_ids
+    }
+}
@@ -0,0 +1,91 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::ops::Range;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    client::api::search_address,
+    types::block::address::Bech32Address,
+    wallet::{account::types::AccountAddress, Wallet},
+};
+
+/// The account and bip32 indexes an address belongs to, see [`Wallet::find_account_for_address()`].
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AddressOwner {
+    /// The index of the account.
+    pub account_index: u32,
+    /// The address index.
+    pub key_index: u32,
+    /// If the address is an internal (remainder) address.
+    pub internal: bool,
+}
+
+impl AddressOwner {
+    fn new(account_index: u32, account_address: &AccountAddress) -> Self {
+        Self {
+            account_index,
+            key_index: *account_address.key_index(),
+            internal: *account_address.internal(),
+        }
+    }
+}
+
+impl Wallet {
+    /// Finds the account that owns an address. The generated addresses of the accounts are checked first, then the
+    /// public and internal addresses with an index in `range` are derived for every account until the address is
+    /// found. Returns `None` if the address doesn't belong to any account of the wallet.
+    pub async fn find_account_for_address(
+        &self,
+        address: &Bech32Address,
+        range: Range<u32>,
+    ) -> crate::wallet::Result<Option<AddressOwner>> {
+        log::debug!("[find_account_for_address] {address} in {range:?}");
+        let accounts = self.accounts.read().await;
+
+        let mut account_indexes = Vec::with_capacity(accounts.len());
+        for account in accounts.iter() {
+            let account = self.load_account(&accounts, account).await?;
+            let account_details = account.read().await;
+            if let Some(account_address) = account_details
+                .public_addresses()
+                .iter()
+                .chain(account_details.internal_addresses().iter())
+                .find(|a| a.address() == address)
+            {
+                return Ok(Some(AddressOwner::new(*account_details.index(), account_address)));
+            }
+            account_indexes.push(*account_details.index());
+        }
+        drop(accounts);
+
+        let coin_type = self.coin_type.load(std::sync::atomic::Ordering::Relaxed);
+        let secret_manager = self.secret_manager.read().await;
+        for account_index in account_indexes {
+            match search_address(
+                &secret_manager,
+                address.hrp(),
+                coin_type,
+                account_index,
+                range.clone(),
+                address.inner(),
+            )
+            .await
+            {
+                Ok((key_index, internal)) => {
+                    return Ok(Some(AddressOwner {
+                        account_index,
+                        key_index,
+                        internal,
+                    }));
+                }
+                Err(crate::client::Error::InputAddressNotFound { .. }) => {}
+                Err(e) => return Err(e.into()),
+            }
+        }
+
+        Ok(None)
+    }
+}
@@ -0,0 +1,162 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::{collections::BTreeMap, ops::Range, sync::atomic::Ordering};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    types::block::address::Bech32Address,
+    wallet::{
+        account::{builder::next_account_index, AddressGenerationOptions},
+        Account, Wallet,
+    },
+};
+
+// Address indexes in which the deposit address of an account is searched by default
+const DEFAULT_DEPOSIT_ADDRESS_RANGE: Range<u32> = 0..20;
+
+/// A profile exported by Firefly. Only the fields that are needed to recreate the accounts are read, all others are
+/// ignored.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FireflyProfile {
+    /// The name of the profile.
+    pub name: String,
+    /// The network of the profile.
+    #[serde(default)]
+    pub network: Option<FireflyNetwork>,
+    /// The data of the accounts, by account index.
+    #[serde(default)]
+    pub account_persisted_data: BTreeMap<u32, FireflyAccountData>,
+}
+
+/// The network of a [`FireflyProfile`].
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FireflyNetwork {
+    /// The coin type for which the addresses of the profile were generated.
+    pub coin_type: Option<u32>,
+}
+
+/// The data of an account of a [`FireflyProfile`].
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FireflyAccountData {
+    /// The name of the account, used as alias.
+    pub name: Option<String>,
+    /// The address the account received funds on.
+    pub deposit_address: Option<Bech32Address>,
+}
+
+/// Options for [`Wallet::import_firefly_profile()`].
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct FireflyImportOptions {
+    /// The address indexes in which the deposit address of an account is searched.
+    pub deposit_address_range: Range<u32>,
+    /// If set, the addresses with unspent outputs of every imported account are searched on the network, with this
+    /// number of addresses after the last one with unspent outputs.
+    pub address_gap_limit: Option<u32>,
+}
+
+impl Default for FireflyImportOptions {
+    fn default() -> Self {
+        Self {
+            deposit_address_range: DEFAULT_DEPOSIT_ADDRESS_RANGE,
+            address_gap_limit: None,
+        }
+    }
+}
+
+impl Wallet {
+    /// Recreates the accounts of a profile exported by Firefly, the wallet needs to use the same mnemonic as the
+    /// profile. Accounts are created up to the highest account index of the profile and get the names of the profile
+    /// as alias, existing accounts are renamed. The addresses up to the deposit address of an account are generated,
+    /// so the address indexes match the ones of Firefly. Returns the imported accounts.
+    pub async fn import_firefly_profile(
+        &self,
+        profile: FireflyProfile,
+        options: Option<FireflyImportOptions>,
+    ) -> crate::wallet::Result<Vec<Account>> {
+        log::debug!(
+            "[import_firefly_profile] {} with {} accounts",
+            profile.name,
+            profile.account_persisted_data.len()
+        );
+        let options = options.unwrap_or_default();
+
+        let coin_type = self.coin_type.load(Ordering::Relaxed);
+        if let Some(profile_coin_type) = profile.network.and_then(|network| network.coin_type) {
+            if profile_coin_type != coin_type {
+                return Err(crate::wallet::Error::InvalidCoinType {
+                    new_coin_type: profile_coin_type,
+                    existing_coin_type: coin_type,
+                });
+            }
+        }
+
+        let mut imported_accounts = Vec::with_capacity(profile.account_persisted_data.len());
+        for (account_index, account_data) in profile.account_persisted_data {
+            let account = self.import_firefly_account(account_index, account_data.name).await?;
+
+            if let Some(deposit_address) = account_data.deposit_address {
+                let owner = self
+                    .find_account_for_address(&deposit_address, options.deposit_address_range.clone())
+                    .await?
+                    .filter(|owner| owner.account_index == account_index)
+                    .ok_or_else(|| crate::wallet::Error::AddressNotFoundInAccount(deposit_address.to_string()))?;
+
+                let next_key_index = {
+                    let account_details = account.read().await;
+                    let addresses = if owner.internal {
+                        account_details.internal_addresses()
+                    } else {
+                        account_details.public_addresses()
+                    };
+                    addresses.last().map_or(0, |address| *address.key_index() + 1)
+                };
+                if owner.key_index >= next_key_index {
+                    let options = AddressGenerationOptions {
+                        internal: owner.internal,
+                        ..Default::default()
+                    };
+                    account
+                        .generate_addresses(owner.key_index + 1 - next_key_index, Some(options))
+                        .await?;
+                }
+            }
+
+            if let Some(address_gap_limit) = options.address_gap_limit {
+                account.search_addresses_with_outputs(address_gap_limit, None).await?;
+            }
+            imported_accounts.push(account);
+        }
+
+        Ok(imported_accounts)
+    }
+
+    // Returns the account with the index, creates it and the missing accounts below it if it doesn't exist yet
+    async fn import_firefly_account(&self, account_index: u32, name: Option<String>) -> crate::wallet::Result<Account> {
+        loop {
+            let next_account_index = next_account_index(&self.accounts.read().await).await;
+            if next_account_index > account_index {
+                break;
+            }
+            let mut account_builder = self.create_account();
+            if next_account_index == account_index {
+                account_builder = account_builder.with_alias(name.clone());
+            }
+            account_builder.finish().await?;
+        }
+
+        let account = self.get_account(account_index).await?;
+        if let Some(name) = name {
+            if account.read().await.alias() != &name {
+                account.set_alias(&name).await?;
+            }
+        }
+
+        Ok(account)
+    }
+}
@@ -18,19 +18,15 @@ impl Wallet {
         match &account_id {
             AccountIdentifier::Index(index) => {
                 for account in accounts.iter() {
-                    let account_details = account.read().await;
-
-                    if account_details.index() == index {
-                        return Ok(account.clone());
+                    if account.read().await.index() == index {
+                        return self.load_account(&accounts, account).await;
                     }
                 }
             }
             AccountIdentifier::Alias(alias) => {
                 for account in accounts.iter() {
-                    let account_details = account.read().await;
-
-                    if account_details.alias() == alias {
-                        return Ok(account.clone());
+                    if account.read().await.alias() == alias {
+                        return self.load_account(&accounts, account).await;
                     }
                 }
             }
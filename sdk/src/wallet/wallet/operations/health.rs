@@ -0,0 +1,76 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::sync::atomic::Ordering;
+
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "stronghold")]
+use crate::client::secret::SecretManager;
+use crate::wallet::Wallet;
+
+/// The status of a wallet, to embed it in the health checks of a service.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WalletHealth {
+    /// If the wallet data can be read from the storage, `None` if the wallet has no storage.
+    pub storage_reachable: Option<bool>,
+    /// If the stronghold password is available, `None` if the secret manager isn't a stronghold.
+    pub stronghold_unlocked: Option<bool>,
+    /// If there is a healthy node to send requests to.
+    pub nodes_healthy: bool,
+    /// If the background syncing is running.
+    pub background_syncing: bool,
+    /// The number of pending transactions of all accounts.
+    pub pending_transactions: usize,
+}
+
+impl WalletHealth {
+    /// Returns true if the storage is reachable and there is a healthy node, a locked stronghold or stopped background
+    /// syncing can be intended and don't make the wallet unhealthy.
+    pub fn is_healthy(&self) -> bool {
+        self.storage_reachable.unwrap_or(true) && self.nodes_healthy
+    }
+}
+
+impl Wallet {
+    /// Returns the status of the storage, the stronghold, the nodes and the background syncing of the wallet and the
+    /// number of pending transactions. The status is checked without requests to the nodes, so it can be called for
+    /// every health check of a service.
+    pub async fn health(&self) -> WalletHealth {
+        #[cfg(feature = "storage")]
+        let storage_reachable = Some(self.storage_manager.lock().await.get_wallet_data().await.is_ok());
+        #[cfg(not(feature = "storage"))]
+        let storage_reachable = None;
+
+        #[cfg(feature = "stronghold")]
+        let stronghold_unlocked = match &*self.secret_manager.read().await {
+            SecretManager::Stronghold(stronghold) => Some(stronghold.is_key_available().await),
+            _ => None,
+        };
+        #[cfg(not(feature = "stronghold"))]
+        let stronghold_unlocked = None;
+
+        let nodes_healthy = self
+            .get_client()
+            .await
+            .map_or(false, |client| client.get_node().is_ok());
+
+        let accounts = self.accounts.read().await;
+        let mut pending_transactions = 0;
+        for account in accounts.iter() {
+            // Accounts that can't be loaded are reported by `storage_reachable`
+            if let Ok(account) = self.load_account(&accounts, account).await {
+                pending_transactions += account.snapshot().pending_transactions().len();
+            }
+        }
+
+        WalletHealth {
+            storage_reachable,
+            stronghold_unlocked,
+            nodes_healthy,
+            background_syncing: self.background_syncing_status.load(Ordering::Relaxed) == 1,
+            pending_transactions,
+        }
+    }
+}
@@ -0,0 +1,52 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::wallet::{
+    account::{
+        types::{AccountIdentifier, Transaction},
+        TransactionOptions,
+    },
+    AddressWithAmount, Error, Wallet,
+};
+
+impl Wallet {
+    /// Sends an amount from one account of the wallet to the first public address of another one. The transaction is
+    /// stored as outgoing transaction in the sending account and as pending incoming transaction in the receiving
+    /// account, so it's known to both before the receiving account is synced. The incoming transaction is updated by
+    /// the first sync of the receiving account after the transaction is confirmed.
+    pub async fn transfer_between_accounts(
+        &self,
+        from: impl Into<AccountIdentifier> + Send,
+        to: impl Into<AccountIdentifier> + Send,
+        amount: u64,
+        options: impl Into<Option<TransactionOptions>> + Send,
+    ) -> crate::wallet::Result<Transaction> {
+        let sender = self.get_account(from).await?;
+        let receiver = self.get_account(to).await?;
+
+        let sender_index = *sender.snapshot().index();
+        let receiver_details = receiver.snapshot();
+        if *receiver_details.index() == sender_index {
+            return Err(Error::TransferToSameAccount(sender_index));
+        }
+        let address = receiver_details
+            .public_addresses()
+            .first()
+            .expect("account needs to have a public address")
+            .address()
+            .to_string();
+        log::debug!(
+            "[TRANSACTION] transfer of {amount} from account {sender_index} to account {}",
+            receiver_details.index()
+        );
+        drop(receiver_details);
+
+        let transaction = sender
+            .send_amount(vec![AddressWithAmount::new(address, amount)], options)
+            .await?;
+
+        receiver.update_account_with_incoming_transaction(&transaction).await?;
+
+        Ok(transaction)
+    }
+}
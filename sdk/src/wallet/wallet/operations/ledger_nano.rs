@@ -1,6 +1,11 @@
 // Copyright 2022 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+#[cfg(feature = "events")]
+use tokio::sync::broadcast::error::RecvError;
+
+#[cfg(feature = "events")]
+use crate::wallet::events::types::WalletEvent;
 use crate::{
     client::secret::{LedgerNanoStatus, SecretManager},
     wallet::Wallet,
@@ -15,4 +20,28 @@ impl Wallet {
             Err(crate::client::Error::SecretManagerMismatch.into())
         }
     }
+
+    // Emits the positions in the signing queue of the ledger nano as events, until the secret manager is dropped
+    #[cfg(feature = "events")]
+    pub(crate) async fn emit_signing_queue_positions(&self) {
+        if let SecretManager::LedgerNano(ledger) = &*self.secret_manager.read().await {
+            let mut positions = ledger.signing_queue.subscribe();
+            let event_emitter = self.event_emitter.clone();
+
+            tokio::spawn(async move {
+                loop {
+                    match positions.recv().await {
+                        Ok(position) => event_emitter.lock().await.emit(
+                            position.account_index.unwrap_or_default(),
+                            WalletEvent::LedgerQueuePosition(position),
+                        ),
+                        Err(RecvError::Lagged(skipped)) => {
+                            log::debug!("[LEDGER] skipped {skipped} signing queue positions");
+                        }
+                        Err(RecvError::Closed) => break,
+                    }
+                }
+            });
+        }
+    }
 }
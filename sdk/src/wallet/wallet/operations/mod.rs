@@ -1,13 +1,28 @@
 // Copyright 2021 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+pub(crate) mod account_cache;
+#[cfg(feature = "storage")]
+pub(crate) mod account_export;
 pub(crate) mod account_recovery;
 pub(crate) mod address_generation;
 pub(crate) mod background_syncing;
 pub(crate) mod client;
+#[cfg(all(feature = "events", feature = "storage"))]
+pub(crate) mod event_history;
+pub(crate) mod external_id;
+pub(crate) mod find_account;
+pub(crate) mod firefly_import;
 pub(crate) mod get_account;
+pub(crate) mod health;
+pub(crate) mod internal_transfer;
 #[cfg(feature = "ledger_nano")]
 pub(crate) mod ledger_nano;
+pub(crate) mod ownership_proof;
+pub(crate) mod search;
+pub(crate) mod shutdown;
+#[cfg(feature = "storage")]
+pub(crate) mod storage_encryption;
 #[cfg(feature = "stronghold")]
 pub(crate) mod stronghold;
 #[cfg(feature = "stronghold")]
@@ -0,0 +1,125 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use crypto::{
+    hashes::{blake2b::Blake2b256, Digest},
+    keys::slip10::Chain,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    client::secret::SecretManage,
+    types::block::{
+        address::{Address, Bech32Address},
+        signature::Ed25519Signature,
+    },
+    utils::unix_timestamp_now,
+    wallet::{proof_of_reserves::ReservesSnapshot, Wallet},
+};
+
+// Prefix for the signed message, so a challenge can never be a valid transaction essence hash
+const OWNERSHIP_PROOF_DOMAIN: &[u8] = b"IOTA-SDK-OWNERSHIP-PROOF";
+
+/// A signature over a challenge, proving the ownership of an address, e.g. for proof-of-reserves attestations.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OwnershipProof {
+    /// The address the ownership is proven for.
+    pub address: Bech32Address,
+    /// The hex encoded challenge supplied by the verifier.
+    pub challenge: String,
+    /// The signature of the key that controls the address.
+    pub signature: Ed25519Signature,
+}
+
+impl OwnershipProof {
+    /// Returns the message that gets signed for a challenge.
+    pub fn signing_message(challenge: &[u8]) -> [u8; 32] {
+        Blake2b256::digest([OWNERSHIP_PROOF_DOMAIN, challenge].concat()).into()
+    }
+
+    /// Verifies that the proof was created for the expected challenge and that the signature is valid for the address.
+    pub fn verify(&self, challenge: &[u8]) -> crate::wallet::Result<()> {
+        if prefix_hex::decode::<Vec<u8>>(&self.challenge).ok().as_deref() != Some(challenge) {
+            return Err(crate::wallet::Error::InvalidOwnershipProof(format!(
+                "challenge mismatch for {}",
+                self.address
+            )));
+        }
+        match self.address.inner() {
+            Address::Ed25519(address) => Ok(self
+                .signature
+                .is_valid(&Self::signing_message(challenge), address)?),
+            _ => Err(crate::wallet::Error::InvalidOwnershipProof(format!(
+                "{} is not an ed25519 address",
+                self.address
+            ))),
+        }
+    }
+}
+
+impl Wallet {
+    /// Signs a challenge with the keys of the provided addresses, the addresses need to be generated by one of the
+    /// accounts. The returned proofs can be verified with [`OwnershipProof::verify()`].
+    pub async fn prove_ownership(
+        &self,
+        addresses: &[Bech32Address],
+        challenge: &[u8],
+    ) -> crate::wallet::Result<Vec<OwnershipProof>> {
+        log::debug!("[prove_ownership] for {} addresses", addresses.len());
+        let message = OwnershipProof::signing_message(challenge);
+        let mut proofs = Vec::with_capacity(addresses.len());
+
+        for address in addresses {
+            let chain = self
+                .address_chain(address)
+                .await
+                .ok_or_else(|| crate::wallet::Error::AddressNotFoundInAccount(address.to_string()))?;
+            let signature = self.secret_manager.read().await.sign_ed25519(&message, &chain).await?;
+            proofs.push(OwnershipProof {
+                address: address.clone(),
+                challenge: prefix_hex::encode(challenge),
+                signature,
+            });
+        }
+
+        Ok(proofs)
+    }
+
+    /// Creates a snapshot of the on-chain holdings of the provided addresses with ownership proofs over the challenge,
+    /// based on the synced unspent outputs of the accounts, so the accounts should be synced before.
+    pub async fn reserves_snapshot(
+        &self,
+        addresses: &[Bech32Address],
+        challenge: &[u8],
+    ) -> crate::wallet::Result<ReservesSnapshot> {
+        let ownership_proofs = self.prove_ownership(addresses, challenge).await?;
+
+        let mut total_holdings = 0u64;
+        let accounts = self.accounts.read().await;
+        for account in accounts.iter() {
+            let account = self.load_account(&accounts, account).await?;
+            for output_data in account.read().await.unspent_outputs().values() {
+                if addresses.iter().any(|address| address.inner() == &output_data.address) {
+                    total_holdings = total_holdings.saturating_add(output_data.output.amount());
+                }
+            }
+        }
+
+        Ok(ReservesSnapshot {
+            ownership_proofs,
+            total_holdings,
+            timestamp: unix_timestamp_now().as_secs(),
+        })
+    }
+
+    // Returns the bip32 chain of an address from any account
+    async fn address_chain(&self, address: &Bech32Address) -> Option<Chain> {
+        for account in self.accounts.read().await.iter() {
+            if let Some(chain) = account.read().await.address_chain(address) {
+                return Some(chain);
+            }
+        }
+        None
+    }
+}
@@ -0,0 +1,159 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::HashSet;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    types::block::{
+        address::Bech32Address,
+        output::{NftId, Output, OutputId, TokenId},
+        payload::transaction::TransactionId,
+    },
+    wallet::Wallet,
+};
+
+/// An item of an account that matches a search query, see [`Wallet::search()`].
+#[derive(Clone, Debug, Eq, PartialEq, Hash, Serialize, Deserialize)]
+#[serde(tag = "type", content = "value", rename_all = "camelCase")]
+pub enum SearchItem {
+    /// A public or internal address of the account.
+    Address(Bech32Address),
+    /// A sent or incoming transaction.
+    Transaction(TransactionId),
+    /// An output of the account.
+    Output(OutputId),
+    /// An NFT owned by the account.
+    Nft(NftId),
+    /// A native token held by the account.
+    NativeToken(TokenId),
+    /// The tag feature of an output, shown as text if it's valid UTF-8 and as hex otherwise.
+    #[serde(rename_all = "camelCase")]
+    Tag {
+        /// The tag.
+        tag: String,
+        /// The output with the tag feature.
+        output_id: OutputId,
+    },
+}
+
+/// A match of [`Wallet::search()`].
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SearchResult {
+    /// The index of the account the item belongs to.
+    pub account_index: u32,
+    /// The matching item.
+    pub item: SearchItem,
+    /// How well the item matches, 3 for an exact match, 2 for a prefix and 1 for any other partial match.
+    pub rank: u8,
+}
+
+impl Wallet {
+    /// Searches the addresses, transactions, outputs, NFTs, native tokens and tags of all accounts for a partial value,
+    /// ignoring the case and the `0x` prefix of hex values. Results are sorted by their rank, so exact matches come
+    /// before prefixes and other partial matches, then by the account index.
+    pub async fn search(&self, query: &str) -> crate::wallet::Result<Vec<SearchResult>> {
+        log::debug!("[search] {query}");
+        let query = query.trim().to_lowercase();
+        let query = query.strip_prefix("0x").unwrap_or(&query);
+        if query.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let accounts = self.accounts.read().await;
+        let mut results = Vec::new();
+
+        for account in accounts.iter() {
+            let account = self.load_account(&accounts, account).await?;
+            let account_details = account.read().await;
+            let account_index = *account_details.index();
+            let mut items = HashSet::new();
+
+            for account_address in account_details
+                .public_addresses()
+                .iter()
+                .chain(account_details.internal_addresses().iter())
+            {
+                items.insert(SearchItem::Address(account_address.address().clone()));
+            }
+            for transaction_id in account_details
+                .transactions()
+                .keys()
+                .chain(account_details.incoming_transactions().keys())
+            {
+                items.insert(SearchItem::Transaction(*transaction_id));
+            }
+            for (output_id, output_data) in account_details.outputs() {
+                let output = &output_data.output;
+                items.insert(SearchItem::Output(*output_id));
+                if let Output::Nft(nft_output) = output {
+                    items.insert(SearchItem::Nft(nft_output.nft_id_non_null(output_id)));
+                }
+                if let Some(native_tokens) = output.native_tokens() {
+                    for native_token in native_tokens.iter() {
+                        items.insert(SearchItem::NativeToken(*native_token.token_id()));
+                    }
+                }
+                if let Some(tag) = output.features().and_then(|features| features.tag()) {
+                    let tag = core::str::from_utf8(tag.tag())
+                        .map_or_else(|_| prefix_hex::encode(tag.tag()), ToString::to_string);
+                    items.insert(SearchItem::Tag {
+                        tag,
+                        output_id: *output_id,
+                    });
+                }
+            }
+
+            for item in items {
+                if let Some(rank) = rank(&item.to_string(), query) {
+                    results.push(SearchResult {
+                        account_index,
+                        item,
+                        rank,
+                    });
+                }
+            }
+        }
+
+        // Sort by the item too, so the order doesn't depend on the iteration order of the hash sets
+        results.sort_by(|a, b| {
+            b.rank
+                .cmp(&a.rank)
+                .then(a.account_index.cmp(&b.account_index))
+                .then_with(|| a.item.to_string().cmp(&b.item.to_string()))
+        });
+
+        Ok(results)
+    }
+}
+
+impl core::fmt::Display for SearchItem {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Address(address) => write!(f, "{address}"),
+            Self::Transaction(transaction_id) => write!(f, "{transaction_id}"),
+            Self::Output(output_id) => write!(f, "{output_id}"),
+            Self::Nft(nft_id) => write!(f, "{nft_id}"),
+            Self::NativeToken(token_id) => write!(f, "{token_id}"),
+            Self::Tag { tag, .. } => write!(f, "{tag}"),
+        }
+    }
+}
+
+// Ranks how well a value matches the lowercase query without `0x` prefix
+fn rank(value: &str, query: &str) -> Option<u8> {
+    let value = value.to_lowercase();
+    let value = value.strip_prefix("0x").unwrap_or(&value);
+
+    if value == query {
+        Some(3)
+    } else if value.starts_with(query) {
+        Some(2)
+    } else if value.contains(query) {
+        Some(1)
+    } else {
+        None
+    }
+}
@@ -0,0 +1,44 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::{sync::atomic::Ordering, time::Duration};
+
+use crate::wallet::Wallet;
+
+impl Wallet {
+    /// Stops the wallet, so the application can shut down cleanly. The clients of all accounts are cancelled with
+    /// their [`CancellationToken`](crate::client::CancellationToken), which aborts node requests in flight and makes
+    /// syncs, PoW, retries until a transaction is included and MQTT listeners stop with
+    /// [`Error::Cancelled`](crate::client::Error::Cancelled). Waits up to `timeout` for the background syncing to
+    /// stop, then writes the storage to the disk. The accounts can't send requests to the nodes anymore afterwards, a
+    /// new wallet needs to be created.
+    pub async fn stop(&self, timeout: Duration) -> crate::wallet::Result<()> {
+        log::debug!("[stop]");
+        let deadline = instant::Instant::now() + timeout;
+
+        for account in self.accounts.read().await.iter() {
+            account.client.cancellation_token().cancel();
+        }
+
+        // Request the background syncing to stop, a running sync is aborted by the cancelled client
+        if self.background_syncing_status.load(Ordering::Relaxed) != 0 {
+            self.background_syncing_status.store(2, Ordering::Relaxed);
+        }
+        while self.background_syncing_status.load(Ordering::Relaxed) != 0 {
+            if instant::Instant::now() >= deadline {
+                log::debug!("[stop] background syncing didn't stop within {:?}", timeout);
+                break;
+            }
+            #[cfg(target_family = "wasm")]
+            gloo_timers::future::TimeoutFuture::new(10).await;
+            #[cfg(not(target_family = "wasm"))]
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+
+        #[cfg(feature = "storage")]
+        self.storage_manager.lock().await.flush().await?;
+
+        log::debug!("[stop] stopped");
+        Ok(())
+    }
+}
@@ -0,0 +1,17 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::wallet::{storage::encryption::StorageEncryption, Wallet};
+
+impl Wallet {
+    /// Encrypts all records of the storage with a new key or password, e.g. to rotate the key or to encrypt an
+    /// existing unencrypted storage, or decrypts them if `None` is provided. All records are rewritten in a single
+    /// batch, so an interruption never leaves the storage with records of different keys. The new key or password
+    /// needs to be provided with
+    /// [`WalletBuilder::with_storage_encryption()`](crate::wallet::WalletBuilder::with_storage_encryption) the next time
+    /// the wallet is built.
+    pub async fn set_storage_encryption(&self, encryption: Option<StorageEncryption>) -> crate::wallet::Result<()> {
+        log::debug!("[set_storage_encryption]");
+        self.storage_manager.lock().await.set_encryption(encryption).await
+    }
+}
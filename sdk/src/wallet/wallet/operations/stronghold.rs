@@ -3,7 +3,12 @@
 
 use std::time::Duration;
 
-use crate::{client::secret::SecretManager, wallet::Wallet};
+use zeroize::Zeroizing;
+
+use crate::{
+    client::{secret::SecretManager, stronghold::StoreMnemonicOptions},
+    wallet::Wallet,
+};
 
 impl Wallet {
     /// Sets the Stronghold password
@@ -43,6 +48,29 @@ impl Wallet {
         Ok(())
     }
 
+    /// Stores a mnemonic into the Stronghold vault, with a BIP-39 passphrase and/or after checking that it derives the
+    /// expected address
+    pub async fn store_mnemonic_with_options(
+        &self,
+        mnemonic: String,
+        options: StoreMnemonicOptions,
+    ) -> crate::wallet::Result<()> {
+        if let SecretManager::Stronghold(stronghold) = &mut *self.secret_manager.write().await {
+            stronghold.store_mnemonic_with_options(mnemonic, options).await?;
+        }
+        Ok(())
+    }
+
+    /// Exports the mnemonic from the Stronghold vault, if it was stored as exportable. Requires the Stronghold password
+    /// again.
+    pub async fn export_mnemonic(&self, password: &str) -> crate::wallet::Result<Zeroizing<String>> {
+        log::debug!("[export_mnemonic]");
+        match &*self.secret_manager.read().await {
+            SecretManager::Stronghold(stronghold) => Ok(stronghold.export_mnemonic(password).await?),
+            _ => Err(crate::client::Error::SecretManagerMismatch.into()),
+        }
+    }
+
     /// Clears the Stronghold password from memory.
     pub async fn clear_stronghold_password(&self) -> crate::wallet::Result<()> {
         log::debug!("[clear_stronghold_password]");
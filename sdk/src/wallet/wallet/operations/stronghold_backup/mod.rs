@@ -3,7 +3,11 @@
 
 mod stronghold_snapshot;
 
-use std::{fs, path::PathBuf, sync::atomic::Ordering};
+use std::{
+    fs,
+    path::PathBuf,
+    sync::{atomic::Ordering, Arc},
+};
 
 use futures::{future::try_join_all, FutureExt};
 use zeroize::Zeroize;
@@ -170,6 +174,8 @@ impl Wallet {
                             a,
                             client.clone(),
                             self.secret_manager.clone(),
+                            self.address_generation_lock.clone(),
+                            Arc::downgrade(&self.accounts),
                             #[cfg(feature = "events")]
                             self.event_emitter.clone(),
                             #[cfg(feature = "storage")]
@@ -50,7 +50,7 @@ pub(crate) async fn store_data_to_stronghold(
     }
 
     let mut serialized_accounts = Vec::new();
-    for account in wallet.accounts.read().await.iter() {
+    for account in wallet.load_accounts(&wallet.accounts.read().await).await? {
         serialized_accounts.push(serde_json::to_string(&*account.read().await)?);
     }
 
@@ -0,0 +1,62 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use iota_sdk::{
+    client::{Client, Result},
+    types::block::{
+        payload::{Payload, TaggedDataPayload},
+        BlockId,
+    },
+};
+
+#[tokio::test]
+async fn build_tagged_data_block_without_posting() -> Result<()> {
+    let client = Client::builder().with_local_pow(false).finish()?;
+    let parent = BlockId::null();
+
+    let block = client
+        .build_block()
+        .with_tagged_data_payload(TaggedDataPayload::new(b"sensor".to_vec(), b"21.5".to_vec())?)
+        .with_parents(vec![parent])?
+        .build()
+        .await?;
+
+    assert_eq!(block.parents().iter().collect::<Vec<_>>(), vec![&parent]);
+    match block.payload() {
+        Some(Payload::TaggedData(tagged_data)) => {
+            assert_eq!(tagged_data.tag(), b"sensor");
+            assert_eq!(tagged_data.data(), b"21.5");
+        }
+        _ => panic!("expected a tagged data payload"),
+    }
+
+    // Data without a tag is not allowed
+    assert!(
+        client
+            .build_block()
+            .with_data(b"21.5".to_vec())
+            .with_parents(vec![parent])?
+            .build()
+            .await
+            .is_err()
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn estimate_pow_capacity() -> Result<()> {
+    let client = Client::builder().with_local_pow(true).finish()?;
+
+    // Without timings the capacity is unknown
+    let estimate = client.estimate_pow_capacity(1.0, None).await?;
+    assert_eq!(estimate.sample_count, 0);
+    assert_eq!(estimate.max_blocks_per_second, None);
+    assert_eq!(estimate.sustainable, None);
+
+    // Without local PoW the nodes do the PoW
+    let client = Client::builder().with_local_pow(false).finish()?;
+    assert_eq!(client.estimate_pow_capacity(1.0, None).await?.sustainable, Some(true));
+
+    Ok(())
+}
@@ -1,7 +1,16 @@
 // Copyright 2022 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use iota_sdk::client::{Client, ClientBuilder};
+use std::time::Duration;
+
+use iota_sdk::{
+    client::{
+        node_manager::node::{NodeAuth, NodeDto},
+        CacheOptions, Client, ClientBuilder, Error, RequestJournalOptions, SubmissionOptions, SubmissionWindow,
+    },
+    types::block::{output::RentStructure, protocol::ProtocolParameters, Error as BlockError},
+    Url,
+};
 
 #[tokio::test]
 async fn invalid_url() {
@@ -132,3 +141,420 @@ async fn client_builder() {
 
     let _client_builder = serde_json::from_str::<ClientBuilder>(client_builder_json).unwrap();
 }
+
+#[test]
+fn client_builder_http_options() {
+    let client_builder = Client::builder()
+        .with_header("x-api-key", "secret")
+        .with_proxy("http://localhost:8080")
+        .unwrap()
+        .with_jwt("token");
+    assert!(Client::builder().with_proxy("not a url").is_err());
+
+    let json = client_builder.to_json().unwrap();
+    let deserialized = Client::builder().from_json(&json).unwrap();
+
+    assert_eq!(
+        deserialized.node_manager_builder.headers.get("x-api-key"),
+        Some(&"secret".to_string())
+    );
+    assert_eq!(
+        deserialized.node_manager_builder.proxy.as_ref().map(|url| url.as_str()),
+        Some("http://localhost:8080/")
+    );
+    assert_eq!(deserialized.node_manager_builder.jwt.as_deref(), Some("token"));
+}
+
+#[tokio::test]
+async fn client_jwt_and_timeout() {
+    use std::{
+        io::{Read, Write},
+        net::TcpListener,
+        sync::{
+            atomic::{AtomicBool, Ordering},
+            Arc, Mutex,
+        },
+    };
+
+    // A node which records the received requests and answers them with a server error, or not at all once it's stalled
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let node_url = format!("http://{}", listener.local_addr().unwrap());
+    let requests = Arc::new(Mutex::new(Vec::new()));
+    let stalled = Arc::new(AtomicBool::new(false));
+    let (requests_, stalled_) = (requests.clone(), stalled.clone());
+    std::thread::spawn(move || {
+        let mut stalled_streams = Vec::new();
+        for mut stream in listener.incoming().flatten() {
+            let mut buffer = [0; 4096];
+            let len = stream.read(&mut buffer).unwrap_or(0);
+            requests_
+                .lock()
+                .unwrap()
+                .push(String::from_utf8_lossy(&buffer[..len]).to_lowercase());
+            if stalled_.load(Ordering::SeqCst) {
+                stalled_streams.push(stream);
+            } else {
+                stream
+                    .write_all(b"HTTP/1.1 500 Internal Server Error\r\nconnection: close\r\ncontent-length: 0\r\n\r\n")
+                    .ok();
+            }
+        }
+    });
+
+    let client = Client::builder()
+        .with_node(&node_url)
+        .unwrap()
+        .with_ignore_node_health()
+        .with_api_timeout(Duration::from_secs(60))
+        .with_jwt("token")
+        .finish()
+        .unwrap();
+    assert!(client.get_info().await.is_err());
+
+    // The JWT of the node is sent instead of the one of the builder
+    let client_with_node_auth = Client::builder()
+        .with_node_auth(
+            &node_url,
+            Some(NodeAuth {
+                jwt: Some("node-token".to_string()),
+                basic_auth_name_pwd: None,
+            }),
+        )
+        .unwrap()
+        .with_ignore_node_health()
+        .with_jwt("token")
+        .finish()
+        .unwrap();
+    assert!(client_with_node_auth.get_info().await.is_err());
+
+    let requests = requests.lock().unwrap().clone();
+    assert!(!requests.is_empty());
+    let (node_auth_requests, builder_auth_requests): (Vec<_>, Vec<_>) = requests
+        .iter()
+        .partition(|request| request.contains("authorization: bearer node-token"));
+    assert!(!node_auth_requests.is_empty());
+    assert!(
+        builder_auth_requests
+            .iter()
+            .all(|request| request.contains("authorization: bearer token"))
+    );
+
+    // Requests of the clone time out after its own timeout instead of the API timeout of the builder
+    stalled.store(true, Ordering::SeqCst);
+    let start = std::time::Instant::now();
+    let result = client.with_timeout(Duration::from_millis(500)).get_info().await;
+    assert!(result.is_err());
+    assert!(start.elapsed() < Duration::from_secs(10));
+}
+
+#[test]
+fn client_builder_retry_options() {
+    let client_builder = Client::builder()
+        .with_max_retries(5)
+        .with_max_requests_per_second(10)
+        .with_max_parallel_requests_per_node(4);
+
+    let json = client_builder.to_json().unwrap();
+    let deserialized = Client::builder().from_json(&json).unwrap();
+
+    assert_eq!(deserialized.node_manager_builder.max_retries, 5);
+    assert_eq!(deserialized.node_manager_builder.max_requests_per_second, Some(10));
+    assert_eq!(deserialized.node_manager_builder.max_parallel_requests_per_node, Some(4));
+}
+
+#[test]
+fn client_builder_stable_json() {
+    let urls = ["http://localhost:14265", "http://localhost:14266", "http://localhost:14267"];
+    let forward = Client::builder()
+        .with_nodes(&urls)
+        .unwrap()
+        .with_header("a", "1")
+        .with_header("b", "2");
+    let mut reversed = Client::builder();
+    for url in urls.iter().rev() {
+        reversed = reversed.with_node(url).unwrap();
+    }
+    let reversed = reversed.with_header("b", "2").with_header("a", "1");
+
+    // The same options serialize to the same JSON, independent of the order they were added in
+    assert_eq!(forward.to_json().unwrap(), reversed.to_json().unwrap());
+    assert_eq!(
+        Client::builder().from_json(&forward.to_json().unwrap()).unwrap(),
+        forward
+    );
+}
+
+#[test]
+fn client_builder_validation() {
+    let invalid_option = |client_builder: ClientBuilder| match client_builder.validate() {
+        Err(Error::InvalidClientOptions { option, .. }) => option,
+        other => panic!("expected invalid client options, got {other:?}"),
+    };
+
+    let client_builder = Client::builder().with_node("http://localhost:14265").unwrap();
+    client_builder.validate().unwrap();
+
+    let mut with_invalid_url = client_builder.clone();
+    with_invalid_url
+        .node_manager_builder
+        .nodes
+        .insert(NodeDto::Url(Url::parse("ftp://localhost:14265").unwrap()));
+    assert_eq!(invalid_option(with_invalid_url), "nodes");
+
+    let with_conflicting_auth = client_builder
+        .clone()
+        .with_node_auth(
+            "http://localhost:14265",
+            Some(NodeAuth {
+                jwt: Some("jwt".to_string()),
+                basic_auth_name_pwd: None,
+            }),
+        )
+        .unwrap();
+    assert_eq!(invalid_option(with_conflicting_auth), "nodes");
+
+    assert_eq!(
+        invalid_option(client_builder.clone().with_api_timeout(Duration::ZERO)),
+        "apiTimeout"
+    );
+    assert_eq!(
+        invalid_option(client_builder.clone().with_node_sync_interval(Duration::ZERO)),
+        "nodeSyncInterval"
+    );
+    assert_eq!(
+        invalid_option(client_builder.clone().with_header("invalid header", "value")),
+        "headers"
+    );
+    assert_eq!(
+        invalid_option(client_builder.clone().with_proxy("ftp://localhost:8080").unwrap()),
+        "proxy"
+    );
+    assert_eq!(
+        invalid_option(client_builder.clone().with_proxy("socks5://localhost:1080").unwrap()),
+        "proxy"
+    );
+    assert_eq!(invalid_option(client_builder.clone().with_jwt("invalid\njwt")), "jwt");
+
+    let private_tangle_parameters = |bech32_hrp: &str| {
+        ProtocolParameters::new(
+            2,
+            String::from("private-tangle"),
+            String::from(bech32_hrp),
+            0,
+            15,
+            RentStructure::new(10, 10, 1),
+            2_779_530_283_277_761,
+        )
+        .unwrap()
+    };
+    client_builder
+        .clone()
+        .with_protocol_parameters(private_tangle_parameters("tst"))
+        .validate()
+        .unwrap();
+    assert_eq!(
+        invalid_option(
+            client_builder
+                .clone()
+                .with_protocol_parameters(private_tangle_parameters(""))
+        ),
+        "protocolParameters"
+    );
+    assert_eq!(
+        invalid_option(
+            client_builder
+                .clone()
+                .with_protocol_parameters(private_tangle_parameters("TST"))
+        ),
+        "protocolParameters"
+    );
+
+    // Quorum options are only validated if quorum is enabled
+    let with_quorum = client_builder.with_min_quorum_size(2);
+    with_quorum.validate().unwrap();
+    assert!(matches!(
+        with_quorum.clone().with_quorum(true).validate(),
+        Err(Error::QuorumPoolSizeError {
+            available_nodes: 1,
+            minimum_threshold: 2
+        })
+    ));
+    with_quorum
+        .with_quorum(true)
+        .with_node("http://localhost:14266")
+        .unwrap()
+        .validate()
+        .unwrap();
+}
+
+#[test]
+fn client_builder_cache_options() {
+    assert_eq!(Client::builder().cache, None);
+
+    let cache_options = CacheOptions {
+        capacity: 100,
+        ttl: Some(Duration::from_secs(60)),
+    };
+    let client_builder = Client::builder().with_cache(cache_options);
+
+    let json = client_builder.to_json().unwrap();
+    let deserialized = Client::builder().from_json(&json).unwrap();
+
+    assert_eq!(deserialized.cache, Some(cache_options));
+}
+
+#[test]
+fn client_builder_request_journal_options() {
+    assert_eq!(Client::builder().node_manager_builder.request_journal, None);
+
+    let options = RequestJournalOptions {
+        max_entries: 10,
+        ..Default::default()
+    }
+    .with_path("test-storage/request_journal.jsonl");
+    let client_builder = Client::builder().with_request_journal(options.clone());
+
+    let json = client_builder.to_json().unwrap();
+    let deserialized = Client::builder().from_json(&json).unwrap();
+
+    assert_eq!(deserialized.node_manager_builder.request_journal, Some(options));
+}
+
+#[tokio::test]
+async fn client_builder_submission_options() {
+    assert_eq!(Client::builder().submission_options, SubmissionOptions::default());
+
+    let window = SubmissionWindow {
+        start: 1_700_000_000,
+        end: Some(1_700_003_600),
+    };
+    let client_builder = Client::builder()
+        .with_target_pow_score(3000)
+        .with_max_blocks_per_second(5)
+        .with_submission_window(window);
+
+    let json = client_builder.to_json().unwrap();
+    let deserialized = Client::builder().from_json(&json).unwrap();
+
+    assert_eq!(
+        deserialized.submission_options,
+        SubmissionOptions {
+            target_pow_score: Some(3000),
+            max_blocks_per_second: Some(5),
+            window: Some(window),
+        }
+    );
+
+    let protocol_parameters = ProtocolParameters::new(
+        2,
+        String::from("testnet"),
+        String::from("rms"),
+        1500,
+        15,
+        RentStructure::default(),
+        1_813_620_509_061_365,
+    )
+    .unwrap();
+    let client = Client::builder()
+        .with_protocol_parameters(protocol_parameters.clone())
+        .with_target_pow_score(3000)
+        .finish()
+        .unwrap();
+    assert_eq!(client.get_target_pow_score().await.unwrap(), 3000);
+
+    // A target below the min PoW score of the network isn't used
+    let client = Client::builder()
+        .with_protocol_parameters(protocol_parameters)
+        .with_target_pow_score(1000)
+        .finish()
+        .unwrap();
+    assert_eq!(client.get_target_pow_score().await.unwrap(), 1500);
+}
+
+#[tokio::test]
+async fn client_builder_protocol_parameters() {
+    let protocol_parameters = ProtocolParameters::new(
+        3,
+        String::from("testnet"),
+        String::from("rms"),
+        0,
+        15,
+        RentStructure::default(),
+        1_813_620_509_061_365,
+    )
+    .unwrap();
+    let client = Client::builder()
+        .with_protocol_parameters(protocol_parameters.clone())
+        .finish()
+        .unwrap();
+
+    assert_eq!(client.get_protocol_parameters().await.unwrap(), protocol_parameters);
+    assert_eq!(
+        client.get_supported_protocol_parameters().await.unwrap(),
+        protocol_parameters
+    );
+
+    let unsupported_protocol_parameters = ProtocolParameters::new(
+        4,
+        String::from("testnet"),
+        String::from("rms"),
+        0,
+        15,
+        RentStructure::default(),
+        1_813_620_509_061_365,
+    )
+    .unwrap();
+    let client = Client::builder()
+        .with_protocol_parameters(unsupported_protocol_parameters)
+        .finish()
+        .unwrap();
+
+    assert!(matches!(
+        client.get_supported_protocol_parameters().await,
+        Err(Error::Block(BlockError::UnsupportedProtocolVersion(4)))
+    ));
+}
+
+#[tokio::test]
+async fn offline_client() {
+    let client_builder = Client::builder()
+        .with_node("http://localhost:14265")
+        .unwrap()
+        .offline();
+    assert_eq!(
+        Client::builder().from_json(&client_builder.to_json().unwrap()).unwrap(),
+        client_builder
+    );
+
+    let client = client_builder.finish().unwrap();
+    assert!(client.is_offline());
+
+    // Local functionality works with the protocol parameters of the builder
+    assert_eq!(
+        client.get_protocol_parameters().await.unwrap(),
+        ProtocolParameters::default()
+    );
+    // Requests aren't sent to the node
+    assert!(matches!(
+        client.get_tips().await,
+        Err(Error::OfflineMode(path)) if path == "api/core/v2/tips"
+    ));
+    assert!(client.healthy_nodes().is_empty());
+}
+
+#[tokio::test]
+async fn unhealthy_nodes_with_error() {
+    // Nothing listens on the port, so the node sync fails
+    let client = Client::builder()
+        .with_node("http://localhost:1")
+        .unwrap()
+        .with_max_retries(0)
+        .finish()
+        .unwrap();
+
+    let unhealthy_nodes = client.unhealthy_nodes();
+    assert_eq!(unhealthy_nodes.len(), 1);
+    assert_eq!(unhealthy_nodes[0].node.url.port(), Some(1));
+    assert!(unhealthy_nodes[0].error.is_some());
+    assert!(client.healthy_nodes().is_empty());
+}
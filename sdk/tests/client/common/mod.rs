@@ -5,10 +5,7 @@
 
 mod constants;
 
-use iota_sdk::client::{
-    constants::SHIMMER_COIN_TYPE, node_api::indexer::query_parameters::QueryParameter, request_funds_from_faucet,
-    secret::SecretManager, Client, Result,
-};
+use iota_sdk::client::{constants::SHIMMER_COIN_TYPE, secret::SecretManager, Client, Result};
 
 pub use self::constants::{FAUCET_URL, NODE_LOCAL};
 
@@ -32,23 +29,9 @@ pub async fn create_client_and_secret_manager_with_funds(mnemonic: Option<&str>)
         .finish()
         .await?[0];
 
-    request_funds_from_faucet(FAUCET_URL, address).await?;
-
-    // Continue only after funds are received
-    for _ in 0..30 {
-        tokio::time::sleep(std::time::Duration::from_secs(2)).await;
-        let output_ids_response = client
-            .basic_output_ids(vec![
-                QueryParameter::Address(address.clone()),
-                QueryParameter::HasExpiration(false),
-                QueryParameter::HasTimelock(false),
-                QueryParameter::HasStorageDepositReturn(false),
-            ])
-            .await?;
-
-        if !output_ids_response.is_empty() {
-            return Ok((client, secret_manager));
-        }
-    }
-    panic!("Faucet no longer wants to hand over coins");
+    client
+        .request_funds_from_faucet(FAUCET_URL, address, std::time::Duration::from_secs(60))
+        .await?;
+
+    Ok((client, secret_manager))
 }
@@ -0,0 +1,183 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use iota_sdk::client::{
+    api::GetAddressesBuilder,
+    compat::{derive_addresses, find_derivation, CompatScanOptions, DerivationPath, DerivationScheme},
+    constants::{IOTA_COIN_TYPE, SHIMMER_COIN_TYPE},
+    secret::{GenerateAddressOptions, SecretManager},
+    Result,
+};
+
+const MNEMONIC: &str = "acoustic trophy damage hint search taste love bicycle foster cradle brown govern endless depend situate athlete pudding blame question genius transfer van random vast";
+
+#[test]
+fn known_schemes() {
+    assert_eq!(
+        DerivationScheme::known(SHIMMER_COIN_TYPE),
+        [
+            DerivationScheme::Bip44 {
+                coin_type: SHIMMER_COIN_TYPE
+            },
+            DerivationScheme::Bip44 {
+                coin_type: IOTA_COIN_TYPE
+            },
+            DerivationScheme::SingleAddressAccounts {
+                coin_type: SHIMMER_COIN_TYPE
+            },
+            DerivationScheme::SingleAddressAccounts {
+                coin_type: IOTA_COIN_TYPE
+            },
+        ]
+    );
+    assert_eq!(
+        DerivationScheme::known(60),
+        [
+            DerivationScheme::Bip44 { coin_type: 60 },
+            DerivationScheme::SingleAddressAccounts { coin_type: 60 },
+        ]
+    );
+
+    let path = DerivationPath {
+        coin_type: IOTA_COIN_TYPE,
+        account_index: 1,
+        internal: true,
+        address_index: 3,
+    };
+    assert_eq!(path.to_string(), "m/44'/4218'/1'/1'/3'");
+}
+
+#[tokio::test]
+async fn find_address_derivation() -> Result<()> {
+    let secret_manager = SecretManager::try_from_mnemonic(MNEMONIC)?;
+    let schemes = DerivationScheme::known(SHIMMER_COIN_TYPE);
+    let options = CompatScanOptions::default();
+
+    // A change address of a profile that was created for IOTA
+    let address = GetAddressesBuilder::new(&secret_manager)
+        .with_coin_type(IOTA_COIN_TYPE)
+        .with_account_index(2)
+        .with_range(3..4)
+        .with_options(GenerateAddressOptions::internal())
+        .get_all_raw()
+        .await?
+        .internal[0];
+    let derived = find_derivation(&secret_manager, &address, &schemes, &options)
+        .await?
+        .unwrap();
+    assert_eq!(
+        derived.scheme,
+        DerivationScheme::Bip44 {
+            coin_type: IOTA_COIN_TYPE
+        }
+    );
+    assert_eq!(
+        derived.path,
+        DerivationPath {
+            coin_type: IOTA_COIN_TYPE,
+            account_index: 2,
+            internal: true,
+            address_index: 3,
+        }
+    );
+
+    // The 8th address of a single address wallet, beyond the scanned BIP-44 accounts
+    let address = GetAddressesBuilder::new(&secret_manager)
+        .with_coin_type(SHIMMER_COIN_TYPE)
+        .with_account_index(7)
+        .with_range(0..1)
+        .get_raw()
+        .await?[0];
+    let derived = find_derivation(&secret_manager, &address, &schemes, &options)
+        .await?
+        .unwrap();
+    assert_eq!(
+        derived.scheme,
+        DerivationScheme::SingleAddressAccounts {
+            coin_type: SHIMMER_COIN_TYPE
+        }
+    );
+    assert_eq!(derived.path.account_index, 7);
+
+    // Not derived within the scanned indexes
+    let address = GetAddressesBuilder::new(&secret_manager)
+        .with_coin_type(SHIMMER_COIN_TYPE)
+        .with_range(50..51)
+        .get_raw()
+        .await?[0];
+    assert!(
+        find_derivation(&secret_manager, &address, &schemes, &options)
+            .await?
+            .is_none()
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn derive_addresses_once() -> Result<()> {
+    let secret_manager = SecretManager::try_from_mnemonic(MNEMONIC)?;
+    let options = CompatScanOptions {
+        account_indexes: 0..2,
+        address_indexes: 0..3,
+    };
+
+    let derived = derive_addresses(&secret_manager, &DerivationScheme::known(SHIMMER_COIN_TYPE), &options).await?;
+
+    // 2 accounts * 2 chains * 3 indexes per coin type, the single address accounts 0 and 1 are BIP-44 addresses
+    assert_eq!(derived.len(), 2 * (2 * 2 * 3 + 1));
+    assert!(
+        derived
+            .iter()
+            .filter(|derived| matches!(derived.scheme, DerivationScheme::SingleAddressAccounts { .. }))
+            .all(|derived| derived.path.account_index == 2)
+    );
+
+    Ok(())
+}
+
+#[cfg(feature = "test_utils")]
+#[tokio::test]
+async fn scan_derivation_schemes() -> Result<()> {
+    use iota_sdk::{
+        client::{test_utils::MockNode, Client},
+        types::block::output::{unlock_condition::AddressUnlockCondition, BasicOutputBuilder},
+    };
+
+    let secret_manager = SecretManager::try_from_mnemonic(MNEMONIC)?;
+    let mock_node = MockNode::default();
+    // Funds on an IOTA derived address, like the claimed Shimmer airdrop
+    let address = GetAddressesBuilder::new(&secret_manager)
+        .with_coin_type(IOTA_COIN_TYPE)
+        .with_range(1..2)
+        .get_raw()
+        .await?[0];
+    let output = BasicOutputBuilder::new_with_amount(1_000_000)
+        .add_unlock_condition(AddressUnlockCondition::new(address))
+        .finish_output(mock_node.protocol_parameters().token_supply())?;
+    let output_id = mock_node.add_output(output);
+    let client = Client::builder().with_mock_node(mock_node)?.finish()?;
+
+    let funded = client
+        .scan_derivation_schemes(
+            &secret_manager,
+            &DerivationScheme::known(SHIMMER_COIN_TYPE),
+            &CompatScanOptions {
+                account_indexes: 0..1,
+                address_indexes: 0..3,
+            },
+        )
+        .await?;
+
+    assert_eq!(funded.len(), 1);
+    assert_eq!(funded[0].derived.address, address);
+    assert_eq!(funded[0].derived.path.to_string(), "m/44'/4218'/0'/0'/1'");
+    assert_eq!(
+        funded[0].bech32_address,
+        address.to_bech32(client.get_bech32_hrp().await?)
+    );
+    assert_eq!(funded[0].output_ids, [output_id]);
+    assert_eq!(funded[0].amount, 1_000_000);
+
+    Ok(())
+}
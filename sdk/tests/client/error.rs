@@ -43,4 +43,14 @@ fn stringified_error() {
         &serde_json::to_string(&error).unwrap(),
         "{\"type\":\"inputSelection\",\"error\":\"invalid address provided\"}"
     );
+
+    let error = Error::ResponseError {
+        code: 404,
+        text: "not found".to_string(),
+        url: "http://localhost:14265/api/core/v2/info".to_string(),
+    };
+    assert_eq!(
+        &serde_json::to_string(&error).unwrap(),
+        "{\"type\":\"responseError\",\"error\":\"response error with status code 404: not found, URL: http://localhost:14265/api/core/v2/info\",\"status\":404,\"url\":\"http://localhost:14265/api/core/v2/info\"}"
+    );
 }
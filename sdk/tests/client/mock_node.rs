@@ -0,0 +1,296 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::time::Duration;
+
+use futures::{StreamExt, TryStreamExt};
+use iota_sdk::{
+    client::{
+        node_api::indexer::query_parameters::QueryParameter,
+        test_utils::{Confirmation, MockNode},
+        Client, Error, Result,
+    },
+    types::block::{
+        address::Address,
+        output::{unlock_condition::AddressUnlockCondition, BasicOutputBuilder, OutputId},
+        payload::{transaction::TransactionId, TaggedDataPayload},
+    },
+};
+
+const BECH32_ADDRESS: &str = "rms1qr2xsmt3v3eyp2ja80wd2sq8xx0fslefmxguf7tshzezzr5qsctzc2f5dg6";
+#[cfg(feature = "operator")]
+const PEER_ID: &str = "12D3KooWRcm6eU9XA8qjU5GEh3ZbuMyA7qa4kBR2kAPYnGNESpDT";
+
+#[tokio::test]
+async fn mock_node() -> Result<()> {
+    let mock_node = MockNode::default();
+    mock_node.set_confirmation(Confirmation::Never);
+    let output = BasicOutputBuilder::new_with_amount(1_000_000)
+        .add_unlock_condition(AddressUnlockCondition::new(Address::try_from_bech32(BECH32_ADDRESS)?))
+        .finish_output(mock_node.protocol_parameters().token_supply())?;
+    let output_id = mock_node.add_output(output.clone());
+
+    let client = Client::builder().with_mock_node(mock_node.clone())?.finish()?;
+
+    assert_eq!(client.get_info().await?.node_info.protocol.network_name, "mock");
+    assert_eq!(client.get_output(&output_id).await?.output(), &output);
+    let output_ids = client
+        .basic_output_ids(vec![QueryParameter::Address(BECH32_ADDRESS.to_string())])
+        .await?;
+    assert_eq!(output_ids.items, [output_id]);
+    let output_ids = client
+        .basic_output_ids(vec![QueryParameter::HasExpiration(true)])
+        .await?;
+    assert!(output_ids.items.is_empty());
+
+    // Only the next request fails
+    mock_node.inject_error("api/core/v2/outputs", 404, 1);
+    assert!(matches!(client.get_output(&output_id).await, Err(Error::NotFound(_))));
+    assert!(client.get_output(&output_id).await.is_ok());
+    assert!(
+        mock_node
+            .requests()
+            .contains(&format!("GET /api/core/v2/outputs/{output_id}"))
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn get_outputs_with_errors() -> Result<()> {
+    let mock_node = MockNode::default();
+    let output = BasicOutputBuilder::new_with_amount(1_000_000)
+        .add_unlock_condition(AddressUnlockCondition::new(Address::try_from_bech32(BECH32_ADDRESS)?))
+        .finish_output(mock_node.protocol_parameters().token_supply())?;
+    let output_id = mock_node.add_output(output.clone());
+    let pruned_output_id = OutputId::new(TransactionId::new([1; 32]), 0)?;
+
+    let client = Client::builder().with_mock_node(mock_node.clone())?.finish()?;
+
+    let outputs_with_errors = client
+        .get_outputs_with_errors(vec![output_id, pruned_output_id, output_id])
+        .await;
+    // The duplicated output id is only requested once
+    assert_eq!(outputs_with_errors.outputs.len(), 1);
+    assert_eq!(outputs_with_errors.outputs[0].output(), &output);
+    assert_eq!(outputs_with_errors.errors.len(), 1);
+    assert_eq!(outputs_with_errors.errors[0].0, pruned_output_id);
+    assert!(matches!(outputs_with_errors.errors[0].1, Error::NotFound(_)));
+    assert_eq!(
+        mock_node
+            .requests()
+            .iter()
+            .filter(|request| **request == format!("GET /api/core/v2/outputs/{output_id}"))
+            .count(),
+        1
+    );
+
+    assert!(client.get_outputs(vec![output_id, pruned_output_id]).await.is_err());
+    assert_eq!(
+        client
+            .get_outputs_ignore_errors(vec![output_id, pruned_output_id])
+            .await?
+            .len(),
+        1
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn permanode_fallback() -> Result<()> {
+    let mock_node = MockNode::default();
+    let output = BasicOutputBuilder::new_with_amount(1_000_000)
+        .add_unlock_condition(AddressUnlockCondition::new(Address::try_from_bech32(BECH32_ADDRESS)?))
+        .finish_output(mock_node.protocol_parameters().token_supply())?;
+    let output_id = mock_node.add_output(output.clone());
+
+    // The mock node also answers the requests to the permanode
+    let client = Client::builder()
+        .with_mock_node(mock_node.clone())?
+        .with_permanode("http://permanode.test", None)?
+        .finish()?;
+    let request_count = |request: &str| mock_node.requests().iter().filter(|r| r.as_str() == request).count();
+    let milestone_index = mock_node.milestone_index();
+    let utxo_changes_request = format!("GET /api/core/v2/milestones/by-index/{milestone_index}/utxo-changes");
+
+    // The node pruned the milestone, but the permanode still has it
+    mock_node.inject_error("api/core/v2/milestones", 404, 1);
+    assert_eq!(
+        client.get_utxo_changes(milestone_index).await?.created_outputs,
+        [output_id]
+    );
+    assert_eq!(request_count(&utxo_changes_request), 2);
+
+    // The error of the node is returned if the permanode doesn't have the data either
+    mock_node.inject_error("api/core/v2/milestones", 404, 2);
+    assert!(matches!(
+        client.get_utxo_changes(milestone_index).await,
+        Err(Error::NotFound(_))
+    ));
+    assert_eq!(request_count(&utxo_changes_request), 4);
+
+    // Other errors aren't caused by pruning, so the permanode isn't requested
+    mock_node.inject_error("api/core/v2/milestones", 400, 1);
+    assert!(client.get_utxo_changes(milestone_index).await.is_err());
+    assert_eq!(request_count(&utxo_changes_request), 5);
+
+    // Raw data is also requested from the permanode
+    mock_node.inject_error("api/core/v2/outputs", 404, 1);
+    assert!(client.get_output_raw(&output_id).await.is_ok());
+    assert_eq!(request_count(&format!("GET /api/core/v2/outputs/{output_id}")), 2);
+
+    // Blocks are never posted to the permanode
+    let block = client
+        .build_block()
+        .with_tagged_data_payload(TaggedDataPayload::new(b"tag".to_vec(), Vec::new())?)
+        .build()
+        .await?;
+    mock_node.inject_error("api/core/v2/blocks", 404, 1);
+    assert!(client.post_block(&block).await.is_err());
+    assert_eq!(request_count("POST /api/core/v2/blocks"), 1);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn node_health() -> Result<()> {
+    // The info of the node says it's healthy, but the /health endpoint doesn't, health probes aren't retried
+    for status in [503, 429] {
+        let mock_node = MockNode::default();
+        mock_node.inject_error("health", status, 1);
+        let client = Client::builder().with_mock_node(mock_node.clone())?.finish()?;
+
+        assert!(client.healthy_nodes().is_empty());
+        let unhealthy_nodes = client.unhealthy_nodes();
+        assert_eq!(unhealthy_nodes.len(), 1);
+        assert_eq!(unhealthy_nodes[0].error.as_deref(), Some("node is not healthy"));
+        assert!(matches!(client.get_node(), Err(Error::HealthyNodePoolEmpty)));
+        assert_eq!(
+            mock_node
+                .requests()
+                .iter()
+                .filter(|request| *request == "GET /health")
+                .count(),
+            1
+        );
+    }
+
+    // The /health endpoint isn't requested if the node health is ignored
+    let mock_node = MockNode::default();
+    mock_node.inject_error("health", 503, 1);
+    let client = Client::builder()
+        .with_mock_node(mock_node.clone())?
+        .with_ignore_node_health()
+        .finish()?;
+
+    assert_eq!(client.healthy_nodes().len(), 1);
+    assert!(client.unhealthy_nodes().is_empty());
+    assert!(!mock_node.requests().iter().any(|request| request == "GET /health"));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn node_failover() -> Result<()> {
+    let mock_node = MockNode::default();
+    // The mock node answers the requests to both nodes
+    let client = Client::builder()
+        .with_mock_node(mock_node.clone())?
+        .with_node("http://node.test")?
+        .finish()?;
+    assert_eq!(client.healthy_nodes().len(), 2);
+
+    // The request isn't retried on the first node, but sent to the other node
+    mock_node.inject_error("api/core/v2/tips", 503, 1);
+    assert!(!client.get_tips().await?.is_empty());
+    assert_eq!(
+        mock_node
+            .requests()
+            .iter()
+            .filter(|request| *request == "GET /api/core/v2/tips")
+            .count(),
+        2
+    );
+
+    // Both nodes failing is an error
+    mock_node.inject_error("api/core/v2/tips", 503, 2);
+    assert!(client.get_tips().await.is_err());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn rate_limit_retries() -> Result<()> {
+    let mock_node = MockNode::default();
+    let client = Client::builder().with_mock_node(mock_node.clone())?.finish()?;
+    let tips_requests = || {
+        mock_node
+            .requests()
+            .iter()
+            .filter(|request| *request == "GET /api/core/v2/tips")
+            .count()
+    };
+
+    // Rate limited requests are retried on the same node
+    mock_node.inject_error("api/core/v2/tips", 429, 1);
+    assert!(!client.get_tips().await?.is_empty());
+    assert_eq!(tips_requests(), 2);
+
+    // Server errors aren't retried, without another node the request fails
+    mock_node.inject_error("api/core/v2/tips", 503, 1);
+    assert!(client.get_tips().await.is_err());
+    assert_eq!(tips_requests(), 3);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn utxo_changes_stream() -> Result<()> {
+    let mock_node = MockNode::default();
+    let output = BasicOutputBuilder::new_with_amount(1_000_000)
+        .add_unlock_condition(AddressUnlockCondition::new(Address::try_from_bech32(BECH32_ADDRESS)?))
+        .finish_output(mock_node.protocol_parameters().token_supply())?;
+    let start_index = mock_node.milestone_index();
+    let output_id = mock_node.add_output(output);
+    mock_node.issue_milestone();
+
+    let client = Client::builder().with_mock_node(mock_node.clone())?.finish()?;
+
+    let utxo_changes = client.get_utxo_changes(start_index).await?;
+    assert_eq!(utxo_changes.created_outputs, [output_id]);
+    assert!(utxo_changes.consumed_outputs.is_empty());
+
+    // Both milestones are already confirmed, so they're yielded without waiting
+    let utxo_changes = client
+        .utxo_changes_stream(Some(start_index), Duration::from_secs(60))
+        .take(2)
+        .try_collect::<Vec<_>>()
+        .await?;
+    assert_eq!(*utxo_changes[0].index, start_index);
+    assert_eq!(*utxo_changes[1].index, start_index + 1);
+    assert!(utxo_changes[1].created_outputs.is_empty());
+
+    Ok(())
+}
+
+#[cfg(feature = "operator")]
+#[tokio::test]
+async fn mock_node_operator_routes() -> Result<()> {
+    let mock_node = MockNode::default();
+    let client = Client::builder().with_mock_node(mock_node.clone())?.finish()?;
+
+    let capabilities = client.get_node_capabilities().await?;
+    assert!(capabilities.has_route("indexer/v1"));
+    assert!(!capabilities.has_route("participation/v1"));
+
+    // The mock node has no peer routes
+    assert!(matches!(client.remove_peer(PEER_ID).await, Err(Error::NotFound(_))));
+    assert!(
+        mock_node
+            .requests()
+            .contains(&format!("DELETE /api/core/v2/peers/{PEER_ID}"))
+    );
+
+    Ok(())
+}
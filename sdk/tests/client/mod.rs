@@ -2,15 +2,20 @@
 // SPDX-License-Identifier: Apache-2.0
 
 mod addresses;
+mod block_builder;
 mod client_builder;
+mod compat;
 mod common;
 mod error;
 mod input_selection;
 mod message_interface;
 mod mnemonic;
+#[cfg(feature = "test_utils")]
+mod mock_node;
 #[cfg(feature = "mqtt")]
 mod mqtt;
 mod node_api;
+mod request_journal;
 mod secret_manager;
 mod signing;
 mod transactions;
@@ -3,10 +3,11 @@
 
 // These are E2E test samples, so they are ignored by default.
 
+use futures::TryStreamExt;
 use iota_sdk::{
     client::{
-        bech32_to_hex, node_api::indexer::query_parameters::QueryParameter, request_funds_from_faucet,
-        secret::SecretManager, Client,
+        api::ConeWalkOptions, bech32_to_hex, node_api::indexer::query_parameters::QueryParameter,
+        request_funds_from_faucet, secret::SecretManager, Client,
     },
     types::block::{
         output::OutputId,
@@ -163,6 +164,41 @@ async fn test_get_block_metadata() {
     println!("{r:#?}");
 }
 
+#[ignore]
+#[tokio::test]
+async fn test_get_block_parents_and_children() {
+    let block_id = setup_tagged_data_block().await;
+    let client = setup_client_with_node_health_ignored();
+
+    let parents = client.get_block_parents(&block_id).await.unwrap();
+    let children = client.get_block_children(&parents[0]).await.unwrap();
+
+    assert!(children.contains(&block_id));
+}
+
+#[ignore]
+#[tokio::test]
+async fn test_walk_cone() {
+    let block_id = setup_tagged_data_block().await;
+
+    let visited_blocks = setup_client_with_node_health_ignored()
+        .walk_cone(
+            block_id,
+            ConeWalkOptions {
+                max_depth: 2,
+                max_blocks: 20,
+                ..Default::default()
+            },
+        )
+        .try_collect::<Vec<_>>()
+        .await
+        .unwrap();
+
+    assert_eq!(visited_blocks[0].block_id, block_id);
+    assert!(visited_blocks.len() <= 20);
+    assert!(visited_blocks.iter().all(|visited_block| visited_block.depth <= 2));
+}
+
 #[ignore]
 #[tokio::test]
 async fn test_get_block_raw() {
@@ -339,3 +375,26 @@ async fn test_get_included_block() {
 
     println!("{r:#?}");
 }
+
+#[ignore]
+#[tokio::test]
+async fn test_send_and_retrieve_data() {
+    let client = setup_client_with_node_health_ignored();
+
+    let block = client.send_data(b"notarization".to_vec(), b"document hash".to_vec()).await.unwrap();
+    let tagged_data = client.retrieve_data(&block.id()).await.unwrap();
+    assert_eq!(tagged_data.tag(), b"notarization");
+    assert_eq!(tagged_data.data(), b"document hash");
+
+    let key = [7; 32];
+    let block = client
+        .send_encrypted_data(b"notarization".to_vec(), b"secret document", &key)
+        .await
+        .unwrap();
+    assert_ne!(client.retrieve_data(&block.id()).await.unwrap().data(), b"secret document");
+    assert_eq!(
+        client.retrieve_encrypted_data(&block.id(), &key).await.unwrap(),
+        b"secret document"
+    );
+    assert!(client.retrieve_encrypted_data(&block.id(), &[8; 32]).await.is_err());
+}
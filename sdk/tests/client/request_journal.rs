@@ -0,0 +1,53 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::time::Duration;
+
+use iota_sdk::{
+    client::{parse_request_journal, Client, Error, RequestJournalEntry},
+    types::block::BlockId,
+};
+
+fn recorded_tips(tip: BlockId) -> RequestJournalEntry {
+    RequestJournalEntry {
+        timestamp: 0,
+        method: "GET".to_string(),
+        url: "https://node.example.com/api/core/v2/tips".to_string(),
+        request_body: None,
+        status: Some(200),
+        response_body: Some(format!(r#"{{"tips":["{tip}"]}}"#)),
+        error: None,
+        duration: Duration::from_millis(10),
+        truncated: false,
+    }
+}
+
+#[tokio::test]
+async fn replay_request_journal() -> iota_sdk::client::Result<()> {
+    let first_tip = BlockId::new([1; 32]);
+    let second_tip = BlockId::new([2; 32]);
+    let journal = [recorded_tips(first_tip), recorded_tips(second_tip)]
+        .iter()
+        .map(serde_json::to_string)
+        .collect::<Result<Vec<_>, _>>()?
+        .join("\n");
+
+    // Replayed with another node than the recorded one
+    let client = Client::builder()
+        .with_node("http://localhost:14265")?
+        .with_ignore_node_health()
+        .with_request_replay(parse_request_journal(&journal)?)
+        .finish()?;
+
+    assert_eq!(client.get_tips().await?, [first_tip]);
+    assert_eq!(client.get_tips().await?, [second_tip]);
+    // The last recorded response is repeated
+    assert_eq!(client.get_tips().await?, [second_tip]);
+    // Requests that weren't recorded fail
+    assert!(matches!(
+        client.get_block(&first_tip).await,
+        Err(Error::NoRecordedResponse(_))
+    ));
+
+    Ok(())
+}
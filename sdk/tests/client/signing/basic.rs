@@ -4,10 +4,13 @@
 use crypto::keys::slip10::Chain;
 use iota_sdk::{
     client::{
-        api::{transaction::validate_transaction_payload_length, verify_semantic, PreparedTransactionData},
+        api::{
+            rebind_network, transaction::validate_transaction_payload_length, verify_network_id, verify_semantic,
+            PreparedTransactionData,
+        },
         constants::{HD_WALLET_TYPE, SHIMMER_COIN_TYPE, SHIMMER_TESTNET_BECH32_HRP},
         secret::{SecretManage, SecretManageExt, SecretManager},
-        Client, Result,
+        Client, Error, Result,
     },
     types::block::{
         input::{Input, UtxoInput},
@@ -16,9 +19,10 @@ use iota_sdk::{
             transaction::{RegularTransactionEssence, TransactionEssence},
             TransactionPayload,
         },
-        protocol::protocol_parameters,
-        semantic::ConflictReason,
-        unlock::{SignatureUnlock, Unlock},
+        protocol::{protocol_parameters, ProtocolParameters},
+        semantic::{validate_transaction_payload, ConflictReason},
+        unlock::{ReferenceUnlock, SignatureUnlock, Unlock, Unlocks},
+        Error as BlockError,
     },
 };
 
@@ -356,3 +360,285 @@ async fn two_signature_unlocks() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn reference_unlock_of_other_address() -> Result<()> {
+    let secret_manager = SecretManager::try_from_mnemonic(&Client::generate_mnemonic()?)?;
+
+    let bech32_address_0 = &secret_manager
+        .generate_addresses(SHIMMER_COIN_TYPE, 0, 0..1, None)
+        .await?[0]
+        .to_bech32(SHIMMER_TESTNET_BECH32_HRP);
+    let bech32_address_1 = &secret_manager
+        .generate_addresses(SHIMMER_COIN_TYPE, 0, 1..2, None)
+        .await?[0]
+        .to_bech32(SHIMMER_TESTNET_BECH32_HRP);
+
+    let protocol_parameters = protocol_parameters();
+
+    let inputs = build_inputs(
+        [(bech32_address_0, 0), (bech32_address_1, 1), (bech32_address_1, 1)]
+            .into_iter()
+            .map(|(address, index)| {
+                Basic(
+                    1_000_000,
+                    address,
+                    None,
+                    None,
+                    None,
+                    None,
+                    None,
+                    Some(Chain::from_u32_hardened(vec![
+                        HD_WALLET_TYPE,
+                        SHIMMER_COIN_TYPE,
+                        0,
+                        0,
+                        index,
+                    ])),
+                )
+            })
+            .collect(),
+    );
+
+    let outputs = build_outputs(vec![Basic(
+        3_000_000,
+        bech32_address_0,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    )]);
+
+    let essence = TransactionEssence::Regular(
+        RegularTransactionEssence::builder(
+            protocol_parameters.network_id(),
+            InputsCommitment::new(inputs.iter().map(|i| &i.output)),
+        )
+        .with_inputs(
+            inputs
+                .iter()
+                .map(|i| Input::Utxo(UtxoInput::from(*i.output_metadata.output_id())))
+                .collect(),
+        )
+        .with_outputs(outputs)
+        .finish(&protocol_parameters)?,
+    );
+
+    let prepared_transaction_data = PreparedTransactionData {
+        essence,
+        inputs_data: inputs,
+        remainder: None,
+    };
+
+    let unlocks = secret_manager
+        .sign_transaction_essence(&prepared_transaction_data, Some(0))
+        .await?;
+
+    assert_eq!(unlocks.len(), 3);
+    assert_eq!((*unlocks).get(2).unwrap().kind(), ReferenceUnlock::KIND);
+
+    let current_time = 100;
+
+    let tx_payload = TransactionPayload::new(prepared_transaction_data.essence.clone(), unlocks.clone())?;
+    let conflict = verify_semantic(&prepared_transaction_data.inputs_data, &tx_payload, current_time)?;
+    assert_eq!(conflict, ConflictReason::None);
+
+    // The consumed outputs need to be in the order of the inputs
+    let mut inputs = prepared_transaction_data
+        .inputs_data
+        .iter()
+        .map(|input| (*input.output_id(), &input.output))
+        .collect::<Vec<_>>();
+    inputs.swap(0, 1);
+    let conflict = validate_transaction_payload(&tx_payload, &inputs, current_time)?;
+    assert_eq!(conflict, ConflictReason::InputUtxoNotFound);
+
+    // The third input is owned by the second address, so it can't reference the signature of the first one
+    let unlocks = Unlocks::new(vec![
+        unlocks[0].clone(),
+        unlocks[1].clone(),
+        Unlock::Reference(ReferenceUnlock::new(0)?),
+    ])?;
+    let tx_payload = TransactionPayload::new(prepared_transaction_data.essence.clone(), unlocks)?;
+    let conflict = verify_semantic(&prepared_transaction_data.inputs_data, &tx_payload, current_time)?;
+    assert_eq!(conflict, ConflictReason::InvalidUnlock);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn rebind_essence_to_other_network() -> Result<()> {
+    let secret_manager = SecretManager::try_from_mnemonic(&Client::generate_mnemonic()?)?;
+
+    let bech32_address_0 = &secret_manager
+        .generate_addresses(SHIMMER_COIN_TYPE, 0, 0..1, None)
+        .await?[0]
+        .to_bech32(SHIMMER_TESTNET_BECH32_HRP);
+
+    let protocol_parameters = protocol_parameters();
+    let other_protocol_parameters = ProtocolParameters::new(
+        protocol_parameters.protocol_version(),
+        String::from("other"),
+        protocol_parameters.bech32_hrp().to_string(),
+        protocol_parameters.min_pow_score(),
+        protocol_parameters.below_max_depth(),
+        *protocol_parameters.rent_structure(),
+        protocol_parameters.token_supply(),
+    )?;
+
+    let inputs = build_inputs(vec![Basic(
+        1_000_000,
+        bech32_address_0,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    )]);
+    let outputs = build_outputs(vec![Basic(
+        1_000_000,
+        bech32_address_0,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    )]);
+
+    let essence = TransactionEssence::Regular(
+        RegularTransactionEssence::builder(
+            protocol_parameters.network_id(),
+            InputsCommitment::new(inputs.iter().map(|i| &i.output)),
+        )
+        .with_inputs(
+            inputs
+                .iter()
+                .map(|i| Input::Utxo(UtxoInput::from(*i.output_metadata.output_id())))
+                .collect(),
+        )
+        .with_outputs(outputs)
+        .finish(&protocol_parameters)?,
+    );
+
+    let error = verify_network_id(&essence, &other_protocol_parameters).unwrap_err();
+    assert!(matches!(
+        error,
+        Error::TransactionNetworkIdMismatch { expected, actual }
+            if expected == other_protocol_parameters.network_id() && actual == protocol_parameters.network_id()
+    ));
+
+    let rebound_essence = rebind_network(&essence, &other_protocol_parameters)?;
+    verify_network_id(&rebound_essence, &other_protocol_parameters)?;
+
+    let (TransactionEssence::Regular(essence), TransactionEssence::Regular(rebound_essence)) =
+        (&essence, &rebound_essence);
+    assert_eq!(rebound_essence.network_id(), other_protocol_parameters.network_id());
+    assert_eq!(rebound_essence.inputs(), essence.inputs());
+    assert_eq!(rebound_essence.outputs(), essence.outputs());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn inputs_commitment_mismatch() -> Result<()> {
+    let secret_manager = SecretManager::try_from_mnemonic(&Client::generate_mnemonic()?)?;
+
+    let bech32_address_0 = &secret_manager
+        .generate_addresses(SHIMMER_COIN_TYPE, 0, 0..1, None)
+        .await?[0]
+        .to_bech32(SHIMMER_TESTNET_BECH32_HRP);
+
+    let protocol_parameters = protocol_parameters();
+    let chain = Chain::from_u32_hardened(vec![HD_WALLET_TYPE, SHIMMER_COIN_TYPE, 0, 0, 0]);
+
+    let inputs = build_inputs(vec![
+        Basic(
+            1_000_000,
+            bech32_address_0,
+            None,
+            None,
+            None,
+            None,
+            None,
+            Some(chain.clone()),
+        ),
+        Basic(
+            2_000_000,
+            bech32_address_0,
+            None,
+            None,
+            None,
+            None,
+            None,
+            Some(chain.clone()),
+        ),
+    ]);
+
+    let outputs = build_outputs(vec![Basic(
+        3_000_000,
+        bech32_address_0,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    )]);
+
+    let essence = TransactionEssence::Regular(
+        RegularTransactionEssence::builder(
+            protocol_parameters.network_id(),
+            InputsCommitment::new(inputs.iter().map(|i| &i.output)),
+        )
+        .with_inputs(
+            inputs
+                .iter()
+                .map(|i| Input::Utxo(UtxoInput::from(*i.output_metadata.output_id())))
+                .collect(),
+        )
+        .with_outputs(outputs)
+        .finish(&protocol_parameters)?,
+    );
+
+    let prepared_transaction_data = PreparedTransactionData {
+        essence: essence.clone(),
+        inputs_data: inputs.clone(),
+        remainder: None,
+    };
+    prepared_transaction_data.verify_inputs_commitment()?;
+
+    // Inputs data in another order than the inputs of the essence.
+    let prepared_transaction_data = PreparedTransactionData {
+        essence: essence.clone(),
+        inputs_data: inputs.iter().rev().cloned().collect(),
+        remainder: None,
+    };
+    assert!(matches!(
+        secret_manager
+            .sign_transaction_essence(&prepared_transaction_data, Some(0))
+            .await,
+        Err(Error::Block(BlockError::InputOutputIdMismatch { index: 0, expected, actual }))
+            if &expected == inputs[0].output_id() && &actual == inputs[1].output_id()
+    ));
+
+    // Inputs data with an output that isn't committed to.
+    let mut inputs_data = inputs.clone();
+    inputs_data[0].output = inputs[1].output.clone();
+    let prepared_transaction_data = PreparedTransactionData {
+        essence,
+        inputs_data,
+        remainder: None,
+    };
+    assert!(matches!(
+        secret_manager
+            .sign_transaction_essence(&prepared_transaction_data, Some(0))
+            .await,
+        Err(Error::Block(BlockError::InputsCommitmentMismatch { .. }))
+    ));
+
+    Ok(())
+}
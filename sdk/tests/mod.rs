@@ -6,5 +6,6 @@ mod client;
 #[cfg(feature = "pow")]
 mod pow;
 mod types;
+mod utils;
 #[cfg(feature = "wallet")]
 mod wallet;
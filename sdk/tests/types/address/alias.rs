@@ -101,6 +101,33 @@ fn bech32_roundtrip() {
     );
 }
 
+#[test]
+fn alias_id_conversions() {
+    let alias_id = AliasId::from_str(ALIAS_ID).unwrap();
+    let address = Address::from(alias_id);
+
+    assert_eq!(address, Address::from(AliasAddress::new(alias_id)));
+    assert_eq!(AliasId::try_from(address).unwrap(), alias_id);
+    assert_eq!(
+        Address::try_from_bech32_with_expected_hrp(ALIAS_BECH32, "rms").unwrap(),
+        address
+    );
+    assert_eq!(
+        Address::try_from_bech32_with_expected_hrp(ALIAS_BECH32, "smr"),
+        Err(Error::Bech32HrpMismatch {
+            expected: String::from("smr"),
+            actual: String::from("rms"),
+        })
+    );
+    assert_eq!(
+        iota_sdk::types::block::output::NftId::try_from(address),
+        Err(Error::AddressKindMismatch {
+            expected: iota_sdk::types::block::address::NftAddress::KIND,
+            actual: AliasAddress::KIND,
+        })
+    );
+}
+
 #[test]
 fn dto_fields() {
     let alias_address = AliasAddress::from_str(ALIAS_ID).unwrap();
@@ -49,6 +49,18 @@ fn as_methods() {
     assert!(std::panic::catch_unwind(|| address.as_nft()).is_err());
 }
 
+#[test]
+fn from_public_key_bytes() {
+    let public_key = prefix_hex::decode("0x2baaf3bca8ace9f862e60184bd3e79df25ff230f7eaaa4c7f03daa9833ba854a").unwrap();
+    let address = Address::from(Ed25519Address::from_public_key_bytes(public_key));
+
+    assert_eq!(
+        address.to_bech32("atoi"),
+        "atoi1qzt0nhsf38nh6rs4p6zs5knqp6psgha9wsv74uajqgjmwc75ugupx3y7x0r"
+    );
+    assert_eq!(Ed25519Address::try_from(address).unwrap(), *address.as_ed25519());
+}
+
 #[test]
 fn new_bytes() {
     let bytes = prefix_hex::decode(ED25519_ADDRESS).unwrap();
@@ -5,7 +5,10 @@ mod alias;
 mod ed25519;
 mod nft;
 
-use iota_sdk::types::block::{address::Address, Error};
+use iota_sdk::types::block::{
+    address::{Address, Bech32Address},
+    Error,
+};
 
 const ED25519_ADDRESS_INVALID: &str = "0x52fdfc072182654f163f5f0f9a621d729566c74d10037c4d7bbb0407d1e2c64x";
 
@@ -15,3 +18,19 @@ fn invalid_bech32() {
 
     assert!(matches!(address, Err(Error::InvalidAddress)));
 }
+
+#[test]
+fn malformed_hrp() {
+    let address = Address::try_from_bech32("rms1qr47gz3xxjqpjrwd0yu5glhqrth6w0t08npney8000ust2lcw2r92j5a8rt").unwrap();
+
+    assert_eq!(
+        address.try_to_bech32("rms").unwrap(),
+        "rms1qr47gz3xxjqpjrwd0yu5glhqrth6w0t08npney8000ust2lcw2r92j5a8rt"
+    );
+    assert_eq!(address.try_to_bech32(""), Err(Error::MalformedBech32Hrp(String::new())));
+    assert_eq!(
+        address.try_to_bech32("Rms"),
+        Err(Error::MalformedBech32Hrp(String::from("Rms")))
+    );
+    assert!(Bech32Address::new(String::from("r ms"), address).is_err());
+}
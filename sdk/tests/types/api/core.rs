@@ -0,0 +1,35 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use iota_sdk::types::{
+    api::core::response::{Treasury, TreasuryResponse},
+    block::{protocol::protocol_parameters, Error},
+};
+
+const MILESTONE_ID: &str = "0x52fdfc072182654f163f5f0f9a621d729566c74d10037c4d7bbb0407d1e2c649";
+
+#[test]
+fn treasury_from_dto() {
+    let treasury_response = TreasuryResponse {
+        milestone_id: MILESTONE_ID.to_string(),
+        amount: "1000000".to_string(),
+    };
+    let treasury = Treasury::try_from_dto(&treasury_response, protocol_parameters().token_supply()).unwrap();
+
+    assert_eq!(treasury.milestone_id.to_string(), MILESTONE_ID);
+    assert_eq!(treasury.output.amount(), 1_000_000);
+    assert_eq!(TreasuryResponse::from(&treasury), treasury_response);
+}
+
+#[test]
+fn treasury_from_dto_invalid_amount() {
+    let treasury_response = TreasuryResponse {
+        milestone_id: MILESTONE_ID.to_string(),
+        amount: "-1".to_string(),
+    };
+
+    assert!(matches!(
+        Treasury::try_from_dto(&treasury_response, protocol_parameters().token_supply()),
+        Err(Error::InvalidField("amount"))
+    ));
+}
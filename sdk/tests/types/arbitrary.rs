@@ -0,0 +1,187 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use arbitrary::{Arbitrary, Unstructured};
+use iota_sdk::types::block::{
+    output::{
+        dto::OutputDto,
+        feature::{dto::FeatureDto, Feature},
+        unlock_condition::{dto::UnlockConditionDto, UnlockCondition},
+        Output, OutputId,
+    },
+    packed::{PackedBlock, PackedTransactionPayload},
+    payload::{dto::TransactionPayloadDto, transaction::TransactionEssence, TransactionPayload},
+    protocol::ProtocolParameters,
+    rand::parents::rand_parents,
+    Block,
+};
+use packable::PackableExt;
+
+const SEEDS: u64 = 64;
+
+// Returns deterministic pseudo random data for a seed, generated with SplitMix64.
+fn data(seed: u64, len: usize) -> Vec<u8> {
+    let mut state = seed;
+
+    (0..len / 8)
+        .flat_map(|_| {
+            state = state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+            let mut z = state;
+            z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+            z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+            (z ^ (z >> 31)).to_le_bytes()
+        })
+        .collect()
+}
+
+// Generates a value for every seed, values that aren't accepted by the builders are skipped.
+fn generate<T: for<'a> Arbitrary<'a>>(len: usize) -> Vec<T> {
+    let values = (0..SEEDS)
+        .filter_map(|seed| T::arbitrary(&mut Unstructured::new(&data(seed, len))).ok())
+        .collect::<Vec<_>>();
+
+    assert!(!values.is_empty());
+
+    values
+}
+
+#[test]
+fn generation_is_deterministic() {
+    assert_eq!(generate::<Output>(4096), generate::<Output>(4096));
+    assert_eq!(
+        generate::<TransactionPayload>(65536),
+        generate::<TransactionPayload>(65536)
+    );
+}
+
+#[test]
+fn unlock_conditions_round_trip() {
+    let protocol_parameters = ProtocolParameters::default();
+
+    for unlock_condition in generate::<UnlockCondition>(256) {
+        assert_eq!(
+            UnlockCondition::unpack_verified(unlock_condition.pack_to_vec().as_slice(), &protocol_parameters).unwrap(),
+            unlock_condition
+        );
+
+        let json = serde_json::to_string(&UnlockConditionDto::from(&unlock_condition)).unwrap();
+        let dto = serde_json::from_str::<UnlockConditionDto>(&json).unwrap();
+        assert_eq!(
+            UnlockCondition::try_from_dto(&dto, protocol_parameters.token_supply()).unwrap(),
+            unlock_condition
+        );
+    }
+}
+
+#[test]
+fn features_round_trip() {
+    for feature in generate::<Feature>(16384) {
+        assert_eq!(
+            Feature::unpack_verified(feature.pack_to_vec().as_slice(), &()).unwrap(),
+            feature
+        );
+
+        let json = serde_json::to_string(&FeatureDto::from(&feature)).unwrap();
+        let dto = serde_json::from_str::<FeatureDto>(&json).unwrap();
+        assert_eq!(Feature::try_from(&dto).unwrap(), feature);
+    }
+}
+
+#[test]
+fn outputs_round_trip() {
+    let protocol_parameters = ProtocolParameters::default();
+
+    for output in generate::<Output>(65536) {
+        assert_eq!(
+            Output::unpack_verified(output.pack_to_vec().as_slice(), &protocol_parameters).unwrap(),
+            output
+        );
+
+        let json = serde_json::to_string(&OutputDto::from(&output)).unwrap();
+        let dto = serde_json::from_str::<OutputDto>(&json).unwrap();
+        assert_eq!(
+            Output::try_from_dto(&dto, protocol_parameters.token_supply()).unwrap(),
+            output
+        );
+    }
+}
+
+#[test]
+fn transaction_payloads_round_trip() {
+    let protocol_parameters = ProtocolParameters::default();
+
+    for transaction_payload in generate::<TransactionPayload>(262144) {
+        assert_eq!(
+            TransactionPayload::unpack_verified(transaction_payload.pack_to_vec().as_slice(), &protocol_parameters)
+                .unwrap(),
+            transaction_payload
+        );
+
+        let json = serde_json::to_string(&TransactionPayloadDto::from(&transaction_payload)).unwrap();
+        let dto = serde_json::from_str::<TransactionPayloadDto>(&json).unwrap();
+        assert_eq!(
+            TransactionPayload::try_from_dto(&dto, &protocol_parameters).unwrap(),
+            transaction_payload
+        );
+    }
+}
+
+#[test]
+fn packed_transaction_payloads() {
+    let protocol_parameters = ProtocolParameters::default();
+
+    for transaction_payload in generate::<TransactionPayload>(262144) {
+        let bytes = transaction_payload.pack_to_vec();
+        let packed = PackedTransactionPayload::new(&bytes, &protocol_parameters).unwrap();
+        let TransactionEssence::Regular(essence) = transaction_payload.essence();
+
+        assert_eq!(packed.as_bytes(), bytes.as_slice());
+        assert_eq!(packed.id(), transaction_payload.id());
+        assert_eq!(packed.network_id(), essence.network_id());
+        assert_eq!(packed.inputs().collect::<Vec<_>>(), essence.inputs());
+        assert_eq!(packed.outputs(&protocol_parameters).len(), essence.outputs().len());
+
+        for (index, ((output_id, packed_output), output)) in
+            packed.outputs(&protocol_parameters).zip(essence.outputs()).enumerate()
+        {
+            assert_eq!(
+                output_id,
+                OutputId::new(transaction_payload.id(), index as u16).unwrap()
+            );
+            assert_eq!(packed_output.kind(), output.kind());
+            assert_eq!(packed_output.amount(), output.amount());
+            assert_eq!(packed_output.as_bytes(), output.pack_to_vec().as_slice());
+            assert_eq!(&packed_output.unpack(&protocol_parameters).unwrap(), output);
+        }
+
+        assert_eq!(packed.unpack(&protocol_parameters).unwrap(), transaction_payload);
+
+        // Truncated payloads are rejected.
+        assert!(PackedTransactionPayload::new(&bytes[..bytes.len() - 1], &protocol_parameters).is_err());
+    }
+}
+
+#[test]
+fn packed_blocks() {
+    let protocol_parameters = ProtocolParameters::default();
+
+    for transaction_payload in generate::<TransactionPayload>(65536) {
+        // Payloads that don't fit in a block are skipped.
+        let Ok(block) = Block::build(rand_parents())
+            .with_payload(transaction_payload.clone())
+            .with_nonce(42)
+            .finish()
+        else {
+            continue;
+        };
+        let bytes = block.pack_to_vec();
+        let packed = PackedBlock::new(&bytes, &protocol_parameters).unwrap();
+
+        assert_eq!(packed.id(), block.id());
+        assert_eq!(packed.parents().collect::<Vec<_>>(), block.parents().to_vec());
+        assert_eq!(packed.payload_kind(), Some(TransactionPayload::KIND));
+        assert_eq!(packed.transaction_payload().unwrap().id(), transaction_payload.id());
+        assert_eq!(packed.nonce(), 42);
+        assert_eq!(packed.unpack(&protocol_parameters).unwrap(), block);
+    }
+}
@@ -5,14 +5,21 @@ use iota_sdk::{
     pow::{miner::get_miner, score::PowScorer},
     types::block::{
         parent::Parents,
-        payload::{Payload, TaggedDataPayload},
-        protocol::protocol_parameters,
+        payload::{
+            milestone::{MilestoneEssence, MilestoneOption, MilestoneOptions, MilestonePayload},
+            Payload, TaggedDataPayload,
+        },
+        protocol::{protocol_parameters, ProtocolFeature, ProtocolParameters},
         rand::{
             block::rand_block_ids,
+            bytes::{rand_bytes, rand_bytes_array},
+            milestone::{rand_merkle_root, rand_milestone_id, rand_milestone_index},
+            milestone_option::rand_receipt_milestone_option,
             number::rand_number,
             parents::rand_parents,
             payload::{rand_tagged_data_payload, rand_treasury_transaction_payload},
         },
+        signature::{Ed25519Signature, Signature},
         Block, BlockBuilder, Error,
     },
 };
@@ -48,6 +55,19 @@ fn invalid_length() {
     assert!(matches!(res, Err(Error::InvalidBlockLength(len)) if len == Block::LENGTH_MAX + 33));
 }
 
+#[test]
+fn packed_len() {
+    let payload = Payload::from(rand_tagged_data_payload());
+    let block = BlockBuilder::new(rand_parents())
+        .with_payload(payload.clone())
+        .finish()
+        .unwrap();
+
+    assert_eq!(block.packed_len(), block.pack_to_vec().len());
+    assert_eq!(payload.packed_len(), payload.pack_to_vec().len());
+    assert!(block.packed_len() > payload.packed_len());
+}
+
 #[test]
 fn invalid_payload_kind() {
     let protocol_parameters = protocol_parameters();
@@ -124,3 +144,125 @@ fn getters() {
     assert_eq!(*block.payload().as_ref().unwrap(), &payload);
     assert_eq!(block.nonce(), nonce);
 }
+
+fn protocol_parameters_with_version(protocol_version: u8) -> ProtocolParameters {
+    let protocol_parameters = protocol_parameters();
+
+    ProtocolParameters::new(
+        protocol_version,
+        protocol_parameters.network_name().to_string(),
+        protocol_parameters.bech32_hrp().to_string(),
+        protocol_parameters.min_pow_score(),
+        protocol_parameters.below_max_depth(),
+        *protocol_parameters.rent_structure(),
+        protocol_parameters.token_supply(),
+    )
+    .unwrap()
+}
+
+fn milestone_payload_with_receipt(protocol_version: u8) -> MilestonePayload {
+    let receipt = rand_receipt_milestone_option(protocol_parameters().token_supply());
+    let essence = MilestoneEssence::new(
+        rand_milestone_index(),
+        rand_number(),
+        protocol_version,
+        rand_milestone_id(),
+        rand_parents(),
+        rand_merkle_root(),
+        rand_merkle_root(),
+        rand_bytes(32),
+        MilestoneOptions::from_vec(vec![MilestoneOption::from(receipt)]).unwrap(),
+    )
+    .unwrap();
+    let signatures = vec![Signature::from(Ed25519Signature::new(
+        rand_bytes_array(),
+        rand_bytes_array(),
+    ))];
+
+    MilestonePayload::new(essence, signatures).unwrap()
+}
+
+#[test]
+fn pack_unpack_protocol_versions() {
+    for protocol_version in [2, 3] {
+        let protocol_parameters = protocol_parameters_with_version(protocol_version);
+        let block = BlockBuilder::new(rand_parents())
+            .with_protocol_parameters(&protocol_parameters)
+            .with_payload(rand_tagged_data_payload())
+            .finish()
+            .unwrap();
+
+        assert_eq!(block.protocol_version(), protocol_version);
+        assert_eq!(
+            block,
+            PackableExt::unpack_verified(block.pack_to_vec().as_slice(), &protocol_parameters).unwrap()
+        );
+    }
+
+    let block = BlockBuilder::new(rand_parents()).with_protocol_version(3).finish().unwrap();
+
+    assert!(matches!(
+        Block::unpack_verified(block.pack_to_vec().as_slice(), &protocol_parameters()),
+        Err(UnpackError::Packable(Error::ProtocolVersionMismatch {
+            expected: 2,
+            actual: 3
+        }))
+    ));
+}
+
+#[test]
+fn unsupported_protocol_version() {
+    assert!(matches!(
+        BlockBuilder::new(rand_parents()).with_protocol_version(1).finish(),
+        Err(Error::UnsupportedProtocolVersion(1))
+    ));
+
+    let mut packed_block = BlockBuilder::new(rand_parents()).finish().unwrap().pack_to_vec();
+    packed_block[0] = 4;
+
+    assert!(matches!(
+        Block::unpack_verified(packed_block.as_slice(), &protocol_parameters_with_version(4)),
+        Err(UnpackError::Packable(Error::UnsupportedProtocolVersion(4)))
+    ));
+}
+
+#[test]
+fn unavailable_protocol_feature() {
+    assert!(protocol_parameters_with_version(2).is_available(ProtocolFeature::Treasury));
+    assert!(!protocol_parameters_with_version(3).is_available(ProtocolFeature::Treasury));
+
+    let block = BlockBuilder::new(rand_parents())
+        .with_payload(milestone_payload_with_receipt(2))
+        .finish()
+        .unwrap();
+
+    assert_eq!(
+        block,
+        PackableExt::unpack_verified(block.pack_to_vec().as_slice(), &protocol_parameters()).unwrap()
+    );
+
+    assert_eq!(
+        BlockBuilder::new(rand_parents())
+            .with_protocol_version(3)
+            .with_payload(milestone_payload_with_receipt(3))
+            .finish(),
+        Err(Error::UnavailableProtocolFeature {
+            feature: ProtocolFeature::Treasury,
+            protocol_version: 3
+        })
+    );
+
+    // Change the protocol version of the block and of the milestone essence
+    let mut packed_block = block.pack_to_vec();
+    packed_block[0] = 3;
+    let essence_protocol_version_index = 1 + block.parents().packed_len() + 4 + 4 + 4 + 4;
+    packed_block[essence_protocol_version_index] = 3;
+
+    assert!(matches!(
+        Block::unpack_verified(packed_block.as_slice(), &protocol_parameters_with_version(3)),
+        Err(UnpackError::Packable(Error::UnavailableProtocolFeature {
+            feature: ProtocolFeature::Treasury,
+            protocol_version: 3
+        }))
+    ));
+}
@@ -0,0 +1,39 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use iota_sdk::types::block::{
+    output::{InputsCommitment, Output},
+    protocol::protocol_parameters,
+    rand::output::rand_output,
+    Error,
+};
+
+#[test]
+fn new_from_output_hashes() {
+    let token_supply = protocol_parameters().token_supply();
+    let outputs = (0..3).map(|_| rand_output(token_supply)).collect::<Vec<Output>>();
+
+    assert_eq!(
+        InputsCommitment::new(outputs.iter()),
+        InputsCommitment::from_output_hashes(outputs.iter().map(InputsCommitment::output_hash))
+    );
+}
+
+#[test]
+fn verify() {
+    let token_supply = protocol_parameters().token_supply();
+    let outputs = (0..3).map(|_| rand_output(token_supply)).collect::<Vec<Output>>();
+    let inputs_commitment = InputsCommitment::new(outputs.iter());
+
+    assert!(inputs_commitment.verify(outputs.iter()).is_ok());
+
+    let reversed = InputsCommitment::new(outputs.iter().rev());
+
+    assert_eq!(
+        inputs_commitment.verify(outputs.iter().rev()),
+        Err(Error::InputsCommitmentMismatch {
+            expected: inputs_commitment,
+            actual: reversed,
+        })
+    );
+}
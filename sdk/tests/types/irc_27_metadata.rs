@@ -0,0 +1,83 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use iota_sdk::types::block::{
+    output::feature::{Irc27Metadata, MetadataFeature},
+    Error,
+};
+
+const ROYALTY_ADDRESS: &str = "rms1qr47gz3xxjqpjrwd0yu5glhqrth6w0t08npney8000ust2lcw2r92j5a8rt";
+
+#[test]
+fn decode() {
+    let json = format!(
+        r#"{{"standard":"IRC27","version":"v1.0","type":"image/png","uri":"ipfs://QmHash/nft.png","name":"NFT #1",
+        "collectionName":"Collection","royalties":{{"{ROYALTY_ADDRESS}":0.025}},
+        "attributes":[{{"trait_type":"Background","value":"Blue"}}]}}"#
+    );
+    let metadata = Irc27Metadata::try_from(json.as_bytes()).unwrap();
+
+    assert_eq!(metadata.media_type, "image/png");
+    assert_eq!(metadata.name, "NFT #1");
+    assert_eq!(metadata.collection_name.as_deref(), Some("Collection"));
+    assert_eq!(metadata.royalties.get(ROYALTY_ADDRESS), Some(&0.025));
+    assert_eq!(metadata.attributes[0].trait_type, "Background");
+    assert_eq!(metadata.attributes[0].value, "Blue");
+    assert_eq!(metadata.issuer_name, None);
+}
+
+#[test]
+fn roundtrip() {
+    let mut metadata = Irc27Metadata::new("image/png", "https://example.com/nft.png", "NFT #1");
+    metadata.royalties.insert(ROYALTY_ADDRESS.to_string(), 0.5);
+
+    let feature = MetadataFeature::try_from(&metadata).unwrap();
+    assert_eq!(Irc27Metadata::try_from(&feature).unwrap(), metadata);
+}
+
+#[test]
+fn media_url() {
+    let metadata = Irc27Metadata::new("image/png", "ipfs://QmHash/nft.png", "NFT #1");
+    assert_eq!(metadata.media_url("https://ipfs.io/"), "https://ipfs.io/ipfs/QmHash/nft.png");
+
+    let metadata = Irc27Metadata::new("image/png", "ipfs://ipfs/QmHash", "NFT #1");
+    assert_eq!(metadata.media_url("https://ipfs.io"), "https://ipfs.io/ipfs/QmHash");
+
+    let metadata = Irc27Metadata::new("image/png", "https://example.com/nft.png", "NFT #1");
+    assert_eq!(metadata.media_url("https://ipfs.io"), "https://example.com/nft.png");
+}
+
+#[test]
+fn invalid() {
+    let valid = Irc27Metadata::new("image/png", "https://example.com/nft.png", "NFT #1");
+    assert!(valid.validate().is_ok());
+
+    let mut metadata = valid.clone();
+    metadata.version = "v2.0".to_string();
+    assert!(matches!(metadata.validate(), Err(Error::InvalidIrc27Metadata(_))));
+
+    let mut metadata = valid.clone();
+    metadata.media_type = "png".to_string();
+    assert!(matches!(metadata.validate(), Err(Error::InvalidIrc27Metadata(_))));
+
+    let mut metadata = valid.clone();
+    metadata.uri = String::new();
+    assert!(matches!(metadata.validate(), Err(Error::InvalidIrc27Metadata(_))));
+
+    let mut metadata = valid.clone();
+    metadata.royalties.insert("invalid".to_string(), 0.1);
+    assert!(matches!(metadata.validate(), Err(Error::InvalidIrc27Metadata(_))));
+
+    let mut metadata = valid.clone();
+    metadata.royalties.insert(ROYALTY_ADDRESS.to_string(), 1.5);
+    assert!(matches!(metadata.validate(), Err(Error::InvalidIrc27Metadata(_))));
+
+    let mut metadata = valid;
+    metadata.standard = "IRC30".to_string();
+    assert!(matches!(metadata.to_bytes(), Err(Error::InvalidIrc27Metadata(_))));
+
+    assert!(matches!(
+        Irc27Metadata::try_from(br#"{"standard":"IRC27","version":"v1.0","name":"NFT #1"}"#.as_slice()),
+        Err(Error::InvalidIrc27Metadata(_))
+    ));
+}
@@ -0,0 +1,95 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use iota_sdk::types::block::{
+    output::feature::{Irc30Metadata, MetadataFeature},
+    Error,
+};
+use primitive_types::U256;
+
+#[test]
+fn decode() {
+    let json = r#"{"standard":"IRC30","name":"Test Token","symbol":"TST","decimals":6,"url":"https://example.com"}"#;
+    let metadata = Irc30Metadata::try_from(json.as_bytes()).unwrap();
+
+    assert_eq!(metadata.name, "Test Token");
+    assert_eq!(metadata.symbol, "TST");
+    assert_eq!(metadata.decimals, 6);
+    assert_eq!(metadata.url.as_deref(), Some("https://example.com"));
+    assert_eq!(metadata.description, None);
+    assert_eq!(metadata.logo, None);
+}
+
+#[test]
+fn roundtrip() {
+    let mut metadata = Irc30Metadata::new("Shimmer Token", "TST", 6);
+    metadata.logo_url = Some("https://example.com/logo.png".to_string());
+
+    let feature = MetadataFeature::try_from(&metadata).unwrap();
+    assert_eq!(Irc30Metadata::try_from(&feature).unwrap(), metadata);
+    // Unset fields aren't encoded
+    assert!(!String::from_utf8(feature.data().to_vec()).unwrap().contains("description"));
+}
+
+#[test]
+fn invalid() {
+    assert!(matches!(
+        Irc30Metadata::try_from(br#"{"standard":"IRC27","name":"Token","symbol":"TST","decimals":0}"#.as_slice()),
+        Err(Error::InvalidIrc30Metadata(_))
+    ));
+    assert!(matches!(
+        Irc30Metadata::try_from(br#"{"standard":"IRC30","name":"Token","symbol":" ","decimals":0}"#.as_slice()),
+        Err(Error::InvalidIrc30Metadata(_))
+    ));
+    assert!(matches!(
+        Irc30Metadata::try_from(br#"{"standard":"IRC30","name":"Token"}"#.as_slice()),
+        Err(Error::InvalidIrc30Metadata(_))
+    ));
+    assert!(matches!(
+        Irc30Metadata::try_from(b"not json".as_slice()),
+        Err(Error::InvalidIrc30Metadata(_))
+    ));
+    assert!(Irc30Metadata::new("", "TST", 0).to_bytes().is_err());
+}
+
+#[test]
+fn format_and_parse_amount() {
+    let metadata = Irc30Metadata::new("Test Token", "TST", 6);
+
+    assert_eq!(metadata.format_amount(U256::from(1_234_500)).unwrap(), "1.2345");
+    assert_eq!(metadata.format_amount(U256::from(1_000_000)).unwrap(), "1");
+    assert_eq!(metadata.format_amount(U256::from(42)).unwrap(), "0.000042");
+    assert_eq!(metadata.format_amount(U256::zero()).unwrap(), "0");
+
+    assert_eq!(metadata.parse_amount("1.2345").unwrap(), U256::from(1_234_500));
+    assert_eq!(metadata.parse_amount("1").unwrap(), U256::from(1_000_000));
+    assert_eq!(metadata.parse_amount(".000042").unwrap(), U256::from(42));
+    assert_eq!(
+        metadata.parse_amount(&metadata.format_amount(U256::MAX).unwrap()).unwrap(),
+        U256::MAX
+    );
+
+    let no_decimals = Irc30Metadata::new("Test Token", "TST", 0);
+    assert_eq!(no_decimals.format_amount(U256::from(123)).unwrap(), "123");
+    assert_eq!(no_decimals.parse_amount("123").unwrap(), U256::from(123));
+}
+
+#[test]
+fn parse_invalid_amount() {
+    let metadata = Irc30Metadata::new("Test Token", "TST", 6);
+
+    for amount in ["", ".", "1.2.3", "-1", "1,5", " 1", "1.0000001"] {
+        assert!(
+            matches!(metadata.parse_amount(amount), Err(Error::InvalidTokenAmount(_))),
+            "{amount}"
+        );
+    }
+    assert!(matches!(
+        metadata.parse_amount(&U256::MAX.to_string()),
+        Err(Error::InvalidTokenAmount(_))
+    ));
+    assert!(matches!(
+        Irc30Metadata::new("Test Token", "TST", 100).format_amount(U256::one()),
+        Err(Error::InvalidTokenAmount(_))
+    ));
+}
@@ -140,3 +140,63 @@ fn pack_unpack_valid() {
         milestone_payload,
     );
 }
+
+#[test]
+fn builder() {
+    let protocol_parameters = protocol_parameters();
+    let index = rand_milestone_index();
+    let previous_milestone_id = rand_milestone_id();
+    let parents = rand_parents();
+    let inclusion_merkle_root = rand_merkle_root();
+    let applied_merkle_root = rand_merkle_root();
+    let parameters = ParametersMilestoneOption::new(
+        rand_milestone_index(),
+        protocol_parameters.protocol_version(),
+        rand_bytes(rand_number_range(ParametersMilestoneOption::BINARY_PARAMETERS_LENGTH_RANGE) as usize),
+    )
+    .unwrap();
+
+    let built = MilestoneEssence::build(index, 1_680_000_000, parents.clone())
+        .with_protocol_version(protocol_parameters.protocol_version())
+        .with_previous_milestone_id(previous_milestone_id)
+        .with_inclusion_merkle_root(inclusion_merkle_root)
+        .with_applied_merkle_root(applied_merkle_root)
+        .with_metadata(b"metadata".to_vec())
+        .add_option(MilestoneOption::Parameters(parameters.clone()))
+        .finish()
+        .unwrap();
+
+    let essence = MilestoneEssence::new(
+        index,
+        1_680_000_000,
+        protocol_parameters.protocol_version(),
+        previous_milestone_id,
+        parents.clone(),
+        inclusion_merkle_root,
+        applied_merkle_root,
+        b"metadata".to_vec(),
+        MilestoneOptions::from_vec(vec![MilestoneOption::Parameters(parameters)]).unwrap(),
+    )
+    .unwrap();
+
+    assert_eq!(built, essence);
+
+    // Defaults
+    let built = MilestoneEssence::build(MilestoneIndex(0), 0, parents).finish().unwrap();
+    assert_eq!(built.protocol_version(), protocol_parameters.protocol_version());
+    assert!(built.metadata().is_empty());
+    assert!(built.options().is_empty());
+
+    // Duplicated options are invalid
+    assert!(
+        MilestoneEssence::build(MilestoneIndex(0), 0, rand_parents())
+            .add_option(MilestoneOption::Parameters(
+                ParametersMilestoneOption::new(MilestoneIndex(1), 2, vec![]).unwrap()
+            ))
+            .add_option(MilestoneOption::Parameters(
+                ParametersMilestoneOption::new(MilestoneIndex(2), 2, vec![]).unwrap()
+            ))
+            .finish()
+            .is_err()
+    );
+}
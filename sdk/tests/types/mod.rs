@@ -3,30 +3,42 @@
 
 mod address;
 mod api;
+#[cfg(feature = "arbitrary")]
+mod arbitrary;
 #[cfg(feature = "pow")]
 mod block;
 mod block_id;
 mod ed25519_signature;
 mod foundry_id;
 mod input;
+mod inputs_commitment;
+mod irc_27_metadata;
+mod irc_30_metadata;
 mod migrated_funds_entry;
 mod milestone_id;
 mod milestone_index;
 mod milestone_payload;
 mod milestone_payload_essence;
 mod output_id;
+mod parameters_milestone_option;
 mod parents;
 mod payload;
+mod protocol_parameters;
 mod receipt_milestone_option;
 mod reference_unlock;
 mod rent;
 mod signature_unlock;
+mod sorting;
+mod state_log;
 mod tagged_data_payload;
 mod tail_transaction_hash;
+mod token_scheme;
 mod transaction_essence;
 mod transaction_id;
 mod transaction_payload;
 mod transaction_regular_essence;
 mod treasury_output;
 mod treasury_transaction_payload;
+#[cfg(feature = "unknown_payloads")]
+mod unknown_payload;
 mod unlocks;
@@ -0,0 +1,40 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use iota_sdk::types::block::{
+    payload::milestone::{MilestoneIndex, ParametersMilestoneOption},
+    protocol::protocol_parameters,
+    Error,
+};
+use packable::PackableExt;
+
+#[test]
+fn kind() {
+    assert_eq!(ParametersMilestoneOption::KIND, 1);
+}
+
+#[test]
+fn protocol_parameters_valid() {
+    let protocol_parameters = protocol_parameters();
+    let parameters_milestone_option = ParametersMilestoneOption::new(
+        MilestoneIndex::new(1000),
+        protocol_parameters.protocol_version(),
+        protocol_parameters.pack_to_vec(),
+    )
+    .unwrap();
+
+    assert_eq!(
+        parameters_milestone_option.protocol_parameters().unwrap(),
+        protocol_parameters
+    );
+}
+
+#[test]
+fn protocol_parameters_invalid() {
+    let parameters_milestone_option = ParametersMilestoneOption::new(MilestoneIndex::new(1000), 2, vec![2, 0]).unwrap();
+
+    assert!(matches!(
+        parameters_milestone_option.protocol_parameters(),
+        Err(Error::InvalidField("params"))
+    ));
+}
@@ -0,0 +1,46 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use iota_sdk::types::block::{
+    output::RentStructure,
+    protocol::{ProtocolParameters, KNOWN_NETWORK_NAMES},
+};
+
+#[test]
+fn presets() {
+    let mainnet = ProtocolParameters::mainnet();
+    assert_eq!(mainnet.network_name(), "iota-mainnet");
+    assert_eq!(mainnet.bech32_hrp(), "iota");
+    assert_eq!(mainnet.rent_structure(), &RentStructure::new(250, 10, 1));
+    assert_eq!(mainnet.token_supply(), 4_600_000_000_000_000);
+
+    let shimmer = ProtocolParameters::shimmer();
+    assert_eq!(shimmer.network_name(), "shimmer");
+    assert_eq!(shimmer.bech32_hrp(), "smr");
+    assert_eq!(shimmer, ProtocolParameters::default());
+
+    let testnet = ProtocolParameters::testnet();
+    assert_eq!(testnet.network_name(), "testnet");
+    assert_eq!(testnet.bech32_hrp(), "rms");
+
+    for preset in [mainnet, shimmer, testnet] {
+        assert!(preset.is_supported_version());
+        assert_eq!(
+            ProtocolParameters::from_network_name(preset.network_name()),
+            Some(preset)
+        );
+    }
+}
+
+#[test]
+fn known_network_names() {
+    for network_name in KNOWN_NETWORK_NAMES {
+        assert_eq!(
+            ProtocolParameters::from_network_name(network_name)
+                .unwrap()
+                .network_name(),
+            network_name
+        );
+    }
+    assert_eq!(ProtocolParameters::from_network_name("private-tangle"), None);
+}
@@ -2,9 +2,12 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use iota_sdk::types::block::{
-    output::{Output, Rent, RentStructure},
+    output::{unlock_condition::AddressUnlockCondition, BasicOutputBuilder, Output, RentStructure},
     protocol::protocol_parameters,
-    rand::output::{rand_alias_output, rand_basic_output, rand_foundry_output, rand_nft_output},
+    rand::{
+        address::rand_address,
+        output::{rand_alias_output, rand_basic_output, rand_foundry_output, rand_nft_output},
+    },
 };
 
 const BYTE_COST: u32 = 1;
@@ -29,3 +32,21 @@ fn valid_rent_cost_range() {
     output_in_range(Output::Foundry(rand_foundry_output(token_supply)), 496..=21_365);
     output_in_range(Output::Nft(rand_nft_output(token_supply)), 435..=21_734);
 }
+
+#[test]
+fn minimum_storage_deposit() {
+    let protocol_parameters = protocol_parameters();
+    let rent_structure = *protocol_parameters.rent_structure();
+
+    let output = Output::Basic(rand_basic_output(protocol_parameters.token_supply()));
+    assert_eq!(
+        rent_structure.minimum_storage_deposit(&output),
+        output.rent_cost(&rent_structure)
+    );
+
+    let output = BasicOutputBuilder::new_with_minimum_storage_deposit(rent_structure)
+        .add_unlock_condition(AddressUnlockCondition::new(rand_address()))
+        .finish_output(protocol_parameters.token_supply())
+        .unwrap();
+    assert_eq!(output.amount(), rent_structure.minimum_storage_deposit(&output));
+}
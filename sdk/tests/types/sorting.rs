@@ -0,0 +1,61 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use core::str::FromStr;
+use std::collections::BTreeSet;
+
+use iota_sdk::types::block::{
+    helper::{is_sorted_lexically, sort_lexically},
+    input::{Input, UtxoInput},
+    output::OutputId,
+    payload::transaction::TransactionId,
+};
+
+const TRANSACTION_ID_1: &str = "0x52fdfc072182654f163f5f0f9a621d729566c74d10037c4d7bbb0407d1e2c649";
+const TRANSACTION_ID_2: &str = "0xff6477b2369f6e5b8bbd8f50d4b2a0aa5fe1b0d3c11a0b93a263a7e0a2bb3b70";
+
+#[test]
+fn output_ids_in_btree_set() {
+    let transaction_id_1 = TransactionId::from_str(TRANSACTION_ID_1).unwrap();
+    let transaction_id_2 = TransactionId::from_str(TRANSACTION_ID_2).unwrap();
+
+    let output_ids = BTreeSet::from([
+        OutputId::new(transaction_id_2, 0).unwrap(),
+        OutputId::new(transaction_id_1, 1).unwrap(),
+        OutputId::new(transaction_id_1, 0).unwrap(),
+    ]);
+
+    assert_eq!(
+        output_ids.into_iter().collect::<Vec<_>>(),
+        vec![
+            OutputId::new(transaction_id_1, 0).unwrap(),
+            OutputId::new(transaction_id_1, 1).unwrap(),
+            OutputId::new(transaction_id_2, 0).unwrap(),
+        ]
+    );
+}
+
+#[test]
+fn inputs_sorted_lexically() {
+    let transaction_id_1 = TransactionId::from_str(TRANSACTION_ID_1).unwrap();
+    let transaction_id_2 = TransactionId::from_str(TRANSACTION_ID_2).unwrap();
+
+    let mut inputs = vec![
+        Input::Utxo(UtxoInput::new(transaction_id_2, 0).unwrap()),
+        Input::Utxo(UtxoInput::new(transaction_id_1, 1).unwrap()),
+        Input::Utxo(UtxoInput::new(transaction_id_1, 0).unwrap()),
+    ];
+    assert!(!is_sorted_lexically(&inputs));
+
+    sort_lexically(&mut inputs);
+
+    assert!(is_sorted_lexically(&inputs));
+    assert_eq!(
+        inputs,
+        vec![
+            Input::Utxo(UtxoInput::new(transaction_id_1, 0).unwrap()),
+            Input::Utxo(UtxoInput::new(transaction_id_1, 1).unwrap()),
+            Input::Utxo(UtxoInput::new(transaction_id_2, 0).unwrap()),
+        ]
+    );
+}
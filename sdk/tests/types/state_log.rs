@@ -0,0 +1,58 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use iota_sdk::types::block::{
+    output::feature::{MetadataFeature, StateLog},
+    Error,
+};
+use serde_json::json;
+
+#[test]
+fn append() {
+    let mut log = StateLog::new();
+    log.append(json!({ "location": "factory" }), 1_000).unwrap();
+    log.append(json!({ "location": "warehouse" }), 2_000).unwrap();
+
+    assert_eq!(log.updates.len(), 2);
+    assert_eq!(log.updates[0].previous_hash, None);
+    assert_eq!(
+        log.updates[1].previous_hash,
+        Some(prefix_hex::encode(log.updates[0].hash().unwrap()))
+    );
+    assert_eq!(log.latest().unwrap().state, json!({ "location": "warehouse" }));
+    // Updates can't be older than the latest one
+    assert!(matches!(
+        log.append(json!({ "location": "store" }), 1_500),
+        Err(Error::InvalidStateLog(_))
+    ));
+}
+
+#[test]
+fn roundtrip() {
+    let mut log = StateLog::new();
+    log.append(json!({ "custodian": "carrier" }), 1_000).unwrap();
+    log.append(json!({ "custodian": "retailer" }), 1_000).unwrap();
+
+    let feature = MetadataFeature::try_from(&log).unwrap();
+    assert_eq!(StateLog::try_from(&feature).unwrap(), log);
+}
+
+#[test]
+fn tampered() {
+    let mut log = StateLog::new();
+    log.append(json!({ "location": "factory" }), 1_000).unwrap();
+    log.append(json!({ "location": "warehouse" }), 2_000).unwrap();
+
+    let mut changed_state = log.clone();
+    changed_state.updates[0].state = json!({ "location": "elsewhere" });
+    assert!(matches!(changed_state.validate(), Err(Error::InvalidStateLog(_))));
+
+    let mut removed_update = log.clone();
+    removed_update.updates.remove(0);
+    assert!(matches!(removed_update.validate(), Err(Error::InvalidStateLog(_))));
+
+    assert!(matches!(
+        StateLog::try_from(br#"{"standard":"IRC27","updates":[]}"#.as_slice()),
+        Err(Error::InvalidStateLog(_))
+    ));
+}
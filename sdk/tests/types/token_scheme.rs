@@ -0,0 +1,39 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use iota_sdk::types::block::{output::SimpleTokenScheme, Error};
+use primitive_types::U256;
+
+#[test]
+fn mint_and_melt() {
+    let token_scheme = SimpleTokenScheme::new(U256::from(50), U256::from(10), U256::from(100)).unwrap();
+    assert_eq!(token_scheme.circulating_supply(), U256::from(40));
+    assert_eq!(token_scheme.mintable_supply(), U256::from(60));
+
+    let minted = token_scheme.mint(U256::from(60)).unwrap();
+    assert_eq!(minted.minted_tokens(), U256::from(110));
+    assert_eq!(minted.melted_tokens(), U256::from(10));
+    assert_eq!(minted.circulating_supply(), minted.maximum_supply());
+
+    let melted = minted.melt(U256::from(100)).unwrap();
+    assert_eq!(melted.melted_tokens(), U256::from(110));
+    assert!(melted.circulating_supply().is_zero());
+    // The original scheme is unchanged
+    assert_eq!(token_scheme.minted_tokens(), U256::from(50));
+}
+
+#[test]
+fn mint_and_melt_invalid() {
+    let token_scheme = SimpleTokenScheme::new(U256::from(50), U256::from(10), U256::from(100)).unwrap();
+
+    assert!(matches!(
+        token_scheme.mint(U256::from(61)),
+        Err(Error::InvalidFoundryOutputSupply { .. })
+    ));
+    assert!(matches!(
+        token_scheme.melt(U256::from(41)),
+        Err(Error::InvalidFoundryOutputSupply { .. })
+    ));
+    assert_eq!(token_scheme.mint(U256::MAX), Err(Error::TokenSupplyOverflow));
+    assert_eq!(token_scheme.melt(U256::MAX), Err(Error::TokenSupplyOverflow));
+}
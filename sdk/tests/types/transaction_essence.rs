@@ -4,8 +4,11 @@
 use iota_sdk::types::block::{
     address::{Address, Ed25519Address},
     input::{Input, UtxoInput},
-    output::{unlock_condition::AddressUnlockCondition, BasicOutput, Output},
-    payload::transaction::{RegularTransactionEssence, TransactionEssence, TransactionId},
+    output::{
+        unlock_condition::{AddressUnlockCondition, TimelockUnlockCondition},
+        BasicOutput, Output,
+    },
+    payload::transaction::{RegularTransactionEssence, TransactionEssence, TransactionId, UnlockConditionDescription},
     protocol::protocol_parameters,
     rand::output::rand_inputs_commitment,
     Error,
@@ -48,3 +51,111 @@ fn essence_unpack_invalid_kind() {
         Err(UnpackError::Packable(Error::InvalidEssenceKind(2)))
     ));
 }
+
+#[test]
+fn essence_describe() {
+    let protocol_parameters = protocol_parameters();
+    let transaction_id = TransactionId::new(prefix_hex::decode(TRANSACTION_ID).unwrap());
+    let input = Input::Utxo(UtxoInput::new(transaction_id, 0).unwrap());
+    let bytes: [u8; 32] = prefix_hex::decode(ED25519_ADDRESS).unwrap();
+    let address = Address::from(Ed25519Address::new(bytes));
+    let output = Output::Basic(
+        BasicOutput::build_with_amount(1_000_000)
+            .add_unlock_condition(AddressUnlockCondition::new(address))
+            .add_unlock_condition(TimelockUnlockCondition::new(1_700_000_000).unwrap())
+            .finish(protocol_parameters.token_supply())
+            .unwrap(),
+    );
+    let essence = TransactionEssence::Regular(
+        RegularTransactionEssence::builder(protocol_parameters.network_id(), rand_inputs_commitment())
+            .add_input(input.clone())
+            .add_output(output)
+            .finish(&protocol_parameters)
+            .unwrap(),
+    );
+
+    let description = essence.describe(&protocol_parameters);
+    let bech32_address = address.to_bech32("rms");
+
+    assert_eq!(description.network_id, protocol_parameters.network_id());
+    assert_eq!(description.inputs, vec![input]);
+    assert_eq!(description.outputs.len(), 1);
+    assert_eq!(description.outputs[0].kind, "basic");
+    assert_eq!(description.outputs[0].amount, 1_000_000);
+    assert_eq!(
+        description.outputs[0].address.as_ref().map(ToString::to_string),
+        Some(bech32_address.clone())
+    );
+    assert_eq!(
+        description.outputs[0].unlock_conditions[1],
+        UnlockConditionDescription::Timelock {
+            timestamp: 1_700_000_000
+        }
+    );
+    assert_eq!(
+        description.to_string(),
+        format!(
+            "Inputs:\n- output {TRANSACTION_ID}0000\nOutputs:\n- basic output of 1000000 to {bech32_address}, unlockable \
+             by {bech32_address}, locked until unix timestamp 1700000000\n"
+        )
+    );
+}
+
+#[test]
+fn essence_diff() {
+    let protocol_parameters = protocol_parameters();
+    let transaction_id = TransactionId::new(prefix_hex::decode(TRANSACTION_ID).unwrap());
+    let input1 = Input::Utxo(UtxoInput::new(transaction_id, 0).unwrap());
+    let input2 = Input::Utxo(UtxoInput::new(transaction_id, 1).unwrap());
+    let bytes: [u8; 32] = prefix_hex::decode(ED25519_ADDRESS).unwrap();
+    let address = Address::from(Ed25519Address::new(bytes));
+    let output = |amount| {
+        Output::Basic(
+            BasicOutput::build_with_amount(amount)
+                .add_unlock_condition(AddressUnlockCondition::new(address))
+                .finish(protocol_parameters.token_supply())
+                .unwrap(),
+        )
+    };
+    let essence = TransactionEssence::Regular(
+        RegularTransactionEssence::builder(protocol_parameters.network_id(), rand_inputs_commitment())
+            .add_input(input1.clone())
+            .add_output(output(1_000_000))
+            .finish(&protocol_parameters)
+            .unwrap(),
+    );
+    let other_essence = TransactionEssence::Regular(
+        RegularTransactionEssence::builder(protocol_parameters.network_id(), rand_inputs_commitment())
+            .add_input(input2.clone())
+            .with_outputs(vec![output(2_000_000), output(1_000_000)])
+            .finish(&protocol_parameters)
+            .unwrap(),
+    );
+
+    assert!(essence.diff(&essence, &protocol_parameters).is_empty());
+    assert_eq!(essence.diff(&essence, &protocol_parameters).to_string(), "No differences\n");
+
+    let diff = essence.diff(&other_essence, &protocol_parameters);
+    let bech32_address = address.to_bech32("rms");
+
+    assert!(!diff.is_empty());
+    assert_eq!(diff.network_id, None);
+    assert_eq!(diff.removed_inputs, vec![input1]);
+    assert_eq!(diff.added_inputs, vec![input2]);
+    assert_eq!(diff.changed_outputs.len(), 1);
+    assert_eq!(diff.changed_outputs[0].index, 0);
+    assert!(diff.changed_outputs[0].removed_unlock_conditions.is_empty());
+    assert!(diff.changed_outputs[0].added_unlock_conditions.is_empty());
+    assert!(!diff.changed_outputs[0].other_fields_changed);
+    assert!(diff.removed_outputs.is_empty());
+    assert_eq!(diff.added_outputs.len(), 1);
+    assert_eq!(diff.added_outputs[0].amount, 1_000_000);
+    assert!(!diff.payload_changed);
+    assert_eq!(
+        diff.to_string(),
+        format!(
+            "- input {TRANSACTION_ID}0000 removed\n- input {TRANSACTION_ID}0100 added\n- output 0: amount changed from \
+             1000000 to 2000000\n- basic output of 1000000 to {bech32_address}, unlockable by {bech32_address} added\n"
+        )
+    );
+}
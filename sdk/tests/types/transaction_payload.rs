@@ -1,15 +1,23 @@
 // Copyright 2020-2021 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+use crypto::signatures::ed25519::SecretKey;
 use iota_sdk::types::block::{
-    address::{Address, Ed25519Address},
+    address::{Address, AliasAddress, Ed25519Address},
     input::{Input, UtxoInput},
-    output::{unlock_condition::AddressUnlockCondition, BasicOutput, Output},
-    payload::transaction::{RegularTransactionEssence, TransactionEssence, TransactionId, TransactionPayload},
+    output::{
+        unlock_condition::{
+            AddressUnlockCondition, GovernorAddressUnlockCondition, StateControllerAddressUnlockCondition,
+        },
+        AliasId, AliasOutput, BasicOutput, Output, OutputId,
+    },
+    payload::transaction::{
+        compute_unlocks, RegularTransactionEssence, TransactionEssence, TransactionId, TransactionPayload,
+    },
     protocol::protocol_parameters,
     rand::output::rand_inputs_commitment,
     signature::{Ed25519Signature, Signature},
-    unlock::{ReferenceUnlock, SignatureUnlock, Unlock, Unlocks},
+    unlock::{AliasUnlock, ReferenceUnlock, SignatureUnlock, Unlock, Unlocks},
     Error,
 };
 use packable::PackableExt;
@@ -181,3 +189,166 @@ fn getters() {
     assert_eq!(*tx_payload.essence(), essence);
     assert_eq!(*tx_payload.unlocks(), unlocks);
 }
+
+fn essence_with_inputs(count: u16) -> TransactionEssence {
+    let protocol_parameters = protocol_parameters();
+    let transaction_id = TransactionId::new(prefix_hex::decode(TRANSACTION_ID).unwrap());
+    let bytes: [u8; 32] = prefix_hex::decode(ED25519_ADDRESS).unwrap();
+    let address = Address::from(Ed25519Address::new(bytes));
+    let output = Output::Basic(
+        BasicOutput::build_with_amount(1_000_000)
+            .add_unlock_condition(AddressUnlockCondition::new(address))
+            .finish(protocol_parameters.token_supply())
+            .unwrap(),
+    );
+
+    TransactionEssence::Regular(
+        RegularTransactionEssence::builder(protocol_parameters.network_id(), rand_inputs_commitment())
+            .with_inputs(
+                (0..count)
+                    .map(|index| Input::Utxo(UtxoInput::new(transaction_id, index).unwrap()))
+                    .collect::<Vec<_>>(),
+            )
+            .add_output(output)
+            .finish(&protocol_parameters)
+            .unwrap(),
+    )
+}
+
+fn sign(secret_key: &SecretKey, message: &[u8]) -> Ed25519Signature {
+    Ed25519Signature::new(secret_key.public_key().to_bytes(), secret_key.sign(message).to_bytes())
+}
+
+#[test]
+fn from_essence_and_signatures() {
+    let essence = essence_with_inputs(3);
+    let essence_hash = essence.hash();
+    let secret_key_1 = SecretKey::from_bytes(&[1; 32]);
+    let secret_key_2 = SecretKey::from_bytes(&[2; 32]);
+    let signature_1 = sign(&secret_key_1, &essence_hash);
+    let signature_2 = sign(&secret_key_2, &essence_hash);
+
+    let tx_payload = TransactionPayload::from_essence_and_signatures(
+        essence.clone(),
+        vec![signature_1.clone(), signature_2.clone(), signature_1.clone()],
+    )
+    .unwrap();
+
+    assert_eq!(*tx_payload.essence(), essence);
+    assert_eq!(
+        *tx_payload.unlocks(),
+        Unlocks::new(vec![
+            Unlock::Signature(SignatureUnlock::from(Signature::Ed25519(signature_1))),
+            Unlock::Signature(SignatureUnlock::from(Signature::Ed25519(signature_2))),
+            Unlock::Reference(ReferenceUnlock::new(0).unwrap()),
+        ])
+        .unwrap()
+    );
+}
+
+#[test]
+fn from_essence_and_signatures_signature_count_mismatch() {
+    let essence = essence_with_inputs(2);
+    let signature = sign(&SecretKey::from_bytes(&[1; 32]), &essence.hash());
+
+    assert!(matches!(
+        TransactionPayload::from_essence_and_signatures(essence, vec![signature]),
+        Err(Error::InputUnlockCountMismatch {
+            input_count: 2,
+            unlock_count: 1
+        })
+    ));
+}
+
+#[test]
+fn from_essence_and_signatures_invalid_signature() {
+    let essence = essence_with_inputs(1);
+    let signature = sign(&SecretKey::from_bytes(&[1; 32]), &[0; 32]);
+
+    assert!(matches!(
+        TransactionPayload::from_essence_and_signatures(essence, vec![signature]),
+        Err(Error::InvalidSignature)
+    ));
+}
+
+#[test]
+fn compute_unlocks_of_inputs() {
+    let token_supply = protocol_parameters().token_supply();
+    let essence = essence_with_inputs(4);
+    let signature = sign(&SecretKey::from_bytes(&[1; 32]), &essence.hash());
+    let ed25519_address = Address::from(signature.address());
+    let transaction_id = TransactionId::new(prefix_hex::decode(TRANSACTION_ID).unwrap());
+    let alias_address = Address::from(AliasAddress::new(AliasId::from(
+        &OutputId::new(transaction_id, 1).unwrap(),
+    )));
+    let basic_output = |address| {
+        Output::Basic(
+            BasicOutput::build_with_amount(1_000_000)
+                .add_unlock_condition(AddressUnlockCondition::new(address))
+                .finish(token_supply)
+                .unwrap(),
+        )
+    };
+    let alias_output = Output::Alias(
+        AliasOutput::build_with_amount(1_000_000, AliasId::null())
+            .add_unlock_condition(StateControllerAddressUnlockCondition::new(ed25519_address))
+            .add_unlock_condition(GovernorAddressUnlockCondition::new(ed25519_address))
+            .finish(token_supply)
+            .unwrap(),
+    );
+    let ed25519_output = basic_output(ed25519_address);
+    let alias_owned_output = basic_output(alias_address);
+
+    let unlocks = compute_unlocks(
+        &essence,
+        &[
+            (&ed25519_output, ed25519_address),
+            (&alias_output, ed25519_address),
+            (&alias_owned_output, alias_address),
+            (&ed25519_output, ed25519_address),
+        ],
+        &[signature.clone()],
+    )
+    .unwrap();
+
+    assert_eq!(
+        unlocks,
+        Unlocks::new(vec![
+            Unlock::Signature(SignatureUnlock::from(Signature::Ed25519(signature.clone()))),
+            Unlock::Reference(ReferenceUnlock::new(0).unwrap()),
+            Unlock::Alias(AliasUnlock::new(1).unwrap()),
+            Unlock::Reference(ReferenceUnlock::new(0).unwrap()),
+        ])
+        .unwrap()
+    );
+
+    // The alias output needs to be consumed before the outputs it unlocks.
+    assert_eq!(
+        compute_unlocks(
+            &essence,
+            &[
+                (&ed25519_output, ed25519_address),
+                (&alias_owned_output, alias_address),
+                (&alias_output, ed25519_address),
+                (&ed25519_output, ed25519_address),
+            ],
+            &[signature],
+        ),
+        Err(Error::UnresolvedUnlockAddress(alias_address))
+    );
+
+    // Ed25519 addresses need a signature.
+    assert_eq!(
+        compute_unlocks(
+            &essence,
+            &[
+                (&ed25519_output, ed25519_address),
+                (&alias_output, ed25519_address),
+                (&alias_owned_output, alias_address),
+                (&ed25519_output, ed25519_address),
+            ],
+            &[],
+        ),
+        Err(Error::MissingSignature(*ed25519_address.as_ed25519()))
+    );
+}
@@ -4,7 +4,17 @@
 use core::str::FromStr;
 
 use iota_sdk::types::block::{
-    input::TreasuryInput, output::TreasuryOutput, payload::TreasuryTransactionPayload, protocol::protocol_parameters,
+    input::{
+        dto::{InputDto, UtxoInputDto},
+        TreasuryInput,
+    },
+    output::TreasuryOutput,
+    payload::{
+        dto::{PayloadDto, TreasuryTransactionPayloadDto},
+        Payload, TreasuryTransactionPayload,
+    },
+    protocol::protocol_parameters,
+    Error,
 };
 use packable::PackableExt;
 
@@ -64,3 +74,95 @@ fn pack_unpack_invalid() {
     // `InvalidOutputKind` but something related to an invalid address, so not really relevant for this test.
     assert!(TreasuryTransactionPayload::unpack_verified(bytes.as_slice(), &protocol_parameters()).is_err());
 }
+
+#[test]
+fn dto_roundtrip() {
+    let protocol_parameters = protocol_parameters();
+    let treasury_transaction = TreasuryTransactionPayload::new(
+        TreasuryInput::from_str(BLOCK_ID).unwrap(),
+        TreasuryOutput::new(1_000, protocol_parameters.token_supply()).unwrap(),
+    )
+    .unwrap();
+
+    let dto = TreasuryTransactionPayloadDto::from(&treasury_transaction);
+
+    assert_eq!(
+        TreasuryTransactionPayload::try_from_dto(&dto, protocol_parameters.token_supply()).unwrap(),
+        treasury_transaction
+    );
+    assert_eq!(
+        TreasuryTransactionPayload::try_from_dto_unverified(&dto).unwrap(),
+        treasury_transaction
+    );
+}
+
+#[test]
+fn dto_from_json() {
+    let protocol_parameters = protocol_parameters();
+    let json = r#"{
+        "type": 4,
+        "input": {
+            "type": 1,
+            "milestoneId": "0x52fdfc072182654f163f5f0f9a621d729566c74d10037c4d7bbb0407d1e2c649"
+        },
+        "output": {
+            "type": 2,
+            "amount": "1000"
+        }
+    }"#;
+
+    let payload_dto = serde_json::from_str::<PayloadDto>(json).unwrap();
+    assert!(matches!(payload_dto, PayloadDto::TreasuryTransaction(_)));
+
+    let payload = Payload::try_from_dto(&payload_dto, &protocol_parameters).unwrap();
+    let Payload::TreasuryTransaction(treasury_transaction) = &payload else {
+        panic!("expected a treasury transaction payload");
+    };
+    assert_eq!(treasury_transaction.input().milestone_id().to_string(), BLOCK_ID);
+    assert_eq!(treasury_transaction.output().amount(), 1_000);
+
+    assert_eq!(
+        serde_json::to_value(PayloadDto::from(&payload)).unwrap(),
+        serde_json::from_str::<serde_json::Value>(json).unwrap()
+    );
+}
+
+#[test]
+fn dto_invalid() {
+    let protocol_parameters = protocol_parameters();
+    let treasury_transaction = TreasuryTransactionPayload::new(
+        TreasuryInput::from_str(BLOCK_ID).unwrap(),
+        TreasuryOutput::new(1_000, protocol_parameters.token_supply()).unwrap(),
+    )
+    .unwrap();
+    let dto = TreasuryTransactionPayloadDto::from(&treasury_transaction);
+
+    let invalid_kind = TreasuryTransactionPayloadDto {
+        kind: 5,
+        ..dto.clone()
+    };
+    assert!(matches!(
+        TreasuryTransactionPayload::try_from_dto(&invalid_kind, protocol_parameters.token_supply()),
+        Err(Error::InvalidPayloadKind(5))
+    ));
+
+    let invalid_input = TreasuryTransactionPayloadDto {
+        input: InputDto::Utxo(UtxoInputDto {
+            kind: 0,
+            transaction_id: BLOCK_ID.to_string(),
+            transaction_output_index: 0,
+        }),
+        ..dto.clone()
+    };
+    assert!(matches!(
+        TreasuryTransactionPayload::try_from_dto(&invalid_input, protocol_parameters.token_supply()),
+        Err(Error::InvalidField("input"))
+    ));
+
+    let invalid_amount = TreasuryTransactionPayloadDto {
+        output: serde_json::from_str(r#"{ "type": 2, "amount": "1813620509061366" }"#).unwrap(),
+        ..dto
+    };
+    assert!(TreasuryTransactionPayload::try_from_dto(&invalid_amount, protocol_parameters.token_supply()).is_err());
+    assert!(TreasuryTransactionPayload::try_from_dto_unverified(&invalid_amount).is_ok());
+}
@@ -0,0 +1,81 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use iota_sdk::types::block::{
+    payload::{
+        dto::{PayloadDto, TaggedDataPayloadDto},
+        Payload, TaggedDataPayload, UnknownPayload,
+    },
+    protocol::protocol_parameters,
+    rand::parents::rand_parents,
+    Block, Error,
+};
+use packable::PackableExt;
+
+#[test]
+fn new_valid() {
+    let payload = UnknownPayload::new(42, vec![1, 2, 3]).unwrap();
+
+    assert_eq!(payload.kind(), 42);
+    assert_eq!(payload.data(), &[1, 2, 3]);
+    assert!(Payload::from(payload).is_unknown());
+}
+
+#[test]
+fn new_invalid_known_kind() {
+    assert!(matches!(
+        UnknownPayload::new(TaggedDataPayload::KIND, vec![]),
+        Err(Error::InvalidPayloadKind(5))
+    ));
+}
+
+#[test]
+fn pack_unpack_block() {
+    let protocol_parameters = protocol_parameters();
+    let block = Block::build(rand_parents())
+        .with_payload(UnknownPayload::new(42, vec![1, 2, 3, 4, 5]).unwrap())
+        .finish()
+        .unwrap();
+
+    let packed = block.pack_to_vec();
+    let unpacked = Block::unpack_verified(packed.as_slice(), &protocol_parameters).unwrap();
+
+    assert_eq!(block, unpacked);
+    assert_eq!(unpacked.payload().unwrap().kind(), 42);
+    // The opaque bytes are packed unchanged.
+    assert_eq!(unpacked.pack_to_vec(), packed);
+}
+
+#[test]
+fn unpack_block_with_known_kind() {
+    let protocol_parameters = protocol_parameters();
+    let block = Block::build(rand_parents())
+        .with_payload(TaggedDataPayload::new(vec![1], vec![2]).unwrap())
+        .finish()
+        .unwrap();
+
+    let unpacked = Block::unpack_verified(block.pack_to_vec().as_slice(), &protocol_parameters).unwrap();
+
+    assert!(matches!(unpacked.payload(), Some(Payload::TaggedData(_))));
+}
+
+#[test]
+fn dto() {
+    let protocol_parameters = protocol_parameters();
+    let payload = Payload::from(UnknownPayload::new(42, vec![1, 2, 3]).unwrap());
+    let dto = PayloadDto::from(&payload);
+
+    let json = serde_json::to_string(&dto).unwrap();
+    assert_eq!(json, r#"{"type":42,"data":"0x010203"}"#);
+
+    let deserialized = serde_json::from_str::<PayloadDto>(&json).unwrap();
+    assert!(matches!(deserialized, PayloadDto::Unknown(_)));
+    assert_eq!(Payload::try_from_dto(&deserialized, &protocol_parameters).unwrap(), payload);
+
+    // Known kinds are not shadowed by the unknown payload DTO.
+    let tagged_data = PayloadDto::from(TaggedDataPayloadDto::from(
+        &TaggedDataPayload::new(vec![], vec![1, 2, 3]).unwrap(),
+    ));
+    let deserialized = serde_json::from_str::<PayloadDto>(&serde_json::to_string(&tagged_data).unwrap()).unwrap();
+    assert!(matches!(deserialized, PayloadDto::TaggedData(_)));
+}
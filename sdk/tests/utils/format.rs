@@ -0,0 +1,25 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use iota_sdk::utils::{
+    format::{to_iso_8601, DefaultFormatter, Formatter},
+    unit::Unit,
+};
+
+#[test]
+fn iso_8601() {
+    assert_eq!(to_iso_8601(0), "1970-01-01T00:00:00Z");
+    assert_eq!(to_iso_8601(951_782_400_999), "2000-02-29T00:00:00Z");
+    assert_eq!(to_iso_8601(1_680_697_800_000), "2023-04-05T12:30:00Z");
+    assert_eq!(to_iso_8601(4_102_444_799_000), "2099-12-31T23:59:59Z");
+}
+
+#[test]
+fn default_formatter() {
+    let formatter = DefaultFormatter::default();
+    assert_eq!(formatter.format_amount(1_234_500_000), "1234500000");
+    assert_eq!(formatter.format_timestamp(1_680_697_800_000), "2023-04-05T12:30:00Z");
+
+    let formatter = DefaultFormatter::for_locale(Unit::SMR, "de-DE");
+    assert_eq!(formatter.format_amount(1_234_500_000), "1.234,5 SMR");
+}
@@ -0,0 +1,6 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+mod format;
+mod payment_uri;
+mod unit;
@@ -0,0 +1,92 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::str::FromStr;
+
+use iota_sdk::{
+    types::block::address::Bech32Address,
+    utils::payment_uri::{PaymentUri, PaymentUriError},
+};
+
+const ADDRESS: &str = "rms1qr47gz3xxjqpjrwd0yu5glhqrth6w0t08npney8000ust2lcw2r92j5a8rt";
+
+#[test]
+fn payment_uri_round_trip() {
+    let payment_uri = PaymentUri::new(Bech32Address::try_from_str(ADDRESS).unwrap())
+        .with_amount(1_000_000)
+        .with_tag("invoice 42".to_string())
+        .with_note("Thanks!".to_string())
+        .with_expiration(1_700_000_000);
+    let uri = format!("iota://{ADDRESS}?amount=1000000&tag=invoice%2042&note=Thanks%21&expiration=1700000000");
+
+    assert_eq!(payment_uri.to_string(), uri);
+    assert_eq!(PaymentUri::from_str(&uri).unwrap(), payment_uri);
+}
+
+#[test]
+fn payment_uri_parse() {
+    let payment_uri = PaymentUri::from_str(&format!("iota://{ADDRESS}")).unwrap();
+    assert_eq!(payment_uri.address.to_string(), ADDRESS);
+    assert_eq!(payment_uri.amount, None);
+    assert_eq!(payment_uri.tag, None);
+    assert_eq!(payment_uri.to_string(), format!("iota://{ADDRESS}"));
+
+    // The scheme is case-insensitive, `+` is a space and unknown parameters are ignored
+    let payment_uri = PaymentUri::from_str(&format!(" IOTA://{ADDRESS}/?note=see+you&label=shop ")).unwrap();
+    assert_eq!(payment_uri.note.as_deref(), Some("see you"));
+}
+
+#[test]
+fn payment_uri_invalid() {
+    assert!(matches!(
+        PaymentUri::from_str(&format!("https://{ADDRESS}")),
+        Err(PaymentUriError::InvalidScheme(_))
+    ));
+    assert!(matches!(
+        PaymentUri::from_str("iota://rms1invalid"),
+        Err(PaymentUriError::InvalidAddress(_))
+    ));
+    assert_eq!(
+        PaymentUri::from_str(&format!("iota://{ADDRESS}?amount=1.5")),
+        Err(PaymentUriError::InvalidParameter {
+            name: "amount".to_string(),
+            value: "1.5".to_string()
+        })
+    );
+    assert_eq!(
+        PaymentUri::from_str(&format!("iota://{ADDRESS}?amount=1&amount=2")),
+        Err(PaymentUriError::DuplicateParameter("amount".to_string()))
+    );
+    assert!(matches!(
+        PaymentUri::from_str(&format!("iota://{ADDRESS}?tag={}", "a".repeat(65))),
+        Err(PaymentUriError::InvalidParameter { .. })
+    ));
+    assert!(matches!(
+        PaymentUri::from_str(&format!("iota://{ADDRESS}?note=%zz")),
+        Err(PaymentUriError::InvalidParameter { .. })
+    ));
+}
+
+#[test]
+fn payment_uri_hrp() {
+    let uri = format!("iota://{ADDRESS}?amount=1000000");
+
+    assert!(PaymentUri::parse_with_hrp(&uri, "rms").is_ok());
+    assert_eq!(
+        PaymentUri::parse_with_hrp(&uri, "smr"),
+        Err(PaymentUriError::InvalidHrp {
+            expected: "smr".to_string(),
+            found: "rms".to_string()
+        })
+    );
+}
+
+#[test]
+fn payment_uri_expired() {
+    let payment_uri = PaymentUri::new(Bech32Address::try_from_str(ADDRESS).unwrap());
+    assert!(!payment_uri.is_expired(u32::MAX));
+
+    let payment_uri = payment_uri.with_expiration(1_700_000_000);
+    assert!(!payment_uri.is_expired(1_700_000_000));
+    assert!(payment_uri.is_expired(1_700_000_001));
+}
@@ -0,0 +1,115 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use iota_sdk::utils::unit::{format_amount, parse_amount, to_decimal_string, AmountFormat, Unit, UnitError};
+
+#[test]
+fn from_symbol() {
+    assert_eq!(Unit::from_symbol("SMR"), Some(Unit::SMR));
+    assert_eq!(Unit::from_symbol("smr"), Some(Unit::SMR));
+    assert_eq!(Unit::from_symbol("mi"), Some(Unit::MI));
+    assert_eq!(Unit::from_symbol("Glow"), Some(Unit::GLOW));
+    assert_eq!(Unit::from_symbol("XYZ"), None);
+}
+
+#[test]
+fn decimal_string() {
+    assert_eq!(to_decimal_string(1_500_000, Unit::SMR), "1.5");
+    assert_eq!(to_decimal_string(1_000_000, Unit::MI), "1");
+    assert_eq!(to_decimal_string(1, Unit::SMR), "0.000001");
+    assert_eq!(to_decimal_string(0, Unit::SMR), "0");
+    assert_eq!(to_decimal_string(1_234, Unit::GLOW), "1234");
+    assert_eq!(to_decimal_string(u64::MAX, Unit::PI), "18446.744073709551615");
+}
+
+#[test]
+fn format() {
+    let format = AmountFormat {
+        group_separator: Some(','),
+        min_decimals: 2,
+        with_symbol: true,
+        ..Default::default()
+    };
+    assert_eq!(
+        format_amount(1_234_567_000_000, Unit::SMR, &format),
+        "1,234,567.00 SMR"
+    );
+    assert_eq!(format_amount(123_456_789, Unit::SMR, &format), "123.456789 SMR");
+    assert_eq!(format_amount(100_000, Unit::GLOW, &format), "100,000.00 glow");
+}
+
+#[test]
+fn format_locale() {
+    assert_eq!(
+        format_amount(1_234_500_000, Unit::SMR, &AmountFormat::for_locale("de-DE")),
+        "1.234,5 SMR"
+    );
+    assert_eq!(
+        format_amount(1_234_500_000, Unit::SMR, &AmountFormat::for_locale("en")),
+        "1,234.5 SMR"
+    );
+    assert_eq!(
+        format_amount(1_234_500_000, Unit::SMR, &AmountFormat::for_locale("de-CH")),
+        "1'234.5 SMR"
+    );
+    assert_eq!(
+        format_amount(1_234_500_000, Unit::MI, &AmountFormat::for_locale("fr_FR")),
+        "1\u{a0}234,5 Mi"
+    );
+    assert_eq!(
+        format_amount(1_234_500_000, Unit::SMR, &AmountFormat::for_locale("unknown")),
+        "1,234.5 SMR"
+    );
+}
+
+#[test]
+fn parse() {
+    assert_eq!(parse_amount("1.5 SMR", Unit::GLOW), Ok(1_500_000));
+    assert_eq!(parse_amount("1.5smr", Unit::GLOW), Ok(1_500_000));
+    assert_eq!(parse_amount("1.1 Mi", Unit::I), Ok(1_100_000));
+    assert_eq!(parse_amount(".5 Ki", Unit::I), Ok(500));
+    assert_eq!(parse_amount("2.", Unit::SMR), Ok(2_000_000));
+    assert_eq!(parse_amount("1_000", Unit::GLOW), Ok(1_000));
+    assert_eq!(parse_amount(" 42 ", Unit::GLOW), Ok(42));
+    assert_eq!(parse_amount("1.000 glow", Unit::SMR), Ok(1));
+    assert_eq!(parse_amount("18446744073709.551615 Mi", Unit::I), Ok(u64::MAX));
+}
+
+#[test]
+fn parse_roundtrip() {
+    for amount in [0, 1, 999_999, 1_000_000, 1_234_567, u64::MAX] {
+        for unit in Unit::ALL {
+            assert_eq!(parse_amount(&to_decimal_string(amount, unit), unit), Ok(amount));
+        }
+    }
+}
+
+#[test]
+fn parse_errors() {
+    assert_eq!(parse_amount("", Unit::SMR), Err(UnitError::InvalidNumber(String::new())));
+    assert_eq!(
+        parse_amount("SMR", Unit::SMR),
+        Err(UnitError::InvalidNumber("SMR".to_string()))
+    );
+    assert_eq!(
+        parse_amount("1.2.3", Unit::SMR),
+        Err(UnitError::InvalidNumber("1.2.3".to_string()))
+    );
+    assert_eq!(
+        parse_amount("1 XYZ", Unit::SMR),
+        Err(UnitError::UnknownUnit("XYZ".to_string()))
+    );
+    assert_eq!(
+        parse_amount("0.0000001 SMR", Unit::GLOW),
+        Err(UnitError::TooManyDecimals {
+            unit: "SMR",
+            decimals: 6
+        })
+    );
+    assert_eq!(
+        parse_amount("1.5", Unit::GLOW).unwrap_err().to_string(),
+        "too many decimals: glow has 0 decimals"
+    );
+    assert_eq!(parse_amount("18446744073709551616", Unit::GLOW), Err(UnitError::Overflow));
+    assert_eq!(parse_amount("18446744073710 Mi", Unit::I), Err(UnitError::Overflow));
+}
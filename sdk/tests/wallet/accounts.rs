@@ -1,7 +1,16 @@
 // Copyright 2022 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use iota_sdk::wallet::Result;
+use std::sync::Arc;
+
+use iota_sdk::wallet::{
+    account::{
+        ledger_backend::{LedgerBackend, LedgerBalance, LedgerTransaction, LAYER_1_LEDGER_ID},
+        types::InclusionState,
+        AccountDetails, AddressGenerationOptions,
+    },
+    Error, FireflyProfile, Result,
+};
 #[cfg(feature = "stronghold")]
 use {
     iota_sdk::client::{
@@ -10,8 +19,19 @@ use {
     },
     iota_sdk::wallet::{ClientOptions, Wallet},
 };
+#[cfg(feature = "test_utils")]
+use {
+    iota_sdk::client::test_utils::MockNode,
+    iota_sdk::types::block::{
+        output::{unlock_condition::AddressUnlockCondition, BasicOutputBuilder, OutputId},
+        payload::transaction::TransactionId,
+    },
+    iota_sdk::wallet::account::types::OutputNote,
+};
 
-use crate::wallet::common::{make_wallet, setup, tear_down};
+#[cfg(feature = "test_utils")]
+use crate::wallet::common::make_mock_wallet;
+use crate::wallet::common::{make_wallet, setup, tear_down, DEFAULT_MNEMONIC};
 
 #[tokio::test]
 async fn account_ordering() -> Result<()> {
@@ -29,6 +49,37 @@ async fn account_ordering() -> Result<()> {
     tear_down(storage_path)
 }
 
+#[cfg(feature = "storage")]
+#[tokio::test]
+async fn create_account_for_external_id() -> Result<()> {
+    let storage_path = "test-storage/create_account_for_external_id";
+    setup(storage_path)?;
+
+    {
+        let wallet = make_wallet(storage_path, None, None).await?;
+
+        let first_account = wallet.create_account_for("user-1").await?;
+        let second_account = wallet.create_account_for("user-2").await?;
+        assert_eq!(*first_account.read().await.index(), 0);
+        assert_eq!(*second_account.read().await.index(), 1);
+        assert_eq!(first_account.external_id().await.as_deref(), Some("user-1"));
+
+        // Creating an account for the same identifier again returns the existing account
+        let account = wallet.create_account_for("user-1").await?;
+        assert_eq!(*account.read().await.index(), 0);
+        assert_eq!(wallet.get_accounts().await?.len(), 2);
+    }
+
+    // The mapping is restored from the storage
+    let wallet = make_wallet(storage_path, None, None).await?;
+    assert_eq!(*wallet.get_account_for("user-2").await?.read().await.index(), 1);
+    assert_eq!(*wallet.create_account_for("user-3").await?.read().await.index(), 2);
+    assert_eq!(wallet.get_external_ids().await.len(), 3);
+    assert!(wallet.get_account_for("user-4").await.is_err());
+
+    tear_down(storage_path)
+}
+
 #[cfg(feature = "storage")]
 #[tokio::test]
 async fn remove_latest_account() -> Result<()> {
@@ -164,6 +215,228 @@ async fn account_rename_alias() -> Result<()> {
     tear_down(storage_path)
 }
 
+#[tokio::test]
+async fn account_remove_and_reuse_index() -> Result<()> {
+    let storage_path = "test-storage/account_remove_and_reuse_index";
+    setup(storage_path)?;
+
+    let wallet = make_wallet(storage_path, None, None).await?;
+    wallet.create_account().with_alias("Alice".to_string()).finish().await?;
+    let account = wallet.create_account().with_alias("Bob".to_string()).finish().await?;
+    wallet.create_account().with_alias("Carol".to_string()).finish().await?;
+
+    // The alias needs to be unique, only the own alias can be used with another case
+    let result = account.set_alias("alice").await;
+    assert!(matches!(result, Err(Error::AccountAliasAlreadyExists(_))));
+    account.set_alias("BOB").await?;
+
+    let removed_addresses = account.addresses().await?;
+    wallet.remove_account("BOB".to_string(), false).await?;
+    assert!(wallet.get_account(1).await.is_err());
+    assert_eq!(wallet.get_account_aliases().await?, ["Alice", "Carol"]);
+
+    // The index of the removed account is used again, with the same addresses
+    let account = wallet.create_account().with_alias("Dave".to_string()).finish().await?;
+    assert_eq!(*account.read().await.index(), 1);
+    assert_eq!(account.addresses().await?, removed_addresses);
+    assert_eq!(*wallet.create_account().finish().await?.read().await.index(), 3);
+
+    tear_down(storage_path)
+}
+
+#[tokio::test]
+async fn account_snapshot_during_write() -> Result<()> {
+    let storage_path = "test-storage/account_snapshot_during_write";
+    setup(storage_path)?;
+
+    let wallet = make_wallet(storage_path, None, None).await?;
+    let account = wallet.create_account().with_alias("Alice".to_string()).finish().await?;
+    let account_clone = account.clone();
+
+    let write_guard = account.write().await;
+    // Reads of the snapshot don't wait for the write guard
+    assert_eq!(account_clone.snapshot().alias(), "Alice");
+    assert_eq!(account_clone.addresses().await?.len(), 1);
+    assert!(account_clone.unspent_outputs(None).await?.is_empty());
+    assert_eq!(account_clone.alias().await, "Alice");
+    assert!(account_clone.outputs_page(None, 0, 10).await?.is_empty());
+    assert!(account_clone.transactions().await?.is_empty());
+    assert!(account_clone.pending_transactions().await?.is_empty());
+    assert!(account_clone.incoming_transactions().await?.is_empty());
+    assert_eq!(account_clone.get_metadata("key").await, None);
+    drop(write_guard);
+
+    // The snapshot is replaced when the write guard is dropped and shared by all clones
+    account.set_alias("Bob").await?;
+    assert_eq!(account_clone.snapshot().alias(), "Bob");
+    assert_eq!(*account_clone.snapshot(), *account.read().await);
+
+    tear_down(storage_path)
+}
+
+#[tokio::test]
+async fn account_address_label() -> Result<()> {
+    let storage_path = "test-storage/account_address_label";
+    setup(storage_path)?;
+
+    let wallet = make_wallet(storage_path, None, None).await?;
+    let account = wallet.create_account().with_alias("Alice".to_string()).finish().await?;
+
+    let address = account.addresses().await?[0].address().clone();
+    assert_eq!(account.addresses().await?[0].label(), &None);
+    assert!(account.addresses_with_label("deposit").await?.is_empty());
+
+    account.set_address_label(&address, "deposit".to_string()).await?;
+
+    let labeled = account.addresses_with_label("deposit").await?;
+    assert_eq!(labeled.len(), 1);
+    assert_eq!(labeled[0].address(), &address);
+    assert_eq!(labeled[0].label().as_deref(), Some("deposit"));
+
+    // remove label
+    account.set_address_label(&address, None).await?;
+    assert!(account.addresses_with_label("deposit").await?.is_empty());
+
+    tear_down(storage_path)
+}
+
+#[cfg(feature = "test_utils")]
+#[tokio::test]
+async fn account_output_note() -> Result<()> {
+    let storage_path = "test-storage/account_output_note";
+    setup(storage_path)?;
+
+    let mock_node = MockNode::default();
+    let wallet = make_mock_wallet(storage_path, &mock_node).await?;
+    let account = wallet.create_account().finish().await?;
+    let address = *account.addresses().await?[0].address().inner();
+    let output_id = mock_node.add_output(
+        BasicOutputBuilder::new_with_amount(1_000_000)
+            .add_unlock_condition(AddressUnlockCondition::new(address))
+            .finish_output(mock_node.protocol_parameters().token_supply())?,
+    );
+    account.sync(None).await?;
+
+    // Notes can only be attached to outputs of the account
+    let unknown_output_id = OutputId::new(TransactionId::new([1; 32]), 0)?;
+    assert!(matches!(
+        account.set_output_note(unknown_output_id, OutputNote::new("invoice-1")).await,
+        Err(Error::OutputNotFoundInAccount(output_id)) if output_id == unknown_output_id
+    ));
+    assert!(account.output_notes().await.is_empty());
+
+    assert_eq!(account.get_output_note(&output_id).await, None);
+    let note = OutputNote::new("invoice-1").with_attachment("receipts/invoice-1.pdf");
+    account.set_output_note(output_id, note.clone()).await?;
+    assert_eq!(account.get_output_note(&output_id).await, Some(note));
+
+    // overwrite note
+    let note = OutputNote::new("invoice-2");
+    account.set_output_note(output_id, note.clone()).await?;
+    assert_eq!(account.get_output_note(&output_id).await, Some(note.clone()));
+    assert_eq!(account.output_notes().await.len(), 1);
+
+    // remove note
+    assert_eq!(account.remove_output_note(&output_id).await?, Some(note.clone()));
+    assert_eq!(account.get_output_note(&output_id).await, None);
+    assert_eq!(account.remove_output_note(&output_id).await?, None);
+
+    account.set_output_note(output_id, note.clone()).await?;
+    drop(account);
+    drop(wallet);
+
+    // The note is stored with the account
+    #[cfg(feature = "storage")]
+    {
+        let wallet = make_mock_wallet(storage_path, &mock_node).await?;
+        let account = wallet.get_account(0).await?;
+        assert_eq!(account.get_output_note(&output_id).await, Some(note));
+    }
+
+    tear_down(storage_path)
+}
+
+#[tokio::test]
+async fn account_metadata() -> Result<()> {
+    let storage_path = "test-storage/account_metadata";
+    setup(storage_path)?;
+
+    let wallet = make_wallet(storage_path, Some(DEFAULT_MNEMONIC), None).await?;
+    let account = wallet.create_account().finish().await?;
+
+    assert_eq!(account.get_metadata("kyc/reference").await, None);
+    account.set_metadata("kyc/reference", b"ref-1".to_vec()).await?;
+    account.set_metadata("kyc/level", vec![2]).await?;
+    account.set_metadata("app/checkpoint", vec![0, 1]).await?;
+    assert_eq!(account.get_metadata("kyc/reference").await, Some(b"ref-1".to_vec()));
+    assert_eq!(account.metadata_keys("kyc/").await, ["kyc/level", "kyc/reference"]);
+    assert_eq!(account.metadata_keys("").await.len(), 3);
+
+    assert_eq!(account.remove_metadata("kyc/level").await?, Some(vec![2]));
+    assert_eq!(account.remove_metadata("kyc/level").await?, None);
+    drop(account);
+    drop(wallet);
+
+    // The metadata is stored with the account
+    #[cfg(feature = "storage")]
+    {
+        let wallet = make_wallet(storage_path, Some(DEFAULT_MNEMONIC), None).await?;
+        let account = wallet.get_account(0).await?;
+        assert_eq!(account.get_metadata("kyc/reference").await, Some(b"ref-1".to_vec()));
+        assert_eq!(account.metadata_keys("").await, ["app/checkpoint", "kyc/reference"]);
+    }
+
+    tear_down(storage_path)
+}
+
+#[tokio::test]
+async fn account_generate_addresses_with_options() -> Result<()> {
+    let storage_path = "test-storage/account_generate_addresses_with_options";
+    setup(storage_path)?;
+
+    let wallet = make_wallet(storage_path, None, None).await?;
+    let account = wallet.create_account().with_alias("Alice".to_string()).finish().await?;
+    let first_address = account.addresses().await?[0].clone();
+
+    let internal_addresses = account
+        .generate_addresses(2, Some(AddressGenerationOptions::internal()))
+        .await?;
+    assert_eq!(
+        internal_addresses.iter().map(|a| *a.key_index()).collect::<Vec<_>>(),
+        [0, 1]
+    );
+    assert!(internal_addresses.iter().all(|a| *a.internal()));
+
+    // Addresses with an explicit start index leave a gap, the next addresses continue after the highest index
+    let options = AddressGenerationOptions {
+        start_index: Some(5),
+        ..Default::default()
+    };
+    assert_eq!(*account.generate_addresses(1, Some(options)).await?[0].key_index(), 5);
+    assert_eq!(*account.generate_addresses(1, None).await?[0].key_index(), 6);
+
+    // Existing addresses are returned with the HRP override, but not stored again
+    let options = AddressGenerationOptions {
+        start_index: Some(0),
+        bech32_hrp: Some("rms".to_string()),
+        ..Default::default()
+    };
+    let address = account.generate_addresses(1, Some(options)).await?[0].clone();
+    assert_eq!(address.address().hrp(), "rms");
+    assert_eq!(address.address().inner(), first_address.address().inner());
+    let addresses = account.addresses().await?;
+    assert_eq!(
+        addresses
+            .iter()
+            .map(|a| (*a.key_index(), *a.internal()))
+            .collect::<Vec<_>>(),
+        [(0, false), (5, false), (6, false), (0, true), (1, true)]
+    );
+    assert_eq!(addresses[0].address(), first_address.address());
+
+    tear_down(storage_path)
+}
+
 #[tokio::test]
 async fn account_first_address_exists() -> Result<()> {
     let storage_path = "test-storage/account_first_address_exists";
@@ -212,3 +485,262 @@ async fn account_creation_stronghold() -> Result<()> {
 
     tear_down(storage_path)
 }
+
+#[cfg(feature = "storage")]
+#[tokio::test]
+async fn account_state_events() -> Result<()> {
+    use iota_sdk::client::{
+        constants::SHIMMER_COIN_TYPE,
+        secret::{mnemonic::MnemonicSecretManager, SecretManager},
+        Client,
+    };
+
+    let storage_path = "test-storage/account_state_events";
+    setup(storage_path)?;
+
+    let secret_manager = MnemonicSecretManager::try_from_mnemonic(&Client::generate_mnemonic().unwrap())?;
+    let wallet = iota_sdk::wallet::Wallet::builder()
+        .with_secret_manager(SecretManager::Mnemonic(secret_manager))
+        .with_client_options(iota_sdk::wallet::ClientOptions::new().with_node("http://localhost:14265")?)
+        .with_coin_type(SHIMMER_COIN_TYPE)
+        .with_storage_path(storage_path)
+        .with_event_sourcing(true)
+        .finish()
+        .await?;
+
+    let account = wallet.create_account().with_alias("Alice".to_string()).finish().await?;
+    let created_events = account.state_events().await?;
+    assert!(!created_events.is_empty());
+    let created_timestamp = created_events.last().unwrap().timestamp;
+
+    // Make sure the next event gets a later timestamp
+    tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+    account.set_alias("Bob").await?;
+
+    let events = account.state_events().await?;
+    assert!(events.len() > created_events.len());
+    assert!(events.iter().enumerate().all(|(i, event)| event.sequence == i as u64));
+
+    let created_state = account.state_at(created_timestamp).await?.unwrap();
+    assert_eq!(created_state.alias(), "Alice");
+    let current_state = account.state_at(u128::MAX).await?.unwrap();
+    assert_eq!(current_state.alias(), "Bob");
+    assert!(account.state_at(0).await?.is_none());
+
+    tear_down(storage_path)
+}
+
+#[cfg(all(feature = "storage", feature = "test_utils"))]
+#[tokio::test]
+async fn account_cache_budget() -> Result<()> {
+    use iota_sdk::client::{
+        constants::SHIMMER_COIN_TYPE,
+        secret::{mnemonic::MnemonicSecretManager, SecretManager},
+    };
+
+    let storage_path = "test-storage/account_cache_budget";
+    setup(storage_path)?;
+
+    let mock_node = MockNode::default();
+    let wallet = iota_sdk::wallet::Wallet::builder()
+        .with_secret_manager(SecretManager::Mnemonic(MnemonicSecretManager::try_from_mnemonic(
+            DEFAULT_MNEMONIC,
+        )?))
+        .with_client_options(iota_sdk::wallet::ClientOptions::new().with_mock_node(mock_node.clone())?)
+        .with_coin_type(SHIMMER_COIN_TYPE)
+        .with_storage_path(storage_path)
+        // Smaller than the outputs of a single account
+        .with_account_cache_budget(1)
+        .finish()
+        .await?;
+
+    for alias in ["Alice", "Bob", "Charlie"] {
+        let account = wallet.create_account().with_alias(alias.to_string()).finish().await?;
+        let address = *account.addresses().await?[0].address().inner();
+        mock_node.add_output(
+            BasicOutputBuilder::new_with_amount(1_000_000)
+                .add_unlock_condition(AddressUnlockCondition::new(address))
+                .finish_output(mock_node.protocol_parameters().token_supply())?,
+        );
+        account.sync(None).await?;
+    }
+
+    // Every access evicts the outputs of the previously used account, they're loaded from the storage again
+    for _ in 0..2 {
+        for alias in ["Alice", "Bob", "Charlie"] {
+            let account = wallet.get_account(alias).await?;
+            assert_eq!(account.alias().await, alias);
+            assert_eq!(account.addresses().await?.len(), 1);
+            assert_eq!(account.unspent_outputs(None).await?.len(), 1);
+        }
+    }
+
+    let accounts = wallet.get_accounts().await?;
+    assert_eq!(accounts.len(), 3);
+
+    tear_down(storage_path)
+}
+
+#[cfg(feature = "storage")]
+#[tokio::test]
+async fn account_prune_without_spent_records() -> Result<()> {
+    use std::time::Duration;
+
+    let storage_path = "test-storage/account_prune_without_spent_records";
+    setup(storage_path)?;
+
+    let wallet = make_wallet(storage_path, None, None).await?;
+    let account = wallet.create_account().finish().await?;
+
+    // Nothing is spent, so nothing can be pruned, regardless of the age
+    let pruned_records = account.prune(Duration::ZERO).await?;
+    assert!(pruned_records.is_empty());
+    assert_eq!(account.addresses().await?.len(), 1);
+
+    tear_down(storage_path)
+}
+
+#[cfg(feature = "storage")]
+#[tokio::test]
+async fn export_import_account() -> Result<()> {
+    let storage_path = "test-storage/export_import_account";
+    let import_storage_path = "test-storage/export_import_account_import";
+    let other_seed_storage_path = "test-storage/export_import_account_other_seed";
+    setup(storage_path)?;
+    setup(import_storage_path)?;
+    setup(other_seed_storage_path)?;
+
+    let wallet = make_wallet(storage_path, Some(DEFAULT_MNEMONIC), None).await?;
+    wallet.create_account().finish().await?;
+    let account = wallet
+        .create_account()
+        .with_alias("savings".to_string())
+        .finish()
+        .await?;
+    account.generate_addresses(2, None).await?;
+    let exported_account = wallet.export_account("savings".to_string(), "password").await?;
+
+    let other_seed_wallet = make_wallet(other_seed_storage_path, None, None).await?;
+    let result = other_seed_wallet.import_account(&exported_account, "password").await;
+    assert!(matches!(result, Err(Error::InvalidMnemonic(_))));
+
+    let import_wallet = make_wallet(import_storage_path, Some(DEFAULT_MNEMONIC), None).await?;
+    let result = import_wallet.import_account(&exported_account, "wrong password").await;
+    assert!(matches!(result, Err(Error::Backup(_))));
+
+    let imported_account = import_wallet.import_account(&exported_account, "password").await?;
+    assert_eq!(*imported_account.read().await.index(), 1);
+    assert_eq!(imported_account.alias().await, "savings");
+    assert_eq!(imported_account.addresses().await?, account.addresses().await?);
+    // The account below the index of the imported account was created
+    assert_eq!(import_wallet.get_accounts().await?.len(), 2);
+
+    // The index is already used now
+    let result = import_wallet.import_account(&exported_account, "password").await;
+    assert!(matches!(result, Err(Error::Backup(_))));
+
+    tear_down(other_seed_storage_path)?;
+    tear_down(import_storage_path)?;
+    tear_down(storage_path)
+}
+
+#[tokio::test]
+async fn import_firefly_profile() -> Result<()> {
+    let storage_path = "test-storage/import_firefly_profile";
+    setup(storage_path)?;
+
+    let wallet = make_wallet(storage_path, Some(DEFAULT_MNEMONIC), None).await?;
+    let account = wallet.create_account().with_alias("Alice".to_string()).finish().await?;
+    let hrp = account.addresses().await?[0].address().hrp().to_string();
+    let deposit_address = wallet.generate_address(1, 3, None).await?.to_bech32(&hrp);
+
+    let profile: FireflyProfile = serde_json::from_str(&format!(
+        r##"{{
+            "id": "5c0e5d3a-1e86-4a83-a2b4-2e1f2ff4d2a0",
+            "name": "Profile",
+            "type": "Software",
+            "network": {{ "id": "shimmer", "coinType": 4219 }},
+            "accountPersistedData": {{
+                "0": {{ "name": "Main", "color": "#FF7B00" }},
+                "1": {{ "name": "Savings", "depositAddress": "{deposit_address}" }}
+            }}
+        }}"##
+    ))?;
+    let accounts = wallet.import_firefly_profile(profile.clone(), None).await?;
+    assert_eq!(accounts.len(), 2);
+    assert_eq!(accounts[0].alias().await, "Main");
+    assert_eq!(accounts[1].alias().await, "Savings");
+    // Addresses up to the deposit address got generated
+    let addresses = accounts[1].addresses().await?;
+    assert_eq!(addresses.len(), 4);
+    assert_eq!(addresses[3].address().to_string(), deposit_address);
+
+    // Importing again doesn't change the accounts
+    wallet.import_firefly_profile(profile.clone(), None).await?;
+    assert_eq!(wallet.get_accounts().await?.len(), 2);
+    assert_eq!(accounts[1].addresses().await?.len(), 4);
+
+    // Profile of another network
+    let mut other_profile = profile;
+    other_profile.network.as_mut().unwrap().coin_type = Some(4218);
+    assert!(wallet.import_firefly_profile(other_profile, None).await.is_err());
+
+    tear_down(storage_path)
+}
+
+#[derive(Debug)]
+struct MockLedger;
+
+#[async_trait::async_trait]
+impl LedgerBackend for MockLedger {
+    fn id(&self) -> String {
+        "evm-1074".to_string()
+    }
+
+    async fn balance(&self, _account: &AccountDetails) -> Result<LedgerBalance> {
+        Ok(LedgerBalance {
+            ledger_id: self.id(),
+            total: 1_000_000u64.into(),
+            available: 1_000_000u64.into(),
+            tokens: Vec::new(),
+        })
+    }
+
+    async fn transactions(&self, _account: &AccountDetails) -> Result<Vec<LedgerTransaction>> {
+        Ok(vec![LedgerTransaction {
+            ledger_id: self.id(),
+            transaction_id: "0x52fdfc072182654f163f5f0f9a621d729566c74d10037c4d7bbb0407d1e2c649".to_string(),
+            timestamp: 1_681_000_000_000,
+            incoming: true,
+            inclusion_state: InclusionState::Confirmed,
+            note: None,
+        }])
+    }
+}
+
+#[tokio::test]
+async fn ledger_backends() -> Result<()> {
+    let storage_path = "test-storage/ledger_backends";
+    setup(storage_path)?;
+
+    let wallet = make_wallet(storage_path, Some(DEFAULT_MNEMONIC), None).await?;
+    let account = wallet.create_account().finish().await?;
+
+    account.add_ledger_backend(Arc::new(MockLedger)).await?;
+    assert_eq!(account.ledger_ids().await, [LAYER_1_LEDGER_ID, "evm-1074"]);
+    // The ids of the ledgers must be unique
+    assert!(matches!(
+        account.add_ledger_backend(Arc::new(MockLedger)).await,
+        Err(Error::LedgerBackend(_))
+    ));
+
+    let transactions = account.ledger_transactions().await?;
+    assert_eq!(transactions.len(), 1);
+    assert_eq!(transactions[0].ledger_id, "evm-1074");
+
+    assert!(account.remove_ledger_backend("evm-1074").await);
+    assert!(!account.remove_ledger_backend("evm-1074").await);
+    assert!(account.ledger_transactions().await?.is_empty());
+
+    tear_down(storage_path)
+}
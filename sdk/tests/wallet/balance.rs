@@ -2,12 +2,15 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use iota_sdk::{
-    types::block::output::{
-        feature::SenderFeature,
-        unlock_condition::{AddressUnlockCondition, ExpirationUnlockCondition},
-        BasicOutputBuilder, UnlockCondition,
+    types::block::{
+        address::{Address, AliasAddress},
+        output::{
+            feature::SenderFeature,
+            unlock_condition::{AddressUnlockCondition, ExpirationUnlockCondition},
+            BasicOutputBuilder, UnlockCondition,
+        },
     },
-    wallet::Result,
+    wallet::{account::TransactionOptions, Result},
 };
 
 use crate::wallet::common::{create_accounts_with_funds, make_wallet, setup, tear_down};
@@ -142,3 +145,99 @@ async fn balance_voting_power() -> Result<()> {
 
     tear_down(storage_path)
 }
+
+#[ignore]
+#[tokio::test]
+async fn balance_at_timestamp() -> Result<()> {
+    let storage_path = "test-storage/balance_at_timestamp";
+    setup(storage_path)?;
+
+    let wallet = make_wallet(storage_path, None, None).await?;
+    let account = &create_accounts_with_funds(&wallet, 1).await?[0];
+    let balance = account.sync(None).await?;
+
+    let current_time = account.client().get_time_checked().await?;
+    let balance_now = account.balance_at(current_time).await?;
+    assert_eq!(balance_now.base_coin(), balance.base_coin().total());
+    assert_eq!(balance_now.outputs().len(), account.unspent_outputs(None).await?.len());
+
+    // Before the faucet funded the account
+    let balance_before_funding = account.balance_at(0).await?;
+    assert_eq!(balance_before_funding.base_coin(), 0);
+    assert!(balance_before_funding.outputs().is_empty());
+
+    tear_down(storage_path)
+}
+
+#[ignore]
+#[tokio::test]
+async fn balance_alias_controlled_outputs() -> Result<()> {
+    let storage_path = "test-storage/balance_alias_controlled_outputs";
+    setup(storage_path)?;
+
+    let wallet = make_wallet(storage_path, None, None).await?;
+    let account = &create_accounts_with_funds(&wallet, 1).await?[0];
+
+    let transaction = account.create_alias_output(None, None).await?;
+    account
+        .retry_transaction_until_included(&transaction.transaction_id, None, None)
+        .await?;
+    let balance = account.sync(None).await?;
+    let alias_address = Address::Alias(AliasAddress::new(balance.aliases()[0]));
+
+    // Send funds to the alias address, they're still part of the balance
+    let token_supply = account.client().get_token_supply().await?;
+    let outputs = vec![
+        BasicOutputBuilder::new_with_amount(1_000_000)
+            .add_unlock_condition(AddressUnlockCondition::new(alias_address))
+            .finish_output(token_supply)?,
+    ];
+    let transaction = account.send(outputs, None).await?;
+    account
+        .retry_transaction_until_included(&transaction.transaction_id, None, None)
+        .await?;
+    let balance_with_alias_output = account.sync(None).await?;
+    assert_eq!(
+        balance_with_alias_output.base_coin().total(),
+        balance.base_coin().total()
+    );
+
+    let alias_controlled_output = account
+        .unspent_outputs(None)
+        .await?
+        .into_iter()
+        .find(|output_data| {
+            output_data
+                .output
+                .unlock_conditions()
+                .and_then(|unlock_conditions| unlock_conditions.address())
+                .map_or(false, |address| *address.address() == alias_address)
+        })
+        .expect("alias controlled output");
+
+    // Spending it requires the alias output as additional input
+    let outputs = vec![
+        BasicOutputBuilder::new_with_amount(1_000_000)
+            .add_unlock_condition(AddressUnlockCondition::new(
+                *account.addresses().await?[0].address().as_ref(),
+            ))
+            .finish_output(token_supply)?,
+    ];
+    let transaction = account
+        .send(
+            outputs,
+            Some(TransactionOptions {
+                mandatory_inputs: Some(vec![alias_controlled_output.output_id]),
+                ..Default::default()
+            }),
+        )
+        .await?;
+    account
+        .retry_transaction_until_included(&transaction.transaction_id, None, None)
+        .await?;
+    let balance_after_spending = account.sync(None).await?;
+    assert_eq!(balance_after_spending.base_coin().total(), balance.base_coin().total());
+    assert_eq!(balance_after_spending.aliases(), balance.aliases());
+
+    tear_down(storage_path)
+}
@@ -1,9 +1,9 @@
 // Copyright 2023 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use iota_sdk::{
-    client::Error as ClientError,
-    wallet::{account::OutputOptions, AddressWithAmount, Error, Result},
+use iota_sdk::wallet::{
+    account::{Features, OutputOptions},
+    AddressWithAmount, Error, Result,
 };
 
 use crate::wallet::common::{make_wallet, setup, tear_down};
@@ -32,14 +32,47 @@ async fn bech32_hrp_send_amount() -> Result<()> {
     let bech32_hrp = account.client().get_bech32_hrp().await?;
 
     match error {
-        Error::Client(error) => match *error {
-            ClientError::InvalidBech32Hrp { provided, expected } => {
-                assert_eq!(provided, "wronghrp".to_string());
-                assert_eq!(expected, bech32_hrp);
-            }
-            _ => panic!("expected InvalidBech32Hrp error variant"),
-        },
-        _ => panic!("expected Client error variant"),
+        Error::InvalidNetworkAddress { expected, found } => {
+            assert_eq!(found, "wronghrp".to_string());
+            assert_eq!(expected, bech32_hrp);
+        }
+        _ => panic!("expected InvalidNetworkAddress error variant"),
+    }
+
+    tear_down(storage_path)
+}
+
+#[ignore]
+#[tokio::test]
+async fn bech32_hrp_send_amount_return_address() -> Result<()> {
+    let storage_path = "test-storage/bech32_hrp_send_amount_return_address";
+    setup(storage_path)?;
+
+    let wallet = make_wallet(storage_path, None, None).await?;
+
+    let account = wallet.create_account().finish().await?;
+    let address = account.addresses().await?[0].address().clone();
+
+    // A micro amount needs a storage deposit return, so the return address is used
+    let error = account
+        .send_amount(
+            vec![
+                AddressWithAmount::new(address.to_string(), 1)
+                    .with_return_address(address.as_ref().to_bech32("wronghrp")),
+            ],
+            None,
+        )
+        .await
+        .unwrap_err();
+
+    let bech32_hrp = account.client().get_bech32_hrp().await?;
+
+    match error {
+        Error::InvalidNetworkAddress { expected, found } => {
+            assert_eq!(found, "wronghrp".to_string());
+            assert_eq!(expected, bech32_hrp);
+        }
+        _ => panic!("expected InvalidNetworkAddress error variant"),
     }
 
     tear_down(storage_path)
@@ -72,14 +105,52 @@ async fn bech32_hrp_prepare_output() -> Result<()> {
     let bech32_hrp = account.client().get_bech32_hrp().await?;
 
     match error {
-        Error::Client(error) => match *error {
-            ClientError::InvalidBech32Hrp { provided, expected } => {
-                assert_eq!(provided, "wronghrp".to_string());
-                assert_eq!(expected, bech32_hrp);
-            }
-            _ => panic!("expected InvalidBech32Hrp error variant"),
-        },
-        _ => panic!("expected Client error variant"),
+        Error::InvalidNetworkAddress { expected, found } => {
+            assert_eq!(found, "wronghrp".to_string());
+            assert_eq!(expected, bech32_hrp);
+        }
+        _ => panic!("expected InvalidNetworkAddress error variant"),
+    }
+
+    tear_down(storage_path)
+}
+
+#[ignore]
+#[tokio::test]
+async fn bech32_hrp_prepare_output_sender() -> Result<()> {
+    let storage_path = "test-storage/bech32_hrp_prepare_output_sender";
+    setup(storage_path)?;
+
+    let wallet = make_wallet(storage_path, None, None).await?;
+    let account = wallet.create_account().finish().await?;
+    let address = account.addresses().await?[0].address().clone();
+
+    let error = account
+        .prepare_output(
+            OutputOptions {
+                recipient_address: address.to_string(),
+                amount: 1_000_000,
+                assets: None,
+                features: Some(Features {
+                    sender: Some(address.as_ref().to_bech32("wronghrp")),
+                    ..Default::default()
+                }),
+                unlocks: None,
+                storage_deposit: None,
+            },
+            None,
+        )
+        .await
+        .unwrap_err();
+
+    let bech32_hrp = account.client().get_bech32_hrp().await?;
+
+    match error {
+        Error::InvalidNetworkAddress { expected, found } => {
+            assert_eq!(found, "wronghrp".to_string());
+            assert_eq!(expected, bech32_hrp);
+        }
+        _ => panic!("expected InvalidNetworkAddress error variant"),
     }
 
     tear_down(storage_path)
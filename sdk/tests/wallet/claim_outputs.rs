@@ -8,7 +8,7 @@ use iota_sdk::{
     },
     wallet::{
         account::{OutputsToClaim, TransactionOptions},
-        AddressNativeTokens, AddressWithAmount, NativeTokenOptions, Result,
+        AddressWithAmount, NativeTokenOptions, Result, SendNativeTokensParams,
     },
     U256,
 };
@@ -236,17 +236,15 @@ async fn claim_2_native_tokens() -> Result<()> {
     let tx = accounts[1]
         .send_native_tokens(
             vec![
-                AddressNativeTokens {
+                SendNativeTokensParams {
                     address: accounts[0].addresses().await?[0].address().to_string(),
                     native_tokens: vec![(mint_tx_0.token_id, native_token_amount)],
-                    expiration: None,
-                    return_address: None,
+                    ..Default::default()
                 },
-                AddressNativeTokens {
+                SendNativeTokensParams {
                     address: accounts[0].addresses().await?[0].address().to_string(),
                     native_tokens: vec![(mint_tx_1.token_id, native_token_amount)],
-                    expiration: None,
-                    return_address: None,
+                    ..Default::default()
                 },
             ],
             None,
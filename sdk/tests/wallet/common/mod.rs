@@ -8,13 +8,15 @@ mod constants;
 use iota_sdk::{
     client::{
         constants::SHIMMER_COIN_TYPE,
-        request_funds_from_faucet,
         secret::{mnemonic::MnemonicSecretManager, SecretManager},
         Client,
     },
     wallet::{Account, ClientOptions, Result, Wallet},
 };
 
+#[cfg(feature = "test_utils")]
+use iota_sdk::client::test_utils::MockNode;
+
 pub use self::constants::*;
 
 /// It creates a new wallet with a mnemonic secret manager, a client options object,
@@ -48,25 +50,37 @@ pub(crate) async fn make_wallet(storage_path: &str, mnemonic: Option<&str>, node
     wallet_builder.finish().await
 }
 
+/// Creates a new wallet with the default mnemonic and SHIMMER_COIN_TYPE, whose client sends all requests to the mock
+/// node instead of a real one. The wallet is stored in `storage_path` if the storage feature is enabled.
+#[cfg(feature = "test_utils")]
+#[allow(dead_code, unused_variables)]
+pub(crate) async fn make_mock_wallet(storage_path: &str, mock_node: &MockNode) -> Result<Wallet> {
+    #[allow(unused_mut)]
+    let mut wallet_builder = Wallet::builder()
+        .with_secret_manager(SecretManager::Mnemonic(MnemonicSecretManager::try_from_mnemonic(
+            DEFAULT_MNEMONIC,
+        )?))
+        .with_client_options(ClientOptions::new().with_mock_node(mock_node.clone())?)
+        .with_coin_type(SHIMMER_COIN_TYPE);
+    #[cfg(feature = "storage")]
+    {
+        wallet_builder = wallet_builder.with_storage_path(storage_path);
+    }
+
+    wallet_builder.finish().await
+}
+
 /// Create `amount` new accounts, request funds from the faucet and sync the accounts afterwards until the faucet output
 /// is available. Returns the new accounts.
 #[allow(dead_code)]
 pub(crate) async fn create_accounts_with_funds(wallet: &Wallet, amount: usize) -> Result<Vec<Account>> {
     let mut new_accounts = Vec::new();
-    'accounts: for _ in 0..amount {
+    for _ in 0..amount {
         let account = wallet.create_account().finish().await?;
-        request_funds_from_faucet(FAUCET_URL, &account.addresses().await?[0].address().to_string()).await?;
-
-        // Continue only after funds are received
-        for _ in 0..30 {
-            tokio::time::sleep(std::time::Duration::from_secs(2)).await;
-            let balance = account.sync(None).await?;
-            if balance.base_coin().available() > 0 {
-                new_accounts.push(account);
-                continue 'accounts;
-            }
-        }
-        panic!("Faucet no longer wants to hand over coins");
+        account
+            .request_funds_from_faucet(FAUCET_URL, std::time::Duration::from_secs(60))
+            .await?;
+        new_accounts.push(account);
     }
 
     Ok(new_accounts)
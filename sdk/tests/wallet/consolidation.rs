@@ -1,7 +1,10 @@
 // Copyright 2023 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use iota_sdk::wallet::{AddressWithAmount, Result};
+use iota_sdk::wallet::{
+    account::{DustProtection, DustRemainderAction},
+    AddressWithAmount, Result,
+};
 
 use crate::wallet::common::{create_accounts_with_funds, make_wallet, setup, tear_down};
 
@@ -46,3 +49,47 @@ async fn consolidation() -> Result<()> {
 
     tear_down(storage_path)
 }
+
+#[tokio::test]
+async fn updated_dust_protection() -> Result<()> {
+    let storage_path = "test-storage/updated_dust_protection";
+    setup(storage_path)?;
+
+    let wallet = make_wallet(storage_path, None, None).await?;
+    let account = wallet.create_account().finish().await?;
+
+    assert_eq!(account.dust_protection().await, DustProtection::default());
+    // Disabled by default
+    assert!(account.sweep_dust().await?.is_none());
+
+    let dust_protection = DustProtection {
+        min_remainder_amount: 100_000,
+        remainder_action: DustRemainderAction::AddToDepositReturn,
+        sweep_threshold: 50_000,
+    };
+    account.set_dust_protection(dust_protection).await?;
+    assert_eq!(account.dust_protection().await, dust_protection);
+
+    drop(account);
+    drop(wallet);
+
+    let wallet = make_wallet(storage_path, None, None).await?;
+    let account = wallet.get_account(0).await?;
+
+    assert_eq!(account.dust_protection().await, dust_protection);
+
+    tear_down(storage_path)
+}
+
+#[test]
+fn dust_protection_serde() {
+    // Accounts stored before the dust protection existed deserialize with the default
+    let dust_protection: DustProtection = serde_json::from_str("{}").unwrap();
+    assert_eq!(dust_protection, DustProtection::default());
+
+    let dust_protection: DustProtection =
+        serde_json::from_str(r#"{"minRemainderAmount":10,"remainderAction":"donateToRecipient"}"#).unwrap();
+    assert_eq!(dust_protection.min_remainder_amount, 10);
+    assert_eq!(dust_protection.remainder_action, DustRemainderAction::DonateToRecipient);
+    assert_eq!(dust_protection.sweep_threshold, 0);
+}
@@ -25,4 +25,10 @@ fn stringified_error() {
         &serde_json::to_string(&error).unwrap(),
         "{\"type\":\"failedToGetRemainder\",\"error\":\"failed to get remainder address\"}"
     );
+
+    let error = Error::from(iota_sdk::client::Error::HealthyNodePoolEmpty);
+    assert_eq!(
+        &serde_json::to_string(&error).unwrap(),
+        "{\"type\":\"client\",\"code\":\"client.healthyNodePoolEmpty\",\"error\":\"`no healthy node available`\"}"
+    );
 }
@@ -361,7 +361,8 @@ async fn address_conversion_methods() -> Result<()> {
     let response = wallet_handle
         .send_message(Message::HexToBech32 {
             hex: hex_address.into(),
-            bech32_hrp: None,
+            // Otherwise the HRP of the network is used, which is the default one without a node
+            bech32_hrp: Some("rms".into()),
         })
         .await;
 
@@ -0,0 +1,1327 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+#[cfg(feature = "events")]
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::{sync::Arc, time::Duration};
+
+use futures::StreamExt;
+#[cfg(feature = "events")]
+use iota_sdk::wallet::events::types::WalletEventType;
+use iota_sdk::{
+    client::{
+        api::PreparedTransactionData,
+        constants::SHIMMER_COIN_TYPE,
+        secret::{mnemonic::MnemonicSecretManager, EvmAddress, SecretManager},
+        test_utils::{Confirmation, MockNode},
+    },
+    types::block::{
+        address::{Address, AliasAddress, Bech32Address, Ed25519Address},
+        output::{
+            unlock_condition::{AddressUnlockCondition, ExpirationUnlockCondition, TimelockUnlockCondition},
+            AliasId, BasicOutputBuilder, NftId, NftOutputBuilder, Output, OutputId,
+        },
+        payload::{
+            milestone::option::ParametersMilestoneOption,
+            transaction::{TransactionEssence, TransactionId},
+        },
+        protocol::ProtocolParameters,
+    },
+    utils::unix_timestamp_now,
+    wallet::{
+        account::{
+            types::{AssetId, InclusionState},
+            verify_message_proof, AccountDetails, AddressGenerationOptions, AddressReuse, L2Deposit, OutgoingTransfer,
+            OutputDefaults, OutputOptions, PrivacyOptions, ReturnStrategy, SpendingPolicy, SpendingPolicyViolation,
+            StallAction, StorageDeposit, SyncOptions, TransactionApprover, TransactionOptions, Unlocks, WatchdogOptions,
+            WatchedOperation,
+        },
+        AddressWithAmount, ClientOptions, Error, OwnershipProof, Result, Wallet,
+    },
+};
+use packable::PackableExt;
+
+use crate::wallet::common::{make_mock_wallet, setup, tear_down, DEFAULT_MNEMONIC};
+
+#[tokio::test]
+async fn sync_and_send_with_mock_node() -> Result<()> {
+    let storage_path = "test-storage/sync_and_send_with_mock_node";
+    setup(storage_path)?;
+
+    let mock_node = MockNode::default();
+    let wallet = make_mock_wallet(storage_path, &mock_node).await?;
+
+    let account_0 = wallet.create_account().finish().await?;
+    let account_1 = wallet.create_account().finish().await?;
+    let address = *account_0.addresses().await?[0].address().inner();
+    mock_node.add_output(
+        BasicOutputBuilder::new_with_amount(2_000_000)
+            .add_unlock_condition(AddressUnlockCondition::new(address))
+            .finish_output(mock_node.protocol_parameters().token_supply())?,
+    );
+
+    let balance = account_0.sync(None).await?;
+    assert_eq!(balance.base_coin().available(), 2_000_000);
+
+    let amount = 1_000_000;
+    let transaction = account_0
+        .send_amount(
+            vec![AddressWithAmount::new(
+                account_1.addresses().await?[0].address().to_string(),
+                amount,
+            )],
+            None,
+        )
+        .await?;
+    // Confirmed by the milestone that is issued when the block is submitted
+    account_0
+        .retry_transaction_until_included(&transaction.transaction_id, None, None)
+        .await?;
+
+    let balance = account_1.sync(None).await?;
+    assert_eq!(balance.base_coin().available(), amount);
+    // The input was spent and only the remainder is left
+    let balance = account_0.sync(None).await?;
+    assert_eq!(balance.base_coin().total(), 2_000_000 - amount);
+    assert_eq!(mock_node.blocks().len(), 1);
+
+    tear_down(storage_path)
+}
+
+#[tokio::test]
+async fn send_with_unconfirmed_inputs() -> Result<()> {
+    let storage_path = "test-storage/send_with_unconfirmed_inputs";
+    setup(storage_path)?;
+
+    let mock_node = MockNode::default();
+    mock_node.set_confirmation(Confirmation::AfterMilestones(1));
+    let wallet = make_mock_wallet(storage_path, &mock_node).await?;
+
+    let account_0 = wallet.create_account().finish().await?;
+    let account_1 = wallet.create_account().finish().await?;
+    let address = *account_0.addresses().await?[0].address().inner();
+    mock_node.add_output(
+        BasicOutputBuilder::new_with_amount(3_000_000)
+            .add_unlock_condition(AddressUnlockCondition::new(address))
+            .finish_output(mock_node.protocol_parameters().token_supply())?,
+    );
+    account_0.sync(None).await?;
+
+    let amount = 1_000_000;
+    let outputs = vec![AddressWithAmount::new(
+        account_1.addresses().await?[0].address().to_string(),
+        amount,
+    )];
+    account_0.send_amount(outputs.clone(), None).await?;
+    // The only output is locked by the pending transaction
+    assert!(account_0.send_amount(outputs.clone(), None).await.is_err());
+
+    // Spends the remainder of the pending transaction
+    let transaction = account_0
+        .send_amount(
+            outputs,
+            TransactionOptions {
+                allow_unconfirmed_inputs: true,
+                ..Default::default()
+            },
+        )
+        .await?;
+    let blocks = mock_node.blocks();
+    assert_eq!(blocks.len(), 2);
+    assert!(blocks[1].parents().contains(&blocks[0].id()));
+
+    // Both transactions are confirmed in the order they were submitted
+    mock_node.issue_milestone();
+    account_0
+        .retry_transaction_until_included(&transaction.transaction_id, None, None)
+        .await?;
+
+    let balance = account_1.sync(None).await?;
+    assert_eq!(balance.base_coin().available(), 2 * amount);
+    let balance = account_0.sync(None).await?;
+    assert_eq!(balance.base_coin().total(), 3_000_000 - 2 * amount);
+    assert!(account_0.pending_transactions().await?.is_empty());
+
+    tear_down(storage_path)
+}
+
+#[cfg(feature = "storage")]
+#[tokio::test]
+async fn transaction_recovered_after_restart() -> Result<()> {
+    let storage_path = "test-storage/transaction_recovered_after_restart";
+    setup(storage_path)?;
+
+    let mock_node = MockNode::default();
+    mock_node.set_confirmation(Confirmation::Never);
+    let client_options = ClientOptions::new().with_mock_node(mock_node.clone())?;
+    let wallet = Wallet::builder()
+        .with_secret_manager(SecretManager::Mnemonic(MnemonicSecretManager::try_from_mnemonic(
+            DEFAULT_MNEMONIC,
+        )?))
+        .with_client_options(client_options.clone())
+        .with_coin_type(SHIMMER_COIN_TYPE)
+        .with_storage_path(storage_path)
+        .finish()
+        .await?;
+
+    let account = wallet.create_account().finish().await?;
+    let address = account.addresses().await?[0].address().clone();
+    mock_node.add_output(
+        BasicOutputBuilder::new_with_amount(2_000_000)
+            .add_unlock_condition(AddressUnlockCondition::new(*address.inner()))
+            .finish_output(mock_node.protocol_parameters().token_supply())?,
+    );
+    account.sync(None).await?;
+
+    let prepared_transaction_data = account
+        .prepare_send_amount(vec![AddressWithAmount::new(address.to_string(), 1_000_000)], None)
+        .await?;
+    // The wallet stops while the signed transaction is submitted
+    mock_node.set_response_delay(Duration::from_secs(5));
+    assert!(
+        tokio::time::timeout(
+            Duration::from_millis(500),
+            account.sign_and_submit_transaction(prepared_transaction_data),
+        )
+        .await
+        .is_err()
+    );
+    mock_node.set_response_delay(Duration::ZERO);
+    drop(account);
+    drop(wallet);
+
+    let wallet = Wallet::builder()
+        .with_secret_manager(SecretManager::Mnemonic(MnemonicSecretManager::try_from_mnemonic(
+            DEFAULT_MNEMONIC,
+        )?))
+        .with_client_options(client_options)
+        .with_coin_type(SHIMMER_COIN_TYPE)
+        .with_storage_path(storage_path)
+        .finish()
+        .await?;
+    let account = wallet.get_account(0).await?;
+
+    // The signed transaction is recovered from the journal and its input stays locked
+    let pending_transactions = account.pending_transactions().await?;
+    assert_eq!(pending_transactions.len(), 1);
+    assert!(pending_transactions[0].block_id.is_none());
+    assert_eq!(account.read().await.locked_outputs().len(), 1);
+    assert!(mock_node.blocks().is_empty());
+
+    // Syncing submits the recovered transaction
+    account.sync(None).await?;
+    assert_eq!(mock_node.blocks().len(), 1);
+    assert!(account.pending_transactions().await?[0].block_id.is_some());
+
+    tear_down(storage_path)
+}
+
+#[cfg(feature = "storage")]
+#[tokio::test]
+async fn resolve_unknown_pruned_transactions() -> Result<()> {
+    let storage_path = "test-storage/resolve_unknown_pruned_transactions";
+    setup(storage_path)?;
+
+    let mock_node = MockNode::default();
+    let client_options = ClientOptions::new().with_mock_node(mock_node.clone())?;
+    let wallet = Wallet::builder()
+        .with_secret_manager(SecretManager::Mnemonic(MnemonicSecretManager::try_from_mnemonic(
+            DEFAULT_MNEMONIC,
+        )?))
+        .with_client_options(client_options.clone())
+        .with_coin_type(SHIMMER_COIN_TYPE)
+        .with_storage_path(storage_path)
+        .finish()
+        .await?;
+
+    let account = wallet.create_account().finish().await?;
+    let address = account.addresses().await?[0].address().clone();
+    mock_node.add_output(
+        BasicOutputBuilder::new_with_amount(1_000_000)
+            .add_unlock_condition(AddressUnlockCondition::new(*address.inner()))
+            .finish_output(mock_node.protocol_parameters().token_supply())?,
+    );
+    account.sync(None).await?;
+
+    // Send everything away, so no output of the account references the transaction
+    let external_address = Bech32Address::new(
+        address.hrp().to_string(),
+        Address::Ed25519(Ed25519Address::new([1; 32])),
+    )?;
+    let transaction = account
+        .send_amount(vec![AddressWithAmount::new(external_address.to_string(), 1_000_000)], None)
+        .await?;
+
+    // The block got pruned by the node, while the input is spent
+    mock_node.inject_error("api/core/v2/blocks", 404, 1);
+    account.sync(None).await?;
+    assert_eq!(
+        account.get_transaction(&transaction.transaction_id).await.unwrap().inclusion_state,
+        InclusionState::UnknownPruned
+    );
+
+    let resolved_transactions = account.resolve_unknown_pruned_transactions().await?;
+    assert_eq!(resolved_transactions.len(), 1);
+    assert_eq!(resolved_transactions[0].inclusion_state, InclusionState::Confirmed);
+    drop(account);
+    drop(wallet);
+
+    // The resolved inclusion state was stored
+    let wallet = Wallet::builder()
+        .with_secret_manager(SecretManager::Mnemonic(MnemonicSecretManager::try_from_mnemonic(
+            DEFAULT_MNEMONIC,
+        )?))
+        .with_client_options(client_options)
+        .with_coin_type(SHIMMER_COIN_TYPE)
+        .with_storage_path(storage_path)
+        .finish()
+        .await?;
+    let account = wallet.get_account(0).await?;
+    let transaction = account.get_transaction(&transaction.transaction_id).await.unwrap();
+    assert_eq!(transaction.inclusion_state, InclusionState::Confirmed);
+    assert!(transaction.block_id.is_some());
+
+    tear_down(storage_path)
+}
+
+#[tokio::test]
+async fn account_freeze_address() -> Result<()> {
+    let storage_path = "test-storage/account_freeze_address";
+    setup(storage_path)?;
+
+    let mock_node = MockNode::default();
+    let wallet = make_mock_wallet(storage_path, &mock_node).await?;
+    let account = wallet.create_account().with_alias("Alice".to_string()).finish().await?;
+
+    let address = account.addresses().await?[0].address().clone();
+    let frozen_address = account.generate_addresses(1, None).await?[0].address().clone();
+    let token_supply = mock_node.protocol_parameters().token_supply();
+    let output_id = mock_node.add_output(
+        BasicOutputBuilder::new_with_amount(1_000_000)
+            .add_unlock_condition(AddressUnlockCondition::new(*address.inner()))
+            .finish_output(token_supply)?,
+    );
+    let frozen_output_id = mock_node.add_output(
+        BasicOutputBuilder::new_with_amount(2_000_000)
+            .add_unlock_condition(AddressUnlockCondition::new(*frozen_address.inner()))
+            .finish_output(token_supply)?,
+    );
+    account.sync(None).await?;
+    assert!(account.frozen_addresses().await?.is_empty());
+
+    account.freeze_address(&frozen_address).await?;
+    let frozen = account.frozen_addresses().await?;
+    assert_eq!(frozen.len(), 1);
+    assert_eq!(frozen[0].address(), &frozen_address);
+    assert!(*frozen[0].frozen());
+
+    let recipient = Bech32Address::new(
+        address.hrp().to_string(),
+        Address::Ed25519(Ed25519Address::new([1; 32])),
+    )?
+    .to_string();
+
+    // The output of the frozen address isn't selected, so only the other one can be spent
+    assert!(
+        account
+            .send_amount(vec![AddressWithAmount::new(recipient.clone(), 1_500_000)], None)
+            .await
+            .is_err()
+    );
+    // Custom inputs on a frozen address are rejected
+    assert!(matches!(
+        account
+            .send_amount(
+                vec![AddressWithAmount::new(recipient.clone(), 1_000_000)],
+                TransactionOptions {
+                    custom_inputs: Some(vec![frozen_output_id]),
+                    ..Default::default()
+                },
+            )
+            .await,
+        Err(Error::CustomInput(_))
+    ));
+    let transaction = account
+        .send_amount(vec![AddressWithAmount::new(recipient.clone(), 1_000_000)], None)
+        .await?;
+    let TransactionEssence::Regular(essence) = transaction.payload.essence();
+    assert_eq!(essence.inputs().len(), 1);
+    assert_eq!(*essence.inputs()[0].as_utxo().output_id(), output_id);
+
+    // Once unfrozen, its outputs can be spent again
+    account.unfreeze_address(&frozen_address).await?;
+    assert!(account.frozen_addresses().await?.is_empty());
+    account
+        .send_amount(
+            vec![AddressWithAmount::new(recipient, 1_000_000)],
+            TransactionOptions {
+                custom_inputs: Some(vec![frozen_output_id]),
+                ..Default::default()
+            },
+        )
+        .await?;
+
+    tear_down(storage_path)
+}
+
+#[tokio::test]
+async fn output_age_attestation() -> Result<()> {
+    let storage_path = "test-storage/output_age_attestation";
+    setup(storage_path)?;
+
+    let mock_node = MockNode::default();
+    let wallet = make_mock_wallet(storage_path, &mock_node).await?;
+
+    let account = wallet.create_account().finish().await?;
+    let address = *account.addresses().await?[0].address().inner();
+    for amount in [1_000_000, 2_000_000] {
+        mock_node.add_output(
+            BasicOutputBuilder::new_with_amount(amount)
+                .add_unlock_condition(AddressUnlockCondition::new(address))
+                .finish_output(mock_node.protocol_parameters().token_supply())?,
+        );
+    }
+    account.sync(None).await?;
+
+    let output_ages = account.output_ages().await?;
+    assert_eq!(output_ages.len(), 1);
+    assert_eq!(output_ages[0].amount, 3_000_000);
+    assert_eq!(output_ages[0].outputs.len(), 2);
+    assert_eq!(output_ages[0].amount_held_for(0), 3_000_000);
+    assert_eq!(output_ages[0].amount_held_for(u32::MAX), 0);
+
+    let challenge = b"airdrop";
+    let attestation = account.age_attestation(challenge).await?;
+    assert_eq!(attestation.addresses[0].amount, 3_000_000);
+    attestation.verify(challenge)?;
+    attestation.verify_outputs(account.client()).await?;
+    assert!(attestation.verify(b"another airdrop").is_err());
+
+    // Altered amounts invalidate the ownership proofs
+    let mut altered_attestation = attestation.clone();
+    altered_attestation.addresses[0].outputs[0].amount += 1;
+    altered_attestation.addresses[0].amount += 1;
+    assert!(altered_attestation.verify(challenge).is_err());
+
+    tear_down(storage_path)
+}
+
+#[tokio::test]
+async fn reserve_outputs() -> Result<()> {
+    let storage_path = "test-storage/reserve_outputs";
+    setup(storage_path)?;
+
+    let mock_node = MockNode::default();
+    let wallet = make_mock_wallet(storage_path, &mock_node).await?;
+
+    let account_0 = wallet.create_account().finish().await?;
+    let account_1 = wallet.create_account().finish().await?;
+    let address = *account_0.addresses().await?[0].address().inner();
+    for _ in 0..2 {
+        mock_node.add_output(
+            BasicOutputBuilder::new_with_amount(1_000_000)
+                .add_unlock_condition(AddressUnlockCondition::new(address))
+                .finish_output(mock_node.protocol_parameters().token_supply())?,
+        );
+    }
+    account_0.sync(None).await?;
+    let output_ids = account_0
+        .unspent_outputs(None)
+        .await?
+        .iter()
+        .map(|output_data| output_data.output_id)
+        .collect::<Vec<_>>();
+    let outputs = vec![AddressWithAmount::new(
+        account_1.addresses().await?[0].address().to_string(),
+        1_000_000,
+    )];
+
+    // Expired reservations are released
+    account_0.reserve_outputs(&output_ids, Duration::ZERO).await?;
+    assert!(account_0.reserved_outputs().await.is_empty());
+
+    account_0
+        .reserve_outputs(&output_ids, Duration::from_secs(3600))
+        .await?;
+    assert!(matches!(
+        account_0
+            .reserve_outputs(&output_ids[..1], Duration::from_secs(3600))
+            .await,
+        Err(Error::OutputReserved(_))
+    ));
+    assert!(account_0.prepare_send_amount(outputs.clone(), None).await.is_err());
+    account_0.release_outputs(&output_ids).await;
+
+    // Inputs of a prepared transaction are reserved until it's submitted
+    let prepared_transaction = account_0.prepare_send_amount(outputs.clone(), None).await?;
+    assert_eq!(account_0.reserved_outputs().await.len(), 1);
+    // Concurrent transactions select the other output
+    let (first_transaction, second_transaction) = tokio::join!(
+        account_0.sign_and_submit_transaction(prepared_transaction),
+        account_0.send_amount(outputs.clone(), None)
+    );
+    let (first_transaction, second_transaction) = (first_transaction?, second_transaction?);
+    assert_ne!(
+        first_transaction.inputs[0].metadata.output_id()?,
+        second_transaction.inputs[0].metadata.output_id()?
+    );
+    assert!(account_0.reserved_outputs().await.is_empty());
+
+    tear_down(storage_path)
+}
+
+#[tokio::test]
+async fn watchdog_aborts_stalled_sync() -> Result<()> {
+    let storage_path = "test-storage/watchdog_aborts_stalled_sync";
+    setup(storage_path)?;
+
+    let mock_node = MockNode::default();
+    let wallet = make_mock_wallet(storage_path, &mock_node).await?;
+    let account = wallet.create_account().finish().await?;
+
+    #[cfg(feature = "events")]
+    let stalled_events = {
+        let stalled_events = Arc::new(AtomicUsize::new(0));
+        let stalled_events_clone = stalled_events.clone();
+        wallet
+            .listen(vec![WalletEventType::OperationStalled], move |_event| {
+                stalled_events_clone.fetch_add(1, Ordering::SeqCst);
+            })
+            .await;
+        stalled_events
+    };
+    account
+        .set_watchdog_options(WatchdogOptions {
+            sync_timeout: Some(Duration::from_millis(100)),
+            action: StallAction::Retry(1),
+            ..Default::default()
+        })
+        .await;
+
+    // The sync is retried once and then aborted
+    mock_node.set_response_delay(Duration::from_secs(1));
+    assert!(matches!(
+        account.sync(None).await,
+        Err(Error::OperationStalled {
+            operation: WatchedOperation::Sync,
+            ..
+        })
+    ));
+    #[cfg(feature = "events")]
+    assert_eq!(stalled_events.load(Ordering::SeqCst), 2);
+    assert_eq!(account.health().await.consecutive_failures, 1);
+
+    mock_node.set_response_delay(Duration::ZERO);
+    account.sync(None).await?;
+    assert!(account.health().await.is_healthy());
+
+    tear_down(storage_path)
+}
+
+#[tokio::test]
+async fn transfer_between_accounts() -> Result<()> {
+    let storage_path = "test-storage/transfer_between_accounts";
+    setup(storage_path)?;
+
+    let mock_node = MockNode::default();
+    mock_node.set_confirmation(Confirmation::AfterMilestones(1));
+    let wallet = make_mock_wallet(storage_path, &mock_node).await?;
+
+    let account_0 = wallet.create_account().finish().await?;
+    let account_1 = wallet.create_account().finish().await?;
+    let address = *account_0.addresses().await?[0].address().inner();
+    mock_node.add_output(
+        BasicOutputBuilder::new_with_amount(2_000_000)
+            .add_unlock_condition(AddressUnlockCondition::new(address))
+            .finish_output(mock_node.protocol_parameters().token_supply())?,
+    );
+    account_0.sync(None).await?;
+
+    assert!(matches!(
+        wallet.transfer_between_accounts(0, 0, 1_000_000, None).await,
+        Err(Error::TransferToSameAccount(0))
+    ));
+
+    let transaction = wallet.transfer_between_accounts(0, 1, 1_000_000, None).await?;
+    assert!(!transaction.incoming);
+    // Known to the receiving account before it's synced
+    let incoming_transaction = account_1
+        .get_incoming_transaction_data(&transaction.transaction_id)
+        .await
+        .unwrap();
+    assert!(incoming_transaction.incoming);
+    assert_eq!(incoming_transaction.inclusion_state, InclusionState::Pending);
+
+    mock_node.issue_milestone();
+    let balance = account_1.sync(None).await?;
+    assert_eq!(balance.base_coin().available(), 1_000_000);
+    let incoming_transaction = account_1
+        .get_incoming_transaction_data(&transaction.transaction_id)
+        .await
+        .unwrap();
+    assert_eq!(incoming_transaction.inclusion_state, InclusionState::Confirmed);
+
+    tear_down(storage_path)
+}
+
+#[tokio::test]
+async fn balance_stream() -> Result<()> {
+    let storage_path = "test-storage/balance_stream";
+    setup(storage_path)?;
+
+    let mock_node = MockNode::default();
+    let wallet = make_mock_wallet(storage_path, &mock_node).await?;
+    let account = wallet.create_account().finish().await?;
+
+    let balance_stream = account.balance_stream();
+    futures::pin_mut!(balance_stream);
+    let balance = balance_stream.next().await.unwrap()?;
+    assert_eq!(balance.base_coin().total(), 0);
+
+    // A sync without changes doesn't yield a balance
+    account.sync(None).await?;
+    let address = *account.addresses().await?[0].address().inner();
+    mock_node.add_output(
+        BasicOutputBuilder::new_with_amount(2_000_000)
+            .add_unlock_condition(AddressUnlockCondition::new(address))
+            .finish_output(mock_node.protocol_parameters().token_supply())?,
+    );
+    account
+        .sync(Some(SyncOptions {
+            force_syncing: true,
+            ..Default::default()
+        }))
+        .await?;
+    let balance = tokio::time::timeout(Duration::from_secs(5), balance_stream.next())
+        .await
+        .expect("balance should be yielded after the sync")
+        .unwrap()?;
+    assert_eq!(balance.base_coin().total(), 2_000_000);
+
+    tear_down(storage_path)
+}
+
+#[tokio::test]
+async fn balance_with_timelock_and_expiration() -> Result<()> {
+    let storage_path = "test-storage/balance_with_timelock_and_expiration";
+    setup(storage_path)?;
+
+    let mock_node = MockNode::default();
+    let wallet = make_mock_wallet(storage_path, &mock_node).await?;
+    let account = wallet.create_account().finish().await?;
+
+    let address = *account.addresses().await?[0].address().inner();
+    let token_supply = mock_node.protocol_parameters().token_supply();
+    let in_an_hour = unix_timestamp_now().as_secs() as u32 + 3600;
+    mock_node.add_output(
+        BasicOutputBuilder::new_with_amount(1_000_000)
+            .add_unlock_condition(AddressUnlockCondition::new(address))
+            .finish_output(token_supply)?,
+    );
+    mock_node.add_output(
+        BasicOutputBuilder::new_with_amount(2_000_000)
+            .add_unlock_condition(AddressUnlockCondition::new(address))
+            .add_unlock_condition(TimelockUnlockCondition::new(in_an_hour)?)
+            .finish_output(token_supply)?,
+    );
+    mock_node.add_output(
+        BasicOutputBuilder::new_with_amount(3_000_000)
+            .add_unlock_condition(AddressUnlockCondition::new(address))
+            .add_unlock_condition(ExpirationUnlockCondition::new(
+                Address::Ed25519(Ed25519Address::new([1; 32])),
+                in_an_hour,
+            )?)
+            .finish_output(token_supply)?,
+    );
+
+    let balance = account.sync(None).await?;
+    assert_eq!(balance.base_coin().total(), 1_000_000);
+    assert_eq!(balance.base_coin().available(), 1_000_000);
+    assert_eq!(balance.base_coin().locked(), 2_000_000);
+    assert_eq!(balance.base_coin().expiring_soon(), 3_000_000);
+    assert_eq!(balance.potentially_locked_outputs().len(), 2);
+
+    tear_down(storage_path)
+}
+
+#[tokio::test]
+async fn wallet_health() -> Result<()> {
+    let storage_path = "test-storage/wallet_health";
+    setup(storage_path)?;
+
+    let mock_node = MockNode::default();
+    mock_node.set_confirmation(Confirmation::AfterMilestones(1));
+    let wallet = make_mock_wallet(storage_path, &mock_node).await?;
+    let account = wallet.create_account().finish().await?;
+
+    let health = wallet.health().await;
+    assert!(health.is_healthy());
+    assert!(health.nodes_healthy);
+    assert_eq!(health.stronghold_unlocked, None);
+    assert!(!health.background_syncing);
+    assert_eq!(health.pending_transactions, 0);
+
+    let address = *account.addresses().await?[0].address().inner();
+    mock_node.add_output(
+        BasicOutputBuilder::new_with_amount(2_000_000)
+            .add_unlock_condition(AddressUnlockCondition::new(address))
+            .finish_output(mock_node.protocol_parameters().token_supply())?,
+    );
+    account.sync(None).await?;
+    account
+        .send_amount(
+            vec![AddressWithAmount::new(
+                account.addresses().await?[0].address().to_string(),
+                1_000_000,
+            )],
+            None,
+        )
+        .await?;
+    assert_eq!(wallet.health().await.pending_transactions, 1);
+
+    wallet.start_background_syncing(None, None).await?;
+    assert!(wallet.health().await.background_syncing);
+    wallet.stop_background_syncing().await?;
+    assert!(!wallet.health().await.background_syncing);
+
+    tear_down(storage_path)
+}
+
+#[tokio::test]
+async fn max_sendable_amount() -> Result<()> {
+    let storage_path = "test-storage/max_sendable_amount";
+    setup(storage_path)?;
+
+    let mock_node = MockNode::default();
+    let wallet = make_mock_wallet(storage_path, &mock_node).await?;
+    let account_0 = wallet.create_account().finish().await?;
+    let account_1 = wallet.create_account().finish().await?;
+    let receiver = account_1.addresses().await?[0].address().to_string();
+
+    assert_eq!(account_0.max_sendable_amount(&receiver).await?, 0);
+
+    let address = *account_0.addresses().await?[0].address().inner();
+    let token_supply = mock_node.protocol_parameters().token_supply();
+    for amount in [1_000_000, 2_000_000] {
+        mock_node.add_output(
+            BasicOutputBuilder::new_with_amount(amount)
+                .add_unlock_condition(AddressUnlockCondition::new(address))
+                .finish_output(token_supply)?,
+        );
+    }
+    // Time locked outputs can't be sent yet
+    mock_node.add_output(
+        BasicOutputBuilder::new_with_amount(4_000_000)
+            .add_unlock_condition(AddressUnlockCondition::new(address))
+            .add_unlock_condition(TimelockUnlockCondition::new(unix_timestamp_now().as_secs() as u32 + 3600)?)
+            .finish_output(token_supply)?,
+    );
+    account_0.sync(None).await?;
+
+    let max_sendable_amount = account_0.max_sendable_amount(&receiver).await?;
+    assert_eq!(max_sendable_amount, 3_000_000);
+    account_0
+        .send_amount(vec![AddressWithAmount::new(receiver.clone(), max_sendable_amount)], None)
+        .await?;
+    assert_eq!(account_0.max_sendable_amount(&receiver).await?, 0);
+
+    tear_down(storage_path)
+}
+
+#[tokio::test]
+async fn asset_quarantine() -> Result<()> {
+    let storage_path = "test-storage/asset_quarantine";
+    setup(storage_path)?;
+
+    let mock_node = MockNode::default();
+    let wallet = make_mock_wallet(storage_path, &mock_node).await?;
+    let account = wallet.create_account().finish().await?;
+    account.set_asset_quarantine(true).await?;
+
+    let address = *account.addresses().await?[0].address().inner();
+    let token_supply = mock_node.protocol_parameters().token_supply();
+    mock_node.add_output(
+        BasicOutputBuilder::new_with_amount(1_000_000)
+            .add_unlock_condition(AddressUnlockCondition::new(address))
+            .finish_output(token_supply)?,
+    );
+    let nft_output_id = mock_node.add_output(
+        NftOutputBuilder::new_with_amount(1_000_000, NftId::null())
+            .add_unlock_condition(AddressUnlockCondition::new(address))
+            .finish_output(token_supply)?,
+    );
+    let nft_id = NftId::from(&nft_output_id);
+
+    // The received NFT isn't part of the balance until it's accepted
+    let balance = account.sync(None).await?;
+    assert!(balance.nfts().is_empty());
+    assert_eq!(balance.base_coin().total(), 1_000_000);
+    assert_eq!(
+        account.quarantined_assets().await,
+        [AssetId::Nft(nft_id)].into_iter().collect()
+    );
+
+    account.accept_asset(nft_id).await?;
+    let balance = account.balance().await?;
+    assert_eq!(balance.nfts(), &[nft_id]);
+    assert_eq!(balance.base_coin().total(), 2_000_000);
+    assert!(account.quarantined_assets().await.is_empty());
+
+    tear_down(storage_path)
+}
+
+#[derive(Debug)]
+struct RejectingApprover;
+
+#[async_trait::async_trait]
+impl TransactionApprover for RejectingApprover {
+    async fn approve(
+        &self,
+        _account: &AccountDetails,
+        _prepared_transaction_data: &PreparedTransactionData,
+        transfer: &OutgoingTransfer,
+    ) -> Result<bool> {
+        Ok(transfer.amount <= 1_000_000)
+    }
+}
+
+#[tokio::test]
+async fn spending_policy() -> Result<()> {
+    let storage_path = "test-storage/spending_policy";
+    setup(storage_path)?;
+
+    let mock_node = MockNode::default();
+    let wallet = make_mock_wallet(storage_path, &mock_node).await?;
+    let account_0 = wallet.create_account().finish().await?;
+    let account_1 = wallet.create_account().finish().await?;
+    let receiver = account_1.addresses().await?[0].address().clone();
+
+    let address = *account_0.addresses().await?[0].address().inner();
+    let token_supply = mock_node.protocol_parameters().token_supply();
+    for amount in [2_000_000, 3_000_000] {
+        mock_node.add_output(
+            BasicOutputBuilder::new_with_amount(amount)
+                .add_unlock_condition(AddressUnlockCondition::new(address))
+                .finish_output(token_supply)?,
+        );
+    }
+    account_0.sync(None).await?;
+
+    account_0
+        .set_spending_policy(SpendingPolicy {
+            max_amount_per_transaction: Some(2_000_000),
+            max_amount_per_day: Some(3_000_000),
+            ..Default::default()
+        })
+        .await;
+    let send = |amount| account_0.send_amount(vec![AddressWithAmount::new(receiver.to_string(), amount)], None);
+    assert!(matches!(
+        send(2_500_000).await,
+        Err(Error::SpendingPolicyViolation(
+            SpendingPolicyViolation::TransactionLimitExceeded {
+                amount: 2_500_000,
+                limit: 2_000_000
+            }
+        ))
+    ));
+    send(2_000_000).await?;
+    // The amount of the previous transaction counts for the daily limit, the remainder doesn't
+    assert!(matches!(
+        send(1_500_000).await,
+        Err(Error::SpendingPolicyViolation(
+            SpendingPolicyViolation::DailyLimitExceeded {
+                amount: 3_500_000,
+                limit: 3_000_000
+            }
+        ))
+    ));
+
+    account_0
+        .set_spending_policy(SpendingPolicy {
+            denied_addresses: [receiver.clone()].into_iter().collect(),
+            ..Default::default()
+        })
+        .await;
+    assert!(matches!(
+        send(1_000_000).await,
+        Err(Error::SpendingPolicyViolation(SpendingPolicyViolation::AddressDenied(
+            _
+        )))
+    ));
+
+    account_0.set_spending_policy(SpendingPolicy::default()).await;
+    account_0
+        .set_transaction_approver(Some(Arc::new(RejectingApprover)))
+        .await;
+    assert!(matches!(
+        send(1_500_000).await,
+        Err(Error::SpendingPolicyViolation(SpendingPolicyViolation::Rejected))
+    ));
+    send(1_000_000).await?;
+
+    tear_down(storage_path)
+}
+
+#[tokio::test]
+async fn watch_only_account() -> Result<()> {
+    let storage_path = "test-storage/watch_only_account";
+    setup(storage_path)?;
+
+    let mock_node = MockNode::default();
+    let wallet = make_mock_wallet(storage_path, &mock_node).await?;
+    let account_0 = wallet.create_account().finish().await?;
+    let watched_address = account_0.addresses().await?[0].address().clone();
+
+    let watch_only_account = wallet
+        .create_account()
+        .with_alias("auditor".to_string())
+        .with_watch_only_addresses([watched_address.clone()])
+        .finish()
+        .await?;
+    assert!(*watch_only_account.read().await.watch_only());
+
+    let token_supply = mock_node.protocol_parameters().token_supply();
+    mock_node.add_output(
+        BasicOutputBuilder::new_with_amount(1_000_000)
+            .add_unlock_condition(AddressUnlockCondition::new(*watched_address.inner()))
+            .finish_output(token_supply)?,
+    );
+
+    // The account can be synced, but not sign
+    let balance = watch_only_account.sync(None).await?;
+    assert_eq!(balance.base_coin().total(), 1_000_000);
+    let outputs = vec![AddressWithAmount::new(watched_address.to_string(), 1_000_000)];
+    assert!(matches!(
+        watch_only_account.send_amount(outputs, None).await,
+        Err(Error::WatchOnlyAccount(1))
+    ));
+    assert!(matches!(
+        watch_only_account.generate_addresses(1, None).await,
+        Err(Error::WatchOnlyAccount(1))
+    ));
+    // The inputs are unlocked again
+    assert!(watch_only_account.read().await.locked_outputs().is_empty());
+
+    // Accounts of the seed can still be created after a watch-only account
+    wallet.create_account().finish().await?;
+
+    tear_down(storage_path)
+}
+
+#[tokio::test]
+async fn privacy_options() -> Result<()> {
+    let storage_path = "test-storage/privacy_options";
+    setup(storage_path)?;
+
+    let mock_node = MockNode::default();
+    let wallet = make_mock_wallet(storage_path, &mock_node).await?;
+    let account_0 = wallet.create_account().finish().await?;
+    let account_1 = wallet.create_account().finish().await?;
+    account_0.generate_addresses(1, None).await?;
+
+    let token_supply = mock_node.protocol_parameters().token_supply();
+    for (address, amount) in account_0.addresses().await?.iter().zip([3_000_000, 2_000_000]) {
+        mock_node.add_output(
+            BasicOutputBuilder::new_with_amount(amount)
+                .add_unlock_condition(AddressUnlockCondition::new(*address.address().inner()))
+                .finish_output(token_supply)?,
+        );
+    }
+    account_0.sync(None).await?;
+
+    let options = PrivacyOptions {
+        address_reuse: AddressReuse::Refuse,
+        fresh_remainder_addresses: true,
+    };
+    account_0.set_privacy_options(options.clone()).await?;
+    assert_eq!(account_0.privacy_options().await, options);
+
+    // The first address already received funds
+    let address_options = AddressGenerationOptions {
+        start_index: Some(0),
+        ..Default::default()
+    };
+    assert!(matches!(
+        account_0.generate_addresses(1, Some(address_options)).await,
+        Err(Error::AddressReused(_))
+    ));
+
+    // Every transaction only uses the outputs of one address and the remainder goes to a new internal address
+    let receiver = account_1.addresses().await?[0].address().to_string();
+    let transactions = account_0
+        .send_amount_split(AddressWithAmount::new(receiver, 4_000_000), None)
+        .await?;
+    assert_eq!(transactions.len(), 2);
+    for transaction in &transactions {
+        account_0
+            .retry_transaction_until_included(&transaction.transaction_id, None, None)
+            .await?;
+    }
+
+    let balance = account_1.sync(None).await?;
+    assert_eq!(balance.base_coin().available(), 4_000_000);
+    account_0.sync(None).await?;
+    let unspent_outputs = account_0.unspent_outputs(None).await?;
+    assert_eq!(unspent_outputs.len(), 1);
+    assert_eq!(unspent_outputs[0].output.amount(), 1_000_000);
+    assert!(
+        account_0
+            .addresses()
+            .await?
+            .iter()
+            .any(|address| *address.internal() && address.address().inner() == &unspent_outputs[0].address)
+    );
+
+    tear_down(storage_path)
+}
+
+#[tokio::test]
+async fn send_to_l2() -> Result<()> {
+    let storage_path = "test-storage/send_to_l2";
+    setup(storage_path)?;
+
+    let mock_node = MockNode::default();
+    let wallet = make_mock_wallet(storage_path, &mock_node).await?;
+    let account = wallet.create_account().finish().await?;
+    let address = account.addresses().await?[0].address().clone();
+    mock_node.add_output(
+        BasicOutputBuilder::new_with_amount(2_000_000)
+            .add_unlock_condition(AddressUnlockCondition::new(*address.inner()))
+            .finish_output(mock_node.protocol_parameters().token_supply())?,
+    );
+    account.sync(None).await?;
+
+    let l2_receiver: EvmAddress = "0xf39Fd6e51aad88F6F4ce6aB8827279cffFb92266".parse()?;
+    let deposit = L2Deposit {
+        base_coins: 1_000_000,
+        ..Default::default()
+    };
+
+    // Only chains can receive deposits
+    assert!(matches!(
+        account
+            .send_to_l2(&address.to_string(), deposit.clone(), l2_receiver, 500_000, None)
+            .await,
+        Err(Error::InvalidChainAddress(_))
+    ));
+
+    let chain_address = Address::Alias(AliasAddress::new(AliasId::new([1; 32])));
+    let transaction = account
+        .send_to_l2(
+            &chain_address.to_bech32(address.hrp()),
+            deposit,
+            l2_receiver,
+            500_000,
+            None,
+        )
+        .await?;
+
+    let TransactionEssence::Regular(essence) = transaction.payload.essence();
+    let output = essence
+        .outputs()
+        .iter()
+        .find(|output| {
+            output
+                .unlock_conditions()
+                .and_then(|unlock_conditions| unlock_conditions.address())
+                .map_or(false, |unlock_condition| unlock_condition.address() == &chain_address)
+        })
+        .unwrap();
+    assert_eq!(output.amount(), 1_500_000);
+    let features = output.features().unwrap();
+    assert_eq!(features.sender().unwrap().address(), address.inner());
+    let metadata = features.metadata().unwrap().data();
+    // Sender contract, `accounts` contract and `transferAllowanceTo` entry point
+    assert_eq!(
+        &metadata[..12],
+        &[0, 0, 0, 0, 0x02, 0x5e, 0x4b, 0x3c, 0xa1, 0xe3, 0xf4, 0x23]
+    );
+    // Gas budget
+    assert_eq!(&metadata[12..20], &500_000u64.to_le_bytes());
+
+    tear_down(storage_path)
+}
+
+#[tokio::test]
+async fn output_defaults() -> Result<()> {
+    let storage_path = "test-storage/output_defaults";
+    setup(storage_path)?;
+
+    let mock_node = MockNode::default();
+    let wallet = make_mock_wallet(storage_path, &mock_node).await?;
+    let account = wallet.create_account().finish().await?;
+    let hrp = account.addresses().await?[0].address().hrp().to_string();
+    let recipient_address = Address::Ed25519(Ed25519Address::new([1; 32])).to_bech32(hrp);
+    let output_options = |storage_deposit, unlocks| OutputOptions {
+        recipient_address: recipient_address.clone(),
+        amount: 1,
+        assets: None,
+        features: None,
+        unlocks,
+        storage_deposit,
+    };
+
+    // The storage deposit is returned without expiration by default
+    assert_eq!(account.output_defaults().await, OutputDefaults::default());
+    let output = account.prepare_output(output_options(None, None), None).await?;
+    let unlock_conditions = output.unlock_conditions().unwrap();
+    assert!(unlock_conditions.storage_deposit_return().is_some());
+    assert!(unlock_conditions.expiration().is_none());
+
+    account
+        .set_output_defaults(OutputDefaults {
+            default_return_strategy: ReturnStrategy::Gift,
+            ..Default::default()
+        })
+        .await?;
+    let output = account.prepare_output(output_options(None, None), None).await?;
+    // Only the address unlock condition, the storage deposit is gifted
+    assert_eq!(output.unlock_conditions().unwrap().len(), 1);
+
+    // The return strategy of the options overrides the default
+    let output = account
+        .prepare_output(
+            output_options(
+                Some(StorageDeposit {
+                    return_strategy: Some(ReturnStrategy::Return),
+                    use_excess_if_low: None,
+                }),
+                None,
+            ),
+            None,
+        )
+        .await?;
+    assert!(output.unlock_conditions().unwrap().storage_deposit_return().is_some());
+
+    let default_expiration = 60 * 60 * 24;
+    account
+        .set_output_defaults(OutputDefaults {
+            default_expiration: Some(default_expiration),
+            default_return_strategy: ReturnStrategy::Return,
+        })
+        .await?;
+    let output = account.prepare_output(output_options(None, None), None).await?;
+    let unlock_conditions = output.unlock_conditions().unwrap();
+    assert!(unlock_conditions.storage_deposit_return().is_some());
+    let expiration = unlock_conditions.expiration().unwrap();
+    assert!(expiration.timestamp() > unix_timestamp_now().as_secs() as u32);
+    assert!(expiration.timestamp() <= unix_timestamp_now().as_secs() as u32 + default_expiration);
+
+    // The expiration of the options overrides the default
+    let output = account
+        .prepare_output(
+            output_options(
+                None,
+                Some(Unlocks {
+                    expiration_unix_time: Some(u32::MAX),
+                    ..Default::default()
+                }),
+            ),
+            None,
+        )
+        .await?;
+    assert_eq!(
+        output.unlock_conditions().unwrap().expiration().unwrap().timestamp(),
+        u32::MAX
+    );
+
+    // Gifted storage deposits don't expire
+    let output = account
+        .prepare_output(
+            output_options(
+                Some(StorageDeposit {
+                    return_strategy: Some(ReturnStrategy::Gift),
+                    use_excess_if_low: None,
+                }),
+                None,
+            ),
+            None,
+        )
+        .await?;
+    assert_eq!(output.unlock_conditions().unwrap().len(), 1);
+
+    tear_down(storage_path)
+}
+
+// Writes a full Hornet snapshot with the unspent outputs
+fn write_full_snapshot(path: &str, protocol_parameters: &ProtocolParameters, outputs: &[Output]) -> Result<()> {
+    let ledger_index = 100u32;
+    let mut bytes = vec![2, 0];
+    // Genesis milestone index, target milestone index, timestamp and id
+    bytes.extend(0u32.to_le_bytes());
+    bytes.extend(ledger_index.to_le_bytes());
+    bytes.extend(0u32.to_le_bytes());
+    bytes.extend([0; 32]);
+    bytes.extend(ledger_index.to_le_bytes());
+    // Treasury output
+    bytes.extend([0; 32]);
+    bytes.extend(0u64.to_le_bytes());
+    let mut parameters_option = vec![ParametersMilestoneOption::KIND];
+    parameters_option.extend(
+        ParametersMilestoneOption::new(
+            0.into(),
+            protocol_parameters.protocol_version(),
+            protocol_parameters.pack_to_vec(),
+        )?
+        .pack_to_vec(),
+    );
+    bytes.extend((parameters_option.len() as u16).to_le_bytes());
+    bytes.extend(parameters_option);
+    bytes.extend((outputs.len() as u64).to_le_bytes());
+    // Milestone diff and solid entry point count
+    bytes.extend(0u32.to_le_bytes());
+    bytes.extend(0u16.to_le_bytes());
+    for (index, output) in outputs.iter().enumerate() {
+        bytes.extend(OutputId::new(TransactionId::new([index as u8; 32]), 0)?.pack_to_vec());
+        bytes.extend([0; 32]);
+        bytes.extend(ledger_index.to_le_bytes());
+        bytes.extend((unix_timestamp_now().as_secs() as u32).to_le_bytes());
+        let output = output.pack_to_vec();
+        bytes.extend((output.len() as u32).to_le_bytes());
+        bytes.extend(output);
+    }
+
+    Ok(std::fs::write(path, bytes)?)
+}
+
+#[tokio::test]
+async fn import_hornet_snapshot() -> Result<()> {
+    let storage_path = "test-storage/import_hornet_snapshot";
+    let storage_path_import = "test-storage/import_hornet_snapshot_import";
+    setup(storage_path)?;
+    setup(storage_path_import)?;
+
+    let mock_node = MockNode::default();
+    let wallet = make_mock_wallet(storage_path, &mock_node).await?;
+    let account = wallet.create_account().finish().await?;
+    let address = *account.addresses().await?[0].address().inner();
+    // An address after a gap of unused addresses
+    let gap_address = *account.generate_addresses(3, None).await?[2].address().inner();
+
+    let protocol_parameters = mock_node.protocol_parameters();
+    let token_supply = protocol_parameters.token_supply();
+    let outputs = [
+        (address, 1_000_000),
+        (gap_address, 2_000_000),
+        (Address::Ed25519(Ed25519Address::new([1; 32])), 3_000_000),
+    ]
+    .into_iter()
+    .map(|(address, amount)| {
+        BasicOutputBuilder::new_with_amount(amount)
+            .add_unlock_condition(AddressUnlockCondition::new(address))
+            .finish_output(token_supply)
+    })
+    .collect::<std::result::Result<Vec<_>, _>>()?;
+    // The storage directory doesn't exist without a persistent storage
+    std::fs::create_dir_all(storage_path)?;
+    let snapshot_path = format!("{storage_path}/full_snapshot.bin");
+    write_full_snapshot(&snapshot_path, &protocol_parameters, &outputs)?;
+
+    // A new wallet instance with the same mnemonic only has the first address
+    let wallet_import = make_mock_wallet(storage_path_import, &mock_node).await?;
+    let account_import = wallet_import.create_account().finish().await?;
+
+    // Without address discovery only the output of the first address is imported
+    assert_eq!(
+        account_import.import_hornet_snapshot(&snapshot_path, &[], None).await?,
+        1
+    );
+    // With a gap limit the address after the gap is found
+    assert_eq!(
+        account_import
+            .import_hornet_snapshot(&snapshot_path, &[], Some(5))
+            .await?,
+        1
+    );
+    let unspent_outputs = account_import.unspent_outputs(None).await?;
+    assert_eq!(unspent_outputs.len(), 2);
+    assert_eq!(
+        unspent_outputs
+            .iter()
+            .map(|output_data| output_data.output.amount())
+            .sum::<u64>(),
+        3_000_000
+    );
+    assert!(
+        account_import
+            .addresses()
+            .await?
+            .iter()
+            .any(|account_address| account_address.address().inner() == &gap_address)
+    );
+
+    // Missing snapshot files fail
+    assert!(matches!(
+        account_import
+            .import_hornet_snapshot(format!("{storage_path}/missing.bin"), &[], None)
+            .await,
+        Err(Error::Io(_))
+    ));
+
+    tear_down(storage_path)?;
+    tear_down(storage_path_import)
+}
+
+#[tokio::test]
+async fn sign_message() -> Result<()> {
+    let storage_path = "test-storage/sign_message";
+    setup(storage_path)?;
+
+    let mock_node = MockNode::default();
+    let wallet = make_mock_wallet(storage_path, &mock_node).await?;
+    let account = wallet.create_account().finish().await?;
+    let addresses = account.generate_addresses(1, None).await?;
+    let address = addresses[0].address();
+
+    let message = b"I control this address, nonce 42";
+    let proof = account.sign_message(address, message).await?;
+    assert_eq!(&proof.address, address);
+    verify_message_proof(&proof, message)?;
+
+    // The proof can be sent as JSON
+    let proof: OwnershipProof = serde_json::from_str(&serde_json::to_string(&proof)?)?;
+    verify_message_proof(&proof, message)?;
+
+    // The proof is only valid for the signed message and address
+    assert!(verify_message_proof(&proof, b"another message").is_err());
+    let other_address = account.addresses().await?[0].address().clone();
+    let mut forged_proof = proof.clone();
+    forged_proof.address = other_address;
+    assert!(verify_message_proof(&forged_proof, message).is_err());
+
+    // Only addresses of the account can sign
+    let foreign_address = Bech32Address::new(
+        address.hrp().to_string(),
+        Address::Ed25519(Ed25519Address::new([1; 32])),
+    )?;
+    assert!(matches!(
+        account.sign_message(&foreign_address, message).await,
+        Err(Error::AddressNotFoundInAccount(_))
+    ));
+
+    tear_down(storage_path)
+}
+
+#[tokio::test]
+async fn stop_wallet() -> Result<()> {
+    let storage_path = "test-storage/stop_wallet";
+    setup(storage_path)?;
+
+    let mock_node = MockNode::default();
+    let wallet = make_mock_wallet(storage_path, &mock_node).await?;
+    let account = wallet.create_account().finish().await?;
+    account.sync(None).await?;
+
+    wallet.start_background_syncing(None, None).await?;
+    wallet.stop(Duration::from_secs(5)).await?;
+    assert!(!wallet.health().await.background_syncing);
+    assert!(account.client().cancellation_token().is_cancelled());
+
+    // The account can't be synced anymore
+    assert!(matches!(
+        account.sync(None).await,
+        Err(Error::Client(error)) if matches!(*error, iota_sdk::client::Error::Cancelled)
+    ));
+
+    tear_down(storage_path)
+}
@@ -14,8 +14,12 @@ mod consolidation;
 mod error;
 #[cfg(feature = "message_interface")]
 mod message_interface;
+#[cfg(feature = "test_utils")]
+mod mock_node;
 mod native_tokens;
 mod output_preparation;
+mod payment_reference;
+mod proof_of_reserves;
 mod syncing;
 mod transactions;
 #[allow(clippy::module_inception)]
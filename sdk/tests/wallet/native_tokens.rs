@@ -2,7 +2,8 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use iota_sdk::{
-    wallet::{account::SyncOptions, NativeTokenOptions, Result},
+    types::block::output::{feature::Irc30Metadata, FoundryId},
+    wallet::{account::SyncOptions, Error, NativeTokenOptions, Result, SendNativeTokensParams},
     U256,
 };
 
@@ -127,3 +128,141 @@ async fn native_token_foundry_metadata() -> Result<()> {
 
     tear_down(storage_path)
 }
+
+#[ignore]
+#[tokio::test]
+async fn native_token_irc_30_metadata() -> Result<()> {
+    let storage_path = "test-storage/native_token_irc_30_metadata";
+    setup(storage_path)?;
+
+    let wallet = make_wallet(storage_path, None, None).await?;
+
+    let account = &create_accounts_with_funds(&wallet, 1).await?[0];
+
+    let tx = account.create_alias_output(None, None).await?;
+    account
+        .retry_transaction_until_included(&tx.transaction_id, None, None)
+        .await?;
+    account.sync(None).await?;
+
+    let irc_30_metadata = Irc30Metadata::new("Test Token", "TST", 6);
+
+    let mint_tx = account
+        .mint_native_token(
+            NativeTokenOptions {
+                alias_id: None,
+                circulating_supply: U256::from(50),
+                maximum_supply: U256::from(100),
+                foundry_metadata: Some(irc_30_metadata.to_bytes()?),
+            },
+            None,
+        )
+        .await?;
+    account
+        .retry_transaction_until_included(&mint_tx.transaction.transaction_id, None, None)
+        .await?;
+
+    // Not synced yet, so the foundry is requested from the node and cached
+    assert_eq!(
+        account.get_native_token_metadata(mint_tx.token_id).await?,
+        Some(irc_30_metadata.clone())
+    );
+    assert!(
+        account
+            .read()
+            .await
+            .native_token_foundries()
+            .contains_key(&FoundryId::from(mint_tx.token_id))
+    );
+    assert_eq!(
+        account.client().foundry_metadata(FoundryId::from(mint_tx.token_id)).await?,
+        Some(irc_30_metadata.clone())
+    );
+
+    let balance = account.sync(None).await?;
+    let native_token_balance = balance
+        .native_tokens()
+        .iter()
+        .find(|t| t.token_id() == &mint_tx.token_id)
+        .unwrap();
+    assert_eq!(native_token_balance.irc_30_metadata(), &Some(irc_30_metadata));
+
+    tear_down(storage_path)
+}
+
+#[ignore]
+#[tokio::test]
+async fn send_native_tokens_with_change() -> Result<()> {
+    let storage_path = "test-storage/send_native_tokens_with_change";
+    setup(storage_path)?;
+
+    let wallet = make_wallet(storage_path, None, None).await?;
+
+    let account_0 = &create_accounts_with_funds(&wallet, 1).await?[0];
+    let account_1 = wallet.create_account().finish().await?;
+
+    let tx = account_0.create_alias_output(None, None).await?;
+    account_0
+        .retry_transaction_until_included(&tx.transaction_id, None, None)
+        .await?;
+    account_0.sync(None).await?;
+
+    let mint_tx = account_0
+        .mint_native_token(
+            NativeTokenOptions {
+                alias_id: None,
+                circulating_supply: U256::from(100),
+                maximum_supply: U256::from(100),
+                foundry_metadata: None,
+            },
+            None,
+        )
+        .await?;
+    account_0
+        .retry_transaction_until_included(&mint_tx.transaction.transaction_id, None, None)
+        .await?;
+    account_0.sync(None).await?;
+
+    // Amounts of the same token are summed up
+    let tx = account_0
+        .send_native_tokens(
+            vec![SendNativeTokensParams {
+                address: account_1.addresses().await?[0].address().to_string(),
+                native_tokens: vec![(mint_tx.token_id, U256::from(20)), (mint_tx.token_id, U256::from(10))],
+                gift_storage_deposit: true,
+                ..Default::default()
+            }],
+            None,
+        )
+        .await?;
+    account_0
+        .retry_transaction_until_included(&tx.transaction_id, None, None)
+        .await?;
+
+    // The tokens that weren't sent are in the remainder
+    let balance = account_0.sync(None).await?;
+    assert_eq!(balance.native_tokens()[0].available(), U256::from(70));
+    // The gifted output doesn't need to be claimed
+    let balance = account_1.sync(None).await?;
+    assert_eq!(balance.native_tokens()[0].available(), U256::from(30));
+    assert!(balance.potentially_locked_outputs().is_empty());
+
+    let error = account_0
+        .send_native_tokens(
+            vec![SendNativeTokensParams {
+                address: account_1.addresses().await?[0].address().to_string(),
+                native_tokens: vec![(mint_tx.token_id, U256::from(71))],
+                ..Default::default()
+            }],
+            None,
+        )
+        .await
+        .unwrap_err();
+    assert!(matches!(
+        error,
+        Error::InsufficientNativeTokens { token_id, available, required }
+            if token_id == mint_tx.token_id && available == U256::from(70) && required == U256::from(71)
+    ));
+
+    tear_down(storage_path)
+}
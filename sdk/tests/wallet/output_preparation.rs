@@ -10,7 +10,7 @@ use iota_sdk::{
     },
     wallet::{
         account::{Assets, Features, OutputOptions, ReturnStrategy, StorageDeposit, Unlocks},
-        NftOptions, Result,
+        Error, NftOptions, Result,
     },
     U256,
 };
@@ -368,6 +368,7 @@ async fn output_preparation() -> Result<()> {
                 unlocks: Some(Unlocks {
                     expiration_unix_time: Some(1),
                     timelock_unix_time: None,
+                    expiration_in_seconds: None,
                 }),
                 storage_deposit: None,
             },
@@ -492,6 +493,57 @@ async fn output_preparation_sdr() -> Result<()> {
     tear_down(storage_path)
 }
 
+#[tokio::test]
+async fn output_preparation_invalid_unlocks() -> Result<()> {
+    let storage_path = "test-storage/output_preparation_invalid_unlocks";
+    setup(storage_path)?;
+
+    let wallet = make_wallet(storage_path, None, None).await?;
+    let account = wallet.create_account().finish().await?;
+
+    let recipient_address_bech32 = String::from("rms1qpszqzadsym6wpppd6z037dvlejmjuke7s24hm95s9fg9vpua7vluaw60xu");
+    // Roundtrip to get the correct bech32 HRP
+    let recipient_address =
+        Address::try_from_bech32(&recipient_address_bech32)?.to_bech32(account.client().get_bech32_hrp().await?);
+
+    let output_options = |unlocks| OutputOptions {
+        recipient_address: recipient_address.clone(),
+        amount: 1_000_000,
+        assets: None,
+        features: None,
+        unlocks: Some(unlocks),
+        storage_deposit: None,
+    };
+
+    // Expiration before the timelock ends
+    let result = account
+        .prepare_output(
+            output_options(Unlocks {
+                expiration_unix_time: Some(1_000),
+                timelock_unix_time: Some(2_000),
+                expiration_in_seconds: None,
+            }),
+            None,
+        )
+        .await;
+    assert!(matches!(result, Err(Error::InvalidOutputOptions(_))));
+
+    // Absolute and relative expiration
+    let result = account
+        .prepare_output(
+            output_options(Unlocks {
+                expiration_unix_time: Some(1_000),
+                timelock_unix_time: None,
+                expiration_in_seconds: Some(3_600),
+            }),
+            None,
+        )
+        .await;
+    assert!(matches!(result, Err(Error::InvalidOutputOptions(_))));
+
+    tear_down(storage_path)
+}
+
 #[ignore]
 #[tokio::test]
 async fn prepare_nft_output_features_update() -> Result<()> {
@@ -0,0 +1,61 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::str::FromStr;
+
+use iota_sdk::wallet::{account::types::PaymentReference, Result};
+
+use crate::wallet::common::{make_wallet, setup, tear_down};
+
+#[test]
+fn payment_reference_check_digits() {
+    let reference = PaymentReference::new("539007547034").unwrap();
+    assert_eq!(reference.to_string(), "RF18539007547034");
+    assert_eq!(reference.reference(), "539007547034");
+
+    // Spaces and lowercase are accepted
+    assert_eq!(PaymentReference::from_str("rf18 5390 0754 7034").unwrap(), reference);
+    // Wrong check digits
+    assert!(PaymentReference::from_str("RF19539007547034").is_err());
+    // Typo in the reference
+    assert!(PaymentReference::from_str("RF18539007547035").is_err());
+    assert!(PaymentReference::from_str("539007547034").is_err());
+    assert!(PaymentReference::new("").is_err());
+    assert!(PaymentReference::new("invoice-1").is_err());
+    assert!(PaymentReference::new("1234567890123456789012").is_err());
+}
+
+#[test]
+fn payment_reference_generate() {
+    let reference = PaymentReference::generate(b"invoice 1");
+    assert_eq!(reference, PaymentReference::generate(b"invoice 1"));
+    assert_ne!(reference, PaymentReference::generate(b"invoice 2"));
+    assert_eq!(reference.reference().len(), PaymentReference::REFERENCE_LENGTH_MAX);
+    assert_eq!(PaymentReference::from_str(&reference.to_string()).unwrap(), reference);
+
+    let tag = reference.to_tag_feature().unwrap();
+    assert_eq!(PaymentReference::from_bytes(tag.tag()), Some(reference.clone()));
+
+    let json = serde_json::to_string(&reference).unwrap();
+    assert_eq!(serde_json::from_str::<PaymentReference>(&json).unwrap(), reference);
+    assert!(serde_json::from_str::<PaymentReference>("\"RF00123\"").is_err());
+}
+
+#[tokio::test]
+async fn expected_payment_references() -> Result<()> {
+    let storage_path = "test-storage/expected_payment_references";
+    setup(storage_path)?;
+
+    let wallet = make_wallet(storage_path, None, None).await?;
+    let account = wallet.create_account().with_alias("Alice".to_string()).finish().await?;
+
+    let reference = PaymentReference::new("INVOICE1")?;
+    account.add_expected_payment_reference(reference.clone()).await?;
+    assert!(account.expected_payment_references().await.contains(&reference));
+
+    assert!(account.remove_expected_payment_reference(&reference).await?);
+    assert!(!account.remove_expected_payment_reference(&reference).await?);
+    assert!(account.expected_payment_references().await.is_empty());
+
+    tear_down(storage_path)
+}
@@ -0,0 +1,67 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use iota_sdk::wallet::proof_of_reserves::{CustomerBalance, MerkleSumNode, MerkleSumTree};
+
+fn customer_balances(amount: u64) -> Vec<CustomerBalance> {
+    (0..amount)
+        .map(|i| CustomerBalance::new(format!("customer{i}"), format!("nonce{i}"), i * 1_000))
+        .collect()
+}
+
+#[test]
+fn merkle_sum_tree_inclusion_proofs() {
+    for amount in 1..=9 {
+        let tree = MerkleSumTree::new(customer_balances(amount)).unwrap();
+        assert_eq!(tree.total_liabilities(), (0..amount).map(|i| i * 1_000).sum::<u64>());
+
+        let proofs = tree.inclusion_proofs();
+        assert_eq!(proofs.len() as u64, amount);
+        for proof in proofs {
+            proof.verify(&tree.root()).unwrap();
+        }
+    }
+}
+
+#[test]
+fn merkle_sum_tree_invalid_proofs() {
+    let tree = MerkleSumTree::new(customer_balances(5)).unwrap();
+    assert!(tree.inclusion_proof("unknown").is_none());
+
+    // Changed balance
+    let mut proof = tree.inclusion_proof("customer3").unwrap();
+    proof.customer_balance.balance -= 1;
+    assert!(proof.verify(&tree.root()).is_err());
+
+    // Changed sum of a sibling
+    let mut proof = tree.inclusion_proof("customer1").unwrap();
+    proof.path[0].sibling.sum += 1;
+    assert!(proof.verify(&tree.root()).is_err());
+
+    // Root from another tree
+    let other_tree = MerkleSumTree::new(customer_balances(6)).unwrap();
+    let proof = tree.inclusion_proof("customer0").unwrap();
+    assert!(proof.verify(&other_tree.root()).is_err());
+
+    let json = serde_json::to_string(&tree.root()).unwrap();
+    assert_eq!(serde_json::from_str::<MerkleSumNode>(&json).unwrap(), tree.root());
+}
+
+#[test]
+fn merkle_sum_tree_invalid_balances() {
+    assert!(MerkleSumTree::new(Vec::new()).is_err());
+    assert!(
+        MerkleSumTree::new(vec![
+            CustomerBalance::new("customer", "nonce1", 1),
+            CustomerBalance::new("customer", "nonce2", 2)
+        ])
+        .is_err()
+    );
+    assert!(
+        MerkleSumTree::new(vec![
+            CustomerBalance::new("customer1", "nonce", u64::MAX),
+            CustomerBalance::new("customer2", "nonce", 1)
+        ])
+        .is_err()
+    );
+}
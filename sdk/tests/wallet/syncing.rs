@@ -2,6 +2,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use iota_sdk::{
+    client::Client,
     types::block::output::{
         unlock_condition::{
             AddressUnlockCondition, ExpirationUnlockCondition, GovernorAddressUnlockCondition,
@@ -9,11 +10,37 @@ use iota_sdk::{
         },
         AliasId, AliasOutputBuilder, BasicOutputBuilder, NftId, NftOutputBuilder, UnlockCondition,
     },
-    wallet::{account::SyncOptions, Result},
+    wallet::{
+        account::{SyncOptions, SyncToken, UtxoSnapshot},
+        AddressWithAmount, Result,
+    },
 };
 
 use crate::wallet::common::{create_accounts_with_funds, make_wallet, setup, tear_down};
 
+#[tokio::test]
+async fn failed_sync_updates_health() -> Result<()> {
+    let storage_path = "test-storage/failed_sync_updates_health";
+    setup(storage_path)?;
+
+    // No node is listening on this port, so syncing fails
+    let wallet = make_wallet(storage_path, None, Some("http://127.0.0.1:1")).await?;
+    let account = wallet.create_account().finish().await?;
+    assert!(account.health().await.is_healthy());
+
+    assert!(account.sync(None).await.is_err());
+    let health = account.health().await;
+    assert!(!health.is_healthy());
+    assert_eq!(health.account_index, 0);
+    assert_eq!(health.consecutive_failures, 1);
+    assert!(health.last_error.is_some());
+    assert!(health.last_failed_sync.is_some());
+    assert!(health.last_successful_sync.is_none());
+    assert_eq!(wallet.get_accounts_health().await, vec![health]);
+
+    tear_down(storage_path)
+}
+
 #[tokio::test]
 async fn updated_default_sync_options() -> Result<()> {
     let storage_path = "test-storage/updated_default_sync_options";
@@ -147,3 +174,129 @@ async fn sync_only_most_basic_outputs() -> Result<()> {
 
     tear_down(storage_path)
 }
+
+#[ignore]
+#[tokio::test]
+async fn sync_from_utxo_snapshot() -> Result<()> {
+    let storage_path = "test-storage/sync_from_utxo_snapshot";
+    let storage_path_snapshot = "test-storage/sync_from_utxo_snapshot_import";
+    setup(storage_path)?;
+    setup(storage_path_snapshot)?;
+
+    let mnemonic = Client::generate_mnemonic()?;
+    let wallet = make_wallet(storage_path, Some(&mnemonic), None).await?;
+    let account = &create_accounts_with_funds(&wallet, 1).await?[0];
+    let balance = account.sync(None).await?;
+
+    let snapshot_file = format!("{storage_path}/snapshot.json");
+    account.utxo_snapshot().await?.write_to_file(&snapshot_file)?;
+    let snapshot = UtxoSnapshot::read_from_file(&snapshot_file)?;
+    assert_eq!(snapshot.outputs.len(), account.unspent_outputs(None).await?.len());
+
+    // A new wallet instance with the same mnemonic imports the outputs and only verifies them with the sync
+    let wallet_snapshot = make_wallet(storage_path_snapshot, Some(&mnemonic), None).await?;
+    let account_snapshot = wallet_snapshot.create_account().finish().await?;
+    let imported = account_snapshot.import_utxo_snapshot(&snapshot).await?;
+    assert_eq!(imported, snapshot.outputs.len());
+    assert_eq!(account_snapshot.balance().await?, balance);
+
+    let balance_snapshot = account_snapshot.sync(None).await?;
+    assert_eq!(balance_snapshot.base_coin(), balance.base_coin());
+    // Importing again doesn't import known outputs
+    assert_eq!(account_snapshot.import_utxo_snapshot(&snapshot).await?, 0);
+
+    tear_down(storage_path)?;
+    tear_down(storage_path_snapshot)
+}
+
+#[ignore]
+#[tokio::test]
+async fn verify_integrity() -> Result<()> {
+    let storage_path = "test-storage/verify_integrity";
+    let storage_path_other = "test-storage/verify_integrity_other";
+    setup(storage_path)?;
+    setup(storage_path_other)?;
+
+    let mnemonic = Client::generate_mnemonic()?;
+    let wallet = make_wallet(storage_path, Some(&mnemonic), None).await?;
+    let account = &create_accounts_with_funds(&wallet, 1).await?[0];
+    account.sync(None).await?;
+
+    let report = account.verify_integrity().await?;
+    assert!(report.is_consistent());
+    assert_eq!(report.checked_outputs, account.outputs(None).await?.len());
+
+    // Another wallet instance with the same mnemonic spends the outputs, so the local state of the first one diverges
+    let wallet_other = make_wallet(storage_path_other, Some(&mnemonic), None).await?;
+    let account_other = wallet_other.create_account().finish().await?;
+    account_other.sync(None).await?;
+    let address = account_other.addresses().await?[0].address().to_string();
+    for tx in account_other.send_all(&address, None).await? {
+        account_other
+            .retry_transaction_until_included(&tx.transaction_id, None, None)
+            .await?;
+    }
+
+    let unspent_output_ids = account
+        .unspent_outputs(None)
+        .await?
+        .into_iter()
+        .map(|output_data| output_data.output_id)
+        .collect::<Vec<_>>();
+    let report = account.verify_integrity().await?;
+    assert!(!report.is_consistent());
+    assert_eq!(report.marked_spent.len(), unspent_output_ids.len());
+    assert!(account.unspent_outputs(None).await?.is_empty());
+    // Already repaired
+    assert!(account.verify_integrity().await?.is_consistent());
+
+    tear_down(storage_path)?;
+    tear_down(storage_path_other)
+}
+
+#[ignore]
+#[tokio::test]
+async fn changes_since_sync_token() -> Result<()> {
+    let storage_path = "test-storage/changes_since_sync_token";
+    setup(storage_path)?;
+
+    let wallet = make_wallet(storage_path, None, None).await?;
+    let account = &create_accounts_with_funds(&wallet, 1).await?[0];
+    let balance = account.sync(None).await?;
+
+    // All outputs are new for the default token
+    let changes = account.changes_since(&SyncToken::default()).await?;
+    assert_eq!(changes.new_outputs.len(), account.outputs(None).await?.len());
+    assert_eq!(changes.total_balance_delta, balance.base_coin().total() as i128);
+    // Nothing changed since the returned token
+    let sync_token = changes.sync_token;
+    assert!(account.changes_since(&sync_token).await?.is_empty());
+
+    let tx = account
+        .send_amount(
+            vec![AddressWithAmount::new(
+                account.addresses().await?[0].address().to_string(),
+                1_000_000,
+            )],
+            None,
+        )
+        .await?;
+    account
+        .retry_transaction_until_included(&tx.transaction_id, None, None)
+        .await?;
+    account.sync(None).await?;
+
+    let changes = account.changes_since(&sync_token).await?;
+    assert!(!changes.spent_outputs.is_empty());
+    assert!(!changes.new_outputs.is_empty());
+    assert!(
+        changes
+            .new_transactions
+            .iter()
+            .any(|transaction| transaction.transaction_id == tx.transaction_id)
+    );
+    // Sent to ourself
+    assert_eq!(changes.total_balance_delta, 0);
+
+    tear_down(storage_path)
+}
@@ -1,7 +1,19 @@
 // Copyright 2023 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use iota_sdk::wallet::{account::TransactionOptions, AddressAndNftId, AddressWithAmount, NftOptions, Result};
+use iota_sdk::{
+    types::block::{
+        address::{Address, NftAddress},
+        output::{feature::Irc27Metadata, NftId, Output, OutputId},
+        payload::transaction::TransactionEssence,
+        Error as BlockError,
+    },
+    utils::{format::Formatter, payment_uri::PaymentUri},
+    wallet::{
+        account::{InclusionStats, SendAllOptions, TransactionOptions, TransactionSummary},
+        AddressWithAmount, Error, NftOptions, Result, SendNftParams,
+    },
+};
 
 use crate::wallet::common::{create_accounts_with_funds, make_wallet, setup, tear_down};
 
@@ -37,6 +49,272 @@ async fn send_amount() -> Result<()> {
     tear_down(storage_path)
 }
 
+#[ignore]
+#[tokio::test]
+async fn send_payment_uri() -> Result<()> {
+    let storage_path = "test-storage/send_payment_uri";
+    setup(storage_path)?;
+
+    let wallet = make_wallet(storage_path, None, None).await?;
+
+    let account_0 = &create_accounts_with_funds(&wallet, 1).await?[0];
+    let account_1 = wallet.create_account().finish().await?;
+
+    let amount = 1_000_000;
+    let payment_uri = PaymentUri::new(account_1.addresses().await?[0].address().clone())
+        .with_amount(amount)
+        .with_tag("invoice 42".to_string());
+    let prepared_transaction = account_0.prepare_payment_uri(&payment_uri.to_string(), None).await?;
+    let tx = account_0.sign_and_submit_transaction(prepared_transaction).await?;
+
+    account_0
+        .retry_transaction_until_included(&tx.transaction_id, None, None)
+        .await?;
+
+    let balance = account_1.sync(None).await.unwrap();
+    assert_eq!(balance.base_coin().available(), amount);
+
+    let unspent_outputs = account_1.unspent_outputs(None).await?;
+    let features = unspent_outputs[0].output.features().unwrap();
+    assert_eq!(features.tag().unwrap().tag(), b"invoice 42");
+
+    tear_down(storage_path)
+}
+
+#[ignore]
+#[tokio::test]
+async fn transaction_confirmations() -> Result<()> {
+    let storage_path = "test-storage/transaction_confirmations";
+    setup(storage_path)?;
+
+    let wallet = make_wallet(storage_path, None, None).await?;
+
+    let account = &create_accounts_with_funds(&wallet, 1).await?[0];
+
+    let tx = account
+        .send_amount(
+            vec![AddressWithAmount::new(
+                account.addresses().await?[0].address().to_string(),
+                1_000_000,
+            )],
+            None,
+        )
+        .await?;
+    // Pending transactions have no confirmations
+    assert_eq!(tx.confirmations(u32::MAX), None);
+    assert_eq!(account.transaction_confirmations(&tx.transaction_id).await?, None);
+
+    account
+        .retry_transaction_until_included(&tx.transaction_id, None, None)
+        .await?;
+    account.sync(None).await?;
+
+    let transaction = account.get_transaction(&tx.transaction_id).await.unwrap();
+    let milestone_index = transaction.milestone_index.unwrap();
+    assert_eq!(transaction.confirmations(milestone_index), Some(1));
+    assert_eq!(transaction.confirmations(milestone_index + 2), Some(3));
+    assert_eq!(transaction.confirmations(milestone_index - 1), None);
+    assert!(account.transaction_confirmations(&tx.transaction_id).await?.unwrap() >= 1);
+
+    tear_down(storage_path)
+}
+
+#[ignore]
+#[tokio::test]
+async fn inclusion_stats() -> Result<()> {
+    let storage_path = "test-storage/inclusion_stats";
+    setup(storage_path)?;
+
+    let wallet = make_wallet(storage_path, None, None).await?;
+
+    let account = &create_accounts_with_funds(&wallet, 1).await?[0];
+    assert_eq!(account.inclusion_stats().await, InclusionStats::default());
+
+    let tx = account
+        .send_amount(
+            vec![AddressWithAmount::new(
+                account.addresses().await?[0].address().to_string(),
+                1_000_000,
+            )],
+            None,
+        )
+        .await?;
+    let stats = account.inclusion_stats().await;
+    assert_eq!(stats.confirmed_transactions, 0);
+    assert_eq!(stats.pending_transactions, 1);
+    assert_eq!(stats.median_latency, None);
+
+    account
+        .retry_transaction_until_included(&tx.transaction_id, None, None)
+        .await?;
+    account.sync(None).await?;
+
+    let stats = account.inclusion_stats().await;
+    assert_eq!(stats.confirmed_transactions, 1);
+    assert_eq!(stats.pending_transactions, 0);
+    assert!(stats.min_latency.unwrap() > 0);
+    assert_eq!(stats.min_latency, stats.max_latency);
+    assert_eq!(stats.median_latency, stats.p90_latency);
+    assert_eq!(wallet.inclusion_stats().await?, stats);
+
+    tear_down(storage_path)
+}
+
+#[ignore]
+#[tokio::test]
+async fn send_amount_batched_rollback() -> Result<()> {
+    let storage_path = "test-storage/send_amount_batched_rollback";
+    setup(storage_path)?;
+
+    let wallet = make_wallet(storage_path, None, None).await?;
+
+    let account_0 = &create_accounts_with_funds(&wallet, 1).await?[0];
+    let account_1 = wallet.create_account().finish().await?;
+    let available = account_0.balance().await?.base_coin().available();
+
+    // The funds are in a single output, so the second transaction can't get inputs and nothing is sent
+    let address = account_1.addresses().await?[0].address().to_string();
+    let addresses_with_amount = (0..130)
+        .map(|_| AddressWithAmount::new(address.clone(), 1_000_000))
+        .collect::<Vec<_>>();
+    assert!(account_0.send_amount_batched(addresses_with_amount, None).await.is_err());
+
+    let balance = account_0.balance().await?;
+    assert_eq!(balance.base_coin().available(), available);
+    assert!(account_0.pending_transactions().await?.is_empty());
+
+    tear_down(storage_path)
+}
+
+#[ignore]
+#[tokio::test]
+async fn send_all() -> Result<()> {
+    let storage_path = "test-storage/send_all";
+    setup(storage_path)?;
+
+    let wallet = make_wallet(storage_path, None, None).await?;
+
+    let account_0 = &create_accounts_with_funds(&wallet, 1).await?[0];
+    let account_1 = wallet.create_account().finish().await?;
+    let funds = account_0.balance().await?.base_coin().available();
+
+    let transactions = account_0
+        .send_all(
+            &account_1.addresses().await?[0].address().to_string(),
+            SendAllOptions {
+                include_nfts: true,
+                ..Default::default()
+            },
+        )
+        .await?;
+    for transaction in &transactions {
+        account_0
+            .retry_transaction_until_included(&transaction.transaction_id, None, None)
+            .await?;
+    }
+
+    let balance = account_0.sync(None).await?;
+    assert_eq!(balance.base_coin().available(), 0);
+    let balance = account_1.sync(None).await?;
+    assert_eq!(balance.base_coin().available(), funds);
+
+    tear_down(storage_path)
+}
+
+#[ignore]
+#[tokio::test]
+async fn send_micro_amount() -> Result<()> {
+    let storage_path = "test-storage/send_micro_amount";
+    setup(storage_path)?;
+
+    let wallet = make_wallet(storage_path, None, None).await?;
+
+    let account_0 = &create_accounts_with_funds(&wallet, 1).await?[0];
+    let account_1 = wallet.create_account().finish().await?;
+
+    let addresses_with_amount = vec![AddressWithAmount::new(
+        account_1.addresses().await?[0].address().to_string(),
+        1,
+    )];
+
+    // Amounts below the minimum storage deposit need to be allowed explicitly
+    assert!(
+        account_0
+            .dry_run_send_amount(addresses_with_amount.clone(), None)
+            .await
+            .is_err()
+    );
+
+    let expiration = 3600;
+    let local_time = account_0.client().get_time_checked().await?;
+    let dry_run = account_0
+        .dry_run_send_amount(
+            addresses_with_amount,
+            TransactionOptions {
+                allow_micro_amount: true,
+                micro_amount_expiration: Some(expiration),
+                ..Default::default()
+            },
+        )
+        .await?;
+
+    let TransactionEssence::Regular(essence) = &dry_run.prepared_transaction_data.essence;
+    let micro_output = essence
+        .outputs()
+        .iter()
+        .find(|output| {
+            output
+                .unlock_conditions()
+                .map_or(false, |unlock_conditions| unlock_conditions.storage_deposit_return().is_some())
+        })
+        .unwrap();
+    let unlock_conditions = micro_output.unlock_conditions().unwrap();
+    // The receiver only gets the micro amount, the storage deposit is returned to the sender
+    assert_eq!(
+        micro_output.amount() - unlock_conditions.storage_deposit_return().unwrap().amount(),
+        1
+    );
+    let expiration_timestamp = unlock_conditions.expiration().unwrap().timestamp();
+    assert!(expiration_timestamp >= local_time + expiration);
+    assert!(expiration_timestamp <= local_time + expiration + 60);
+
+    tear_down(storage_path)
+}
+
+#[ignore]
+#[tokio::test]
+async fn dry_run_send_amount() -> Result<()> {
+    let storage_path = "test-storage/dry_run_send_amount";
+    setup(storage_path)?;
+
+    let wallet = make_wallet(storage_path, None, None).await?;
+
+    let account_0 = &create_accounts_with_funds(&wallet, 1).await?[0];
+    let account_1 = wallet.create_account().finish().await?;
+
+    let amount = 1_000_000;
+    let addresses_with_amount = vec![AddressWithAmount::new(
+        account_1.addresses().await?[0].address().to_string(),
+        amount,
+    )];
+    let dry_run = account_0.dry_run_send_amount(addresses_with_amount.clone(), None).await?;
+
+    assert_eq!(dry_run.summary.sent_amount, amount);
+    assert_eq!(dry_run.summary.consumed_amount, dry_run.summary.created_amount);
+    assert_eq!(
+        dry_run.summary.inputs.len(),
+        dry_run.prepared_transaction_data.inputs_data.len()
+    );
+    // The inputs aren't locked, so the same transaction is prepared again
+    assert_eq!(
+        account_0.dry_run_send_amount(addresses_with_amount, None).await?.summary,
+        dry_run.summary
+    );
+    assert!(account_0.read().await.locked_outputs().is_empty());
+
+    tear_down(storage_path)
+}
+
 #[ignore]
 #[tokio::test]
 async fn send_amount_127_outputs() -> Result<()> {
@@ -148,10 +426,10 @@ async fn send_nft() -> Result<()> {
     // Send to account 1
     let transaction = accounts[0]
         .send_nft(
-            vec![AddressAndNftId {
-                address: accounts[1].addresses().await?[0].address().to_string(),
+            vec![SendNftParams::new(
+                accounts[1].addresses().await?[0].address().to_string(),
                 nft_id,
-            }],
+            )],
             None,
         )
         .await
@@ -166,3 +444,236 @@ async fn send_nft() -> Result<()> {
 
     tear_down(storage_path)
 }
+
+#[ignore]
+#[tokio::test]
+async fn send_and_accept_nft_offer() -> Result<()> {
+    let storage_path = "test-storage/send_and_accept_nft_offer";
+    setup(storage_path)?;
+
+    let wallet = make_wallet(storage_path, None, None).await?;
+    let accounts = &create_accounts_with_funds(&wallet, 2).await?;
+
+    let nft_options = vec![NftOptions {
+        address: None,
+        sender: None,
+        metadata: None,
+        tag: None,
+        issuer: None,
+        immutable_metadata: None,
+    }];
+
+    let transaction = accounts[0].mint_nfts(nft_options, None).await?;
+    accounts[0]
+        .retry_transaction_until_included(&transaction.transaction_id, None, None)
+        .await?;
+    let nft_id = *accounts[0].sync(None).await?.nfts().first().unwrap();
+
+    // Offer to account 1, which has to return the storage deposit
+    let transaction = accounts[0]
+        .send_nft(
+            vec![
+                SendNftParams::new(accounts[1].addresses().await?[0].address().to_string(), nft_id)
+                    .with_expiration(3600)
+                    .with_return_storage_deposit(true),
+            ],
+            None,
+        )
+        .await?;
+    accounts[0]
+        .retry_transaction_until_included(&transaction.transaction_id, None, None)
+        .await?;
+
+    accounts[1].sync(None).await?;
+    let offer = accounts[1]
+        .unspent_outputs(None)
+        .await?
+        .into_iter()
+        .find(|output_data| match &output_data.output {
+            Output::Nft(nft_output) => nft_output.nft_id_non_null(&output_data.output_id) == nft_id,
+            _ => false,
+        })
+        .unwrap();
+    let unlock_conditions = offer.output.unlock_conditions().unwrap();
+    assert!(unlock_conditions.expiration().is_some());
+    assert_eq!(
+        unlock_conditions.storage_deposit_return().unwrap().amount(),
+        offer.output.amount()
+    );
+
+    let transaction = accounts[1].accept_nft_offer(offer.output_id).await?;
+    accounts[1]
+        .retry_transaction_until_included(&transaction.transaction_id, None, None)
+        .await?;
+
+    let balance = accounts[1].sync(None).await?;
+    assert_eq!(balance.nfts(), &[nft_id]);
+    let nft_output = accounts[1]
+        .unspent_outputs(None)
+        .await?
+        .into_iter()
+        .find(|output_data| matches!(output_data.output, Output::Nft(_)))
+        .unwrap();
+    // Accepted without expiration
+    assert_eq!(nft_output.output.unlock_conditions().unwrap().len(), 1);
+
+    tear_down(storage_path)
+}
+
+#[tokio::test]
+async fn accept_unknown_nft_offer() -> Result<()> {
+    let storage_path = "test-storage/accept_unknown_nft_offer";
+    setup(storage_path)?;
+
+    let wallet = make_wallet(storage_path, None, None).await?;
+    let account = wallet.create_account().finish().await?;
+
+    assert!(matches!(
+        account.accept_nft_offer(OutputId::null()).await,
+        Err(Error::NftNotFoundInUnspentOutputs)
+    ));
+
+    tear_down(storage_path)
+}
+
+#[ignore]
+#[tokio::test]
+async fn nft_irc_27_metadata() -> Result<()> {
+    let storage_path = "test-storage/nft_irc_27_metadata";
+    setup(storage_path)?;
+
+    let wallet = make_wallet(storage_path, None, None).await?;
+    let accounts = &create_accounts_with_funds(&wallet, 2).await?;
+
+    let irc_27_metadata = Irc27Metadata::new("image/png", "ipfs://QmHash/nft.png", "NFT #1");
+    let nft_options = vec![
+        NftOptions {
+            address: None,
+            sender: None,
+            metadata: None,
+            tag: None,
+            issuer: None,
+            immutable_metadata: Some(irc_27_metadata.to_bytes()?),
+        },
+        NftOptions {
+            address: None,
+            sender: None,
+            metadata: None,
+            tag: None,
+            issuer: None,
+            immutable_metadata: Some(b"some immutable nft metadata".to_vec()),
+        },
+    ];
+
+    let transaction = accounts[0].mint_nfts(nft_options, None).await?;
+    accounts[0]
+        .retry_transaction_until_included(&transaction.transaction_id, None, None)
+        .await?;
+    accounts[0].sync(None).await?;
+
+    let TransactionEssence::Regular(essence) = transaction.payload.essence();
+    let nft_ids = essence
+        .outputs()
+        .iter()
+        .enumerate()
+        .filter_map(|(index, output)| match output {
+            Output::Nft(_) => Some(NftId::from(&OutputId::new(transaction.transaction_id, index as u16).unwrap())),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+
+    // Owned by the account
+    assert_eq!(accounts[0].get_nft_metadata(nft_ids[0]).await?, Some(irc_27_metadata.clone()));
+    // Requested from the node
+    assert_eq!(accounts[1].get_nft_metadata(nft_ids[0]).await?, Some(irc_27_metadata));
+    // Not following the standard
+    assert!(matches!(
+        accounts[0].get_nft_metadata(nft_ids[1]).await,
+        Err(Error::Block(error)) if matches!(*error, BlockError::InvalidIrc27Metadata(_))
+    ));
+
+    tear_down(storage_path)
+}
+
+#[ignore]
+#[tokio::test]
+async fn receive_on_nft_address() -> Result<()> {
+    let storage_path = "test-storage/receive_on_nft_address";
+    setup(storage_path)?;
+
+    let wallet = make_wallet(storage_path, None, None).await?;
+    let account = &create_accounts_with_funds(&wallet, 1).await?[0];
+    assert!(account.chain_addresses().await?.is_empty());
+
+    let nft_options = vec![NftOptions {
+        address: None,
+        sender: None,
+        metadata: None,
+        tag: None,
+        issuer: None,
+        immutable_metadata: None,
+    }];
+    let transaction = account.mint_nfts(nft_options, None).await?;
+    account
+        .retry_transaction_until_included(&transaction.transaction_id, None, None)
+        .await?;
+    let nft_id = *account.sync(None).await?.nfts().first().unwrap();
+
+    let chain_addresses = account.chain_addresses().await?;
+    assert_eq!(chain_addresses.len(), 1);
+    let nft_address = chain_addresses[0].address();
+    assert_eq!(nft_address.inner(), &Address::Nft(NftAddress::from(nft_id)));
+    assert_eq!(chain_addresses[0].owner(), account.addresses().await?[0].address());
+
+    // Funds sent to the NFT address are owned by the account
+    let amount = 1_000_000;
+    let transaction = account
+        .send_amount(vec![AddressWithAmount::new(nft_address.to_string(), amount)], None)
+        .await?;
+    account
+        .retry_transaction_until_included(&transaction.transaction_id, None, None)
+        .await?;
+    account.sync(None).await?;
+    assert!(
+        account
+            .unspent_outputs(None)
+            .await?
+            .iter()
+            .any(|output_data| output_data.address == *nft_address.inner() && output_data.output.amount() == amount)
+    );
+
+    tear_down(storage_path)
+}
+
+struct TestFormatter;
+
+impl Formatter for TestFormatter {
+    fn format_amount(&self, amount: u64) -> String {
+        format!("{amount} units")
+    }
+}
+
+#[test]
+fn transaction_summary_formatting() {
+    let summary = TransactionSummary {
+        inputs: Vec::new(),
+        consumed_amount: 3,
+        created_amount: 3,
+        sent_amount: 2,
+        remainder_amount: 1,
+        outputs_count: 2,
+        consumed_storage_deposit: 2,
+        created_storage_deposit: 1,
+        storage_deposit_delta: -1,
+    };
+
+    assert_eq!(
+        summary.to_string(),
+        "consumes 0 input(s) with 3, creates 2 output(s) with 3 (2 sent, 1 remainder), storage deposit delta -1"
+    );
+    assert_eq!(
+        summary.to_string_with(&TestFormatter),
+        "consumes 0 input(s) with 3 units, creates 2 output(s) with 3 units (2 units sent, 1 units remainder), \
+         storage deposit delta -1 units"
+    );
+}
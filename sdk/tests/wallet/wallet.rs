@@ -11,9 +11,13 @@ use iota_sdk::{
 use iota_sdk::{
     client::{
         constants::IOTA_COIN_TYPE,
-        secret::{mnemonic::MnemonicSecretManager, SecretManager},
+        secret::{mnemonic::MnemonicSecretManager, GenerateAddressOptions, SecretManager},
+    },
+    types::block::{address::Bech32Address, output::RentStructure, protocol::ProtocolParameters},
+    wallet::{
+        proof_of_reserves::{CustomerBalance, MerkleSumTree, ProofOfReserves},
+        AddressOwner, ClientOptions, Result, SearchItem, SearchResult, Wallet,
     },
-    wallet::{ClientOptions, Result, Wallet},
 };
 
 use crate::wallet::common::{make_wallet, setup, tear_down, DEFAULT_MNEMONIC, NODE_LOCAL, NODE_OTHER};
@@ -41,6 +45,19 @@ async fn update_client_options() -> Result<()> {
     assert!(client_options.node_manager_builder.nodes.contains(&node_dto_new));
     assert!(!client_options.node_manager_builder.nodes.contains(&node_dto_old));
 
+    // Invalid options are rejected and the previous ones are kept
+    let invalid_options = ClientOptions::new()
+        .with_node(NODE_OTHER)?
+        .with_api_timeout(std::time::Duration::ZERO);
+    assert!(matches!(
+        wallet.set_client_options(invalid_options).await,
+        Err(iota_sdk::wallet::Error::Client(error)) if matches!(
+            *error,
+            iota_sdk::client::Error::InvalidClientOptions { option: "apiTimeout", .. }
+        )
+    ));
+    assert_eq!(wallet.get_client_options().await, client_options);
+
     tear_down(storage_path)
 }
 
@@ -157,6 +174,55 @@ async fn iota_coin_type() -> Result<()> {
     tear_down(storage_path)
 }
 
+#[tokio::test]
+async fn private_tangle() -> Result<()> {
+    let storage_path = "test-storage/private_tangle";
+    setup(storage_path)?;
+
+    // A private tangle with its own HRP, coin type and rent structure, no node is needed to create accounts
+    let protocol_parameters = ProtocolParameters::new(
+        2,
+        String::from("private-tangle"),
+        String::from("tst"),
+        0,
+        15,
+        RentStructure::new(10, 10, 1),
+        2_779_530_283_277_761,
+    )?;
+    let client_options = ClientOptions::new().with_protocol_parameters(protocol_parameters.clone());
+    let secret_manager = MnemonicSecretManager::try_from_mnemonic(DEFAULT_MNEMONIC)?;
+
+    #[allow(unused_mut)]
+    let mut wallet_builder = Wallet::builder()
+        .with_secret_manager(SecretManager::Mnemonic(secret_manager))
+        .with_client_options(client_options)
+        .with_coin_type(1);
+    #[cfg(feature = "storage")]
+    {
+        wallet_builder = wallet_builder.with_storage_path(storage_path);
+    }
+    let wallet = wallet_builder.finish().await?;
+
+    assert_eq!(wallet.get_bech32_hrp().await?, "tst");
+
+    let account = wallet.create_account().finish().await?;
+    assert_eq!(account.read().await.coin_type(), &1);
+    let address = account.addresses().await?[0].address().clone();
+    assert_eq!(address.hrp(), "tst");
+    assert!(address.to_string().starts_with("tst1"));
+
+    let generated = account.generate_addresses(1, None).await?;
+    assert_eq!(generated[0].address().hrp(), "tst");
+
+    assert_eq!(wallet.get_bech32_hrp().await?, "tst");
+    assert_eq!(
+        account.client().get_rent_structure().await?,
+        *protocol_parameters.rent_structure()
+    );
+
+    tear_down(storage_path)
+}
+
 #[tokio::test]
 async fn wallet_address_generation() -> Result<()> {
     let storage_path = "test-storage/wallet_address_generation";
@@ -245,3 +311,357 @@ async fn update_node_auth() -> Result<()> {
 
     tear_down(storage_path)
 }
+
+#[tokio::test]
+async fn prove_ownership() -> Result<()> {
+    let storage_path = "test-storage/prove_ownership";
+    setup(storage_path)?;
+
+    let wallet = make_wallet(storage_path, Some(DEFAULT_MNEMONIC), None).await?;
+    let account = wallet.create_account().with_alias("Alice".to_string()).finish().await?;
+    let address = account.addresses().await?[0].address().clone();
+
+    let challenge = b"proof-of-reserves 2023-06-30";
+    let proofs = wallet.prove_ownership(&[address.clone()], challenge).await?;
+    assert_eq!(proofs.len(), 1);
+    assert_eq!(proofs[0].address, address);
+    proofs[0].verify(challenge)?;
+    assert!(proofs[0].verify(b"another challenge").is_err());
+
+    // Signature from another address is invalid
+    let other_address = account.generate_addresses(1, None).await?[0].address().clone();
+    let mut proof = proofs[0].clone();
+    proof.address = other_address;
+    assert!(proof.verify(challenge).is_err());
+
+    // Unknown address
+    let unknown_address = wallet.generate_address(1, 0, None).await?.to_bech32(address.hrp());
+    assert!(
+        wallet
+            .prove_ownership(&[unknown_address.parse()?], challenge)
+            .await
+            .is_err()
+    );
+
+    tear_down(storage_path)
+}
+
+#[tokio::test]
+async fn find_account_for_address() -> Result<()> {
+    let storage_path = "test-storage/find_account_for_address";
+    setup(storage_path)?;
+
+    let wallet = make_wallet(storage_path, Some(DEFAULT_MNEMONIC), None).await?;
+    wallet.create_account().with_alias("Alice".to_string()).finish().await?;
+    let account = wallet.create_account().with_alias("Bob".to_string()).finish().await?;
+
+    // Generated address of the account
+    let address = account.addresses().await?[0].address().clone();
+    assert_eq!(
+        wallet.find_account_for_address(&address, 0..10).await?,
+        Some(AddressOwner {
+            account_index: 1,
+            key_index: 0,
+            internal: false
+        })
+    );
+
+    // Address that wasn't generated by the account yet
+    let internal_address: Bech32Address = wallet
+        .generate_address(1, 5, Some(GenerateAddressOptions::internal()))
+        .await?
+        .to_bech32(address.hrp())
+        .parse()?;
+    assert_eq!(
+        wallet.find_account_for_address(&internal_address, 0..10).await?,
+        Some(AddressOwner {
+            account_index: 1,
+            key_index: 5,
+            internal: true
+        })
+    );
+    // Outside of the searched range
+    assert!(wallet.find_account_for_address(&internal_address, 0..5).await?.is_none());
+
+    // Account that doesn't exist in the wallet
+    let unknown_address: Bech32Address = wallet
+        .generate_address(2, 0, None)
+        .await?
+        .to_bech32(address.hrp())
+        .parse()?;
+    assert!(wallet.find_account_for_address(&unknown_address, 0..10).await?.is_none());
+
+    tear_down(storage_path)
+}
+
+#[tokio::test]
+async fn search() -> Result<()> {
+    let storage_path = "test-storage/search";
+    setup(storage_path)?;
+
+    let wallet = make_wallet(storage_path, Some(DEFAULT_MNEMONIC), None).await?;
+    wallet.create_account().finish().await?;
+    let account = wallet.create_account().finish().await?;
+    let address = account.addresses().await?[0].address().clone();
+
+    // Exact match, regardless of the case
+    assert_eq!(
+        wallet.search(&address.to_string().to_uppercase()).await?,
+        [SearchResult {
+            account_index: 1,
+            item: SearchItem::Address(address.clone()),
+            rank: 3,
+        }]
+    );
+
+    // Prefix
+    let results = wallet.search(&address.to_string()[..20]).await?;
+    assert_eq!(results[0].item, SearchItem::Address(address));
+    assert_eq!(results[0].rank, 2);
+
+    // `b`, `i` and `o` aren't part of bech32 or hex encoded values
+    assert!(wallet.search("bio").await?.is_empty());
+    assert!(wallet.search(" ").await?.is_empty());
+
+    tear_down(storage_path)
+}
+
+#[tokio::test]
+async fn reserves_snapshot() -> Result<()> {
+    let storage_path = "test-storage/reserves_snapshot";
+    setup(storage_path)?;
+
+    let wallet = make_wallet(storage_path, Some(DEFAULT_MNEMONIC), None).await?;
+    let account = wallet.create_account().with_alias("Alice".to_string()).finish().await?;
+    let address = account.addresses().await?[0].address().clone();
+
+    let challenge = b"proof-of-reserves 2023-06-30";
+    let snapshot = wallet.reserves_snapshot(&[address], challenge).await?;
+    // Not synced, so there are no holdings
+    assert_eq!(snapshot.total_holdings, 0);
+
+    let tree = MerkleSumTree::new(vec![CustomerBalance::new("customer", "nonce", 1)])?;
+    let proof_of_reserves = ProofOfReserves::new(&tree, snapshot);
+    // The holdings don't cover the liabilities
+    assert!(!proof_of_reserves.verify(challenge)?);
+    assert!(proof_of_reserves.verify(b"another challenge").is_err());
+
+    tear_down(storage_path)
+}
+
+#[cfg(feature = "storage")]
+#[tokio::test]
+async fn storage_encryption() -> Result<()> {
+    use iota_sdk::{client::constants::SHIMMER_COIN_TYPE, wallet::storage::encryption::StorageEncryption};
+
+    let storage_path = "test-storage/storage_encryption";
+    setup(storage_path)?;
+
+    let build_wallet = |encryption: Option<StorageEncryption>| async move {
+        let secret_manager = MnemonicSecretManager::try_from_mnemonic(DEFAULT_MNEMONIC)?;
+        let mut wallet_builder = Wallet::builder()
+            .with_secret_manager(SecretManager::Mnemonic(secret_manager))
+            .with_client_options(ClientOptions::new().with_node(NODE_LOCAL)?)
+            .with_coin_type(SHIMMER_COIN_TYPE)
+            .with_storage_path(storage_path);
+        if let Some(encryption) = encryption {
+            wallet_builder = wallet_builder.with_storage_encryption(encryption);
+        }
+        wallet_builder.finish().await
+    };
+
+    let wallet = build_wallet(Some(StorageEncryption::Key([1; 32]))).await?;
+    wallet.create_account().with_alias("Alice".to_string()).finish().await?;
+    drop(wallet);
+
+    // The storage can only be read with the right key
+    assert!(build_wallet(None).await.is_err());
+    assert!(build_wallet(Some(StorageEncryption::Key([2; 32]))).await.is_err());
+    let wallet = build_wallet(Some(StorageEncryption::Key([1; 32]))).await?;
+    assert_eq!(wallet.get_account("Alice").await?.alias().await, "Alice");
+
+    // Rotate the key to a password
+    wallet
+        .set_storage_encryption(Some(StorageEncryption::Password("password".to_string())))
+        .await?;
+    drop(wallet);
+    assert!(build_wallet(Some(StorageEncryption::Key([1; 32]))).await.is_err());
+    let wallet = build_wallet(Some(StorageEncryption::Password("password".to_string()))).await?;
+    assert_eq!(wallet.get_account("Alice").await?.alias().await, "Alice");
+
+    // Decrypt the storage again
+    wallet.set_storage_encryption(None).await?;
+    drop(wallet);
+    let wallet = build_wallet(None).await?;
+    assert_eq!(wallet.get_accounts().await?.len(), 1);
+
+    tear_down(storage_path)
+}
+
+#[cfg(all(feature = "storage", feature = "events"))]
+#[tokio::test]
+async fn event_persistence() -> Result<()> {
+    use iota_sdk::{
+        client::constants::SHIMMER_COIN_TYPE,
+        wallet::{
+            events::types::{TransactionProgressEvent, WalletEvent, WalletEventType},
+            storage::event_history::WalletEventFilter,
+        },
+    };
+
+    let storage_path = "test-storage/event_persistence";
+    setup(storage_path)?;
+
+    let build_wallet = || async {
+        let secret_manager = MnemonicSecretManager::try_from_mnemonic(DEFAULT_MNEMONIC)?;
+        Wallet::builder()
+            .with_secret_manager(SecretManager::Mnemonic(secret_manager))
+            .with_client_options(ClientOptions::new().with_node(NODE_LOCAL)?)
+            .with_coin_type(SHIMMER_COIN_TYPE)
+            .with_storage_path(storage_path)
+            .with_event_persistence(true)
+            .finish()
+            .await
+    };
+
+    let wallet = build_wallet().await?;
+    wallet.emit_test_event(WalletEvent::ConsolidationRequired).await?;
+    wallet
+        .emit_test_event(WalletEvent::TransactionProgress(
+            TransactionProgressEvent::SelectingInputs,
+        ))
+        .await?;
+    wallet.emit_test_event(WalletEvent::ConsolidationRequired).await?;
+
+    let events = wallet.get_events(0, WalletEventFilter::default()).await?;
+    assert_eq!(events.len(), 3);
+    assert!(events.iter().enumerate().all(|(i, event)| event.sequence == i as u64));
+    assert_eq!(
+        events[1].event.event,
+        WalletEvent::TransactionProgress(TransactionProgressEvent::SelectingInputs)
+    );
+    assert_eq!(wallet.get_events(2, WalletEventFilter::default()).await?, events[2..]);
+
+    let filter = WalletEventFilter {
+        event_types: Some(vec![WalletEventType::ConsolidationRequired]),
+        ..Default::default()
+    };
+    let consolidation_events = wallet.get_events(0, filter.clone()).await?;
+    let sequences = consolidation_events.iter().map(|event| event.sequence).collect::<Vec<_>>();
+    assert_eq!(sequences, [0, 2]);
+    let filter = WalletEventFilter {
+        account_indexes: Some(vec![1]),
+        ..Default::default()
+    };
+    assert!(wallet.get_events(0, filter).await?.is_empty());
+    drop(wallet);
+
+    // The history is kept and continued when the wallet is loaded again
+    let wallet = build_wallet().await?;
+    wallet.emit_test_event(WalletEvent::ConsolidationRequired).await?;
+    let events = wallet.get_events(0, WalletEventFilter::default()).await?;
+    assert_eq!(events.len(), 4);
+    assert_eq!(events[3].sequence, 3);
+
+    // Cleared events are removed, but their sequence numbers aren't used again
+    wallet.clear_events(2).await?;
+    let events = wallet.get_events(0, WalletEventFilter::default()).await?;
+    assert_eq!(events.iter().map(|event| event.sequence).collect::<Vec<_>>(), [2, 3]);
+    wallet.clear_events(u64::MAX).await?;
+    assert!(wallet.get_events(0, WalletEventFilter::default()).await?.is_empty());
+    wallet.emit_test_event(WalletEvent::ConsolidationRequired).await?;
+    let events = wallet.get_events(0, WalletEventFilter::default()).await?;
+    assert_eq!(events.iter().map(|event| event.sequence).collect::<Vec<_>>(), [4]);
+
+    tear_down(storage_path)
+}
+
+#[cfg(feature = "webhooks")]
+#[tokio::test]
+async fn webhook_delivery() -> Result<()> {
+    use std::{
+        io::{BufRead, BufReader, Read, Write},
+        net::TcpListener,
+        sync::mpsc,
+        time::Duration,
+    };
+
+    use iota_sdk::{
+        types::block::payload::transaction::TransactionId,
+        wallet::{
+            account::types::InclusionState,
+            events::{
+                types::{TransactionInclusionEvent, WalletEvent},
+                webhook::{webhook_signature, WebhookOptions, SIGNATURE_HEADER, TIMESTAMP_HEADER},
+            },
+        },
+    };
+
+    let storage_path = "test-storage/webhook_delivery";
+    setup(storage_path)?;
+
+    // An endpoint that fails the first request and accepts the retry
+    let listener = TcpListener::bind("127.0.0.1:0")?;
+    let url = format!("http://localhost:{}/events", listener.local_addr()?.port());
+    let (sender, receiver) = mpsc::channel();
+    std::thread::spawn(move || {
+        for (i, stream) in listener.incoming().enumerate() {
+            let mut reader = BufReader::new(stream.unwrap());
+            let mut headers = Vec::new();
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                if line.trim().is_empty() {
+                    break;
+                }
+                headers.push(line.trim().to_lowercase());
+            }
+            let header = |name: &str| {
+                headers
+                    .iter()
+                    .find_map(|header| header.strip_prefix(&format!("{}: ", name.to_lowercase())).map(str::to_string))
+                    .unwrap()
+            };
+            let mut body = vec![0; header("content-length").parse().unwrap()];
+            reader.read_exact(&mut body).unwrap();
+            let status = if i == 0 { "500 Internal Server Error" } else { "200 OK" };
+            write!(
+                reader.get_mut(),
+                "HTTP/1.1 {status}\r\ncontent-length: 0\r\nconnection: close\r\n\r\n"
+            )
+            .unwrap();
+            sender
+                .send((header(TIMESTAMP_HEADER), header(SIGNATURE_HEADER), String::from_utf8(body).unwrap()))
+                .unwrap();
+        }
+    });
+
+    let wallet = make_wallet(storage_path, None, None).await?;
+    wallet
+        .add_webhook(
+            WebhookOptions::new(&url, "secret")?.with_retry_interval(Duration::from_millis(10), Duration::from_secs(1)),
+        )
+        .await?;
+    let event = WalletEvent::TransactionInclusion(TransactionInclusionEvent {
+        transaction_id: TransactionId::null(),
+        inclusion_state: InclusionState::Confirmed,
+    });
+    wallet.emit_test_event(event.clone()).await?;
+
+    let mut requests = Vec::new();
+    for _ in 0..500 {
+        requests.extend(receiver.try_iter());
+        if requests.len() == 2 {
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(10)).await;
+    }
+    assert_eq!(requests.len(), 2);
+    for (timestamp, signature, body) in requests {
+        assert_eq!(signature, webhook_signature("secret", timestamp.parse().unwrap(), &body));
+        let posted = serde_json::from_str::<iota_sdk::wallet::events::types::Event>(&body)?;
+        assert_eq!(posted.event, event);
+    }
+
+    tear_down(storage_path)
+}